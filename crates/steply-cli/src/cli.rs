@@ -3,9 +3,14 @@ use std::ffi::OsString;
 use std::path::PathBuf;
 
 use clap::builder::PossibleValuesParser;
-use clap::{Arg, ArgAction, ArgMatches, Command, error::ErrorKind};
+use clap::{Arg, ArgAction, ArgMatches, Command, error::ErrorKind, value_parser};
+use clap_complete::Shell;
 use steply_core::config::{ConfigDocs, FieldDoc, WidgetDoc, schema_docs};
-use steply_runtime::{RenderJsonRequest, StartOptions};
+use steply_core::core::action_bindings::KeymapPreset;
+use steply_core::state::export::ExportFormat;
+use steply_core::state::flow_graph::GraphFormat;
+use steply_core::ui::theme::Appearance;
+use steply_runtime::{RenderJsonRequest, SnapshotFormat, StartOptions};
 
 use crate::flow::FlowInvocation;
 use crate::prompt::PromptInvocation;
@@ -14,12 +19,26 @@ pub enum Invocation {
     Run(StartOptions),
     Prompt(PromptInvocation),
     Export(ExportInvocation),
+    Graph(GraphInvocation),
     Flow(FlowInvocation),
+    Validate(ValidateInvocation),
+    Completions(Shell),
 }
 
 pub struct ExportInvocation {
     pub kind: ExportKind,
-    pub out_path: PathBuf,
+    /// Prints to stdout when `None`.
+    pub out_path: Option<PathBuf>,
+}
+
+pub struct ValidateInvocation {
+    pub config_paths: Vec<PathBuf>,
+}
+
+pub struct GraphInvocation {
+    pub config_path: PathBuf,
+    pub format: GraphFormat,
+    pub out_path: Option<PathBuf>,
 }
 
 pub enum ExportKind {
@@ -45,7 +64,28 @@ fn parse_invocation_from(
 
     if let Some((name, sub_matches)) = matches.subcommand() {
         return match name {
-            "run" => Ok(Invocation::Run(parse_run_options(sub_matches)?)),
+            "run" => {
+                let mut options = parse_run_options(sub_matches)?;
+                if let Some(file) = sub_matches.get_one::<String>("file") {
+                    options.config_path = Some(file.clone());
+                }
+                Ok(Invocation::Run(options))
+            }
+            "replay" => Ok(Invocation::Run(parse_replay_options(sub_matches)?)),
+            "validate" => Ok(Invocation::Validate(ValidateInvocation {
+                config_paths: sub_matches
+                    .get_many::<String>("files")
+                    .map(|values| values.map(PathBuf::from).collect())
+                    .unwrap_or_default(),
+            })),
+            "completions" => Ok(Invocation::Completions(
+                sub_matches
+                    .get_one::<Shell>("shell")
+                    .copied()
+                    .ok_or_else(|| {
+                        clap::Error::raw(ErrorKind::MissingRequiredArgument, "missing shell")
+                    })?,
+            )),
             "export-schema" => Ok(Invocation::Export(parse_export_invocation(
                 ExportKind::Schema,
                 sub_matches,
@@ -54,6 +94,7 @@ fn parse_invocation_from(
                 ExportKind::Docs,
                 sub_matches,
             )?)),
+            "export-graph" => Ok(Invocation::Graph(parse_graph_invocation(sub_matches)?)),
             "flow" => Ok(Invocation::Flow(parse_flow_invocation(sub_matches)?)),
             other => {
                 let Some(doc) = docs_by_command.get(other).cloned() else {
@@ -73,6 +114,15 @@ fn parse_invocation_from(
     Ok(Invocation::Run(parse_run_options(&matches)?))
 }
 
+/// Writes the completion script for `shell`, covering every subcommand
+/// including the generated widget commands.
+pub fn write_completions(shell: Shell, out: &mut dyn std::io::Write) -> Result<(), String> {
+    let docs = schema_docs()
+        .map_err(|err| format!("failed to load widget docs for completions: {err}"))?;
+    clap_complete::generate(shell, &mut build_cli(&docs), "steply", out);
+    Ok(())
+}
+
 fn build_cli(docs: &ConfigDocs) -> Command {
     let mut command = add_run_args(
         Command::new("steply")
@@ -82,16 +132,30 @@ fn build_cli(docs: &ConfigDocs) -> Command {
             .disable_help_subcommand(true),
     )
     .subcommand(add_run_args(
-        Command::new("run").about("Run a full Steply flow from YAML or render JSON preview."),
-    ))
-    .subcommand(build_export_command(
-        "export-schema",
-        "Export the generated JSON Schema for the YAML config format.",
+        Command::new("run")
+            .about("Run a full Steply flow from YAML or render JSON preview.")
+            .arg(
+                Arg::new("file")
+                    .value_name("FILE")
+                    .conflicts_with("config")
+                    .help("YAML flow to run; same as --config."),
+            ),
     ))
+    .subcommand(build_replay_command())
+    .subcommand(build_validate_command())
+    .subcommand(
+        build_export_command(
+            "export-schema",
+            "Export the generated JSON Schema for the YAML config format.",
+        )
+        .visible_alias("schema"),
+    )
     .subcommand(build_export_command(
         "export-docs",
         "Export the generated docs JSON consumed by the web documentation.",
     ))
+    .subcommand(build_graph_command())
+    .subcommand(build_completions_command())
     .subcommand(build_flow_command());
 
     let mut widgets = docs.widgets.clone();
@@ -189,12 +253,144 @@ fn add_run_args(command: Command) -> Command {
                 .value_name("PATH_OR_URL")
                 .help("YAML config path, http(s) URL, or '-' to read YAML from stdin."),
         )
+        .arg(
+            Arg::new("prefill")
+                .long("prefill")
+                .value_name("PATH")
+                .help("JSON file of values keyed by store selector used to prefill fields."),
+        )
+        .arg(
+            Arg::new("env_file")
+                .long("env-file")
+                .value_name("PATH")
+                .action(ArgAction::Append)
+                .help("Load a .env file under the 'env' store root; repeat to layer files."),
+        )
+        .arg(Arg::new("answers").long("answers").value_name("PATH").help(
+            "JSON or YAML answers keyed by store selector; completes the flow without a terminal.",
+        ))
+        .arg(
+            Arg::new("output")
+                .long("output")
+                .value_name("PATH")
+                .help("Write the collected values here when the flow finishes ('-' for stdout)."),
+        )
+        .arg(
+            Arg::new("output_format")
+                .long("output-format")
+                .value_name("FORMAT")
+                .value_parser(PossibleValuesParser::new([
+                    "json", "yaml", "toml", "markdown", "html",
+                ]))
+                .help("Format of the values written on finish; markdown and html write a report."),
+        )
+        .arg(
+            Arg::new("json")
+                .long("json")
+                .action(ArgAction::SetTrue)
+                .help("Print the collected values as JSON on stdout and draw the UI on stderr."),
+        )
+        .arg(
+            Arg::new("locale")
+                .long("locale")
+                .value_name("LOCALE")
+                .help("Locale of the config's message catalogs to use, e.g. 'de' or 'pt-BR'."),
+        )
+        .arg(Arg::new("title").long("title").value_name("TITLE").help(
+            "Set the terminal window title, followed by the current step, e.g. 'Setup — step 3/7'.",
+        ))
+        .arg(Arg::new("theme").long("theme").value_name("PATH").help(
+            "TOML theme with palette, per-widget colors and glyphs; reloaded when it changes.",
+        ))
+        .arg(
+            Arg::new("appearance")
+                .long("appearance")
+                .value_name("MODE")
+                .value_parser(PossibleValuesParser::new([
+                    "auto",
+                    "light",
+                    "dark",
+                    "high-contrast",
+                ]))
+                .help(
+                    "Default theme for a light or dark terminal, or high contrast; 'auto' asks \
+                     the terminal.",
+                ),
+        )
+        .arg(
+            Arg::new("skip_answered")
+                .long("skip-answered")
+                .action(ArgAction::SetTrue)
+                .help("Skip leading steps whose fields are all prefilled."),
+        )
+        .arg(
+            Arg::new("fields")
+                .num_args(0..)
+                .last(true)
+                .allow_hyphen_values(true)
+                .value_name("FIELD_ARGS")
+                .help("Field answers after '--', e.g. '-- --name api --port 8080'."),
+        )
+        .arg(
+            Arg::new("ascii")
+                .long("ascii")
+                .action(ArgAction::SetTrue)
+                .help("Draw arrows, borders and markers with plain ASCII characters."),
+        )
+        .arg(
+            Arg::new("keymap")
+                .long("keymap")
+                .value_name("PRESET")
+                .value_parser(PossibleValuesParser::new(["default", "vim", "emacs"]))
+                .help("Navigation key preset applied across all widgets."),
+        )
+        .arg(
+            Arg::new("announce")
+                .long("announce")
+                .value_name("PATH")
+                .help(
+                    "Append screen-reader announcements of focus, value and error changes to a file or FIFO.",
+                ),
+        )
+        .arg(
+            Arg::new("inspect")
+                .long("inspect")
+                .action(ArgAction::SetTrue)
+                .help("Record state after every action; Alt+I opens an inspector to step back through it."),
+        )
+        .arg(
+            Arg::new("record_input")
+                .long("record-input")
+                .value_name("PATH")
+                .help("Record terminal input with timestamps to a file for later replay."),
+        )
+        .arg(
+            Arg::new("replay_input")
+                .long("replay-input")
+                .value_name("PATH")
+                .conflicts_with("record_input")
+                .help("Replay terminal input from a file written by --record-input."),
+        )
+        .arg(
+            Arg::new("replay_speed")
+                .long("replay-speed")
+                .value_name("FACTOR")
+                .requires("replay_input")
+                .help("Replay speed multiplier, e.g. 2 for double speed or 0 for no delays."),
+        )
         .arg(
             Arg::new("render_json")
                 .long("render-json")
                 .action(ArgAction::SetTrue)
                 .help("Print preview render JSON instead of running the interactive flow."),
         )
+        .arg(
+            Arg::new("render_format")
+                .long("render-format")
+                .value_name("FORMAT")
+                .value_parser(PossibleValuesParser::new(["json", "text", "ansi"]))
+                .help("Preview output format; 'text' and 'ansi' print a snapshot of the frame."),
+        )
         .arg(
             Arg::new("render_scope")
                 .long("render-scope")
@@ -261,11 +457,89 @@ fn build_export_command(name: &'static str, about: &'static str) -> Command {
         Arg::new("out")
             .long("out")
             .value_name("PATH")
-            .required(true)
-            .help("Destination path for the generated JSON file."),
+            .help("Destination path for the generated JSON file; prints to stdout when omitted."),
     )
 }
 
+fn build_replay_command() -> Command {
+    Command::new("replay")
+        .about("Run a flow driven by terminal input written by --record-input.")
+        .arg(
+            Arg::new("recording")
+                .required(true)
+                .value_name("RECORDING")
+                .help("Input recording to replay."),
+        )
+        .arg(
+            Arg::new("file")
+                .value_name("FILE")
+                .conflicts_with("config")
+                .help("YAML flow the recording was made against; same as --config."),
+        )
+        .arg(
+            Arg::new("config")
+                .long("config")
+                .value_name("PATH_OR_URL")
+                .help("YAML config path, http(s) URL, or '-' to read YAML from stdin."),
+        )
+        .arg(
+            Arg::new("speed")
+                .long("speed")
+                .value_name("FACTOR")
+                .help("Replay speed multiplier, e.g. 2 for double speed or 0 for no delays."),
+        )
+}
+
+fn build_validate_command() -> Command {
+    Command::new("validate")
+        .about("Check YAML flows without running them; exits non-zero on the first invalid one.")
+        .arg(
+            Arg::new("files")
+                .required(true)
+                .num_args(1..)
+                .value_name("FILE")
+                .help("YAML flows to check."),
+        )
+}
+
+fn build_completions_command() -> Command {
+    Command::new("completions")
+        .about("Print a shell completion script, e.g. `steply completions zsh > _steply`.")
+        .arg(
+            Arg::new("shell")
+                .required(true)
+                .value_name("SHELL")
+                .value_parser(value_parser!(Shell))
+                .help("Shell to generate completions for."),
+        )
+}
+
+fn build_graph_command() -> Command {
+    Command::new("export-graph")
+        .about("Export the step/branch graph of a YAML flow as Mermaid or Graphviz DOT.")
+        .arg(
+            Arg::new("config")
+                .long("config")
+                .value_name("PATH")
+                .required(true)
+                .help("YAML config path."),
+        )
+        .arg(
+            Arg::new("format")
+                .long("format")
+                .value_name("FORMAT")
+                .value_parser(PossibleValuesParser::new(["mermaid", "dot"]))
+                .default_value("mermaid")
+                .help("Graph syntax to emit."),
+        )
+        .arg(
+            Arg::new("out")
+                .long("out")
+                .value_name("PATH")
+                .help("Destination path; prints to stdout when omitted."),
+        )
+}
+
 fn build_widget_arg(field: &FieldDoc) -> Arg {
     let mut arg = Arg::new(field.name.clone())
         .long(flag_name(field.name.as_str()))
@@ -287,7 +561,10 @@ fn build_widget_arg(field: &FieldDoc) -> Arg {
 
 fn parse_run_options(matches: &ArgMatches) -> Result<StartOptions, clap::Error> {
     let config_path = matches.get_one::<String>("config").cloned();
-    let render_json = if matches.get_flag("render_json") {
+    let render_format = matches
+        .get_one::<String>("render_format")
+        .and_then(|raw| SnapshotFormat::parse(raw));
+    let render_json = if matches.get_flag("render_json") || render_format.is_some() {
         Some(
             RenderJsonRequest::from_named_parts(
                 matches.get_one::<String>("render_scope").cloned(),
@@ -308,7 +585,54 @@ fn parse_run_options(matches: &ArgMatches) -> Result<StartOptions, clap::Error>
 
     Ok(StartOptions {
         config_path,
+        prefill_path: matches.get_one::<String>("prefill").cloned(),
+        env_files: matches
+            .get_many::<String>("env_file")
+            .map(|values| values.cloned().collect())
+            .unwrap_or_default(),
+        field_args: matches
+            .get_many::<String>("fields")
+            .map(|values| values.cloned().collect())
+            .unwrap_or_default(),
+        skip_answered_steps: matches.get_flag("skip_answered"),
+        answers_path: matches.get_one::<String>("answers").cloned(),
+        output_path: matches.get_one::<String>("output").cloned(),
+        output_format: matches
+            .get_one::<String>("output_format")
+            .and_then(|raw| ExportFormat::parse(raw)),
+        json_result: matches.get_flag("json"),
+        locale: matches.get_one::<String>("locale").cloned(),
+        window_title: matches.get_one::<String>("title").cloned(),
+        theme_path: matches.get_one::<String>("theme").cloned(),
+        appearance: matches
+            .get_one::<String>("appearance")
+            .and_then(|raw| Appearance::parse(raw)),
+        ascii_glyphs: matches.get_flag("ascii"),
+        keymap: matches
+            .get_one::<String>("keymap")
+            .and_then(|raw| KeymapPreset::parse(raw)),
+        announce_path: matches.get_one::<String>("announce").cloned(),
+        inspect: matches.get_flag("inspect"),
+        record_input_path: matches.get_one::<String>("record_input").cloned(),
+        replay_input_path: matches.get_one::<String>("replay_input").cloned(),
+        replay_speed: parse_optional_f64(
+            matches.get_one::<String>("replay_speed"),
+            "--replay-speed",
+        )?,
         render_json,
+        render_format,
+    })
+}
+
+fn parse_replay_options(matches: &ArgMatches) -> Result<StartOptions, clap::Error> {
+    Ok(StartOptions {
+        config_path: matches
+            .get_one::<String>("file")
+            .or_else(|| matches.get_one::<String>("config"))
+            .cloned(),
+        replay_input_path: matches.get_one::<String>("recording").cloned(),
+        replay_speed: parse_optional_f64(matches.get_one::<String>("speed"), "--speed")?,
+        ..StartOptions::default()
     })
 }
 
@@ -361,12 +685,27 @@ fn parse_export_invocation(
     kind: ExportKind,
     matches: &ArgMatches,
 ) -> Result<ExportInvocation, clap::Error> {
-    let out_path = matches
-        .get_one::<String>("out")
+    Ok(ExportInvocation {
+        kind,
+        out_path: matches.get_one::<String>("out").map(PathBuf::from),
+    })
+}
+
+fn parse_graph_invocation(matches: &ArgMatches) -> Result<GraphInvocation, clap::Error> {
+    let config_path = matches
+        .get_one::<String>("config")
         .map(PathBuf::from)
-        .ok_or_else(|| clap::Error::raw(ErrorKind::MissingRequiredArgument, "missing --out"))?;
+        .ok_or_else(|| clap::Error::raw(ErrorKind::MissingRequiredArgument, "missing --config"))?;
+    let format = matches
+        .get_one::<String>("format")
+        .and_then(|raw| GraphFormat::parse(raw))
+        .unwrap_or_default();
 
-    Ok(ExportInvocation { kind, out_path })
+    Ok(GraphInvocation {
+        config_path,
+        format,
+        out_path: matches.get_one::<String>("out").map(PathBuf::from),
+    })
 }
 
 fn parse_optional_u16(value: Option<&String>, arg_name: &str) -> Result<Option<u16>, clap::Error> {
@@ -382,6 +721,22 @@ fn parse_optional_u16(value: Option<&String>, arg_name: &str) -> Result<Option<u
         .transpose()
 }
 
+fn parse_optional_f64(value: Option<&String>, arg_name: &str) -> Result<Option<f64>, clap::Error> {
+    value
+        .map(|raw| {
+            raw.parse::<f64>()
+                .ok()
+                .filter(|value| value.is_finite() && *value >= 0.0)
+                .ok_or_else(|| {
+                    clap::Error::raw(
+                        ErrorKind::ValueValidation,
+                        format!("invalid value for {arg_name}: {raw}"),
+                    )
+                })
+        })
+        .transpose()
+}
+
 fn build_docs_lookup(docs: &ConfigDocs) -> HashMap<String, WidgetDoc> {
     docs.widgets
         .iter()
@@ -9,7 +9,7 @@ use serde_yaml::{Mapping, Value as YamlValue};
 use steply_core::config::{WidgetDoc, load_from_yaml_str};
 use steply_core::ui::renderer::RendererConfig;
 use steply_core::{HostContext, set_host_context};
-use steply_runtime::{RenderMode, Runtime, Terminal};
+use steply_runtime::{RenderMode, RunOutcome, Runtime, Terminal};
 
 use crate::prompt::build_widget_yaml;
 
@@ -65,12 +65,11 @@ impl FlowDraft {
     }
 }
 
-pub fn handle_flow(invocation: FlowInvocation) -> Result<(), String> {
+pub fn handle_flow(invocation: FlowInvocation) -> Result<RunOutcome, String> {
     match invocation {
         FlowInvocation::Create { decorate } => {
             let flow_id = create_flow(decorate)?;
             println!("{flow_id}");
-            Ok(())
         }
         FlowInvocation::Step {
             flow_id,
@@ -79,12 +78,12 @@ pub fn handle_flow(invocation: FlowInvocation) -> Result<(), String> {
         } => {
             let step_id = create_or_select_step(flow_id.as_str(), title.as_str(), step_id)?;
             println!("{step_id}");
-            Ok(())
         }
-        FlowInvocation::Run { flow_id } => run_flow(flow_id.as_str()),
-        FlowInvocation::Export { flow_id, out_path } => export_flow(flow_id.as_str(), out_path),
-        FlowInvocation::Drop { flow_id } => drop_flow(flow_id.as_str()),
+        FlowInvocation::Run { flow_id } => return run_flow(flow_id.as_str()),
+        FlowInvocation::Export { flow_id, out_path } => export_flow(flow_id.as_str(), out_path)?,
+        FlowInvocation::Drop { flow_id } => drop_flow(flow_id.as_str())?,
     }
+    Ok(RunOutcome::Completed)
 }
 
 pub fn append_widget_to_flow(
@@ -159,7 +158,7 @@ fn create_or_select_step(
     Ok(step_id)
 }
 
-fn run_flow(flow_id: &str) -> Result<(), String> {
+fn run_flow(flow_id: &str) -> Result<RunOutcome, String> {
     let _ = set_host_context(HostContext {
         cwd: std::env::current_dir().unwrap_or_else(|_| PathBuf::from("/")),
         home_dir: std::env::var_os("HOME").map(PathBuf::from),
@@ -247,7 +246,7 @@ fn flow_storage_dir() -> Result<PathBuf, String> {
     if let Some(runtime_dir) = std::env::var_os("XDG_RUNTIME_DIR") {
         return Ok(PathBuf::from(runtime_dir).join("steply").join("flows"));
     }
-    Ok(PathBuf::from("/tmp").join("steply").join("flows"))
+    Ok(std::env::temp_dir().join("steply").join("flows"))
 }
 
 fn serialize_flow_yaml(draft: &FlowDraft) -> Result<String, String> {
@@ -7,24 +7,28 @@ use std::fs;
 use std::fs::OpenOptions;
 use std::io::Write;
 use std::panic::PanicHookInfo;
-use std::path::{Path, PathBuf};
+use std::path::PathBuf;
 use std::process;
-use std::time::{SystemTime, UNIX_EPOCH};
 
 use cli::Invocation;
 use flow::handle_flow;
 use prompt::PromptExit;
-use steply_core::config::{config_schema_json, schema_docs_json};
-use steply_runtime::run_with_options;
+use steply_core::config::{config_schema_json, load_from_yaml_file, schema_docs_json};
+use steply_runtime::recovery::{enable_crash_recovery, write_recovery_snapshot};
+use steply_runtime::{RunOutcome, run_with_options};
+use tracing::level_filters::LevelFilter;
+use tracing_subscriber::fmt::MakeWriter;
+
+#[cfg(feature = "metrics")]
+#[global_allocator]
+static ALLOCATOR: steply_runtime::metrics::CountingAllocator =
+    steply_runtime::metrics::CountingAllocator;
 
 fn main() {
+    install_error_log();
     install_panic_logging();
     if let Err(err) = run() {
-        append_error_log(
-            error_log_path().as_path(),
-            "runtime_error",
-            err.message.as_str(),
-        );
+        tracing::error!(exit_code = err.exit_code, error = %err.message, "runtime error");
         if !err.message.is_empty() {
             eprintln!("{}", err.message);
         }
@@ -34,7 +38,11 @@ fn main() {
 
 fn run() -> Result<(), CliError> {
     match cli::parse_invocation() {
-        Ok(Invocation::Run(options)) => run_with_options(options).map_err(CliError::io),
+        Ok(Invocation::Run(options)) => match run_with_options(options) {
+            Ok(RunOutcome::Completed) => Ok(()),
+            Ok(RunOutcome::Cancelled) => Err(CliError::new(130, "flow cancelled")),
+            Err(err) => Err(CliError::io(err)),
+        },
         Ok(Invocation::Prompt(invocation)) => {
             if let Some(flow_id) = invocation.flow_id.as_deref() {
                 flow::append_widget_to_flow(flow_id, &invocation.doc, &invocation.values)
@@ -48,9 +56,15 @@ fn run() -> Result<(), CliError> {
             }
         }
         Ok(Invocation::Export(invocation)) => export_json(invocation).map_err(CliError::io),
-        Ok(Invocation::Flow(invocation)) => {
-            handle_flow(invocation).map_err(|err| CliError::new(1, format!("error: {err}")))
-        }
+        Ok(Invocation::Graph(invocation)) => export_graph(invocation).map_err(CliError::io),
+        Ok(Invocation::Flow(invocation)) => match handle_flow(invocation) {
+            Ok(RunOutcome::Completed) => Ok(()),
+            Ok(RunOutcome::Cancelled) => Err(CliError::new(130, "flow cancelled")),
+            Err(err) => Err(CliError::new(1, format!("error: {err}"))),
+        },
+        Ok(Invocation::Validate(invocation)) => validate_configs(invocation),
+        Ok(Invocation::Completions(shell)) => cli::write_completions(shell, &mut std::io::stdout())
+            .map_err(|err| CliError::new(1, format!("error: {err}"))),
         Err(err) => {
             let exit_code = err.exit_code();
             err.print().ok();
@@ -62,19 +76,70 @@ fn run() -> Result<(), CliError> {
 fn error_log_path() -> PathBuf {
     std::env::var_os("STEPLY_ERROR_LOG")
         .map(PathBuf::from)
-        .unwrap_or_else(|| PathBuf::from("/tmp/steply-errors.log"))
+        .unwrap_or_else(|| std::env::temp_dir().join("steply-errors.log"))
+}
+
+fn recovery_file_path() -> PathBuf {
+    std::env::var_os("STEPLY_RECOVERY_FILE")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| user_cache_dir().join("steply").join("recovery.json"))
+}
+
+/// The current user's cache directory, so answers never land in a shared
+/// temp directory.
+fn user_cache_dir() -> PathBuf {
+    let var = |name| std::env::var_os(name).filter(|value| !value.is_empty());
+    var("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| var("LOCALAPPDATA").map(PathBuf::from))
+        .or_else(|| var("HOME").map(|home| PathBuf::from(home).join(".cache")))
+        .unwrap_or_else(|| {
+            let user = var("USER").or_else(|| var("USERNAME")).unwrap_or_default();
+            std::env::temp_dir().join(format!("steply-{}", user.to_string_lossy()))
+        })
+}
+
+/// Sends `tracing` output to the error log: errors only, unless `STEPLY_LOG`
+/// names a more verbose level such as `debug` or `trace`.
+fn install_error_log() {
+    let level = std::env::var("STEPLY_LOG")
+        .ok()
+        .and_then(|raw| raw.parse::<LevelFilter>().ok())
+        .unwrap_or(LevelFilter::ERROR);
+    let _ = tracing_subscriber::fmt()
+        .with_writer(ErrorLog(error_log_path()))
+        .with_ansi(false)
+        .with_max_level(level)
+        .try_init();
+}
+
+/// Opens the log per event, so runs that log nothing leave no file behind.
+struct ErrorLog(PathBuf);
+
+impl<'a> MakeWriter<'a> for ErrorLog {
+    type Writer = Box<dyn Write + 'a>;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        match OpenOptions::new().create(true).append(true).open(&self.0) {
+            Ok(file) => Box::new(file),
+            Err(_) => Box::new(std::io::sink()),
+        }
+    }
 }
 
 fn install_panic_logging() {
-    let log_path = error_log_path();
+    enable_crash_recovery(recovery_file_path());
     let default_hook = std::panic::take_hook();
     std::panic::set_hook(Box::new(move |info| {
-        log_panic(log_path.as_path(), info);
+        if let Err(err) = write_recovery_snapshot() {
+            tracing::error!(error = %err, "failed to write recovery snapshot");
+        }
+        log_panic(info);
         default_hook(info);
     }));
 }
 
-fn log_panic(path: &Path, info: &PanicHookInfo<'_>) {
+fn log_panic(info: &PanicHookInfo<'_>) {
     let payload = if let Some(message) = info.payload().downcast_ref::<&str>() {
         (*message).to_string()
     } else if let Some(message) = info.payload().downcast_ref::<String>() {
@@ -87,19 +152,7 @@ fn log_panic(path: &Path, info: &PanicHookInfo<'_>) {
         .map(|loc| format!("{}:{}:{}", loc.file(), loc.line(), loc.column()))
         .unwrap_or_else(|| "unknown location".to_string());
     let backtrace = Backtrace::force_capture();
-    let body = format!("panic at {location}: {payload}\nbacktrace:\n{backtrace}");
-    append_error_log(path, "panic", body.as_str());
-}
-
-fn append_error_log(path: &Path, kind: &str, message: &str) {
-    let timestamp = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .map(|duration| duration.as_secs())
-        .unwrap_or(0);
-    let Ok(mut file) = OpenOptions::new().create(true).append(true).open(path) else {
-        return;
-    };
-    let _ = writeln!(file, "[{timestamp}] {kind}: {message}");
+    tracing::error!(%location, %payload, %backtrace, "panic");
 }
 
 fn export_json(invocation: cli::ExportInvocation) -> std::io::Result<()> {
@@ -111,10 +164,45 @@ fn export_json(invocation: cli::ExportInvocation) -> std::io::Result<()> {
         }
     };
 
-    if let Some(parent) = invocation.out_path.parent() {
+    let Some(out_path) = invocation.out_path else {
+        println!("{json}");
+        return Ok(());
+    };
+    if let Some(parent) = out_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(out_path, json)
+}
+
+/// Loads each flow as `run` would, without touching the terminal.
+fn validate_configs(invocation: cli::ValidateInvocation) -> Result<(), CliError> {
+    for path in invocation.config_paths {
+        load_from_yaml_file(path.as_path())
+            .map_err(|err| err.to_string())
+            .and_then(|loaded| {
+                loaded
+                    .into_app_state()
+                    .map_err(|err| format!("app init error: {err}"))
+            })
+            .map_err(|err| CliError::new(1, format!("{}: {err}", path.display())))?;
+        println!("{}: ok", path.display());
+    }
+    Ok(())
+}
+
+fn export_graph(invocation: cli::GraphInvocation) -> std::io::Result<()> {
+    let loaded = load_from_yaml_file(invocation.config_path.as_path())
+        .map_err(|err| std::io::Error::other(format!("yaml config error: {err}")))?;
+    let graph = loaded.flow.to_graph(invocation.format);
+
+    let Some(out_path) = invocation.out_path else {
+        print!("{graph}");
+        return Ok(());
+    };
+    if let Some(parent) = out_path.parent() {
         fs::create_dir_all(parent)?;
     }
-    fs::write(invocation.out_path, json)
+    fs::write(out_path, graph)
 }
 
 struct CliError {
@@ -0,0 +1,115 @@
+//! Render benchmarks for the widgets whose frames grow with their data.
+//!
+//! Run with `cargo bench -p steply-core`; pass names to run only some, e.g.
+//! `cargo bench -p steply-core -- table tree_view`. Each benchmark draws a
+//! whole frame through the renderer, as the runtime does on every change.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use steply_core::core::value::Value;
+use steply_core::state::app::AppState;
+use steply_core::state::flow::Flow;
+use steply_core::state::step::Step;
+use steply_core::terminal::TerminalSize;
+use steply_core::ui::render_view::RenderView;
+use steply_core::ui::renderer::{Renderer, RendererConfig};
+use steply_core::widgets::components::object_editor::ObjectEditor;
+use steply_core::widgets::components::table::Table;
+use steply_core::widgets::components::tree_view::{TreeNode, TreeView};
+use steply_core::widgets::inputs::text::TextInput;
+use steply_core::widgets::node::Node;
+
+type Bench = (&'static str, fn() -> AppState);
+
+const WARM_UP: Duration = Duration::from_millis(200);
+const MEASURE: Duration = Duration::from_secs(1);
+const SIZE: TerminalSize = TerminalSize {
+    width: 120,
+    height: 40,
+};
+
+fn main() {
+    let filters = std::env::args()
+        .skip(1)
+        .filter(|arg| !arg.starts_with("--"))
+        .collect::<Vec<_>>();
+    let benches: [Bench; 3] = [
+        ("table", table_state),
+        ("tree_view", tree_view_state),
+        ("object_editor", object_editor_state),
+    ];
+    for (name, build) in benches {
+        if filters.is_empty() || filters.iter().any(|filter| name.contains(filter.as_str())) {
+            bench_frame(name, &build());
+        }
+    }
+}
+
+fn bench_frame(name: &str, state: &AppState) {
+    let mut renderer = Renderer::new(RendererConfig {
+        chrome_enabled: true,
+    });
+    let mut frame = || {
+        black_box(renderer.render(&RenderView::from_state(state), SIZE));
+    };
+
+    let warm_up = Instant::now();
+    while warm_up.elapsed() < WARM_UP {
+        frame();
+    }
+    let mut iterations = 0u32;
+    let started = Instant::now();
+    while started.elapsed() < MEASURE {
+        frame();
+        iterations += 1;
+    }
+    let per_frame = started.elapsed() / iterations.max(1);
+    println!(
+        "{name:<16} {:>10.1} µs/frame  ({iterations} frames)",
+        per_frame.as_secs_f64() * 1_000_000.0
+    );
+}
+
+fn single_step(node: Node) -> AppState {
+    AppState::new(Flow::new(vec![Step::new("bench", "Bench", vec![node])])).expect("app state")
+}
+
+fn table_state() -> AppState {
+    let table = Table::new("hosts", "Hosts")
+        .column("Name", TextInput::new)
+        .column("Address", TextInput::new)
+        .column("Role", TextInput::new)
+        .with_initial_rows(500);
+    single_step(Node::Component(Box::new(table)))
+}
+
+fn tree_view_state() -> AppState {
+    let mut nodes = Vec::new();
+    for dir in 0..100 {
+        nodes.push(TreeNode::new(format!("dir-{dir}"), 0, true).expanded());
+        for file in 0..20 {
+            nodes.push(TreeNode::new(format!("file-{dir}-{file}.rs"), 1, false));
+        }
+    }
+    let tree = TreeView::new("files", "Files", nodes)
+        .with_max_visible(30)
+        .with_indent_guides(true);
+    single_step(Node::Component(Box::new(tree)))
+}
+
+fn object_editor_state() -> AppState {
+    let services = (0..200)
+        .map(|n| {
+            format!(
+                r#""service-{n}": {{"image": "app:{n}", "replicas": {n}, "ports": [80, 443], "env": {{"LOG": "info"}}}}"#
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    let value = Value::from_json(format!("{{{services}}}").as_str()).expect("json value");
+    let editor = ObjectEditor::new("services", "Services")
+        .with_value(value)
+        .with_max_visible(30);
+    single_step(Node::Component(Box::new(editor)))
+}
@@ -1,10 +1,14 @@
 use super::spec::{ConfigSpec, StepSpec, TaskTemplateSpec};
 use super::{LoadedConfig, parse, utils, widgets};
-use crate::config::model::ConditionOperatorDef;
+use crate::config::model::{ConditionOperatorDef, LayoutDef, LayoutDirectionDef, LayoutSizeDef};
 use crate::state::flow::Flow;
 use crate::state::step::{Step, StepCondition, StepNavigation};
 use crate::task::TaskSpec;
+use crate::time::Duration;
+use crate::ui::layout::{Constraint, Direction, LayoutNode};
+use crate::ui::panel::Panel;
 use crate::widgets::node::Node;
+use std::collections::HashSet;
 
 pub(super) fn assemble(spec: ConfigSpec) -> Result<LoadedConfig, String> {
     let mut steps = Vec::<Step>::with_capacity(spec.steps.len());
@@ -17,28 +21,153 @@ pub(super) fn assemble(spec: ConfigSpec) -> Result<LoadedConfig, String> {
         flow: Flow::new(steps),
         task_specs,
         confirm_finish: spec.confirm_finish,
+        breadcrumb: spec.breadcrumb,
+        prefill: spec.prefill,
+        idle_timeout: spec.idle_timeout_ms.map(Duration::from_millis),
+        max_parallel_tasks: spec.max_parallel_tasks,
+        output: spec.output,
+        canvas: spec.canvas,
+        localization: spec.localization,
     })
 }
 
 fn assemble_step(spec: StepSpec) -> Result<Step, String> {
     let mut nodes = Vec::<Node>::with_capacity(spec.widgets.len());
+    let mut field_help = Vec::<(String, String)>::new();
+    let mut aria_labels = Vec::<(String, String)>::new();
     for widget in spec.widgets {
+        if let Some(help) = widgets::widget_help(&widget) {
+            field_help.push((widgets::widget_id(&widget).to_string(), help.to_string()));
+        }
+        if let Some(label) = widgets::widget_aria_label(&widget) {
+            aria_labels.push((widgets::widget_id(&widget).to_string(), label.to_string()));
+        }
         nodes.push(widgets::compile_widget(widget)?);
     }
 
+    let layout = spec
+        .layout
+        .map(|layout| assemble_layout(&layout, &nodes, &mut HashSet::new()))
+        .transpose()
+        .map_err(|err| format!("step '{}' layout: {err}", spec.id))?;
+
     let mut step = Step::new(spec.id, spec.title, nodes);
+    step.review = spec.review;
+    step.layout = layout;
     if let Some(description) = spec.description {
         step = step.with_description(description);
     }
+    if let Some(help) = spec.help {
+        step = step.with_help(help);
+    }
+    for (widget_id, help) in field_help {
+        step = step.with_field_help(widget_id, help);
+    }
+    for (widget_id, label) in aria_labels {
+        step = step.with_aria_label(widget_id, label);
+    }
     if let Some(navigation) = spec.navigation {
         step = step.with_navigation(assemble_navigation(navigation));
     }
     if let Some(when) = spec.when {
         step = step.with_when(assemble_when(&when)?);
     }
+    for task_id in spec.on_submit {
+        step = step.with_submit_hook(task_id);
+    }
+    if let Some(after_ms) = spec.auto_advance_ms {
+        step = step.with_auto_advance(Duration::from_millis(after_ms));
+    }
     Ok(step)
 }
 
+fn assemble_layout<'a>(
+    def: &'a LayoutDef,
+    nodes: &[Node],
+    placed: &mut HashSet<&'a str>,
+) -> Result<LayoutNode, String> {
+    let pane = assemble_pane(def, nodes, placed)?;
+    Ok(match &def.panel {
+        Some(panel) => LayoutNode::panel(
+            Panel {
+                title: panel.title.clone(),
+                padding: panel.padding,
+            },
+            pane,
+        ),
+        None => pane,
+    })
+}
+
+fn assemble_pane<'a>(
+    def: &'a LayoutDef,
+    nodes: &[Node],
+    placed: &mut HashSet<&'a str>,
+) -> Result<LayoutNode, String> {
+    match (def.widgets.is_empty(), def.children.is_empty()) {
+        (false, false) => return Err("a pane takes either widgets or children".to_string()),
+        (true, true) => return Err("a pane needs widgets or children".to_string()),
+        (false, true) => {
+            for id in &def.widgets {
+                if !nodes.iter().any(|node| node.id() == id) {
+                    return Err(format!("unknown widget '{id}'"));
+                }
+                if !placed.insert(id.as_str()) {
+                    return Err(format!("widget '{id}' is placed twice"));
+                }
+            }
+            return Ok(LayoutNode::Widgets(def.widgets.clone()));
+        }
+        (true, false) => {}
+    }
+    let mut children = Vec::with_capacity(def.children.len());
+    for child in &def.children {
+        let constraint = child
+            .size
+            .as_ref()
+            .map(parse_layout_size)
+            .transpose()?
+            .unwrap_or_default();
+        children.push((constraint, assemble_layout(child, nodes, placed)?));
+    }
+    Ok(LayoutNode::Split {
+        direction: match def.direction {
+            LayoutDirectionDef::Row => Direction::Row,
+            LayoutDirectionDef::Column => Direction::Column,
+        },
+        gap: def.gap,
+        children,
+    })
+}
+
+fn parse_layout_size(def: &LayoutSizeDef) -> Result<Constraint, String> {
+    let raw = match def {
+        LayoutSizeDef::Cells(cells) => return Ok(Constraint::Length(*cells)),
+        LayoutSizeDef::Text(raw) => raw.trim(),
+    };
+    let number = |text: &str| {
+        text.trim()
+            .parse::<u16>()
+            .map_err(|_| format!("invalid size '{raw}'"))
+    };
+    if raw == "fill" {
+        return Ok(Constraint::Fill(1));
+    }
+    if let Some(percent) = raw.strip_suffix('%') {
+        let percent = number(percent)?;
+        if percent > 100 {
+            return Err(format!("size '{raw}' is over 100%"));
+        }
+        return Ok(Constraint::Percentage(percent));
+    }
+    match raw.split_once(':') {
+        Some(("min", cells)) => Ok(Constraint::Min(number(cells)?)),
+        Some(("max", cells)) => Ok(Constraint::Max(number(cells)?)),
+        Some(("fill", weight)) => Ok(Constraint::Fill(number(weight)?)),
+        _ => number(raw).map(Constraint::Length),
+    }
+}
+
 fn assemble_navigation(def: super::model::NavigationDef) -> StepNavigation {
     match def {
         super::model::NavigationDef::Allowed => StepNavigation::Allowed,
@@ -140,6 +269,9 @@ fn assemble_task(def: TaskTemplateSpec) -> Result<TaskSpec, String> {
     let mut spec = TaskSpec::exec(def.id, def.program, def.args)
         .with_triggers(def.triggers)
         .with_enabled(def.enabled)
+        .with_depends_on(def.depends_on)
+        .with_retry_policy(def.retry)
+        .with_failure_policy(def.failure)
         .with_writes(widgets::compile_task_writes(def.writes)?);
     if let Some(reads) = def.reads {
         spec = spec.with_reads(super::binding_compile::compile_read_binding_value(
@@ -149,5 +281,8 @@ fn assemble_task(def: TaskTemplateSpec) -> Result<TaskSpec, String> {
     if let Some(timeout_ms) = def.timeout_ms {
         spec = spec.with_timeout_ms(timeout_ms);
     }
+    if let Some(progress) = def.progress {
+        spec = spec.with_progress(progress);
+    }
     Ok(spec)
 }
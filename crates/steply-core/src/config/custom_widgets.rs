@@ -0,0 +1,107 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, OnceLock, RwLock};
+
+use crate::core::value::Value;
+use crate::widgets::node::Node;
+
+use super::model::CustomWidgetDef;
+use super::{utils, widgets};
+
+/// A config widget entry whose `type` was registered with [`register_widget`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CustomWidgetConfig {
+    pub widget_type: String,
+    pub id: String,
+    /// Every key of the entry except `type`, `id`, `when` and the binding keys.
+    pub options: BTreeMap<String, Value>,
+}
+
+impl CustomWidgetConfig {
+    pub fn option(&self, key: &str) -> Option<&Value> {
+        self.options.get(key)
+    }
+}
+
+pub type WidgetFactory = Arc<dyn Fn(&CustomWidgetConfig) -> Result<Node, String> + Send + Sync>;
+
+static CUSTOM_WIDGETS: OnceLock<RwLock<HashMap<String, WidgetFactory>>> = OnceLock::new();
+
+fn custom_widgets() -> &'static RwLock<HashMap<String, WidgetFactory>> {
+    CUSTOM_WIDGETS.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+/// Makes `widget_type` usable in flow configs loaded afterwards.
+///
+/// The factory builds the widget from its config entry and must give it the
+/// entry's id. Registering a type again replaces its factory; built-in type
+/// names cannot be taken over.
+pub fn register_widget(
+    widget_type: impl Into<String>,
+    factory: impl Fn(&CustomWidgetConfig) -> Result<Node, String> + Send + Sync + 'static,
+) -> Result<(), String> {
+    let widget_type = widget_type.into();
+    if widget_type.trim().is_empty() {
+        return Err("custom widget type must not be empty".to_string());
+    }
+    if widgets::is_builtin_widget_type(widget_type.as_str()) {
+        return Err(format!("'{widget_type}' is a built-in widget type"));
+    }
+    custom_widgets()
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .insert(widget_type, Arc::new(factory));
+    Ok(())
+}
+
+pub fn is_widget_registered(widget_type: &str) -> bool {
+    custom_widgets()
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .contains_key(widget_type)
+}
+
+pub(super) fn compile_custom_widget(def: CustomWidgetDef) -> Result<Node, String> {
+    let factory = custom_widgets()
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .get(def.widget_type.as_str())
+        .cloned()
+        .ok_or_else(|| unknown_widget_type(&def))?;
+    let mut options = BTreeMap::new();
+    for (key, value) in &def.options {
+        let value = utils::yaml_value_to_value(value)
+            .map_err(|err| format!("widget '{}' option '{key}': {err}", def.id))?;
+        options.insert(key.clone(), value);
+    }
+    let config = CustomWidgetConfig {
+        widget_type: def.widget_type,
+        id: def.id,
+        options,
+    };
+    let node = factory(&config)
+        .map_err(|err| format!("widget '{}' ({}): {err}", config.id, config.widget_type))?;
+    if node.id() != config.id {
+        return Err(format!(
+            "widget factory for '{}' returned id '{}' instead of '{}'",
+            config.widget_type,
+            node.id(),
+            config.id
+        ));
+    }
+    Ok(node)
+}
+
+pub(super) fn validate_custom_widget(def: &CustomWidgetDef) -> Result<(), String> {
+    if is_widget_registered(def.widget_type.as_str()) {
+        Ok(())
+    } else {
+        Err(unknown_widget_type(def))
+    }
+}
+
+fn unknown_widget_type(def: &CustomWidgetDef) -> String {
+    format!(
+        "unknown widget type '{}' for widget '{}'",
+        def.widget_type, def.id
+    )
+}
@@ -82,6 +82,12 @@ pub fn yaml_value_schema(
     <serde_json::Value as JsonSchema>::json_schema(generator)
 }
 
+pub fn yaml_values_schema(
+    generator: &mut schemars::r#gen::SchemaGenerator,
+) -> schemars::schema::Schema {
+    <Vec<serde_json::Value> as JsonSchema>::json_schema(generator)
+}
+
 pub(super) fn build_widget_doc<T: JsonSchema>(
     descriptor: WidgetDocDescriptor,
 ) -> Result<WidgetDoc, String> {
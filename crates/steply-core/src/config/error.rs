@@ -9,6 +9,7 @@ pub enum ConfigLoadError {
         source: std::io::Error,
     },
     ParseYaml(serde_yaml::Error),
+    Interpolate(String),
     Normalize(String),
     Validate(String),
     Assemble(String),
@@ -21,6 +22,9 @@ impl fmt::Display for ConfigLoadError {
                 write!(f, "failed to read yaml config {}: {source}", path.display())
             }
             Self::ParseYaml(source) => write!(f, "failed to parse yaml config: {source}"),
+            Self::Interpolate(message) => {
+                write!(f, "failed to resolve environment in yaml config: {message}")
+            }
             Self::Normalize(message) => write!(f, "failed to normalize yaml config: {message}"),
             Self::Validate(message) => write!(f, "invalid yaml config: {message}"),
             Self::Assemble(message) => write!(f, "failed to assemble yaml config: {message}"),
@@ -33,7 +37,9 @@ impl Error for ConfigLoadError {
         match self {
             Self::ReadFile { source, .. } => Some(source),
             Self::ParseYaml(source) => Some(source),
-            Self::Normalize(_) | Self::Validate(_) | Self::Assemble(_) => None,
+            Self::Interpolate(_) | Self::Normalize(_) | Self::Validate(_) | Self::Assemble(_) => {
+                None
+            }
         }
     }
 }
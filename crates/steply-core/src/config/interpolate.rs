@@ -0,0 +1,128 @@
+use serde_yaml::Value as YamlValue;
+
+/// Keys whose string values may reference the environment. Commands and
+/// templates are left alone so shell `${VAR}` still reaches the shell.
+const INTERPOLATED_KEYS: &[&str] = &[
+    "title",
+    "prompt",
+    "description",
+    "help",
+    "aria_label",
+    "label",
+    "yes_label",
+    "no_label",
+    "default",
+    "path",
+    "cwd",
+    "previous_run",
+];
+
+/// Resolves `${NAME}` and `${NAME:-fallback}` in the labels, defaults and
+/// paths of a raw config document. `$$` stands for a literal `$`.
+pub(super) fn interpolate_env(
+    doc: &mut YamlValue,
+    lookup: &dyn Fn(&str) -> Option<String>,
+) -> Result<(), String> {
+    match doc {
+        YamlValue::Mapping(mapping) => {
+            for (key, value) in mapping.iter_mut() {
+                if key
+                    .as_str()
+                    .is_some_and(|key| INTERPOLATED_KEYS.contains(&key))
+                {
+                    interpolate_strings(value, lookup)?;
+                } else {
+                    interpolate_env(value, lookup)?;
+                }
+            }
+            Ok(())
+        }
+        YamlValue::Sequence(items) => items
+            .iter_mut()
+            .try_for_each(|item| interpolate_env(item, lookup)),
+        YamlValue::Tagged(tagged) => interpolate_env(&mut tagged.value, lookup),
+        _ => Ok(()),
+    }
+}
+
+/// Interpolates every string below an interpolated key, so list and
+/// object defaults are covered too.
+fn interpolate_strings(
+    value: &mut YamlValue,
+    lookup: &dyn Fn(&str) -> Option<String>,
+) -> Result<(), String> {
+    match value {
+        YamlValue::String(text) => {
+            *text = interpolate(text.as_str(), lookup)?;
+            Ok(())
+        }
+        YamlValue::Mapping(mapping) => mapping
+            .iter_mut()
+            .try_for_each(|(_, value)| interpolate_strings(value, lookup)),
+        YamlValue::Sequence(items) => items
+            .iter_mut()
+            .try_for_each(|item| interpolate_strings(item, lookup)),
+        YamlValue::Tagged(tagged) => interpolate_strings(&mut tagged.value, lookup),
+        _ => Ok(()),
+    }
+}
+
+pub(super) fn interpolate(
+    raw: &str,
+    lookup: &dyn Fn(&str) -> Option<String>,
+) -> Result<String, String> {
+    let mut out = String::with_capacity(raw.len());
+    let mut rest = raw;
+    while let Some(pos) = rest.find('$') {
+        out.push_str(&rest[..pos]);
+        let after = &rest[pos + 1..];
+        if let Some(tail) = after.strip_prefix('$') {
+            out.push('$');
+            rest = tail;
+            continue;
+        }
+        let Some(body) = after.strip_prefix('{') else {
+            out.push('$');
+            rest = after;
+            continue;
+        };
+        let Some(end) = body.find('}') else {
+            return Err(format!("unterminated '${{' in '{raw}'"));
+        };
+        let (name, fallback) = match body[..end].split_once(":-") {
+            Some((name, fallback)) => (name, Some(fallback)),
+            None => (&body[..end], None),
+        };
+        if !is_env_name(name) {
+            return Err(format!(
+                "invalid environment variable name '{name}' in '{raw}'"
+            ));
+        }
+        let value = lookup(name);
+        match fallback {
+            Some(fallback) => match value.filter(|value| !value.is_empty()) {
+                Some(value) => out.push_str(value.as_str()),
+                None => out.push_str(fallback),
+            },
+            None => {
+                let value = value.ok_or_else(|| {
+                    format!(
+                        "environment variable '{name}' is not set (use '${{{name}:-}}' to allow it)"
+                    )
+                })?;
+                out.push_str(value.as_str());
+            }
+        }
+        rest = &body[end + 1..];
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+fn is_env_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|first| first == '_' || first.is_ascii_alphabetic())
+        && chars.all(|ch| ch == '_' || ch.is_ascii_alphanumeric())
+}
@@ -1,30 +1,49 @@
 mod assemble;
 mod binding_compile;
+mod custom_widgets;
 mod doc_model;
 mod error;
+mod interpolate;
 mod model;
 mod normalize;
 mod parse;
 mod spec;
+mod subflow;
 mod utils;
 mod validate;
 mod widgets;
 
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::Path;
 
 use model::ConfigDoc;
 use schemars::schema_for;
 
+use crate::core::i18n::Localization;
 use crate::state::app::{AppState, AppStateInitError};
+use crate::state::export::ResultExport;
 use crate::state::flow::Flow;
+use crate::state::prefill::Prefill;
 use crate::task::TaskSpec;
+use crate::time::Duration;
+use crate::ui::canvas::Canvas;
 
+pub use custom_widgets::{
+    CustomWidgetConfig, WidgetFactory, is_widget_registered, register_widget,
+};
 pub use error::ConfigLoadError;
 pub struct LoadedConfig {
     pub flow: Flow,
     pub task_specs: Vec<TaskSpec>,
     pub confirm_finish: bool,
+    pub breadcrumb: bool,
+    pub prefill: Prefill,
+    pub idle_timeout: Option<Duration>,
+    pub max_parallel_tasks: Option<usize>,
+    pub output: Option<ResultExport>,
+    pub canvas: Canvas,
+    pub localization: Localization,
 }
 
 pub use doc_model::{
@@ -35,6 +54,13 @@ impl LoadedConfig {
     pub fn into_app_state(self) -> Result<AppState, AppStateInitError> {
         let mut state = AppState::with_tasks(self.flow, self.task_specs)?;
         state.set_confirm_finish(self.confirm_finish);
+        state.set_breadcrumb_visible(self.breadcrumb);
+        state.set_idle_timeout(self.idle_timeout);
+        state.set_max_parallel_tasks(self.max_parallel_tasks);
+        state.set_result_export(self.output);
+        state.set_canvas(self.canvas);
+        state.set_localization(self.localization);
+        state.apply_prefill(&self.prefill)?;
         Ok(state)
     }
 }
@@ -47,8 +73,28 @@ pub fn load_from_yaml_file(path: &Path) -> Result<LoadedConfig, ConfigLoadError>
     load_from_yaml_str(raw.as_str())
 }
 
+/// Parses a JSON or YAML document of values keyed by store selector, as
+/// used for answers files.
+pub fn parse_values_document(raw: &str) -> Result<crate::core::value::Value, String> {
+    let value: serde_yaml::Value = serde_yaml::from_str(raw).map_err(|err| err.to_string())?;
+    utils::yaml_value_to_value(&value)
+}
+
 pub fn load_from_yaml_str(raw: &str) -> Result<LoadedConfig, ConfigLoadError> {
-    let value: serde_yaml::Value = serde_yaml::from_str(raw).map_err(ConfigLoadError::ParseYaml)?;
+    load_from_yaml_str_with_env(raw, |name| std::env::var(name).ok())
+}
+
+/// Like [`load_from_yaml_str`], resolving `${NAME}` references in labels,
+/// defaults and paths through `env` instead of the process environment.
+pub fn load_from_yaml_str_with_env(
+    raw: &str,
+    env: impl Fn(&str) -> Option<String>,
+) -> Result<LoadedConfig, ConfigLoadError> {
+    let mut value: serde_yaml::Value =
+        serde_yaml::from_str(raw).map_err(ConfigLoadError::ParseYaml)?;
+    let dotenv = load_dotenv(&value)?;
+    let lookup = |name: &str| env(name).or_else(|| dotenv.get(name).cloned());
+    interpolate::interpolate_env(&mut value, &lookup).map_err(ConfigLoadError::Interpolate)?;
     if value.as_mapping().is_some_and(|mapping| {
         mapping.contains_key(serde_yaml::Value::String("subscriptions".to_string()))
     }) {
@@ -60,7 +106,21 @@ pub fn load_from_yaml_str(raw: &str) -> Result<LoadedConfig, ConfigLoadError> {
     let doc: ConfigDoc = serde_yaml::from_value(value).map_err(ConfigLoadError::ParseYaml)?;
     let spec = normalize::normalize(doc).map_err(ConfigLoadError::Normalize)?;
     validate::validate(&spec).map_err(ConfigLoadError::Validate)?;
-    assemble::assemble(spec).map_err(ConfigLoadError::Assemble)
+    let mut loaded = assemble::assemble(spec).map_err(ConfigLoadError::Assemble)?;
+    loaded.prefill.dotenv = dotenv;
+    Ok(loaded)
+}
+
+/// Reads the `prefill.dotenv` files up front, so their variables can feed
+/// `${NAME}` interpolation in the rest of the document.
+fn load_dotenv(doc: &serde_yaml::Value) -> Result<BTreeMap<String, String>, ConfigLoadError> {
+    let Some(paths) = doc.get("prefill").and_then(|prefill| prefill.get("dotenv")) else {
+        return Ok(BTreeMap::new());
+    };
+    let paths: Vec<String> = serde_yaml::from_value(paths.clone())
+        .map_err(|err| ConfigLoadError::Normalize(format!("prefill.dotenv: {err}")))?;
+    crate::core::dotenv::load_files(paths.as_slice())
+        .map_err(|err| ConfigLoadError::Normalize(format!("prefill.dotenv: {err}")))
 }
 
 pub fn config_schema_json() -> Result<String, String> {
@@ -1,5 +1,5 @@
 use schemars::JsonSchema;
-use serde::Deserialize;
+use serde::{Deserialize, Deserializer};
 use std::collections::BTreeMap;
 
 #[derive(Debug, Deserialize, JsonSchema)]
@@ -9,11 +9,99 @@ pub(super) struct ConfigDoc {
     #[serde(default)]
     pub(super) confirm_finish: Option<bool>,
     #[serde(default)]
+    pub(super) breadcrumb: Option<bool>,
+    #[serde(default)]
+    pub(super) prefill: Option<PrefillDef>,
+    #[serde(default)]
+    pub(super) idle_timeout_ms: Option<u64>,
+    #[serde(default)]
+    pub(super) output: Option<OutputDef>,
+    /// Width, alignment and padding of the form within the terminal.
+    #[serde(default)]
+    pub(super) canvas: Option<CanvasDef>,
+    /// Locale selected when the runtime does not pick one, e.g. `de` or `pt-BR`.
+    #[serde(default)]
+    pub(super) locale: Option<String>,
+    /// Translations keyed by locale, then by the source text or message id.
+    #[serde(default)]
+    pub(super) messages: BTreeMap<String, BTreeMap<String, String>>,
+    #[serde(default)]
     pub(super) steps: Vec<StepDef>,
     #[serde(default)]
     pub(super) flow: Vec<FlowItemDef>,
     #[serde(default)]
+    pub(super) subflows: Vec<SubflowDef>,
+    #[serde(default)]
     pub(super) tasks: Vec<TaskDef>,
+    /// Most task runs in flight at once; further runs wait for a free slot.
+    #[serde(default)]
+    pub(super) max_parallel_tasks: Option<usize>,
+}
+
+#[derive(Debug, Deserialize, Default, JsonSchema)]
+pub(super) struct PrefillDef {
+    /// JSON file with answers from a previous run; rewritten when the flow finishes.
+    #[serde(default)]
+    pub(super) previous_run: Option<String>,
+    /// `.env` files loaded in order, later ones overriding earlier ones.
+    /// Their variables land under the `env` store root, answer `env` prefill
+    /// lookups and `${NAME}` references the process environment leaves unset.
+    #[serde(default)]
+    pub(super) dotenv: Vec<String>,
+    /// Literal values keyed by store selector.
+    #[serde(default)]
+    #[schemars(schema_with = "super::doc_model::yaml_value_schema")]
+    pub(super) values: Option<serde_yaml::Value>,
+    /// Environment variable names keyed by store selector.
+    #[serde(default)]
+    pub(super) env: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Deserialize, Default, JsonSchema)]
+pub(super) struct OutputDef {
+    /// Serialization of the collected values; ignored when `template` is set.
+    #[serde(default)]
+    pub(super) format: Option<OutputFormatDef>,
+    /// File to write once the flow finishes; omit or use `-` for stdout.
+    #[serde(default)]
+    pub(super) path: Option<String>,
+    /// Template rendered against the store and written instead of the values.
+    #[serde(default)]
+    pub(super) template: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, JsonSchema, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub(super) enum OutputFormatDef {
+    #[default]
+    Json,
+    Yaml,
+    Toml,
+    /// Report of each step's answers and the tasks that ran.
+    Markdown,
+    Html,
+}
+
+#[derive(Debug, Deserialize, Default, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(super) struct CanvasDef {
+    /// Widest the form gets, in columns; wider terminals leave the rest empty.
+    #[serde(default)]
+    pub(super) max_width: Option<u16>,
+    /// Where the form sits when the terminal is wider than `max_width`.
+    #[serde(default)]
+    pub(super) align: CanvasAlignDef,
+    /// Columns kept free on the left of the form.
+    #[serde(default)]
+    pub(super) padding: u16,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, JsonSchema, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub(super) enum CanvasAlignDef {
+    #[default]
+    Left,
+    Center,
 }
 
 #[derive(Debug, Deserialize, Clone, JsonSchema)]
@@ -23,14 +111,86 @@ pub(super) struct StepDef {
     pub(super) title: String,
     #[serde(default)]
     pub(super) description: Option<String>,
+    /// Explanation shown under the focused field when the field has none.
+    #[serde(default)]
+    pub(super) help: Option<String>,
     #[serde(default)]
     pub(super) when: Option<WhenDef>,
     #[serde(default)]
     pub(super) navigation: Option<NavigationDef>,
     #[serde(default)]
+    pub(super) on_submit: Vec<String>,
+    /// Submit the step automatically after this many milliseconds without input.
+    #[serde(default)]
+    pub(super) auto_advance_ms: Option<u64>,
+    /// Generate a summary of the preceding steps with edit buttons and a confirm button.
+    #[serde(default)]
+    pub(super) review: bool,
+    /// Places widgets side by side or in sized stacks; widgets it does not
+    /// name are drawn below it.
+    #[serde(default)]
+    pub(super) layout: Option<LayoutDef>,
+    #[serde(default)]
     pub(super) widgets: Vec<WidgetDef>,
 }
 
+/// A pane of a step layout: either `widgets` drawn top to bottom, or
+/// `children` split along `direction`.
+#[derive(Debug, Deserialize, Clone, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(super) struct LayoutDef {
+    /// Size along the parent's direction: cells (`30`), a percentage
+    /// (`"40%"`), `"min:20"`, `"max:60"`, or `"fill"`/`"fill:2"` (default).
+    #[serde(default)]
+    pub(super) size: Option<LayoutSizeDef>,
+    #[serde(default)]
+    pub(super) direction: LayoutDirectionDef,
+    /// Cells between children.
+    #[serde(default = "default_layout_gap")]
+    pub(super) gap: u16,
+    #[serde(default)]
+    pub(super) widgets: Vec<String>,
+    #[serde(default)]
+    pub(super) children: Vec<LayoutDef>,
+    /// Draws a border around the pane.
+    #[serde(default)]
+    pub(super) panel: Option<PanelDef>,
+}
+
+fn default_layout_gap() -> u16 {
+    1
+}
+
+#[derive(Debug, Deserialize, Clone, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(super) struct PanelDef {
+    /// Shown in the top border.
+    #[serde(default)]
+    pub(super) title: Option<String>,
+    /// Blank cells between the border and the content on either side.
+    #[serde(default = "default_panel_padding")]
+    pub(super) padding: u16,
+}
+
+fn default_panel_padding() -> u16 {
+    1
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub(super) enum LayoutSizeDef {
+    Cells(u16),
+    Text(String),
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, JsonSchema, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub(super) enum LayoutDirectionDef {
+    #[default]
+    Row,
+    Column,
+}
+
 #[derive(Debug, Deserialize, Clone, JsonSchema)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub(super) enum NavigationDef {
@@ -42,11 +202,23 @@ pub(super) enum NavigationDef {
 
 #[derive(Debug, Deserialize, Clone, JsonSchema)]
 pub(super) struct FlowItemDef {
-    pub(super) step: String,
+    #[serde(default)]
+    pub(super) step: Option<String>,
+    #[serde(default)]
+    pub(super) subflow: Option<String>,
+    #[serde(default)]
+    pub(super) namespace: Option<String>,
     #[serde(default)]
     pub(super) when: Option<WhenDef>,
 }
 
+#[derive(Debug, Deserialize, Clone, JsonSchema)]
+pub(super) struct SubflowDef {
+    pub(super) id: String,
+    #[serde(default)]
+    pub(super) steps: Vec<StepDef>,
+}
+
 #[derive(Debug, Deserialize, JsonSchema)]
 pub(super) struct TaskDef {
     pub(super) id: String,
@@ -65,6 +237,56 @@ pub(super) struct TaskDef {
     pub(super) triggers: Vec<TaskTriggerDef>,
     #[serde(default)]
     pub(super) writes: Option<WriteBindingDef>,
+    /// Store selector that receives progress the task reports while running.
+    #[serde(default)]
+    pub(super) progress: Option<String>,
+    /// Tasks that must succeed first; they are run when this one is requested.
+    #[serde(default)]
+    pub(super) depends_on: Vec<String>,
+    /// Runs a failed task again before its failure counts.
+    #[serde(default)]
+    pub(super) retry: Option<TaskRetryDef>,
+    /// What happens once the last attempt has failed.
+    #[serde(default)]
+    pub(super) on_failure: TaskFailureDef,
+}
+
+#[derive(Debug, Deserialize, Clone, JsonSchema)]
+pub(super) struct TaskRetryDef {
+    /// Runs in total, the first one included.
+    pub(super) max_attempts: u32,
+    /// Wait before the next attempt.
+    #[serde(default)]
+    pub(super) delay_ms: u64,
+    #[serde(default)]
+    pub(super) backoff: TaskBackoffDef,
+    /// Longest wait with exponential backoff.
+    #[serde(default)]
+    pub(super) max_delay_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, JsonSchema, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub(super) enum TaskBackoffDef {
+    /// The same wait before every attempt.
+    #[default]
+    Fixed,
+    /// The wait doubles after every failed attempt.
+    Exponential,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, JsonSchema, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub(super) enum TaskFailureDef {
+    /// Show the error and block tasks that depend on this one.
+    #[default]
+    Report,
+    /// Cancel the flow.
+    Abort,
+    /// Go on as if the task had succeeded.
+    Skip,
+    /// Ask whether to retry, skip or abort.
+    Prompt,
 }
 
 #[derive(Debug, Deserialize, Clone, JsonSchema)]
@@ -95,6 +317,7 @@ pub(super) enum TaskTriggerDef {
         #[serde(default)]
         only_when_step_active: bool,
     },
+    IdleTimeout,
 }
 
 #[derive(Debug, Deserialize, Clone, JsonSchema)]
@@ -151,6 +374,20 @@ pub(super) struct WidgetBindingDef {
     /// When the widget value should be committed to the store.
     #[serde(default)]
     pub(super) commit_policy: BindingCommitPolicyDef,
+    /// Rewrites applied in order to the value as it is committed.
+    #[serde(default)]
+    pub(super) transform: Vec<TransformDef>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub(super) enum TransformDef {
+    /// Strips leading and trailing whitespace.
+    Trim,
+    Lowercase,
+    Uppercase,
+    /// Drops `.` segments and redundant separators and folds `..`.
+    NormalizePath,
 }
 
 #[derive(Debug, Clone, Copy, Deserialize, JsonSchema, Default, PartialEq, Eq)]
@@ -194,16 +431,19 @@ impl Default for SelectListOptionsDef {
 }
 
 #[derive(Debug, Deserialize, Clone, JsonSchema)]
-#[serde(tag = "type", rename_all = "snake_case")]
+#[serde(tag = "type", rename_all = "snake_case", remote = "Self")]
 pub(super) enum WidgetDef {
     TextOutput(TextOutputDef),
     DataOutput(DataOutputDef),
     UrlOutput(UrlOutputDef),
     ThinkingOutput(ThinkingOutputDef),
     ProgressOutput(ProgressOutputDef),
+    SpinnerOutput(SpinnerOutputDef),
     ChartOutput(ChartOutputDef),
     TableOutput(TableOutputDef),
     DiffOutput(DiffOutputDef),
+    LogOutput(LogOutputDef),
+    TaskGraphOutput(TaskGraphOutputDef),
     TaskLogOutput(TaskLogOutputDef),
     TextInput(TextInputDef),
     ArrayInput(ArrayInputDef),
@@ -214,6 +454,8 @@ pub(super) enum WidgetDef {
     MaskedInput(MaskedInputDef),
     Slider(SliderDef),
     ColorInput(ColorInputDef),
+    DateInput(DateInputDef),
+    DurationInput(DurationInputDef),
     ConfirmInput(ConfirmInputDef),
     Checkbox(CheckboxDef),
     Calendar(CalendarDef),
@@ -225,6 +467,47 @@ pub(super) enum WidgetDef {
     Snippet(SnippetDef),
     Table(TableDef),
     Repeater(RepeaterDef),
+    #[serde(skip)]
+    Custom(CustomWidgetDef),
+}
+
+impl<'de> Deserialize<'de> for WidgetDef {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = serde_yaml::Value::deserialize(deserializer)?;
+        match raw.get("type").and_then(serde_yaml::Value::as_str) {
+            Some(widget_type) if !super::widgets::is_builtin_widget_type(widget_type) => {
+                CustomWidgetDef::deserialize(raw)
+                    .map(Self::Custom)
+                    .map_err(serde::de::Error::custom)
+            }
+            _ => Self::deserialize(raw).map_err(serde::de::Error::custom),
+        }
+    }
+}
+
+/// Widget of a type registered at runtime with `config::register_widget`.
+#[derive(Debug, Clone, Deserialize)]
+pub(super) struct CustomWidgetDef {
+    #[serde(rename = "type")]
+    pub(super) widget_type: String,
+    pub(super) id: String,
+    /// Longer explanation shown under the field while it is focused.
+    #[serde(default)]
+    pub(super) help: Option<String>,
+    /// Name announced for the widget in accessibility mode instead of its
+    /// label.
+    #[serde(default)]
+    pub(super) aria_label: Option<String>,
+    #[serde(default)]
+    pub(super) when: Option<WhenDef>,
+    #[serde(default, flatten)]
+    pub(super) binding: WidgetBindingDef,
+    /// Remaining keys, handed to the widget factory.
+    #[serde(flatten)]
+    pub(super) options: BTreeMap<String, serde_yaml::Value>,
 }
 
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
@@ -233,6 +516,13 @@ pub(super) struct TextOutputDef {
     pub(super) id: String,
     /// Rendered text content.
     pub(super) text: String,
+    /// Longer explanation shown under the field while it is focused.
+    #[serde(default)]
+    pub(super) help: Option<String>,
+    /// Name announced for the widget in accessibility mode instead of its
+    /// label.
+    #[serde(default)]
+    pub(super) aria_label: Option<String>,
     #[serde(default)]
     pub(super) when: Option<WhenDef>,
     #[serde(default, flatten)]
@@ -249,6 +539,13 @@ pub(super) struct DataOutputDef {
     /// Render format for structured values.
     #[serde(default)]
     pub(super) format: Option<DataOutputFormatDef>,
+    /// Longer explanation shown under the field while it is focused.
+    #[serde(default)]
+    pub(super) help: Option<String>,
+    /// Name announced for the widget in accessibility mode instead of its
+    /// label.
+    #[serde(default)]
+    pub(super) aria_label: Option<String>,
     #[serde(default)]
     pub(super) when: Option<WhenDef>,
     #[serde(default, flatten)]
@@ -272,6 +569,13 @@ pub(super) struct UrlOutputDef {
     /// Optional display label.
     #[serde(default)]
     pub(super) name: Option<String>,
+    /// Longer explanation shown under the field while it is focused.
+    #[serde(default)]
+    pub(super) help: Option<String>,
+    /// Name announced for the widget in accessibility mode instead of its
+    /// label.
+    #[serde(default)]
+    pub(super) aria_label: Option<String>,
     #[serde(default)]
     pub(super) when: Option<WhenDef>,
     #[serde(default, flatten)]
@@ -301,6 +605,13 @@ pub(super) struct ThinkingOutputDef {
     /// Peak RGB color for the animation gradient.
     #[serde(default)]
     pub(super) peak_rgb: Option<[u8; 3]>,
+    /// Longer explanation shown under the field while it is focused.
+    #[serde(default)]
+    pub(super) help: Option<String>,
+    /// Name announced for the widget in accessibility mode instead of its
+    /// label.
+    #[serde(default)]
+    pub(super) aria_label: Option<String>,
     #[serde(default)]
     pub(super) when: Option<WhenDef>,
 }
@@ -329,12 +640,47 @@ pub(super) struct ProgressOutputDef {
     /// Transition configuration for value changes.
     #[serde(default)]
     pub(super) transition: Option<ProgressTransitionDef>,
+    /// Task whose reported progress drives the bar.
+    #[serde(default)]
+    pub(super) task: Option<String>,
+    /// Longer explanation shown under the field while it is focused.
+    #[serde(default)]
+    pub(super) help: Option<String>,
+    /// Name announced for the widget in accessibility mode instead of its
+    /// label.
+    #[serde(default)]
+    pub(super) aria_label: Option<String>,
     #[serde(default)]
     pub(super) when: Option<WhenDef>,
     #[serde(default, flatten)]
     pub(super) binding: WidgetBindingDef,
 }
 
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub(super) struct SpinnerOutputDef {
+    /// Unique widget identifier within the step.
+    pub(super) id: String,
+    /// Visible widget label.
+    #[serde(default)]
+    pub(super) label: String,
+    /// Task whose runs start and stop the spinner; without one it always
+    /// spins.
+    #[serde(default)]
+    pub(super) task: Option<String>,
+    /// Spinner animation style.
+    #[serde(default)]
+    pub(super) spinner_style: Option<String>,
+    /// Longer explanation shown under the field while it is focused.
+    #[serde(default)]
+    pub(super) help: Option<String>,
+    /// Name announced for the widget in accessibility mode instead of its
+    /// label.
+    #[serde(default)]
+    pub(super) aria_label: Option<String>,
+    #[serde(default)]
+    pub(super) when: Option<WhenDef>,
+}
+
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub(super) struct ChartOutputDef {
     /// Unique widget identifier within the step.
@@ -359,6 +705,19 @@ pub(super) struct ChartOutputDef {
     /// Enables gradient coloring.
     #[serde(default)]
     pub(super) gradient: Option<bool>,
+    /// Rows taken by the `bars` mode.
+    #[serde(default)]
+    pub(super) height: Option<usize>,
+    /// Labels the bar chart with its range. Defaults to true.
+    #[serde(default)]
+    pub(super) axis: Option<bool>,
+    /// Longer explanation shown under the field while it is focused.
+    #[serde(default)]
+    pub(super) help: Option<String>,
+    /// Name announced for the widget in accessibility mode instead of its
+    /// label.
+    #[serde(default)]
+    pub(super) aria_label: Option<String>,
     #[serde(default)]
     pub(super) when: Option<WhenDef>,
     #[serde(default, flatten)]
@@ -380,6 +739,13 @@ pub(super) struct TableOutputDef {
     /// Table rows.
     #[serde(default)]
     pub(super) rows: Vec<Vec<String>>,
+    /// Longer explanation shown under the field while it is focused.
+    #[serde(default)]
+    pub(super) help: Option<String>,
+    /// Name announced for the widget in accessibility mode instead of its
+    /// label.
+    #[serde(default)]
+    pub(super) aria_label: Option<String>,
     #[serde(default)]
     pub(super) when: Option<WhenDef>,
     #[serde(default, flatten)]
@@ -399,6 +765,83 @@ pub(super) struct DiffOutputDef {
     /// Maximum number of visible diff lines.
     #[serde(default)]
     pub(super) max_visible: Option<usize>,
+    /// Draws a scrollbar instead of the scroll position footer.
+    #[serde(default)]
+    pub(super) scrollbar: bool,
+    /// Starts in the single-column unified layout instead of side by side.
+    #[serde(default)]
+    pub(super) unified: bool,
+    /// Lets the user stage or unstage each hunk; the value is the old text
+    /// with the staged hunks applied.
+    #[serde(default)]
+    pub(super) review: bool,
+    /// Longer explanation shown under the field while it is focused.
+    #[serde(default)]
+    pub(super) help: Option<String>,
+    /// Name announced for the widget in accessibility mode instead of its
+    /// label.
+    #[serde(default)]
+    pub(super) aria_label: Option<String>,
+    #[serde(default)]
+    pub(super) when: Option<WhenDef>,
+    #[serde(default, flatten)]
+    pub(super) binding: WidgetBindingDef,
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub(super) struct LogOutputDef {
+    /// Unique widget identifier within the step.
+    pub(super) id: String,
+    /// Visible widget label.
+    #[serde(default)]
+    pub(super) label: String,
+    /// Tasks whose output lines are appended.
+    #[serde(default)]
+    pub(super) tasks: Vec<String>,
+    /// Maximum number of rendered log lines.
+    #[serde(default)]
+    pub(super) visible_lines: Option<usize>,
+    /// Lines kept for scrolling back; older ones are dropped.
+    #[serde(default)]
+    pub(super) history_lines: Option<usize>,
+    /// Keeps the newest line in view as output arrives. Defaults to true.
+    #[serde(default)]
+    pub(super) follow: Option<bool>,
+    /// Glides between scroll positions instead of jumping.
+    #[serde(default)]
+    pub(super) animate: bool,
+    /// Longer explanation shown under the field while it is focused.
+    #[serde(default)]
+    pub(super) help: Option<String>,
+    /// Name announced for the widget in accessibility mode instead of its
+    /// label.
+    #[serde(default)]
+    pub(super) aria_label: Option<String>,
+    #[serde(default)]
+    pub(super) when: Option<WhenDef>,
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub(super) struct TaskGraphOutputDef {
+    /// Unique widget identifier within the step.
+    pub(super) id: String,
+    /// Line shown above the graph.
+    #[serde(default)]
+    pub(super) label: Option<String>,
+    /// Task ids to show, in order; defaults to every task that depends on
+    /// or is depended on by another.
+    #[serde(default)]
+    pub(super) tasks: Vec<String>,
+    /// Spinner style used while tasks are running.
+    #[serde(default)]
+    pub(super) spinner_style: Option<String>,
+    /// Longer explanation shown under the field while it is focused.
+    #[serde(default)]
+    pub(super) help: Option<String>,
+    /// Name announced for the widget in accessibility mode instead of its
+    /// label.
+    #[serde(default)]
+    pub(super) aria_label: Option<String>,
     #[serde(default)]
     pub(super) when: Option<WhenDef>,
 }
@@ -415,6 +858,16 @@ pub(super) struct TaskLogOutputDef {
     pub(super) spinner_style: Option<String>,
     /// Task log steps with label and task id.
     pub(super) steps: Vec<TaskLogStepDef>,
+    /// Draws a scrollbar instead of the scroll position footer.
+    #[serde(default)]
+    pub(super) scrollbar: bool,
+    /// Longer explanation shown under the field while it is focused.
+    #[serde(default)]
+    pub(super) help: Option<String>,
+    /// Name announced for the widget in accessibility mode instead of its
+    /// label.
+    #[serde(default)]
+    pub(super) aria_label: Option<String>,
     #[serde(default)]
     pub(super) when: Option<WhenDef>,
 }
@@ -443,12 +896,44 @@ pub(super) struct TextInputDef {
     /// Static completion candidates.
     #[serde(default)]
     pub(super) completion_items: Vec<String>,
+    /// Secret store entry the value defaults from and is saved to, instead
+    /// of appearing in exported results.
+    #[serde(default)]
+    pub(super) secret: Option<SecretDef>,
+    /// Longer explanation shown under the field while it is focused.
+    #[serde(default)]
+    pub(super) help: Option<String>,
+    /// Name announced for the widget in accessibility mode instead of its
+    /// label.
+    #[serde(default)]
+    pub(super) aria_label: Option<String>,
     #[serde(default)]
     pub(super) when: Option<WhenDef>,
     #[serde(default, flatten)]
     pub(super) binding: WidgetBindingDef,
 }
 
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub(super) struct SecretDef {
+    /// Store holding the secret.
+    pub(super) backend: SecretBackendDef,
+    /// Service the secret is filed under (the folder in `pass`).
+    pub(super) service: String,
+    /// Account within the service.
+    pub(super) account: String,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub(super) enum SecretBackendDef {
+    /// macOS Keychain or the Secret Service (`secret-tool`).
+    Keychain,
+    /// The `pass` password store.
+    Pass,
+    /// An environment variable named `SERVICE_ACCOUNT`; read-only.
+    Env,
+}
+
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub(super) struct ArrayInputDef {
     /// Unique widget identifier within the step.
@@ -464,6 +949,13 @@ pub(super) struct ArrayInputDef {
     /// Validation rules applied to the value.
     #[serde(default)]
     pub(super) validators: Vec<ValidatorDef>,
+    /// Longer explanation shown under the field while it is focused.
+    #[serde(default)]
+    pub(super) help: Option<String>,
+    /// Name announced for the widget in accessibility mode instead of its
+    /// label.
+    #[serde(default)]
+    pub(super) aria_label: Option<String>,
     #[serde(default)]
     pub(super) when: Option<WhenDef>,
     #[serde(default, flatten)]
@@ -482,6 +974,13 @@ pub(super) struct ButtonInputDef {
     /// Optional task executed when activated.
     #[serde(default)]
     pub(super) task_id: Option<String>,
+    /// Longer explanation shown under the field while it is focused.
+    #[serde(default)]
+    pub(super) help: Option<String>,
+    /// Name announced for the widget in accessibility mode instead of its
+    /// label.
+    #[serde(default)]
+    pub(super) aria_label: Option<String>,
     #[serde(default)]
     pub(super) when: Option<WhenDef>,
 }
@@ -506,6 +1005,13 @@ pub(super) struct SelectDef {
     /// Validation rules applied to the value.
     #[serde(default)]
     pub(super) validators: Vec<ValidatorDef>,
+    /// Longer explanation shown under the field while it is focused.
+    #[serde(default)]
+    pub(super) help: Option<String>,
+    /// Name announced for the widget in accessibility mode instead of its
+    /// label.
+    #[serde(default)]
+    pub(super) aria_label: Option<String>,
     #[serde(default)]
     pub(super) when: Option<WhenDef>,
     #[serde(default, flatten)]
@@ -532,6 +1038,13 @@ pub(super) struct ChoiceInputDef {
     /// Validation rules applied to the value.
     #[serde(default)]
     pub(super) validators: Vec<ValidatorDef>,
+    /// Longer explanation shown under the field while it is focused.
+    #[serde(default)]
+    pub(super) help: Option<String>,
+    /// Name announced for the widget in accessibility mode instead of its
+    /// label.
+    #[serde(default)]
+    pub(super) aria_label: Option<String>,
     #[serde(default)]
     pub(super) when: Option<WhenDef>,
     #[serde(default, flatten)]
@@ -559,6 +1072,19 @@ pub(super) struct SelectListDef {
     /// Whether to render the label.
     #[serde(default)]
     pub(super) show_label: Option<bool>,
+    /// Draws a scrollbar instead of the scroll position footer.
+    #[serde(default)]
+    pub(super) scrollbar: bool,
+    /// Most options a multi-select list accepts; more fail validation.
+    #[serde(default)]
+    pub(super) max_selected: Option<usize>,
+    /// Longer explanation shown under the field while it is focused.
+    #[serde(default)]
+    pub(super) help: Option<String>,
+    /// Name announced for the widget in accessibility mode instead of its
+    /// label.
+    #[serde(default)]
+    pub(super) aria_label: Option<String>,
     #[serde(default)]
     pub(super) when: Option<WhenDef>,
     #[serde(default, flatten)]
@@ -582,6 +1108,13 @@ pub(super) struct MaskedInputDef {
     /// Validation rules applied to the value.
     #[serde(default)]
     pub(super) validators: Vec<ValidatorDef>,
+    /// Longer explanation shown under the field while it is focused.
+    #[serde(default)]
+    pub(super) help: Option<String>,
+    /// Name announced for the widget in accessibility mode instead of its
+    /// label.
+    #[serde(default)]
+    pub(super) aria_label: Option<String>,
     #[serde(default)]
     pub(super) when: Option<WhenDef>,
     #[serde(default, flatten)]
@@ -616,6 +1149,13 @@ pub(super) struct SliderDef {
     /// Validation rules applied to the value.
     #[serde(default)]
     pub(super) validators: Vec<ValidatorDef>,
+    /// Longer explanation shown under the field while it is focused.
+    #[serde(default)]
+    pub(super) help: Option<String>,
+    /// Name announced for the widget in accessibility mode instead of its
+    /// label.
+    #[serde(default)]
+    pub(super) aria_label: Option<String>,
     #[serde(default)]
     pub(super) when: Option<WhenDef>,
     #[serde(default, flatten)]
@@ -637,6 +1177,13 @@ pub(super) struct ColorInputDef {
     /// Validation rules applied to the value.
     #[serde(default)]
     pub(super) validators: Vec<ValidatorDef>,
+    /// Longer explanation shown under the field while it is focused.
+    #[serde(default)]
+    pub(super) help: Option<String>,
+    /// Name announced for the widget in accessibility mode instead of its
+    /// label.
+    #[serde(default)]
+    pub(super) aria_label: Option<String>,
     #[serde(default)]
     pub(super) when: Option<WhenDef>,
     #[serde(default, flatten)]
@@ -661,6 +1208,13 @@ pub(super) struct ConfirmInputDef {
     /// Initial boolean value.
     #[serde(default)]
     pub(super) default: Option<bool>,
+    /// Longer explanation shown under the field while it is focused.
+    #[serde(default)]
+    pub(super) help: Option<String>,
+    /// Name announced for the widget in accessibility mode instead of its
+    /// label.
+    #[serde(default)]
+    pub(super) aria_label: Option<String>,
     #[serde(default)]
     pub(super) when: Option<WhenDef>,
     #[serde(default, flatten)]
@@ -682,12 +1236,94 @@ pub(super) struct CheckboxDef {
     /// Validation rules applied to the value.
     #[serde(default)]
     pub(super) validators: Vec<ValidatorDef>,
+    /// Longer explanation shown under the field while it is focused.
+    #[serde(default)]
+    pub(super) help: Option<String>,
+    /// Name announced for the widget in accessibility mode instead of its
+    /// label.
+    #[serde(default)]
+    pub(super) aria_label: Option<String>,
     #[serde(default)]
     pub(super) when: Option<WhenDef>,
     #[serde(default, flatten)]
     pub(super) binding: WidgetBindingDef,
 }
 
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub(super) struct DateInputDef {
+    /// Unique widget identifier within the step.
+    pub(super) id: String,
+    /// Visible widget label.
+    pub(super) label: String,
+    /// Initial date as `YYYY-MM-DD`.
+    #[serde(default)]
+    pub(super) default: Option<String>,
+    /// Earliest selectable date as `YYYY-MM-DD`.
+    #[serde(default)]
+    pub(super) min: Option<String>,
+    /// Latest selectable date as `YYYY-MM-DD`.
+    #[serde(default)]
+    pub(super) max: Option<String>,
+    /// Display pattern of `YYYY`, `MM` and `DD` fields, e.g. `DD.MM.YYYY`.
+    #[serde(default)]
+    pub(super) format: Option<String>,
+    /// Locale whose usual date pattern is shown when `format` is not set.
+    #[serde(default)]
+    pub(super) locale: Option<String>,
+    /// Whether the field is required.
+    #[serde(default)]
+    pub(super) required: Option<bool>,
+    /// Validation rules applied to the value.
+    #[serde(default)]
+    pub(super) validators: Vec<ValidatorDef>,
+    /// Longer explanation shown under the field while it is focused.
+    #[serde(default)]
+    pub(super) help: Option<String>,
+    /// Name announced for the widget in accessibility mode instead of its
+    /// label.
+    #[serde(default)]
+    pub(super) aria_label: Option<String>,
+    #[serde(default)]
+    pub(super) when: Option<WhenDef>,
+    #[serde(default, flatten)]
+    pub(super) binding: WidgetBindingDef,
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub(super) struct DurationInputDef {
+    /// Unique widget identifier within the step.
+    pub(super) id: String,
+    /// Visible widget label.
+    pub(super) label: String,
+    /// Initial duration in seconds or as text such as `1h30m`.
+    #[serde(default)]
+    pub(super) default: Option<DurationValueDef>,
+    /// Whether the field is required.
+    #[serde(default)]
+    pub(super) required: Option<bool>,
+    /// Validation rules applied to the value.
+    #[serde(default)]
+    pub(super) validators: Vec<ValidatorDef>,
+    /// Longer explanation shown under the field while it is focused.
+    #[serde(default)]
+    pub(super) help: Option<String>,
+    /// Name announced for the widget in accessibility mode instead of its
+    /// label.
+    #[serde(default)]
+    pub(super) aria_label: Option<String>,
+    #[serde(default)]
+    pub(super) when: Option<WhenDef>,
+    #[serde(default, flatten)]
+    pub(super) binding: WidgetBindingDef,
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub(super) enum DurationValueDef {
+    Seconds(f64),
+    Text(String),
+}
+
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub(super) struct CalendarDef {
     /// Unique widget identifier within the step.
@@ -703,6 +1339,13 @@ pub(super) struct CalendarDef {
     /// Validation rules applied to the value.
     #[serde(default)]
     pub(super) validators: Vec<ValidatorDef>,
+    /// Longer explanation shown under the field while it is focused.
+    #[serde(default)]
+    pub(super) help: Option<String>,
+    /// Name announced for the widget in accessibility mode instead of its
+    /// label.
+    #[serde(default)]
+    pub(super) aria_label: Option<String>,
     #[serde(default)]
     pub(super) when: Option<WhenDef>,
     #[serde(default, flatten)]
@@ -713,6 +1356,9 @@ pub(super) struct CalendarDef {
 pub(super) struct TextareaDef {
     /// Unique widget identifier within the step.
     pub(super) id: String,
+    /// Label drawn above the text.
+    #[serde(default)]
+    pub(super) label: Option<String>,
     /// Minimum visible height.
     #[serde(default)]
     pub(super) min_height: Option<usize>,
@@ -722,12 +1368,28 @@ pub(super) struct TextareaDef {
     /// Initial text value.
     #[serde(default)]
     pub(super) default: Option<String>,
+    /// Wraps long lines onto the following rows.
+    #[serde(default)]
+    pub(super) soft_wrap: Option<bool>,
+    /// Shows line numbers in the gutter (on by default).
+    #[serde(default)]
+    pub(super) line_numbers: Option<bool>,
+    /// Enter starts a new line and Ctrl+Enter submits.
+    #[serde(default)]
+    pub(super) newline_on_enter: Option<bool>,
     /// Whether the field is required.
     #[serde(default)]
     pub(super) required: Option<bool>,
     /// Validation rules applied to the value.
     #[serde(default)]
     pub(super) validators: Vec<ValidatorDef>,
+    /// Longer explanation shown under the field while it is focused.
+    #[serde(default)]
+    pub(super) help: Option<String>,
+    /// Name announced for the widget in accessibility mode instead of its
+    /// label.
+    #[serde(default)]
+    pub(super) aria_label: Option<String>,
     #[serde(default)]
     pub(super) when: Option<WhenDef>,
     #[serde(default, flatten)]
@@ -758,8 +1420,21 @@ pub(super) struct CommandRunnerDef {
     /// Command timeout in milliseconds.
     #[serde(default)]
     pub(super) timeout_ms: Option<u64>,
+    /// Block leaving the step until the commands have succeeded.
+    #[serde(default)]
+    pub(super) require_success: Option<bool>,
+    /// Keep the command output visible after a successful run.
+    #[serde(default)]
+    pub(super) keep_output: Option<bool>,
     /// Commands executed by the runner.
     pub(super) commands: Vec<CommandRunnerCommandDef>,
+    /// Longer explanation shown under the field while it is focused.
+    #[serde(default)]
+    pub(super) help: Option<String>,
+    /// Name announced for the widget in accessibility mode instead of its
+    /// label.
+    #[serde(default)]
+    pub(super) aria_label: Option<String>,
     #[serde(default)]
     pub(super) when: Option<WhenDef>,
     #[serde(default, flatten)]
@@ -799,6 +1474,10 @@ pub(super) struct FileBrowserDef {
     /// Allowed file extensions.
     #[serde(default)]
     pub(super) ext_filter: Vec<String>,
+    /// Whether the open browser can create folders and rename or delete
+    /// entries.
+    #[serde(default)]
+    pub(super) file_ops: Option<bool>,
     /// Maximum number of visible rows.
     #[serde(default)]
     pub(super) max_visible: Option<usize>,
@@ -808,6 +1487,13 @@ pub(super) struct FileBrowserDef {
     /// Validation rules applied to the value.
     #[serde(default)]
     pub(super) validators: Vec<ValidatorDef>,
+    /// Longer explanation shown under the field while it is focused.
+    #[serde(default)]
+    pub(super) help: Option<String>,
+    /// Name announced for the widget in accessibility mode instead of its
+    /// label.
+    #[serde(default)]
+    pub(super) aria_label: Option<String>,
     #[serde(default)]
     pub(super) when: Option<WhenDef>,
     #[serde(default, flatten)]
@@ -831,6 +1517,23 @@ pub(super) struct TreeViewDef {
     /// Whether to render indentation guides.
     #[serde(default)]
     pub(super) indent_guides: Option<bool>,
+    /// Draws a scrollbar instead of the scroll position footer.
+    #[serde(default)]
+    pub(super) scrollbar: bool,
+    /// Rolls expanded children in instead of showing them at once.
+    #[serde(default)]
+    pub(super) animate: bool,
+    /// Puts a checkbox on every node; the value becomes the list of checked
+    /// node paths.
+    #[serde(default)]
+    pub(super) checkboxes: bool,
+    /// Longer explanation shown under the field while it is focused.
+    #[serde(default)]
+    pub(super) help: Option<String>,
+    /// Name announced for the widget in accessibility mode instead of its
+    /// label.
+    #[serde(default)]
+    pub(super) aria_label: Option<String>,
     #[serde(default)]
     pub(super) when: Option<WhenDef>,
     #[serde(default, flatten)]
@@ -850,12 +1553,59 @@ pub(super) struct ObjectEditorDef {
     /// Maximum number of visible rows.
     #[serde(default)]
     pub(super) max_visible: Option<usize>,
+    /// Store selector that `y` writes the copied JSON to, instead of the
+    /// clipboard.
+    #[serde(default)]
+    pub(super) yank_to: Option<String>,
+    /// What the value may hold; inserts suggest its keys and violations
+    /// block submitting.
+    #[serde(default)]
+    pub(super) schema: Option<ObjectSchemaDef>,
+    /// Longer explanation shown under the field while it is focused.
+    #[serde(default)]
+    pub(super) help: Option<String>,
+    /// Name announced for the widget in accessibility mode instead of its
+    /// label.
+    #[serde(default)]
+    pub(super) aria_label: Option<String>,
     #[serde(default)]
     pub(super) when: Option<WhenDef>,
     #[serde(default, flatten)]
     pub(super) binding: WidgetBindingDef,
 }
 
+#[derive(Debug, Clone, Default, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(super) struct ObjectSchemaDef {
+    /// Allowed kinds: `text`, `number`, `bool`, `list`, `object`, `none`,
+    /// or a list of them. Any kind when omitted.
+    #[serde(default, rename = "type")]
+    pub(super) kind: Option<SchemaTypesDef>,
+    /// Values the field is limited to.
+    #[serde(default, rename = "enum")]
+    #[schemars(schema_with = "super::doc_model::yaml_values_schema")]
+    pub(super) choices: Vec<serde_yaml::Value>,
+    /// Keys the object must have.
+    #[serde(default)]
+    pub(super) required: Vec<String>,
+    /// Schemas of the object's keys.
+    #[serde(default)]
+    pub(super) fields: BTreeMap<String, ObjectSchemaDef>,
+    /// Rejects keys that are not listed in `fields` or `required`.
+    #[serde(default)]
+    pub(super) closed: bool,
+    /// Schema of every list item.
+    #[serde(default)]
+    pub(super) items: Option<Box<ObjectSchemaDef>>,
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub(super) enum SchemaTypesDef {
+    One(String),
+    Many(Vec<String>),
+}
+
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub(super) struct SnippetDef {
     /// Unique widget identifier within the step.
@@ -867,6 +1617,13 @@ pub(super) struct SnippetDef {
     /// Nested interactive widget definitions.
     #[serde(default)]
     pub(super) inputs: Vec<WidgetDef>,
+    /// Longer explanation shown under the field while it is focused.
+    #[serde(default)]
+    pub(super) help: Option<String>,
+    /// Name announced for the widget in accessibility mode instead of its
+    /// label.
+    #[serde(default)]
+    pub(super) aria_label: Option<String>,
     #[serde(default)]
     pub(super) when: Option<WhenDef>,
     #[serde(default, flatten)]
@@ -888,8 +1645,18 @@ pub(super) struct TableDef {
     /// Initial number of rows.
     #[serde(default)]
     pub(super) initial_rows: Option<usize>,
+    /// Maximum number of body rows shown at once; the rest scroll.
+    #[serde(default)]
+    pub(super) max_visible_rows: Option<usize>,
     /// Column definitions with embedded widgets.
     pub(super) columns: Vec<TableColumnDef>,
+    /// Longer explanation shown under the field while it is focused.
+    #[serde(default)]
+    pub(super) help: Option<String>,
+    /// Name announced for the widget in accessibility mode instead of its
+    /// label.
+    #[serde(default)]
+    pub(super) aria_label: Option<String>,
     #[serde(default)]
     pub(super) when: Option<WhenDef>,
     #[serde(default, flatten)]
@@ -926,6 +1693,13 @@ pub(super) struct RepeaterDef {
     /// Widgets rendered for the active iteration.
     #[serde(default)]
     pub(super) widgets: Vec<WidgetDef>,
+    /// Longer explanation shown under the field while it is focused.
+    #[serde(default)]
+    pub(super) help: Option<String>,
+    /// Name announced for the widget in accessibility mode instead of its
+    /// label.
+    #[serde(default)]
+    pub(super) aria_label: Option<String>,
     #[serde(default)]
     pub(super) when: Option<WhenDef>,
     #[serde(default, flatten)]
@@ -944,6 +1718,10 @@ pub(super) enum SelectListOptionDef {
         /// Detailed description shown in the list.
         description: String,
     },
+    Group {
+        /// Section header for the options that follow it.
+        group: String,
+    },
 }
 
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
@@ -1026,6 +1804,9 @@ pub(super) struct TreeNodeDef {
     /// Whether the node starts expanded.
     #[serde(default)]
     pub(super) expanded: Option<bool>,
+    /// Whether the node starts checked when the tree has checkboxes.
+    #[serde(default)]
+    pub(super) checked: bool,
 }
 
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
@@ -1100,7 +1881,7 @@ pub(super) struct CommandRunnerCommandDef {
     pub(super) label: String,
     /// Executed program name.
     pub(super) program: String,
-    /// Program arguments.
+    /// Program arguments; `{{ selector }}` is replaced with form values.
     #[serde(default)]
     pub(super) args: Vec<String>,
     #[serde(default)]
@@ -1,25 +1,155 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::PathBuf;
 
-use super::model::{ConfigDoc, FlowItemDef, StepDef, TaskDef, TaskTriggerDef, WhenDef};
+use super::model::{
+    CanvasAlignDef, CanvasDef, ConfigDoc, FlowItemDef, OutputDef, OutputFormatDef, PrefillDef,
+    StepDef, SubflowDef, TaskBackoffDef, TaskDef, TaskFailureDef, TaskRetryDef, TaskTriggerDef,
+    WhenDef,
+};
 use super::spec::{ConfigSpec, StepSpec, TaskTemplateSpec};
-use crate::task::TaskTrigger;
+use super::subflow::expand_subflow;
+use super::utils::yaml_value_to_value;
+use crate::core::i18n::{Localization, MessageCatalog};
+use crate::core::store_refs::parse_store_selector;
+use crate::state::export::{ExportFormat, ExportTarget, ResultExport};
+use crate::state::prefill::Prefill;
+use crate::task::{Backoff, FailurePolicy, RetryPolicy, TaskTrigger};
+use crate::ui::canvas::{Canvas, CanvasAlign};
 
 pub(super) fn normalize(doc: ConfigDoc) -> Result<ConfigSpec, String> {
     if doc.version.unwrap_or(1) != 1 {
         return Err("unsupported config version (expected version: 1)".to_string());
     }
 
-    let steps = resolve_steps(doc.steps, doc.flow)?;
+    let steps = resolve_steps(doc.steps, doc.flow, doc.subflows)?;
     let tasks = resolve_tasks(doc.tasks)?;
+    let prefill = resolve_prefill(doc.prefill.unwrap_or_default())?;
+    let output = doc.output.map(resolve_output).transpose()?;
+    let canvas = resolve_canvas(doc.canvas.unwrap_or_default())?;
+    let localization = resolve_localization(doc.locale, doc.messages)?;
 
     Ok(ConfigSpec {
         confirm_finish: doc.confirm_finish.unwrap_or(true),
+        breadcrumb: doc.breadcrumb.unwrap_or(false),
+        prefill,
+        idle_timeout_ms: doc.idle_timeout_ms,
+        max_parallel_tasks: doc.max_parallel_tasks,
+        output,
+        canvas,
+        localization,
         steps,
         tasks,
     })
 }
 
-fn resolve_steps(steps: Vec<StepDef>, flow: Vec<FlowItemDef>) -> Result<Vec<StepSpec>, String> {
+fn resolve_prefill(def: PrefillDef) -> Result<Prefill, String> {
+    let parse_target = |selector: &str| {
+        parse_store_selector(selector)
+            .map_err(|err| format!("prefill selector '{selector}' is invalid: {err}"))
+    };
+
+    // The files themselves are read before interpolation, see `load_dotenv`.
+    if def.dotenv.iter().any(|path| path.trim().is_empty()) {
+        return Err("prefill.dotenv entries must be file paths".to_string());
+    }
+
+    let mut values = Vec::new();
+    if let Some(raw) = def.values.as_ref() {
+        let Some(mapping) = raw.as_mapping() else {
+            return Err("prefill.values must be a mapping keyed by store selector".to_string());
+        };
+        for (key, raw) in mapping {
+            let Some(selector) = key.as_str() else {
+                return Err("prefill.values keys must be store selectors".to_string());
+            };
+            let value = yaml_value_to_value(raw)
+                .map_err(|err| format!("prefill value for '{selector}' is invalid: {err}"))?;
+            values.push((parse_target(selector)?, value));
+        }
+    }
+
+    let mut env = Vec::with_capacity(def.env.len());
+    for (selector, name) in def.env {
+        if name.trim().is_empty() {
+            return Err(format!(
+                "prefill env for '{selector}' must name an environment variable"
+            ));
+        }
+        env.push((parse_target(selector.as_str())?, name));
+    }
+
+    Ok(Prefill {
+        previous_run: def.previous_run.map(PathBuf::from),
+        dotenv: BTreeMap::new(),
+        values,
+        env,
+    })
+}
+
+fn resolve_output(def: OutputDef) -> Result<ResultExport, String> {
+    let target = match def.path.as_deref().map(str::trim) {
+        None => ExportTarget::Stdout,
+        Some("") => return Err("output.path must not be empty".to_string()),
+        Some(path) => ExportTarget::parse(path),
+    };
+    let format = match def.format.unwrap_or_default() {
+        OutputFormatDef::Json => ExportFormat::Json,
+        OutputFormatDef::Yaml => ExportFormat::Yaml,
+        OutputFormatDef::Toml => ExportFormat::Toml,
+        OutputFormatDef::Markdown => ExportFormat::Markdown,
+        OutputFormatDef::Html => ExportFormat::Html,
+    };
+    Ok(ResultExport {
+        format,
+        target,
+        template: def.template,
+    })
+}
+
+fn resolve_canvas(def: CanvasDef) -> Result<Canvas, String> {
+    if def.max_width == Some(0) {
+        return Err("canvas.max_width must be at least 1".to_string());
+    }
+    let align = match def.align {
+        CanvasAlignDef::Left => CanvasAlign::Left,
+        CanvasAlignDef::Center => CanvasAlign::Center,
+    };
+    Ok(Canvas {
+        max_width: def.max_width,
+        align,
+        padding: def.padding,
+    })
+}
+
+fn resolve_localization(
+    locale: Option<String>,
+    messages: BTreeMap<String, BTreeMap<String, String>>,
+) -> Result<Localization, String> {
+    let mut localization = Localization::new();
+    for (catalog_locale, entries) in messages {
+        if catalog_locale.trim().is_empty() {
+            return Err("messages keys must be locale names".to_string());
+        }
+        let mut catalog = MessageCatalog::new(catalog_locale);
+        for (id, text) in entries {
+            catalog.insert(id, text);
+        }
+        localization.add_catalog(catalog);
+    }
+    if let Some(locale) = locale {
+        if locale.trim().is_empty() {
+            return Err("locale must not be empty".to_string());
+        }
+        localization.select(locale.trim());
+    }
+    Ok(localization)
+}
+
+fn resolve_steps(
+    steps: Vec<StepDef>,
+    flow: Vec<FlowItemDef>,
+    subflows: Vec<SubflowDef>,
+) -> Result<Vec<StepSpec>, String> {
     if flow.is_empty() {
         let mut seen = HashSet::<String>::new();
         let mut out = Vec::with_capacity(steps.len());
@@ -39,12 +169,46 @@ fn resolve_steps(steps: Vec<StepDef>, flow: Vec<FlowItemDef>) -> Result<Vec<Step
         }
     }
 
+    let mut subflow_defs = HashMap::<String, SubflowDef>::new();
+    for subflow in subflows {
+        if subflow_defs.contains_key(subflow.id.as_str()) {
+            return Err(format!(
+                "duplicate subflow id in yaml config: {}",
+                subflow.id
+            ));
+        }
+        subflow_defs.insert(subflow.id.clone(), subflow);
+    }
+
     let mut resolved = Vec::<StepSpec>::with_capacity(flow.len());
+    let mut seen = HashSet::<String>::new();
     for item in &flow {
-        let Some(def) = step_defs.remove(item.step.as_str()) else {
-            return Err(format!("flow references unknown step: {}", item.step));
+        let expanded = match (&item.step, &item.subflow) {
+            (Some(step_id), None) => {
+                let Some(def) = step_defs.remove(step_id.as_str()) else {
+                    return Err(format!("flow references unknown step: {step_id}"));
+                };
+                vec![def]
+            }
+            (None, Some(subflow_id)) => {
+                let Some(def) = subflow_defs.get(subflow_id.as_str()) else {
+                    return Err(format!("flow references unknown subflow: {subflow_id}"));
+                };
+                let namespace = item.namespace.as_deref().unwrap_or(subflow_id.as_str());
+                expand_subflow(def, namespace)?
+            }
+            _ => {
+                return Err(
+                    "flow item must reference exactly one of 'step' or 'subflow'".to_string(),
+                );
+            }
         };
-        resolved.push(build_step_spec(def, item.when.as_ref()));
+        for def in expanded {
+            if !seen.insert(def.id.clone()) {
+                return Err(format!("duplicate step id in yaml config: {}", def.id));
+            }
+            resolved.push(build_step_spec(def, item.when.as_ref()));
+        }
     }
 
     if !step_defs.is_empty() {
@@ -64,8 +228,13 @@ fn build_step_spec(def: StepDef, flow_when: Option<&WhenDef>) -> StepSpec {
         id: def.id,
         title: def.title,
         description: def.description,
+        help: def.help,
         navigation: def.navigation,
         when: merge_when(def.when.as_ref(), flow_when),
+        on_submit: def.on_submit,
+        auto_advance_ms: def.auto_advance_ms,
+        review: def.review,
+        layout: def.layout,
         widgets: def.widgets,
     }
 }
@@ -77,6 +246,17 @@ fn resolve_tasks(tasks: Vec<TaskDef>) -> Result<Vec<TaskTemplateSpec>, String> {
         if !ids.insert(task.id.clone()) {
             return Err(format!("duplicate task id in yaml config: {}", task.id));
         }
+        let progress = task
+            .progress
+            .map(|selector| {
+                parse_store_selector(selector.as_str()).map_err(|err| {
+                    format!(
+                        "task '{}' progress selector '{selector}' is invalid: {err}",
+                        task.id
+                    )
+                })
+            })
+            .transpose()?;
         out.push(TaskTemplateSpec {
             id: task.id,
             kind: task.kind,
@@ -87,11 +267,31 @@ fn resolve_tasks(tasks: Vec<TaskDef>) -> Result<Vec<TaskTemplateSpec>, String> {
             enabled: task.enabled.unwrap_or(true),
             triggers: resolve_task_triggers(task.triggers)?,
             writes: task.writes,
+            progress,
+            depends_on: task.depends_on,
+            retry: task.retry.map(resolve_task_retry).unwrap_or_default(),
+            failure: match task.on_failure {
+                TaskFailureDef::Report => FailurePolicy::Report,
+                TaskFailureDef::Abort => FailurePolicy::Abort,
+                TaskFailureDef::Skip => FailurePolicy::Skip,
+                TaskFailureDef::Prompt => FailurePolicy::Prompt,
+            },
         });
     }
     Ok(out)
 }
 
+fn resolve_task_retry(retry: TaskRetryDef) -> RetryPolicy {
+    let backoff = match retry.backoff {
+        TaskBackoffDef::Fixed => Backoff::Fixed { ms: retry.delay_ms },
+        TaskBackoffDef::Exponential => Backoff::Exponential {
+            initial_ms: retry.delay_ms,
+            max_ms: retry.max_delay_ms.unwrap_or(u64::MAX),
+        },
+    };
+    RetryPolicy::new(retry.max_attempts, backoff)
+}
+
 fn resolve_task_triggers(triggers: Vec<TaskTriggerDef>) -> Result<Vec<TaskTrigger>, String> {
     triggers
         .into_iter()
@@ -120,6 +320,7 @@ fn resolve_task_triggers(triggers: Vec<TaskTriggerDef>) -> Result<Vec<TaskTrigge
                 every_ms: every_ms.max(1),
                 only_when_step_active,
             }),
+            TaskTriggerDef::IdleTimeout => Ok(TaskTrigger::IdleTimeout),
         })
         .collect()
 }
@@ -1,17 +1,23 @@
+use crate::core::secrets::{SecretBackend, SecretKey};
 use crate::ui::spinner::SpinnerStyle;
 use crate::widgets::components::calendar::CalendarMode;
 use crate::widgets::components::file_browser::{
     BrowserMode, DisplayMode, EntryFilter, SelectionMode,
 };
 use crate::widgets::components::select_list::SelectMode;
+use crate::widgets::inputs::duration::parse_duration;
 use crate::widgets::inputs::text::TextMode;
 use crate::widgets::outputs::chart::ChartRenderMode;
 use crate::widgets::outputs::progress::{Easing, ProgressStyle, ProgressTransition};
 use crate::widgets::outputs::table::TableOutputStyle;
 use crate::widgets::outputs::thinking::ThinkingMode;
-use crate::widgets::validators;
+use crate::widgets::shared::calendar::{self, Date};
+use crate::widgets::{transforms, validators};
 
-use super::model::{ConfirmModeDef, ProgressTransitionDef, ValidatorDef};
+use super::model::{
+    ConfirmModeDef, DurationValueDef, ProgressTransitionDef, SecretBackendDef, SecretDef,
+    TransformDef, ValidatorDef,
+};
 
 pub(super) fn parse_text_mode(raw: Option<&str>) -> Result<TextMode, String> {
     match raw.unwrap_or("plain") {
@@ -24,6 +30,18 @@ pub(super) fn parse_text_mode(raw: Option<&str>) -> Result<TextMode, String> {
     }
 }
 
+pub(super) fn parse_secret(def: SecretDef) -> Result<SecretKey, String> {
+    if def.service.trim().is_empty() || def.account.trim().is_empty() {
+        return Err("secret needs a non-empty service and account".to_string());
+    }
+    let backend = match def.backend {
+        SecretBackendDef::Keychain => SecretBackend::Keychain,
+        SecretBackendDef::Pass => SecretBackend::Pass,
+        SecretBackendDef::Env => SecretBackend::Env,
+    };
+    Ok(SecretKey::new(backend, def.service, def.account))
+}
+
 pub(super) fn parse_select_mode(raw: Option<&str>) -> Result<SelectMode, String> {
     match raw.unwrap_or("single") {
         "single" => Ok(SelectMode::Single),
@@ -115,6 +133,35 @@ pub(super) fn parse_calendar_mode(raw: Option<&str>) -> Result<CalendarMode, Str
     }
 }
 
+pub(super) fn parse_iso_date(field: &str, raw: &str) -> Result<Date, String> {
+    calendar::parse_date(raw, calendar::fmt::DATE_YMD)
+        .ok_or_else(|| format!("date_input {field} must be a YYYY-MM-DD date, got: {raw}"))
+}
+
+pub(super) fn parse_date_pattern(raw: &str) -> Result<String, String> {
+    let fields = ["YYYY", "MM", "DD"];
+    if fields.iter().any(|field| raw.matches(field).count() != 1) {
+        return Err(format!(
+            "unsupported date_input format: {raw} (expected YYYY, MM and DD once each)"
+        ));
+    }
+    Ok(raw.to_string())
+}
+
+pub(super) fn parse_duration_value(def: DurationValueDef) -> Result<u64, String> {
+    match def {
+        DurationValueDef::Seconds(seconds) if seconds.is_finite() && seconds >= 0.0 => {
+            Ok(seconds.round() as u64)
+        }
+        DurationValueDef::Seconds(seconds) => Err(format!(
+            "duration_input default must not be negative: {seconds}"
+        )),
+        DurationValueDef::Text(text) => parse_duration(text.as_str()).ok_or_else(|| {
+            format!("duration_input default is not a duration (e.g. 1h30m, 90s): {text}")
+        }),
+    }
+}
+
 pub(super) fn parse_confirm_mode(
     def: Option<ConfirmModeDef>,
 ) -> crate::widgets::inputs::confirm::ConfirmMode {
@@ -155,8 +202,9 @@ pub(super) fn parse_chart_mode(raw: Option<&str>) -> Result<ChartRenderMode, Str
         "braille" => Ok(ChartRenderMode::Braille),
         "dots" => Ok(ChartRenderMode::Dots),
         "sparkline" => Ok(ChartRenderMode::Sparkline),
+        "bars" => Ok(ChartRenderMode::Bars),
         other => Err(format!(
-            "unsupported chart mode: {other} (expected braille|dots|sparkline)"
+            "unsupported chart mode: {other} (expected braille|dots|sparkline|bars)"
         )),
     }
 }
@@ -191,8 +239,9 @@ pub(super) fn parse_easing(raw: Option<&str>) -> Result<Easing, String> {
         "linear" => Ok(Easing::Linear),
         "out_quad" => Ok(Easing::OutQuad),
         "out_cubic" => Ok(Easing::OutCubic),
+        "in_out_cubic" => Ok(Easing::InOutCubic),
         other => Err(format!(
-            "unsupported easing: {other} (expected linear|out_quad|out_cubic)"
+            "unsupported easing: {other} (expected linear|out_quad|out_cubic|in_out_cubic)"
         )),
     }
 }
@@ -246,6 +295,17 @@ pub(super) fn compile_validators(defs: Vec<ValidatorDef>) -> Vec<validators::Val
         .collect()
 }
 
+pub(super) fn compile_transforms(defs: &[TransformDef]) -> Vec<transforms::Transform> {
+    defs.iter()
+        .map(|def| match def {
+            TransformDef::Trim => transforms::trim(),
+            TransformDef::Lowercase => transforms::lowercase(),
+            TransformDef::Uppercase => transforms::uppercase(),
+            TransformDef::NormalizePath => transforms::normalize_path(),
+        })
+        .collect()
+}
+
 pub(super) fn parse_task_kind(raw: &str) -> Result<(), String> {
     if raw == "exec" {
         Ok(())
@@ -1,9 +1,21 @@
-use super::model::{NavigationDef, WhenDef, WidgetDef, WriteBindingDef};
-use crate::task::TaskTrigger;
+use super::model::{LayoutDef, NavigationDef, WhenDef, WidgetDef, WriteBindingDef};
+use crate::core::i18n::Localization;
+use crate::core::value_path::ValueTarget;
+use crate::state::export::ResultExport;
+use crate::state::prefill::Prefill;
+use crate::task::{FailurePolicy, RetryPolicy, TaskTrigger};
+use crate::ui::canvas::Canvas;
 
 #[derive(Debug)]
 pub(super) struct ConfigSpec {
     pub confirm_finish: bool,
+    pub breadcrumb: bool,
+    pub prefill: Prefill,
+    pub idle_timeout_ms: Option<u64>,
+    pub max_parallel_tasks: Option<usize>,
+    pub output: Option<ResultExport>,
+    pub canvas: Canvas,
+    pub localization: Localization,
     pub steps: Vec<StepSpec>,
     pub tasks: Vec<TaskTemplateSpec>,
 }
@@ -13,8 +25,13 @@ pub(super) struct StepSpec {
     pub id: String,
     pub title: String,
     pub description: Option<String>,
+    pub help: Option<String>,
     pub navigation: Option<NavigationDef>,
     pub when: Option<WhenDef>,
+    pub on_submit: Vec<String>,
+    pub auto_advance_ms: Option<u64>,
+    pub review: bool,
+    pub layout: Option<LayoutDef>,
     pub widgets: Vec<WidgetDef>,
 }
 
@@ -29,4 +46,8 @@ pub(super) struct TaskTemplateSpec {
     pub enabled: bool,
     pub triggers: Vec<TaskTrigger>,
     pub writes: Option<WriteBindingDef>,
+    pub progress: Option<ValueTarget>,
+    pub depends_on: Vec<String>,
+    pub retry: RetryPolicy,
+    pub failure: FailurePolicy,
 }
@@ -0,0 +1,177 @@
+use std::collections::HashSet;
+
+use super::model::{StepDef, SubflowDef, TextOutputDef, WhenDef, WidgetDef, WriteBindingDef};
+use super::widgets;
+use crate::core::store_refs::parse_store_selector;
+use crate::core::value_path::{PathSegment, ValuePath, ValueTarget};
+
+pub(super) fn expand_subflow(def: &SubflowDef, namespace: &str) -> Result<Vec<StepDef>, String> {
+    if namespace.trim().is_empty() || namespace.contains("::") || namespace.contains('.') {
+        return Err(format!(
+            "subflow '{}' namespace must be a plain identifier, got '{namespace}'",
+            def.id
+        ));
+    }
+    if def.steps.is_empty() {
+        return Err(format!(
+            "subflow '{}' must define at least one step",
+            def.id
+        ));
+    }
+
+    let owned_roots = collect_owned_roots(def.steps.as_slice())?;
+    let rewriter = NamespaceRewriter {
+        namespace,
+        owned_roots: &owned_roots,
+    };
+
+    let mut steps = def.steps.clone();
+    for step in &mut steps {
+        step.id = format!("{namespace}.{}", step.id);
+        if let Some(when) = step.when.as_mut() {
+            rewriter.rewrite_when(when)?;
+        }
+        rewriter.rewrite_widgets(step.widgets.as_mut_slice())?;
+    }
+    Ok(steps)
+}
+
+fn collect_owned_roots(steps: &[StepDef]) -> Result<HashSet<String>, String> {
+    let mut roots = HashSet::<String>::new();
+    for step in steps {
+        widgets::walk_widgets(step.widgets.as_slice(), &mut |widget| {
+            let mut record = |selector: &str| {
+                let target = parse_store_selector(selector)?;
+                roots.insert(target.root().to_string());
+                Ok(())
+            };
+            widgets::visit_widget_binding_direct_value_targets(widget, &mut record)?;
+            widgets::visit_widget_binding_write_targets(widget, &mut record)
+        })?;
+    }
+    Ok(roots)
+}
+
+struct NamespaceRewriter<'a> {
+    namespace: &'a str,
+    owned_roots: &'a HashSet<String>,
+}
+
+impl NamespaceRewriter<'_> {
+    fn rewrite_widgets(&self, widgets: &mut [WidgetDef]) -> Result<(), String> {
+        for widget in widgets {
+            self.rewrite_widget(widget)?;
+            if let Some(children) = widgets::widget_children_mut(widget) {
+                self.rewrite_widgets(children)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn rewrite_widget(&self, widget: &mut WidgetDef) -> Result<(), String> {
+        if let Some(when) = widgets::widget_when_mut(widget) {
+            self.rewrite_when(when)?;
+        }
+        if let Some(selector) = widgets::widget_option_selector_mut(widget) {
+            *selector = self.rewrite_selector(selector)?;
+        }
+        if let WidgetDef::TextOutput(TextOutputDef { text, .. }) = widget {
+            *text = self.rewrite_template(text);
+        }
+        let Some(binding) = widgets::widget_binding_mut(widget) else {
+            return Ok(());
+        };
+        if let Some(value) = binding.value.as_mut() {
+            *value = self.rewrite_selector(value)?;
+        }
+        if let Some(reads) = binding.reads.as_mut() {
+            self.rewrite_reads(reads, true);
+        }
+        match binding.writes.as_mut() {
+            None => {}
+            Some(WriteBindingDef::Selector(selector)) => {
+                *selector = self.rewrite_selector(selector)?;
+            }
+            Some(WriteBindingDef::Map(entries)) => {
+                let mut rewritten = std::collections::BTreeMap::new();
+                for (target, expr) in std::mem::take(entries) {
+                    rewritten.insert(self.rewrite_selector(target.as_str())?, expr);
+                }
+                *entries = rewritten;
+            }
+        }
+        Ok(())
+    }
+
+    fn rewrite_when(&self, when: &mut WhenDef) -> Result<(), String> {
+        if let Some(field) = when.field_ref.as_mut() {
+            *field = self.rewrite_selector(field)?;
+        }
+        for nested in when.all.iter_mut().chain(when.any.iter_mut()) {
+            self.rewrite_when(nested)?;
+        }
+        if let Some(inner) = when.not.as_mut() {
+            self.rewrite_when(inner)?;
+        }
+        Ok(())
+    }
+
+    fn rewrite_reads(&self, value: &mut serde_yaml::Value, top_level: bool) {
+        match value {
+            serde_yaml::Value::String(text) => {
+                if text.contains("{{") && text.contains("}}") {
+                    *text = self.rewrite_template(text);
+                } else if top_level && let Ok(rewritten) = self.rewrite_selector(text) {
+                    *text = rewritten;
+                }
+            }
+            serde_yaml::Value::Mapping(map) => {
+                for (_, nested) in map.iter_mut() {
+                    self.rewrite_reads(nested, false);
+                }
+            }
+            serde_yaml::Value::Sequence(items) => {
+                for item in items {
+                    self.rewrite_reads(item, false);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn rewrite_template(&self, template: &str) -> String {
+        let mut out = String::with_capacity(template.len());
+        let mut rest = template;
+        while let Some(open) = rest.find("{{") {
+            let Some(close) = rest[open + 2..].find("}}") else {
+                break;
+            };
+            let inner = &rest[open + 2..open + 2 + close];
+            let expr = inner.trim();
+            out.push_str(&rest[..open + 2]);
+            match self.rewrite_selector(expr) {
+                Ok(rewritten) if !expr.is_empty() => {
+                    out.push_str(inner.replacen(expr, rewritten.as_str(), 1).as_str());
+                }
+                _ => out.push_str(inner),
+            }
+            out.push_str("}}");
+            rest = &rest[open + 2 + close + 2..];
+        }
+        out.push_str(rest);
+        out
+    }
+
+    fn rewrite_selector(&self, selector: &str) -> Result<String, String> {
+        let target = parse_store_selector(selector)
+            .map_err(|err| format!("invalid selector '{selector}': {err}"))?;
+        if !self.owned_roots.contains(target.root().as_str()) {
+            return Ok(selector.to_string());
+        }
+        let mut segments = vec![PathSegment::Key(target.root().to_string())];
+        if let Some(path) = target.nested_path() {
+            segments.extend(path.segments().iter().cloned());
+        }
+        Ok(ValueTarget::path(self.namespace, ValuePath::new(segments)).to_selector())
+    }
+}
@@ -0,0 +1,118 @@
+use super::invalid_yaml_message;
+use crate::config::{is_widget_registered, load_from_yaml_str, register_widget};
+use crate::core::value::Value;
+use crate::terminal::TerminalSize;
+use crate::ui::frame_text::{SnapshotFormat, frame_to_text};
+use crate::ui::render_view::RenderView;
+use crate::ui::renderer::{Renderer, RendererConfig};
+use crate::widgets::node::Node;
+use crate::widgets::outputs::text::TextOutput;
+
+fn register_badge() {
+    register_widget("badge", |config| {
+        let Some(Value::Text(label)) = config.option("label") else {
+            return Err("missing label".to_string());
+        };
+        let level = match config.option("level") {
+            Some(Value::Number(level)) => *level,
+            _ => 0.0,
+        };
+        Ok(Node::Output(Box::new(TextOutput::new(
+            config.id.clone(),
+            format!("[{label} {level}]"),
+        ))))
+    })
+    .expect("register badge");
+}
+
+#[test]
+fn registered_widget_types_are_built_from_config() {
+    register_badge();
+    assert!(is_widget_registered("badge"));
+
+    let yaml = r#"
+version: 1
+steps:
+  - id: demo
+    title: Demo
+    widgets:
+      - type: badge
+        id: status
+        label: Ready
+        level: 3
+"#;
+    let state = load_from_yaml_str(yaml)
+        .expect("load config")
+        .into_app_state()
+        .expect("app state");
+    let mut renderer = Renderer::new(RendererConfig {
+        chrome_enabled: false,
+    });
+    let frame = renderer.render(
+        &RenderView::from_state(&state),
+        TerminalSize {
+            width: 40,
+            height: 10,
+        },
+    );
+    let text = frame_to_text(
+        &frame,
+        TerminalSize {
+            width: 40,
+            height: 10,
+        },
+        SnapshotFormat::Text,
+    );
+    assert!(text.contains("[Ready 3]"), "rendered: {text}");
+}
+
+#[test]
+fn factory_errors_and_unknown_types_are_reported() {
+    register_badge();
+    let missing_option = r#"
+version: 1
+steps:
+  - id: demo
+    title: Demo
+    widgets:
+      - type: badge
+        id: status
+"#;
+    let err = invalid_yaml_message(missing_option);
+    assert!(
+        err.contains("widget 'status' (badge): missing label"),
+        "{err}"
+    );
+
+    let unknown = r#"
+version: 1
+steps:
+  - id: demo
+    title: Demo
+    widgets:
+      - type: gauge
+        id: level
+"#;
+    let err = invalid_yaml_message(unknown);
+    assert!(
+        err.contains("unknown widget type 'gauge' for widget 'level'"),
+        "{err}"
+    );
+}
+
+#[test]
+fn built_in_types_keep_their_own_errors_and_cannot_be_replaced() {
+    assert!(register_widget("text_input", |_| Err("unused".to_string())).is_err());
+
+    let yaml = r#"
+version: 1
+steps:
+  - id: demo
+    title: Demo
+    widgets:
+      - type: text_input
+        id: name
+"#;
+    let err = invalid_yaml_message(yaml);
+    assert!(err.contains("missing field `label`"), "{err}");
+}
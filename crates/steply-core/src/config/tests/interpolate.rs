@@ -0,0 +1,124 @@
+use super::super::interpolate::interpolate;
+use super::super::load_from_yaml_str_with_env;
+
+fn env(name: &str) -> Option<String> {
+    match name {
+        "DEPLOY_ENV" => Some("staging".to_string()),
+        "EMPTY" => Some(String::new()),
+        _ => None,
+    }
+}
+
+#[test]
+fn resolves_variables_fallbacks_and_escaped_dollars() {
+    assert_eq!(
+        interpolate("env ${DEPLOY_ENV}", &env).as_deref(),
+        Ok("env staging")
+    );
+    assert_eq!(
+        interpolate("${REGION:-eu-west-1}", &env).as_deref(),
+        Ok("eu-west-1")
+    );
+    assert_eq!(
+        interpolate("${EMPTY:-fallback}", &env).as_deref(),
+        Ok("fallback")
+    );
+    assert_eq!(interpolate("[${EMPTY}]", &env).as_deref(), Ok("[]"));
+    assert_eq!(
+        interpolate("cost $$5 or $5", &env).as_deref(),
+        Ok("cost $5 or $5")
+    );
+    assert_eq!(
+        interpolate("$${DEPLOY_ENV}", &env).as_deref(),
+        Ok("${DEPLOY_ENV}")
+    );
+}
+
+#[test]
+fn reports_unset_variables_and_malformed_references() {
+    let unset = interpolate("${REGION}", &env).unwrap_err();
+    assert!(unset.contains("'REGION' is not set"), "{unset}");
+    assert!(interpolate("${DEPLOY_ENV", &env).is_err());
+    assert!(interpolate("${1BAD}", &env).is_err());
+}
+
+#[test]
+fn interpolates_labels_defaults_and_paths_but_not_commands() {
+    let yaml = r#"
+version: 1
+output:
+  path: "out/${DEPLOY_ENV}.json"
+steps:
+  - id: target
+    title: "Deploy to ${DEPLOY_ENV}"
+    widgets:
+      - type: text_input
+        id: host
+        label: "Host (${DEPLOY_ENV})"
+        default: "${DEPLOY_ENV}.example.com"
+      - type: command_runner
+        id: ping
+        label: Ping
+        commands:
+          - label: Ping
+            program: sh
+            args: ["-c", "echo ${HOME}"]
+"#;
+    let loaded = load_from_yaml_str_with_env(yaml, env).expect("load config");
+    let step = loaded.flow.current_step();
+    assert_eq!(step.prompt, "Deploy to staging");
+    let host = step
+        .nodes
+        .iter()
+        .find(|node| node.id() == "host")
+        .expect("host");
+    assert_eq!(
+        host.value(),
+        Some(crate::core::value::Value::Text(
+            "staging.example.com".into()
+        ))
+    );
+}
+
+#[test]
+fn unset_variables_fail_the_load() {
+    let yaml = r#"
+version: 1
+steps:
+  - id: target
+    title: "Deploy to ${MISSING_TARGET}"
+    widgets: []
+"#;
+    let err = match load_from_yaml_str_with_env(yaml, env) {
+        Ok(_) => panic!("expected unresolved variable"),
+        Err(err) => err.to_string(),
+    };
+    assert!(err.contains("MISSING_TARGET"), "{err}");
+}
+
+#[test]
+fn dotenv_files_feed_interpolation_and_the_env_store_root() {
+    let path = std::env::temp_dir().join(format!("steply-config-{}.env", std::process::id()));
+    std::fs::write(&path, "CLUSTER=blue\n").expect("write env file");
+    let yaml = format!(
+        r#"
+version: 1
+prefill:
+  dotenv: ["{}"]
+steps:
+  - id: target
+    title: "Deploy to ${{CLUSTER}}"
+    widgets: []
+"#,
+        path.display()
+    );
+    let loaded = load_from_yaml_str_with_env(yaml.as_str(), env);
+    let _ = std::fs::remove_file(&path);
+    let loaded = loaded.expect("load config");
+
+    assert_eq!(loaded.flow.current_step().prompt, "Deploy to blue");
+    assert_eq!(
+        loaded.prefill.dotenv.get("CLUSTER").map(String::as_str),
+        Some("blue")
+    );
+}
@@ -1,3 +1,7 @@
+mod custom_widgets;
+mod interpolate;
+mod subflow;
+mod transforms;
 mod validate;
 
 fn invalid_yaml_message(raw: &str) -> String {
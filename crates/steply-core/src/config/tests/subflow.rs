@@ -0,0 +1,110 @@
+use super::invalid_yaml_message;
+use crate::core::value::Value;
+use crate::runtime::event::SystemEvent;
+use crate::terminal::{KeyCode, KeyEvent, KeyModifiers};
+
+const CREDENTIALS_YAML: &str = r#"
+version: 1
+steps:
+  - id: intro
+    title: Intro
+    widgets:
+      - type: text_input
+        id: env
+        label: Environment
+        value: env
+subflows:
+  - id: credentials
+    steps:
+      - id: login
+        title: Login
+        widgets:
+          - type: text_input
+            id: user
+            label: User
+            value: user
+      - id: confirm
+        title: Confirm
+        when:
+          ref: user
+          operator: not_empty
+        widgets:
+          - type: text_output
+            id: summary
+            text: "{{ user }} on {{ env }}"
+flow:
+  - step: intro
+  - subflow: credentials
+    namespace: prod
+  - subflow: credentials
+    namespace: staging
+"#;
+
+#[test]
+fn subflow_steps_are_expanded_per_namespace() {
+    let loaded = crate::config::load_from_yaml_str(CREDENTIALS_YAML).expect("load config");
+    let ids = loaded
+        .flow
+        .steps()
+        .iter()
+        .map(|step| step.id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        ids,
+        [
+            "intro",
+            "prod.login",
+            "prod.confirm",
+            "staging.login",
+            "staging.confirm"
+        ]
+    );
+}
+
+#[test]
+fn subflow_values_are_written_under_their_namespace() {
+    let loaded = crate::config::load_from_yaml_str(CREDENTIALS_YAML).expect("load config");
+    let mut state = loaded.into_app_state().expect("app state");
+
+    state.dispatch_key_to_focused(KeyEvent {
+        code: KeyCode::Char('x'),
+        modifiers: KeyModifiers::NONE,
+    });
+    state.handle_system_event(SystemEvent::RequestSubmit);
+    state.dispatch_key_to_focused(KeyEvent {
+        code: KeyCode::Char('a'),
+        modifiers: KeyModifiers::NONE,
+    });
+    state.handle_system_event(SystemEvent::RequestSubmit);
+
+    assert_eq!(state.current_step_id(), "prod.confirm");
+    assert_eq!(
+        state.store_value("prod.user"),
+        Some(&Value::Text("a".to_string()))
+    );
+    assert_eq!(state.store_value("user"), None);
+    assert_eq!(
+        state.store_value("env"),
+        Some(&Value::Text("x".to_string()))
+    );
+}
+
+#[test]
+fn rejects_flow_item_with_unknown_subflow() {
+    let yaml = r#"
+version: 1
+steps:
+  - id: intro
+    title: Intro
+    widgets:
+      - type: text_output
+        id: note
+        text: Hello
+flow:
+  - step: intro
+  - subflow: missing
+"#;
+
+    let err = invalid_yaml_message(yaml);
+    assert!(err.contains("flow references unknown subflow: missing"));
+}
@@ -0,0 +1,62 @@
+use super::invalid_yaml_message;
+use crate::core::value::Value;
+use crate::runtime::event::SystemEvent;
+
+#[test]
+fn committed_values_are_transformed_in_order() {
+    let yaml = r#"
+version: 1
+steps:
+  - id: account
+    title: Account
+    widgets:
+      - type: text_input
+        id: email
+        label: Email
+        value: email
+        transform: [trim, lowercase]
+      - type: text_input
+        id: home
+        label: Home
+        value: home
+        commit_policy: on_submit
+        transform: [normalize_path]
+"#;
+    let loaded = crate::config::load_from_yaml_str(yaml).expect("load config");
+    let mut state = loaded.into_app_state().expect("app state");
+
+    state.dispatch_paste_to_focused("  Ops@Example.COM ");
+    assert_eq!(
+        state.store_value("email"),
+        Some(&Value::Text("ops@example.com".to_string()))
+    );
+    state.focus_next();
+    state.dispatch_paste_to_focused("/srv//app/./logs/../data/");
+    assert_eq!(state.store_value("home"), Some(&Value::Text(String::new())));
+    state.handle_system_event(SystemEvent::RequestSubmit);
+
+    assert_eq!(
+        state.store_value("home"),
+        Some(&Value::Text("/srv/app/data".to_string()))
+    );
+}
+
+#[test]
+fn rejects_transform_on_widget_without_committed_value() {
+    let yaml = r#"
+version: 1
+steps:
+  - id: account
+    title: Account
+    widgets:
+      - type: text_input
+        id: email
+        label: Email
+        transform: [trim]
+"#;
+    let message = invalid_yaml_message(yaml);
+    assert!(
+        message.contains("binding 'transform' needs a 'value' or 'writes' binding"),
+        "{message}"
+    );
+}
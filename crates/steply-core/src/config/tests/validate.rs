@@ -394,3 +394,139 @@ steps:
     let err = invalid_yaml_message(yaml);
     assert!(err.contains("condition operator 'truthy' does not allow 'value'"));
 }
+
+#[test]
+fn rejects_step_on_submit_hook_referencing_unknown_task() {
+    let yaml = r#"
+version: 1
+steps:
+  - id: demo
+    title: Demo
+    on_submit: [check_connectivity]
+    widgets:
+      - type: text_input
+        id: host
+        label: Host
+"#;
+
+    let err = invalid_yaml_message(yaml);
+    assert!(err.contains("on_submit references unknown task: check_connectivity"));
+}
+
+#[test]
+fn rejects_zero_auto_advance_timeout() {
+    let yaml = r#"
+version: 1
+steps:
+  - id: demo
+    title: Demo
+    auto_advance_ms: 0
+    widgets:
+      - type: text_output
+        id: note
+        text: "Hi"
+"#;
+
+    let err = invalid_yaml_message(yaml);
+    assert!(err.contains("auto_advance_ms must be greater than 0"));
+}
+
+#[test]
+fn rejects_layouts_naming_unknown_or_repeated_widgets() {
+    let layout_yaml = |children: &str| {
+        format!(
+            r#"
+version: 1
+steps:
+  - id: demo
+    title: Demo
+    layout:
+      children:
+{children}
+    widgets:
+      - type: text_input
+        id: name
+        label: Name
+"#
+        )
+    };
+
+    let unknown = invalid_yaml_message(&layout_yaml("        - widgets: [missing]"));
+    assert!(
+        unknown.contains("step 'demo' layout: unknown widget 'missing'"),
+        "{unknown}"
+    );
+
+    let repeated = invalid_yaml_message(&layout_yaml(
+        "        - widgets: [name]\n        - widgets: [name]",
+    ));
+    assert!(repeated.contains("placed twice"), "{repeated}");
+
+    let bad_size = invalid_yaml_message(&layout_yaml(
+        "        - size: \"120%\"\n          widgets: [name]",
+    ));
+    assert!(bad_size.contains("over 100%"), "{bad_size}");
+}
+
+#[test]
+fn reads_task_retry_and_failure_policies() {
+    let yaml = r#"
+version: 1
+steps:
+  - id: demo
+    title: Demo
+    widgets: []
+tasks:
+  - id: fetch
+    kind: exec
+    program: curl
+    retry:
+      max_attempts: 4
+      delay_ms: 200
+      backoff: exponential
+      max_delay_ms: 1000
+    on_failure: prompt
+"#;
+
+    let config = crate::config::load_from_yaml_str(yaml).expect("yaml should validate");
+    let spec = &config.task_specs[0];
+    assert_eq!(
+        spec.retry_policy,
+        crate::task::RetryPolicy::new(
+            4,
+            crate::task::Backoff::Exponential {
+                initial_ms: 200,
+                max_ms: 1000,
+            },
+        )
+    );
+    assert_eq!(
+        (1..4)
+            .map(|attempt| spec.retry_policy.delay_ms(attempt))
+            .collect::<Vec<_>>(),
+        vec![200, 400, 800]
+    );
+    assert_eq!(spec.failure_policy, crate::task::FailurePolicy::Prompt);
+}
+
+#[test]
+fn rejects_unknown_object_editor_schema_types() {
+    let yaml = r#"
+version: 1
+steps:
+  - id: demo
+    title: Demo
+    widgets:
+      - type: object_editor
+        id: settings
+        label: Settings
+        schema:
+          required: [env]
+          fields:
+            env:
+              type: [text, colour]
+"#;
+
+    let err = invalid_yaml_message(yaml);
+    assert!(err.contains("unknown schema type `colour`"));
+}
@@ -1,6 +1,7 @@
 use std::collections::{HashMap, HashSet};
 
-use super::model::{WhenDef, WriteBindingDef};
+use super::custom_widgets::validate_custom_widget;
+use super::model::{WhenDef, WidgetDef, WriteBindingDef};
 use super::spec::{ConfigSpec, StepSpec};
 use super::{utils, widgets};
 use crate::core::store_refs::parse_store_selector;
@@ -15,6 +16,7 @@ pub(super) fn validate(spec: &ConfigSpec) -> Result<(), String> {
         return Err("yaml config must define at least one step".to_string());
     }
 
+    validate_timers(spec)?;
     validate_step_widgets(spec.steps.as_slice())?;
     validate_widget_bindings(spec.steps.as_slice())?;
     validate_cross_owner_store_writers(spec)?;
@@ -32,6 +34,21 @@ pub(super) fn validate(spec: &ConfigSpec) -> Result<(), String> {
     validate_task_references(spec, &known_task_ids, &known_step_ids)
 }
 
+fn validate_timers(spec: &ConfigSpec) -> Result<(), String> {
+    if spec.idle_timeout_ms == Some(0) {
+        return Err("idle_timeout_ms must be greater than 0".to_string());
+    }
+    for step in &spec.steps {
+        if step.auto_advance_ms == Some(0) {
+            return Err(format!(
+                "step '{}' auto_advance_ms must be greater than 0",
+                step.id
+            ));
+        }
+    }
+    Ok(())
+}
+
 fn validate_step_widgets(steps: &[StepSpec]) -> Result<(), String> {
     for step in steps {
         let mut seen = HashSet::<String>::new();
@@ -40,6 +57,9 @@ fn validate_step_widgets(steps: &[StepSpec]) -> Result<(), String> {
             if !seen.insert(id.clone()) {
                 return Err(format!("duplicate widget id '{id}' in step '{}'", step.id));
             }
+            if let WidgetDef::Custom(def) = widget {
+                validate_custom_widget(def)?;
+            }
             Ok(())
         })?;
     }
@@ -646,7 +666,8 @@ fn validate_task_references(
                 TaskTrigger::FlowStart
                 | TaskTrigger::FlowEnd
                 | TaskTrigger::StoreChanged { .. }
-                | TaskTrigger::Interval { .. } => {}
+                | TaskTrigger::Interval { .. }
+                | TaskTrigger::IdleTimeout => {}
             }
         }
         if let Some(reads) = &task.reads {
@@ -666,6 +687,14 @@ fn validate_task_references(
     }
 
     for step in &spec.steps {
+        for task_id in &step.on_submit {
+            if !known_task_ids.contains(task_id) {
+                return Err(format!(
+                    "step '{}' on_submit references unknown task: {task_id}",
+                    step.id
+                ));
+            }
+        }
         widgets::walk_widgets(step.widgets.as_slice(), &mut |widget| {
             widgets::visit_widget_task_references(widget, &mut |task_id| {
                 if !known_task_ids.contains(task_id) {
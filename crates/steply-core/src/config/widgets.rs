@@ -15,8 +15,10 @@ use crate::widgets::shared::condition::wrap_node_when;
 use crate::widgets::static_hints;
 
 use super::binding_compile::{compile_read_binding_value, compile_write_bindings, parse_selector};
+use super::custom_widgets::compile_custom_widget;
 use super::doc_model::{WidgetCategory, WidgetDoc, WidgetDocDescriptor, build_widget_doc};
 use super::model::{self, WidgetDef};
+use super::parse::compile_transforms;
 
 pub(super) struct WidgetRegistryEntry {
     pub(super) doc: WidgetDocDescriptor,
@@ -65,6 +67,21 @@ macro_rules! widget_binding_value {
     };
 }
 
+macro_rules! widget_binding_value_mut {
+    (no, $def:ident) => {
+        None
+    };
+    (yes, $def:ident) => {
+        Some(&mut $def.binding)
+    };
+    (read_only, $def:ident) => {
+        Some(&mut $def.binding)
+    };
+    (writes_only, $def:ident) => {
+        Some(&mut $def.binding)
+    };
+}
+
 macro_rules! widget_binding_support_value {
     (no) => {
         WidgetBindingSupport::None
@@ -86,6 +103,12 @@ macro_rules! widget_when_value {
     };
 }
 
+macro_rules! widget_when_value_mut {
+    ($def:ident) => {
+        $def.when.as_mut()
+    };
+}
+
 macro_rules! widget_children_value {
     (none, $def:ident) => {
         None
@@ -98,6 +121,18 @@ macro_rules! widget_children_value {
     };
 }
 
+macro_rules! widget_children_value_mut {
+    (none, $def:ident) => {
+        None
+    };
+    (inputs, $def:ident) => {
+        Some($def.inputs.as_mut_slice())
+    };
+    (widgets, $def:ident) => {
+        Some($def.widgets.as_mut_slice())
+    };
+}
+
 macro_rules! define_widget_registry {
     (
         $(
@@ -134,39 +169,80 @@ macro_rules! define_widget_registry {
         ];
 
         impl WidgetDef {
-            fn registry_type_name(&self) -> &'static str {
+            fn registry_type_name(&self) -> &str {
                 match self {
                     $(Self::$variant(_) => $type_name,)+
+                    Self::Custom(def) => def.widget_type.as_str(),
                 }
             }
 
             fn registry_id(&self) -> &str {
                 match self {
                     $(Self::$variant(def) => def.id.as_str(),)+
+                    Self::Custom(def) => def.id.as_str(),
                 }
             }
 
             fn registry_binding(&self) -> Option<&model::WidgetBindingDef> {
                 match self {
                     $(Self::$variant(_def) => widget_binding_value!($binding, _def),)+
+                    Self::Custom(def) => Some(&def.binding),
                 }
             }
 
             fn registry_when(&self) -> Option<&model::WhenDef> {
                 match self {
                     $(Self::$variant(def) => widget_when_value!(def),)+
+                    Self::Custom(def) => widget_when_value!(def),
+                }
+            }
+
+            fn registry_help(&self) -> Option<&str> {
+                match self {
+                    $(Self::$variant(def) => def.help.as_deref(),)+
+                    Self::Custom(def) => def.help.as_deref(),
+                }
+            }
+
+            fn registry_aria_label(&self) -> Option<&str> {
+                match self {
+                    $(Self::$variant(def) => def.aria_label.as_deref(),)+
+                    Self::Custom(def) => def.aria_label.as_deref(),
                 }
             }
 
             fn registry_binding_support(&self) -> WidgetBindingSupport {
                 match self {
                     $(Self::$variant(_) => widget_binding_support_value!($binding),)+
+                    Self::Custom(_) => WidgetBindingSupport::Full,
                 }
             }
 
             fn registry_children(&self) -> Option<&[WidgetDef]> {
                 match self {
                     $(Self::$variant(_def) => widget_children_value!($children, _def),)+
+                    Self::Custom(_) => None,
+                }
+            }
+
+            fn registry_binding_mut(&mut self) -> Option<&mut model::WidgetBindingDef> {
+                match self {
+                    $(Self::$variant(_def) => widget_binding_value_mut!($binding, _def),)+
+                    Self::Custom(def) => Some(&mut def.binding),
+                }
+            }
+
+            fn registry_when_mut(&mut self) -> Option<&mut model::WhenDef> {
+                match self {
+                    $(Self::$variant(def) => widget_when_value_mut!(def),)+
+                    Self::Custom(def) => widget_when_value_mut!(def),
+                }
+            }
+
+            fn registry_children_mut(&mut self) -> Option<&mut [WidgetDef]> {
+                match self {
+                    $(Self::$variant(_def) => widget_children_value_mut!($children, _def),)+
+                    Self::Custom(_) => None,
                 }
             }
         }
@@ -255,13 +331,29 @@ max: 100"#,
         binding: read_only,
         children: none
     },
+    {
+        variant: SpinnerOutput,
+        def: model::SpinnerOutputDef,
+        type_name: "spinner_output",
+        category: Output,
+        short: "Spinner output.",
+        long: "Spins beside a label while work without measurable progress runs; with a task it follows the task's runs and ends in a check mark or a cross.",
+        example: r#"type: spinner_output
+id: deploying
+label: Deploying
+task: deploy"#,
+        hints: &[],
+        compile: compile_spinner_output_widget,
+        binding: no,
+        children: none
+    },
     {
         variant: ChartOutput,
         def: model::ChartOutputDef,
         type_name: "chart_output",
         category: Output,
         short: "Terminal chart output.",
-        long: "Renders numeric values as a small terminal chart.",
+        long: "Renders numeric values as a small terminal chart: a braille, dot or sparkline line, or bars with range labels. Points are squeezed to fit the width.",
         example: r#"type: chart_output
 id: cpu
 label: CPU load"#,
@@ -300,6 +392,38 @@ old: before
 new: after"#,
         hints: &[],
         compile: compile_diff_output_widget,
+        binding: yes,
+        children: none
+    },
+    {
+        variant: LogOutput,
+        def: model::LogOutputDef,
+        type_name: "log_output",
+        category: Output,
+        short: "Scrolling console output.",
+        long: "Streams the output lines of tasks with their ANSI colors, following new lines until scrolled back. `/` searches the kept history.",
+        example: r#"type: log_output
+id: console
+label: Build output
+tasks: [build]"#,
+        hints: &[],
+        compile: compile_log_output_widget,
+        binding: no,
+        children: none
+    },
+    {
+        variant: TaskGraphOutput,
+        def: model::TaskGraphOutputDef,
+        type_name: "task_graph_output",
+        category: Output,
+        short: "Task dependency graph.",
+        long: "Shows tasks linked by depends_on with their live status: pending, running, retrying, done, failed, skipped or blocked.",
+        example: r#"type: task_graph_output
+id: pipeline
+label: Release
+tasks: [build, test, deploy]"#,
+        hints: &[],
+        compile: compile_task_graph_output_widget,
         binding: no,
         children: none
     },
@@ -461,6 +585,39 @@ label: Accent color"#,
         binding: yes,
         children: none
     },
+    {
+        variant: DateInput,
+        def: model::DateInputDef,
+        type_name: "date_input",
+        category: Input,
+        short: "Date input.",
+        long: "Types a date in a locale pattern or picks it from a calendar opened with ↓. The value is an ISO-8601 date.",
+        example: r#"type: date_input
+id: start
+label: Start date
+min: 2026-01-01
+locale: de"#,
+        hints: static_hints::DATE_INPUT_HINTS,
+        compile: compile_date_input_widget,
+        binding: yes,
+        children: none
+    },
+    {
+        variant: DurationInput,
+        def: model::DurationInputDef,
+        type_name: "duration_input",
+        category: Input,
+        short: "Duration input.",
+        long: "Edits hours, minutes and seconds with ↑↓ or reads typed text such as `1h30m`, `90s` or `2 days`. The value is a number of seconds.",
+        example: r#"type: duration_input
+id: timeout
+label: Timeout
+default: 1h30m"#,
+        hints: static_hints::DURATION_INPUT_HINTS,
+        compile: compile_duration_input_widget,
+        binding: yes,
+        children: none
+    },
     {
         variant: ConfirmInput,
         def: model::ConfirmInputDef,
@@ -512,10 +669,12 @@ label: Deploy at"#,
         type_name: "textarea",
         category: Component,
         short: "Textarea component.",
-        long: "Multi-line text editor widget.",
+        long: "Multi-line text editor widget with optional soft wrap and line numbers. Shift+Enter adds a line, or Enter with `newline_on_enter`; Ctrl+Enter submits.",
         example: r#"type: textarea
 id: notes
-min_height: 4"#,
+label: Notes
+min_height: 4
+soft_wrap: true"#,
         hints: static_hints::TEXTAREA_HINTS,
         compile: compile_textarea_widget,
         binding: yes,
@@ -531,10 +690,11 @@ min_height: 4"#,
         example: r#"type: command_runner
 id: install
 label: Install dependencies
+require_success: true
 commands:
   - label: Cargo fetch
     program: cargo
-    args: [fetch]"#,
+    args: [fetch, "--manifest-path", "{{ project.path }}/Cargo.toml"]"#,
         hints: static_hints::COMMAND_RUNNER_HINTS,
         compile: compile_command_runner_widget,
         binding: writes_only,
@@ -676,10 +836,48 @@ pub(super) fn widget_when(widget: &WidgetDef) -> Option<&model::WhenDef> {
     widget.registry_when()
 }
 
+pub(super) fn widget_help(widget: &WidgetDef) -> Option<&str> {
+    widget.registry_help()
+}
+
+pub(super) fn widget_aria_label(widget: &WidgetDef) -> Option<&str> {
+    widget.registry_aria_label()
+}
+
 pub(super) fn widget_children(widget: &WidgetDef) -> Option<&[WidgetDef]> {
     widget.registry_children()
 }
 
+pub(super) fn widget_binding_mut(widget: &mut WidgetDef) -> Option<&mut model::WidgetBindingDef> {
+    widget.registry_binding_mut()
+}
+
+pub(super) fn widget_when_mut(widget: &mut WidgetDef) -> Option<&mut model::WhenDef> {
+    widget.registry_when_mut()
+}
+
+pub(super) fn widget_children_mut(widget: &mut WidgetDef) -> Option<&mut [WidgetDef]> {
+    widget.registry_children_mut()
+}
+
+pub(super) fn widget_option_selector_mut(widget: &mut WidgetDef) -> Option<&mut String> {
+    match widget {
+        WidgetDef::Select(model::SelectDef {
+            options: model::StringOptionsDef::Selector(selector),
+            ..
+        })
+        | WidgetDef::ChoiceInput(model::ChoiceInputDef {
+            options: model::StringOptionsDef::Selector(selector),
+            ..
+        })
+        | WidgetDef::SelectList(model::SelectListDef {
+            options: model::SelectListOptionsDef::Selector(selector),
+            ..
+        }) => Some(selector),
+        _ => None,
+    }
+}
+
 pub(super) fn visit_widget_inline_task_ids(
     widget: &WidgetDef,
     visitor: &mut impl FnMut(String) -> Result<(), String>,
@@ -706,6 +904,22 @@ pub(super) fn visit_widget_task_references(
             }
             Ok(())
         }
+        WidgetDef::TaskGraphOutput(def) => {
+            for task_id in &def.tasks {
+                visitor(task_id.as_str())?;
+            }
+            Ok(())
+        }
+        WidgetDef::LogOutput(def) => {
+            for task_id in &def.tasks {
+                visitor(task_id.as_str())?;
+            }
+            Ok(())
+        }
+        WidgetDef::SpinnerOutput(def) => match &def.task {
+            Some(task_id) => visitor(task_id.as_str()),
+            None => Ok(()),
+        },
         WidgetDef::ButtonInput(def) => match &def.task_id {
             Some(task_id) => visitor(task_id.as_str()),
             None => Ok(()),
@@ -810,19 +1024,29 @@ pub(super) fn compile_widget(def: WidgetDef) -> Result<Node, String> {
         .map(super::assemble::assemble_when)
         .transpose()?;
     let binding = compile_store_binding(&def)?;
-    let widget_type = def.registry_type_name();
-    let Some(entry) = widget_entry(widget_type) else {
-        return Err(format!(
-            "internal widget registry is missing entry for '{widget_type}'"
-        ));
+    let node = match def {
+        WidgetDef::Custom(def) => compile_custom_widget(def)?,
+        def => {
+            let widget_type = def.registry_type_name();
+            let Some(entry) = widget_entry(widget_type) else {
+                return Err(format!(
+                    "internal widget registry is missing entry for '{widget_type}'"
+                ));
+            };
+            (entry.compile)(def)?
+        }
     };
-    let node = bind_node((entry.compile)(def)?, binding);
+    let node = bind_node(node, binding);
     Ok(match condition {
         Some(condition) => wrap_node_when(node, condition),
         None => node,
     })
 }
 
+pub(super) fn is_builtin_widget_type(widget_type: &str) -> bool {
+    widget_entry(widget_type).is_some()
+}
+
 fn widget_entry(widget_type: &str) -> Option<&'static WidgetRegistryEntry> {
     widget_registry()
         .iter()
@@ -973,6 +1197,7 @@ fn compile_widget_binding(
     }
 
     let widget_type = def.registry_type_name();
+    let transforms = compile_transforms(&binding.transform);
     let commit_policy = match binding.commit_policy {
         model::BindingCommitPolicyDef::Immediate => {
             crate::state::change::StoreCommitPolicy::Immediate
@@ -1004,6 +1229,11 @@ fn compile_widget_binding(
                     "widget '{widget_type}' does not support binding.commit_policy"
                 ));
             }
+            if !transforms.is_empty() {
+                return Err(format!(
+                    "widget '{widget_type}' commits no value; binding.transform is not allowed"
+                ));
+            }
 
             let reads = binding
                 .reads
@@ -1016,6 +1246,7 @@ fn compile_widget_binding(
                 reads,
                 writes: Vec::new(),
                 commit_policy,
+                transforms: Vec::new(),
             });
         }
         WidgetBindingSupport::WritesOnly => {
@@ -1042,6 +1273,7 @@ fn compile_widget_binding(
                 reads: None,
                 writes,
                 commit_policy,
+                transforms,
             });
         }
         WidgetBindingSupport::Full => {}
@@ -1070,6 +1302,7 @@ fn compile_widget_binding(
                 expr: WriteExpr::ScopeRef("value".to_string()),
             }],
             commit_policy,
+            transforms,
         });
     }
 
@@ -1080,12 +1313,19 @@ fn compile_widget_binding(
 
     let writes = compile_write_bindings(binding.writes, "value", widget_scope_ref(def))?;
 
+    if !transforms.is_empty() && writes.is_empty() {
+        return Err(
+            "binding 'transform' needs a 'value' or 'writes' binding to apply to".to_string(),
+        );
+    }
+
     Ok(StoreBinding {
         value: None,
         options: None,
         reads,
         writes,
         commit_policy,
+        transforms,
     })
 }
 
@@ -1103,10 +1343,8 @@ fn compile_implicit_store_binding(def: &WidgetDef) -> Result<StoreBinding, Strin
     }
 }
 
-fn widget_scope_ref(def: &WidgetDef) -> fn(&str) -> bool {
-    match def {
-        _ => is_value_scope_ref,
-    }
+fn widget_scope_ref(_def: &WidgetDef) -> fn(&str) -> bool {
+    is_value_scope_ref
 }
 
 fn compile_string_options(options: model::StringOptionsDef) -> Vec<String> {
@@ -1175,6 +1413,7 @@ fn widget_binding_is_empty(binding: &model::WidgetBindingDef) -> bool {
         && binding.reads.is_none()
         && binding.writes.is_none()
         && binding.commit_policy == model::BindingCommitPolicyDef::Immediate
+        && binding.transform.is_empty()
 }
 
 fn write_expr_is_identity(value: &serde_yaml::Value) -> bool {
@@ -1244,9 +1483,10 @@ fn compile_progress_output_widget(def: WidgetDef) -> Result<Node, String> {
             bar_width,
             style,
             transition,
+            task,
             ..
         }) => outputs::compile_progress_output(
-            id, label, min, max, unit, bar_width, style, transition,
+            id, label, min, max, unit, bar_width, style, transition, task,
         ),
         _ => registry_dispatch_mismatch("progress_output"),
     }
@@ -1263,8 +1503,12 @@ fn compile_chart_output_widget(def: WidgetDef) -> Result<Node, String> {
             max,
             unit,
             gradient,
+            height,
+            axis,
             ..
-        }) => outputs::compile_chart_output(id, label, mode, capacity, min, max, unit, gradient),
+        }) => outputs::compile_chart_output(
+            id, label, mode, capacity, min, max, unit, gradient, height, axis,
+        ),
         _ => registry_dispatch_mismatch("chart_output"),
     }
 }
@@ -1291,12 +1535,74 @@ fn compile_diff_output_widget(def: WidgetDef) -> Result<Node, String> {
             old,
             new,
             max_visible,
+            scrollbar,
+            unified,
+            review,
             ..
-        }) => outputs::compile_diff_output(id, label, old, new, max_visible),
+        }) => outputs::compile_diff_output(
+            id,
+            label,
+            old,
+            new,
+            max_visible,
+            scrollbar,
+            unified,
+            review,
+        ),
         _ => registry_dispatch_mismatch("diff_output"),
     }
 }
 
+fn compile_task_graph_output_widget(def: WidgetDef) -> Result<Node, String> {
+    match def {
+        WidgetDef::TaskGraphOutput(model::TaskGraphOutputDef {
+            id,
+            label,
+            tasks,
+            spinner_style,
+            ..
+        }) => outputs::compile_task_graph_output(id, label, tasks, spinner_style),
+        _ => registry_dispatch_mismatch("task_graph_output"),
+    }
+}
+
+fn compile_spinner_output_widget(def: WidgetDef) -> Result<Node, String> {
+    match def {
+        WidgetDef::SpinnerOutput(model::SpinnerOutputDef {
+            id,
+            label,
+            task,
+            spinner_style,
+            ..
+        }) => outputs::compile_spinner_output(id, label, task, spinner_style),
+        _ => registry_dispatch_mismatch("spinner_output"),
+    }
+}
+
+fn compile_log_output_widget(def: WidgetDef) -> Result<Node, String> {
+    match def {
+        WidgetDef::LogOutput(model::LogOutputDef {
+            id,
+            label,
+            tasks,
+            visible_lines,
+            history_lines,
+            follow,
+            animate,
+            ..
+        }) => outputs::compile_log_output(
+            id,
+            label,
+            tasks,
+            visible_lines,
+            history_lines,
+            follow,
+            animate,
+        ),
+        _ => registry_dispatch_mismatch("log_output"),
+    }
+}
+
 fn compile_task_log_output_widget(def: WidgetDef) -> Result<Node, String> {
     match def {
         WidgetDef::TaskLogOutput(model::TaskLogOutputDef {
@@ -1304,8 +1610,9 @@ fn compile_task_log_output_widget(def: WidgetDef) -> Result<Node, String> {
             visible_lines,
             spinner_style,
             steps,
+            scrollbar,
             ..
-        }) => outputs::compile_task_log_output(id, visible_lines, spinner_style, steps),
+        }) => outputs::compile_task_log_output(id, visible_lines, spinner_style, steps, scrollbar),
         _ => registry_dispatch_mismatch("task_log_output"),
     }
 }
@@ -1321,6 +1628,7 @@ fn compile_text_input_widget(def: WidgetDef) -> Result<Node, String> {
             required,
             validators,
             completion_items,
+            secret,
             ..
         }) => inputs::compile_text_input(
             id,
@@ -1331,6 +1639,7 @@ fn compile_text_input_widget(def: WidgetDef) -> Result<Node, String> {
             required,
             validators,
             completion_items,
+            secret,
         ),
         _ => registry_dispatch_mismatch("text_input"),
     }
@@ -1421,6 +1730,8 @@ fn compile_select_list_widget(def: WidgetDef) -> Result<Node, String> {
             max_visible,
             selected,
             show_label,
+            scrollbar,
+            max_selected,
             ..
         }) => components::compile_select_list(
             id,
@@ -1430,6 +1741,8 @@ fn compile_select_list_widget(def: WidgetDef) -> Result<Node, String> {
             max_visible,
             selected,
             show_label,
+            scrollbar,
+            max_selected,
         ),
         _ => registry_dispatch_mismatch("select_list"),
     }
@@ -1485,6 +1798,40 @@ fn compile_color_input_widget(def: WidgetDef) -> Result<Node, String> {
     }
 }
 
+fn compile_date_input_widget(def: WidgetDef) -> Result<Node, String> {
+    match def {
+        WidgetDef::DateInput(model::DateInputDef {
+            id,
+            label,
+            default,
+            min,
+            max,
+            format,
+            locale,
+            required,
+            validators,
+            ..
+        }) => inputs::compile_date_input(
+            id, label, default, min, max, format, locale, required, validators,
+        ),
+        _ => registry_dispatch_mismatch("date_input"),
+    }
+}
+
+fn compile_duration_input_widget(def: WidgetDef) -> Result<Node, String> {
+    match def {
+        WidgetDef::DurationInput(model::DurationInputDef {
+            id,
+            label,
+            default,
+            required,
+            validators,
+            ..
+        }) => inputs::compile_duration_input(id, label, default, required, validators),
+        _ => registry_dispatch_mismatch("duration_input"),
+    }
+}
+
 fn compile_confirm_input_widget(def: WidgetDef) -> Result<Node, String> {
     match def {
         WidgetDef::ConfirmInput(model::ConfirmInputDef {
@@ -1532,15 +1879,28 @@ fn compile_textarea_widget(def: WidgetDef) -> Result<Node, String> {
     match def {
         WidgetDef::Textarea(model::TextareaDef {
             id,
+            label,
             min_height,
             max_height,
             default,
+            soft_wrap,
+            line_numbers,
+            newline_on_enter,
             required,
             validators,
             ..
-        }) => {
-            components::compile_textarea(id, min_height, max_height, default, required, validators)
-        }
+        }) => components::compile_textarea(
+            id,
+            label,
+            min_height,
+            max_height,
+            default,
+            soft_wrap,
+            line_numbers,
+            newline_on_enter,
+            required,
+            validators,
+        ),
         _ => registry_dispatch_mismatch("textarea"),
     }
 }
@@ -1556,6 +1916,8 @@ fn compile_command_runner_widget(def: WidgetDef) -> Result<Node, String> {
             visible_lines,
             spinner_style,
             timeout_ms,
+            require_success,
+            keep_output,
             commands,
             ..
         }) => components::compile_command_runner(
@@ -1567,6 +1929,8 @@ fn compile_command_runner_widget(def: WidgetDef) -> Result<Node, String> {
             visible_lines,
             spinner_style,
             timeout_ms,
+            require_success,
+            keep_output,
             commands,
         ),
         _ => registry_dispatch_mismatch("command_runner"),
@@ -1587,6 +1951,7 @@ fn compile_file_browser_widget(def: WidgetDef) -> Result<Node, String> {
             recursive,
             hide_hidden,
             ext_filter,
+            file_ops,
             max_visible,
             required,
             validators,
@@ -1603,6 +1968,7 @@ fn compile_file_browser_widget(def: WidgetDef) -> Result<Node, String> {
             recursive,
             hide_hidden,
             ext_filter,
+            file_ops,
             max_visible,
             required,
             validators,
@@ -1620,10 +1986,21 @@ fn compile_tree_view_widget(def: WidgetDef) -> Result<Node, String> {
             max_visible,
             show_label,
             indent_guides,
+            scrollbar,
+            animate,
+            checkboxes,
             ..
-        }) => {
-            components::compile_tree_view(id, label, nodes, max_visible, show_label, indent_guides)
-        }
+        }) => components::compile_tree_view(
+            id,
+            label,
+            nodes,
+            max_visible,
+            show_label,
+            indent_guides,
+            scrollbar,
+            animate,
+            checkboxes,
+        ),
         _ => registry_dispatch_mismatch("tree_view"),
     }
 }
@@ -1635,8 +2012,10 @@ fn compile_object_editor_widget(def: WidgetDef) -> Result<Node, String> {
             label,
             default,
             max_visible,
+            yank_to,
+            schema,
             ..
-        }) => components::compile_object_editor(id, label, default, max_visible),
+        }) => components::compile_object_editor(id, label, default, max_visible, yank_to, schema),
         _ => registry_dispatch_mismatch("object_editor"),
     }
 }
@@ -1662,9 +2041,18 @@ fn compile_table_widget(def: WidgetDef) -> Result<Node, String> {
             style,
             row_numbers,
             initial_rows,
+            max_visible_rows,
             columns,
             ..
-        }) => components::compile_table(id, label, style, row_numbers, initial_rows, columns),
+        }) => components::compile_table(
+            id,
+            label,
+            style,
+            row_numbers,
+            initial_rows,
+            max_visible_rows,
+            columns,
+        ),
         _ => registry_dispatch_mismatch("table"),
     }
 }
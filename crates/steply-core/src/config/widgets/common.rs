@@ -6,6 +6,8 @@ use crate::widgets::inputs::button::ButtonInput;
 use crate::widgets::inputs::checkbox::CheckboxInput;
 use crate::widgets::inputs::choice::ChoiceInput;
 use crate::widgets::inputs::color::ColorInput;
+use crate::widgets::inputs::date_input::DateInput;
+use crate::widgets::inputs::duration::DurationInput;
 use crate::widgets::inputs::masked::MaskedInput;
 use crate::widgets::inputs::select::SelectInput;
 use crate::widgets::inputs::slider::SliderInput;
@@ -67,6 +69,18 @@ impl SupportsValidator for ColorInput {
     }
 }
 
+impl SupportsValidator for DateInput {
+    fn with_runtime_validator(self, validator: validators::Validator) -> Self {
+        self.with_validator(validator)
+    }
+}
+
+impl SupportsValidator for DurationInput {
+    fn with_runtime_validator(self, validator: validators::Validator) -> Self {
+        self.with_validator(validator)
+    }
+}
+
 impl SupportsValidator for CheckboxInput {
     fn with_runtime_validator(self, validator: validators::Validator) -> Self {
         self.with_validator(validator)
@@ -5,7 +5,7 @@ use crate::widgets::{
         calendar::Calendar,
         command_runner::CommandRunner,
         file_browser::FileBrowserInput,
-        object_editor::ObjectEditor,
+        object_editor::{ObjectEditor, ObjectSchema, SchemaType},
         repeater::Repeater,
         select_list::{SelectItem, SelectList},
         snippet::Snippet,
@@ -17,10 +17,10 @@ use crate::widgets::{
     shared::binding::ReadBinding,
 };
 
-use super::super::binding_compile::compile_read_binding_value;
+use super::super::binding_compile::{compile_read_binding_value, parse_selector};
 use super::super::model::{
-    CommandRunnerCommandDef, SelectListOptionDef, TableColumnDef, TreeNodeDef, ValidatorDef,
-    WidgetDef,
+    CommandRunnerCommandDef, ObjectSchemaDef, SchemaTypesDef, SelectListOptionDef, TableColumnDef,
+    TreeNodeDef, ValidatorDef, WidgetDef,
 };
 use super::super::parse::{
     parse_browser_mode, parse_calendar_mode, parse_display_mode, parse_file_browser_entry_filter,
@@ -41,6 +41,8 @@ pub(super) fn compile_select_list(
     max_visible: Option<usize>,
     selected: Vec<usize>,
     show_label: Option<bool>,
+    scrollbar: bool,
+    max_selected: Option<usize>,
 ) -> Result<Node, String> {
     let select_mode = parse_select_mode(mode.as_deref())?;
     let items = options
@@ -52,17 +54,22 @@ pub(super) fn compile_select_list(
                 title,
                 description,
             } => SelectItem::detailed(value, title, description),
+            SelectListOptionDef::Group { group } => SelectItem::group(group),
         })
         .collect::<Vec<_>>();
     let mut widget = SelectList::new(id, label, items)
         .with_mode(select_mode)
-        .with_selected(selected);
+        .with_selected(selected)
+        .with_scrollbar(scrollbar);
     if let Some(max_visible) = max_visible {
         widget = widget.with_max_visible(max_visible);
     }
     if let Some(show_label) = show_label {
         widget = widget.with_show_label(show_label);
     }
+    if let Some(max_selected) = max_selected {
+        widget = widget.with_max_selected(max_selected);
+    }
     Ok(Node::Component(Box::new(widget)))
 }
 
@@ -78,15 +85,26 @@ pub(super) fn compile_calendar(
     Ok(Node::Component(Box::new(widget)))
 }
 
+#[allow(clippy::too_many_arguments)]
 pub(super) fn compile_textarea(
     id: String,
+    label: Option<String>,
     min_height: Option<usize>,
     max_height: Option<usize>,
     default: Option<String>,
+    soft_wrap: Option<bool>,
+    line_numbers: Option<bool>,
+    newline_on_enter: Option<bool>,
     required: Option<bool>,
     extra_validators: Vec<ValidatorDef>,
 ) -> Result<Node, String> {
-    let mut widget = TextAreaComponent::new(id);
+    let mut widget = TextAreaComponent::new(id)
+        .with_soft_wrap(soft_wrap.unwrap_or(false))
+        .with_line_numbers(line_numbers.unwrap_or(true))
+        .with_newline_on_enter(newline_on_enter.unwrap_or(false));
+    if let Some(label) = label {
+        widget = widget.with_label(label);
+    }
     if let Some(min_height) = min_height {
         widget = widget.with_min_height(min_height);
     }
@@ -110,12 +128,16 @@ pub(super) fn compile_command_runner(
     visible_lines: Option<usize>,
     spinner_style: Option<String>,
     timeout_ms: Option<u64>,
+    require_success: Option<bool>,
+    keep_output: Option<bool>,
     commands: Vec<CommandRunnerCommandDef>,
 ) -> Result<Node, String> {
     let mut runner = CommandRunner::new(id, label)
         .with_run_mode(parse_run_mode(run_mode.as_deref())?)
         .with_on_error(parse_on_error(on_error.as_deref())?)
-        .with_advance_on_success(advance_on_success.unwrap_or(false));
+        .with_advance_on_success(advance_on_success.unwrap_or(false))
+        .with_require_success(require_success.unwrap_or(false))
+        .with_keep_output(keep_output.unwrap_or(false));
     for command in commands {
         let reads = command
             .reads
@@ -149,6 +171,7 @@ pub(super) fn compile_file_browser(
     recursive: Option<bool>,
     hide_hidden: Option<bool>,
     ext_filter: Vec<String>,
+    file_ops: Option<bool>,
     max_visible: Option<usize>,
     required: Option<bool>,
     extra_validators: Vec<ValidatorDef>,
@@ -179,6 +202,9 @@ pub(super) fn compile_file_browser(
         let refs = ext_filter.iter().map(String::as_str).collect::<Vec<_>>();
         widget = widget.with_ext_filter(refs.as_slice());
     }
+    if let Some(file_ops) = file_ops {
+        widget = widget.with_file_ops(file_ops);
+    }
     if let Some(max_visible) = max_visible {
         widget = widget.with_max_visible(max_visible);
     }
@@ -195,6 +221,9 @@ pub(super) fn compile_tree_view(
     max_visible: Option<usize>,
     show_label: Option<bool>,
     indent_guides: Option<bool>,
+    scrollbar: bool,
+    animate: bool,
+    checkboxes: bool,
 ) -> Result<Node, String> {
     let mut tree_nodes = Vec::with_capacity(nodes.len());
     for node in nodes {
@@ -202,9 +231,15 @@ pub(super) fn compile_tree_view(
         if node.expanded.unwrap_or(false) {
             item = item.expanded();
         }
+        if node.checked {
+            item = item.checked();
+        }
         tree_nodes.push(item);
     }
-    let mut widget = TreeView::new(id, label, tree_nodes);
+    let mut widget = TreeView::new(id, label, tree_nodes)
+        .with_scrollbar(scrollbar)
+        .with_animation(animate)
+        .with_checkboxes(checkboxes);
     if let Some(max_visible) = max_visible {
         widget = widget.with_max_visible(max_visible);
     }
@@ -222,6 +257,8 @@ pub(super) fn compile_object_editor(
     label: String,
     default: Option<serde_yaml::Value>,
     max_visible: Option<usize>,
+    yank_to: Option<String>,
+    schema: Option<ObjectSchemaDef>,
 ) -> Result<Node, String> {
     let mut widget = ObjectEditor::new(id, label);
     if let Some(default) = default {
@@ -230,9 +267,57 @@ pub(super) fn compile_object_editor(
     if let Some(max_visible) = max_visible {
         widget = widget.with_max_visible(max_visible);
     }
+    if let Some(selector) = yank_to {
+        widget = widget.with_yank_target(parse_selector(selector.as_str())?);
+    }
+    if let Some(schema) = schema {
+        widget = widget.with_schema(compile_object_schema(schema)?);
+    }
     Ok(Node::Component(Box::new(widget)))
 }
 
+fn compile_object_schema(def: ObjectSchemaDef) -> Result<ObjectSchema, String> {
+    let mut schema = ObjectSchema::new();
+    let kinds = match def.kind {
+        Some(SchemaTypesDef::One(kind)) => vec![kind],
+        Some(SchemaTypesDef::Many(kinds)) => kinds,
+        None => Vec::new(),
+    };
+    for kind in kinds {
+        let parsed = SchemaType::parse(kind.as_str())
+            .ok_or_else(|| format!("unknown schema type `{kind}`"))?;
+        schema = schema.with_type(parsed);
+    }
+    if !def.choices.is_empty() {
+        let choices = def
+            .choices
+            .iter()
+            .map(yaml_value_to_value)
+            .collect::<Result<Vec<_>, _>>()?;
+        schema = schema.with_choices(choices);
+    }
+    let mut fields = def.fields;
+    for key in &def.required {
+        fields.entry(key.clone()).or_default();
+    }
+    for (key, field) in fields {
+        let required = def.required.contains(&key);
+        let field = compile_object_schema(field)?;
+        schema = if required {
+            schema.with_required_field(key, field)
+        } else {
+            schema.with_field(key, field)
+        };
+    }
+    if def.closed {
+        schema = schema.closed();
+    }
+    if let Some(items) = def.items {
+        schema = schema.with_items(compile_object_schema(*items)?);
+    }
+    Ok(schema)
+}
+
 pub(super) fn compile_snippet(
     id: String,
     label: String,
@@ -256,12 +341,16 @@ pub(super) fn compile_table(
     style: Option<String>,
     row_numbers: Option<bool>,
     initial_rows: Option<usize>,
+    max_visible_rows: Option<usize>,
     columns: Vec<TableColumnDef>,
 ) -> Result<Node, String> {
     let mut widget = Table::new(id, label).with_style(parse_table_style(style.as_deref())?);
     if let Some(row_numbers) = row_numbers {
         widget = widget.with_row_numbers(row_numbers);
     }
+    if let Some(max_visible_rows) = max_visible_rows {
+        widget = widget.with_max_visible_rows(max_visible_rows);
+    }
     for column in columns {
         let cell_factory = compile_table_embedded_factory(column.widget)?;
         widget = widget.column_boxed(column.header, cell_factory);
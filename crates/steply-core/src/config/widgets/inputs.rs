@@ -2,15 +2,18 @@ use crate::core::value::Value;
 use crate::widgets::{
     inputs::{
         array::ArrayInput, button::ButtonInput, checkbox::CheckboxInput, choice::ChoiceInput,
-        color::ColorInput, confirm::ConfirmInput, masked::MaskedInput, select::SelectInput,
-        slider::SliderInput, text::TextInput,
+        color::ColorInput, confirm::ConfirmInput, date_input::DateInput, duration::DurationInput,
+        masked::MaskedInput, select::SelectInput, slider::SliderInput, text::TextInput,
     },
     node::Node,
     validators,
 };
 
-use super::super::model::{ConfirmModeDef, ValidatorDef};
-use super::super::parse::{compile_validators, parse_confirm_mode, parse_text_mode};
+use super::super::model::{ConfirmModeDef, DurationValueDef, SecretDef, ValidatorDef};
+use super::super::parse::{
+    compile_validators, parse_confirm_mode, parse_date_pattern, parse_duration_value,
+    parse_iso_date, parse_secret, parse_text_mode,
+};
 use super::common::with_required_and_validators;
 
 #[allow(clippy::too_many_arguments)]
@@ -23,6 +26,7 @@ pub(super) fn compile_text_input(
     required: Option<bool>,
     extra_validators: Vec<ValidatorDef>,
     completion_items: Vec<String>,
+    secret: Option<SecretDef>,
 ) -> Result<Node, String> {
     let mut input = TextInput::new(id, label)
         .with_mode(parse_text_mode(mode.as_deref())?)
@@ -33,6 +37,9 @@ pub(super) fn compile_text_input(
     if let Some(default) = default {
         input = input.with_default(Value::Text(default));
     }
+    if let Some(secret) = secret {
+        input = input.with_secret(parse_secret(secret)?);
+    }
     input = with_required_and_validators(input, required, extra_validators);
     Ok(Node::Input(Box::new(input)))
 }
@@ -169,6 +176,53 @@ pub(super) fn compile_color_input(
     Ok(Node::Input(Box::new(widget)))
 }
 
+#[allow(clippy::too_many_arguments)]
+pub(super) fn compile_date_input(
+    id: String,
+    label: String,
+    default: Option<String>,
+    min: Option<String>,
+    max: Option<String>,
+    format: Option<String>,
+    locale: Option<String>,
+    required: Option<bool>,
+    extra_validators: Vec<ValidatorDef>,
+) -> Result<Node, String> {
+    let mut widget = DateInput::new(id, label);
+    if let Some(format) = format {
+        widget = widget.with_format(parse_date_pattern(format.as_str())?);
+    } else if let Some(locale) = locale {
+        widget = widget.with_locale(locale.as_str());
+    }
+    if let Some(min) = min {
+        widget = widget.with_min(parse_iso_date("min", min.as_str())?);
+    }
+    if let Some(max) = max {
+        widget = widget.with_max(parse_iso_date("max", max.as_str())?);
+    }
+    if let Some(default) = default {
+        parse_iso_date("default", default.as_str())?;
+        widget = widget.with_default(Value::Text(default));
+    }
+    widget = with_required_and_validators(widget, required, extra_validators);
+    Ok(Node::Input(Box::new(widget)))
+}
+
+pub(super) fn compile_duration_input(
+    id: String,
+    label: String,
+    default: Option<DurationValueDef>,
+    required: Option<bool>,
+    extra_validators: Vec<ValidatorDef>,
+) -> Result<Node, String> {
+    let mut widget = DurationInput::new(id, label);
+    if let Some(default) = default {
+        widget = widget.with_seconds(parse_duration_value(default)?);
+    }
+    widget = with_required_and_validators(widget, required, extra_validators);
+    Ok(Node::Input(Box::new(widget)))
+}
+
 pub(super) fn compile_confirm_input(
     id: String,
     label: String,
@@ -4,8 +4,11 @@ use crate::widgets::{
         chart::ChartOutput,
         data::{DataOutput, DataOutputFormat},
         diff::DiffOutput,
+        log::LogOutput,
         progress::ProgressOutput,
+        spinner::SpinnerOutput,
         table::TableOutput,
+        task_graph::TaskGraphOutput,
         task_log::{TaskLog, TaskLogStep},
         text::TextOutput,
         thinking::ThinkingOutput,
@@ -84,6 +87,7 @@ pub(super) fn compile_progress_output(
     bar_width: Option<usize>,
     style: Option<String>,
     transition: Option<ProgressTransitionDef>,
+    task: Option<String>,
 ) -> Result<Node, String> {
     let mut output =
         ProgressOutput::new(id, label).with_style(parse_progress_style(style.as_deref())?);
@@ -99,6 +103,9 @@ pub(super) fn compile_progress_output(
     if let Some(transition) = transition {
         output = output.with_transition(parse_progress_transition(transition)?);
     }
+    if let Some(task) = task {
+        output = output.with_task(task);
+    }
     Ok(Node::Output(Box::new(output)))
 }
 
@@ -112,8 +119,16 @@ pub(super) fn compile_chart_output(
     max: Option<f64>,
     unit: Option<String>,
     gradient: Option<bool>,
+    height: Option<usize>,
+    axis: Option<bool>,
 ) -> Result<Node, String> {
     let mut output = ChartOutput::new(id, label).with_mode(parse_chart_mode(mode.as_deref())?);
+    if let Some(height) = height {
+        output = output.with_height(height);
+    }
+    if let Some(axis) = axis {
+        output = output.with_axis(axis);
+    }
     if let Some(capacity) = capacity {
         output = output.with_capacity(capacity);
     }
@@ -144,31 +159,92 @@ pub(super) fn compile_table_output(
     )))
 }
 
+#[allow(clippy::too_many_arguments)]
 pub(super) fn compile_diff_output(
     id: String,
     label: String,
     old: String,
     new: String,
     max_visible: Option<usize>,
+    scrollbar: bool,
+    unified: bool,
+    review: bool,
 ) -> Result<Node, String> {
-    let mut output = DiffOutput::new(id, label, old, new);
+    let mut output = DiffOutput::new(id, label, old, new)
+        .with_scrollbar(scrollbar)
+        .with_unified(unified)
+        .with_review(review);
     if let Some(max_visible) = max_visible {
         output = output.with_max_visible(max_visible);
     }
     Ok(Node::Component(Box::new(output)))
 }
 
+pub(super) fn compile_spinner_output(
+    id: String,
+    label: String,
+    task: Option<String>,
+    spinner_style: Option<String>,
+) -> Result<Node, String> {
+    let mut output = SpinnerOutput::new(id, label);
+    if let Some(spinner_style) = spinner_style {
+        output = output.with_spinner_style(parse_spinner_style(spinner_style.as_str())?);
+    }
+    if let Some(task) = task {
+        output = output.with_task(task);
+    }
+    Ok(Node::Output(Box::new(output)))
+}
+
+pub(super) fn compile_log_output(
+    id: String,
+    label: String,
+    tasks: Vec<String>,
+    visible_lines: Option<usize>,
+    history_lines: Option<usize>,
+    follow: Option<bool>,
+    animate: bool,
+) -> Result<Node, String> {
+    let mut output = LogOutput::new(id, label)
+        .with_follow(follow.unwrap_or(true))
+        .with_animation(animate);
+    for task_id in tasks {
+        output = output.with_task(task_id);
+    }
+    if let Some(visible_lines) = visible_lines {
+        output = output.with_visible_lines(visible_lines);
+    }
+    if let Some(history_lines) = history_lines {
+        output = output.with_history_lines(history_lines);
+    }
+    Ok(Node::Component(Box::new(output)))
+}
+
+pub(super) fn compile_task_graph_output(
+    id: String,
+    label: Option<String>,
+    tasks: Vec<String>,
+    spinner_style: Option<String>,
+) -> Result<Node, String> {
+    let mut output = TaskGraphOutput::new(id, label.unwrap_or_default()).with_tasks(tasks);
+    if let Some(spinner_style) = spinner_style {
+        output = output.with_spinner_style(parse_spinner_style(spinner_style.as_str())?);
+    }
+    Ok(Node::Output(Box::new(output)))
+}
+
 pub(super) fn compile_task_log_output(
     id: String,
     visible_lines: Option<usize>,
     spinner_style: Option<String>,
     steps: Vec<TaskLogStepDef>,
+    scrollbar: bool,
 ) -> Result<Node, String> {
     let step_defs = steps
         .into_iter()
         .map(|step| TaskLogStep::new(step.label, step.task_id))
         .collect::<Vec<_>>();
-    let mut output = TaskLog::new(id, step_defs);
+    let mut output = TaskLog::new(id, step_defs).with_scrollbar(scrollbar);
     if let Some(visible_lines) = visible_lines {
         output = output.with_visible_lines(visible_lines);
     }
@@ -0,0 +1,202 @@
+use crate::runtime::key_bindings::{KeyBinding, KeyBindings};
+use crate::terminal::{KeyCode, KeyEvent, KeyModifiers};
+use std::collections::HashMap;
+
+/// Navigation action widgets understand, delivered to them as its canonical key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    Up,
+    Down,
+    Left,
+    Right,
+    PageUp,
+    PageDown,
+    Home,
+    End,
+    Confirm,
+    Cancel,
+}
+
+impl Action {
+    pub fn parse(raw: &str) -> Option<Self> {
+        match raw.trim().to_ascii_lowercase().as_str() {
+            "up" => Some(Self::Up),
+            "down" => Some(Self::Down),
+            "left" => Some(Self::Left),
+            "right" => Some(Self::Right),
+            "page_up" => Some(Self::PageUp),
+            "page_down" => Some(Self::PageDown),
+            "home" => Some(Self::Home),
+            "end" => Some(Self::End),
+            "confirm" => Some(Self::Confirm),
+            "cancel" => Some(Self::Cancel),
+            _ => None,
+        }
+    }
+
+    pub fn key_event(self) -> KeyEvent {
+        let code = match self {
+            Self::Up => KeyCode::Up,
+            Self::Down => KeyCode::Down,
+            Self::Left => KeyCode::Left,
+            Self::Right => KeyCode::Right,
+            Self::PageUp => KeyCode::PageUp,
+            Self::PageDown => KeyCode::PageDown,
+            Self::Home => KeyCode::Home,
+            Self::End => KeyCode::End,
+            Self::Confirm => KeyCode::Enter,
+            Self::Cancel => KeyCode::Esc,
+        };
+        KeyEvent {
+            code,
+            modifiers: KeyModifiers::NONE,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KeymapPreset {
+    /// Arrow keys, Home/End and PageUp/PageDown only.
+    #[default]
+    Default,
+    /// `h` `j` `k` `l`, `g`/`G` and Ctrl+U/Ctrl+D outside text fields.
+    Vim,
+    /// Ctrl+N/P/F/B, Ctrl+A/E, Ctrl+V/Alt+V and Ctrl+G everywhere, except
+    /// keys already taken by a global shortcut (Ctrl+P toggles the preview).
+    Emacs,
+}
+
+impl KeymapPreset {
+    pub fn parse(raw: &str) -> Option<Self> {
+        match raw.trim().to_ascii_lowercase().as_str() {
+            "default" => Some(Self::Default),
+            "vim" => Some(Self::Vim),
+            "emacs" => Some(Self::Emacs),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct BoundAction {
+    action: Action,
+    outside_text: bool,
+}
+
+/// Translates keys into navigation actions before they reach widgets, so a
+/// remapped key behaves the same in every widget.
+#[derive(Debug, Clone, Default)]
+pub struct ActionBindings {
+    bindings: HashMap<KeyBinding, BoundAction>,
+}
+
+impl ActionBindings {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn preset(preset: KeymapPreset) -> Self {
+        let mut bindings = Self::new();
+        match preset {
+            KeymapPreset::Default => {}
+            KeymapPreset::Vim => bindings.install_vim(),
+            KeymapPreset::Emacs => bindings.install_emacs(),
+        }
+        bindings
+    }
+
+    pub fn bind(&mut self, key: KeyBinding, action: Action) {
+        self.bindings.insert(
+            key,
+            BoundAction {
+                action,
+                outside_text: false,
+            },
+        );
+    }
+
+    /// Binds `key` only while the focused widget is not editing text, so
+    /// plain letters can still be typed into inputs.
+    pub fn bind_outside_text(&mut self, key: KeyBinding, action: Action) {
+        self.bindings.insert(
+            key,
+            BoundAction {
+                action,
+                outside_text: true,
+            },
+        );
+    }
+
+    pub fn unbind(&mut self, key: &KeyBinding) {
+        self.bindings.remove(key);
+    }
+
+    pub fn keys_for(&self, action: Action) -> Vec<KeyBinding> {
+        self.bindings
+            .iter()
+            .filter(|(_, bound)| bound.action == action)
+            .map(|(key, _)| *key)
+            .collect()
+    }
+
+    pub fn resolve(&self, event: KeyEvent, editing_text: bool) -> Option<Action> {
+        let bound = self.bindings.get(&KeyBinding::from_event(event))?;
+        (!bound.outside_text || !editing_text).then_some(bound.action)
+    }
+
+    /// Returns the canonical key for a bound action, or `event` unchanged.
+    pub fn translate(&self, event: KeyEvent, editing_text: bool) -> KeyEvent {
+        self.resolve(event, editing_text)
+            .map(Action::key_event)
+            .unwrap_or(event)
+    }
+
+    fn install_vim(&mut self) {
+        for (ch, action) in [
+            ('h', Action::Left),
+            ('j', Action::Down),
+            ('k', Action::Up),
+            ('l', Action::Right),
+            ('g', Action::Home),
+        ] {
+            self.bind_outside_text(KeyBinding::key(KeyCode::Char(ch)), action);
+        }
+        self.bind_outside_text(KeyBinding::key(KeyCode::Char('G')), Action::End);
+        self.bind_outside_text(
+            KeyBinding::new(KeyCode::Char('G'), KeyModifiers::SHIFT),
+            Action::End,
+        );
+        self.bind_outside_text(KeyBinding::ctrl(KeyCode::Char('u')), Action::PageUp);
+        self.bind_outside_text(KeyBinding::ctrl(KeyCode::Char('d')), Action::PageDown);
+    }
+
+    fn install_emacs(&mut self) {
+        let globals = KeyBindings::new();
+        let ctrl = [
+            ('p', Action::Up),
+            ('n', Action::Down),
+            ('b', Action::Left),
+            ('f', Action::Right),
+            ('a', Action::Home),
+            ('e', Action::End),
+            ('v', Action::PageDown),
+            ('g', Action::Cancel),
+        ]
+        .map(|(ch, action)| (KeyBinding::ctrl(KeyCode::Char(ch)), action));
+        let alt = [(KeyBinding::alt(KeyCode::Char('v')), Action::PageUp)];
+
+        for (key, action) in ctrl.into_iter().chain(alt) {
+            let event = KeyEvent {
+                code: key.code,
+                modifiers: key.modifiers,
+            };
+            if globals.resolve(event).is_none() {
+                self.bind(key, action);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/action_bindings.rs"]
+mod tests;
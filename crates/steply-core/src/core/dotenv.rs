@@ -0,0 +1,89 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Reads `.env` files in order into one set of variables, later files
+/// overriding earlier ones. Files that do not exist are skipped, so an
+/// optional `.env.local` can be listed after a shared `.env`.
+pub fn load_files<P: AsRef<Path>>(paths: &[P]) -> Result<BTreeMap<String, String>, String> {
+    let mut vars = BTreeMap::new();
+    for path in paths {
+        let path = path.as_ref();
+        let raw = match std::fs::read_to_string(path) {
+            Ok(raw) => raw,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(err) => return Err(format!("failed to read '{}': {err}", path.display())),
+        };
+        let parsed = parse(raw.as_str()).map_err(|err| format!("{}: {err}", path.display()))?;
+        vars.extend(parsed);
+    }
+    Ok(vars)
+}
+
+/// Parses `.env` syntax: `KEY=value` lines with optional `export` prefix,
+/// `#` comments, and single- or double-quoted values. Double quotes
+/// understand `\n`, `\t`, `\"` and `\\`; single quotes are literal.
+pub fn parse(raw: &str) -> Result<Vec<(String, String)>, String> {
+    let mut vars = Vec::new();
+    for (index, line) in raw.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let line = line.strip_prefix("export ").unwrap_or(line).trim_start();
+        let Some((key, value)) = line.split_once('=') else {
+            return Err(format!("line {}: expected KEY=value", index + 1));
+        };
+        let key = key.trim();
+        if !is_valid_key(key) {
+            return Err(format!("line {}: invalid variable name '{key}'", index + 1));
+        }
+        let value =
+            parse_value(value.trim()).map_err(|err| format!("line {}: {err}", index + 1))?;
+        vars.push((key.to_string(), value));
+    }
+    Ok(vars)
+}
+
+fn parse_value(raw: &str) -> Result<String, String> {
+    if let Some(rest) = raw.strip_prefix('\'') {
+        let Some(end) = rest.find('\'') else {
+            return Err("unterminated single quote".to_string());
+        };
+        return Ok(rest[..end].to_string());
+    }
+    if let Some(rest) = raw.strip_prefix('"') {
+        let mut out = String::new();
+        let mut chars = rest.chars();
+        while let Some(ch) = chars.next() {
+            match ch {
+                '"' => return Ok(out),
+                '\\' => match chars.next() {
+                    Some('n') => out.push('\n'),
+                    Some('t') => out.push('\t'),
+                    Some(other) => out.push(other),
+                    None => break,
+                },
+                other => out.push(other),
+            }
+        }
+        return Err("unterminated double quote".to_string());
+    }
+    // Unquoted values end at an inline comment.
+    let value = match raw.find(" #") {
+        Some(pos) => &raw[..pos],
+        None => raw,
+    };
+    Ok(value.trim_end().to_string())
+}
+
+fn is_valid_key(key: &str) -> bool {
+    let mut chars = key.chars();
+    chars
+        .next()
+        .is_some_and(|first| first == '_' || first.is_ascii_alphabetic())
+        && chars.all(|ch| ch == '_' || ch.is_ascii_alphanumeric())
+}
+
+#[cfg(test)]
+#[path = "tests/dotenv.rs"]
+mod tests;
@@ -0,0 +1,297 @@
+//! The subset of [Fluent](https://projectfluent.org) syntax message catalogs
+//! are written in: messages, `-terms`, `.attributes`, multiline values,
+//! `{ $variable }`, `{ -term }`, `{ message }` and `{ "literal" }`
+//! placeables, and select expressions over a variable.
+
+/// Nesting limit for references, so a message that refers to itself
+/// renders its placeable instead of recursing forever.
+const MAX_DEPTH: usize = 8;
+
+/// Parses an `.ftl` resource into `(id, pattern)` pairs. Terms keep their
+/// leading `-`; attributes are returned as `id.attribute`.
+pub fn parse(raw: &str) -> Result<Vec<(String, String)>, String> {
+    let mut entries = Vec::new();
+    let mut current: Option<Entry> = None;
+    for (index, line) in raw.lines().enumerate() {
+        let trimmed = line.trim();
+        // A select expression may close at the start of a line.
+        let continues = line.starts_with([' ', '\t']) || trimmed.starts_with('}');
+        if !continues {
+            if let Some(entry) = current.take() {
+                entry.finish(&mut entries);
+            }
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+            let Some((id, value)) = trimmed.split_once('=') else {
+                return Err(format!("line {}: expected 'id = value'", index + 1));
+            };
+            let id = id.trim();
+            if !is_identifier(id.strip_prefix('-').unwrap_or(id)) {
+                return Err(format!("line {}: invalid message id '{id}'", index + 1));
+            }
+            current = Some(Entry::new(id.to_string(), value));
+            continue;
+        }
+        let Some(entry) = current.as_mut() else {
+            if trimmed.is_empty() {
+                continue;
+            }
+            return Err(format!(
+                "line {}: indented text outside of a message",
+                index + 1
+            ));
+        };
+        if let Some(attribute) = trimmed.strip_prefix('.')
+            && let Some((name, value)) = attribute.split_once('=')
+            && is_identifier(name.trim())
+        {
+            entry.start_attribute(name.trim(), value);
+        } else {
+            entry.push_line(line);
+        }
+    }
+    if let Some(entry) = current {
+        entry.finish(&mut entries);
+    }
+    Ok(entries)
+}
+
+/// Formats `pattern`, taking variables from `args` and message or term
+/// references from `lookup`. Placeables that cannot be resolved are left as
+/// written.
+pub fn format(
+    pattern: &str,
+    args: &dyn Fn(&str) -> Option<String>,
+    lookup: &dyn Fn(&str) -> Option<String>,
+) -> String {
+    format_at(pattern, args, lookup, 0)
+}
+
+struct Entry {
+    id: String,
+    lines: Vec<String>,
+    attributes: Vec<(String, Vec<String>)>,
+}
+
+impl Entry {
+    fn new(id: String, value: &str) -> Self {
+        Self {
+            id,
+            lines: vec![value.trim().to_string()],
+            attributes: Vec::new(),
+        }
+    }
+
+    fn start_attribute(&mut self, name: &str, value: &str) {
+        self.attributes
+            .push((name.to_string(), vec![value.trim().to_string()]));
+    }
+
+    fn push_line(&mut self, line: &str) {
+        match self.attributes.last_mut() {
+            Some((_, lines)) => lines.push(line.to_string()),
+            None => self.lines.push(line.to_string()),
+        }
+    }
+
+    fn finish(self, entries: &mut Vec<(String, String)>) {
+        let value = join_lines(self.lines);
+        if !value.is_empty() {
+            entries.push((self.id.clone(), value));
+        }
+        for (name, lines) in self.attributes {
+            entries.push((format!("{}.{name}", self.id), join_lines(lines)));
+        }
+    }
+}
+
+/// Joins a value's first line with its continuation lines, removing the
+/// indentation they share.
+fn join_lines(lines: Vec<String>) -> String {
+    let mut lines = lines.into_iter();
+    let first = lines.next().unwrap_or_default();
+    let rest = lines.collect::<Vec<_>>();
+    let indent = rest
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    let mut out = first;
+    for line in rest {
+        if !out.is_empty() {
+            out.push('\n');
+        }
+        out.push_str(line.get(indent..).unwrap_or("").trim_end());
+    }
+    out.trim().to_string()
+}
+
+fn format_at(
+    pattern: &str,
+    args: &dyn Fn(&str) -> Option<String>,
+    lookup: &dyn Fn(&str) -> Option<String>,
+    depth: usize,
+) -> String {
+    let mut out = String::with_capacity(pattern.len());
+    let mut rest = pattern;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let Some(len) = placeable_len(&rest[start..]) else {
+            out.push_str(&rest[start..]);
+            return out;
+        };
+        let source = &rest[start..start + len];
+        let expression = &source[1..source.len() - 1];
+        match resolve(expression, args, lookup, depth) {
+            Some(value) => out.push_str(value.as_str()),
+            None => out.push_str(source),
+        }
+        rest = &rest[start + len..];
+    }
+    out.push_str(rest);
+    out
+}
+
+fn resolve(
+    expression: &str,
+    args: &dyn Fn(&str) -> Option<String>,
+    lookup: &dyn Fn(&str) -> Option<String>,
+    depth: usize,
+) -> Option<String> {
+    if depth >= MAX_DEPTH {
+        return None;
+    }
+    if let Some((selector, variants)) = split_select(expression) {
+        let selector = resolve(selector.trim(), args, lookup, depth)?;
+        let variant = select_variant(variants, selector.as_str())?;
+        return Some(format_at(variant.as_str(), args, lookup, depth + 1));
+    }
+    let expression = expression.trim();
+    if let Some(name) = expression.strip_prefix('$') {
+        return args(name);
+    }
+    if let Some(literal) = expression
+        .strip_prefix('"')
+        .and_then(|rest| rest.strip_suffix('"'))
+    {
+        return Some(literal.replace("\\\"", "\"").replace("\\\\", "\\"));
+    }
+    if expression.parse::<f64>().is_ok() {
+        return Some(expression.to_string());
+    }
+    let pattern = lookup(expression)?;
+    Some(format_at(pattern.as_str(), args, lookup, depth + 1))
+}
+
+/// Splits `$count -> [one] ... *[other] ...` into its selector and variants.
+fn split_select(expression: &str) -> Option<(&str, &str)> {
+    let arrow = top_level_find(expression, "->")?;
+    Some((&expression[..arrow], &expression[arrow + 2..]))
+}
+
+/// Picks the variant whose key equals `selector`, then `one` for a count of
+/// one, then the `*` default.
+fn select_variant(variants: &str, selector: &str) -> Option<String> {
+    let mut parsed = Vec::<(bool, &str, String)>::new();
+    let mut rest = variants.trim_start();
+    while !rest.is_empty() {
+        let default = rest.starts_with('*');
+        let body = rest.strip_prefix('*').unwrap_or(rest).strip_prefix('[')?;
+        let close = body.find(']')?;
+        let key = body[..close].trim();
+        let value = &body[close + 1..];
+        let end = next_variant(value);
+        parsed.push((default, key, join_lines(vec![value[..end].to_string()])));
+        rest = value[end..].trim_start();
+    }
+    let plural_one = selector.parse::<f64>().is_ok_and(|n| n == 1.0);
+    parsed
+        .iter()
+        .find(|(_, key, _)| *key == selector)
+        .or_else(|| {
+            plural_one
+                .then(|| parsed.iter().find(|(_, key, _)| *key == "one"))
+                .flatten()
+        })
+        .or_else(|| parsed.iter().find(|(default, _, _)| *default))
+        .map(|(_, _, value)| value.clone())
+}
+
+/// Byte offset where the next variant key starts: a `[` or `*[` beginning a
+/// line, outside of any placeable.
+fn next_variant(value: &str) -> usize {
+    let mut depth = 0usize;
+    let mut line_start = false;
+    for (index, ch) in value.char_indices() {
+        match ch {
+            '{' => depth += 1,
+            '}' => depth = depth.saturating_sub(1),
+            '\n' => {
+                line_start = true;
+                continue;
+            }
+            '[' | '*' if depth == 0 && line_start => return index,
+            ch if ch.is_whitespace() => continue,
+            _ => {}
+        }
+        line_start = false;
+    }
+    value.len()
+}
+
+/// Length of the `{ ... }` placeable at the start of `text`, counting nested
+/// placeables and skipping braces inside string literals.
+fn placeable_len(text: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+    for (index, ch) in text.char_indices() {
+        if in_string {
+            match ch {
+                '\\' if !escaped => escaped = true,
+                '"' if !escaped => in_string = false,
+                _ => escaped = false,
+            }
+            continue;
+        }
+        match ch {
+            '"' => in_string = true,
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(index + 1);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+fn top_level_find(text: &str, needle: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    for (index, ch) in text.char_indices() {
+        match ch {
+            '{' => depth += 1,
+            '}' => depth = depth.saturating_sub(1),
+            _ if depth == 0 && text[index..].starts_with(needle) => return Some(index),
+            _ => {}
+        }
+    }
+    None
+}
+
+fn is_identifier(id: &str) -> bool {
+    let mut chars = id.chars();
+    chars
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic())
+        && chars.all(|ch| ch == '-' || ch == '_' || ch.is_ascii_alphanumeric())
+}
+
+#[cfg(test)]
+#[path = "tests/fluent.rs"]
+mod tests;
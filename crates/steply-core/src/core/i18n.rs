@@ -0,0 +1,375 @@
+use crate::core::fluent;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Ids and English text of the messages steply itself shows, in Fluent
+/// syntax. Validators and widgets refer to them by id (see
+/// [`Message::builtin`]); catalogs translate them by id, or by the English
+/// text for catalogs keyed by source text.
+///
+/// Key hint verbs are covered by rule instead of by list: a hint labelled
+/// `toggle selection` is looked up as `hint-toggle-selection` (see
+/// [`hint_message_id`]).
+pub const BUILTIN_MESSAGES: &[(&str, &str)] = &[
+    ("validation-required", "This field is required."),
+    ("validation-must-be-checked", "This field must be checked."),
+    (
+        "validation-min-length",
+        "Minimum { $min } characters required.",
+    ),
+    (
+        "validation-max-length",
+        "Maximum { $max } characters allowed.",
+    ),
+    (
+        "validation-min-selections",
+        "Select at least { $min } option(s).",
+    ),
+    (
+        "validation-max-selections",
+        "Select at most { $max } option(s).",
+    ),
+    ("validation-min-value", "Value must be at least { $min }."),
+    ("validation-max-value", "Value must be at most { $max }."),
+    (
+        "validation-summary-title",
+        "{ $count } problems need fixing",
+    ),
+    ("validation-summary-step", "Step"),
+    ("confirm-yes", "yes"),
+    ("confirm-no", "no"),
+    ("confirm-type-word", "Type \"{ $word }\" to confirm: "),
+    ("task-failed", "Task '{ $task }' failed: { $error }"),
+    ("review-edit", "Edit { $step }"),
+    ("review-confirm", "Confirm"),
+    ("announce-step", "Step { $position } of { $total }"),
+    (
+        "announce-step-prompt",
+        "Step { $position } of { $total }: { $prompt }",
+    ),
+    ("announce-edit-text", "{ $label }, edit text"),
+    ("announce-edit-text-blank", "{ $label }, edit text, blank"),
+    ("announce-value", "{ $label }, { $value }"),
+    ("announce-field-error", "Error: { $label }: { $error }"),
+    ("announce-error", "Error: { $error }"),
+    ("announce-blank", "blank"),
+    ("announce-checked", "checked"),
+    ("announce-not-checked", "not checked"),
+    ("announce-none-selected", "none selected"),
+    ("announce-entries", "{ $count } entries"),
+];
+
+/// Text shown to the user. Flow text is translated by the text itself;
+/// built-in messages keep their id and arguments and are formatted in the
+/// locale active when they are shown, so switching locales re-translates
+/// them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Message {
+    Text(String),
+    Builtin {
+        id: &'static str,
+        args: Vec<(&'static str, String)>,
+    },
+    /// `location: message`, e.g. `row 2, Port: This field is required.`
+    Located {
+        location: String,
+        message: Box<Message>,
+    },
+}
+
+impl Message {
+    /// Built-in message `id` from [`BUILTIN_MESSAGES`].
+    pub fn builtin(id: &'static str) -> Self {
+        Self::Builtin {
+            id,
+            args: Vec::new(),
+        }
+    }
+
+    pub fn with_arg(mut self, name: &'static str, value: impl ToString) -> Self {
+        if let Self::Builtin { args, .. } = &mut self {
+            args.push((name, value.to_string()));
+        }
+        self
+    }
+
+    pub fn located(self, location: impl Into<String>) -> Self {
+        Self::Located {
+            location: location.into(),
+            message: Box::new(self),
+        }
+    }
+}
+
+impl From<String> for Message {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<&str> for Message {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+
+/// The message in English, for callers without a catalog.
+impl std::fmt::Display for Message {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(MessageCatalog::default().render(self).as_ref())
+    }
+}
+
+/// Translations for one locale.
+///
+/// Message ids are the source strings themselves (or an explicit id used in
+/// their place), so a lookup that misses falls back to the original text.
+/// Built-in messages are translated by their id from [`BUILTIN_MESSAGES`],
+/// falling back to their English text.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MessageCatalog {
+    locale: String,
+    messages: HashMap<String, String>,
+}
+
+impl MessageCatalog {
+    pub fn new(locale: impl Into<String>) -> Self {
+        Self {
+            locale: locale.into(),
+            messages: HashMap::new(),
+        }
+    }
+
+    /// Reads a catalog from a Fluent (`.ftl`) resource.
+    pub fn from_ftl(locale: impl Into<String>, raw: &str) -> Result<Self, String> {
+        let mut catalog = Self::new(locale);
+        for (id, text) in fluent::parse(raw)? {
+            catalog.insert(id, text);
+        }
+        Ok(catalog)
+    }
+
+    /// The built-in messages in English, as a starting point for
+    /// translations.
+    pub fn builtin() -> Self {
+        let mut catalog = Self::new("en");
+        for (id, text) in BUILTIN_MESSAGES {
+            catalog.insert(*id, *text);
+        }
+        catalog
+    }
+
+    pub fn with_message(mut self, id: impl Into<String>, text: impl Into<String>) -> Self {
+        self.insert(id, text);
+        self
+    }
+
+    pub fn insert(&mut self, id: impl Into<String>, text: impl Into<String>) {
+        self.messages.insert(id.into(), text.into());
+    }
+
+    pub fn locale(&self) -> &str {
+        self.locale.as_str()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+
+    pub fn get(&self, id: &str) -> Option<&str> {
+        self.messages.get(id).map(String::as_str)
+    }
+
+    /// Formats message `id` with `args` filling its `{ $variable }`
+    /// placeables, or `None` when the catalog lacks it.
+    pub fn format(&self, id: &str, args: &[(&str, &str)]) -> Option<String> {
+        let pattern = self.get(id)?;
+        Some(fluent::format(pattern, &|name| arg(args, name), &|id| {
+            self.get(id).map(str::to_string)
+        }))
+    }
+
+    /// Formats built-in message `id` with `args`, in English when the
+    /// catalog translates it neither by id nor by its English text.
+    pub fn message(&self, id: &str, args: &[(&str, &str)]) -> String {
+        if let Some(text) = self.format(id, args) {
+            return text;
+        }
+        let Some((_, source)) = BUILTIN_MESSAGES.iter().find(|(builtin, _)| *builtin == id) else {
+            return id.to_string();
+        };
+        let english = fluent::format(source, &|name| arg(args, name), &|_| None);
+        self.format(english.as_str(), &[]).unwrap_or(english)
+    }
+
+    /// Translates source text by the text itself.
+    pub fn translate<'a>(&'a self, text: &'a str) -> Cow<'a, str> {
+        if self.messages.contains_key(text) {
+            return Cow::Owned(self.format(text, &[]).unwrap_or_default());
+        }
+        Cow::Borrowed(text)
+    }
+
+    /// Formats `message` in this catalog's locale.
+    pub fn render<'a>(&'a self, message: &'a Message) -> Cow<'a, str> {
+        match message {
+            Message::Text(text) => self.translate(text),
+            Message::Builtin { id, args } => {
+                let args = args
+                    .iter()
+                    .map(|(name, value)| (*name, value.as_str()))
+                    .collect::<Vec<_>>();
+                Cow::Owned(self.message(id, args.as_slice()))
+            }
+            Message::Located { location, message } => {
+                Cow::Owned(format!("{location}: {}", self.render(message)))
+            }
+        }
+    }
+
+    /// Translates a key hint's verb, trying its `hint-` id before the text.
+    pub fn translate_hint<'a>(&'a self, label: &'a str) -> Cow<'a, str> {
+        match self.format(hint_message_id(label).as_str(), &[]) {
+            Some(text) => Cow::Owned(text),
+            None => self.translate(label),
+        }
+    }
+}
+
+fn arg(args: &[(&str, &str)], name: &str) -> Option<String> {
+    args.iter()
+        .find(|(key, _)| *key == name)
+        .map(|(_, value)| value.to_string())
+}
+
+/// The message id a key hint's verb is translated by: `hint-` followed by
+/// the label in lowercase with other characters collapsed to `-`, so
+/// `choose No/Yes` becomes `hint-choose-no-yes`.
+pub fn hint_message_id(label: &str) -> String {
+    let mut id = String::from("hint");
+    for word in label
+        .split(|ch: char| !ch.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+    {
+        id.push('-');
+        id.push_str(word.to_lowercase().as_str());
+    }
+    id
+}
+
+/// The user's preferred locales from `LANGUAGE`, `LC_ALL`, `LC_MESSAGES`
+/// and `LANG`, most preferred first. The `C` and `POSIX` locales are
+/// skipped since they name no language.
+pub fn env_locales(env: impl Fn(&str) -> Option<String>) -> Vec<String> {
+    let mut locales = Vec::<String>::new();
+    let language = env("LANGUAGE").unwrap_or_default();
+    let others = ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .into_iter()
+        .filter_map(&env)
+        .collect::<Vec<_>>();
+    for locale in language.split(':').chain(others.iter().map(String::as_str)) {
+        let locale = locale.trim();
+        let bare = locale.split(['.', '@']).next().unwrap_or(locale);
+        if bare.is_empty() || bare == "C" || bare == "POSIX" {
+            continue;
+        }
+        if !locales.iter().any(|known| known == locale) {
+            locales.push(locale.to_string());
+        }
+    }
+    locales
+}
+
+/// All catalogs a flow ships with plus the active locale.
+#[derive(Debug, Clone, Default)]
+pub struct Localization {
+    catalogs: HashMap<String, Arc<MessageCatalog>>,
+    locale: Option<String>,
+    active: Arc<MessageCatalog>,
+}
+
+impl Localization {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_catalog(mut self, catalog: MessageCatalog) -> Self {
+        self.add_catalog(catalog);
+        self
+    }
+
+    /// Adds or merges a catalog; entries already present are overwritten.
+    pub fn add_catalog(&mut self, catalog: MessageCatalog) {
+        let entry = self
+            .catalogs
+            .entry(catalog.locale.clone())
+            .or_insert_with(|| Arc::new(MessageCatalog::new(catalog.locale.clone())));
+        Arc::make_mut(entry).messages.extend(catalog.messages);
+        if let Some(locale) = self.locale.clone() {
+            self.select(locale.as_str());
+        }
+    }
+
+    /// Selects `locale`, falling back from `de-AT` / `de_AT.UTF-8` to `de`.
+    /// Returns whether a catalog matched; without one, text is shown as written.
+    pub fn select(&mut self, locale: &str) -> bool {
+        self.locale = Some(locale.to_string());
+        match self.resolve(locale) {
+            Some(catalog) => {
+                self.active = catalog;
+                true
+            }
+            None => {
+                self.active = Arc::new(MessageCatalog::new(locale));
+                false
+            }
+        }
+    }
+
+    /// Selects the first of `preferred` that has a catalog, with the same
+    /// fallback as [`Self::select`]. Leaves the selection alone and returns
+    /// `None` when nothing matches.
+    pub fn negotiate<'a>(
+        &mut self,
+        preferred: impl IntoIterator<Item = &'a str>,
+    ) -> Option<String> {
+        let locale = preferred
+            .into_iter()
+            .find(|locale| self.resolve(locale).is_some())?
+            .to_string();
+        self.select(locale.as_str());
+        Some(locale)
+    }
+
+    pub fn locale(&self) -> Option<&str> {
+        self.locale.as_deref()
+    }
+
+    pub fn locales(&self) -> impl Iterator<Item = &str> {
+        self.catalogs.keys().map(String::as_str)
+    }
+
+    pub fn active(&self) -> &Arc<MessageCatalog> {
+        &self.active
+    }
+
+    pub fn translate<'a>(&'a self, text: &'a str) -> Cow<'a, str> {
+        self.active.translate(text)
+    }
+
+    fn resolve(&self, locale: &str) -> Option<Arc<MessageCatalog>> {
+        let without_encoding = locale.split('.').next().unwrap_or(locale);
+        let language = without_encoding
+            .split(['-', '_'])
+            .next()
+            .unwrap_or(without_encoding);
+        [locale, without_encoding, language]
+            .into_iter()
+            .find_map(|candidate| self.catalogs.get(candidate).cloned())
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/i18n.rs"]
+mod tests;
@@ -1,4 +1,9 @@
+pub mod action_bindings;
+pub mod dotenv;
+pub mod fluent;
+pub mod i18n;
 pub mod search;
+pub mod secrets;
 pub mod store_refs;
 pub mod value;
 pub mod value_path;
@@ -0,0 +1,91 @@
+use std::fmt;
+
+/// Where a secret input reads its default from and writes what was
+/// submitted, instead of keeping it with the other answers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SecretBackend {
+    /// The OS keychain: macOS Keychain through `security`, the Secret
+    /// Service through `secret-tool` elsewhere.
+    Keychain,
+    /// The `pass` password store, under `service/account`.
+    Pass,
+    /// An environment variable; read-only.
+    Env,
+}
+
+impl SecretBackend {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Keychain => "keychain",
+            Self::Pass => "pass",
+            Self::Env => "env",
+        }
+    }
+}
+
+/// Names one secret in a backend.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecretKey {
+    pub backend: SecretBackend,
+    pub service: String,
+    pub account: String,
+}
+
+impl SecretKey {
+    pub fn new(
+        backend: SecretBackend,
+        service: impl Into<String>,
+        account: impl Into<String>,
+    ) -> Self {
+        Self {
+            backend,
+            service: service.into(),
+            account: account.into(),
+        }
+    }
+
+    /// The entry name in `pass`: `service/account`.
+    pub fn pass_path(&self) -> String {
+        format!("{}/{}", self.service, self.account)
+    }
+
+    /// The variable the `env` backend reads: service and account upper-cased
+    /// and joined with `_`, other characters replaced by `_`, so
+    /// `github`/`deploy-token` reads `GITHUB_DEPLOY_TOKEN`.
+    pub fn env_var(&self) -> String {
+        format!("{}_{}", self.service, self.account)
+            .chars()
+            .map(|ch| {
+                if ch.is_ascii_alphanumeric() {
+                    ch.to_ascii_uppercase()
+                } else {
+                    '_'
+                }
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for SecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}/{}",
+            self.backend.as_str(),
+            self.service,
+            self.account
+        )
+    }
+}
+
+/// Reads and writes secrets for [`SecretKey`]s. The runtime ships one that
+/// talks to the real backends; embedders can supply their own.
+pub trait SecretStore {
+    /// The stored secret, or `None` when there is none yet.
+    fn read(&self, key: &SecretKey) -> Result<Option<String>, String>;
+    fn write(&self, key: &SecretKey, secret: &str) -> Result<(), String>;
+}
+
+#[cfg(test)]
+#[path = "tests/secrets.rs"]
+mod tests;
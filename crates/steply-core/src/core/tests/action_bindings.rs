@@ -0,0 +1,88 @@
+use super::{Action, ActionBindings, KeymapPreset};
+use crate::runtime::key_bindings::{KeyBinding, KeyBindings};
+use crate::terminal::{KeyCode, KeyEvent, KeyModifiers};
+
+fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+    KeyEvent { code, modifiers }
+}
+
+#[test]
+fn vim_letters_navigate_only_outside_text_fields() {
+    let bindings = ActionBindings::preset(KeymapPreset::Vim);
+    let j = key(KeyCode::Char('j'), KeyModifiers::NONE);
+
+    assert_eq!(bindings.resolve(j, false), Some(Action::Down));
+    assert_eq!(bindings.translate(j, false).code, KeyCode::Down);
+    assert_eq!(bindings.translate(j, true), j);
+}
+
+#[test]
+fn emacs_bindings_apply_while_editing_text() {
+    let bindings = ActionBindings::preset(KeymapPreset::Emacs);
+    let ctrl_n = key(KeyCode::Char('n'), KeyModifiers::CONTROL);
+
+    assert_eq!(bindings.resolve(ctrl_n, true), Some(Action::Down));
+    assert_eq!(
+        bindings.translate(key(KeyCode::Char('a'), KeyModifiers::CONTROL), true),
+        Action::Home.key_event()
+    );
+}
+
+#[test]
+fn actions_can_be_remapped_per_key() {
+    let mut bindings = ActionBindings::preset(KeymapPreset::Default);
+    let ctrl_j = KeyBinding::ctrl(KeyCode::Char('j'));
+    assert_eq!(
+        bindings.resolve(key(KeyCode::Char('j'), KeyModifiers::CONTROL), false),
+        None
+    );
+
+    bindings.bind(ctrl_j, Action::Confirm);
+    assert_eq!(bindings.keys_for(Action::Confirm), vec![ctrl_j]);
+    assert_eq!(
+        bindings.translate(key(KeyCode::Char('j'), KeyModifiers::CONTROL), true),
+        Action::Confirm.key_event()
+    );
+
+    bindings.unbind(&ctrl_j);
+    assert!(bindings.keys_for(Action::Confirm).is_empty());
+    assert_eq!(Action::parse("page_down"), Some(Action::PageDown));
+    assert_eq!(KeymapPreset::parse("Vim"), Some(KeymapPreset::Vim));
+}
+
+#[test]
+fn emacs_preset_does_not_shadow_global_shortcuts() {
+    let bindings = ActionBindings::preset(KeymapPreset::Emacs);
+    let globals = KeyBindings::new();
+    let actions = [
+        Action::Up,
+        Action::Down,
+        Action::Left,
+        Action::Right,
+        Action::PageUp,
+        Action::PageDown,
+        Action::Home,
+        Action::End,
+        Action::Confirm,
+        Action::Cancel,
+    ];
+
+    for action in actions {
+        for bound in bindings.keys_for(action) {
+            let event = key(bound.code, bound.modifiers);
+            assert!(
+                globals.resolve(event).is_none(),
+                "{bound:?} shadows a global"
+            );
+        }
+    }
+    assert!(
+        bindings
+            .resolve(key(KeyCode::Char('p'), KeyModifiers::CONTROL), true)
+            .is_none()
+    );
+    assert_eq!(
+        bindings.resolve(key(KeyCode::Char('v'), KeyModifiers::ALT), true),
+        Some(Action::PageUp)
+    );
+}
@@ -0,0 +1,49 @@
+use super::{load_files, parse};
+
+#[test]
+fn parses_comments_exports_and_quoting() {
+    let raw = r#"
+# database
+export DB_HOST=db.internal
+DB_PORT = 5432 # default port
+GREETING="hello\n\"world\""
+LITERAL='no $expansion \n here'
+EMPTY=
+"#;
+    let vars = parse(raw).expect("parse");
+    let get = |key: &str| {
+        vars.iter()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value.as_str())
+    };
+
+    assert_eq!(get("DB_HOST"), Some("db.internal"));
+    assert_eq!(get("DB_PORT"), Some("5432"));
+    assert_eq!(get("GREETING"), Some("hello\n\"world\""));
+    assert_eq!(get("LITERAL"), Some("no $expansion \\n here"));
+    assert_eq!(get("EMPTY"), Some(""));
+}
+
+#[test]
+fn reports_the_offending_line() {
+    let err = parse("OK=1\nnot a pair\n").unwrap_err();
+    assert!(err.starts_with("line 2"), "{err}");
+    assert!(parse("1BAD=x").is_err());
+    assert!(parse("OPEN=\"unterminated").is_err());
+}
+
+#[test]
+fn later_files_override_earlier_ones_and_missing_files_are_skipped() {
+    let dir = std::env::temp_dir().join(format!("steply-dotenv-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).expect("temp dir");
+    let shared = dir.join(".env");
+    let local = dir.join(".env.local");
+    std::fs::write(&shared, "REGION=eu\nTIER=small\n").expect("write shared");
+    std::fs::write(&local, "TIER=large\n").expect("write local");
+
+    let vars = load_files(&[shared, dir.join(".env.missing"), local]).expect("load");
+    let _ = std::fs::remove_dir_all(&dir);
+
+    assert_eq!(vars.get("REGION").map(String::as_str), Some("eu"));
+    assert_eq!(vars.get("TIER").map(String::as_str), Some("large"));
+}
@@ -0,0 +1,72 @@
+use super::{format, parse};
+
+fn no_args(_: &str) -> Option<String> {
+    None
+}
+
+#[test]
+fn parse_reads_messages_terms_attributes_and_multiline_values() {
+    let entries = parse(
+        "# Comment\n-brand = Steply\nwelcome = Welcome to { -brand }\n    and enjoy.\nsubmit = Submit\n    .title = Send the form\n",
+    )
+    .expect("valid ftl");
+
+    assert_eq!(
+        entries,
+        vec![
+            ("-brand".to_string(), "Steply".to_string()),
+            (
+                "welcome".to_string(),
+                "Welcome to { -brand }\nand enjoy.".to_string()
+            ),
+            ("submit".to_string(), "Submit".to_string()),
+            ("submit.title".to_string(), "Send the form".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn parse_rejects_lines_without_an_id() {
+    let err = parse("welcome = Hi\njust text\n").expect_err("missing '='");
+    assert_eq!(err, "line 2: expected 'id = value'");
+}
+
+#[test]
+fn format_fills_variables_and_references() {
+    let args = |name: &str| (name == "min").then(|| "3".to_string());
+    let lookup = |id: &str| (id == "-brand").then(|| "Steply".to_string());
+
+    assert_eq!(
+        format("{ -brand }: at least { $min } ({ \"!\" })", &args, &lookup),
+        "Steply: at least 3 (!)"
+    );
+    assert_eq!(
+        format("Missing { $other }", &args, &lookup),
+        "Missing { $other }"
+    );
+}
+
+#[test]
+fn format_picks_select_variants() {
+    let pattern = parse("items = { $count ->\n    [0] No items\n    [one] One item\n   *[other] { $count } items\n}\n")
+        .expect("valid ftl")
+        .remove(0)
+        .1;
+    let render = |count: &'static str| {
+        format(
+            pattern.as_str(),
+            &move |name: &str| (name == "count").then(|| count.to_string()),
+            &no_args,
+        )
+    };
+
+    assert_eq!(render("0"), "No items");
+    assert_eq!(render("1"), "One item");
+    assert_eq!(render("5"), "5 items");
+}
+
+#[test]
+fn format_stops_at_self_references() {
+    let lookup = |id: &str| (id == "loop").then(|| "{ loop }".to_string());
+    assert_eq!(format("{ loop }", &no_args, &lookup), "{ loop }");
+}
@@ -0,0 +1,108 @@
+use super::{Localization, Message, MessageCatalog, env_locales, hint_message_id};
+
+fn german() -> MessageCatalog {
+    MessageCatalog::new("de")
+        .with_message("Name", "Vorname")
+        .with_message("Required", "Pflichtfeld")
+}
+
+#[test]
+fn select_falls_back_to_language_catalog() {
+    let mut localization = Localization::new().with_catalog(german());
+
+    assert!(localization.select("de_AT.UTF-8"));
+    assert_eq!(localization.translate("Name"), "Vorname");
+    assert_eq!(localization.translate("Untranslated"), "Untranslated");
+}
+
+#[test]
+fn unknown_locale_shows_source_text() {
+    let mut localization = Localization::new().with_catalog(german());
+
+    assert!(!localization.select("fr"));
+    assert_eq!(localization.translate("Name"), "Name");
+}
+
+#[test]
+fn catalogs_added_after_selection_apply_to_active_locale() {
+    let mut localization = Localization::new();
+    localization.select("de");
+    localization.add_catalog(german());
+
+    assert_eq!(localization.translate("Required"), "Pflichtfeld");
+}
+
+#[test]
+fn builtin_messages_translate_by_id_with_arguments() {
+    let catalog = MessageCatalog::from_ftl(
+        "de",
+        "validation-min-length = Mindestens { $min } Zeichen erforderlich.\nconfirm-yes = ja\n",
+    )
+    .expect("valid ftl");
+    let mut localization = Localization::new().with_catalog(catalog);
+    localization.select("de");
+    let catalog = localization.active();
+    let min_length = Message::builtin("validation-min-length").with_arg("min", 3);
+
+    assert_eq!(
+        catalog.render(&min_length),
+        "Mindestens 3 Zeichen erforderlich."
+    );
+    assert_eq!(
+        catalog.render(&min_length.clone().located("row 1, Name")),
+        "row 1, Name: Mindestens 3 Zeichen erforderlich."
+    );
+    assert_eq!(catalog.render(&Message::builtin("confirm-yes")), "ja");
+    assert_eq!(catalog.render(&Message::builtin("confirm-no")), "no");
+    assert_eq!(min_length.to_string(), "Minimum 3 characters required.");
+    assert_eq!(
+        localization.translate("Minimum 3 characters required."),
+        "Minimum 3 characters required."
+    );
+}
+
+#[test]
+fn builtin_messages_fall_back_to_catalogs_keyed_by_english_text() {
+    let catalog = MessageCatalog::new("de").with_message("This field is required.", "Pflichtfeld.");
+
+    assert_eq!(catalog.message("validation-required", &[]), "Pflichtfeld.");
+    assert_eq!(
+        MessageCatalog::new("en").message("validation-max-value", &[("max", "9")]),
+        "Value must be at most 9."
+    );
+}
+
+#[test]
+fn hint_verbs_translate_by_derived_id() {
+    let catalog = MessageCatalog::new("de").with_message("hint-choose-no-yes", "Nein/Ja wählen");
+
+    assert_eq!(hint_message_id("choose No/Yes"), "hint-choose-no-yes");
+    assert_eq!(catalog.translate_hint("choose No/Yes"), "Nein/Ja wählen");
+    assert_eq!(catalog.translate_hint("confirm"), "confirm");
+}
+
+#[test]
+fn negotiate_picks_first_preferred_locale_with_a_catalog() {
+    let mut localization = Localization::new()
+        .with_catalog(german())
+        .with_catalog(MessageCatalog::new("fr").with_message("Name", "Nom"));
+
+    let chosen = localization.negotiate(["pt_BR", "fr_CA.UTF-8", "de"]);
+
+    assert_eq!(chosen.as_deref(), Some("fr_CA.UTF-8"));
+    assert_eq!(localization.translate("Name"), "Nom");
+    assert_eq!(localization.negotiate(["pt"]), None);
+    assert_eq!(localization.locale(), Some("fr_CA.UTF-8"));
+}
+
+#[test]
+fn env_locales_reads_language_list_then_locale_variables() {
+    let env = |name: &str| match name {
+        "LANGUAGE" => Some("de_AT:fr".to_string()),
+        "LC_ALL" => Some("C".to_string()),
+        "LANG" => Some("de_AT.UTF-8".to_string()),
+        _ => None,
+    };
+
+    assert_eq!(env_locales(env), vec!["de_AT", "fr", "de_AT.UTF-8"]);
+}
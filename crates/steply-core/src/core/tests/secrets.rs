@@ -0,0 +1,10 @@
+use super::{SecretBackend, SecretKey};
+
+#[test]
+fn key_names_its_entry_in_each_backend() {
+    let key = SecretKey::new(SecretBackend::Env, "github", "deploy-token");
+
+    assert_eq!(key.env_var(), "GITHUB_DEPLOY_TOKEN");
+    assert_eq!(key.pass_path(), "github/deploy-token");
+    assert_eq!(key.to_string(), "env:github/deploy-token");
+}
@@ -216,6 +216,10 @@ impl Value {
         serde_json::to_string_pretty(&self.to_serde_checked()?).map_err(|err| err.to_string())
     }
 
+    pub fn to_yaml_string(&self) -> Result<String, String> {
+        serde_yaml::to_string(&self.to_serde_checked()?).map_err(|err| err.to_string())
+    }
+
     pub fn to_json(&self) -> String {
         self.to_json_string().unwrap_or_else(|_| "null".to_string())
     }
@@ -5,6 +5,7 @@ pub mod runtime;
 pub mod state;
 pub mod task;
 pub mod terminal;
+pub mod trace;
 pub mod ui;
 pub mod widgets;
 
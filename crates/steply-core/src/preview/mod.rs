@@ -2,7 +2,9 @@ pub mod render;
 pub mod request;
 pub mod service;
 
+pub use crate::ui::frame_text::SnapshotFormat;
 pub use request::{RenderJsonRequest, RenderJsonScope};
 pub use service::{
     PreviewService, PreviewServiceInitError, PreviewServiceOptions, render_yaml_preview_json,
+    render_yaml_snapshot,
 };
@@ -2,10 +2,16 @@ use crate::preview::request::{RenderJsonRequest, RenderJsonScope};
 use crate::state::app::AppState;
 use crate::terminal::TerminalSize;
 use crate::ui::frame_json::{draw_output_to_json, frame_to_json};
+use crate::ui::frame_text::{SnapshotFormat, draw_output_to_text, frame_to_text};
 use crate::ui::render_view::RenderView;
-use crate::ui::renderer::Renderer;
+use crate::ui::renderer::{RenderFrame, Renderer};
 use crate::widgets::node::find_node;
-use crate::widgets::traits::RenderContext;
+use crate::widgets::traits::{DrawOutput, RenderContext};
+
+enum Rendered {
+    Frame(RenderFrame),
+    Widget(DrawOutput),
+}
 
 pub fn render_json(
     state: &mut AppState,
@@ -13,18 +19,47 @@ pub fn render_json(
     renderer: &mut Renderer,
     default_size: TerminalSize,
 ) -> Result<serde_json::Value, String> {
+    let size = request.terminal_size.unwrap_or(default_size);
+    Ok(match render_scope(state, request, renderer, size)? {
+        Rendered::Frame(frame) => frame_to_json(&frame, size),
+        Rendered::Widget(output) => draw_output_to_json(&output, size),
+    })
+}
+
+/// Renders the requested scope to a text snapshot for golden-file tests.
+///
+/// Uses `request.terminal_size`, or `default_size` when unset, and never the
+/// size of the attached terminal, so output is identical across machines.
+pub fn render_snapshot(
+    state: &mut AppState,
+    request: &RenderJsonRequest,
+    renderer: &mut Renderer,
+    default_size: TerminalSize,
+    format: SnapshotFormat,
+) -> Result<String, String> {
+    let size = request.terminal_size.unwrap_or(default_size);
+    Ok(match render_scope(state, request, renderer, size)? {
+        Rendered::Frame(frame) => frame_to_text(&frame, size, format),
+        Rendered::Widget(output) => draw_output_to_text(&output, size, format),
+    })
+}
+
+fn render_scope(
+    state: &mut AppState,
+    request: &RenderJsonRequest,
+    renderer: &mut Renderer,
+    size: TerminalSize,
+) -> Result<Rendered, String> {
     if let Some(step_id) = request.active_step_id.as_deref()
         && !state.set_current_step_by_id_for_preview(step_id)
     {
         return Err(format!("unknown active step id for render json: {step_id}"));
     }
 
-    let size = request.terminal_size.unwrap_or(default_size);
     match &request.scope {
         RenderJsonScope::Current | RenderJsonScope::Flow => {
             let view = RenderView::from_state(state);
-            let frame = renderer.render(&view, size);
-            Ok(frame_to_json(&frame, size))
+            Ok(Rendered::Frame(renderer.render(&view, size)))
         }
         RenderJsonScope::Step { step_id } => {
             let Some(step_index) = state.step_index_by_id(step_id.as_str()) else {
@@ -36,8 +71,7 @@ pub fn render_json(
             let Some(view) = RenderView::from_state_step(state, step_index) else {
                 return Err(format!("cannot build render view for step: {step_id}"));
             };
-            let frame = renderer.render(&view, size);
-            Ok(frame_to_json(&frame, size))
+            Ok(Rendered::Frame(renderer.render(&view, size)))
         }
         RenderJsonScope::Widget { step_id, widget_id } => {
             let Some(step_index) = state.step_index_by_id(step_id.as_str()) else {
@@ -53,8 +87,11 @@ pub fn render_json(
                 ));
             };
             let ctx = RenderContext::empty(size).with_focus(Some(widget_id.clone()));
-            let output = node.draw(&ctx);
-            Ok(draw_output_to_json(&output, size))
+            Ok(Rendered::Widget(node.draw(&ctx)))
         }
     }
 }
+
+#[cfg(test)]
+#[path = "tests/render.rs"]
+mod tests;
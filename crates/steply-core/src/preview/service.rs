@@ -1,10 +1,14 @@
 use crate::config::{ConfigLoadError, LoadedConfig, load_from_yaml_str};
-use crate::preview::render::render_json;
+use crate::core::i18n::Localization;
+use crate::preview::render::{render_json, render_snapshot};
 use crate::preview::request::RenderJsonRequest;
 use crate::state::app::AppStateInitError;
 use crate::state::flow::Flow;
+use crate::state::prefill::Prefill;
 use crate::task::TaskSpec;
 use crate::terminal::TerminalSize;
+use crate::ui::canvas::Canvas;
+use crate::ui::frame_text::SnapshotFormat;
 use crate::ui::renderer::{Renderer, RendererConfig};
 use std::error::Error;
 use std::fmt;
@@ -101,6 +105,13 @@ impl PreviewService {
             flow,
             task_specs,
             confirm_finish: true,
+            breadcrumb: false,
+            prefill: Prefill::default(),
+            idle_timeout: None,
+            max_parallel_tasks: None,
+            output: None,
+            canvas: Canvas::default(),
+            localization: Localization::default(),
         };
         Self::from_loaded_config_with_options(loaded, options)
     }
@@ -125,6 +136,20 @@ impl PreviewService {
             self.default_terminal_size,
         )
     }
+
+    pub fn render_snapshot(
+        &mut self,
+        request: &RenderJsonRequest,
+        format: SnapshotFormat,
+    ) -> Result<String, String> {
+        render_snapshot(
+            &mut self.state,
+            request,
+            &mut self.renderer,
+            self.default_terminal_size,
+            format,
+        )
+    }
 }
 
 pub fn render_yaml_preview_json(
@@ -136,3 +161,14 @@ pub fn render_yaml_preview_json(
         PreviewService::from_yaml_str_with_options(yaml, options).map_err(|err| err.to_string())?;
     service.render(request)
 }
+
+pub fn render_yaml_snapshot(
+    yaml: &str,
+    request: &RenderJsonRequest,
+    format: SnapshotFormat,
+    options: PreviewServiceOptions,
+) -> Result<String, String> {
+    let mut service =
+        PreviewService::from_yaml_str_with_options(yaml, options).map_err(|err| err.to_string())?;
+    service.render_snapshot(request, format)
+}
@@ -0,0 +1,347 @@
+use crate::preview::{
+    PreviewServiceOptions, RenderJsonRequest, RenderJsonScope, SnapshotFormat, render_yaml_snapshot,
+};
+use crate::terminal::TerminalSize;
+
+const YAML: &str = r#"
+steps:
+  - id: intro
+    title: Intro
+    widgets:
+      - type: text_input
+        id: name
+        label: Name
+        value: name
+      - type: text_output
+        id: note
+        text: A note long enough to wrap at narrow widths
+"#;
+
+fn snapshot(scope: RenderJsonScope, width: u16, format: SnapshotFormat) -> String {
+    let request = RenderJsonRequest {
+        scope,
+        active_step_id: None,
+        terminal_size: Some(TerminalSize { width, height: 10 }),
+    };
+    render_yaml_snapshot(YAML, &request, format, PreviewServiceOptions::default())
+        .expect("snapshot renders")
+}
+
+#[test]
+fn widget_snapshot_wraps_plain_text_to_requested_width() {
+    let scope = RenderJsonScope::Widget {
+        step_id: "intro".to_string(),
+        widget_id: "note".to_string(),
+    };
+
+    assert_eq!(
+        snapshot(scope, 20, SnapshotFormat::Text),
+        "A note long enough\nto wrap at narrow\nwidths\n"
+    );
+}
+
+#[test]
+fn flow_snapshot_ignores_default_size_and_trims_trailing_space() {
+    let text = snapshot(RenderJsonScope::Current, 40, SnapshotFormat::Text);
+    let request = RenderJsonRequest {
+        terminal_size: Some(TerminalSize {
+            width: 40,
+            height: 10,
+        }),
+        ..RenderJsonRequest::default()
+    };
+    let other_default = PreviewServiceOptions {
+        default_terminal_size: TerminalSize {
+            width: 7,
+            height: 3,
+        },
+        ..PreviewServiceOptions::default()
+    };
+
+    assert_eq!(
+        text,
+        "┌\n◇  Intro [intro]\n│  Name:\n│  A note long enough to wrap at narrow\n│  widths\n└\n"
+    );
+    assert_eq!(
+        render_yaml_snapshot(YAML, &request, SnapshotFormat::Text, other_default).as_deref(),
+        Ok(text.as_str())
+    );
+}
+
+#[test]
+fn ansi_snapshot_wraps_styled_spans_in_sgr_codes() {
+    let ansi = snapshot(RenderJsonScope::Current, 40, SnapshotFormat::Ansi);
+
+    assert!(ansi.contains("\x1b[36mIntro [intro]\x1b[0m"));
+    assert!(ansi.contains("\x1b[97mName: \x1b[0m"));
+}
+
+#[test]
+fn ansi_snapshot_writes_extended_attributes() {
+    let yaml = r#"
+steps:
+  - id: intro
+    title: Intro
+    widgets:
+      - type: text_input
+        id: name
+        label: Name
+        placeholder: Ada Lovelace
+        value: name
+"#;
+    let request = RenderJsonRequest {
+        scope: RenderJsonScope::Current,
+        active_step_id: None,
+        terminal_size: Some(TerminalSize {
+            width: 40,
+            height: 10,
+        }),
+    };
+    let ansi = render_yaml_snapshot(
+        yaml,
+        &request,
+        SnapshotFormat::Ansi,
+        PreviewServiceOptions::default(),
+    )
+    .expect("snapshot renders");
+
+    assert!(ansi.contains("\x1b[90;3mAda Lovelace\x1b[0m"), "{ansi:?}");
+}
+
+#[test]
+fn markup_in_labels_and_descriptions_becomes_styled_spans() {
+    let yaml = r#"
+steps:
+  - id: intro
+    title: Intro
+    description: Nothing is saved _until_ the end
+    widgets:
+      - type: text_input
+        id: name
+        label: "Your **name**"
+        value: name
+"#;
+    let request = RenderJsonRequest {
+        scope: RenderJsonScope::Current,
+        active_step_id: None,
+        terminal_size: Some(TerminalSize {
+            width: 60,
+            height: 10,
+        }),
+    };
+    let render = |format| {
+        render_yaml_snapshot(yaml, &request, format, PreviewServiceOptions::default())
+            .expect("snapshot renders")
+    };
+
+    let text = render(SnapshotFormat::Text);
+    assert!(
+        text.contains("Description: Nothing is saved until the end"),
+        "{text}"
+    );
+    assert!(text.contains("│  Your name:\n"), "{text}");
+    let ansi = render(SnapshotFormat::Ansi);
+    assert!(ansi.contains("\x1b[97;1mname\x1b[0m"), "{ansi:?}");
+    assert!(ansi.contains("\x1b[33;2muntil\x1b[0m"), "{ansi:?}");
+}
+
+#[test]
+fn footer_lists_the_focused_widget_hints_on_one_line() {
+    let yaml = r#"
+steps:
+  - id: pick
+    title: Pick
+    widgets:
+      - type: select_list
+        id: lang
+        label: Language
+        options: [Rust, Go]
+"#;
+    let request = RenderJsonRequest {
+        scope: RenderJsonScope::Current,
+        active_step_id: None,
+        terminal_size: Some(TerminalSize {
+            width: 40,
+            height: 12,
+        }),
+    };
+    let text = render_yaml_snapshot(
+        yaml,
+        &request,
+        SnapshotFormat::Text,
+        PreviewServiceOptions::default(),
+    )
+    .expect("snapshot renders");
+
+    let footer = text.lines().last().expect("footer line");
+    assert!(footer.starts_with("└  ↑ ↓ move"), "{text}");
+    assert!(footer.ends_with("more (Ctrl+h)"), "{text}");
+}
+
+#[test]
+fn canvas_caps_and_centers_the_form() {
+    let yaml = r#"
+canvas:
+  max_width: 30
+  align: center
+  padding: 2
+steps:
+  - id: intro
+    title: Intro
+    widgets:
+      - type: text_input
+        id: name
+        label: Name
+        value: name
+"#;
+    let request = RenderJsonRequest {
+        scope: RenderJsonScope::Current,
+        active_step_id: None,
+        terminal_size: Some(TerminalSize {
+            // The last column stays free, leaving 62 to place the canvas in.
+            width: 63,
+            height: 10,
+        }),
+    };
+    let text = render_yaml_snapshot(
+        yaml,
+        &request,
+        SnapshotFormat::Text,
+        PreviewServiceOptions::default(),
+    )
+    .expect("snapshot renders");
+
+    let indent = " ".repeat(17);
+    for line in text.lines().filter(|line| !line.trim().is_empty()) {
+        assert!(line.starts_with(indent.as_str()), "{text}");
+        assert!(line.chars().count() <= 17 + 30, "{text}");
+    }
+    assert!(
+        text.contains(format!("{indent}│  Name:").as_str()),
+        "{text}"
+    );
+}
+
+#[test]
+fn step_layout_places_widgets_side_by_side() {
+    let yaml = r#"
+steps:
+  - id: intro
+    title: Intro
+    layout:
+      gap: 2
+      children:
+        - size: 50%
+          widgets: [name]
+        - widgets: [preview]
+    widgets:
+      - type: text_input
+        id: name
+        label: Name
+        value: name
+      - type: text_output
+        id: preview
+        text: Preview of the answers
+      - type: text_output
+        id: footer
+        text: Footer
+"#;
+    let request = RenderJsonRequest {
+        scope: RenderJsonScope::Current,
+        active_step_id: None,
+        terminal_size: Some(TerminalSize {
+            width: 40,
+            height: 10,
+        }),
+    };
+    let text = render_yaml_snapshot(
+        yaml,
+        &request,
+        SnapshotFormat::Text,
+        PreviewServiceOptions::default(),
+    )
+    .expect("snapshot renders");
+
+    assert_eq!(
+        text,
+        "┌\n◇  Intro [intro]\n│  Name:              Preview of the\n│                     answers\n│  Footer\n└\n"
+    );
+}
+
+#[test]
+fn layout_panels_box_their_widgets() {
+    let yaml = r#"
+steps:
+  - id: intro
+    title: Intro
+    layout:
+      panel:
+        title: Account
+      widgets: [name]
+    widgets:
+      - type: text_input
+        id: name
+        label: Name
+        value: name
+"#;
+    let request = RenderJsonRequest {
+        scope: RenderJsonScope::Current,
+        active_step_id: None,
+        terminal_size: Some(TerminalSize {
+            width: 24,
+            height: 10,
+        }),
+    };
+    let text = render_yaml_snapshot(
+        yaml,
+        &request,
+        SnapshotFormat::Text,
+        PreviewServiceOptions::default(),
+    )
+    .expect("snapshot renders");
+
+    assert_eq!(
+        text,
+        "┌\n◇  Intro [intro]\n│  ┌─ Account ────────┐\n│  │ Name:            │\n│  └──────────────────┘\n└\n"
+    );
+}
+
+#[test]
+fn select_list_scrollbar_replaces_the_position_footer() {
+    let yaml = r#"
+steps:
+  - id: pick
+    title: Pick
+    widgets:
+      - type: select_list
+        id: fruit
+        label: Fruit
+        show_label: false
+        max_visible: 3
+        scrollbar: true
+        options: [apple, banana, cherry, damson, elderberry, fig]
+"#;
+    let request = RenderJsonRequest {
+        scope: RenderJsonScope::Widget {
+            step_id: "pick".to_string(),
+            widget_id: "fruit".to_string(),
+        },
+        active_step_id: None,
+        terminal_size: Some(TerminalSize {
+            width: 16,
+            height: 10,
+        }),
+    };
+    let text = render_yaml_snapshot(
+        yaml,
+        &request,
+        SnapshotFormat::Text,
+        PreviewServiceOptions::default(),
+    )
+    .expect("snapshot renders");
+
+    assert_eq!(
+        text,
+        "❯ □ apple      █\n  □ banana     █\n  □ cherry     │\n"
+    );
+}
@@ -7,4 +7,6 @@ pub enum Effect {
     System(SystemEvent),
     Schedule(SchedulerCommand),
     RequestRender,
+    /// Scrolls the screen by rows, for wheel input no widget took.
+    ScrollViewport(i32),
 }
@@ -39,16 +39,34 @@ impl ValueChange {
 
 #[derive(Debug, Clone)]
 pub enum WidgetAction {
-    ValueChanged { source: NodeId, change: ValueChange },
-    OpenUrl { url: String },
+    ValueChanged {
+        source: NodeId,
+        change: ValueChange,
+    },
+    OpenUrl {
+        url: String,
+    },
+    /// Text for the system clipboard; the runtime owns the clipboard.
+    CopyToClipboard {
+        text: String,
+    },
 
     InputDone,
     ValidateFocusedSubmit,
     ValidateFocusedSubmitAndInputDone,
     ValidateCurrentStepSubmit,
-    ValidateCurrentStepSubmitAndTaskRequest { request: TaskRequest },
-    RequestFocus { target: NodeId },
-    TaskRequested { request: TaskRequest },
+    ValidateCurrentStepSubmitAndTaskRequest {
+        request: TaskRequest,
+    },
+    RequestFocus {
+        target: NodeId,
+    },
+    JumpToStep {
+        step_id: String,
+    },
+    TaskRequested {
+        request: TaskRequest,
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -84,12 +102,20 @@ pub enum SystemEvent {
         run_id: u64,
         line: String,
     },
+    TaskProgress {
+        task_id: TaskId,
+        run_id: u64,
+        progress: f64,
+    },
     TaskLoadingStateTick {
         step_id: String,
     },
     TaskCompleted {
         completion: TaskCompletion,
     },
+    TerminalFocusChanged {
+        focused: bool,
+    },
 }
 
 #[derive(Debug, Clone)]
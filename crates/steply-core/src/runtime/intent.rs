@@ -15,11 +15,16 @@ pub enum Intent {
     PrevFocus,
     InputKey(KeyEvent),
     TextAction(TextAction),
+    Paste(String),
     OpenOverlay(NodeId),
     OpenOverlayAtIndex(usize),
     OpenOverlayShortcut,
     CloseOverlay,
     ToggleHints,
+    ToggleValuePreview,
+    ToggleTaskDetails,
+    ToggleInspector,
+    ExpandValue,
     Tick,
     Noop,
     ScrollUp,
@@ -27,6 +32,8 @@ pub enum Intent {
     ScrollPageUp,
     ScrollPageDown,
     CopySelection,
+    Suspend,
+    ToggleMetrics,
     Pointer(PointerEvent),
     PointerOn { target: NodeId, event: PointerEvent },
 }
@@ -74,10 +74,22 @@ impl KeyBindings {
 
     fn install_defaults(&mut self) {
         self.bind(KeyBinding::ctrl(KeyCode::Char('c')), Intent::Exit);
+        self.bind(KeyBinding::ctrl(KeyCode::Char('z')), Intent::Suspend);
+        self.bind(KeyBinding::alt(KeyCode::Char('m')), Intent::ToggleMetrics);
+        self.bind(KeyBinding::alt(KeyCode::Char('i')), Intent::ToggleInspector);
+        self.bind(KeyBinding::alt(KeyCode::Char('e')), Intent::ExpandValue);
         self.bind(
             KeyBinding::ctrl(KeyCode::Char('o')),
             Intent::OpenOverlayShortcut,
         );
+        self.bind(
+            KeyBinding::ctrl(KeyCode::Char('p')),
+            Intent::ToggleValuePreview,
+        );
+        self.bind(
+            KeyBinding::ctrl(KeyCode::Char('t')),
+            Intent::ToggleTaskDetails,
+        );
 
         self.bind(KeyBinding::ctrl(KeyCode::Char('/')), Intent::ToggleHints);
         self.bind(KeyBinding::ctrl(KeyCode::Char('?')), Intent::ToggleHints);
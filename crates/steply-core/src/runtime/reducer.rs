@@ -2,15 +2,40 @@ use crate::runtime::effect::Effect;
 use crate::runtime::event::SystemEvent;
 use crate::runtime::intent::Intent;
 use crate::state::app::{AppState, ExitConfirmChoice, ExitConfirmMode};
-use crate::terminal::{KeyCode, KeyEvent, KeyModifiers};
+use crate::terminal::{KeyCode, KeyEvent, KeyModifiers, PointerEvent};
 use crate::widgets::traits::InteractionResult;
 
+/// Screen rows one wheel notch scrolls when no widget under the pointer does.
+const WHEEL_SCROLL_ROWS: i32 = 3;
+
 pub struct Reducer;
 
 impl Reducer {
     pub fn reduce(state: &mut AppState, intent: Intent) -> Vec<Effect> {
+        let _span = crate::trace_span!(DEBUG, "reduce", intent = ?intent);
+        if !matches!(intent, Intent::Tick | Intent::Noop) {
+            state.note_user_activity();
+        }
+        let recorded = (state.inspector_recording()
+            && !matches!(
+                intent,
+                Intent::Tick | Intent::Noop | Intent::ToggleInspector
+            ))
+        .then(|| format!("{intent:?}"));
         let mut effects = if state.exit_confirm_active() {
             reduce_with_exit_confirm(state, intent)
+        } else if state.inspector_active() {
+            reduce_with_inspector(state, intent)
+        } else if state.task_failure_prompt_active() {
+            reduce_with_task_failure_prompt(state, intent)
+        } else if state.expanded_value_active() {
+            reduce_with_expanded_value(state, intent)
+        } else if state.value_preview_active() {
+            reduce_with_value_preview(state, intent)
+        } else if state.task_details_active() {
+            reduce_with_task_details(state, intent)
+        } else if state.validation_summary_active() {
+            reduce_with_validation_summary(state, intent)
         } else {
             match intent {
                 Intent::Exit => {
@@ -77,6 +102,7 @@ impl Reducer {
                 Intent::TextAction(action) => {
                     collect_effects(state.dispatch_text_action_to_focused(action))
                 }
+                Intent::Paste(text) => collect_effects(state.dispatch_paste_to_focused(&text)),
                 Intent::OpenOverlay(overlay_id) => {
                     vec![Effect::System(SystemEvent::OpenOverlay { overlay_id })]
                 }
@@ -99,20 +125,62 @@ impl Reducer {
                     state.toggle_hints_visibility();
                     vec![Effect::RequestRender]
                 }
-                Intent::Tick => collect_effects(state.tick_all_nodes()),
+                Intent::ToggleValuePreview => {
+                    state.open_value_preview();
+                    vec![Effect::RequestRender]
+                }
+                Intent::ToggleTaskDetails => {
+                    state.open_task_details();
+                    vec![Effect::RequestRender]
+                }
+                Intent::ToggleInspector => {
+                    if state.open_inspector() {
+                        vec![Effect::RequestRender]
+                    } else {
+                        vec![]
+                    }
+                }
+                Intent::ExpandValue => {
+                    if state.open_expanded_value() {
+                        vec![Effect::RequestRender]
+                    } else {
+                        vec![]
+                    }
+                }
+                Intent::Tick => {
+                    let mut effects = if state.terminal_focused() {
+                        collect_effects(state.tick_all_nodes())
+                    } else {
+                        vec![]
+                    };
+                    if state.tick_timers() {
+                        effects.push(Effect::RequestRender);
+                    }
+                    effects
+                }
                 Intent::Noop => vec![],
                 Intent::ScrollUp
                 | Intent::ScrollDown
                 | Intent::ScrollPageUp
                 | Intent::ScrollPageDown
                 | Intent::CopySelection
-                | Intent::Pointer(_) => vec![],
+                | Intent::Suspend
+                | Intent::ToggleMetrics => vec![],
+                Intent::Pointer(event) => wheel_fallback(event).into_iter().collect(),
                 Intent::PointerOn { target, event } => {
-                    collect_effects(state.dispatch_pointer_to_node(target.as_str(), event))
+                    let result = state.dispatch_pointer_to_node(target.as_str(), event);
+                    match wheel_fallback(event) {
+                        Some(effect) if !result.handled => vec![effect],
+                        _ => collect_effects(result),
+                    }
                 }
             }
         };
 
+        if let Some(action) = recorded {
+            state.record_inspector_entry(action);
+        }
+
         effects.extend(
             state
                 .take_pending_scheduler_commands()
@@ -127,10 +195,17 @@ impl Reducer {
 fn reduce_with_exit_confirm(state: &mut AppState, intent: Intent) -> Vec<Effect> {
     match intent {
         Intent::Exit => {
-            if state.exit_confirm_mode() == Some(ExitConfirmMode::ExitApplication) {
-                state.request_exit();
-            } else {
-                state.set_exit_confirm_choice(ExitConfirmChoice::Exit);
+            match state.exit_confirm_mode() {
+                Some(ExitConfirmMode::ExitApplication) => {
+                    state.cancel_flow();
+                }
+                Some(ExitConfirmMode::RestoreSession) => {
+                    state.cancel_exit_confirm();
+                    state.begin_exit_confirm();
+                }
+                _ => {
+                    state.set_exit_confirm_choice(ExitConfirmChoice::Exit);
+                }
             }
             vec![Effect::RequestRender]
         }
@@ -150,6 +225,9 @@ fn reduce_with_exit_confirm(state: &mut AppState, intent: Intent) -> Vec<Effect>
         | Intent::ScrollPageUp
         | Intent::ScrollPageDown
         | Intent::CopySelection
+        | Intent::Suspend
+        | Intent::ToggleMetrics
+        | Intent::Paste(_)
         | Intent::Pointer(_) => vec![],
         Intent::PointerOn { .. }
         | Intent::Back
@@ -163,9 +241,177 @@ fn reduce_with_exit_confirm(state: &mut AppState, intent: Intent) -> Vec<Effect>
         | Intent::OpenOverlay(_)
         | Intent::OpenOverlayAtIndex(_)
         | Intent::OpenOverlayShortcut
-        | Intent::CloseOverlay => {
+        | Intent::CloseOverlay
+        | Intent::ToggleValuePreview
+        | Intent::ToggleTaskDetails
+        | Intent::ToggleInspector
+        | Intent::ExpandValue => {
+            vec![Effect::RequestRender]
+        }
+    }
+}
+
+/// A failed task waits for retry, skip or abort; everything else is held
+/// until the user has chosen.
+fn reduce_with_task_failure_prompt(state: &mut AppState, intent: Intent) -> Vec<Effect> {
+    match intent {
+        Intent::Exit => {
+            state.begin_exit_confirm();
+            vec![Effect::RequestRender]
+        }
+        Intent::Submit => {
+            state.resolve_task_failure_prompt();
+            vec![Effect::RequestRender]
+        }
+        Intent::InputKey(key) => collect_effects(state.dispatch_key_to_task_failure_prompt(key)),
+        Intent::ToggleHints => {
+            state.toggle_hints_visibility();
+            vec![Effect::RequestRender]
+        }
+        Intent::Tick => collect_effects(state.tick_all_nodes()),
+        _ => vec![],
+    }
+}
+
+fn reduce_with_value_preview(state: &mut AppState, intent: Intent) -> Vec<Effect> {
+    match intent {
+        Intent::Exit => {
+            state.close_value_preview();
+            state.begin_exit_confirm();
+            vec![Effect::RequestRender]
+        }
+        Intent::Cancel | Intent::CloseOverlay | Intent::ToggleValuePreview => {
+            state.close_value_preview();
+            vec![Effect::RequestRender]
+        }
+        Intent::InputKey(KeyEvent {
+            code: KeyCode::Esc, ..
+        }) => {
+            state.close_value_preview();
+            vec![Effect::RequestRender]
+        }
+        Intent::InputKey(key) => collect_effects(state.dispatch_key_to_value_preview(key)),
+        Intent::Pointer(event) | Intent::PointerOn { event, .. } => {
+            collect_effects(state.dispatch_pointer_to_value_preview(event))
+        }
+        Intent::ToggleHints => {
+            state.toggle_hints_visibility();
             vec![Effect::RequestRender]
         }
+        Intent::Tick => collect_effects(state.tick_all_nodes()),
+        _ => vec![],
+    }
+}
+
+/// The expanded value is a read-only copy; keys only scroll it.
+fn reduce_with_expanded_value(state: &mut AppState, intent: Intent) -> Vec<Effect> {
+    match intent {
+        Intent::Exit => {
+            state.close_expanded_value();
+            state.begin_exit_confirm();
+            vec![Effect::RequestRender]
+        }
+        Intent::Cancel | Intent::CloseOverlay | Intent::ExpandValue => {
+            state.close_expanded_value();
+            vec![Effect::RequestRender]
+        }
+        Intent::InputKey(key) => collect_effects(state.dispatch_key_to_expanded_value(key)),
+        Intent::Pointer(event) | Intent::PointerOn { event, .. } => {
+            collect_effects(state.dispatch_pointer_to_expanded_value(event))
+        }
+        Intent::ToggleHints => {
+            state.toggle_hints_visibility();
+            vec![Effect::RequestRender]
+        }
+        Intent::Tick => collect_effects(state.tick_all_nodes()),
+        _ => vec![],
+    }
+}
+
+/// Task logs stay live while the view is open; the flow itself waits.
+fn reduce_with_task_details(state: &mut AppState, intent: Intent) -> Vec<Effect> {
+    match intent {
+        Intent::Exit => {
+            state.close_task_details();
+            state.begin_exit_confirm();
+            vec![Effect::RequestRender]
+        }
+        Intent::CloseOverlay | Intent::ToggleTaskDetails => {
+            state.close_task_details();
+            vec![Effect::RequestRender]
+        }
+        Intent::ToggleValuePreview => {
+            state.close_task_details();
+            state.open_value_preview();
+            vec![Effect::RequestRender]
+        }
+        Intent::Cancel => collect_effects(state.dispatch_key_to_task_details(KeyEvent {
+            code: KeyCode::Esc,
+            modifiers: KeyModifiers::NONE,
+        })),
+        Intent::InputKey(key) => collect_effects(state.dispatch_key_to_task_details(key)),
+        Intent::ToggleHints => {
+            state.toggle_hints_visibility();
+            vec![Effect::RequestRender]
+        }
+        Intent::Tick => collect_effects(state.tick_all_nodes()),
+        _ => vec![],
+    }
+}
+
+/// The summary of a failed submit; picking an entry jumps to its field.
+fn reduce_with_validation_summary(state: &mut AppState, intent: Intent) -> Vec<Effect> {
+    match intent {
+        Intent::Exit => {
+            state.close_validation_summary();
+            state.begin_exit_confirm();
+            vec![Effect::RequestRender]
+        }
+        Intent::CloseOverlay => {
+            state.close_validation_summary();
+            vec![Effect::RequestRender]
+        }
+        Intent::Submit => collect_effects(state.dispatch_key_to_validation_summary(KeyEvent {
+            code: KeyCode::Enter,
+            modifiers: KeyModifiers::NONE,
+        })),
+        Intent::Cancel => collect_effects(state.dispatch_key_to_validation_summary(KeyEvent {
+            code: KeyCode::Esc,
+            modifiers: KeyModifiers::NONE,
+        })),
+        Intent::InputKey(key) => collect_effects(state.dispatch_key_to_validation_summary(key)),
+        Intent::ToggleHints => {
+            state.toggle_hints_visibility();
+            vec![Effect::RequestRender]
+        }
+        Intent::Tick => collect_effects(state.tick_all_nodes()),
+        _ => vec![],
+    }
+}
+
+/// Scrubbing puts the whole app back at the recorded point; nothing else
+/// runs until the inspector is closed.
+fn reduce_with_inspector(state: &mut AppState, intent: Intent) -> Vec<Effect> {
+    match intent {
+        Intent::Exit => {
+            state.close_inspector();
+            state.begin_exit_confirm();
+            vec![Effect::RequestRender]
+        }
+        Intent::Cancel | Intent::CloseOverlay | Intent::ToggleInspector => {
+            state.close_inspector();
+            vec![Effect::RequestRender]
+        }
+        Intent::Submit => {
+            state.resume_from_inspector();
+            vec![Effect::RequestRender]
+        }
+        Intent::InputKey(key) => collect_effects(state.dispatch_key_to_inspector(key)),
+        Intent::ToggleHints => {
+            state.toggle_hints_visibility();
+            vec![Effect::RequestRender]
+        }
+        _ => vec![],
     }
 }
 
@@ -215,6 +461,11 @@ fn collect_effects(result: InteractionResult) -> Vec<Effect> {
     effects
 }
 
+fn wheel_fallback(event: PointerEvent) -> Option<Effect> {
+    let rows = event.kind.wheel_delta()? as i32 * WHEEL_SCROLL_ROWS;
+    Some(Effect::ScrollViewport(rows))
+}
+
 fn is_plain_enter(key: KeyEvent) -> bool {
     key.code == KeyCode::Enter && key.modifiers == KeyModifiers::NONE
 }
@@ -142,6 +142,11 @@ impl Scheduler {
         self.ready.drain(..).collect()
     }
 
+    /// Events waiting to be delivered, including delayed ones not yet due.
+    pub fn pending_len(&self) -> usize {
+        self.ready.len() + self.delayed.len()
+    }
+
     pub fn poll_timeout(&self, now: Instant, default_timeout: Duration) -> Duration {
         let mut next = default_timeout;
 
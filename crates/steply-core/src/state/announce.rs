@@ -0,0 +1,161 @@
+use crate::core::i18n::{Message, MessageCatalog};
+use crate::core::value::Value;
+use crate::state::app::AppState;
+use crate::widgets::node::{NodeWalkScope, find_node, walk_nodes};
+
+/// Turns state changes into short sentences for a screen reader: the step
+/// that opened, the field that took focus, a choice that changed and any
+/// validation error that appeared.
+///
+/// Text fields only say they are text fields; the reader echoes typed
+/// characters itself, and the value may be a password.
+#[derive(Debug, Default)]
+pub struct Announcer {
+    last: Option<Observed>,
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct Observed {
+    step_id: String,
+    focused_id: Option<String>,
+    focused_value: Option<Value>,
+    errors: Vec<(String, Message)>,
+    step_errors: Vec<Message>,
+}
+
+impl Announcer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Announcements for everything that changed since the last call; the
+    /// first call describes the step and focus from scratch.
+    pub fn observe(&mut self, state: &AppState) -> Vec<String> {
+        let now = Observed::from_state(state);
+        let last = self.last.take().unwrap_or_default();
+        let messages = state.message_catalog();
+        let mut out = Vec::new();
+
+        let step_changed = now.step_id != last.step_id;
+        if step_changed {
+            let total = state.visible_step_indices().len().to_string();
+            let position = (state.current_visible_step_index() + 1).to_string();
+            let prompt = messages.translate(state.current_prompt());
+            let mut args = vec![("position", position.as_str()), ("total", total.as_str())];
+            out.push(if prompt.is_empty() {
+                messages.message("announce-step", &args)
+            } else {
+                args.push(("prompt", prompt.as_ref()));
+                messages.message("announce-step-prompt", &args)
+            });
+        }
+
+        if let Some(id) = now.focused_id.as_deref() {
+            let label = messages.translate(state.accessible_label(id));
+            let text_field = focused_is_text_field(state, id);
+            if step_changed || now.focused_id != last.focused_id {
+                out.push(if text_field {
+                    let id = match now.focused_value.as_ref() {
+                        Some(value) if !value.is_empty() => "announce-edit-text",
+                        _ => "announce-edit-text-blank",
+                    };
+                    messages.message(id, &[("label", label.as_ref())])
+                } else {
+                    let value = spoken_value(messages, now.focused_value.as_ref());
+                    messages.message(
+                        "announce-value",
+                        &[("label", label.as_ref()), ("value", value.as_str())],
+                    )
+                });
+            } else if !text_field && now.focused_value != last.focused_value {
+                out.push(spoken_value(messages, now.focused_value.as_ref()));
+            }
+        }
+
+        for (id, error) in &now.errors {
+            if !last
+                .errors
+                .iter()
+                .any(|seen| seen.0 == *id && seen.1 == *error)
+            {
+                out.push(messages.message(
+                    "announce-field-error",
+                    &[
+                        (
+                            "label",
+                            messages.translate(state.accessible_label(id)).as_ref(),
+                        ),
+                        ("error", messages.render(error).as_ref()),
+                    ],
+                ));
+            }
+        }
+        for error in &now.step_errors {
+            if !last.step_errors.contains(error) && !now.errors.iter().any(|(_, e)| e == error) {
+                out.push(messages.message(
+                    "announce-error",
+                    &[("error", messages.render(error).as_ref())],
+                ));
+            }
+        }
+
+        self.last = Some(now);
+        out
+    }
+}
+
+impl Observed {
+    fn from_state(state: &AppState) -> Self {
+        let focused_id = state.focused_id().map(str::to_string);
+        let focused_value = focused_id
+            .as_deref()
+            .and_then(|id| find_node(state.current_step_nodes(), id))
+            .and_then(|node| node.value());
+        let mut errors = Vec::new();
+        walk_nodes(
+            state.current_step_nodes(),
+            NodeWalkScope::Recursive,
+            &mut |node| {
+                if let Some(error) = state.visible_error(node.id()) {
+                    errors.push((node.id().to_string(), error.clone()));
+                }
+            },
+        );
+        Self {
+            step_id: state.current_step_id().to_string(),
+            focused_id,
+            focused_value,
+            errors,
+            step_errors: state.current_step_errors().to_vec(),
+        }
+    }
+}
+
+/// Widgets with a text cursor are edited as text.
+fn focused_is_text_field(state: &AppState, id: &str) -> bool {
+    find_node(state.current_step_nodes(), id).is_some_and(|node| node.cursor_pos().is_some())
+}
+
+fn spoken_value(messages: &MessageCatalog, value: Option<&Value>) -> String {
+    match value {
+        None | Some(Value::None) => messages.message("announce-blank", &[]),
+        Some(Value::Bool(true)) => messages.message("announce-checked", &[]),
+        Some(Value::Bool(false)) => messages.message("announce-not-checked", &[]),
+        Some(Value::List(items)) if items.is_empty() => {
+            messages.message("announce-none-selected", &[])
+        }
+        Some(value @ Value::List(_)) => value.to_text_list().unwrap_or_default().join(", "),
+        Some(Value::Object(map)) => messages.message(
+            "announce-entries",
+            &[("count", map.len().to_string().as_str())],
+        ),
+        Some(value) => match value.to_text_scalar() {
+            Some(text) if !text.is_empty() => text,
+            _ => messages.message("announce-blank", &[]),
+        },
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/announce.rs"]
+mod tests;
@@ -4,16 +4,96 @@ use crate::runtime::scheduler::SchedulerCommand;
 use crate::state::app::AppState;
 use crate::state::change::StorePatch;
 use crate::state::step::StepStatus;
-use crate::task::engine::{TaskEngineHost, TaskStartResult};
-use crate::task::{TaskCancelToken, TaskId, TaskInvocation, TaskKind, TaskRequest, TaskSpec};
+use crate::task::engine::{TaskEngineHost, TaskStartResult, retry_key};
+use crate::task::graph::dependency_order;
+use crate::task::{
+    FailurePolicy, TaskCancelToken, TaskGraph, TaskGraphEntry, TaskId, TaskInvocation, TaskKind,
+    TaskLogBuffer, TaskRequest, TaskRunOutcome, TaskRunState, TaskSpec, TaskStatus,
+};
 use crate::time::{Duration, Instant};
+use crate::widgets::shared::binding::render_store_template;
 
 impl AppState {
+    /// Requests a run of a registered task; the invocation is picked up by
+    /// the executor through [`AppState::take_pending_task_invocations`].
+    pub fn request_task(&mut self, request: TaskRequest) -> TaskStartResult {
+        crate::task::engine::request_task_run(self, request)
+    }
+
+    pub fn has_running_tasks(&self) -> bool {
+        !self.runtime.running_task_cancellations.is_empty()
+    }
+
     pub fn take_pending_task_invocations(&mut self) -> Vec<TaskInvocation> {
         self.runtime.pending_task_invocations.drain(..).collect()
     }
 
+    /// Caps how many task runs are in flight at once; further requests wait
+    /// for a run to finish. `None` lifts the cap.
+    pub fn set_max_parallel_tasks(&mut self, max: Option<usize>) {
+        self.runtime.max_parallel_tasks = max.map(|max| max.max(1));
+    }
+
+    pub fn task_status(&self, task_id: &TaskId) -> TaskStatus {
+        if self.is_task_running(task_id) {
+            return TaskStatus::Running;
+        }
+        if self.runtime.retrying_tasks.contains(task_id.as_str()) {
+            return TaskStatus::Retrying;
+        }
+        if self.runtime.blocked_tasks.contains_key(task_id.as_str()) {
+            return TaskStatus::Blocked;
+        }
+        if self
+            .runtime
+            .parked_task_requests
+            .iter()
+            .any(|request| &request.task_id == task_id)
+        {
+            return TaskStatus::Pending;
+        }
+        match self.last_task_outcome(task_id) {
+            Some(TaskRunOutcome::Succeeded) => TaskStatus::Done,
+            Some(TaskRunOutcome::Skipped(_)) => TaskStatus::Skipped,
+            Some(_) => TaskStatus::Failed,
+            None => TaskStatus::Pending,
+        }
+    }
+
+    /// Every registered task with its live status, dependencies first.
+    pub fn task_graph(&self) -> TaskGraph {
+        let mut specs = self
+            .runtime
+            .task_specs
+            .values()
+            .cloned()
+            .collect::<Vec<_>>();
+        specs.sort_by(|a, b| a.id.cmp(&b.id));
+        let Ok(ordered) = dependency_order(specs.as_slice()) else {
+            return TaskGraph::default();
+        };
+        TaskGraph::new(
+            ordered
+                .into_iter()
+                .map(|spec| TaskGraphEntry {
+                    id: spec.id.clone(),
+                    depends_on: spec.depends_on.clone(),
+                    status: self.task_status(&spec.id),
+                    attempt: self.task_attempt(&spec.id),
+                    max_attempts: spec.retry_policy.max_attempts,
+                })
+                .collect(),
+        )
+    }
+
     pub(in crate::state::app) fn cancel_all_running_tasks(&mut self) {
+        self.runtime.parked_task_requests.clear();
+        for task_id in std::mem::take(&mut self.runtime.retrying_tasks) {
+            self.runtime
+                .push_scheduler_command(SchedulerCommand::Cancel {
+                    key: retry_key(task_id.as_str()),
+                });
+        }
         for tokens in self.runtime.running_task_cancellations.values() {
             for handle in tokens {
                 handle.cancel_token.cancel();
@@ -76,11 +156,18 @@ impl AppState {
         fingerprint: Option<u64>,
         now: Instant,
         origin_step_id: Option<String>,
+        attempt: u32,
     ) -> u64 {
+        let spec = self.render_exec_args_internal(spec);
+        self.runtime.blocked_tasks.remove(spec.id.as_str());
+        self.runtime.retrying_tasks.remove(spec.id.as_str());
         let cancel_token = TaskCancelToken::new();
         let run_state = self.runtime.task_runs.entry(spec.id.clone()).or_default();
         let run_id = run_state.next_run_id();
-        run_state.on_started(run_id, now, fingerprint);
+        run_state.on_started(run_id, now, fingerprint, attempt);
+        self.runtime
+            .task_logs
+            .insert(spec.id.clone(), TaskLogBuffer::new(run_id));
         self.register_running_cancel_token_internal(
             spec.id.clone(),
             run_id,
@@ -95,18 +182,29 @@ impl AppState {
             stdin_json,
             cancel_token,
             log_tx: None,
+            progress_tx: None,
         });
         self.refresh_current_step_running_status_internal();
         run_id
     }
 
+    /// Exec args may reference form values as `{{ selector }}`; they are
+    /// rendered against the store when the run starts.
+    fn render_exec_args_internal(&self, mut spec: TaskSpec) -> TaskSpec {
+        if let TaskKind::Exec { args, .. } = &mut spec.kind {
+            for arg in args.iter_mut().filter(|arg| arg.contains("{{")) {
+                *arg = render_store_template(&self.data.store, arg);
+            }
+        }
+        spec
+    }
+
     fn build_task_stdin_json_internal(&self, spec: &TaskSpec) -> Result<String, String> {
-        let reads = match &spec.kind {
-            TaskKind::Exec { reads, .. } => reads
-                .as_ref()
-                .and_then(|binding| binding.resolve(&self.data.store))
-                .unwrap_or(Value::None),
-        };
+        let reads = spec
+            .kind
+            .reads()
+            .and_then(|binding| binding.resolve(&self.data.store))
+            .unwrap_or(Value::None);
         reads
             .to_json_string()
             .map_err(|err| format!("failed to serialize task input for '{}': {err}", spec.id))
@@ -356,6 +454,7 @@ impl AppState {
     }
 
     fn emit_task_start_feedback_internal(&mut self, result: &TaskStartResult) {
+        crate::trace_event!(DEBUG, result = ?result, "task run requested");
         let event = match result {
             TaskStartResult::Started { task_id, run_id } => SystemEvent::TaskStarted {
                 task_id: task_id.clone(),
@@ -365,6 +464,15 @@ impl AppState {
                 task_id: task_id.clone(),
                 reason: "queued: task already running".to_string(),
             },
+            // Not a rejection: the run starts once its dependencies allow.
+            TaskStartResult::Waiting { .. } => return,
+            TaskStartResult::Blocked {
+                task_id,
+                dependency,
+            } => SystemEvent::TaskStartRejected {
+                task_id: task_id.clone(),
+                reason: format!("blocked: dependency '{dependency}' did not succeed"),
+            },
             TaskStartResult::SpecNotFound { task_id } => SystemEvent::TaskStartRejected {
                 task_id: task_id.clone(),
                 reason: "task spec not found".to_string(),
@@ -458,6 +566,109 @@ impl TaskEngineHost for AppState {
             .is_some_and(|s| s.is_running())
     }
 
+    fn is_run_active(&self, task_id: &TaskId, run_id: u64) -> bool {
+        self.runtime
+            .running_task_cancellations
+            .get(task_id.as_str())
+            .is_some_and(|handles| handles.iter().any(|handle| handle.run_id == run_id))
+    }
+
+    fn last_task_outcome(&self, task_id: &TaskId) -> Option<TaskRunOutcome> {
+        self.runtime
+            .task_runs
+            .get(task_id.as_str())
+            .and_then(|run_state| run_state.last_outcome().cloned())
+    }
+
+    fn last_started_run_id(&self, task_id: &TaskId) -> Option<u64> {
+        self.runtime
+            .task_runs
+            .get(task_id.as_str())
+            .and_then(TaskRunState::last_started_run_id)
+    }
+
+    fn task_attempt(&self, task_id: &TaskId) -> u32 {
+        self.runtime
+            .task_runs
+            .get(task_id.as_str())
+            .map_or(1, TaskRunState::attempt)
+    }
+
+    fn schedule_task_retry(&mut self, request: TaskRequest, delay_ms: u64) {
+        self.runtime.retrying_tasks.insert(request.task_id.clone());
+        self.runtime
+            .push_scheduler_command(SchedulerCommand::EmitAfter {
+                key: retry_key(request.task_id.as_str()),
+                delay: Duration::from_millis(delay_ms),
+                event: AppEvent::System(SystemEvent::TaskRequested { request }),
+            });
+    }
+
+    fn cancel_task_retry(&mut self, task_id: &TaskId) {
+        if self.runtime.retrying_tasks.remove(task_id.as_str()) {
+            self.runtime
+                .push_scheduler_command(SchedulerCommand::Cancel {
+                    key: retry_key(task_id.as_str()),
+                });
+        }
+    }
+
+    fn escalate_task_failure(&mut self, task_id: &TaskId, policy: FailurePolicy, error: &str) {
+        match policy {
+            FailurePolicy::Abort => self.abort_flow_for_task_failure(task_id, error),
+            FailurePolicy::Prompt => self.open_task_failure_prompt(task_id, error),
+            FailurePolicy::Report | FailurePolicy::Skip => {}
+        }
+    }
+
+    fn has_task_capacity(&self) -> bool {
+        let Some(max) = self.runtime.max_parallel_tasks else {
+            return true;
+        };
+        let running = self
+            .runtime
+            .running_task_cancellations
+            .values()
+            .map(Vec::len)
+            .sum::<usize>();
+        running < max
+    }
+
+    fn is_task_waiting(&self, task_id: &TaskId) -> bool {
+        self.runtime
+            .parked_task_requests
+            .iter()
+            .any(|request| &request.task_id == task_id)
+            || self.runtime.retrying_tasks.contains(task_id.as_str())
+            || self.has_task_failure_prompt(task_id)
+    }
+
+    fn park_task_request(&mut self, request: TaskRequest) {
+        self.runtime.blocked_tasks.remove(request.task_id.as_str());
+        let parked = &mut self.runtime.parked_task_requests;
+        match parked
+            .iter_mut()
+            .find(|pending| pending.task_id == request.task_id)
+        {
+            Some(pending) => *pending = request,
+            None => parked.push(request),
+        }
+    }
+
+    fn take_parked_task_requests(&mut self) -> Vec<TaskRequest> {
+        std::mem::take(&mut self.runtime.parked_task_requests)
+    }
+
+    fn is_task_blocked(&self, task_id: &TaskId) -> bool {
+        self.runtime.blocked_tasks.contains_key(task_id.as_str())
+    }
+
+    fn block_task(&mut self, task_id: &TaskId, dependency: &TaskId) {
+        self.runtime
+            .blocked_tasks
+            .insert(task_id.clone(), dependency.clone());
+    }
+
     fn enqueue_task_request(&mut self, task_id: TaskId, request: TaskRequest) {
         self.enqueue_task_request_internal(task_id, request);
     }
@@ -481,8 +692,16 @@ impl TaskEngineHost for AppState {
         fingerprint: Option<u64>,
         now: Instant,
         origin_step_id: Option<String>,
+        attempt: u32,
     ) -> u64 {
-        self.start_task_invocation_internal(spec, stdin_json, fingerprint, now, origin_step_id)
+        self.start_task_invocation_internal(
+            spec,
+            stdin_json,
+            fingerprint,
+            now,
+            origin_step_id,
+            attempt,
+        )
     }
 
     fn emit_task_start_feedback(&mut self, result: &TaskStartResult) {
@@ -493,10 +712,18 @@ impl TaskEngineHost for AppState {
         self.remove_running_cancel_token_internal(task_id, run_id);
     }
 
-    fn on_run_finished(&mut self, task_id: &TaskId, run_id: u64, now: Instant) -> Option<u64> {
-        let run_state = self.runtime.task_runs.entry(task_id.clone()).or_default();
-        run_state.on_finished(run_id, now);
-        run_state.last_started_run_id()
+    fn on_run_finished(
+        &mut self,
+        task_id: &TaskId,
+        run_id: u64,
+        now: Instant,
+        outcome: TaskRunOutcome,
+    ) {
+        self.runtime
+            .task_runs
+            .entry(task_id.clone())
+            .or_default()
+            .on_finished(run_id, now, outcome);
     }
 
     fn pop_queued_task_request(&mut self, task_id: &TaskId) -> Option<TaskRequest> {
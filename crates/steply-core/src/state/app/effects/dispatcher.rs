@@ -1,7 +1,7 @@
 use crate::runtime::event::{SystemEvent, WidgetAction};
 use crate::state::app::AppState;
 use crate::task::TaskId;
-use crate::task::engine::{complete_task_run, request_task_run};
+use crate::task::engine::{complete_task_run, report_task_progress, request_task_run};
 use crate::time::Instant;
 use crate::widgets::node::{NodeWalkScope, find_node, walk_nodes_mut};
 use crate::widgets::traits::{InteractionResult, ValidationMode};
@@ -30,7 +30,9 @@ impl<'a> EffectDispatcher<'a> {
                 self.state.clear_step_errors();
                 InteractionResult::handled()
             }
-            WidgetAction::OpenUrl { .. } => InteractionResult::consumed(),
+            WidgetAction::OpenUrl { .. } | WidgetAction::CopyToClipboard { .. } => {
+                InteractionResult::consumed()
+            }
             WidgetAction::InputDone => self.complete_input_done(),
             WidgetAction::ValidateFocusedSubmit => {
                 self.state.validate_focused_submit();
@@ -73,6 +75,13 @@ impl<'a> EffectDispatcher<'a> {
                 request_task_run(self.state, request);
                 InteractionResult::handled()
             }
+            WidgetAction::JumpToStep { step_id } => {
+                if self.state.jump_to_step(step_id.as_str()) {
+                    InteractionResult::handled()
+                } else {
+                    InteractionResult::ignored()
+                }
+            }
         }
     }
 
@@ -113,9 +122,34 @@ impl<'a> EffectDispatcher<'a> {
                 request_task_run(self.state, request);
                 InteractionResult::handled()
             }
-            SystemEvent::TaskStarted { .. }
-            | SystemEvent::TaskStartRejected { .. }
-            | SystemEvent::TaskLogLine { .. } => {
+            SystemEvent::TaskStartRejected { .. } => {
+                let result = self.broadcast_system_event(&event);
+                self.handled_with_followup(result)
+            }
+            SystemEvent::TaskStarted { .. } => {
+                let mut result = self.broadcast_system_event(&event);
+                result.merge(self.state.notify_task_details(&event));
+                self.handled_with_followup(result)
+            }
+            SystemEvent::TaskLogLine {
+                ref task_id,
+                run_id,
+                ref line,
+            } => {
+                self.state
+                    .record_task_log_line(task_id, run_id, line.as_str());
+                let mut result = self.broadcast_system_event(&event);
+                result.merge(self.state.notify_task_details(&event));
+                self.handled_with_followup(result)
+            }
+            SystemEvent::TaskProgress {
+                ref task_id,
+                run_id,
+                progress,
+            } => {
+                if !report_task_progress(self.state, task_id, run_id, progress) {
+                    return InteractionResult::ignored();
+                }
                 let result = self.broadcast_system_event(&event);
                 self.handled_with_followup(result)
             }
@@ -127,12 +161,24 @@ impl<'a> EffectDispatcher<'a> {
             SystemEvent::TaskCompleted { ref completion } => {
                 let route = self.task_event_scope(&completion.task_id, completion.run_id);
                 let accepted = complete_task_run(self.state, completion.clone());
+                self.state.on_submit_hook_completed(completion);
                 if accepted {
-                    let result = self.dispatch_system_event_with_scope(&event, route);
+                    let mut result = self.dispatch_system_event_with_scope(&event, route);
+                    result.merge(self.state.notify_task_details(&event));
                     return self.handled_with_followup(result);
                 }
                 InteractionResult::handled()
             }
+            SystemEvent::TerminalFocusChanged { focused } => {
+                self.state.ui.terminal_blurred = !focused;
+                // Files or services the answer refers to may have changed
+                // while the user was in another window.
+                if focused {
+                    self.state.validate_focused_live();
+                }
+                let result = self.broadcast_system_event(&event);
+                self.handled_with_followup(result)
+            }
             SystemEvent::RequestSubmit => {
                 if self.state.has_blocking_overlay() {
                     self.state.close_overlay();
@@ -200,8 +246,13 @@ impl<'a> EffectDispatcher<'a> {
             SystemEvent::TaskStarted { task_id, run_id }
             | SystemEvent::TaskLogLine {
                 task_id, run_id, ..
+            }
+            | SystemEvent::TaskProgress {
+                task_id, run_id, ..
             } => self.task_event_scope(task_id, *run_id),
-            SystemEvent::TaskStartRejected { .. } => EventDispatchScope::CurrentStep,
+            SystemEvent::TaskStartRejected { .. } | SystemEvent::TerminalFocusChanged { .. } => {
+                EventDispatchScope::CurrentStep
+            }
             SystemEvent::TaskCompleted { completion } => {
                 self.task_event_scope(&completion.task_id, completion.run_id)
             }
@@ -1,3 +1,5 @@
+use crate::core::value::Value;
+
 use super::{AppState, ExitConfirmChoice, ExitConfirmMode, ExitConfirmState};
 
 impl AppState {
@@ -35,8 +37,19 @@ impl AppState {
         });
     }
 
+    /// Asks whether to seed the store with `values`, the answers of a session
+    /// that crashed before finishing.
+    pub fn begin_restore_confirm(&mut self, values: Value) {
+        self.pending_session_restore = Some(values);
+        self.pending_exit_confirm = Some(ExitConfirmState {
+            mode: ExitConfirmMode::RestoreSession,
+            choice: ExitConfirmChoice::Exit,
+        });
+    }
+
     pub fn cancel_exit_confirm(&mut self) {
         self.pending_exit_confirm = None;
+        self.pending_session_restore = None;
     }
 
     pub fn toggle_exit_confirm_choice(&mut self) -> bool {
@@ -71,10 +84,18 @@ impl AppState {
         let Some(state) = self.pending_exit_confirm.take() else {
             return false;
         };
+        let restore = self.pending_session_restore.take();
         if state.choice == ExitConfirmChoice::Exit {
             match state.mode {
-                ExitConfirmMode::ExitApplication => self.request_exit(),
+                ExitConfirmMode::ExitApplication => {
+                    self.cancel_flow();
+                }
                 ExitConfirmMode::FinishFlow => self.finalize_flow_exit(),
+                ExitConfirmMode::RestoreSession => {
+                    if let Some(values) = restore {
+                        let _ = self.prefill_values(&values);
+                    }
+                }
             }
         }
         true
@@ -0,0 +1,100 @@
+use std::collections::HashSet;
+
+use crate::state::app::AppState;
+use crate::state::app::validation_runtime::collect_node_values;
+use crate::state::change::collect_store_ownership;
+use crate::state::step::Step;
+use crate::state::validation::StepContext;
+use crate::task::collect_inline_tasks_from_steps;
+
+fn expansion_error_key(step_id: &str) -> String {
+    format!("expansion:{step_id}")
+}
+
+impl AppState {
+    /// Inserts the current step's expanded steps after it, replacing the
+    /// ones an earlier submit inserted along with whatever those expanded
+    /// into. Shows a step error and returns
+    /// `false` when an expanded step's id is already taken.
+    pub(in crate::state::app) fn expand_current_step_or_report(&mut self) -> bool {
+        let key = expansion_error_key(self.current_step_id());
+        self.runtime
+            .validation
+            .clear_runtime_step_error(key.as_str());
+        match self.expand_current_step() {
+            Ok(()) => true,
+            Err(message) => {
+                self.runtime.validation.set_runtime_step_error(key, message);
+                false
+            }
+        }
+    }
+
+    fn expand_current_step(&mut self) -> Result<(), String> {
+        let origin_step_id = self.current_step_id().to_string();
+        let inserted: Vec<Step> = {
+            let step = self.flow.current_step();
+            if step.expanders.is_empty() {
+                Vec::new()
+            } else {
+                let values = collect_node_values(step.nodes.as_slice());
+                let ctx = StepContext::new(&step.id, &values);
+                step.expanders
+                    .iter()
+                    .flat_map(|expander| expander(&ctx))
+                    .collect()
+            }
+        };
+
+        let replaced = self.flow.pending_inserted_by(origin_step_id.as_str());
+        let mut seen_ids = self
+            .flow
+            .steps()
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| !replaced.contains(index))
+            .map(|(_, step)| step.id.as_str())
+            .collect::<HashSet<_>>();
+        if let Some(duplicate) = inserted
+            .iter()
+            .find(|step| !seen_ids.insert(step.id.as_str()))
+        {
+            return Err(format!(
+                "expanded step id '{}' is already used in the flow",
+                duplicate.id
+            ));
+        }
+
+        let removed = self
+            .flow
+            .remove_pending_inserted_by(origin_step_id.as_str());
+        if removed == 0 && inserted.is_empty() {
+            return Ok(());
+        }
+        let inserted = inserted
+            .into_iter()
+            .map(|mut step| {
+                step.inserted_by = Some(origin_step_id.clone());
+                step
+            })
+            .collect::<Vec<_>>();
+        for spec in collect_inline_tasks_from_steps(inserted.as_slice()) {
+            if self.runtime.task_specs.contains_key(spec.id.as_str()) {
+                continue;
+            }
+            if spec.enabled {
+                self.runtime.task_triggers.extend(
+                    spec.triggers
+                        .iter()
+                        .cloned()
+                        .map(|trigger| (spec.id.clone(), trigger)),
+                );
+            }
+            self.runtime.task_specs.insert(spec.id.clone(), spec);
+        }
+        self.flow.insert_after_current(inserted);
+        self.runtime.store_ownership =
+            collect_store_ownership(&self.flow, self.runtime.task_specs.values().cloned());
+        Ok(())
+    }
+}
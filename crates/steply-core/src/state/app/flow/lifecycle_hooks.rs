@@ -0,0 +1,88 @@
+use crate::core::value::Value;
+use crate::state::app::AppState;
+use crate::state::change::StoreWriteOrigin;
+use crate::state::lifecycle::{LifecycleContext, LifecycleEvent};
+
+fn lifecycle_error_key(step_id: &str) -> String {
+    format!("lifecycle:{step_id}")
+}
+
+#[derive(Clone, Copy)]
+pub(in crate::state::app) enum HookScope {
+    Step,
+    Flow,
+}
+
+impl AppState {
+    /// Runs hooks and shows a failure as a step error. Returns `false` when a
+    /// hook vetoed the transition.
+    pub(in crate::state::app) fn run_lifecycle_hooks_or_report(
+        &mut self,
+        scope: HookScope,
+        event: LifecycleEvent,
+    ) -> bool {
+        if self.flow.is_empty() {
+            return true;
+        }
+        let key = lifecycle_error_key(self.current_step_id());
+        self.runtime
+            .validation
+            .clear_runtime_step_error(key.as_str());
+        match self.run_lifecycle_hooks(scope, event) {
+            Ok(()) => true,
+            Err(message) => {
+                self.runtime.validation.set_runtime_step_error(key, message);
+                false
+            }
+        }
+    }
+
+    pub(in crate::state::app) fn run_lifecycle_hooks(
+        &mut self,
+        scope: HookScope,
+        event: LifecycleEvent,
+    ) -> Result<(), String> {
+        if self.flow.is_empty() {
+            return Ok(());
+        }
+        let (result, written) = {
+            let step = self.flow.current_step();
+            let hooks = match scope {
+                HookScope::Step => step.hooks.for_event(event),
+                HookScope::Flow => self.flow.hooks().for_event(event),
+            };
+            if hooks.is_empty() {
+                return Ok(());
+            }
+            let mut ctx = LifecycleContext::new(step.id.as_str(), &mut self.data.store);
+            let result = hooks.iter().try_for_each(|hook| hook(&mut ctx));
+            (result, ctx.into_written())
+        };
+
+        if !written.is_empty() {
+            self.force_hydrate_current_step_from_store();
+            for target in written {
+                let value = self
+                    .data
+                    .store
+                    .get_target(&target)
+                    .cloned()
+                    .unwrap_or(Value::None);
+                self.apply_system_value_change(target, value, StoreWriteOrigin::System);
+            }
+        }
+        result
+    }
+
+    /// Cancels the flow unless a step or flow `on_cancel` hook vetoes it.
+    pub fn cancel_flow(&mut self) -> bool {
+        self.pending_exit_confirm = None;
+        if !self.run_lifecycle_hooks_or_report(HookScope::Step, LifecycleEvent::Cancel)
+            || !self.run_lifecycle_hooks_or_report(HookScope::Flow, LifecycleEvent::Cancel)
+        {
+            return false;
+        }
+        self.request_exit();
+        true
+    }
+}
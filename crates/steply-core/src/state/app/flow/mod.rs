@@ -1,3 +1,9 @@
+mod expansion;
+mod lifecycle_hooks;
 mod navigation;
 mod overlay;
+mod review;
 mod step;
+mod submit_hooks;
+
+pub(in crate::state::app) use lifecycle_hooks::HookScope;
@@ -29,6 +29,18 @@ impl AppState {
         self.broadcast_current_focus_request();
     }
 
+    /// Moves focus to the node a click landed on; clicks on nodes that take
+    /// no focus leave it where it is.
+    pub(in crate::state::app) fn focus_clicked(&mut self, id: &str) {
+        if self.ui.focus.current_id() == Some(id) || !self.ui.focus.contains(id) {
+            return;
+        }
+        self.reset_completion_for_focus_change();
+        self.validate_focused_live();
+        self.ui.focus.set_focus_by_id(id);
+        self.broadcast_current_focus_request();
+    }
+
     pub(in crate::state::app) fn rebuild_focus_with_target(
         &mut self,
         target: Option<&str>,
@@ -53,20 +65,26 @@ impl AppState {
     }
 
     pub(in crate::state::app) fn refresh_focus_for_current_visibility(&mut self) {
-        let next_index = NodeIndex::build(self.active_nodes());
-        let current_focus = self.ui.focus.current_id().map(str::to_string);
-        let current_still_visible = current_focus
-            .as_deref()
-            .is_some_and(|id| next_index.has_visible(id));
+        self.ui.active_node_index = NodeIndex::build(self.active_nodes());
+        let previous = std::mem::take(&mut self.ui.focus);
+        let mut next = FocusState::from_nodes(self.active_nodes());
+        for id in previous
+            .ids()
+            .filter(|id| !next.contains(id) && self.ui.active_node_index.has_visible(id))
+        {
+            self.runtime.validation.clear_error(id);
+        }
 
-        if current_still_visible {
-            self.ui.active_node_index = next_index;
+        if let Some(id) = previous.current_id()
+            && next.contains(id)
+        {
+            next.set_focus_by_id(id);
+            self.ui.focus = next;
             return;
         }
 
         self.reset_completion_for_focus_change();
-        self.ui.active_node_index = next_index;
-        self.ui.focus = FocusState::from_nodes(self.active_nodes());
+        self.ui.focus = next;
         self.broadcast_current_focus_request();
     }
 
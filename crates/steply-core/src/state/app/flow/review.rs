@@ -0,0 +1,111 @@
+use indexmap::IndexMap;
+
+use crate::core::value::Value;
+use crate::runtime::event::WidgetAction;
+use crate::state::app::AppState;
+use crate::state::report::ReportEntry;
+use crate::widgets::inputs::button::ButtonInput;
+use crate::widgets::node::{Node, NodeWalkScope, walk_nodes};
+use crate::widgets::outputs::data::{DataOutput, DataOutputFormat};
+use crate::widgets::traits::OutputNode;
+
+/// Shown instead of a masked widget's value, whatever its length.
+const MASKED_VALUE: &str = "********";
+
+impl AppState {
+    /// Regenerates the widgets of a review step from the steps before it.
+    pub(in crate::state::app) fn rebuild_review_step(&mut self) {
+        if self.flow.is_empty() || !self.flow.current_step().review {
+            return;
+        }
+        let messages = self.message_catalog();
+        let mut nodes = Vec::new();
+        for index in 0..self.flow.current_index() {
+            if !self.step_visible_at(index) {
+                continue;
+            }
+            let step = &self.flow.steps()[index];
+            if step.review {
+                continue;
+            }
+            let entries = self.review_entries(step.nodes.as_slice());
+            if entries.is_empty() {
+                continue;
+            }
+            let mut summary = DataOutput::new(
+                format!("__review_{}", step.id),
+                Some(step.prompt.clone()),
+                DataOutputFormat::Yaml,
+            );
+            summary.set_value(review_summary(entries));
+            nodes.push(Node::Output(Box::new(summary)));
+            nodes.push(Node::Input(Box::new(
+                ButtonInput::new(
+                    format!("__review_edit_{}", step.id),
+                    messages.message(
+                        "review-edit",
+                        &[("step", messages.translate(step.prompt.as_str()).as_ref())],
+                    ),
+                )
+                .with_action(WidgetAction::JumpToStep {
+                    step_id: step.id.clone(),
+                }),
+            )));
+        }
+        nodes.push(Node::Input(Box::new(
+            ButtonInput::new("__review_confirm", messages.message("review-confirm", &[]))
+                .with_action(WidgetAction::InputDone),
+        )));
+        self.flow.current_step_mut().replace_nodes(nodes);
+    }
+
+    /// The bound answers among `nodes`, keyed by node id. Secret widgets
+    /// are left out and masked ones show a placeholder.
+    pub(in crate::state::app) fn review_entries(
+        &self,
+        nodes: &[Node],
+    ) -> IndexMap<String, ReportEntry> {
+        let mut entries = IndexMap::new();
+        walk_nodes(nodes, NodeWalkScope::Recursive, &mut |node| {
+            if node.secret().is_some() {
+                return;
+            }
+            let Some(target) = node
+                .store_binding()
+                .and_then(|binding| binding.value.as_ref())
+            else {
+                return;
+            };
+            let label = if node.label().is_empty() {
+                node.id()
+            } else {
+                node.label()
+            };
+            let value = if node.value_masked() {
+                Value::Text(MASKED_VALUE.to_string())
+            } else {
+                self.data
+                    .store
+                    .get_target(target)
+                    .cloned()
+                    .unwrap_or(Value::None)
+            };
+            entries.insert(node.id().to_string(), ReportEntry::new(label, value));
+        });
+        entries
+    }
+}
+
+/// Entries by label; a label that repeats is told apart by its node id.
+fn review_summary(entries: IndexMap<String, ReportEntry>) -> Value {
+    let mut summary = IndexMap::new();
+    for (id, entry) in entries {
+        let key = if summary.contains_key(entry.label.as_str()) {
+            format!("{} ({id})", entry.label)
+        } else {
+            entry.label
+        };
+        summary.insert(key, entry.value);
+    }
+    Value::Object(summary)
+}
@@ -1,6 +1,7 @@
 use crate::core::NodeId;
 use crate::core::value::Value;
 use crate::state::app::AppState;
+use crate::state::lifecycle::LifecycleEvent;
 use crate::state::step::StepNavigation;
 use crate::task::engine::{
     refresh_active_step_interval_tasks, trigger_flow_end_tasks, trigger_step_enter_tasks,
@@ -9,11 +10,17 @@ use crate::task::engine::{
 use crate::widgets::node::{NodeWalkScope, walk_nodes};
 use crate::widgets::traits::ValidationMode;
 
+use super::lifecycle_hooks::HookScope;
+
 impl AppState {
     pub(in crate::state::app) fn handle_step_submit(&mut self) {
+        if self.has_pending_submit_hooks() {
+            return;
+        }
         self.clear_completion_session();
         if !self.validate_current_step(ValidationMode::Submit) {
             self.focus_first_invalid_on_current_step();
+            self.open_validation_summary();
             return;
         }
 
@@ -24,8 +31,21 @@ impl AppState {
             return;
         }
 
-        let submit_step_id = self.current_step_id().to_string();
         self.sync_current_step_values_to_store();
+        if !self.start_submit_hooks() {
+            return;
+        }
+        self.finish_step_submit();
+    }
+
+    pub(in crate::state::app) fn finish_step_submit(&mut self) {
+        let submit_step_id = self.current_step_id().to_string();
+        if !self.run_lifecycle_hooks_or_report(HookScope::Step, LifecycleEvent::Exit) {
+            return;
+        }
+        if !self.expand_current_step_or_report() {
+            return;
+        }
         trigger_submit_before_tasks(self, submit_step_id.as_str());
         let previous_step_id = self.leave_current_step();
         trigger_submit_after_tasks(self, previous_step_id.as_str());
@@ -33,15 +53,24 @@ impl AppState {
     }
 
     pub fn handle_step_back(&mut self) {
-        if !self.has_prev_visible_step() || self.pending_back_confirm.is_some() {
+        if !self.has_prev_visible_step()
+            || self.pending_back_confirm.is_some()
+            || self.has_pending_submit_hooks()
+        {
             return;
         }
         match self.flow.current_step().navigation.clone() {
             StepNavigation::Locked => {}
-            StepNavigation::Allowed => self.execute_step_back(),
+            StepNavigation::Allowed => {
+                if self.run_lifecycle_hooks_or_report(HookScope::Step, LifecycleEvent::Exit) {
+                    self.execute_step_back();
+                }
+            }
             StepNavigation::Reset => {
-                self.reset_current_step_values();
-                self.execute_step_back();
+                if self.run_lifecycle_hooks_or_report(HookScope::Step, LifecycleEvent::Exit) {
+                    self.reset_current_step_values();
+                    self.execute_step_back();
+                }
             }
             StepNavigation::Destructive { warning } => {
                 self.pending_back_confirm = Some(warning);
@@ -51,13 +80,36 @@ impl AppState {
 
     pub fn confirm_back(&mut self) {
         self.pending_back_confirm = None;
-        self.execute_step_back();
+        if self.run_lifecycle_hooks_or_report(HookScope::Step, LifecycleEvent::Exit) {
+            self.execute_step_back();
+        }
     }
 
     pub fn cancel_back_confirm(&mut self) {
         self.pending_back_confirm = None;
     }
 
+    /// Jumps back to an earlier visible step, e.g. from a review step's edit
+    /// button. Navigation policies of the skipped steps are not applied.
+    pub fn jump_to_step(&mut self, step_id: &str) -> bool {
+        let Some(target) = self.flow.steps().iter().position(|step| step.id == step_id) else {
+            return false;
+        };
+        if target >= self.flow.current_index()
+            || !self.step_visible_at(target)
+            || self.has_pending_submit_hooks()
+        {
+            return false;
+        }
+        if !self.run_lifecycle_hooks_or_report(HookScope::Step, LifecycleEvent::Exit) {
+            return false;
+        }
+        self.leave_current_step();
+        while self.flow.current_index() > target && self.flow.go_back() {}
+        self.enter_current_step_after_transition();
+        true
+    }
+
     fn execute_step_back(&mut self) {
         self.leave_current_step();
         self.transition_back_to_previous();
@@ -116,6 +168,7 @@ impl AppState {
 
     pub(in crate::state::app) fn enter_current_step_after_transition(&mut self) {
         self.ui.overlays.clear();
+        self.rebuild_review_step();
         self.refresh_current_step_bindings();
         let current_step_id = self.current_step_id().to_string();
         let restore_focus = self
@@ -126,6 +179,7 @@ impl AppState {
         self.rebuild_focus_with_target(restore_focus.as_deref(), true);
         trigger_step_enter_tasks(self, current_step_id.as_str());
         refresh_active_step_interval_tasks(self);
+        self.run_lifecycle_hooks_or_report(HookScope::Step, LifecycleEvent::Enter);
     }
 
     pub(in crate::state::app) fn reconcile_current_step_after_store_change(&mut self) -> bool {
@@ -136,6 +190,7 @@ impl AppState {
         let previous_step_id = self.current_step_id().to_string();
         let current_became_hidden = !self.step_visible_at(self.flow.current_index());
         if current_became_hidden {
+            let _ = self.run_lifecycle_hooks(HookScope::Step, LifecycleEvent::Exit);
             let _ = self.leave_current_step();
         }
 
@@ -159,6 +214,9 @@ impl AppState {
 
     pub(in crate::state::app) fn finalize_flow_exit(&mut self) {
         self.ui.overlays.clear();
+        if !self.run_lifecycle_hooks_or_report(HookScope::Flow, LifecycleEvent::Exit) {
+            return;
+        }
         trigger_flow_end_tasks(self);
         self.flow.complete_current();
         self.request_exit();
@@ -0,0 +1,165 @@
+use crate::state::app::AppState;
+use crate::state::app::state::PendingSubmitHooks;
+use crate::task::engine::{TaskEngineHost, TaskStartResult, request_task_run};
+use crate::task::{TaskCompletion, TaskId, TaskRequest, TaskRunOutcome};
+
+fn submit_hook_error_key(step_id: &str) -> String {
+    format!("submit_hook:{step_id}")
+}
+
+impl AppState {
+    pub fn has_pending_submit_hooks(&self) -> bool {
+        self.runtime.pending_submit_hooks.is_some()
+    }
+
+    pub(in crate::state::app) fn has_pending_submit_hooks_for(&self, step_id: &str) -> bool {
+        self.runtime
+            .pending_submit_hooks
+            .as_ref()
+            .is_some_and(|pending| pending.step_id == step_id)
+    }
+
+    pub(in crate::state::app) fn start_submit_hooks(&mut self) -> bool {
+        let step = self.flow.current_step();
+        if step.submit_hooks.is_empty() {
+            return true;
+        }
+        let step_id = step.id.clone();
+        let hooks = step.submit_hooks.clone();
+        self.runtime
+            .validation
+            .clear_runtime_step_error(submit_hook_error_key(step_id.as_str()).as_str());
+
+        let mut runs = Vec::<(TaskId, Option<u64>)>::with_capacity(hooks.len());
+        for task_id in hooks {
+            let failure = match request_task_run(self, TaskRequest::new(task_id.clone())) {
+                TaskStartResult::Started { task_id, run_id } => {
+                    runs.push((task_id, Some(run_id)));
+                    continue;
+                }
+                TaskStartResult::Waiting { task_id } => {
+                    runs.push((task_id, None));
+                    continue;
+                }
+                TaskStartResult::Blocked { dependency, .. } => {
+                    format!("dependency '{dependency}' did not succeed")
+                }
+                TaskStartResult::Disabled { .. } | TaskStartResult::Skipped { .. } => continue,
+                TaskStartResult::Queued { .. } | TaskStartResult::Dropped { .. } => {
+                    "task is already running".to_string()
+                }
+                TaskStartResult::SpecNotFound { .. } => "task spec not found".to_string(),
+                TaskStartResult::Rejected { reason, .. } => reason,
+            };
+            for (started_id, _) in &runs {
+                self.cancel_running_task(started_id);
+            }
+            self.runtime.validation.set_runtime_step_error(
+                submit_hook_error_key(step_id.as_str()),
+                format!("{task_id}: {failure}"),
+            );
+            return false;
+        }
+
+        if runs.is_empty() {
+            return true;
+        }
+        self.runtime.pending_submit_hooks = Some(PendingSubmitHooks { step_id, runs });
+        false
+    }
+
+    pub(in crate::state::app) fn on_submit_hook_completed(&mut self, completion: &TaskCompletion) {
+        // A failed run that will be retried, or whose failure waits for the
+        // user's decision, leaves the hook waiting for the next run.
+        let settled = completion.cancelled || !self.is_task_waiting(&completion.task_id);
+        let skipped = matches!(
+            self.last_task_outcome(&completion.task_id),
+            Some(TaskRunOutcome::Skipped(_))
+        );
+        let Some(pending) = self.runtime.pending_submit_hooks.as_mut() else {
+            return;
+        };
+        let position = pending.runs.iter().position(|(task_id, run_id)| {
+            *task_id == completion.task_id
+                && run_id.is_none_or(|run_id| run_id == completion.run_id)
+        });
+        let failure = match position {
+            Some(position) if !settled => {
+                pending.runs[position].1 = None;
+                return;
+            }
+            Some(position) => {
+                pending.runs.remove(position);
+                if completion.cancelled {
+                    Some(format!("{}: cancelled", completion.task_id))
+                } else {
+                    completion
+                        .error
+                        .as_ref()
+                        .filter(|_| !skipped)
+                        .map(|err| format!("{}: {err}", completion.task_id))
+                }
+            }
+            None => None,
+        };
+        // A hook waiting on a dependency that just failed will never run.
+        let failure = failure.or_else(|| {
+            let pending = self.runtime.pending_submit_hooks.as_ref()?;
+            pending.runs.iter().find_map(|(task_id, run_id)| {
+                let dependency = self.runtime.blocked_tasks.get(task_id.as_str())?;
+                run_id
+                    .is_none()
+                    .then(|| format!("{task_id}: dependency '{dependency}' did not succeed"))
+            })
+        });
+        if position.is_none() && failure.is_none() {
+            return;
+        }
+
+        if let Some(message) = failure {
+            let Some(pending) = self.runtime.pending_submit_hooks.take() else {
+                return;
+            };
+            for (task_id, _) in &pending.runs {
+                self.cancel_running_task(task_id);
+            }
+            self.runtime
+                .validation
+                .set_runtime_step_error(submit_hook_error_key(pending.step_id.as_str()), message);
+            return;
+        }
+        self.finish_submit_hooks_if_done();
+    }
+
+    /// The user chose to skip a failed hook; the submit goes on without it.
+    pub(in crate::state::app) fn on_submit_hook_skipped(&mut self, task_id: &TaskId) {
+        let Some(pending) = self.runtime.pending_submit_hooks.as_mut() else {
+            return;
+        };
+        let before = pending.runs.len();
+        pending
+            .runs
+            .retain(|(hook_id, run_id)| hook_id != task_id || run_id.is_some());
+        if pending.runs.len() != before {
+            self.finish_submit_hooks_if_done();
+        }
+    }
+
+    fn finish_submit_hooks_if_done(&mut self) {
+        if self
+            .runtime
+            .pending_submit_hooks
+            .as_ref()
+            .is_some_and(|pending| !pending.runs.is_empty())
+        {
+            return;
+        }
+        let Some(pending) = self.runtime.pending_submit_hooks.take() else {
+            return;
+        };
+        if self.flow.is_empty() || self.current_step_id() != pending.step_id {
+            return;
+        }
+        self.finish_step_submit();
+    }
+}
@@ -1,11 +1,51 @@
 use super::completion::CompletionStartResult;
+use crate::core::action_bindings::ActionBindings;
 use crate::runtime::event::SystemEvent;
+use crate::runtime::intent::Intent;
+use crate::runtime::key_bindings::KeyBindings;
 use crate::state::app::AppState;
-use crate::terminal::{KeyCode, KeyEvent, KeyModifiers, PointerEvent};
-use crate::widgets::node::{Node, NodeWalkScope, find_node_mut, walk_nodes_mut};
+use crate::terminal::{KeyCode, KeyEvent, KeyModifiers, PointerButton, PointerEvent, PointerKind};
+use crate::widgets::node::{Node, NodeWalkScope, find_node_mut, walk_nodes, walk_nodes_mut};
 use crate::widgets::traits::{InteractionResult, TextAction};
 
 impl AppState {
+    pub fn action_bindings(&self) -> &ActionBindings {
+        &self.ui.action_bindings
+    }
+
+    pub fn set_action_bindings(&mut self, bindings: ActionBindings) {
+        self.ui.action_bindings = bindings;
+    }
+
+    /// Maps a raw key through the action bindings before any other key
+    /// handling, so remapped keys reach widgets as their canonical key.
+    pub fn translate_key(&mut self, key: KeyEvent) -> KeyEvent {
+        if self.ui.action_bindings.resolve(key, false).is_none() {
+            return key;
+        }
+        let editing_text = self.focused_edits_text();
+        self.ui.action_bindings.translate(key, editing_text)
+    }
+
+    /// The intent for a raw key. Global bindings come first, so a keymap
+    /// preset cannot shadow shortcuts such as Ctrl+P; other keys go through
+    /// the action bindings and are looked up again as their canonical key.
+    pub fn key_intent(&mut self, key: KeyEvent, key_bindings: &KeyBindings) -> Intent {
+        if let Some(intent) = key_bindings.resolve(key) {
+            return intent;
+        }
+        let key = self.translate_key(key);
+        key_bindings.resolve(key).unwrap_or(Intent::InputKey(key))
+    }
+
+    fn focused_edits_text(&mut self) -> bool {
+        let Some(focused_id) = self.ui.focus.current_id().map(ToOwned::to_owned) else {
+            return false;
+        };
+        self.find_focused_node_mut(&focused_id)
+            .is_some_and(|node| node.edits_text())
+    }
+
     pub fn dispatch_key_to_focused(&mut self, key: KeyEvent) -> InteractionResult {
         let Some(focused_id) = self.ui.focus.current_id().map(ToOwned::to_owned) else {
             return InteractionResult::ignored();
@@ -31,6 +71,13 @@ impl AppState {
         }
 
         let result = self.route_to_focused_node(&focused_id, |node| node.on_key(key));
+        crate::trace_event!(
+            TRACE,
+            node = %focused_id,
+            key = ?key,
+            handled = result.handled,
+            "key routed to widget"
+        );
 
         if result.handled {
             if should_clear_completion_suppression_for_key(key) {
@@ -55,6 +102,20 @@ impl AppState {
         result
     }
 
+    pub fn dispatch_paste_to_focused(&mut self, text: &str) -> InteractionResult {
+        let Some(focused_id) = self.ui.focus.current_id().map(ToOwned::to_owned) else {
+            return InteractionResult::ignored();
+        };
+
+        let result = self.route_to_focused_node(&focused_id, |node| node.on_paste(text));
+
+        if result.handled {
+            self.clear_completion_tab_suppression_for_focused();
+            self.refresh_after_input();
+        }
+        result
+    }
+
     pub fn dispatch_pointer_to_node(
         &mut self,
         target_node_id: &str,
@@ -65,13 +126,19 @@ impl AppState {
         }
 
         self.clean_broken_overlays();
-        let result = {
+        let focus_before = self.ui.focus.current_id().map(str::to_owned);
+        if event.kind == PointerKind::Down(PointerButton::Left) {
+            self.focus_clicked(target_node_id);
+        }
+        let focus_moved = self.ui.focus.current_id() != focus_before.as_deref();
+        let mut result = {
             let nodes = self.flow.current_step_mut().nodes.as_mut_slice();
             let Some(node) = find_node_mut(nodes, target_node_id) else {
                 return InteractionResult::ignored();
             };
             node.on_pointer(event)
         };
+        result.handled |= focus_moved;
 
         if result.handled {
             self.refresh_after_input();
@@ -154,6 +221,30 @@ impl AppState {
         merged
     }
 
+    /// Whether anything needs ticking soon: a widget with pending async
+    /// work or animation, a running task or submit hook, or a step
+    /// countdown. Widgets are not ticked while the terminal is unfocused.
+    pub fn wants_tick(&self) -> bool {
+        if self.has_running_tasks()
+            || self.has_pending_submit_hooks()
+            || self.auto_advance_remaining().is_some()
+        {
+            return true;
+        }
+        if !self.terminal_focused() {
+            return false;
+        }
+        let mut wants = false;
+        for step in self.flow.steps() {
+            walk_nodes(
+                step.nodes.as_slice(),
+                NodeWalkScope::Recursive,
+                &mut |node| wants |= node.wants_tick(),
+            );
+        }
+        wants
+    }
+
     fn accept_and_refresh_completion(&mut self) {
         self.accept_completion_for_focused();
         self.refresh_after_input();
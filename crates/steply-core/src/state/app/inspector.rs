@@ -0,0 +1,305 @@
+use super::AppState;
+use super::state::{Inspector, InspectorEntry};
+use crate::core::value::Value;
+use crate::state::step::StepStatus;
+use crate::terminal::{KeyCode, KeyEvent};
+use crate::widgets::node::{Node, NodeWalkScope, walk_nodes, walk_nodes_mut};
+use crate::widgets::outputs::data::{DataOutput, DataOutputFormat};
+use crate::widgets::traits::{InteractionResult, OutputNode};
+use std::collections::VecDeque;
+
+const INSPECTOR_NODE_ID: &str = "__inspector";
+/// Snapshots kept; the oldest are dropped first.
+const INSPECTOR_HISTORY: usize = 500;
+const PAGE_ENTRIES: isize = 10;
+
+impl AppState {
+    /// Starts recording a snapshot of the store, step, widgets and focus after
+    /// every dispatched intent, for the inspector to scrub through. Off by
+    /// default because each snapshot copies the whole store.
+    pub fn enable_inspector(&mut self) {
+        if self.ui.inspector.is_some() {
+            return;
+        }
+        self.ui.inspector = Some(Inspector {
+            entries: VecDeque::new(),
+            cursor: None,
+            node: None,
+        });
+        self.record_inspector_entry("Start".to_string());
+    }
+
+    /// Whether intents should be recorded: the inspector is enabled and not
+    /// open, since scrubbing is not part of the history.
+    pub fn inspector_recording(&self) -> bool {
+        self.ui
+            .inspector
+            .as_ref()
+            .is_some_and(|inspector| inspector.cursor.is_none())
+    }
+
+    pub fn inspector_active(&self) -> bool {
+        self.ui
+            .inspector
+            .as_ref()
+            .is_some_and(|inspector| inspector.cursor.is_some())
+    }
+
+    pub fn inspector_node(&self) -> Option<&Node> {
+        self.ui.inspector.as_ref()?.node.as_ref()
+    }
+
+    /// Index of the snapshot on screen and the number recorded.
+    pub fn inspector_position(&self) -> Option<(usize, usize)> {
+        let inspector = self.ui.inspector.as_ref()?;
+        Some((inspector.cursor?, inspector.entries.len()))
+    }
+
+    pub fn record_inspector_entry(&mut self, action: String) {
+        let entry = self.inspector_snapshot(action);
+        let Some(inspector) = self.ui.inspector.as_mut() else {
+            return;
+        };
+        if inspector.entries.len() == INSPECTOR_HISTORY {
+            inspector.entries.pop_front();
+        }
+        inspector.entries.push_back(entry);
+    }
+
+    /// Opens on the latest snapshot; returns `false` when not enabled.
+    pub fn open_inspector(&mut self) -> bool {
+        let Some(inspector) = self.ui.inspector.as_mut() else {
+            return false;
+        };
+        let Some(last) = inspector.entries.len().checked_sub(1) else {
+            return false;
+        };
+        inspector.cursor = Some(last);
+        self.refresh_inspector_node();
+        true
+    }
+
+    /// Moves `delta` snapshots back or forth and puts the app in that state.
+    pub fn inspector_step(&mut self, delta: isize) -> bool {
+        let Some((cursor, len)) = self.inspector_position() else {
+            return false;
+        };
+        let target = cursor.saturating_add_signed(delta).min(len - 1);
+        self.inspector_seek(target)
+    }
+
+    pub fn inspector_seek(&mut self, index: usize) -> bool {
+        let Some(inspector) = self.ui.inspector.as_mut() else {
+            return false;
+        };
+        if inspector.cursor.is_none()
+            || inspector.cursor == Some(index)
+            || index >= inspector.entries.len()
+        {
+            return false;
+        }
+        inspector.cursor = Some(index);
+        self.restore_inspector_entry(index);
+        self.refresh_inspector_node();
+        true
+    }
+
+    /// Closes the inspector and goes on from the snapshot on screen; the
+    /// snapshots after it are dropped.
+    pub fn resume_from_inspector(&mut self) {
+        let Some(inspector) = self.ui.inspector.as_mut() else {
+            return;
+        };
+        if let Some(cursor) = inspector.cursor.take() {
+            inspector.entries.truncate(cursor + 1);
+        }
+        inspector.node = None;
+    }
+
+    /// Closes the inspector and returns to the latest snapshot.
+    pub fn close_inspector(&mut self) {
+        let Some((cursor, len)) = self.inspector_position() else {
+            return;
+        };
+        if cursor + 1 != len {
+            self.restore_inspector_entry(len - 1);
+        }
+        if let Some(inspector) = self.ui.inspector.as_mut() {
+            inspector.cursor = None;
+            inspector.node = None;
+        }
+    }
+
+    pub fn toggle_inspector(&mut self) -> bool {
+        if self.inspector_active() {
+            self.close_inspector();
+            true
+        } else {
+            self.open_inspector()
+        }
+    }
+
+    pub fn dispatch_key_to_inspector(&mut self, key: KeyEvent) -> InteractionResult {
+        let Some((_, len)) = self.inspector_position() else {
+            return InteractionResult::ignored();
+        };
+        match key.code {
+            KeyCode::Left | KeyCode::Up => InteractionResult::handled_if(self.inspector_step(-1)),
+            KeyCode::Right | KeyCode::Down => InteractionResult::handled_if(self.inspector_step(1)),
+            KeyCode::PageUp => InteractionResult::handled_if(self.inspector_step(-PAGE_ENTRIES)),
+            KeyCode::PageDown => InteractionResult::handled_if(self.inspector_step(PAGE_ENTRIES)),
+            KeyCode::Home => InteractionResult::handled_if(self.inspector_seek(0)),
+            KeyCode::End => InteractionResult::handled_if(self.inspector_seek(len - 1)),
+            KeyCode::Enter => {
+                self.resume_from_inspector();
+                InteractionResult::handled()
+            }
+            KeyCode::Esc => {
+                self.close_inspector();
+                InteractionResult::handled()
+            }
+            _ => InteractionResult::ignored(),
+        }
+    }
+
+    fn inspector_snapshot(&self, action: String) -> InspectorEntry {
+        InspectorEntry {
+            action,
+            step_id: self.current_step_id().to_string(),
+            statuses: self
+                .flow
+                .steps()
+                .iter()
+                .enumerate()
+                .map(|(index, step)| {
+                    let status = match self.flow.status_at(index) {
+                        StepStatus::Running => StepStatus::Active,
+                        status => status,
+                    };
+                    (step.id.clone(), status)
+                })
+                .collect(),
+            focused_id: self.ui.focus.current_id().map(Into::into),
+            store: self.data.store.clone(),
+            widget_values: self
+                .flow
+                .steps()
+                .iter()
+                .map(|step| {
+                    let mut values = Vec::new();
+                    walk_nodes(
+                        step.nodes.as_slice(),
+                        NodeWalkScope::TopLevel,
+                        &mut |node| {
+                            if let Some(value) = node.value() {
+                                values.push((node.id().into(), value));
+                            }
+                        },
+                    );
+                    (step.id.clone(), values)
+                })
+                .collect(),
+        }
+    }
+
+    /// Puts the store, step, widget values and focus back as recorded. Nothing is
+    /// re-derived, so tasks and change hooks do not run again.
+    fn restore_inspector_entry(&mut self, index: usize) {
+        let Some(entry) = self
+            .ui
+            .inspector
+            .as_ref()
+            .and_then(|inspector| inspector.entries.get(index))
+        else {
+            return;
+        };
+        let store = entry.store.clone();
+        let widget_values = entry.widget_values.clone();
+        let focused_id = entry.focused_id.clone();
+        let step_index = self.step_index_by_id(entry.step_id.as_str());
+        let statuses = self
+            .flow
+            .steps()
+            .iter()
+            .map(|step| {
+                entry
+                    .statuses
+                    .get(step.id.as_str())
+                    .copied()
+                    .unwrap_or(StepStatus::Pending)
+            })
+            .collect();
+
+        self.data.store.replace_values(store);
+        if let Some(step_index) = step_index {
+            self.flow.restore(step_index, statuses);
+        }
+        for step in self.flow.steps_mut() {
+            let Some(values) = widget_values.get(step.id.as_str()) else {
+                continue;
+            };
+            walk_nodes_mut(
+                step.nodes.as_mut_slice(),
+                NodeWalkScope::TopLevel,
+                &mut |node| {
+                    if let Some((_, value)) = values.iter().find(|(id, _)| id.as_str() == node.id())
+                    {
+                        node.set_value(value.clone());
+                    }
+                },
+            );
+        }
+        self.prepare_current_step_for_preview();
+        self.force_hydrate_current_step_from_store();
+        if let Some(id) = focused_id {
+            self.rebuild_focus_with_target(Some(id.as_str()), false);
+        }
+    }
+
+    fn refresh_inspector_node(&mut self) {
+        let Some(inspector) = self.ui.inspector.as_mut() else {
+            return;
+        };
+        let Some(cursor) = inspector.cursor else {
+            return;
+        };
+        let Some(entry) = inspector.entries.get(cursor) else {
+            return;
+        };
+        let mut output = DataOutput::new(
+            INSPECTOR_NODE_ID,
+            Some(format!(
+                "Inspector {}/{}: {}",
+                cursor + 1,
+                inspector.entries.len(),
+                entry.action
+            )),
+            DataOutputFormat::Json,
+        );
+        output.set_value(Value::Object(
+            [
+                ("step".to_string(), Value::Text(entry.step_id.clone())),
+                (
+                    "focus".to_string(),
+                    entry
+                        .focused_id
+                        .as_ref()
+                        .map_or(Value::None, |id| Value::Text(id.to_string())),
+                ),
+                (
+                    "values".to_string(),
+                    Value::Object(
+                        entry
+                            .store
+                            .iter()
+                            .map(|(key, value)| (key.to_string(), value.clone()))
+                            .collect(),
+                    ),
+                ),
+            ]
+            .into_iter()
+            .collect(),
+        ));
+        inspector.node = Some(Node::Output(Box::new(output)));
+    }
+}
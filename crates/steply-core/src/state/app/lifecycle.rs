@@ -1,7 +1,10 @@
 use crate::state::change::collect_store_ownership;
 use crate::state::flow::Flow;
+use crate::state::lifecycle::LifecycleEvent;
+use crate::task::graph::dependency_order;
 use crate::task::{TaskSpec, collect_inline_tasks_from_flow, validate_task_id_collisions};
 
+use super::flow::HookScope;
 use super::state::{DataState, RuntimeState, ViewState};
 use super::{AppState, AppStateInitError};
 
@@ -15,6 +18,7 @@ impl AppState {
         validate_task_id_collisions(&task_specs, &inline_specs)?;
         let mut specs = inline_specs;
         specs.extend(task_specs);
+        dependency_order(specs.as_slice())?;
 
         let mut state = Self {
             flow,
@@ -25,6 +29,7 @@ impl AppState {
             should_exit: false,
             pending_back_confirm: None,
             pending_exit_confirm: None,
+            pending_session_restore: None,
             confirm_finish: true,
         };
         state.runtime.store_ownership =
@@ -33,12 +38,15 @@ impl AppState {
             state.should_exit = true;
         } else {
             state.reconcile_current_step_visibility();
+            state.rebuild_review_step();
             state.refresh_current_step_bindings();
             state.rebuild_focus();
             crate::task::engine::trigger_flow_start_tasks(&mut state);
             let current_step_id = state.current_step_id().to_string();
             crate::task::engine::trigger_step_enter_tasks(&mut state, current_step_id.as_str());
             crate::task::engine::bootstrap_interval_tasks(&mut state);
+            state.run_lifecycle_hooks_or_report(HookScope::Flow, LifecycleEvent::Enter);
+            state.run_lifecycle_hooks_or_report(HookScope::Step, LifecycleEvent::Enter);
         }
         Ok(state)
     }
@@ -0,0 +1,36 @@
+use super::AppState;
+use crate::core::i18n::{Localization, MessageCatalog};
+use std::sync::Arc;
+
+impl AppState {
+    pub fn localization(&self) -> &Localization {
+        &self.ui.localization
+    }
+
+    pub fn set_localization(&mut self, localization: Localization) {
+        self.ui.localization = localization;
+    }
+
+    pub fn add_message_catalog(&mut self, catalog: MessageCatalog) {
+        self.ui.localization.add_catalog(catalog);
+    }
+
+    /// Switches the active locale. Returns `false` when no catalog matches,
+    /// in which case labels and messages render as written in the flow.
+    pub fn set_locale(&mut self, locale: &str) -> bool {
+        self.ui.localization.select(locale)
+    }
+
+    /// Selects the first of the user's `preferred` locales that has a
+    /// catalog, returning it; see [`Localization::negotiate`].
+    pub fn negotiate_locale<'a>(
+        &mut self,
+        preferred: impl IntoIterator<Item = &'a str>,
+    ) -> Option<String> {
+        self.ui.localization.negotiate(preferred)
+    }
+
+    pub fn message_catalog(&self) -> &Arc<MessageCatalog> {
+        self.ui.localization.active()
+    }
+}
@@ -1,4 +1,6 @@
 use crate::core::NodeId;
+use crate::core::i18n::Message;
+use crate::core::value::Value;
 use crate::state::flow::Flow;
 use crate::state::validation::ValidationState;
 use crate::task::TaskSetupError;
@@ -19,6 +21,8 @@ pub enum ExitConfirmChoice {
 pub enum ExitConfirmMode {
     ExitApplication,
     FinishFlow,
+    /// Offers to restore answers recovered from a session that crashed.
+    RestoreSession,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -30,12 +34,14 @@ pub struct ExitConfirmState {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum AppStateInitError {
     InvalidTaskSetup(TaskSetupError),
+    InvalidPrefill(String),
 }
 
 impl fmt::Display for AppStateInitError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::InvalidTaskSetup(err) => write!(f, "invalid task setup: {err}"),
+            Self::InvalidPrefill(message) => write!(f, "invalid prefill: {message}"),
         }
     }
 }
@@ -44,6 +50,7 @@ impl Error for AppStateInitError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
             Self::InvalidTaskSetup(err) => Some(err),
+            Self::InvalidPrefill(_) => None,
         }
     }
 }
@@ -63,6 +70,7 @@ pub struct AppState {
     should_exit: bool,
     pending_back_confirm: Option<String>,
     pending_exit_confirm: Option<ExitConfirmState>,
+    pending_session_restore: Option<Value>,
     confirm_finish: bool,
 }
 
@@ -100,7 +108,7 @@ impl AppState {
         &self.runtime.validation
     }
 
-    pub fn visible_error(&self, id: &str) -> Option<&str> {
+    pub fn visible_error(&self, id: &str) -> Option<&Message> {
         self.runtime.validation.visible_error(id)
     }
 
@@ -119,7 +127,7 @@ impl AppState {
         self.runtime.validation.reset_warnings_acknowledged();
     }
 
-    pub fn current_step_errors(&self) -> &[String] {
+    pub fn current_step_errors(&self) -> &[Message] {
         self.runtime.validation.step_errors()
     }
 
@@ -134,12 +142,22 @@ mod effects;
 mod exit;
 mod flow;
 mod input;
+mod inspector;
 mod lifecycle;
+mod localization;
 mod overlay_access;
+mod prefill;
 mod query;
+mod result_export;
+mod secrets;
 mod state;
+mod task_details;
+mod task_failure;
+mod timers;
 mod transaction;
 mod validation_runtime;
+mod validation_summary;
+mod value_preview;
 mod value_sync;
 
 #[cfg(test)]
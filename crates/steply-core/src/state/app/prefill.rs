@@ -0,0 +1,246 @@
+use super::{AppState, AppStateInitError};
+use crate::core::store_refs::parse_store_selector;
+use crate::core::value::Value;
+use crate::core::value_path::{PathSegment, ValuePath, ValueTarget};
+use crate::state::change::StoreWriteOrigin;
+use crate::state::prefill::{DOTENV_ROOT, Prefill};
+use crate::state::step::StepStatus;
+use crate::widgets::node::{NodeWalkScope, walk_nodes};
+use std::collections::HashSet;
+use std::path::Path;
+
+impl AppState {
+    pub fn apply_prefill(&mut self, prefill: &Prefill) -> Result<(), AppStateInitError> {
+        self.apply_prefill_with_env(prefill, |name| std::env::var(name).ok())
+    }
+
+    pub fn apply_prefill_with_env(
+        &mut self,
+        prefill: &Prefill,
+        env: impl Fn(&str) -> Option<String>,
+    ) -> Result<(), AppStateInitError> {
+        if let Some(path) = prefill.previous_run.as_deref() {
+            if let Some(answers) = read_previous_run_answers(path)? {
+                self.prefill_values(&answers)
+                    .map_err(AppStateInitError::InvalidPrefill)?;
+            }
+            self.data.previous_run_path = Some(path.to_path_buf());
+        }
+        for (name, value) in &prefill.dotenv {
+            let path = ValuePath::new(vec![PathSegment::Key(name.clone())]);
+            self.prefill_target(
+                ValueTarget::path(DOTENV_ROOT, path),
+                Value::Text(value.clone()),
+            );
+        }
+        for (target, value) in &prefill.values {
+            self.prefill_target(target.clone(), value.clone());
+        }
+        for (target, name) in &prefill.env {
+            let value = env(name.as_str()).or_else(|| prefill.dotenv.get(name).cloned());
+            if let Some(value) = value {
+                self.prefill_target(target.clone(), Value::Text(value));
+            }
+        }
+        Ok(())
+    }
+
+    /// Seeds the store from an object keyed by store selectors. Nested objects
+    /// are applied leaf by leaf so sibling fields under the same root keep
+    /// their own defaults.
+    pub fn prefill_values(&mut self, values: &Value) -> Result<(), String> {
+        let Value::Object(entries) = values else {
+            return Err("prefill values must be an object keyed by store selector".to_string());
+        };
+        let mut leaves = Vec::new();
+        for (selector, value) in entries {
+            let target = parse_store_selector(selector)
+                .map_err(|err| format!("invalid prefill selector '{selector}': {err}"))?;
+            flatten_prefill_value(target, value, &mut leaves);
+        }
+        for (target, value) in leaves {
+            self.prefill_target(target, value);
+        }
+        Ok(())
+    }
+
+    pub fn prefill_target(&mut self, target: ValueTarget, value: Value) {
+        if self.data.store.set_target(&target, value.clone()).is_err() {
+            return;
+        }
+        if !self.flow.is_empty() {
+            self.force_hydrate_current_step_from_store();
+        }
+        self.apply_system_value_change(target.clone(), value.clone(), StoreWriteOrigin::System);
+        self.data
+            .prefilled
+            .retain(|(existing, _)| *existing != target);
+        self.data.prefilled.push((target, value));
+    }
+
+    /// Prefills the value bound to the widget with the given id, wherever it
+    /// lives in the flow. Used by adapters that map command line arguments
+    /// onto field ids rather than store selectors.
+    pub fn prefill_field(&mut self, field_id: &str, value: Value) -> Result<(), String> {
+        let mut target = None;
+        for step in self.flow.steps() {
+            walk_nodes(
+                step.nodes.as_slice(),
+                NodeWalkScope::Recursive,
+                &mut |node| {
+                    if target.is_none() && node.id() == field_id {
+                        target = node
+                            .store_binding()
+                            .and_then(|binding| binding.value.clone());
+                    }
+                },
+            );
+        }
+        let target = target.ok_or_else(|| format!("unknown prefill field '{field_id}'"))?;
+        self.prefill_target(target, value);
+        Ok(())
+    }
+
+    /// Submits leading steps whose bound widgets are all still prefilled,
+    /// stopping at the first step that needs input, fails validation, or is
+    /// the last visible one, so the flow never finishes unattended. Returns
+    /// the number of steps skipped.
+    pub fn skip_answered_steps(&mut self) -> usize {
+        let mut skipped = 0;
+        while !self.flow.is_empty() && self.current_step_answered() {
+            let index = self.flow.current_index();
+            let has_next = (index + 1..self.flow.len()).any(|next| self.step_visible_at(next));
+            if !has_next {
+                break;
+            }
+            self.handle_step_submit();
+            if self.flow.current_index() <= index {
+                break;
+            }
+            skipped += 1;
+        }
+        skipped
+    }
+
+    fn current_step_answered(&self) -> bool {
+        let prefilled = self.prefilled_node_ids();
+        let mut bound = 0usize;
+        let mut answered = true;
+        walk_nodes(
+            self.flow.current_step().nodes.as_slice(),
+            NodeWalkScope::Recursive,
+            &mut |node| {
+                if node
+                    .store_binding()
+                    .and_then(|binding| binding.value.as_ref())
+                    .is_none()
+                {
+                    return;
+                }
+                bound += 1;
+                answered &= prefilled.contains(node.id());
+            },
+        );
+        bound > 0 && answered
+    }
+
+    /// Ids of current-step widgets whose bound value still matches what was
+    /// prefilled, i.e. the user has not edited them yet.
+    pub fn prefilled_node_ids(&self) -> HashSet<String> {
+        let mut out = HashSet::new();
+        if self.data.prefilled.is_empty() || self.flow.is_empty() {
+            return out;
+        }
+        walk_nodes(
+            self.flow.current_step().nodes.as_slice(),
+            NodeWalkScope::Recursive,
+            &mut |node| {
+                let Some(target) = node
+                    .store_binding()
+                    .and_then(|binding| binding.value.as_ref())
+                else {
+                    return;
+                };
+                let mut matched = false;
+                for (prefilled, value) in &self.data.prefilled {
+                    if !target.contains_target(prefilled) {
+                        continue;
+                    }
+                    if self.data.store.get_target(prefilled) != Some(value) {
+                        return;
+                    }
+                    matched = true;
+                }
+                if matched && node.value() == self.data.store.get_target(target).cloned() {
+                    out.insert(node.id().to_string());
+                }
+            },
+        );
+        out
+    }
+
+    pub fn previous_run_path(&self) -> Option<&Path> {
+        self.data.previous_run_path.as_deref()
+    }
+
+    /// Writes the collected answers to the configured previous-run file once
+    /// the flow has finished, so the next run can prefill from them.
+    pub fn save_previous_run_answers(&self) -> std::io::Result<bool> {
+        let Some(path) = self.previous_run_path() else {
+            return Ok(false);
+        };
+        if self.flow.is_empty() || self.flow.current_status() != StepStatus::Done {
+            return Ok(false);
+        }
+        let json = self
+            .exportable_values()
+            .to_json_string_pretty()
+            .map_err(std::io::Error::other)?;
+        if let Some(parent) = path.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, json)?;
+        Ok(true)
+    }
+}
+
+fn read_previous_run_answers(path: &Path) -> Result<Option<Value>, AppStateInitError> {
+    let raw = match std::fs::read_to_string(path) {
+        Ok(raw) => raw,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => {
+            return Err(AppStateInitError::InvalidPrefill(format!(
+                "failed to read previous run answers '{}': {err}",
+                path.display()
+            )));
+        }
+    };
+    Value::from_json(raw.as_str()).map(Some).map_err(|err| {
+        AppStateInitError::InvalidPrefill(format!(
+            "invalid previous run answers '{}': {err}",
+            path.display()
+        ))
+    })
+}
+
+fn flatten_prefill_value(target: ValueTarget, value: &Value, out: &mut Vec<(ValueTarget, Value)>) {
+    match value {
+        Value::Object(entries) if !entries.is_empty() => {
+            for (key, nested) in entries {
+                flatten_prefill_value(child_target(&target, key), nested, out);
+            }
+        }
+        _ => out.push((target, value.clone())),
+    }
+}
+
+fn child_target(target: &ValueTarget, key: &str) -> ValueTarget {
+    let mut segments = target
+        .nested_path()
+        .map(|path| path.segments().to_vec())
+        .unwrap_or_default();
+    segments.push(PathSegment::Key(key.to_string()));
+    ValueTarget::path(target.root().clone(), ValuePath::new(segments))
+}
@@ -1,6 +1,11 @@
 use super::AppState;
 use crate::core::value::Value;
 use crate::state::step::{Step, StepStatus};
+use crate::terminal::TerminalCapabilities;
+use crate::ui::canvas::Canvas;
+use crate::ui::theme::Theme;
+use crate::widgets::node::{Node, find_node};
+use std::sync::Arc;
 
 impl AppState {
     pub fn current_step_id(&self) -> &str {
@@ -51,13 +56,11 @@ impl AppState {
             return status;
         };
         let now = crate::time::Instant::now();
+        let running = self.is_step_visually_running_at(step.id.as_str(), now)
+            || self.has_pending_submit_hooks_for(step.id.as_str());
         match status {
-            StepStatus::Active if self.is_step_visually_running_at(step.id.as_str(), now) => {
-                StepStatus::Running
-            }
-            StepStatus::Running if !self.is_step_visually_running_at(step.id.as_str(), now) => {
-                StepStatus::Active
-            }
+            StepStatus::Active if running => StepStatus::Running,
+            StepStatus::Running if !running => StepStatus::Active,
             _ => status,
         }
     }
@@ -104,10 +107,69 @@ impl AppState {
         self.ui.hints_visible = !self.ui.hints_visible;
     }
 
+    /// Whether the terminal window has focus, as last reported by the
+    /// terminal. Assumed focused until told otherwise.
+    pub fn terminal_focused(&self) -> bool {
+        !self.ui.terminal_blurred
+    }
+
+    pub fn terminal_capabilities(&self) -> TerminalCapabilities {
+        self.ui.capabilities
+    }
+
+    pub fn set_terminal_capabilities(&mut self, capabilities: TerminalCapabilities) {
+        self.ui.capabilities = capabilities;
+    }
+
+    pub fn theme(&self) -> &Arc<Theme> {
+        &self.ui.theme
+    }
+
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.ui.theme = Arc::new(theme);
+    }
+
+    pub fn breadcrumb_visible(&self) -> bool {
+        self.ui.breadcrumb_visible
+    }
+
+    pub fn set_breadcrumb_visible(&mut self, visible: bool) {
+        self.ui.breadcrumb_visible = visible;
+    }
+
+    pub fn canvas(&self) -> Canvas {
+        self.ui.canvas
+    }
+
+    pub fn set_canvas(&mut self, canvas: Canvas) {
+        self.ui.canvas = canvas;
+    }
+
     pub fn focused_id(&self) -> Option<&str> {
         self.ui.focus.current_id()
     }
 
+    /// The name a screen reader hears for `id`: its `aria_label` override,
+    /// else its label, else the id itself.
+    pub fn accessible_label<'a>(&'a self, id: &'a str) -> &'a str {
+        if self.flow.is_empty() {
+            return id;
+        }
+        let step = self.flow.current_step();
+        if let Some(label) = step.aria_labels.get(id) {
+            return label.as_str();
+        }
+        find_node(step.nodes.as_slice(), id)
+            .map(Node::label)
+            .filter(|label| !label.is_empty())
+            .unwrap_or(id)
+    }
+
+    /// Changes whenever a value in the store is written.
+    pub fn store_revision(&self) -> u64 {
+        self.data.store.revision()
+    }
+
     pub fn store_value(&self, selector: &str) -> Option<&Value> {
         self.data.store.get_selector(selector)
     }
@@ -0,0 +1,106 @@
+use super::AppState;
+use crate::state::export::{ExportTarget, ResultExport};
+use crate::state::report::{FlowReport, ReportStep, ReportTask};
+use crate::state::step::StepStatus;
+use std::io::Write;
+
+impl AppState {
+    pub fn result_export(&self) -> Option<&ResultExport> {
+        self.data.result_export.as_ref()
+    }
+
+    pub fn set_result_export(&mut self, export: Option<ResultExport>) {
+        self.data.result_export = export;
+    }
+
+    /// The configured export rendered from the collected values, or `None`
+    /// while the flow has not finished.
+    pub fn render_result_export(&self) -> Option<Result<String, String>> {
+        let export = self.result_export()?;
+        if self.flow.is_empty() || self.flow.current_status() != StepStatus::Done {
+            return None;
+        }
+        if export.template.is_none()
+            && let Some(format) = export.format.report_format()
+        {
+            return Some(Ok(self.flow_report().render(format)));
+        }
+        Some(export.render(&self.exportable_values()))
+    }
+
+    /// The answers of every visited step, by widget id, and the last run
+    /// of every task that ran.
+    pub fn flow_report(&self) -> FlowReport {
+        let mut steps = Vec::new();
+        let reached = if self.flow.is_empty() {
+            0
+        } else {
+            self.flow.current_index() + 1
+        };
+        for index in 0..reached {
+            let step = &self.flow.steps()[index];
+            if step.review || !self.step_visible_at(index) {
+                continue;
+            }
+            let entries = self.review_entries(step.nodes.as_slice());
+            if entries.is_empty() {
+                continue;
+            }
+            steps.push(ReportStep {
+                id: step.id.clone(),
+                title: step.prompt.clone(),
+                description: step.description.clone(),
+                entries,
+            });
+        }
+        let mut tasks = self
+            .runtime
+            .task_runs
+            .iter()
+            .filter(|(_, run)| run.last_started_run_id().is_some())
+            .map(|(id, run)| ReportTask {
+                id: id.to_string(),
+                runs: run.finished_runs(),
+                outcome: run.last_outcome().cloned(),
+                duration: run.last_duration(),
+            })
+            .collect::<Vec<_>>();
+        tasks.sort_by(|a, b| a.id.cmp(&b.id));
+        FlowReport { steps, tasks }
+    }
+
+    /// The finished flow's values as one compact JSON document, independent
+    /// of any configured export; `None` while the flow has not finished.
+    pub fn render_result_json(&self) -> Option<Result<String, String>> {
+        if self.flow.is_empty() || self.flow.current_status() != StepStatus::Done {
+            return None;
+        }
+        Some(self.exportable_values().to_json_string())
+    }
+
+    /// Writes the finished flow's values to the configured target. Meant to
+    /// run after the terminal is restored so stdout output stays pipeable.
+    pub fn write_result_export(&self) -> std::io::Result<bool> {
+        let (Some(export), Some(rendered)) = (self.result_export(), self.render_result_export())
+        else {
+            return Ok(false);
+        };
+        let rendered = rendered.map_err(std::io::Error::other)?;
+        match &export.target {
+            ExportTarget::File(path) => {
+                if let Some(parent) = path.parent()
+                    && !parent.as_os_str().is_empty()
+                {
+                    std::fs::create_dir_all(parent)?;
+                }
+                std::fs::write(path, rendered)?;
+            }
+            ExportTarget::Stdout => {
+                let mut stdout = std::io::stdout().lock();
+                stdout.write_all(rendered.as_bytes())?;
+                stdout.flush()?;
+            }
+        }
+        Ok(true)
+    }
+}
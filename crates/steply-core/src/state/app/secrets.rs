@@ -0,0 +1,144 @@
+use super::AppState;
+use crate::core::secrets::{SecretKey, SecretStore};
+use crate::core::value::Value;
+use crate::core::value_path::{PathSegment, ValueTarget};
+use crate::state::step::StepStatus;
+use crate::widgets::node::{NodeWalkScope, walk_nodes, walk_nodes_mut};
+
+impl AppState {
+    /// Widgets whose value belongs in a secret store, by node id.
+    pub fn secret_fields(&self) -> Vec<(String, SecretKey)> {
+        let mut fields = Vec::new();
+        for step in self.flow.steps() {
+            walk_nodes(
+                step.nodes.as_slice(),
+                NodeWalkScope::Recursive,
+                &mut |node| {
+                    if let Some(key) = node.secret() {
+                        fields.push((node.id().to_string(), key.clone()));
+                    }
+                },
+            );
+        }
+        fields
+    }
+
+    /// Fills secret widgets from `store`. Returns a message for each secret
+    /// that could not be read; those widgets keep their default.
+    pub fn load_secrets(&mut self, store: &dyn SecretStore) -> Vec<String> {
+        let mut errors = Vec::new();
+        for step in self.flow.steps_mut() {
+            walk_nodes_mut(
+                step.nodes.as_mut_slice(),
+                NodeWalkScope::Recursive,
+                &mut |node| {
+                    let Some(key) = node.secret() else {
+                        return;
+                    };
+                    match store.read(key) {
+                        Ok(Some(secret)) => node.set_value(Value::Text(secret)),
+                        Ok(None) => {}
+                        Err(err) => errors.push(format!("secret {key}: {err}")),
+                    }
+                },
+            );
+        }
+        errors
+    }
+
+    /// Writes the submitted secrets to `store` once the flow has finished.
+    /// Empty values are skipped. Returns a message for each failed write.
+    pub fn save_secrets(&self, store: &dyn SecretStore) -> Vec<String> {
+        if self.flow.is_empty() || self.flow.current_status() != StepStatus::Done {
+            return Vec::new();
+        }
+        let mut errors = Vec::new();
+        for step in self.flow.steps() {
+            walk_nodes(
+                step.nodes.as_slice(),
+                NodeWalkScope::Recursive,
+                &mut |node| {
+                    let Some(key) = node.secret() else {
+                        return;
+                    };
+                    let Some(Value::Text(secret)) = node.value() else {
+                        return;
+                    };
+                    if secret.is_empty() {
+                        return;
+                    }
+                    if let Err(err) = store.write(key, secret.as_str()) {
+                        errors.push(format!("secret {key}: {err}"));
+                    }
+                },
+            );
+        }
+        errors
+    }
+
+    /// The collected values minus anything a secret or masked widget wrote,
+    /// for exports and files that outlive the run.
+    pub fn exportable_values(&self) -> Value {
+        let mut values = self.collected_values();
+        for target in self.hidden_targets() {
+            remove_target(&mut values, &target);
+        }
+        values
+    }
+
+    /// Where secret and masked widgets write their values.
+    pub(super) fn hidden_targets(&self) -> Vec<ValueTarget> {
+        let mut targets = Vec::new();
+        for step in self.flow.steps() {
+            walk_nodes(
+                step.nodes.as_slice(),
+                NodeWalkScope::Recursive,
+                &mut |node| {
+                    if node.secret().is_none() && !node.value_masked() {
+                        return;
+                    }
+                    targets.push(ValueTarget::node(node.id()));
+                    if let Some(binding) = node.store_binding() {
+                        targets.extend(binding.value.iter().cloned());
+                        targets.extend(binding.writes.iter().map(|write| write.target.clone()));
+                    }
+                },
+            );
+        }
+        targets
+    }
+}
+
+fn remove_target(values: &mut Value, target: &ValueTarget) {
+    let Value::Object(roots) = values else {
+        return;
+    };
+    let Some(path) = target.nested_path() else {
+        roots.shift_remove(target.root().as_str());
+        return;
+    };
+    let Some((last, parents)) = path.segments().split_last() else {
+        roots.shift_remove(target.root().as_str());
+        return;
+    };
+    let mut current = roots.get_mut(target.root().as_str());
+    for segment in parents {
+        current = match (current, segment) {
+            (Some(Value::Object(map)), PathSegment::Key(key)) => map.get_mut(key.as_str()),
+            (Some(Value::List(list)), PathSegment::Index(index)) => list.get_mut(*index),
+            _ => None,
+        };
+    }
+    match (current, last) {
+        (Some(Value::Object(map)), PathSegment::Key(key)) => {
+            map.shift_remove(key.as_str());
+        }
+        // Keep the positions of the other items.
+        (Some(Value::List(list)), PathSegment::Index(index)) => {
+            if let Some(item) = list.get_mut(*index) {
+                *item = Value::None;
+            }
+        }
+        _ => {}
+    }
+}
@@ -1,16 +1,29 @@
 use crate::core::NodeId;
+use crate::core::action_bindings::ActionBindings;
+use crate::core::i18n::{Localization, Message};
+use crate::core::value::Value;
+use crate::core::value_path::ValueTarget;
 use crate::runtime::scheduler::SchedulerCommand;
 use crate::state::change::StoreOwnershipRegistry;
+use crate::state::export::ResultExport;
 use crate::state::focus::FocusState;
 use crate::state::overlay::OverlayState;
+use crate::state::step::StepStatus;
 use crate::state::store::ValueStore;
 use crate::state::validation::ValidationState;
 use crate::task::{
-    TaskCancelToken, TaskId, TaskInvocation, TaskRequest, TaskRunState, TaskSpec, TaskTrigger,
+    TaskCancelToken, TaskId, TaskInvocation, TaskLogBuffer, TaskRequest, TaskRunState, TaskSpec,
+    TaskTrigger,
 };
+use crate::terminal::TerminalCapabilities;
 use crate::time::{Duration, Instant};
+use crate::ui::canvas::Canvas;
+use crate::ui::theme::Theme;
+use crate::widgets::node::Node;
 use crate::widgets::node_index::NodeIndex;
-use std::collections::{HashMap, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::PathBuf;
+use std::sync::Arc;
 
 use super::input::completion::CompletionSession;
 
@@ -19,6 +32,8 @@ pub struct RuntimeLimits {
     pub max_pending_scheduler_commands: usize,
     pub max_pending_task_invocations: usize,
     pub max_queued_task_requests_per_task: usize,
+    /// Output lines kept per task for the task detail view.
+    pub max_task_log_lines: usize,
 }
 
 impl Default for RuntimeLimits {
@@ -27,6 +42,7 @@ impl Default for RuntimeLimits {
             max_pending_scheduler_commands: 512,
             max_pending_task_invocations: 128,
             max_queued_task_requests_per_task: 128,
+            max_task_log_lines: 1000,
         }
     }
 }
@@ -40,11 +56,75 @@ pub(super) struct ViewState {
     pub(super) completion_session: Option<CompletionSession>,
     pub(super) completion_tab_suppressed_for: Option<NodeId>,
     pub(super) hints_visible: bool,
+    pub(super) terminal_blurred: bool,
+    pub(super) capabilities: TerminalCapabilities,
+    pub(super) theme: Arc<Theme>,
+    pub(super) breadcrumb_visible: bool,
+    pub(super) canvas: Canvas,
+    pub(super) value_preview: Option<Node>,
+    /// The focused widget's value, filling the screen.
+    pub(super) expanded_value: Option<ExpandedValue>,
+    /// Terminal height as last reported by the runner; 0 until then.
+    pub(super) viewport_rows: u16,
+    pub(super) task_details: Option<Node>,
+    /// Failures of tasks with the `Prompt` policy, the first one on screen.
+    pub(super) task_failure_prompts: VecDeque<TaskFailurePrompt>,
+    pub(super) validation_summary: Option<ValidationSummary>,
+    /// Recorded history; `None` unless the inspector was enabled.
+    pub(super) inspector: Option<Inspector>,
+    pub(super) localization: Localization,
+    pub(super) action_bindings: ActionBindings,
 }
 
 #[derive(Default)]
 pub(super) struct DataState {
     pub(super) store: ValueStore,
+    pub(super) prefilled: Vec<(ValueTarget, Value)>,
+    pub(super) previous_run_path: Option<PathBuf>,
+    pub(super) result_export: Option<ResultExport>,
+}
+
+pub(super) struct ExpandedValue {
+    pub(super) title: String,
+    pub(super) node: Node,
+}
+
+pub(super) struct TaskFailurePrompt {
+    pub(super) task_id: TaskId,
+    pub(super) error: String,
+    pub(super) node: Node,
+}
+
+pub(super) struct ValidationSummary {
+    pub(super) entries: Vec<ValidationSummaryEntry>,
+    pub(super) node: Node,
+}
+
+pub(super) struct ValidationSummaryEntry {
+    /// `None` for errors of the step's own validators.
+    pub(super) node_id: Option<NodeId>,
+    /// Passed to the node's `reveal` to reach a cell or item inside it.
+    pub(super) target: Option<String>,
+    pub(super) label: String,
+    pub(super) message: Message,
+}
+
+pub(super) struct Inspector {
+    pub(super) entries: VecDeque<InspectorEntry>,
+    /// The entry on screen while the inspector is open.
+    pub(super) cursor: Option<usize>,
+    pub(super) node: Option<Node>,
+}
+
+pub(super) struct InspectorEntry {
+    pub(super) action: String,
+    pub(super) step_id: String,
+    pub(super) statuses: HashMap<String, StepStatus>,
+    pub(super) focused_id: Option<NodeId>,
+    pub(super) store: ValueStore,
+    /// Top-level widget values by step id, for widgets whose value is not
+    /// in the store yet.
+    pub(super) widget_values: HashMap<String, Vec<(NodeId, Value)>>,
 }
 
 #[derive(Clone)]
@@ -55,6 +135,30 @@ pub(super) struct RunningTaskHandle {
     pub(super) started_at: Instant,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct PendingSubmitHooks {
+    pub(super) step_id: String,
+    /// Hook runs still in flight; the run id is `None` while the task waits
+    /// for its dependencies.
+    pub(super) runs: Vec<(TaskId, Option<u64>)>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct StepTimerState {
+    pub(super) step_id: String,
+    pub(super) started_at: Instant,
+    pub(super) fired: bool,
+    pub(super) shown_secs: u64,
+}
+
+#[derive(Debug, Default)]
+pub(super) struct IdleState {
+    pub(super) timeout: Option<Duration>,
+    pub(super) last_activity: Option<Instant>,
+    pub(super) fired: bool,
+    pub(super) pending_event: bool,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(super) struct TaskVisualLoadingConfig {
     pub(super) visibility_delay: Duration,
@@ -83,12 +187,24 @@ pub(super) struct RuntimeState {
     pub(super) pending_scheduler: Vec<SchedulerCommand>,
     pub(super) pending_task_invocations: Vec<TaskInvocation>,
     pub(super) queued_task_requests: HashMap<TaskId, VecDeque<TaskRequest>>,
+    /// Requests held until their dependencies finish or a run slot frees up.
+    pub(super) parked_task_requests: Vec<TaskRequest>,
+    /// Tasks that did not run because the dependency they map to failed.
+    pub(super) blocked_tasks: HashMap<TaskId, TaskId>,
+    pub(super) max_parallel_tasks: Option<usize>,
+    /// Tasks waiting out the backoff before their next attempt.
+    pub(super) retrying_tasks: HashSet<TaskId>,
     pub(super) running_task_cancellations: HashMap<TaskId, Vec<RunningTaskHandle>>,
     pub(super) task_runs: HashMap<TaskId, TaskRunState>,
+    /// Output of each task's latest run.
+    pub(super) task_logs: HashMap<TaskId, TaskLogBuffer>,
     pub(super) task_specs: HashMap<TaskId, TaskSpec>,
     pub(super) task_triggers: Vec<(TaskId, TaskTrigger)>,
     pub(super) store_ownership: StoreOwnershipRegistry,
     pub(super) task_visual_loading: TaskVisualLoadingState,
+    pub(super) pending_submit_hooks: Option<PendingSubmitHooks>,
+    pub(super) step_timer: Option<StepTimerState>,
+    pub(super) idle: IdleState,
 }
 
 impl RuntimeState {
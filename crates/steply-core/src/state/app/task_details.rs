@@ -0,0 +1,83 @@
+use super::AppState;
+use crate::runtime::event::SystemEvent;
+use crate::task::{TaskId, TaskLogBuffer};
+use crate::terminal::{KeyCode, KeyEvent};
+use crate::widgets::components::task_details::TaskDetailsView;
+use crate::widgets::node::Node;
+use crate::widgets::traits::InteractionResult;
+
+const TASK_DETAILS_NODE_ID: &str = "__task_details";
+
+impl AppState {
+    /// Output of the latest run of `task_id`, kept while the flow runs.
+    pub fn task_log(&self, task_id: &str) -> Option<&TaskLogBuffer> {
+        self.runtime.task_logs.get(task_id)
+    }
+
+    pub fn task_details_active(&self) -> bool {
+        self.ui.task_details.is_some()
+    }
+
+    pub fn task_details_node(&self) -> Option<&Node> {
+        self.ui.task_details.as_ref()
+    }
+
+    pub fn open_task_details(&mut self) {
+        let mut view = TaskDetailsView::new(TASK_DETAILS_NODE_ID, "Tasks")
+            .with_history_lines(self.runtime.limits.max_task_log_lines);
+        for entry in self.task_graph().entries() {
+            view = view.with_task(entry.id.clone(), self.task_log(entry.id.as_str()));
+        }
+        self.ui.task_details = Some(Node::Component(Box::new(view)));
+    }
+
+    pub fn close_task_details(&mut self) {
+        self.ui.task_details = None;
+    }
+
+    pub fn toggle_task_details(&mut self) {
+        if self.task_details_active() {
+            self.close_task_details();
+        } else {
+            self.open_task_details();
+        }
+    }
+
+    /// Keys go to the view first; an Esc it does not use closes it.
+    pub fn dispatch_key_to_task_details(&mut self, key: KeyEvent) -> InteractionResult {
+        let Some(node) = self.ui.task_details.as_mut() else {
+            return InteractionResult::ignored();
+        };
+        let result = node.on_key(key);
+        if !result.handled && key.code == KeyCode::Esc {
+            self.close_task_details();
+            return InteractionResult::handled();
+        }
+        result
+    }
+
+    pub(in crate::state::app) fn record_task_log_line(
+        &mut self,
+        task_id: &TaskId,
+        run_id: u64,
+        line: &str,
+    ) {
+        let limit = self.runtime.limits.max_task_log_lines;
+        // Lines of a run that has been superseded are dropped.
+        if let Some(log) = self.runtime.task_logs.get_mut(task_id)
+            && log.run_id() == run_id
+        {
+            log.push(line.to_string(), limit);
+        }
+    }
+
+    pub(in crate::state::app) fn notify_task_details(
+        &mut self,
+        event: &SystemEvent,
+    ) -> InteractionResult {
+        match self.ui.task_details.as_mut() {
+            Some(node) => node.on_system_event(event),
+            None => InteractionResult::ignored(),
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use super::AppState;
+use super::state::TaskFailurePrompt;
+use crate::core::i18n::Message;
+use crate::core::value::Value;
+use crate::task::engine::release_parked_task_requests;
+use crate::task::{TaskId, TaskRequest};
+use crate::terminal::{KeyCode, KeyEvent};
+use crate::widgets::inputs::choice::ChoiceInput;
+use crate::widgets::node::Node;
+use crate::widgets::traits::InteractionResult;
+
+const TASK_FAILURE_NODE_ID: &str = "__task_failure";
+const RETRY: &str = "Retry";
+const SKIP: &str = "Skip";
+const ABORT: &str = "Abort";
+
+fn task_failure_error_key(task_id: &TaskId) -> String {
+    format!("task_failure:{task_id}")
+}
+
+impl AppState {
+    pub fn task_failure_prompt_active(&self) -> bool {
+        !self.ui.task_failure_prompts.is_empty()
+    }
+
+    /// The choice shown for the oldest failure still waiting for a decision.
+    pub fn task_failure_prompt_node(&self) -> Option<&Node> {
+        self.ui
+            .task_failure_prompts
+            .front()
+            .map(|prompt| &prompt.node)
+    }
+
+    pub fn dispatch_key_to_task_failure_prompt(&mut self, key: KeyEvent) -> InteractionResult {
+        if key.code == KeyCode::Enter {
+            return InteractionResult::handled_if(self.resolve_task_failure_prompt());
+        }
+        let Some(prompt) = self.ui.task_failure_prompts.front_mut() else {
+            return InteractionResult::ignored();
+        };
+        prompt.node.on_key(key)
+    }
+
+    /// Carries out the choice on screen: retrying starts the task over,
+    /// skipping lets its dependents and submit hooks go on, aborting cancels
+    /// the flow.
+    pub fn resolve_task_failure_prompt(&mut self) -> bool {
+        let Some(prompt) = self.ui.task_failure_prompts.pop_front() else {
+            return false;
+        };
+        let choice = prompt
+            .node
+            .value()
+            .and_then(|value| value.to_text_scalar())
+            .unwrap_or_default();
+        match choice.as_str() {
+            RETRY => {
+                self.request_task(TaskRequest::new(prompt.task_id));
+            }
+            SKIP => {
+                if let Some(run_state) = self.runtime.task_runs.get_mut(prompt.task_id.as_str()) {
+                    run_state.skip_last_failure();
+                }
+                release_parked_task_requests(self);
+                self.on_submit_hook_skipped(&prompt.task_id);
+            }
+            _ => {
+                self.abort_flow_for_task_failure(&prompt.task_id, prompt.error.as_str());
+            }
+        }
+        true
+    }
+
+    pub(in crate::state::app) fn has_task_failure_prompt(&self, task_id: &TaskId) -> bool {
+        self.ui
+            .task_failure_prompts
+            .iter()
+            .any(|prompt| &prompt.task_id == task_id)
+    }
+
+    pub(in crate::state::app) fn open_task_failure_prompt(
+        &mut self,
+        task_id: &TaskId,
+        error: &str,
+    ) {
+        if self.should_exit || self.has_task_failure_prompt(task_id) {
+            return;
+        }
+        let choice = ChoiceInput::new(
+            TASK_FAILURE_NODE_ID,
+            self.message_catalog()
+                .render(&task_failure_message(task_id, error))
+                .into_owned(),
+            vec![RETRY.to_string(), SKIP.to_string(), ABORT.to_string()],
+        )
+        .with_default(Value::Text(RETRY.to_string()));
+        self.ui.task_failure_prompts.push_back(TaskFailurePrompt {
+            task_id: task_id.clone(),
+            error: error.to_string(),
+            node: Node::Input(Box::new(choice)),
+        });
+    }
+
+    /// Cancels the flow, leaving the failure on the step it stopped at.
+    pub(in crate::state::app) fn abort_flow_for_task_failure(
+        &mut self,
+        task_id: &TaskId,
+        error: &str,
+    ) {
+        self.ui.task_failure_prompts.clear();
+        self.runtime.validation.set_runtime_step_error(
+            task_failure_error_key(task_id),
+            task_failure_message(task_id, error),
+        );
+        self.request_exit();
+    }
+}
+
+fn task_failure_message(task_id: &TaskId, error: &str) -> Message {
+    Message::builtin("task-failed")
+        .with_arg("task", task_id)
+        .with_arg("error", error)
+}
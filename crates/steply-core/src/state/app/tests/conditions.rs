@@ -1,5 +1,6 @@
 use super::{AppState, bound_immediate_text_input, char_key};
 use crate::config::load_from_yaml_str;
+use crate::core::i18n::Message;
 use crate::core::value::Value;
 use crate::runtime::event::SystemEvent;
 use crate::state::change::StoreWriteOrigin;
@@ -196,12 +197,13 @@ steps:
             ]),
             error: None,
             cancelled: false,
+            exit_code: None,
         },
     );
 
     assert!(accepted);
     assert_eq!(state.current_step_id(), "gated");
-    assert_eq!(state.current_step_errors(), &[] as &[String]);
+    assert_eq!(state.current_step_errors(), &[] as &[Message]);
     assert_eq!(
         state.store_value("demo.remaining_files"),
         Some(&Value::List(vec![
@@ -231,3 +233,50 @@ steps:
     assert!(rendered.contains("Groups"), "rendered output:\n{rendered}");
     assert!(!rendered.contains("Done"), "rendered output:\n{rendered}");
 }
+
+#[test]
+fn widget_when_adds_revealed_fields_to_focus_order_and_skips_hidden_validation() {
+    let yaml = r#"
+version: 1
+steps:
+  - id: network
+    title: Network
+    widgets:
+      - type: checkbox
+        id: use_proxy
+        label: Use proxy
+        default: false
+        value: network.use_proxy
+      - type: text_input
+        id: proxy_host
+        label: Proxy host
+        required: true
+        value: network.proxy_host
+        when:
+          ref: network.use_proxy
+"#;
+
+    let loaded = load_from_yaml_str(yaml).expect("load config");
+    let mut state = loaded.into_app_state().expect("app state");
+    assert_eq!(state.focused_id(), Some("use_proxy"));
+    state.focus_next();
+    assert_eq!(state.focused_id(), Some("use_proxy"));
+
+    state.dispatch_key_to_focused(char_key(' '));
+    state.focus_next();
+    assert_eq!(state.focused_id(), Some("proxy_host"));
+
+    state.handle_system_event(SystemEvent::RequestSubmit);
+    assert_eq!(state.current_step_index(), 0);
+    assert!(state.visible_error("proxy_host").is_some());
+
+    state.focus_prev();
+    state.dispatch_key_to_focused(char_key(' '));
+    assert_eq!(state.focused_id(), Some("use_proxy"));
+    assert!(state.visible_error("proxy_host").is_none());
+    state.focus_next();
+    assert_eq!(state.focused_id(), Some("use_proxy"));
+
+    state.handle_system_event(SystemEvent::RequestSubmit);
+    assert!(state.should_exit() || state.exit_confirm_active());
+}
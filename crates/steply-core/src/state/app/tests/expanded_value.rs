@@ -0,0 +1,122 @@
+use crate::config::load_from_yaml_str;
+use crate::core::value::Value;
+use crate::runtime::intent::Intent;
+use crate::runtime::reducer::Reducer;
+use crate::state::app::AppState;
+use crate::terminal::{KeyCode, KeyEvent, KeyModifiers, TerminalSize};
+use crate::ui::render_view::RenderView;
+use crate::ui::renderer::{Renderer, RendererConfig};
+
+fn key(code: KeyCode) -> Intent {
+    Intent::InputKey(KeyEvent {
+        code,
+        modifiers: KeyModifiers::NONE,
+    })
+}
+
+fn state() -> AppState {
+    let yaml = r#"
+version: 1
+confirm_finish: false
+steps:
+  - id: demo
+    title: Demo
+    widgets:
+      - type: object_editor
+        id: profile
+        label: Profile
+        default:
+          name: Moonbase
+          tags: [a, b]
+        value: profile
+      - type: textarea
+        id: notes
+        default: "first\nsecond"
+        value: notes
+      - type: text_input
+        id: token
+        label: Token
+        mode: password
+        value: token
+"#;
+    load_from_yaml_str(yaml)
+        .expect("load config")
+        .into_app_state()
+        .expect("app state")
+}
+
+#[test]
+fn an_object_opens_read_only_and_esc_closes_it() {
+    let mut state = state();
+    let before = state.store_value("profile").cloned();
+    assert!(matches!(before, Some(Value::Object(_))));
+
+    Reducer::reduce(&mut state, Intent::ExpandValue);
+    assert!(state.expanded_value_active());
+    let (title, _) = state.expanded_value().expect("expanded value");
+    assert_eq!(title, "Profile");
+
+    // Delete and Enter do nothing: the view neither edits nor submits.
+    Reducer::reduce(&mut state, key(KeyCode::Char('d')));
+    Reducer::reduce(&mut state, key(KeyCode::Enter));
+    let (_, node) = state.expanded_value().expect("still open");
+    assert_eq!(node.value(), before);
+
+    Reducer::reduce(&mut state, key(KeyCode::Esc));
+    assert!(!state.expanded_value_active());
+    assert_eq!(state.store_value("profile").cloned(), before);
+    assert_eq!(state.focused_id(), Some("profile"));
+}
+
+#[test]
+fn text_fills_the_screen_and_cannot_be_typed_into() {
+    let mut state = state();
+    Reducer::reduce(&mut state, Intent::NextFocus);
+    assert_eq!(state.focused_id(), Some("notes"));
+    state.set_viewport_rows(12);
+
+    Reducer::reduce(&mut state, Intent::ExpandValue);
+    Reducer::reduce(&mut state, key(KeyCode::Char('x')));
+    let (_, node) = state.expanded_value().expect("expanded value");
+    assert_eq!(node.value(), Some(Value::Text("first\nsecond".into())));
+
+    let view = RenderView::from_state(&state);
+    let mut renderer = Renderer::new(RendererConfig {
+        chrome_enabled: false,
+    });
+    let frame = renderer.render(
+        &view,
+        TerminalSize {
+            width: 40,
+            height: 12,
+        },
+    );
+    let lines = frame
+        .lines
+        .iter()
+        .map(|line| {
+            line.iter()
+                .map(|span| span.text.as_str())
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(lines.len(), 12);
+    assert!(lines[0].starts_with("notes"));
+    assert!(lines.iter().any(|line| line.contains("second")));
+    assert!(!lines.iter().any(|line| line.contains("Profile")));
+
+    Reducer::reduce(&mut state, Intent::ExpandValue);
+    assert!(!state.expanded_value_active());
+}
+
+#[test]
+fn a_masked_value_is_not_opened() {
+    let mut state = state();
+    Reducer::reduce(&mut state, Intent::NextFocus);
+    Reducer::reduce(&mut state, Intent::NextFocus);
+    assert_eq!(state.focused_id(), Some("token"));
+
+    Reducer::reduce(&mut state, Intent::ExpandValue);
+
+    assert!(!state.expanded_value_active());
+}
@@ -0,0 +1,105 @@
+use crate::config::load_from_yaml_str;
+use crate::core::value::Value;
+use crate::runtime::intent::Intent;
+use crate::runtime::reducer::Reducer;
+use crate::state::app::AppState;
+use crate::terminal::{KeyCode, KeyEvent, KeyModifiers};
+
+fn key(code: KeyCode) -> Intent {
+    Intent::InputKey(KeyEvent {
+        code,
+        modifiers: KeyModifiers::NONE,
+    })
+}
+
+fn two_step_state() -> AppState {
+    let yaml = r#"
+version: 1
+confirm_finish: false
+steps:
+  - id: first
+    title: First
+    widgets:
+      - type: text_input
+        id: name
+        label: Name
+        value: name
+  - id: second
+    title: Second
+    widgets:
+      - type: text_input
+        id: city
+        label: City
+        value: city
+"#;
+    let mut state = load_from_yaml_str(yaml)
+        .expect("load config")
+        .into_app_state()
+        .expect("app state");
+    state.enable_inspector();
+    state
+}
+
+#[test]
+fn inspector_is_unavailable_unless_enabled() {
+    let mut state =
+        load_from_yaml_str("version: 1\nsteps:\n  - id: only\n    title: Only\n    widgets: []\n")
+            .expect("load config")
+            .into_app_state()
+            .expect("app state");
+
+    Reducer::reduce(&mut state, Intent::ToggleInspector);
+
+    assert!(!state.inspector_active());
+    assert!(state.inspector_node().is_none());
+}
+
+#[test]
+fn scrubbing_puts_the_app_back_at_each_recorded_point() {
+    let mut state = two_step_state();
+    Reducer::reduce(&mut state, key(KeyCode::Char('A')));
+    Reducer::reduce(&mut state, Intent::Submit);
+    assert_eq!(state.current_step_id(), "second");
+    Reducer::reduce(&mut state, key(KeyCode::Char('B')));
+
+    Reducer::reduce(&mut state, Intent::ToggleInspector);
+    assert_eq!(state.inspector_position(), Some((3, 4)));
+
+    Reducer::reduce(&mut state, key(KeyCode::Left));
+    assert_eq!(state.current_step_id(), "second");
+    assert_eq!(state.store_value("city"), Some(&Value::Text(String::new())));
+
+    Reducer::reduce(&mut state, key(KeyCode::Left));
+    assert_eq!(state.current_step_id(), "first");
+    assert_eq!(state.focused_id(), Some("name"));
+    assert_eq!(state.store_value("name"), Some(&Value::Text("A".into())));
+
+    // Keys scrub instead of reaching the widget, and none are recorded.
+    Reducer::reduce(&mut state, key(KeyCode::Home));
+    assert_eq!(state.store_value("name"), Some(&Value::Text(String::new())));
+    assert_eq!(state.inspector_position(), Some((0, 4)));
+
+    Reducer::reduce(&mut state, Intent::Cancel);
+    assert!(!state.inspector_active());
+    assert_eq!(state.current_step_id(), "second");
+    assert_eq!(state.store_value("city"), Some(&Value::Text("B".into())));
+}
+
+#[test]
+fn resuming_from_an_earlier_point_drops_the_snapshots_after_it() {
+    let mut state = two_step_state();
+    Reducer::reduce(&mut state, key(KeyCode::Char('A')));
+    Reducer::reduce(&mut state, key(KeyCode::Char('B')));
+
+    Reducer::reduce(&mut state, Intent::ToggleInspector);
+    Reducer::reduce(&mut state, key(KeyCode::Left));
+    Reducer::reduce(&mut state, key(KeyCode::Enter));
+    assert!(!state.inspector_active());
+    assert_eq!(state.store_value("name"), Some(&Value::Text("A".into())));
+
+    Reducer::reduce(&mut state, key(KeyCode::Char('C')));
+    assert_eq!(state.store_value("name"), Some(&Value::Text("AC".into())));
+
+    Reducer::reduce(&mut state, Intent::ToggleInspector);
+    assert_eq!(state.inspector_position(), Some((2, 3)));
+}
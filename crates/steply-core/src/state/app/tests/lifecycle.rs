@@ -0,0 +1,85 @@
+use super::{AppState, bound_immediate_text_input, char_key};
+use crate::core::i18n::Message;
+use crate::core::value::Value;
+use crate::runtime::event::SystemEvent;
+use crate::state::flow::Flow;
+use crate::state::step::Step;
+use crate::widgets::node::find_node;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[test]
+fn on_enter_hook_seeds_widgets_of_the_entered_step() {
+    let first = Step::builder("step_1", "First").build();
+    let second = Step::builder("step_2", "Second")
+        .node(bound_immediate_text_input("city", "City", "profile.city"))
+        .on_enter(|ctx| ctx.set("profile.city", Value::Text("Oslo".to_string())))
+        .build();
+    let mut state = AppState::new(Flow::new(vec![first, second])).expect("app state");
+
+    state.handle_system_event(SystemEvent::RequestSubmit);
+
+    assert_eq!(state.current_step_id(), "step_2");
+    let city = find_node(state.current_step_nodes(), "city").and_then(|node| node.value());
+    assert_eq!(city, Some(Value::Text("Oslo".to_string())));
+}
+
+#[test]
+fn on_exit_hook_vetoes_leaving_until_it_succeeds() {
+    let first = Step::builder("step_1", "First")
+        .node(bound_immediate_text_input("name", "Name", "profile.name"))
+        .on_exit(|ctx| {
+            if ctx.text("profile.name").is_empty() {
+                Err("unsaved changes".to_string())
+            } else {
+                Ok(())
+            }
+        })
+        .build();
+    let second = Step::builder("step_2", "Second").build();
+    let mut state = AppState::new(Flow::new(vec![first, second])).expect("app state");
+
+    state.handle_system_event(SystemEvent::RequestSubmit);
+    assert_eq!(state.current_step_id(), "step_1");
+    assert_eq!(
+        state.current_step_errors(),
+        [Message::from("unsaved changes")]
+    );
+
+    state.dispatch_key_to_focused(char_key('A'));
+    state.handle_system_event(SystemEvent::RequestSubmit);
+    assert_eq!(state.current_step_id(), "step_2");
+    assert!(state.current_step_errors().is_empty());
+}
+
+#[test]
+fn cancel_runs_step_then_flow_hooks_and_can_be_vetoed() {
+    let cleanups = Arc::new(AtomicUsize::new(0));
+    let vetoes = Arc::new(AtomicUsize::new(0));
+    let step_vetoes = Arc::clone(&vetoes);
+    let step = Step::builder("step_1", "First")
+        .on_cancel(move |_| {
+            if step_vetoes.fetch_add(1, Ordering::SeqCst) == 0 {
+                Err("save first".to_string())
+            } else {
+                Ok(())
+            }
+        })
+        .build();
+    let flow_cleanups = Arc::clone(&cleanups);
+    let flow = Flow::new(vec![step]).on_cancel(move |_| {
+        flow_cleanups.fetch_add(1, Ordering::SeqCst);
+        Ok(())
+    });
+    let mut state = AppState::new(flow).expect("app state");
+
+    state.begin_exit_confirm();
+    assert!(!state.cancel_flow());
+    assert!(!state.should_exit());
+    assert!(!state.exit_confirm_active());
+    assert_eq!(cleanups.load(Ordering::SeqCst), 0);
+
+    assert!(state.cancel_flow());
+    assert!(state.should_exit());
+    assert_eq!(cleanups.load(Ordering::SeqCst), 1);
+}
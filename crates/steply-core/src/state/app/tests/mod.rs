@@ -1,8 +1,17 @@
 mod commit_policy;
 mod conditions;
 mod derived;
+mod expanded_value;
+mod inspector;
+mod lifecycle;
 mod outputs;
+mod pointer;
+mod prefill;
+mod review;
+mod secrets;
 mod submit;
+mod tasks;
+mod timers;
 mod triggering;
 
 pub(super) use super::AppState;
@@ -28,6 +37,7 @@ pub(super) fn bound_on_submit_text_input(id: &str, label: &str, selector: &str)
                 expr: WriteExpr::ScopeRef("value".to_string()),
             }],
             commit_policy: StoreCommitPolicy::OnSubmit,
+            transforms: Vec::new(),
         },
     )
 }
@@ -46,6 +56,7 @@ pub(super) fn bound_immediate_text_input(id: &str, label: &str, target: &str) ->
                 expr: WriteExpr::ScopeRef("value".to_string()),
             }],
             commit_policy: StoreCommitPolicy::Immediate,
+            transforms: Vec::new(),
         },
     )
 }
@@ -69,6 +80,7 @@ pub(super) fn derived_copy_text_input(
                 expr: WriteExpr::ScopeRef("value".to_string()),
             }],
             commit_policy: StoreCommitPolicy::Immediate,
+            transforms: Vec::new(),
         },
     )
 }
@@ -1,6 +1,10 @@
 use crate::config::load_from_yaml_str;
 use crate::core::value::Value;
-use crate::terminal::TerminalSize;
+use crate::runtime::event::SystemEvent;
+use crate::runtime::intent::Intent;
+use crate::runtime::reducer::Reducer;
+use crate::state::app::AppState;
+use crate::terminal::{KeyCode, KeyEvent, KeyModifiers, TerminalSize};
 use crate::ui::render_view::RenderView;
 use crate::ui::renderer::{Renderer, RendererConfig};
 use crate::widgets::node::find_node;
@@ -106,3 +110,538 @@ steps:
     );
     assert!(rendered.len() > 2, "rendered lines: {rendered:#?}");
 }
+
+#[test]
+fn breadcrumb_header_tracks_flow_progress() {
+    let yaml = r#"
+version: 1
+breadcrumb: true
+steps:
+  - id: account
+    title: Account
+    widgets:
+      - type: text_output
+        id: account_note
+        text: Account
+  - id: region
+    title: Region
+    widgets:
+      - type: text_output
+        id: region_note
+        text: Region
+  - id: review
+    title: Review
+    widgets:
+      - type: text_output
+        id: review_note
+        text: Review
+"#;
+
+    let loaded = load_from_yaml_str(yaml).expect("load config");
+    let mut state = loaded.into_app_state().expect("app state");
+    let mut renderer = Renderer::new(RendererConfig {
+        chrome_enabled: false,
+    });
+    let render_first_line = |state: &AppState, renderer: &mut Renderer, width: u16| {
+        let view = RenderView::from_state(state);
+        let frame = renderer.render(&view, TerminalSize { width, height: 20 });
+        frame.lines[0]
+            .iter()
+            .map(|span| span.text.as_str())
+            .collect::<String>()
+    };
+
+    assert_eq!(
+        render_first_line(&state, &mut renderer, 80),
+        "◇ Account › ○ Region › ○ Review  · 2 steps left"
+    );
+
+    state.handle_system_event(SystemEvent::RequestSubmit);
+    assert_eq!(
+        render_first_line(&state, &mut renderer, 80),
+        "◈ Account › ◇ Region › ○ Review  · 1 step left"
+    );
+    assert_eq!(
+        render_first_line(&state, &mut renderer, 30),
+        "2/3 ◇ Region  · 1 step left"
+    );
+}
+
+#[test]
+fn breadcrumb_skips_hidden_steps_and_fits_narrow_widths() {
+    let yaml = r#"
+version: 1
+breadcrumb: true
+steps:
+  - id: account
+    title: Account
+    widgets:
+      - type: checkbox
+        id: advanced
+        label: Advanced
+        default: false
+        value: account.advanced
+  - id: tuning
+    title: Tuning
+    when:
+      ref: account.advanced
+    widgets: []
+  - id: deployment
+    title: Deployment settings
+    widgets:
+      - type: text_output
+        id: deployment_note
+        text: Deployment
+"#;
+
+    let loaded = load_from_yaml_str(yaml).expect("load config");
+    let state = loaded.into_app_state().expect("app state");
+    let mut renderer = Renderer::new(RendererConfig {
+        chrome_enabled: false,
+    });
+    let render_first_line = |state: &AppState, renderer: &mut Renderer, width: u16| {
+        let view = RenderView::from_state(state);
+        let frame = renderer.render(&view, TerminalSize { width, height: 20 });
+        frame.lines[0]
+            .iter()
+            .map(|span| span.text.as_str())
+            .collect::<String>()
+    };
+
+    assert_eq!(
+        render_first_line(&state, &mut renderer, 80),
+        "◇ Account › ○ Deployment settings  · 1 step left"
+    );
+    assert_eq!(
+        render_first_line(&state, &mut renderer, 30),
+        "1/2 ◇ Account  · 1 step left"
+    );
+    assert_eq!(
+        render_first_line(&state, &mut renderer, 16),
+        "1/2 ◇ Account"
+    );
+    assert_eq!(render_first_line(&state, &mut renderer, 10), "1/2 ◇ Ac…");
+    assert_eq!(render_first_line(&state, &mut renderer, 4), "1/2");
+}
+
+#[test]
+fn value_preview_renders_collected_values_until_closed() {
+    let yaml = r#"
+version: 1
+steps:
+  - id: profile
+    title: Profile
+    widgets:
+      - type: text_input
+        id: name
+        label: Name
+        default: Ada
+        value: profile.name
+      - type: text_input
+        id: password
+        label: Password
+        mode: password
+        default: hunter2
+        value: profile.password
+"#;
+
+    let loaded = load_from_yaml_str(yaml).expect("load config");
+    let mut state = loaded.into_app_state().expect("app state");
+    let mut renderer = Renderer::new(RendererConfig {
+        chrome_enabled: false,
+    });
+    let render_text = |state: &AppState, renderer: &mut Renderer| {
+        let view = RenderView::from_state(state);
+        let frame = renderer.render(
+            &view,
+            TerminalSize {
+                width: 80,
+                height: 40,
+            },
+        );
+        frame
+            .lines
+            .iter()
+            .map(|line| {
+                line.iter()
+                    .map(|span| span.text.as_str())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    assert!(!render_text(&state, &mut renderer).contains("Collected values"));
+
+    Reducer::reduce(&mut state, Intent::ToggleValuePreview);
+    assert!(state.value_preview_active());
+    let rendered = render_text(&state, &mut renderer);
+    assert!(rendered.contains("Collected values (read-only)"));
+    assert!(rendered.contains("\"name\": \"Ada\""));
+    assert!(!rendered.contains("hunter2"));
+
+    Reducer::reduce(&mut state, Intent::Submit);
+    assert!(state.value_preview_active());
+    assert_eq!(state.current_step_index(), 0);
+
+    Reducer::reduce(&mut state, Intent::Cancel);
+    assert!(!state.value_preview_active());
+    assert!(!render_text(&state, &mut renderer).contains("Collected values"));
+}
+
+#[test]
+fn value_preview_scrolls_long_values() {
+    let yaml = r#"
+version: 1
+steps:
+  - id: profile
+    title: Profile
+    widgets:
+      - type: text_input
+        id: first
+        label: First
+        default: Ada
+        value: profile.first
+      - type: text_input
+        id: last
+        label: Last
+        default: Lovelace
+        value: profile.last
+      - type: text_input
+        id: city
+        label: City
+        default: London
+        value: profile.city
+"#;
+
+    let loaded = load_from_yaml_str(yaml).expect("load config");
+    let mut state = loaded.into_app_state().expect("app state");
+    state.set_viewport_rows(9);
+    let mut renderer = Renderer::new(RendererConfig {
+        chrome_enabled: false,
+    });
+    let render_text = |state: &AppState, renderer: &mut Renderer| {
+        let view = RenderView::from_state(state);
+        let frame = renderer.render(
+            &view,
+            TerminalSize {
+                width: 80,
+                height: 40,
+            },
+        );
+        frame
+            .lines
+            .iter()
+            .map(|line| {
+                line.iter()
+                    .map(|span| span.text.as_str())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    let key = |code| {
+        Intent::InputKey(KeyEvent {
+            code,
+            modifiers: KeyModifiers::NONE,
+        })
+    };
+
+    Reducer::reduce(&mut state, Intent::ToggleValuePreview);
+    let rendered = render_text(&state, &mut renderer);
+    assert!(rendered.contains("[1-3 of 7] ↓"), "{rendered}");
+    assert!(rendered.contains("\"profile\": {"));
+    assert!(!rendered.contains("\"last\""));
+
+    Reducer::reduce(&mut state, key(KeyCode::Down));
+    let rendered = render_text(&state, &mut renderer);
+    assert!(rendered.contains("[2-4 of 7] ↑↓"), "{rendered}");
+    assert!(rendered.contains("\"last\": \"Lovelace\""));
+    assert!(!rendered.contains("\"city\""));
+
+    Reducer::reduce(&mut state, key(KeyCode::End));
+    let rendered = render_text(&state, &mut renderer);
+    assert!(rendered.contains("[5-7 of 7] ↑"), "{rendered}");
+    assert!(rendered.contains("\"city\": \"London\""));
+    assert!(state.value_preview_active());
+}
+
+#[test]
+fn configured_output_is_rendered_only_after_the_flow_finishes() {
+    let yaml = r#"
+version: 1
+confirm_finish: false
+output:
+  format: yaml
+  path: out/answers.yaml
+steps:
+  - id: profile
+    title: Profile
+    widgets:
+      - type: text_input
+        id: name
+        label: Name
+        default: Ada
+        value: profile.name
+"#;
+
+    let loaded = load_from_yaml_str(yaml).expect("load config");
+    let mut state = loaded.into_app_state().expect("app state");
+    assert!(state.render_result_export().is_none());
+
+    state.handle_system_event(SystemEvent::RequestSubmit);
+
+    let rendered = state
+        .render_result_export()
+        .expect("flow finished")
+        .expect("yaml");
+    assert_eq!(rendered, "profile:\n  name: Ada\n");
+    assert_eq!(
+        state
+            .render_result_json()
+            .expect("flow finished")
+            .as_deref(),
+        Ok(r#"{"profile":{"name":"Ada"}}"#)
+    );
+}
+
+#[test]
+fn markdown_output_reports_each_step_by_label_with_passwords_masked() {
+    let yaml = r#"
+version: 1
+confirm_finish: false
+output:
+  format: markdown
+steps:
+  - id: profile
+    title: Profile
+    description: Who runs the setup.
+    widgets:
+      - type: text_input
+        id: name
+        label: Name
+        default: Ada
+        value: profile.name
+      - type: text_input
+        id: password
+        label: Password
+        mode: password
+        default: hunter2
+        value: profile.password
+"#;
+
+    let loaded = load_from_yaml_str(yaml).expect("load config");
+    let mut state = loaded.into_app_state().expect("app state");
+    state.handle_system_event(SystemEvent::RequestSubmit);
+
+    let rendered = state
+        .render_result_export()
+        .expect("flow finished")
+        .expect("markdown");
+    assert_eq!(
+        rendered,
+        "# Flow report\n\n## Profile\n\nWho runs the setup.\n\n| Field | Value |\n| --- | --- |\n| Name | Ada |\n| Password | \\*\\*\\*\\*\\*\\*\\*\\* |\n"
+    );
+    assert!(
+        !state
+            .flow_report()
+            .render(crate::state::report::ReportFormat::Html)
+            .contains("hunter2")
+    );
+}
+
+#[test]
+fn message_catalog_translates_labels_and_validation_errors() {
+    let yaml = r#"
+version: 1
+locale: de
+messages:
+  de:
+    Profile: Profil
+    Name: Vorname
+    Field is required: Pflichtfeld
+steps:
+  - id: profile
+    title: Profile
+    widgets:
+      - type: text_input
+        id: name
+        label: Name
+        required: true
+        value: profile.name
+"#;
+
+    let loaded = load_from_yaml_str(yaml).expect("load config");
+    let mut state = loaded.into_app_state().expect("app state");
+    let mut renderer = Renderer::new(RendererConfig {
+        chrome_enabled: false,
+    });
+    let render_text = |state: &AppState, renderer: &mut Renderer| {
+        let view = RenderView::from_state(state);
+        let frame = renderer.render(
+            &view,
+            TerminalSize {
+                width: 80,
+                height: 20,
+            },
+        );
+        frame
+            .lines
+            .iter()
+            .map(|line| {
+                line.iter()
+                    .map(|span| span.text.as_str())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    let rendered = render_text(&state, &mut renderer);
+    assert!(rendered.contains("Profil [profile]"), "{rendered}");
+    assert!(rendered.contains("Vorname: "), "{rendered}");
+
+    state.handle_system_event(SystemEvent::RequestSubmit);
+    let rendered = render_text(&state, &mut renderer);
+    assert!(rendered.contains("Pflichtfeld"), "{rendered}");
+
+    assert!(!state.set_locale("fr"));
+    let rendered = render_text(&state, &mut renderer);
+    assert!(rendered.contains("Name: "), "{rendered}");
+}
+
+#[test]
+fn builtin_validation_errors_follow_a_locale_switch() {
+    let yaml = r#"
+version: 1
+messages:
+  de:
+    validation-min-length: "Mindestens { $min } Zeichen erforderlich."
+steps:
+  - id: profile
+    title: Profile
+    widgets:
+      - type: text_input
+        id: name
+        label: Name
+        default: A
+        value: profile.name
+        validators:
+          - type: min_length
+            value: 3
+"#;
+
+    let loaded = load_from_yaml_str(yaml).expect("load config");
+    let mut state = loaded.into_app_state().expect("app state");
+    let mut renderer = Renderer::new(RendererConfig {
+        chrome_enabled: false,
+    });
+    let render_text = |state: &AppState, renderer: &mut Renderer| {
+        let view = RenderView::from_state(state);
+        let frame = renderer.render(
+            &view,
+            TerminalSize {
+                width: 80,
+                height: 20,
+            },
+        );
+        frame
+            .lines
+            .iter()
+            .map(|line| {
+                line.iter()
+                    .map(|span| span.text.as_str())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    state.handle_system_event(SystemEvent::RequestSubmit);
+    let rendered = render_text(&state, &mut renderer);
+    assert!(
+        rendered.contains("Minimum 3 characters required."),
+        "{rendered}"
+    );
+
+    assert!(state.set_locale("de"));
+    let rendered = render_text(&state, &mut renderer);
+    assert!(
+        rendered.contains("Mindestens 3 Zeichen erforderlich."),
+        "{rendered}"
+    );
+}
+
+#[test]
+fn field_help_follows_the_focus_and_moves_beside_wide_forms() {
+    let yaml = r#"
+version: 1
+steps:
+  - id: server
+    title: Server
+    help: Applies to every field of this step.
+    widgets:
+      - type: text_input
+        id: host
+        label: Host
+        help: |
+          Name or address the service listens on.
+          Use 0.0.0.0 to listen on every interface.
+      - type: text_input
+        id: port
+        label: Port
+"#;
+
+    let loaded = load_from_yaml_str(yaml).expect("load config");
+    let mut state = loaded.into_app_state().expect("app state");
+    let mut renderer = Renderer::new(RendererConfig {
+        chrome_enabled: false,
+    });
+    let render_lines = |state: &AppState, renderer: &mut Renderer, width: u16| {
+        let view = RenderView::from_state(state);
+        let frame = renderer.render(&view, TerminalSize { width, height: 40 });
+        frame
+            .lines
+            .iter()
+            .map(|line| {
+                line.iter()
+                    .map(|span| span.text.as_str())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let lines = render_lines(&state, &mut renderer, 80);
+    let host = lines
+        .iter()
+        .position(|line| line.starts_with("Host"))
+        .expect("host row");
+    assert_eq!(
+        lines[host + 1],
+        "│ Name or address the service listens on. …"
+    );
+    assert!(lines[host + 2].starts_with("Port"));
+
+    Reducer::reduce(&mut state, Intent::ToggleHints);
+    let lines = render_lines(&state, &mut renderer, 80);
+    assert_eq!(
+        lines[host + 2],
+        "│ Use 0.0.0.0 to listen on every interface."
+    );
+    Reducer::reduce(&mut state, Intent::ToggleHints);
+
+    Reducer::reduce(&mut state, Intent::NextFocus);
+    let lines = render_lines(&state, &mut renderer, 80);
+    assert!(lines[host + 1].starts_with("Port"));
+    assert_eq!(lines[host + 2], "│ Applies to every field of this step.");
+
+    Reducer::reduce(&mut state, Intent::PrevFocus);
+    let lines = render_lines(&state, &mut renderer, 140);
+    assert!(lines[host].starts_with("Host"));
+    assert!(
+        lines[host].contains("│ Name or address the service"),
+        "{}",
+        lines[host]
+    );
+    assert!(lines[host + 1].starts_with("Port"));
+}
@@ -0,0 +1,133 @@
+use crate::config::load_from_yaml_str;
+use crate::core::value::Value;
+use crate::runtime::intent::Intent;
+use crate::runtime::reducer::Reducer;
+use crate::state::app::AppState;
+use crate::terminal::{KeyModifiers, PointerButton, PointerEvent, PointerKind, TerminalSize};
+use crate::ui::render_view::RenderView;
+use crate::ui::renderer::{RenderFrame, Renderer, RendererConfig};
+
+fn state() -> AppState {
+    let yaml = r#"
+version: 1
+confirm_finish: false
+steps:
+  - id: demo
+    title: Demo
+    widgets:
+      - type: select_list
+        id: colors
+        label: Colors
+        options: [red, green, blue]
+        value: colors
+      - type: slider
+        id: volume
+        label: Volume
+        min: 0
+        max: 10
+        track_len: 11
+        value: volume
+"#;
+    load_from_yaml_str(yaml)
+        .expect("load config")
+        .into_app_state()
+        .expect("app state")
+}
+
+fn render(state: &AppState) -> RenderFrame {
+    let view = RenderView::from_state(state);
+    let mut renderer = Renderer::new(RendererConfig {
+        chrome_enabled: false,
+    });
+    renderer.render(
+        &view,
+        TerminalSize {
+            width: 60,
+            height: 20,
+        },
+    )
+}
+
+/// Row of the first line containing `needle` and the column it starts at.
+fn find(frame: &RenderFrame, needle: &str) -> (u16, u16) {
+    frame
+        .lines
+        .iter()
+        .enumerate()
+        .find_map(|(row, line)| {
+            let text = line
+                .iter()
+                .map(|span| span.text.as_str())
+                .collect::<String>();
+            let byte = text.find(needle)?;
+            Some((row as u16, text[..byte].chars().count() as u16))
+        })
+        .unwrap_or_else(|| panic!("`{needle}` not drawn"))
+}
+
+fn point(state: &mut AppState, frame: &RenderFrame, row: u16, col: u16, kind: PointerKind) {
+    let hit = frame
+        .hit_map
+        .resolve(row, col)
+        .expect("a node under the pointer");
+    Reducer::reduce(
+        state,
+        Intent::PointerOn {
+            target: hit.node_id.to_string().into(),
+            event: PointerEvent {
+                kind,
+                col: hit.local_col,
+                row: hit.local_row,
+                modifiers: KeyModifiers::NONE,
+                semantic: hit.local_semantic,
+            },
+        },
+    );
+}
+
+const CLICK: PointerKind = PointerKind::Down(PointerButton::Left);
+
+#[test]
+fn clicking_a_widget_focuses_it() {
+    let mut state = state();
+    assert_eq!(state.focused_id(), Some("colors"));
+
+    let frame = render(&state);
+    let (row, col) = find(&frame, "Volume");
+    point(&mut state, &frame, row, col, CLICK);
+
+    assert_eq!(state.focused_id(), Some("volume"));
+    // The unfocused slider shows no track, so the click only focuses.
+    assert_eq!(state.store_value("volume"), Some(&Value::Number(0.0)));
+
+    let frame = render(&state);
+    let (row, col) = find(&frame, "blue");
+    point(&mut state, &frame, row, col, CLICK);
+    assert_eq!(state.focused_id(), Some("colors"));
+}
+
+#[test]
+fn the_focused_slider_follows_clicks_and_drags_on_its_track() {
+    let mut state = state();
+    Reducer::reduce(&mut state, Intent::NextFocus);
+    assert_eq!(state.focused_id(), Some("volume"));
+
+    let frame = render(&state);
+    let (row, col) = find(&frame, "‹");
+    let track = col + 1;
+    point(&mut state, &frame, row, track + 7, CLICK);
+    assert_eq!(state.store_value("volume"), Some(&Value::Number(7.0)));
+
+    point(
+        &mut state,
+        &frame,
+        row,
+        track + 3,
+        PointerKind::Drag(PointerButton::Left),
+    );
+    assert_eq!(state.store_value("volume"), Some(&Value::Number(3.0)));
+
+    // The closing arrow is no part of the track.
+    point(&mut state, &frame, row, track + 11, CLICK);
+    assert_eq!(state.store_value("volume"), Some(&Value::Number(3.0)));
+}
@@ -0,0 +1,247 @@
+use super::char_key;
+use crate::config::load_from_yaml_str;
+use crate::core::value::Value;
+use crate::runtime::event::SystemEvent;
+use crate::state::app::{ExitConfirmChoice, ExitConfirmMode};
+use crate::terminal::TerminalSize;
+use crate::ui::render_view::RenderView;
+use crate::ui::renderer::{Renderer, RendererConfig};
+
+const PREFILL_YAML: &str = r#"
+version: 1
+prefill:
+  values:
+    network.port: "8080"
+  env:
+    network.proxy_host: HTTP_PROXY
+    network.user: MISSING_VAR
+steps:
+  - id: network
+    title: Network
+    widgets:
+      - type: text_input
+        id: proxy_host
+        label: Proxy host
+        default: localhost
+        value: network.proxy_host
+      - type: text_input
+        id: port
+        label: Port
+        value: network.port
+      - type: text_input
+        id: user
+        label: User
+        default: admin
+        value: network.user
+"#;
+
+fn render_text(state: &crate::state::app::AppState) -> String {
+    let view = RenderView::from_state(state);
+    let mut renderer = Renderer::new(RendererConfig {
+        chrome_enabled: false,
+    });
+    let frame = renderer.render(
+        &view,
+        TerminalSize {
+            width: 80,
+            height: 20,
+        },
+    );
+    frame
+        .lines
+        .iter()
+        .map(|line| {
+            line.iter()
+                .map(|span| span.text.as_str())
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn prefill_applies_values_and_env_before_first_render() {
+    let loaded = load_from_yaml_str(PREFILL_YAML).expect("load config");
+    let mut state =
+        crate::state::app::AppState::with_tasks(loaded.flow, loaded.task_specs).expect("app state");
+    state
+        .apply_prefill_with_env(&loaded.prefill, |name| {
+            (name == "HTTP_PROXY").then(|| "proxy.local".to_string())
+        })
+        .expect("prefill");
+
+    assert_eq!(
+        state.store_value("network.proxy_host"),
+        Some(&Value::Text("proxy.local".into()))
+    );
+    assert_eq!(
+        state.store_value("network.port"),
+        Some(&Value::Text("8080".into()))
+    );
+    assert_eq!(
+        state.store_value("network.user"),
+        Some(&Value::Text("admin".into()))
+    );
+
+    let prefilled = state.prefilled_node_ids();
+    assert!(prefilled.contains("proxy_host"));
+    assert!(prefilled.contains("port"));
+    assert!(!prefilled.contains("user"));
+
+    let rendered = render_text(&state);
+    assert!(
+        rendered.contains("Proxy host: proxy.local  (prefilled)"),
+        "rendered output:\n{rendered}"
+    );
+    assert!(!rendered.contains("admin  (prefilled)"));
+}
+
+#[test]
+fn editing_a_prefilled_field_clears_its_marker() {
+    let loaded = load_from_yaml_str(PREFILL_YAML).expect("load config");
+    let mut state = loaded.into_app_state().expect("app state");
+    state
+        .prefill_values(&Value::from_json(r#"{"network": {"proxy_host": "cache"}}"#).unwrap())
+        .expect("prefill values");
+    assert!(state.prefilled_node_ids().contains("proxy_host"));
+
+    assert_eq!(state.focused_id(), Some("proxy_host"));
+    state.dispatch_key_to_focused(char_key('x'));
+    state.handle_system_event(SystemEvent::RequestSubmit);
+
+    assert!(!state.prefilled_node_ids().contains("proxy_host"));
+}
+
+#[test]
+fn rejects_invalid_prefill_selector() {
+    let yaml = r#"
+version: 1
+prefill:
+  env:
+    "": HOME
+steps:
+  - id: demo
+    title: Demo
+    widgets: []
+"#;
+
+    let err = match load_from_yaml_str(yaml) {
+        Ok(_) => panic!("expected invalid prefill to fail"),
+        Err(err) => err.to_string(),
+    };
+    assert!(err.contains("prefill selector"), "{err}");
+}
+
+#[test]
+fn restore_confirm_applies_recovered_answers_only_when_accepted() {
+    let recovered = Value::from_json(r#"{"network": {"port": "9090"}}"#).unwrap();
+
+    let loaded = load_from_yaml_str(PREFILL_YAML).expect("load config");
+    let mut state = loaded.into_app_state().expect("app state");
+    state.begin_restore_confirm(recovered.clone());
+    assert_eq!(
+        state.exit_confirm_mode(),
+        Some(ExitConfirmMode::RestoreSession)
+    );
+    assert!(state.resolve_exit_confirm());
+    assert_eq!(
+        state.store_value("network.port"),
+        Some(&Value::Text("9090".to_string()))
+    );
+
+    let loaded = load_from_yaml_str(PREFILL_YAML).expect("load config");
+    let mut state = loaded.into_app_state().expect("app state");
+    state.begin_restore_confirm(recovered);
+    state.set_exit_confirm_choice(ExitConfirmChoice::Stay);
+    assert!(state.resolve_exit_confirm());
+    assert!(!state.exit_confirm_active());
+    assert_eq!(
+        state.store_value("network.port"),
+        Some(&Value::Text("8080".to_string()))
+    );
+}
+
+#[test]
+fn prefill_field_targets_the_widget_binding_and_skips_answered_steps() {
+    let yaml = r#"
+version: 1
+steps:
+  - id: project
+    title: Project
+    widgets:
+      - type: text_input
+        id: name
+        label: Name
+        value: project.name
+  - id: deploy
+    title: Deploy
+    widgets:
+      - type: text_input
+        id: region
+        label: Region
+        value: deploy.region
+  - id: done
+    title: Done
+    widgets:
+      - type: text_input
+        id: note
+        label: Note
+        value: note
+"#;
+    let loaded = load_from_yaml_str(yaml).expect("load config");
+    let mut state = loaded.into_app_state().expect("app state");
+
+    state
+        .prefill_field("name", Value::Text("api".to_string()))
+        .expect("known field");
+    state
+        .prefill_field("note", Value::Text("later".to_string()))
+        .expect("field on a later step");
+    assert!(state.prefill_field("missing", Value::None).is_err());
+
+    assert_eq!(state.skip_answered_steps(), 1);
+    assert_eq!(state.current_step_id(), "deploy");
+
+    state
+        .prefill_field("region", Value::Text("eu".to_string()))
+        .expect("known field");
+    assert_eq!(state.skip_answered_steps(), 1);
+    assert_eq!(
+        state.current_step_id(),
+        "done",
+        "the last step is never submitted unattended"
+    );
+}
+
+#[test]
+fn dotenv_variables_reach_the_store_and_back_env_lookups() {
+    let loaded = load_from_yaml_str(PREFILL_YAML).expect("load config");
+    let mut state =
+        crate::state::app::AppState::with_tasks(loaded.flow, loaded.task_specs).expect("app state");
+    let mut prefill = loaded.prefill;
+    prefill
+        .dotenv
+        .insert("HTTP_PROXY".to_string(), "dotenv.proxy".to_string());
+    prefill
+        .dotenv
+        .insert("MISSING_VAR".to_string(), "ops".to_string());
+    state
+        .apply_prefill_with_env(&prefill, |name| {
+            (name == "HTTP_PROXY").then(|| "proxy.local".to_string())
+        })
+        .expect("prefill");
+
+    assert_eq!(
+        state.store_value("env.HTTP_PROXY"),
+        Some(&Value::Text("dotenv.proxy".into()))
+    );
+    // The process environment wins over `.env` files.
+    assert_eq!(
+        state.store_value("network.proxy_host"),
+        Some(&Value::Text("proxy.local".into()))
+    );
+    assert_eq!(
+        state.store_value("network.user"),
+        Some(&Value::Text("ops".into()))
+    );
+}
@@ -0,0 +1,170 @@
+use super::{AppState, bound_immediate_text_input, char_key};
+use crate::core::value::Value;
+use crate::runtime::event::SystemEvent;
+use crate::state::app::ExitConfirmMode;
+use crate::state::flow::Flow;
+use crate::state::step::Step;
+use crate::terminal::{KeyCode, KeyEvent, KeyModifiers};
+use crate::widgets::node::find_node;
+
+fn press_enter(state: &mut AppState) {
+    let result = state.dispatch_key_to_focused(KeyEvent {
+        code: KeyCode::Enter,
+        modifiers: KeyModifiers::NONE,
+    });
+    for action in result.actions {
+        state.handle_action(action);
+    }
+}
+
+fn review_flow() -> AppState {
+    let profile = Step::builder("profile", "Profile")
+        .node(bound_immediate_text_input("name", "Name", "profile.name"))
+        .build();
+    let notes = Step::builder("notes", "Notes").build();
+    let review = Step::review("review", "Review").build();
+    AppState::new(Flow::new(vec![profile, notes, review])).expect("app state")
+}
+
+#[test]
+fn review_step_summarizes_collected_values_by_step() {
+    let mut state = review_flow();
+
+    state.dispatch_key_to_focused(char_key('A'));
+    state.handle_system_event(SystemEvent::RequestSubmit);
+    state.handle_system_event(SystemEvent::RequestSubmit);
+
+    assert_eq!(state.current_step_id(), "review");
+    let summary = find_node(state.current_step_nodes(), "__review_profile")
+        .and_then(|node| node.value())
+        .expect("profile summary");
+    assert_eq!(
+        summary,
+        Value::Object([("Name".to_string(), Value::Text("A".to_string()))].into())
+    );
+    assert!(find_node(state.current_step_nodes(), "__review_notes").is_none());
+    assert_eq!(state.focused_id(), Some("__review_edit_profile"));
+}
+
+#[test]
+fn review_edit_button_jumps_back_and_confirm_finishes_flow() {
+    let mut state = review_flow();
+    state.dispatch_key_to_focused(char_key('A'));
+    state.handle_system_event(SystemEvent::RequestSubmit);
+    state.handle_system_event(SystemEvent::RequestSubmit);
+
+    press_enter(&mut state);
+    assert_eq!(state.current_step_id(), "profile");
+
+    state.dispatch_key_to_focused(char_key('B'));
+    state.handle_system_event(SystemEvent::RequestSubmit);
+    state.handle_system_event(SystemEvent::RequestSubmit);
+    assert_eq!(state.current_step_id(), "review");
+    let summary = find_node(state.current_step_nodes(), "__review_profile")
+        .and_then(|node| node.value())
+        .expect("profile summary");
+    assert_eq!(
+        summary,
+        Value::Object([("Name".to_string(), Value::Text("AB".to_string()))].into())
+    );
+
+    state.focus_next();
+    assert_eq!(state.focused_id(), Some("__review_confirm"));
+    press_enter(&mut state);
+    assert_eq!(state.exit_confirm_mode(), Some(ExitConfirmMode::FinishFlow));
+}
+
+#[test]
+fn review_masks_passwords_and_keeps_fields_with_the_same_label() {
+    let yaml = r#"
+version: 1
+steps:
+  - id: account
+    title: Account
+    widgets:
+      - type: text_input
+        id: primary_email
+        label: Email
+        default: ada@example.com
+        value: account.primary
+      - type: text_input
+        id: backup_email
+        label: Email
+        default: ada@backup.example
+        value: account.backup
+      - type: text_input
+        id: password
+        label: Password
+        mode: password
+        default: hunter2
+        value: account.password
+  - id: review
+    title: Review
+    review: true
+"#;
+    let mut state = crate::config::load_from_yaml_str(yaml)
+        .expect("load config")
+        .into_app_state()
+        .expect("app state");
+    state.handle_system_event(SystemEvent::RequestSubmit);
+
+    assert_eq!(state.current_step_id(), "review");
+    let summary = find_node(state.current_step_nodes(), "__review_account")
+        .and_then(|node| node.value())
+        .expect("account summary");
+    assert_eq!(
+        summary,
+        Value::Object(
+            [
+                (
+                    "Email".to_string(),
+                    Value::Text("ada@example.com".to_string())
+                ),
+                (
+                    "Email (backup_email)".to_string(),
+                    Value::Text("ada@backup.example".to_string())
+                ),
+                ("Password".to_string(), Value::Text("********".to_string())),
+            ]
+            .into()
+        )
+    );
+}
+
+#[test]
+fn review_buttons_use_the_message_catalog() {
+    let yaml = r#"
+version: 1
+locale: de
+messages:
+  de:
+    review-edit: "{ $step } bearbeiten"
+    review-confirm: Bestätigen
+    Account: Konto
+steps:
+  - id: account
+    title: Account
+    widgets:
+      - type: text_input
+        id: email
+        label: Email
+        default: ada@example.com
+        value: account.email
+  - id: review
+    title: Review
+    review: true
+"#;
+    let mut state = crate::config::load_from_yaml_str(yaml)
+        .expect("load config")
+        .into_app_state()
+        .expect("app state");
+    state.handle_system_event(SystemEvent::RequestSubmit);
+
+    let label = |id: &str| {
+        find_node(state.current_step_nodes(), id)
+            .map(|node| node.label().to_string())
+            .expect("review button")
+    };
+    assert_eq!(label("__review_edit_account"), "Konto bearbeiten");
+    assert_eq!(label("__review_confirm"), "Bestätigen");
+}
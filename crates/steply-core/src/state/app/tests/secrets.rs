@@ -0,0 +1,145 @@
+use crate::config::load_from_yaml_str;
+use crate::core::secrets::{SecretBackend, SecretKey, SecretStore};
+use crate::runtime::event::SystemEvent;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+const FLOW: &str = r#"
+version: 1
+confirm_finish: false
+output:
+  format: json
+steps:
+  - id: login
+    title: Login
+    widgets:
+      - type: text_input
+        id: user
+        label: User
+        default: ada
+        value: login.user
+      - type: text_input
+        id: token
+        label: Token
+        mode: password
+        value: login.token
+        secret:
+          backend: pass
+          service: github
+          account: deploy
+"#;
+
+#[derive(Default)]
+struct MemoryStore {
+    secrets: RefCell<HashMap<String, String>>,
+}
+
+impl SecretStore for MemoryStore {
+    fn read(&self, key: &SecretKey) -> Result<Option<String>, String> {
+        Ok(self.secrets.borrow().get(&key.pass_path()).cloned())
+    }
+
+    fn write(&self, key: &SecretKey, secret: &str) -> Result<(), String> {
+        self.secrets
+            .borrow_mut()
+            .insert(key.pass_path(), secret.to_string());
+        Ok(())
+    }
+}
+
+#[test]
+fn secret_fields_are_found_by_node_id() {
+    let state = load_from_yaml_str(FLOW)
+        .expect("load config")
+        .into_app_state()
+        .expect("app state");
+
+    assert_eq!(
+        state.secret_fields(),
+        vec![(
+            "token".to_string(),
+            SecretKey::new(SecretBackend::Pass, "github", "deploy")
+        )]
+    );
+}
+
+#[test]
+fn stored_secret_is_the_default_and_never_exported() {
+    let store = MemoryStore::default();
+    store
+        .secrets
+        .borrow_mut()
+        .insert("github/deploy".to_string(), "s3cret".to_string());
+    let mut state = load_from_yaml_str(FLOW)
+        .expect("load config")
+        .into_app_state()
+        .expect("app state");
+
+    assert!(state.load_secrets(&store).is_empty());
+    state.handle_system_event(SystemEvent::RequestSubmit);
+
+    assert_eq!(
+        state
+            .render_result_json()
+            .expect("flow finished")
+            .as_deref(),
+        Ok(r#"{"login":{"user":"ada"}}"#)
+    );
+    assert!(!state.flow_report().steps[0].entries.contains_key("token"));
+
+    store.secrets.borrow_mut().clear();
+    assert!(state.save_secrets(&store).is_empty());
+    assert_eq!(
+        store
+            .secrets
+            .borrow()
+            .get("github/deploy")
+            .map(String::as_str),
+        Some("s3cret")
+    );
+}
+
+#[test]
+fn secrets_are_saved_only_once_the_flow_finishes() {
+    let store = MemoryStore::default();
+    let mut state = load_from_yaml_str(FLOW)
+        .expect("load config")
+        .into_app_state()
+        .expect("app state");
+    store
+        .secrets
+        .borrow_mut()
+        .insert("github/deploy".to_string(), "s3cret".to_string());
+    state.load_secrets(&store);
+    store.secrets.borrow_mut().clear();
+
+    assert!(state.save_secrets(&store).is_empty());
+    assert!(store.secrets.borrow().is_empty());
+}
+
+#[test]
+fn masked_inputs_without_a_secret_backend_are_never_exported() {
+    let yaml = FLOW.replace(
+        "        secret:\n          backend: pass\n          service: github\n          account: deploy\n",
+        "        default: hunter2\n",
+    );
+    let mut state = load_from_yaml_str(yaml.as_str())
+        .expect("load config")
+        .into_app_state()
+        .expect("app state");
+
+    assert!(
+        state
+            .exportable_values()
+            .to_json_string()
+            .is_ok_and(|json| !json.contains("hunter2"))
+    );
+    state.handle_system_event(SystemEvent::RequestSubmit);
+    assert_eq!(
+        state
+            .render_result_json()
+            .expect("flow finished")
+            .as_deref(),
+        Ok(r#"{"login":{"user":"ada"}}"#)
+    );
+}
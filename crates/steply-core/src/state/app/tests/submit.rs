@@ -1,11 +1,15 @@
 use super::{AppState, bound_on_submit_text_input, char_key};
+use crate::core::i18n::Message;
 use crate::core::value::Value;
 use crate::core::value_path::ValueTarget;
 use crate::runtime::event::{SystemEvent, WidgetAction};
 use crate::state::app::{ExitConfirmChoice, ExitConfirmMode};
 use crate::state::flow::Flow;
-use crate::state::step::Step;
-use crate::task::{TaskRequest, TaskSpec, TaskTrigger};
+use crate::state::step::{Step, StepStatus};
+use crate::task::{ConcurrencyPolicy, TaskCompletion, TaskRequest, TaskSpec, TaskTrigger};
+use crate::terminal::TerminalSize;
+use crate::ui::render_view::RenderView;
+use crate::ui::renderer::{Renderer, RendererConfig};
 
 #[test]
 fn submit_before_tasks_receive_submitted_on_submit_values() {
@@ -118,3 +122,312 @@ fn final_submit_can_still_exit_immediately_when_disabled() {
     assert!(state.should_exit());
     assert!(state.exit_confirm_mode().is_none());
 }
+
+fn hook_completion(task_id: &str, run_id: u64, error: Option<&str>) -> SystemEvent {
+    SystemEvent::TaskCompleted {
+        completion: TaskCompletion {
+            task_id: task_id.into(),
+            run_id,
+            concurrency_policy: ConcurrencyPolicy::Parallel,
+            result: Value::None,
+            error: error.map(ToString::to_string),
+            cancelled: false,
+            exit_code: None,
+        },
+    }
+}
+
+#[test]
+fn submit_hooks_block_advance_until_they_succeed() {
+    let first = Step::builder("step_1", "Step")
+        .node(bound_on_submit_text_input("name", "Name", "draft_name"))
+        .submit_hook("check")
+        .build();
+    let second = Step::builder("step_2", "Next").build();
+    let task = TaskSpec::exec("check", "cat", Vec::new()).with_reads(
+        crate::widgets::shared::binding::ReadBinding::Selector(ValueTarget::node("draft_name")),
+    );
+    let mut state =
+        AppState::with_tasks(Flow::new(vec![first, second]), vec![task]).expect("app state");
+
+    state.dispatch_key_to_focused(char_key('A'));
+    state.handle_system_event(SystemEvent::RequestSubmit);
+
+    let invocations = state.take_pending_task_invocations();
+    assert_eq!(invocations.len(), 1);
+    assert_eq!(invocations[0].stdin_json, "\"A\"");
+    assert!(state.has_pending_submit_hooks());
+    assert_eq!(state.current_step_id(), "step_1");
+    assert_eq!(state.current_step_status(), StepStatus::Running);
+    assert!(rendered_text(&state).contains("running checks"));
+
+    state.handle_system_event(SystemEvent::RequestSubmit);
+    assert!(state.take_pending_task_invocations().is_empty());
+
+    state.handle_system_event(hook_completion("check", invocations[0].run_id, None));
+
+    assert!(!state.has_pending_submit_hooks());
+    assert_eq!(state.current_step_id(), "step_2");
+}
+
+fn rendered_text(state: &AppState) -> String {
+    let frame = Renderer::new(RendererConfig::default()).render(
+        &RenderView::from_state(state),
+        TerminalSize {
+            width: 60,
+            height: 20,
+        },
+    );
+    frame
+        .lines
+        .iter()
+        .flat_map(|line| line.iter().map(|span| span.text.as_str()))
+        .collect()
+}
+
+#[test]
+fn failed_submit_hook_keeps_step_and_shows_step_error() {
+    let first = Step::builder("step_1", "Step").submit_hook("check").build();
+    let second = Step::builder("step_2", "Next").build();
+    let task = TaskSpec::exec("check", "false", Vec::new());
+    let mut state =
+        AppState::with_tasks(Flow::new(vec![first, second]), vec![task]).expect("app state");
+
+    state.handle_system_event(SystemEvent::RequestSubmit);
+    let run_id = state.take_pending_task_invocations()[0].run_id;
+    state.handle_system_event(hook_completion("check", run_id, Some("unreachable")));
+
+    assert!(!state.has_pending_submit_hooks());
+    assert_eq!(state.current_step_id(), "step_1");
+    assert_eq!(
+        state.current_step_errors(),
+        [Message::from("check: unreachable")]
+    );
+
+    state.handle_system_event(SystemEvent::RequestSubmit);
+    assert!(state.current_step_errors().is_empty());
+    assert_eq!(state.take_pending_task_invocations().len(), 1);
+}
+
+#[test]
+fn step_expanders_insert_steps_after_submit_and_replace_them_on_resubmit() {
+    let picker = Step::builder("pick", "Pick")
+        .node(bound_on_submit_text_input("count", "Count", "count"))
+        .expand(|ctx| {
+            ctx.text("count")
+                .chars()
+                .map(|ch| Step::builder(format!("item_{ch}"), format!("Item {ch}")).build())
+                .collect()
+        })
+        .build();
+    let done = Step::builder("done", "Done").build();
+    let mut state = AppState::new(Flow::new(vec![picker, done])).expect("app state");
+
+    state.dispatch_key_to_focused(char_key('a'));
+    state.dispatch_key_to_focused(char_key('b'));
+    state.handle_system_event(SystemEvent::RequestSubmit);
+
+    let ids = |state: &AppState| {
+        state
+            .steps()
+            .iter()
+            .map(|step| step.id.clone())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(ids(&state), ["pick", "item_a", "item_b", "done"]);
+    assert_eq!(state.current_step_id(), "item_a");
+
+    state.handle_step_back();
+    state.dispatch_key_to_focused(char_key('c'));
+    state.handle_system_event(SystemEvent::RequestSubmit);
+
+    assert_eq!(ids(&state), ["pick", "item_a", "item_b", "item_c", "done"]);
+    assert_eq!(state.steps()[1].inserted_by.as_deref(), Some("pick"));
+}
+
+#[test]
+fn reexpanding_a_step_also_removes_steps_its_expanded_steps_inserted() {
+    let picker = Step::builder("pick", "Pick")
+        .node(bound_on_submit_text_input("count", "Count", "count"))
+        .expand(|ctx| {
+            ctx.text("count")
+                .chars()
+                .map(|ch| {
+                    Step::builder(format!("item_{ch}"), format!("Item {ch}"))
+                        .expand(move |_| {
+                            vec![Step::builder(format!("nested_{ch}"), "Nested").build()]
+                        })
+                        .build()
+                })
+                .collect()
+        })
+        .build();
+    let done = Step::builder("done", "Done").build();
+    let mut state = AppState::new(Flow::new(vec![picker, done])).expect("app state");
+    let ids = |state: &AppState| {
+        state
+            .steps()
+            .iter()
+            .map(|step| step.id.clone())
+            .collect::<Vec<_>>()
+    };
+
+    state.dispatch_key_to_focused(char_key('a'));
+    state.handle_system_event(SystemEvent::RequestSubmit);
+    state.handle_system_event(SystemEvent::RequestSubmit);
+    assert_eq!(ids(&state), ["pick", "item_a", "nested_a", "done"]);
+    assert_eq!(state.current_step_id(), "nested_a");
+
+    state.handle_step_back();
+    state.handle_step_back();
+    state.dispatch_key_to_focused(char_key('b'));
+    state.handle_system_event(SystemEvent::RequestSubmit);
+
+    assert_eq!(ids(&state), ["pick", "item_a", "item_b", "done"]);
+    assert!(state.current_step_errors().is_empty());
+}
+
+#[test]
+fn expanded_step_with_a_taken_id_keeps_the_step_and_names_the_id() {
+    let picker = Step::builder("pick", "Pick")
+        .expand(|_| vec![Step::builder("done", "Again").build()])
+        .build();
+    let done = Step::builder("done", "Done").build();
+    let mut state = AppState::new(Flow::new(vec![picker, done])).expect("app state");
+
+    state.handle_system_event(SystemEvent::RequestSubmit);
+
+    assert_eq!(state.current_step_id(), "pick");
+    assert_eq!(state.steps().len(), 2);
+    assert_eq!(
+        state.current_step_errors(),
+        [Message::from(
+            "expanded step id 'done' is already used in the flow"
+        )]
+    );
+}
+
+#[test]
+fn failed_submit_lists_every_problem_and_jumps_to_the_chosen_one() {
+    use crate::terminal::{KeyCode, KeyEvent, KeyModifiers};
+    use crate::widgets::components::table::Table;
+    use crate::widgets::inputs::text::TextInput;
+    use crate::widgets::node::{Node, find_node};
+    use crate::widgets::validators::required;
+
+    let key = |code| KeyEvent {
+        code,
+        modifiers: KeyModifiers::NONE,
+    };
+    let hosts = Table::new("hosts", "Hosts")
+        .column("Host", |id, label| {
+            TextInput::new(id, label).with_validator(required())
+        })
+        .with_initial_rows(2);
+    let step = Step::builder("step_1", "Step")
+        .node(Node::Input(Box::new(
+            TextInput::new("name", "Name").with_validator(required()),
+        )))
+        .node(Node::Input(Box::new(
+            TextInput::new("email", "Email").with_validator(required()),
+        )))
+        .node(Node::Component(Box::new(hosts)))
+        .build();
+    let mut state = AppState::new(Flow::new(vec![step])).expect("app state");
+
+    state.handle_system_event(SystemEvent::RequestSubmit);
+    let labels = state
+        .ui
+        .validation_summary
+        .as_ref()
+        .expect("summary")
+        .entries
+        .iter()
+        .map(|entry| entry.label.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        labels,
+        vec![
+            "Name",
+            "Email",
+            "Hosts › row 1, Host",
+            "Hosts › row 2, Host"
+        ]
+    );
+
+    state.dispatch_key_to_validation_summary(key(KeyCode::Down));
+    state.dispatch_key_to_validation_summary(key(KeyCode::Enter));
+    assert!(!state.validation_summary_active());
+    assert_eq!(state.focused_id(), Some("email"));
+
+    state.handle_system_event(SystemEvent::RequestSubmit);
+    for _ in 0..3 {
+        state.dispatch_key_to_validation_summary(key(KeyCode::Down));
+    }
+    state.dispatch_key_to_validation_summary(key(KeyCode::Enter));
+    assert_eq!(state.focused_id(), Some("hosts"));
+    state.dispatch_key_to_focused(char_key('x'));
+    let rows = find_node(state.current_step_nodes(), "hosts")
+        .and_then(Node::value)
+        .expect("table value");
+    assert_eq!(
+        rows.to_json(),
+        r#"[{"host":""},{"host":"x"}]"#,
+        "typing lands in the second row's cell"
+    );
+}
+
+#[test]
+fn summary_reaches_cells_of_a_bound_and_conditional_table() {
+    use crate::state::step::StepCondition;
+    use crate::terminal::{KeyCode, KeyEvent, KeyModifiers};
+    use crate::widgets::components::table::Table;
+    use crate::widgets::inputs::text::TextInput;
+    use crate::widgets::node::Node;
+    use crate::widgets::shared::binding::{StoreBinding, bind_node};
+    use crate::widgets::shared::condition::wrap_node_when;
+    use crate::widgets::validators::required;
+
+    let key = |code| KeyEvent {
+        code,
+        modifiers: KeyModifiers::NONE,
+    };
+    let hosts = Table::new("hosts", "Hosts")
+        .column("Host", |id, label| {
+            TextInput::new(id, label).with_validator(required())
+        })
+        .with_initial_rows(2);
+    let hosts = bind_node(
+        Node::Component(Box::new(hosts)),
+        StoreBinding {
+            value: Some(ValueTarget::node("hosts")),
+            ..StoreBinding::default()
+        },
+    );
+    let step = Step::builder("step_1", "Step")
+        .node(Node::Input(Box::new(
+            TextInput::new("name", "Name").with_validator(required()),
+        )))
+        .node(wrap_node_when(hosts, StepCondition::All(Vec::new())))
+        .build();
+    let mut state = AppState::new(Flow::new(vec![step])).expect("app state");
+
+    state.handle_system_event(SystemEvent::RequestSubmit);
+    let entries = state
+        .ui
+        .validation_summary
+        .as_ref()
+        .expect("summary")
+        .entries
+        .len();
+    assert_eq!(entries, 3);
+
+    for _ in 0..2 {
+        state.dispatch_key_to_validation_summary(key(KeyCode::Down));
+    }
+    state.dispatch_key_to_validation_summary(key(KeyCode::Enter));
+    assert_eq!(state.focused_id(), Some("hosts"));
+    state.dispatch_key_to_focused(char_key('x'));
+    let rows = state.store_value("hosts").expect("table value");
+    assert_eq!(rows.to_json(), r#"[{"host":""},{"host":"x"}]"#);
+}
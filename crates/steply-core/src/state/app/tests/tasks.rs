@@ -0,0 +1,612 @@
+use super::AppState;
+use crate::core::i18n::Message;
+use crate::core::value::Value;
+use crate::core::value_path::ValueTarget;
+use crate::runtime::event::{AppEvent, SystemEvent};
+use crate::runtime::scheduler::SchedulerCommand;
+use crate::state::flow::Flow;
+use crate::state::step::{Step, StepStatus};
+use crate::task::engine::retry_key;
+use crate::task::{
+    Backoff, ConcurrencyPolicy, FailurePolicy, RerunPolicy, RetryPolicy, TaskCompletion,
+    TaskContext, TaskKind, TaskRequest, TaskSpec, TaskStartResult, TaskStatus,
+};
+use crate::terminal::{KeyCode, KeyEvent, KeyModifiers, TerminalSize};
+use crate::time::Duration;
+use crate::widgets::components::command_runner::CommandRunner;
+use crate::widgets::node::{Node, find_node};
+use crate::widgets::outputs::progress::ProgressOutput;
+use crate::widgets::outputs::task_graph::TaskGraphOutput;
+use crate::widgets::traits::RenderContext;
+use std::sync::{Arc, mpsc};
+
+#[test]
+fn native_task_progress_reaches_store_and_watching_progress_widget() {
+    let step = Step::builder("step_1", "Import")
+        .node(Node::Output(Box::new(
+            ProgressOutput::new("bar", "Importing").with_task("import"),
+        )))
+        .build();
+    let task = TaskSpec::native("import", |ctx| {
+        ctx.progress(50.0);
+        Ok(Value::Bool(true))
+    })
+    .with_progress(ValueTarget::node("import_progress"));
+    let mut state = AppState::with_tasks(Flow::new(vec![step]), vec![task]).expect("app state");
+
+    let result = state.request_task(TaskRequest::new("import"));
+    let TaskStartResult::Started { run_id, .. } = result else {
+        panic!("task should start: {result:?}");
+    };
+    let invocation = state
+        .take_pending_task_invocations()
+        .pop()
+        .expect("pending invocation");
+    let TaskKind::Native { run, .. } = &invocation.spec.kind else {
+        panic!("expected native task");
+    };
+    let (progress_tx, progress_rx) = mpsc::channel();
+    let ctx = TaskContext::new(
+        Value::None,
+        invocation.cancel_token,
+        None,
+        Some(progress_tx),
+    );
+    assert_eq!(run.run(&ctx), Ok(Value::Bool(true)));
+
+    let progress = progress_rx.try_recv().expect("progress reported");
+    state.handle_system_event(SystemEvent::TaskProgress {
+        task_id: "import".into(),
+        run_id,
+        progress,
+    });
+
+    assert_eq!(
+        state.store_value("import_progress"),
+        Some(&Value::Number(50.0))
+    );
+    let bar = find_node(state.current_step_nodes(), "bar").and_then(|node| node.value());
+    assert_eq!(bar, Some(Value::Number(50.0)));
+}
+
+#[test]
+fn progress_from_finished_runs_is_ignored() {
+    let task = TaskSpec::native("import", |_| Ok(Value::None))
+        .with_progress(ValueTarget::node("import_progress"));
+    let step = Step::builder("step_1", "Import").build();
+    let mut state = AppState::with_tasks(Flow::new(vec![step]), vec![task]).expect("app state");
+
+    state.handle_system_event(SystemEvent::TaskProgress {
+        task_id: "import".into(),
+        run_id: 7,
+        progress: 10.0,
+    });
+
+    assert_eq!(state.store_value("import_progress"), None);
+}
+
+#[test]
+fn command_step_templates_args_and_gates_submit_on_success() {
+    let step = Step::command(
+        "install",
+        "Install",
+        CommandRunner::new("runner", "Install").command("Greet", "echo", ["hi {{ name }}"]),
+    )
+    .build();
+    let done = Step::builder("done", "Done").build();
+    let mut state = AppState::new(Flow::new(vec![step, done])).expect("app state");
+    state
+        .prefill_values(&Value::from_json(r#"{"name": "Ada"}"#).expect("json"))
+        .expect("prefill");
+
+    state.handle_system_event(SystemEvent::RequestSubmit);
+    assert_eq!(state.current_step_id(), "install");
+
+    let run = state.dispatch_key_to_focused(KeyEvent {
+        code: KeyCode::Enter,
+        modifiers: KeyModifiers::NONE,
+    });
+    for action in run.actions {
+        state.handle_action(action);
+    }
+    let invocation = state
+        .take_pending_task_invocations()
+        .pop()
+        .expect("command started");
+    let TaskKind::Exec {
+        args,
+        stream_stdout,
+        ..
+    } = &invocation.spec.kind
+    else {
+        panic!("expected exec task");
+    };
+    assert_eq!(args, &vec!["hi Ada".to_string()]);
+    assert!(stream_stdout);
+
+    let task_id = invocation.spec.id.clone();
+    state.handle_system_event(SystemEvent::TaskStarted {
+        task_id: task_id.clone(),
+        run_id: invocation.run_id,
+    });
+    state.handle_system_event(SystemEvent::TaskCompleted {
+        completion: TaskCompletion {
+            task_id,
+            run_id: invocation.run_id,
+            concurrency_policy: ConcurrencyPolicy::default(),
+            result: Value::Text("hi Ada".to_string()),
+            error: None,
+            cancelled: false,
+            exit_code: Some(0),
+        },
+    });
+
+    state.handle_system_event(SystemEvent::RequestSubmit);
+    assert_eq!(state.current_step_id(), "done");
+}
+
+fn finish_task(state: &mut AppState, task_id: &str, error: Option<&str>) {
+    let invocation = state
+        .take_pending_task_invocations()
+        .into_iter()
+        .find(|invocation| invocation.spec.id.as_str() == task_id)
+        .unwrap_or_else(|| panic!("{task_id} should have started"));
+    state.handle_system_event(SystemEvent::TaskCompleted {
+        completion: TaskCompletion {
+            task_id: invocation.spec.id,
+            run_id: invocation.run_id,
+            concurrency_policy: ConcurrencyPolicy::default(),
+            result: Value::None,
+            error: error.map(str::to_string),
+            cancelled: false,
+            exit_code: None,
+        },
+    });
+}
+
+fn pipeline_state() -> AppState {
+    let step = Step::builder("step_1", "Release")
+        .node(Node::Output(Box::new(TaskGraphOutput::new("pipeline", ""))))
+        .build();
+    let tasks = vec![
+        TaskSpec::exec("deploy", "true", Vec::new()).with_depends_on(["build", "test"]),
+        TaskSpec::exec("test", "true", Vec::new()).with_depends_on(["build"]),
+        TaskSpec::exec("build", "true", Vec::new()),
+        TaskSpec::exec("lint", "true", Vec::new()),
+    ];
+    AppState::with_tasks(Flow::new(vec![step]), tasks).expect("app state")
+}
+
+fn statuses(state: &AppState) -> Vec<(String, TaskStatus)> {
+    state
+        .task_graph()
+        .entries()
+        .iter()
+        .map(|entry| (entry.id.to_string(), entry.status))
+        .collect()
+}
+
+#[test]
+fn requesting_a_task_runs_its_dependencies_first() {
+    let mut state = pipeline_state();
+
+    assert_eq!(
+        state.request_task(TaskRequest::new("deploy")),
+        TaskStartResult::Waiting {
+            task_id: "deploy".into()
+        }
+    );
+    assert_eq!(
+        statuses(&state),
+        vec![
+            ("build".to_string(), TaskStatus::Running),
+            ("test".to_string(), TaskStatus::Pending),
+            ("deploy".to_string(), TaskStatus::Pending),
+            ("lint".to_string(), TaskStatus::Pending),
+        ]
+    );
+
+    finish_task(&mut state, "build", None);
+    assert_eq!(state.task_status(&"test".into()), TaskStatus::Running);
+    assert_eq!(state.task_status(&"deploy".into()), TaskStatus::Pending);
+
+    finish_task(&mut state, "test", None);
+    finish_task(&mut state, "deploy", None);
+    assert_eq!(state.task_status(&"deploy".into()), TaskStatus::Done);
+    assert!(state.take_pending_task_invocations().is_empty());
+}
+
+#[test]
+fn failed_dependency_blocks_dependents_until_requested_again() {
+    let mut state = pipeline_state();
+    state.request_task(TaskRequest::new("deploy"));
+
+    finish_task(&mut state, "build", Some("compile error"));
+    assert_eq!(
+        statuses(&state),
+        vec![
+            ("build".to_string(), TaskStatus::Failed),
+            ("test".to_string(), TaskStatus::Blocked),
+            ("deploy".to_string(), TaskStatus::Blocked),
+            ("lint".to_string(), TaskStatus::Pending),
+        ]
+    );
+    assert!(state.take_pending_task_invocations().is_empty());
+
+    let ctx = RenderContext {
+        task_graph: Arc::new(state.task_graph()),
+        ..RenderContext::empty(TerminalSize {
+            width: 40,
+            height: 10,
+        })
+    };
+    let lines = find_node(state.current_step_nodes(), "pipeline")
+        .expect("graph widget")
+        .draw(&ctx)
+        .lines
+        .iter()
+        .map(|line| {
+            line.iter()
+                .map(|span| span.text.as_str())
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        lines,
+        vec!["✗ build", "⊘ test    ← build", "⊘ deploy  ← build, test",]
+    );
+
+    state.request_task(TaskRequest::new("deploy"));
+    assert_eq!(state.task_status(&"build".into()), TaskStatus::Running);
+    assert_eq!(state.task_status(&"deploy".into()), TaskStatus::Pending);
+}
+
+#[test]
+fn parallel_task_limit_holds_runs_until_a_slot_frees_up() {
+    let mut state = pipeline_state();
+    state.set_max_parallel_tasks(Some(1));
+
+    assert!(matches!(
+        state.request_task(TaskRequest::new("build")),
+        TaskStartResult::Started { .. }
+    ));
+    assert_eq!(
+        state.request_task(TaskRequest::new("lint")),
+        TaskStartResult::Waiting {
+            task_id: "lint".into()
+        }
+    );
+    assert_eq!(state.task_status(&"lint".into()), TaskStatus::Pending);
+
+    finish_task(&mut state, "build", None);
+    assert_eq!(state.task_status(&"lint".into()), TaskStatus::Running);
+}
+
+#[test]
+fn dependency_cycles_are_rejected() {
+    let tasks = vec![
+        TaskSpec::exec("a", "true", Vec::new()).with_depends_on(["b"]),
+        TaskSpec::exec("b", "true", Vec::new()).with_depends_on(["a"]),
+    ];
+    let err = AppState::with_tasks(Flow::new(Vec::new()), tasks)
+        .err()
+        .expect("cycle rejected");
+    assert_eq!(
+        err.to_string(),
+        "invalid task setup: task dependency cycle: a -> b -> a"
+    );
+}
+
+fn flaky_build_state(build: TaskSpec) -> AppState {
+    let step = Step::builder("step_1", "Release")
+        .node(Node::Output(Box::new(TaskGraphOutput::new("pipeline", ""))))
+        .build();
+    let tasks = vec![
+        TaskSpec::exec("deploy", "true", Vec::new()).with_depends_on(["build"]),
+        build,
+    ];
+    AppState::with_tasks(Flow::new(vec![step]), tasks).expect("app state")
+}
+
+/// Retries scheduled since the last call, as (delay, attempt).
+fn scheduled_retries(state: &mut AppState) -> Vec<(Duration, u32)> {
+    state
+        .take_pending_scheduler_commands()
+        .into_iter()
+        .filter_map(|command| match command {
+            SchedulerCommand::EmitAfter {
+                key,
+                delay,
+                event: AppEvent::System(SystemEvent::TaskRequested { request }),
+            } if key == retry_key(request.task_id.as_str()) => Some((delay, request.attempt)),
+            _ => None,
+        })
+        .collect()
+}
+
+fn fire_retry(state: &mut AppState, task_id: &str, attempt: u32) {
+    state.handle_system_event(SystemEvent::TaskRequested {
+        request: TaskRequest::new(task_id).with_attempt(attempt),
+    });
+}
+
+#[test]
+fn failed_runs_are_retried_with_backoff_before_dependents_are_blocked() {
+    let mut state = flaky_build_state(
+        TaskSpec::exec("build", "make", Vec::new())
+            .with_rerun_policy(RerunPolicy::Never)
+            .with_retry_policy(RetryPolicy::new(
+                3,
+                Backoff::Exponential {
+                    initial_ms: 100,
+                    max_ms: 150,
+                },
+            )),
+    );
+    state.request_task(TaskRequest::new("deploy"));
+
+    finish_task(&mut state, "build", Some("flaky"));
+    assert_eq!(
+        scheduled_retries(&mut state),
+        vec![(Duration::from_millis(100), 2)]
+    );
+    assert_eq!(
+        statuses(&state),
+        vec![
+            ("build".to_string(), TaskStatus::Retrying),
+            ("deploy".to_string(), TaskStatus::Pending),
+        ]
+    );
+
+    // Retries run even though the rerun policy would refuse another run.
+    fire_retry(&mut state, "build", 2);
+    assert_eq!(state.task_status(&"build".into()), TaskStatus::Running);
+    finish_task(&mut state, "build", Some("flaky"));
+    assert_eq!(
+        scheduled_retries(&mut state),
+        vec![(Duration::from_millis(150), 3)]
+    );
+
+    fire_retry(&mut state, "build", 3);
+    finish_task(&mut state, "build", Some("broken"));
+    assert!(scheduled_retries(&mut state).is_empty());
+    assert_eq!(
+        statuses(&state),
+        vec![
+            ("build".to_string(), TaskStatus::Failed),
+            ("deploy".to_string(), TaskStatus::Blocked),
+        ]
+    );
+    let entry = state.task_graph().get("build").cloned().expect("build");
+    assert_eq!((entry.attempt, entry.max_attempts), (3, 3));
+}
+
+#[test]
+fn submit_hooks_wait_for_retries() {
+    let step = Step::builder("step_1", "Build")
+        .submit_hook("build")
+        .build();
+    let done = Step::builder("done", "Done").build();
+    let build = TaskSpec::exec("build", "make", Vec::new())
+        .with_retry_policy(RetryPolicy::new(2, Backoff::Fixed { ms: 10 }));
+    let mut state =
+        AppState::with_tasks(Flow::new(vec![step, done]), vec![build]).expect("app state");
+
+    state.handle_system_event(SystemEvent::RequestSubmit);
+    finish_task(&mut state, "build", Some("flaky"));
+    assert!(state.has_pending_submit_hooks());
+    assert!(state.current_step_errors().is_empty());
+
+    fire_retry(&mut state, "build", 2);
+    finish_task(&mut state, "build", None);
+    assert_eq!(state.current_step_id(), "done");
+}
+
+#[test]
+fn skipped_failures_let_dependents_run() {
+    let mut state = flaky_build_state(
+        TaskSpec::exec("build", "make", Vec::new()).with_failure_policy(FailurePolicy::Skip),
+    );
+    state.request_task(TaskRequest::new("deploy"));
+
+    finish_task(&mut state, "build", Some("broken"));
+    assert_eq!(
+        statuses(&state),
+        vec![
+            ("build".to_string(), TaskStatus::Skipped),
+            ("deploy".to_string(), TaskStatus::Running),
+        ]
+    );
+}
+
+#[test]
+fn abort_policy_cancels_the_flow() {
+    let mut state = flaky_build_state(
+        TaskSpec::exec("build", "make", Vec::new()).with_failure_policy(FailurePolicy::Abort),
+    );
+    state.request_task(TaskRequest::new("deploy"));
+
+    finish_task(&mut state, "build", Some("broken"));
+    assert!(state.should_exit());
+    assert_eq!(state.current_step_status(), StepStatus::Cancelled);
+    assert_eq!(
+        state.current_step_errors(),
+        [Message::builtin("task-failed")
+            .with_arg("task", "build")
+            .with_arg("error", "broken")]
+    );
+    assert_eq!(
+        state.current_step_errors()[0].to_string(),
+        "Task 'build' failed: broken"
+    );
+}
+
+#[test]
+fn prompt_policy_asks_whether_to_retry_skip_or_abort() {
+    let mut state = flaky_build_state(
+        TaskSpec::exec("build", "make", Vec::new()).with_failure_policy(FailurePolicy::Prompt),
+    );
+    state.request_task(TaskRequest::new("deploy"));
+
+    finish_task(&mut state, "build", Some("broken"));
+    assert!(state.task_failure_prompt_active());
+    assert_eq!(
+        state.task_failure_prompt_node().map(|node| node.label()),
+        Some("Task 'build' failed: broken")
+    );
+    // The dependent keeps waiting while the user decides.
+    assert_eq!(state.task_status(&"deploy".into()), TaskStatus::Pending);
+
+    // Retry is preselected.
+    state.dispatch_key_to_task_failure_prompt(KeyEvent {
+        code: KeyCode::Enter,
+        modifiers: KeyModifiers::NONE,
+    });
+    assert!(!state.task_failure_prompt_active());
+    assert_eq!(state.task_status(&"build".into()), TaskStatus::Running);
+
+    finish_task(&mut state, "build", Some("broken again"));
+    state.dispatch_key_to_task_failure_prompt(KeyEvent {
+        code: KeyCode::Char('s'),
+        modifiers: KeyModifiers::NONE,
+    });
+    assert!(state.resolve_task_failure_prompt());
+    assert_eq!(
+        statuses(&state),
+        vec![
+            ("build".to_string(), TaskStatus::Skipped),
+            ("deploy".to_string(), TaskStatus::Running),
+        ]
+    );
+}
+
+fn start_build(state: &mut AppState) -> u64 {
+    let TaskStartResult::Started { run_id, .. } = state.request_task(TaskRequest::new("build"))
+    else {
+        panic!("build should start");
+    };
+    state.handle_system_event(SystemEvent::TaskStarted {
+        task_id: "build".into(),
+        run_id,
+    });
+    run_id
+}
+
+fn log_line(state: &mut AppState, run_id: u64, line: &str) {
+    state.handle_system_event(SystemEvent::TaskLogLine {
+        task_id: "build".into(),
+        run_id,
+        line: line.to_string(),
+    });
+}
+
+fn draw_task_details(state: &AppState) -> Vec<String> {
+    let ctx = RenderContext {
+        task_graph: Arc::new(state.task_graph()),
+        ..RenderContext::empty(TerminalSize {
+            width: 40,
+            height: 30,
+        })
+    };
+    state
+        .task_details_node()
+        .expect("task details open")
+        .draw(&ctx)
+        .lines
+        .iter()
+        .map(|line| {
+            line.iter()
+                .map(|span| span.text.as_str())
+                .collect::<String>()
+        })
+        .collect()
+}
+
+#[test]
+fn task_log_keeps_output_of_the_latest_run() {
+    let mut state = flaky_build_state(TaskSpec::exec("build", "make", Vec::new()));
+    let first = start_build(&mut state);
+    log_line(&mut state, first, "compiling");
+    state.handle_system_event(SystemEvent::TaskCompleted {
+        completion: TaskCompletion {
+            task_id: "build".into(),
+            run_id: first,
+            concurrency_policy: ConcurrencyPolicy::default(),
+            result: Value::None,
+            error: Some("exit 2".to_string()),
+            cancelled: false,
+            exit_code: Some(2),
+        },
+    });
+    assert_eq!(
+        state
+            .task_log("build")
+            .expect("build log")
+            .lines()
+            .collect::<Vec<_>>(),
+        vec!["compiling"]
+    );
+
+    let second = start_build(&mut state);
+    log_line(&mut state, first, "late line of the first run");
+    log_line(&mut state, second, "compiling again");
+    let log = state.task_log("build").expect("build log");
+    assert_eq!(log.run_id(), second);
+    assert_eq!(log.lines().collect::<Vec<_>>(), vec!["compiling again"]);
+    assert!(state.task_log("deploy").is_none());
+}
+
+#[test]
+fn task_details_view_follows_the_selected_task_log() {
+    let mut state = flaky_build_state(TaskSpec::exec("build", "make", Vec::new()));
+    let run_id = start_build(&mut state);
+    for n in 1..=20 {
+        log_line(&mut state, run_id, format!("line {n}").as_str());
+    }
+
+    state.open_task_details();
+    let list = draw_task_details(&state);
+    assert_eq!(list[0], "Tasks");
+    assert!(list[1].starts_with("❯ ● build"), "{list:?}");
+    assert!(list[1].ends_with("20 lines"), "{list:?}");
+    assert!(list[2].contains("deploy"), "{list:?}");
+
+    state.dispatch_key_to_task_details(KeyEvent {
+        code: KeyCode::Enter,
+        modifiers: KeyModifiers::NONE,
+    });
+    let log = draw_task_details(&state);
+    assert!(log[1].contains("running · following"), "{log:?}");
+    assert_eq!(log[2], "  ↑ 5 more");
+    assert_eq!(log.last().map(String::as_str), Some("  line 20"));
+
+    // Scrolling back pauses following, and new output does not move the view.
+    state.dispatch_key_to_task_details(KeyEvent {
+        code: KeyCode::Up,
+        modifiers: KeyModifiers::NONE,
+    });
+    log_line(&mut state, run_id, "line 21");
+    let paused = draw_task_details(&state);
+    assert!(paused[1].contains("paused"), "{paused:?}");
+    assert_eq!(
+        &paused[paused.len() - 2..],
+        ["  line 19".to_string(), "  ↓ 2 more".to_string()]
+    );
+
+    state.dispatch_key_to_task_details(KeyEvent {
+        code: KeyCode::Char('f'),
+        modifiers: KeyModifiers::NONE,
+    });
+    let following = draw_task_details(&state);
+    assert_eq!(following.last().map(String::as_str), Some("  line 21"));
+
+    // Esc goes back to the list, then closes the view.
+    let esc = KeyEvent {
+        code: KeyCode::Esc,
+        modifiers: KeyModifiers::NONE,
+    };
+    state.dispatch_key_to_task_details(esc);
+    assert!(draw_task_details(&state)[1].contains("21 lines"));
+    state.dispatch_key_to_task_details(esc);
+    assert!(!state.task_details_active());
+}
@@ -0,0 +1,131 @@
+use crate::config::load_from_yaml_str;
+use crate::state::app::AppState;
+use crate::terminal::TerminalSize;
+use crate::time::{Duration, Instant};
+use crate::ui::render_view::RenderView;
+use crate::ui::renderer::{Renderer, RendererConfig};
+
+const KIOSK_YAML: &str = r#"
+version: 1
+idle_timeout_ms: 10000
+steps:
+  - id: welcome
+    title: Welcome
+    auto_advance_ms: 5000
+    widgets:
+      - type: text_output
+        id: intro
+        text: "Touch to start"
+  - id: done
+    title: Done
+    widgets:
+      - type: text_output
+        id: bye
+        text: "Thanks"
+"#;
+
+fn kiosk_state() -> AppState {
+    load_from_yaml_str(KIOSK_YAML)
+        .expect("load config")
+        .into_app_state()
+        .expect("app state")
+}
+
+#[test]
+fn auto_advance_counts_down_and_restarts_on_activity() {
+    let mut state = kiosk_state();
+    let start = Instant::now();
+
+    assert!(state.tick_timers_at(start));
+    assert_eq!(
+        state.auto_advance_remaining_at(start + Duration::from_secs(2)),
+        Some(Duration::from_secs(3))
+    );
+
+    state.note_user_activity_at(start + Duration::from_secs(4));
+    state.tick_timers_at(start + Duration::from_secs(8));
+    assert_eq!(state.current_step_id(), "welcome");
+
+    assert!(state.tick_timers_at(start + Duration::from_secs(9)));
+    assert_eq!(state.current_step_id(), "done");
+    assert_eq!(state.auto_advance_remaining(), None);
+}
+
+#[test]
+fn auto_advance_countdown_is_rendered_in_step_title() {
+    let mut state = kiosk_state();
+    state.tick_timers();
+
+    let mut renderer = Renderer::new(RendererConfig {
+        chrome_enabled: false,
+    });
+    let view = RenderView::from_state(&state);
+    let frame = renderer.render(
+        &view,
+        TerminalSize {
+            width: 80,
+            height: 20,
+        },
+    );
+    let rendered = frame
+        .lines
+        .iter()
+        .map(|line| {
+            line.iter()
+                .map(|span| span.text.as_str())
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>();
+
+    assert!(
+        rendered
+            .iter()
+            .any(|line| line.contains("Welcome") && line.contains("continues in 5s")),
+        "rendered lines: {rendered:#?}"
+    );
+}
+
+#[test]
+fn idle_timeout_event_fires_once_per_idle_period() {
+    let mut state = kiosk_state();
+    assert_eq!(state.idle_timeout(), Some(Duration::from_secs(10)));
+    let start = Instant::now();
+
+    state.note_user_activity_at(start);
+    state.tick_timers_at(start + Duration::from_secs(3));
+    state.note_user_activity_at(start + Duration::from_secs(3));
+    state.tick_timers_at(start + Duration::from_secs(12));
+    assert!(!state.take_idle_timeout_event());
+
+    state.tick_timers_at(start + Duration::from_secs(13));
+    assert!(state.take_idle_timeout_event());
+    state.tick_timers_at(start + Duration::from_secs(30));
+    assert!(!state.take_idle_timeout_event());
+
+    state.note_user_activity_at(start + Duration::from_secs(31));
+    state.tick_timers_at(start + Duration::from_secs(41));
+    assert!(state.take_idle_timeout_event());
+}
+
+#[test]
+fn ticks_are_wanted_only_while_something_is_pending() {
+    let mut state = kiosk_state();
+    let start = Instant::now();
+    assert!(state.wants_tick());
+
+    state.note_user_activity_at(start);
+    state.tick_timers_at(start);
+    state.tick_timers_at(start + Duration::from_secs(6));
+    assert_eq!(state.current_step_id(), "done");
+    assert!(!state.wants_tick());
+    assert_eq!(
+        state.idle_timeout_remaining_at(start + Duration::from_secs(6)),
+        Some(Duration::from_secs(4))
+    );
+
+    state.tick_timers_at(start + Duration::from_secs(10));
+    assert_eq!(
+        state.idle_timeout_remaining_at(start + Duration::from_secs(11)),
+        None
+    );
+}
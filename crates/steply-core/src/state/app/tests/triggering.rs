@@ -37,6 +37,7 @@ fn task_store_writes_run_through_same_runtime_pipeline_as_other_store_updates()
             result: Value::Text("A".to_string()),
             error: None,
             cancelled: false,
+            exit_code: None,
         },
     });
 
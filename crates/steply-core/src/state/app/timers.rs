@@ -0,0 +1,140 @@
+use super::AppState;
+use super::state::StepTimerState;
+use crate::state::step::StepStatus;
+use crate::task::engine::trigger_idle_timeout_tasks;
+use crate::time::{Duration, Instant};
+
+impl AppState {
+    pub fn idle_timeout(&self) -> Option<Duration> {
+        self.runtime.idle.timeout
+    }
+
+    pub fn set_idle_timeout(&mut self, timeout: Option<Duration>) {
+        self.runtime.idle.timeout = timeout;
+        self.runtime.idle.fired = false;
+    }
+
+    /// Returns `true` once per idle period after the idle timeout elapsed,
+    /// so hosts polling the state can react (lock, reset, return to start).
+    pub fn take_idle_timeout_event(&mut self) -> bool {
+        std::mem::take(&mut self.runtime.idle.pending_event)
+    }
+
+    pub fn note_user_activity(&mut self) {
+        self.note_user_activity_at(Instant::now());
+    }
+
+    pub fn note_user_activity_at(&mut self, now: Instant) {
+        self.runtime.idle.last_activity = Some(now);
+        self.runtime.idle.fired = false;
+        if let Some(timer) = self.runtime.step_timer.as_mut() {
+            timer.started_at = now;
+            timer.fired = false;
+        }
+    }
+
+    /// Time left before the idle timeout fires, if one is armed.
+    pub fn idle_timeout_remaining_at(&self, now: Instant) -> Option<Duration> {
+        let timeout = self.runtime.idle.timeout?;
+        if self.runtime.idle.fired {
+            return None;
+        }
+        let elapsed = self
+            .runtime
+            .idle
+            .last_activity
+            .map(|last| now.duration_since(last))
+            .unwrap_or_default();
+        Some(timeout.saturating_sub(elapsed))
+    }
+
+    /// Time left before the current step auto-advances, if it has a timer.
+    pub fn auto_advance_remaining(&self) -> Option<Duration> {
+        self.auto_advance_remaining_at(Instant::now())
+    }
+
+    pub fn auto_advance_remaining_at(&self, now: Instant) -> Option<Duration> {
+        if self.flow.is_empty() || self.flow.current_status() != StepStatus::Active {
+            return None;
+        }
+        let step = self.flow.current_step();
+        let after = step.auto_advance?;
+        match self.runtime.step_timer.as_ref() {
+            Some(timer) if timer.step_id == step.id => {
+                (!timer.fired).then(|| after.saturating_sub(now.duration_since(timer.started_at)))
+            }
+            _ => Some(after),
+        }
+    }
+
+    /// Advances step and idle timers. Returns `true` when a re-render is due.
+    pub fn tick_timers(&mut self) -> bool {
+        self.tick_timers_at(Instant::now())
+    }
+
+    pub fn tick_timers_at(&mut self, now: Instant) -> bool {
+        let idle_changed = self.tick_idle_timeout(now);
+        let step_changed = self.tick_step_timer(now);
+        idle_changed || step_changed
+    }
+
+    fn tick_idle_timeout(&mut self, now: Instant) -> bool {
+        let Some(timeout) = self.runtime.idle.timeout else {
+            return false;
+        };
+        let last_activity = *self.runtime.idle.last_activity.get_or_insert(now);
+        if self.runtime.idle.fired || now.duration_since(last_activity) < timeout {
+            return false;
+        }
+        self.runtime.idle.fired = true;
+        self.runtime.idle.pending_event = true;
+        trigger_idle_timeout_tasks(self);
+        true
+    }
+
+    fn tick_step_timer(&mut self, now: Instant) -> bool {
+        if self.flow.is_empty()
+            || self.has_pending_submit_hooks()
+            || self.flow.current_status() != StepStatus::Active
+        {
+            return false;
+        }
+        let step = self.flow.current_step();
+        let Some(after) = step.auto_advance else {
+            self.runtime.step_timer = None;
+            return false;
+        };
+        let step_id = step.id.clone();
+        let timer = match self.runtime.step_timer.as_mut() {
+            Some(timer) if timer.step_id == step_id => timer,
+            _ => {
+                self.runtime.step_timer = Some(StepTimerState {
+                    step_id,
+                    started_at: now,
+                    fired: false,
+                    shown_secs: countdown_secs(after),
+                });
+                return true;
+            }
+        };
+        if timer.fired {
+            return false;
+        }
+
+        let remaining = after.saturating_sub(now.duration_since(timer.started_at));
+        if !remaining.is_zero() {
+            let secs = countdown_secs(remaining);
+            let changed = secs != timer.shown_secs;
+            timer.shown_secs = secs;
+            return changed;
+        }
+
+        timer.fired = true;
+        self.handle_step_submit();
+        true
+    }
+}
+
+fn countdown_secs(remaining: Duration) -> u64 {
+    remaining.as_millis().div_ceil(1000) as u64
+}
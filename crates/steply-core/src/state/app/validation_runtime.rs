@@ -1,5 +1,5 @@
 use super::AppState;
-use crate::core::{NodeId, value::Value};
+use crate::core::{NodeId, i18n::Message, value::Value};
 use crate::runtime::event::{AppEvent, SystemEvent};
 use crate::runtime::scheduler::SchedulerCommand;
 use crate::state::validation::{ErrorVisibility, StepContext, StepIssue};
@@ -32,7 +32,7 @@ impl AppState {
         self.runtime.validation.clear_step_warnings();
 
         let validations = {
-            let mut out = Vec::<(String, bool, Result<(), String>)>::new();
+            let mut out = Vec::<(String, bool, Result<(), Message>)>::new();
             walk_nodes(
                 self.flow.current_step().nodes.as_slice(),
                 NodeWalkScope::Recursive,
@@ -48,7 +48,7 @@ impl AppState {
         };
 
         let mut valid = true;
-        let mut component_step_errors = Vec::<String>::new();
+        let mut component_step_errors = Vec::<Message>::new();
         for (id, is_input, result) in validations {
             let non_input_error = if mode == ValidationMode::Submit && !is_input {
                 result.as_ref().err().cloned()
@@ -77,7 +77,7 @@ impl AppState {
     }
 
     fn validate_in_active_nodes(&mut self, id: &str, mode: ValidationMode) -> bool {
-        let mut result: Option<Result<(), String>> = None;
+        let mut result: Option<Result<(), Message>> = None;
         walk_nodes(self.active_nodes(), NodeWalkScope::TopLevel, &mut |node| {
             if result.is_none() && node.id() == id {
                 result = Some(node.validate(mode));
@@ -89,7 +89,7 @@ impl AppState {
     fn apply_validation_result(
         &mut self,
         id: &str,
-        result: Option<Result<(), String>>,
+        result: Option<Result<(), Message>>,
         mode: ValidationMode,
     ) -> bool {
         match result {
@@ -138,7 +138,7 @@ impl AppState {
         self.runtime.pending_scheduler.drain(..).collect()
     }
 
-    fn collect_step_validator_issues(&self) -> (Vec<String>, Vec<String>) {
+    fn collect_step_validator_issues(&self) -> (Vec<Message>, Vec<String>) {
         let issues: Vec<StepIssue> = {
             let step = self.flow.current_step();
             if step.validators.is_empty() {
@@ -158,7 +158,7 @@ impl AppState {
         for issue in issues {
             match &issue {
                 StepIssue::Error(msg) => {
-                    step_errors.push(msg.clone());
+                    step_errors.push(Message::Text(msg.clone()));
                 }
                 StepIssue::Warning(msg) => {
                     step_warnings.push(msg.clone());
@@ -174,7 +174,7 @@ fn inline_error_key(id: &str) -> String {
     format!("validation:inline:{id}")
 }
 
-fn collect_node_values(nodes: &[Node]) -> HashMap<NodeId, Value> {
+pub(in crate::state::app) fn collect_node_values(nodes: &[Node]) -> HashMap<NodeId, Value> {
     let mut values = HashMap::<NodeId, Value>::new();
     walk_nodes(nodes, NodeWalkScope::Recursive, &mut |node| {
         if let Some(value) = node.value() {
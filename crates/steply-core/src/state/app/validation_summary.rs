@@ -0,0 +1,162 @@
+use super::AppState;
+use super::state::{ValidationSummary, ValidationSummaryEntry};
+use crate::core::i18n::Message;
+use crate::terminal::{KeyCode, KeyEvent};
+use crate::widgets::components::select_list::{SelectItem, SelectList, SelectMode};
+use crate::widgets::node::{Node, NodeWalkScope, find_node_mut, walk_nodes};
+use crate::widgets::traits::{InteractionResult, ValidationMode};
+
+const VALIDATION_SUMMARY_NODE_ID: &str = "__validation_summary";
+
+impl AppState {
+    pub fn validation_summary_active(&self) -> bool {
+        self.ui.validation_summary.is_some()
+    }
+
+    pub fn validation_summary_node(&self) -> Option<&Node> {
+        self.ui
+            .validation_summary
+            .as_ref()
+            .map(|summary| &summary.node)
+    }
+
+    pub fn close_validation_summary(&mut self) {
+        self.ui.validation_summary = None;
+    }
+
+    /// Enter jumps to the entry under the cursor; an Esc the list does not
+    /// use closes the summary.
+    pub fn dispatch_key_to_validation_summary(&mut self, key: KeyEvent) -> InteractionResult {
+        let Some(summary) = self.ui.validation_summary.as_mut() else {
+            return InteractionResult::ignored();
+        };
+        let result = summary.node.on_key(key);
+        if key.code == KeyCode::Enter {
+            return InteractionResult::handled_if(self.jump_to_validation_summary_entry());
+        }
+        if !result.handled && key.code == KeyCode::Esc {
+            self.close_validation_summary();
+            return InteractionResult::handled();
+        }
+        result
+    }
+
+    /// Closes the summary and focuses the field of the selected entry,
+    /// moving containers to the offending cell or item.
+    pub fn jump_to_validation_summary_entry(&mut self) -> bool {
+        let Some(summary) = self.ui.validation_summary.take() else {
+            return false;
+        };
+        let Some(entry) = summary
+            .node
+            .value()
+            .and_then(|value| value.to_text_scalar())
+            .and_then(|index| index.parse::<usize>().ok())
+            .and_then(|index| summary.entries.into_iter().nth(index))
+        else {
+            return true;
+        };
+        let Some(node_id) = entry.node_id else {
+            return true;
+        };
+        if let Some(target) = entry.target.as_deref()
+            && !self.flow.is_empty()
+            && let Some(node) = find_node_mut(
+                self.flow.current_step_mut().nodes.as_mut_slice(),
+                node_id.as_str(),
+            )
+        {
+            node.reveal(target);
+        }
+        self.ui.focus.set_focus_by_id(node_id.as_str());
+        true
+    }
+
+    /// Lists every problem of a failed submit. A single one is already
+    /// obvious from the field it focuses, so the summary needs two or more.
+    pub(in crate::state::app) fn open_validation_summary(&mut self) {
+        let entries = self.collect_validation_summary_entries();
+        if entries.len() < 2 {
+            return;
+        }
+        let messages = self.message_catalog();
+        let items = entries
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| {
+                SelectItem::detailed(
+                    index.to_string(),
+                    &entry.label,
+                    messages.render(&entry.message),
+                )
+            })
+            .collect();
+        let title = messages.message(
+            "validation-summary-title",
+            &[("count", entries.len().to_string().as_str())],
+        );
+        let list =
+            SelectList::new(VALIDATION_SUMMARY_NODE_ID, title, items).with_mode(SelectMode::List);
+        self.ui.validation_summary = Some(ValidationSummary {
+            entries,
+            node: Node::Component(Box::new(list)),
+        });
+    }
+
+    fn collect_validation_summary_entries(&self) -> Vec<ValidationSummaryEntry> {
+        let mut entries = Vec::<ValidationSummaryEntry>::new();
+        let mut node_errors = Vec::<&Message>::new();
+        walk_nodes(
+            self.current_step_nodes(),
+            NodeWalkScope::Recursive,
+            &mut |node| {
+                let Some(error) = self.runtime.validation.visible_error(node.id()) else {
+                    return;
+                };
+                node_errors.push(error);
+                let label = if node.label().is_empty() {
+                    node.id()
+                } else {
+                    node.label()
+                };
+                let issues = node.validation_issues(ValidationMode::Submit);
+                if issues.is_empty() {
+                    entries.push(ValidationSummaryEntry {
+                        node_id: Some(node.id().into()),
+                        target: None,
+                        label: label.to_string(),
+                        message: error.clone(),
+                    });
+                    return;
+                }
+                for issue in issues {
+                    entries.push(ValidationSummaryEntry {
+                        node_id: Some(node.id().into()),
+                        label: match issue.location.as_deref() {
+                            Some(location) => format!("{label} › {location}"),
+                            None => label.to_string(),
+                        },
+                        target: issue.target,
+                        message: issue.message,
+                    });
+                }
+            },
+        );
+        // Component errors are repeated among the step errors; only the
+        // step's own validators are left to add.
+        for error in self.runtime.validation.step_errors() {
+            if node_errors.contains(&error) {
+                continue;
+            }
+            entries.push(ValidationSummaryEntry {
+                node_id: None,
+                target: None,
+                label: self
+                    .message_catalog()
+                    .message("validation-summary-step", &[]),
+                message: error.clone(),
+            });
+        }
+        entries
+    }
+}
@@ -0,0 +1,165 @@
+use super::AppState;
+use super::state::ExpandedValue;
+use crate::core::value::Value;
+use crate::terminal::{KeyCode, KeyEvent, PointerEvent};
+use crate::widgets::components::object_editor::ObjectEditor;
+use crate::widgets::components::textarea::TextAreaComponent;
+use crate::widgets::node::{Node, find_node};
+use crate::widgets::outputs::data::{DataOutput, DataOutputFormat};
+use crate::widgets::traits::{InteractionResult, OutputNode};
+
+const VALUE_PREVIEW_NODE_ID: &str = "__value_preview";
+const EXPANDED_VALUE_NODE_ID: &str = "__value_expanded";
+/// Used until the runner reports the terminal height.
+const DEFAULT_VIEWPORT_ROWS: u16 = 24;
+/// Screen rows the expanded view leaves to its title, frame and key hints.
+const EXPANDED_VALUE_CHROME_ROWS: usize = 6;
+
+impl AppState {
+    pub fn collected_values(&self) -> Value {
+        Value::Object(
+            self.data
+                .store
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.clone()))
+                .collect(),
+        )
+    }
+
+    pub fn value_preview_active(&self) -> bool {
+        self.ui.value_preview.is_some()
+    }
+
+    pub fn value_preview_node(&self) -> Option<&Node> {
+        self.ui.value_preview.as_ref()
+    }
+
+    /// Shows the collected values without anything a secret or masked
+    /// widget wrote, scrolling once they outgrow the screen.
+    pub fn open_value_preview(&mut self) {
+        let mut output = DataOutput::new(
+            VALUE_PREVIEW_NODE_ID,
+            Some("Collected values (read-only)".to_string()),
+            DataOutputFormat::Json,
+        )
+        .with_max_visible(self.expanded_value_rows());
+        output.set_value(self.exportable_values());
+        self.ui.value_preview = Some(Node::Component(Box::new(output)));
+    }
+
+    pub fn close_value_preview(&mut self) {
+        self.ui.value_preview = None;
+    }
+
+    pub fn dispatch_key_to_value_preview(&mut self, key: KeyEvent) -> InteractionResult {
+        let Some(node) = self.ui.value_preview.as_mut() else {
+            return InteractionResult::ignored();
+        };
+        node.on_key(key)
+    }
+
+    pub fn dispatch_pointer_to_value_preview(&mut self, event: PointerEvent) -> InteractionResult {
+        let Some(node) = self.ui.value_preview.as_mut() else {
+            return InteractionResult::ignored();
+        };
+        node.on_pointer(event)
+    }
+
+    /// Remembers the terminal height so the expanded value can fill it.
+    pub fn set_viewport_rows(&mut self, rows: u16) {
+        self.ui.viewport_rows = rows;
+    }
+
+    pub fn expanded_value_active(&self) -> bool {
+        self.ui.expanded_value.is_some()
+    }
+
+    /// Title and read-only view of the expanded value.
+    pub fn expanded_value(&self) -> Option<(&str, &Node)> {
+        self.ui
+            .expanded_value
+            .as_ref()
+            .map(|expanded| (expanded.title.as_str(), &expanded.node))
+    }
+
+    /// Opens the focused widget's value fullscreen: the widget's own view
+    /// when it has one, else an object browser for lists and objects and a
+    /// scrolling text view for the rest. Masked and secret values are not
+    /// shown; returns `false` when there is nothing to open.
+    pub fn open_expanded_value(&mut self) -> bool {
+        let Some(id) = self.focused_id().filter(|_| !self.flow.is_empty()) else {
+            return false;
+        };
+        let Some(node) = find_node(self.flow.current_step().nodes.as_slice(), id) else {
+            return false;
+        };
+        if node.value_masked() || node.secret().is_some() {
+            return false;
+        }
+        let rows = self.expanded_value_rows();
+        let title = self.accessible_label(id).to_string();
+        let view = match node.expanded_view(EXPANDED_VALUE_NODE_ID, rows) {
+            Some(view) => view,
+            None => match node.value() {
+                Some(value @ (Value::Object(_) | Value::List(_))) => Node::Component(Box::new(
+                    ObjectEditor::new(EXPANDED_VALUE_NODE_ID, title.as_str())
+                        .with_value(value)
+                        .with_max_visible(rows)
+                        .with_read_only(),
+                )),
+                Some(value) => {
+                    let text = value
+                        .to_text_scalar()
+                        .or_else(|| value.to_json_string_pretty().ok())
+                        .unwrap_or_default();
+                    Node::Component(Box::new(
+                        TextAreaComponent::new(EXPANDED_VALUE_NODE_ID)
+                            .with_min_height(rows)
+                            .with_max_height(rows)
+                            .with_default(Value::Text(text))
+                            .with_read_only(),
+                    ))
+                }
+                None => return false,
+            },
+        };
+        self.ui.expanded_value = Some(ExpandedValue { title, node: view });
+        true
+    }
+
+    pub fn close_expanded_value(&mut self) {
+        self.ui.expanded_value = None;
+    }
+
+    /// Esc closes the view; other keys scroll it. Whatever the view asks for
+    /// in return, a submit included, is dropped: it only shows a copy.
+    pub fn dispatch_key_to_expanded_value(&mut self, key: KeyEvent) -> InteractionResult {
+        if key.code == KeyCode::Esc {
+            self.close_expanded_value();
+            return InteractionResult::handled();
+        }
+        let Some(expanded) = self.ui.expanded_value.as_mut() else {
+            return InteractionResult::ignored();
+        };
+        let result = expanded.node.on_key(key);
+        InteractionResult::handled_if(result.handled && result.request_render)
+    }
+
+    pub fn dispatch_pointer_to_expanded_value(&mut self, event: PointerEvent) -> InteractionResult {
+        let Some(expanded) = self.ui.expanded_value.as_mut() else {
+            return InteractionResult::ignored();
+        };
+        let result = expanded.node.on_pointer(event);
+        InteractionResult::handled_if(result.handled && result.request_render)
+    }
+
+    fn expanded_value_rows(&self) -> usize {
+        let rows = match self.ui.viewport_rows {
+            0 => DEFAULT_VIEWPORT_ROWS,
+            rows => rows,
+        };
+        (rows as usize)
+            .saturating_sub(EXPANDED_VALUE_CHROME_ROWS)
+            .max(1)
+    }
+}
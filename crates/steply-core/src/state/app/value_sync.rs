@@ -2,7 +2,7 @@ use super::AppState;
 use super::transaction::AppliedStorePatch;
 use crate::core::{NodeId, value::Value, value_path::ValueTarget};
 use crate::state::change::{StoreCommitPolicy, StorePatch, StoreTransaction, StoreWriteOrigin};
-use crate::widgets::node::{NodeWalkScope, find_node, walk_nodes_mut};
+use crate::widgets::node::{Node, NodeWalkScope, find_node, walk_nodes_mut};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum CommitPhase {
@@ -51,9 +51,19 @@ impl AppState {
     }
 
     pub(super) fn hydrate_current_step_from_store(&mut self) -> bool {
+        let focused_id = self.ui.focus.current_id().map(str::to_string);
+        self.hydrate_current_step_with_focus(focused_id)
+    }
+
+    /// Hydrates every node, including the focused one, which normally keeps
+    /// its in-progress value. Used when the store is seeded from outside.
+    pub(super) fn force_hydrate_current_step_from_store(&mut self) -> bool {
+        self.hydrate_current_step_with_focus(None)
+    }
+
+    fn hydrate_current_step_with_focus(&mut self, focused_id: Option<String>) -> bool {
         let mut changed = false;
         let store = &self.data.store;
-        let focused_id = self.ui.focus.current_id().map(str::to_string);
         walk_nodes_mut(
             self.flow.current_step_mut().nodes.as_mut_slice(),
             NodeWalkScope::Recursive,
@@ -82,7 +92,7 @@ impl AppState {
             }
             let nodes = self.flow.current_step().nodes.as_slice();
             let Some(value) =
-                find_node(nodes, binding.node_id.as_str()).and_then(|node| node.value())
+                find_node(nodes, binding.node_id.as_str()).and_then(Node::committed_value)
             else {
                 continue;
             };
@@ -125,7 +135,7 @@ impl AppState {
 
             let nodes = self.flow.current_step().nodes.as_slice();
             let Some(value) =
-                find_node(nodes, binding.node_id.as_str()).and_then(|node| node.value())
+                find_node(nodes, binding.node_id.as_str()).and_then(Node::committed_value)
             else {
                 continue;
             };
@@ -228,6 +228,9 @@ pub fn collect_store_ownership(
         for binding in spec.writes {
             ownership.register(binding.target, StoreOwnership::Task);
         }
+        if let Some(target) = spec.progress {
+            ownership.register(target, StoreOwnership::Task);
+        }
     }
 
     ownership
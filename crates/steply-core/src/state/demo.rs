@@ -54,6 +54,7 @@ fn bind_writes(node: Node, targets: &[&str]) -> Node {
             reads: None,
             writes,
             commit_policy: crate::state::change::StoreCommitPolicy::Immediate,
+            transforms: Vec::new(),
         },
     )
 }
@@ -0,0 +1,145 @@
+use crate::core::store_refs::render_template;
+use crate::core::value::Value;
+use crate::state::report::{FlowReport, ReportFormat};
+use indexmap::IndexMap;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ExportFormat {
+    #[default]
+    Json,
+    Yaml,
+    Toml,
+    /// A readable [`FlowReport`] rather than the bare values.
+    Markdown,
+    Html,
+}
+
+impl ExportFormat {
+    pub fn parse(raw: &str) -> Option<Self> {
+        match raw {
+            "json" => Some(Self::Json),
+            "yaml" | "yml" => Some(Self::Yaml),
+            "toml" => Some(Self::Toml),
+            "markdown" | "md" => Some(Self::Markdown),
+            "html" => Some(Self::Html),
+            _ => None,
+        }
+    }
+
+    pub fn report_format(self) -> Option<ReportFormat> {
+        match self {
+            Self::Markdown => Some(ReportFormat::Markdown),
+            Self::Html => Some(ReportFormat::Html),
+            Self::Json | Self::Yaml | Self::Toml => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum ExportTarget {
+    #[default]
+    Stdout,
+    File(PathBuf),
+}
+
+impl ExportTarget {
+    /// `-` selects stdout, anything else is treated as a file path.
+    pub fn parse(raw: &str) -> Self {
+        if raw == "-" {
+            Self::Stdout
+        } else {
+            Self::File(PathBuf::from(raw))
+        }
+    }
+}
+
+/// Writes the collected values once the flow finishes.
+///
+/// With a `template`, the rendered template is written instead of the
+/// serialized values; `{{ selector }}` expressions read from the store.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ResultExport {
+    pub format: ExportFormat,
+    pub target: ExportTarget,
+    pub template: Option<String>,
+}
+
+impl ResultExport {
+    pub fn render(&self, values: &Value) -> Result<String, String> {
+        if let Some(template) = self.template.as_deref() {
+            return Ok(render_template(
+                template,
+                |selector| lookup_selector(values, selector),
+                |value| value.to_text_scalar().unwrap_or_else(|| value.to_json()),
+            ));
+        }
+        match self.format {
+            ExportFormat::Json => values.to_json_string_pretty().map(|json| json + "\n"),
+            ExportFormat::Yaml => values.to_yaml_string(),
+            ExportFormat::Toml => to_toml_string(values),
+            ExportFormat::Markdown => {
+                Ok(FlowReport::from_values(values).render(ReportFormat::Markdown))
+            }
+            ExportFormat::Html => Ok(FlowReport::from_values(values).render(ReportFormat::Html)),
+        }
+    }
+}
+
+fn lookup_selector(values: &Value, selector: &str) -> Option<Value> {
+    let target = crate::core::store_refs::parse_store_selector(selector).ok()?;
+    let Value::Object(roots) = values else {
+        return None;
+    };
+    let root = roots.get(target.root().as_str())?;
+    match target.nested_path() {
+        Some(path) => root.get_path(path).cloned(),
+        None => Some(root.clone()),
+    }
+}
+
+fn to_toml_string(value: &Value) -> Result<String, String> {
+    let Value::Object(entries) = value else {
+        return Err("toml export requires an object at the root".to_string());
+    };
+    toml::to_string(&toml_table(entries)?).map_err(|err| err.to_string())
+}
+
+/// TOML has no null: null fields are left out of tables.
+fn toml_table(entries: &IndexMap<String, Value>) -> Result<toml::Table, String> {
+    let mut table = toml::Table::new();
+    for (key, value) in entries {
+        if !matches!(value, Value::None) {
+            table.insert(key.clone(), toml_value(value)?);
+        }
+    }
+    Ok(table)
+}
+
+fn toml_value(value: &Value) -> Result<toml::Value, String> {
+    match value {
+        // Null list items become empty strings to keep their positions.
+        Value::None => Ok(toml::Value::String(String::new())),
+        Value::Bool(flag) => Ok(toml::Value::Boolean(*flag)),
+        Value::Number(number) if !number.is_finite() => {
+            Err(format!("cannot serialize non-finite number: {number}"))
+        }
+        Value::Number(number)
+            if number.fract() == 0.0 && number.abs() < 9_007_199_254_740_992.0 =>
+        {
+            Ok(toml::Value::Integer(*number as i64))
+        }
+        Value::Number(number) => Ok(toml::Value::Float(*number)),
+        Value::Text(text) => Ok(toml::Value::String(text.clone())),
+        Value::List(items) => items
+            .iter()
+            .map(toml_value)
+            .collect::<Result<_, _>>()
+            .map(toml::Value::Array),
+        Value::Object(entries) => toml_table(entries).map(toml::Value::Table),
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/export.rs"]
+mod tests;
@@ -1,9 +1,13 @@
+use std::collections::HashSet;
+
+use crate::state::lifecycle::{LifecycleContext, LifecycleEvent, LifecycleHooks};
 use crate::state::step::{Step, StepStatus};
 
 pub struct Flow {
     steps: Vec<Step>,
     current: usize,
     statuses: Vec<StepStatus>,
+    hooks: LifecycleHooks,
 }
 
 impl Flow {
@@ -16,9 +20,39 @@ impl Flow {
             steps,
             current: 0,
             statuses,
+            hooks: LifecycleHooks::default(),
         }
     }
 
+    /// Flow-level hooks run when the flow starts, finishes and is cancelled.
+    pub fn on_enter(
+        mut self,
+        f: impl Fn(&mut LifecycleContext) -> Result<(), String> + Send + Sync + 'static,
+    ) -> Self {
+        self.hooks.push(LifecycleEvent::Enter, Box::new(f));
+        self
+    }
+
+    pub fn on_exit(
+        mut self,
+        f: impl Fn(&mut LifecycleContext) -> Result<(), String> + Send + Sync + 'static,
+    ) -> Self {
+        self.hooks.push(LifecycleEvent::Exit, Box::new(f));
+        self
+    }
+
+    pub fn on_cancel(
+        mut self,
+        f: impl Fn(&mut LifecycleContext) -> Result<(), String> + Send + Sync + 'static,
+    ) -> Self {
+        self.hooks.push(LifecycleEvent::Cancel, Box::new(f));
+        self
+    }
+
+    pub fn hooks(&self) -> &LifecycleHooks {
+        &self.hooks
+    }
+
     pub fn current_index(&self) -> usize {
         self.current
     }
@@ -107,6 +141,44 @@ impl Flow {
         true
     }
 
+    pub fn insert_after_current(&mut self, steps: Vec<Step>) {
+        if self.steps.is_empty() {
+            return;
+        }
+        let at = self.current + 1;
+        let count = steps.len();
+        self.steps.splice(at..at, steps);
+        self.statuses
+            .splice(at..at, std::iter::repeat_n(StepStatus::Pending, count));
+    }
+
+    /// Indices of the steps after the current one that `origin_step_id`
+    /// inserted, directly or through a step it inserted.
+    pub fn pending_inserted_by(&self, origin_step_id: &str) -> Vec<usize> {
+        let mut origins = HashSet::from([origin_step_id]);
+        let mut indices = Vec::new();
+        for (index, step) in self.steps.iter().enumerate().skip(self.current + 1) {
+            if step
+                .inserted_by
+                .as_deref()
+                .is_some_and(|origin| origins.contains(origin))
+            {
+                origins.insert(step.id.as_str());
+                indices.push(index);
+            }
+        }
+        indices
+    }
+
+    pub fn remove_pending_inserted_by(&mut self, origin_step_id: &str) -> usize {
+        let indices = self.pending_inserted_by(origin_step_id);
+        for &index in indices.iter().rev() {
+            self.steps.remove(index);
+            self.statuses.remove(index);
+        }
+        indices.len()
+    }
+
     pub fn current_step(&self) -> &Step {
         &self.steps[self.current]
     }
@@ -115,6 +187,16 @@ impl Flow {
         &mut self.steps[self.current]
     }
 
+    /// Puts the flow back at `index` with earlier statuses, one per step.
+    pub fn restore(&mut self, index: usize, statuses: Vec<StepStatus>) -> bool {
+        if index >= self.steps.len() || statuses.len() != self.steps.len() {
+            return false;
+        }
+        self.current = index;
+        self.statuses = statuses;
+        true
+    }
+
     pub fn set_current(&mut self, index: usize) -> bool {
         if index >= self.steps.len() {
             return false;
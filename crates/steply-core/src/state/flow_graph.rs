@@ -0,0 +1,182 @@
+use crate::state::flow::Flow;
+use crate::state::step::Step;
+use std::fmt::Write as _;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GraphFormat {
+    Dot,
+    #[default]
+    Mermaid,
+}
+
+impl GraphFormat {
+    pub fn parse(raw: &str) -> Option<Self> {
+        match raw {
+            "dot" | "graphviz" => Some(Self::Dot),
+            "mermaid" => Some(Self::Mermaid),
+            _ => None,
+        }
+    }
+}
+
+struct GraphEdge {
+    from: usize,
+    to: Option<usize>,
+    label: Option<String>,
+    /// Conditional steps the edge jumps past.
+    skipped: Vec<usize>,
+}
+
+const END_NODE_ID: &str = "__end";
+
+impl Flow {
+    /// Renders the step graph: one node per step, an edge to every step that
+    /// can come next. A `when` on the target labels the edge; skipping a
+    /// conditional step is drawn as a direct edge past it, which the DOT
+    /// graph dashes and labels with the steps it skips.
+    pub fn to_graph(&self, format: GraphFormat) -> String {
+        let edges = graph_edges(self.steps());
+        match format {
+            GraphFormat::Dot => render_dot(self.steps(), &edges),
+            GraphFormat::Mermaid => render_mermaid(self.steps(), &edges),
+        }
+    }
+}
+
+fn graph_edges(steps: &[Step]) -> Vec<GraphEdge> {
+    let mut edges = Vec::new();
+    for from in 0..steps.len() {
+        let mut reaches_end = true;
+        for (to, step) in steps.iter().enumerate().skip(from + 1) {
+            edges.push(GraphEdge {
+                from,
+                to: Some(to),
+                label: step.when.as_ref().map(ToString::to_string),
+                skipped: (from + 1..to).collect(),
+            });
+            if step.when.is_none() {
+                reaches_end = false;
+                break;
+            }
+        }
+        if reaches_end {
+            edges.push(GraphEdge {
+                from,
+                to: None,
+                label: None,
+                skipped: (from + 1..steps.len()).collect(),
+            });
+        }
+    }
+    edges
+}
+
+fn node_label(step: &Step) -> String {
+    let mut label = step.prompt.clone();
+    if step.review {
+        label.push_str(" (review)");
+    }
+    if !step.expanders.is_empty() {
+        label.push_str(" (+ dynamic steps)");
+    }
+    if step.auto_advance.is_some() {
+        label.push_str(" (auto-advance)");
+    }
+    label
+}
+
+fn render_dot(steps: &[Step], edges: &[GraphEdge]) -> String {
+    let mut out = String::from("digraph flow {\n    rankdir=TB;\n");
+    for step in steps {
+        let shape = if step.when.is_some() {
+            "diamond"
+        } else {
+            "box"
+        };
+        let _ = writeln!(
+            out,
+            "    {} [label={}, shape={shape}];",
+            dot_quote(step.id.as_str()),
+            dot_quote(node_label(step).as_str())
+        );
+    }
+    let _ = writeln!(
+        out,
+        "    {} [label=\"Done\", shape=doublecircle];",
+        dot_quote(END_NODE_ID)
+    );
+    for edge in edges {
+        let target = edge
+            .to
+            .map_or(END_NODE_ID, |index| steps[index].id.as_str());
+        let _ = write!(
+            out,
+            "    {} -> {}",
+            dot_quote(steps[edge.from].id.as_str()),
+            dot_quote(target)
+        );
+        let mut label = edge.label.clone().into_iter().collect::<Vec<_>>();
+        if !edge.skipped.is_empty() {
+            let skipped = edge
+                .skipped
+                .iter()
+                .map(|index| steps[*index].prompt.as_str())
+                .collect::<Vec<_>>();
+            label.push(format!("skips {}", skipped.join(", ")));
+        }
+        if !label.is_empty() {
+            let _ = write!(out, " [label={}", dot_quote(label.join("; ").as_str()));
+            if !edge.skipped.is_empty() {
+                out.push_str(", style=dashed");
+            }
+            out.push(']');
+        }
+        out.push_str(";\n");
+    }
+    out.push_str("}\n");
+    out
+}
+
+fn dot_quote(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn render_mermaid(steps: &[Step], edges: &[GraphEdge]) -> String {
+    let mut out = String::from("flowchart TD\n");
+    for (index, step) in steps.iter().enumerate() {
+        let label = mermaid_text(node_label(step).as_str());
+        if step.when.is_some() {
+            let _ = writeln!(out, "    s{index}{{\"{label}\"}}");
+        } else {
+            let _ = writeln!(out, "    s{index}[\"{label}\"]");
+        }
+    }
+    let _ = writeln!(out, "    {END_NODE_ID}((\"Done\"))");
+    for edge in edges {
+        let target = edge
+            .to
+            .map_or_else(|| END_NODE_ID.to_string(), |index| format!("s{index}"));
+        match &edge.label {
+            Some(label) => {
+                let _ = writeln!(
+                    out,
+                    "    s{} -->|\"{}\"| {target}",
+                    edge.from,
+                    mermaid_text(label)
+                );
+            }
+            None => {
+                let _ = writeln!(out, "    s{} --> {target}", edge.from);
+            }
+        }
+    }
+    out
+}
+
+fn mermaid_text(text: &str) -> String {
+    text.replace('"', "#quot;")
+}
+
+#[cfg(test)]
+#[path = "tests/flow_graph.rs"]
+mod tests;
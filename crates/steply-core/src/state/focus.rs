@@ -35,6 +35,14 @@ impl FocusState {
             .map(|target| target.id.as_str())
     }
 
+    pub fn ids(&self) -> impl Iterator<Item = &str> {
+        self.targets.iter().map(|target| target.id.as_str())
+    }
+
+    pub fn contains(&self, id: &str) -> bool {
+        self.targets.iter().any(|target| target.id.as_str() == id)
+    }
+
     pub fn set_focus_by_id(&mut self, id: &str) {
         self.index = self
             .targets
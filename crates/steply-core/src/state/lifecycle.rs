@@ -0,0 +1,92 @@
+use crate::core::store_refs::parse_store_selector;
+use crate::core::value::Value;
+use crate::core::value_path::ValueTarget;
+use crate::state::store::ValueStore;
+
+/// Mutable view of the form state handed to lifecycle hooks.
+///
+/// Writes go straight to the store; the app re-hydrates widgets and fires
+/// store-change triggers for every written target once the hook returns.
+pub struct LifecycleContext<'a> {
+    pub step_id: &'a str,
+    store: &'a mut ValueStore,
+    written: Vec<ValueTarget>,
+}
+
+impl<'a> LifecycleContext<'a> {
+    pub fn new(step_id: &'a str, store: &'a mut ValueStore) -> Self {
+        Self {
+            step_id,
+            store,
+            written: Vec::new(),
+        }
+    }
+
+    pub fn get(&self, selector: &str) -> &Value {
+        self.store.get_selector(selector).unwrap_or(&Value::None)
+    }
+
+    pub fn text(&self, selector: &str) -> &str {
+        self.get(selector).as_text().unwrap_or("")
+    }
+
+    pub fn bool(&self, selector: &str) -> bool {
+        self.get(selector).as_bool().unwrap_or(false)
+    }
+
+    pub fn set(&mut self, selector: &str, value: Value) -> Result<(), String> {
+        let target = parse_store_selector(selector)
+            .map_err(|err| format!("invalid selector '{selector}': {err}"))?;
+        self.set_target(target, value)
+    }
+
+    pub fn set_target(&mut self, target: ValueTarget, value: Value) -> Result<(), String> {
+        self.store
+            .set_target(&target, value)
+            .map_err(|err| err.to_string())?;
+        if !self.written.contains(&target) {
+            self.written.push(target);
+        }
+        Ok(())
+    }
+
+    pub fn into_written(self) -> Vec<ValueTarget> {
+        self.written
+    }
+}
+
+/// Returning `Err` from an exit or cancel hook vetoes the transition and
+/// shows the message as a step error; from an enter hook it only reports.
+pub type LifecycleHook = Box<dyn Fn(&mut LifecycleContext) -> Result<(), String> + Send + Sync>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LifecycleEvent {
+    Enter,
+    Exit,
+    Cancel,
+}
+
+#[derive(Default)]
+pub struct LifecycleHooks {
+    pub on_enter: Vec<LifecycleHook>,
+    pub on_exit: Vec<LifecycleHook>,
+    pub on_cancel: Vec<LifecycleHook>,
+}
+
+impl LifecycleHooks {
+    pub fn for_event(&self, event: LifecycleEvent) -> &[LifecycleHook] {
+        match event {
+            LifecycleEvent::Enter => &self.on_enter,
+            LifecycleEvent::Exit => &self.on_exit,
+            LifecycleEvent::Cancel => &self.on_cancel,
+        }
+    }
+
+    pub fn push(&mut self, event: LifecycleEvent, hook: LifecycleHook) {
+        match event {
+            LifecycleEvent::Enter => self.on_enter.push(hook),
+            LifecycleEvent::Exit => self.on_exit.push(hook),
+            LifecycleEvent::Cancel => self.on_cancel.push(hook),
+        }
+    }
+}
@@ -1,9 +1,15 @@
+pub mod announce;
 pub mod app;
 pub mod change;
 pub mod demo;
+pub mod export;
 pub mod flow;
+pub mod flow_graph;
 pub mod focus;
+pub mod lifecycle;
 pub mod overlay;
+pub mod prefill;
+pub mod report;
 pub mod step;
 pub mod store;
 pub mod validation;
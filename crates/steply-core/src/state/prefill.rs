@@ -0,0 +1,41 @@
+use crate::core::value::Value;
+use crate::core::value_path::ValueTarget;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+/// Store root that variables loaded from `.env` files are written under, so
+/// `{{ env.DATABASE_URL }}` reaches them from computed fields and validators.
+pub const DOTENV_ROOT: &str = "env";
+
+/// Values seeded into the store before the first render.
+///
+/// Sources are applied in order — previous-run answers, then `.env`
+/// variables, then literal values, then environment variables — so later
+/// sources override earlier ones.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Prefill {
+    pub previous_run: Option<PathBuf>,
+    /// Variables read from `.env` files, see [`crate::core::dotenv`]. They
+    /// also answer `env` lookups the process environment leaves unset.
+    pub dotenv: BTreeMap<String, String>,
+    pub values: Vec<(ValueTarget, Value)>,
+    pub env: Vec<(ValueTarget, String)>,
+}
+
+impl Prefill {
+    pub fn is_empty(&self) -> bool {
+        self.previous_run.is_none()
+            && self.dotenv.is_empty()
+            && self.values.is_empty()
+            && self.env.is_empty()
+    }
+
+    /// Loads `.env` files in order, later files overriding earlier ones.
+    pub fn with_dotenv_files<P: AsRef<std::path::Path>>(
+        mut self,
+        paths: &[P],
+    ) -> Result<Self, String> {
+        self.dotenv.extend(crate::core::dotenv::load_files(paths)?);
+        Ok(self)
+    }
+}
@@ -0,0 +1,219 @@
+use crate::core::value::Value;
+use crate::task::TaskRunOutcome;
+use crate::time::Duration;
+use indexmap::IndexMap;
+use std::fmt::Write as _;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Markdown,
+    Html,
+}
+
+/// A finished flow written up for an audit trail: what was answered on
+/// each visited step and how the tasks it ran went.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FlowReport {
+    pub steps: Vec<ReportStep>,
+    pub tasks: Vec<ReportTask>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReportStep {
+    pub id: String,
+    pub title: String,
+    pub description: Option<String>,
+    /// Answers keyed by widget id.
+    pub entries: IndexMap<String, ReportEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReportEntry {
+    pub label: String,
+    pub value: Value,
+}
+
+impl ReportEntry {
+    pub fn new(label: impl Into<String>, value: Value) -> Self {
+        Self {
+            label: label.into(),
+            value,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportTask {
+    pub id: String,
+    pub runs: u64,
+    pub outcome: Option<TaskRunOutcome>,
+    /// Duration of the last finished run.
+    pub duration: Option<Duration>,
+}
+
+impl FlowReport {
+    /// A report of bare collected values, one section per store root, for
+    /// when no flow is at hand.
+    pub fn from_values(values: &Value) -> Self {
+        let steps = match values {
+            Value::Object(roots) => roots
+                .iter()
+                .map(|(root, value)| ReportStep {
+                    id: root.clone(),
+                    title: root.clone(),
+                    description: None,
+                    entries: match value {
+                        Value::Object(entries) => entries
+                            .iter()
+                            .map(|(key, value)| {
+                                (key.clone(), ReportEntry::new(key.as_str(), value.clone()))
+                            })
+                            .collect(),
+                        other => IndexMap::from([(
+                            root.clone(),
+                            ReportEntry::new(root.as_str(), other.clone()),
+                        )]),
+                    },
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+        Self {
+            steps,
+            tasks: Vec::new(),
+        }
+    }
+
+    pub fn render(&self, format: ReportFormat) -> String {
+        match format {
+            ReportFormat::Markdown => self.render_markdown(),
+            ReportFormat::Html => self.render_html(),
+        }
+    }
+
+    fn render_markdown(&self) -> String {
+        let mut out = String::from("# Flow report\n");
+        for step in &self.steps {
+            let _ = write!(out, "\n## {}\n\n", step.title);
+            if let Some(description) = step.description.as_deref() {
+                let _ = write!(out, "{description}\n\n");
+            }
+            out.push_str("| Field | Value |\n| --- | --- |\n");
+            for entry in step.entries.values() {
+                let _ = writeln!(
+                    out,
+                    "| {} | {} |",
+                    markdown_cell(entry.label.as_str()),
+                    markdown_cell(display_value(&entry.value).as_str())
+                );
+            }
+        }
+        if !self.tasks.is_empty() {
+            out.push_str(
+                "\n## Tasks\n\n| Task | Runs | Result | Duration |\n| --- | --- | --- | --- |\n",
+            );
+            for task in &self.tasks {
+                let _ = writeln!(
+                    out,
+                    "| {} | {} | {} | {} |",
+                    markdown_cell(task.id.as_str()),
+                    task.runs,
+                    markdown_cell(outcome_label(task.outcome.as_ref()).as_str()),
+                    task.duration.map(format_duration).unwrap_or_default()
+                );
+            }
+        }
+        out
+    }
+
+    fn render_html(&self) -> String {
+        let mut out = String::from(
+            "<!DOCTYPE html>\n<html>\n<head><meta charset=\"utf-8\"><title>Flow report</title></head>\n<body>\n<h1>Flow report</h1>\n",
+        );
+        for step in &self.steps {
+            let _ = writeln!(out, "<h2>{}</h2>", html_escape(step.title.as_str()));
+            if let Some(description) = step.description.as_deref() {
+                let _ = writeln!(out, "<p>{}</p>", html_escape(description));
+            }
+            out.push_str("<table>\n<tr><th>Field</th><th>Value</th></tr>\n");
+            for entry in step.entries.values() {
+                let _ = writeln!(
+                    out,
+                    "<tr><td>{}</td><td>{}</td></tr>",
+                    html_escape(entry.label.as_str()),
+                    html_escape(display_value(&entry.value).as_str())
+                );
+            }
+            out.push_str("</table>\n");
+        }
+        if !self.tasks.is_empty() {
+            out.push_str("<h2>Tasks</h2>\n<table>\n<tr><th>Task</th><th>Runs</th><th>Result</th><th>Duration</th></tr>\n");
+            for task in &self.tasks {
+                let _ = writeln!(
+                    out,
+                    "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+                    html_escape(task.id.as_str()),
+                    task.runs,
+                    html_escape(outcome_label(task.outcome.as_ref()).as_str()),
+                    task.duration.map(format_duration).unwrap_or_default()
+                );
+            }
+            out.push_str("</table>\n");
+        }
+        out.push_str("</body>\n</html>\n");
+        out
+    }
+}
+
+fn display_value(value: &Value) -> String {
+    match value {
+        Value::None => String::new(),
+        value => value.to_text_scalar().unwrap_or_else(|| value.to_json()),
+    }
+}
+
+fn outcome_label(outcome: Option<&TaskRunOutcome>) -> String {
+    match outcome {
+        None => "running".to_string(),
+        Some(TaskRunOutcome::Succeeded) => "succeeded".to_string(),
+        Some(TaskRunOutcome::Cancelled) => "cancelled".to_string(),
+        Some(TaskRunOutcome::Failed(error)) => format!("failed: {error}"),
+        Some(TaskRunOutcome::Skipped(error)) => format!("skipped after failure: {error}"),
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    let millis = duration.as_millis();
+    if millis < 1_000 {
+        format!("{millis} ms")
+    } else {
+        format!("{:.1} s", duration.as_secs_f64())
+    }
+}
+
+/// Keeps a value inside its table cell and out of emphasis markup.
+fn markdown_cell(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('|', "\\|")
+        .replace('*', "\\*")
+        .replace('\n', "<br>")
+}
+
+fn html_escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            ch => out.push(ch),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+#[path = "tests/report.rs"]
+mod tests;
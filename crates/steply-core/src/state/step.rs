@@ -2,13 +2,18 @@ use crate::core::store_refs::{parse_store_selector, template_expressions};
 use crate::core::value::Value;
 use crate::core::value_path::ValueTarget;
 use crate::state::change::StoreCommitPolicy;
+use crate::state::lifecycle::{LifecycleContext, LifecycleEvent, LifecycleHooks};
 use crate::state::store::ValueStore;
 use crate::state::validation::{StepContext, StepIssue, StepValidator};
-use crate::widgets::node::{Component, Node, NodeWalkScope, walk_nodes};
+use crate::task::TaskId;
+use crate::time::Duration;
+use crate::ui::layout::LayoutNode;
+use crate::widgets::components::command_runner::CommandRunner;
+use crate::widgets::node::{Component, Node, NodeWalkScope, find_node, walk_nodes};
 use crate::widgets::shared::binding::{ReadBinding, StoreBinding};
 use crate::widgets::traits::{InteractiveNode, OutputNode};
 use std::cmp::Ordering;
-use std::collections::{HashSet, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum StepStatus {
@@ -33,15 +38,31 @@ pub enum StepNavigation {
     },
 }
 
+pub type StepExpander = Box<dyn Fn(&StepContext) -> Vec<Step> + Send + Sync>;
+
 pub struct Step {
     pub id: String,
     pub prompt: String,
     pub description: Option<String>,
+    /// Shown under the focused field when the field has no help of its own.
+    pub help: Option<String>,
+    /// Help text by widget id, shown while the widget or a child is focused.
+    pub field_help: HashMap<String, String>,
+    /// Names announced for widgets in accessibility mode, by widget id.
+    pub aria_labels: HashMap<String, String>,
     pub nodes: Vec<Node>,
     pub binding_plan: StepBindingPlan,
     pub validators: Vec<StepValidator>,
     pub navigation: StepNavigation,
     pub when: Option<StepCondition>,
+    pub submit_hooks: Vec<TaskId>,
+    pub expanders: Vec<StepExpander>,
+    pub inserted_by: Option<String>,
+    pub auto_advance: Option<Duration>,
+    pub hooks: LifecycleHooks,
+    pub review: bool,
+    /// Places widgets side by side; widgets it leaves out follow below it.
+    pub layout: Option<LayoutNode>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -143,6 +164,37 @@ impl StepCondition {
     }
 }
 
+impl std::fmt::Display for StepCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let literal = |value: &Value| value.to_text_scalar().unwrap_or_else(|| value.to_json());
+        let join = |f: &mut std::fmt::Formatter<'_>, items: &[StepCondition], sep: &str| {
+            for (index, condition) in items.iter().enumerate() {
+                if index > 0 {
+                    write!(f, " {sep} ")?;
+                }
+                write!(f, "({condition})")?;
+            }
+            Ok(())
+        };
+        match self {
+            Self::Truthy { field } => write!(f, "{field}"),
+            Self::Exists { field } => write!(f, "{field} exists"),
+            Self::Empty { field } => write!(f, "{field} is empty"),
+            Self::NotEmpty { field } => write!(f, "{field} is not empty"),
+            Self::Equals { field, value } => write!(f, "{field} == {}", literal(value)),
+            Self::NotEquals { field, value } => write!(f, "{field} != {}", literal(value)),
+            Self::GreaterThan { field, value } => write!(f, "{field} > {}", literal(value)),
+            Self::GreaterOrEqual { field, value } => write!(f, "{field} >= {}", literal(value)),
+            Self::LessThan { field, value } => write!(f, "{field} < {}", literal(value)),
+            Self::LessOrEqual { field, value } => write!(f, "{field} <= {}", literal(value)),
+            Self::Contains { field, value } => write!(f, "{field} contains {}", literal(value)),
+            Self::All(items) => join(f, items, "and"),
+            Self::Any(items) => join(f, items, "or"),
+            Self::Not(condition) => write!(f, "not ({condition})"),
+        }
+    }
+}
+
 fn is_truthy(value: &Value) -> bool {
     match value {
         Value::None => false,
@@ -191,11 +243,21 @@ impl Step {
             id: id.into(),
             prompt: prompt.into(),
             description: None,
+            help: None,
+            field_help: HashMap::new(),
+            aria_labels: HashMap::new(),
             nodes,
             binding_plan,
             validators: Vec::new(),
             navigation: StepNavigation::default(),
             when: None,
+            submit_hooks: Vec::new(),
+            expanders: Vec::new(),
+            inserted_by: None,
+            auto_advance: None,
+            hooks: LifecycleHooks::default(),
+            review: false,
+            layout: None,
         }
     }
 
@@ -204,6 +266,47 @@ impl Step {
         self
     }
 
+    pub fn with_help(mut self, help: impl Into<String>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+
+    pub fn with_field_help(
+        mut self,
+        widget_id: impl Into<String>,
+        help: impl Into<String>,
+    ) -> Self {
+        self.field_help.insert(widget_id.into(), help.into());
+        self
+    }
+
+    pub fn with_aria_label(
+        mut self,
+        widget_id: impl Into<String>,
+        label: impl Into<String>,
+    ) -> Self {
+        self.aria_labels.insert(widget_id.into(), label.into());
+        self
+    }
+
+    pub fn has_help(&self) -> bool {
+        self.help.is_some() || !self.field_help.is_empty()
+    }
+
+    /// The help for `focused_id` together with the id of the top-level widget
+    /// it belongs to, which is where the help is drawn.
+    pub fn help_for(&self, focused_id: &str) -> Option<(&str, &str)> {
+        let owner = self.nodes.iter().find(|node| {
+            node.id() == focused_id || find_node(std::slice::from_ref(*node), focused_id).is_some()
+        })?;
+        let help = self
+            .field_help
+            .get(focused_id)
+            .or_else(|| self.field_help.get(owner.id()))
+            .or(self.help.as_ref())?;
+        Some((owner.id(), help.as_str()))
+    }
+
     pub fn with_validator(mut self, validator: StepValidator) -> Self {
         self.validators.push(validator);
         self
@@ -243,6 +346,50 @@ impl Step {
         self
     }
 
+    pub fn with_submit_hook(mut self, task_id: impl Into<TaskId>) -> Self {
+        self.submit_hooks.push(task_id.into());
+        self
+    }
+
+    pub fn expand(mut self, f: impl Fn(&StepContext) -> Vec<Step> + Send + Sync + 'static) -> Self {
+        self.expanders.push(Box::new(f));
+        self
+    }
+
+    pub fn with_auto_advance(mut self, after: Duration) -> Self {
+        self.auto_advance = Some(after);
+        self
+    }
+
+    pub fn with_layout(mut self, layout: LayoutNode) -> Self {
+        self.layout = Some(layout);
+        self
+    }
+
+    pub fn on_enter(
+        mut self,
+        f: impl Fn(&mut LifecycleContext) -> Result<(), String> + Send + Sync + 'static,
+    ) -> Self {
+        self.hooks.push(LifecycleEvent::Enter, Box::new(f));
+        self
+    }
+
+    pub fn on_exit(
+        mut self,
+        f: impl Fn(&mut LifecycleContext) -> Result<(), String> + Send + Sync + 'static,
+    ) -> Self {
+        self.hooks.push(LifecycleEvent::Exit, Box::new(f));
+        self
+    }
+
+    pub fn on_cancel(
+        mut self,
+        f: impl Fn(&mut LifecycleContext) -> Result<(), String> + Send + Sync + 'static,
+    ) -> Self {
+        self.hooks.push(LifecycleEvent::Cancel, Box::new(f));
+        self
+    }
+
     pub fn is_visible(&self, store: &ValueStore) -> bool {
         self.when
             .as_ref()
@@ -252,16 +399,49 @@ impl Step {
     pub fn builder(id: impl Into<String>, prompt: impl Into<String>) -> StepBuilder {
         StepBuilder::new(id, prompt)
     }
+
+    /// A confirmation step listing every collected value of the preceding
+    /// steps, with a button per step to jump back and edit it. Its widgets
+    /// are regenerated each time the step is entered.
+    pub fn review(id: impl Into<String>, prompt: impl Into<String>) -> StepBuilder {
+        StepBuilder::new(id, prompt).review()
+    }
+
+    /// A step that runs external commands, streaming their output, and only
+    /// lets the user continue once they have succeeded.
+    pub fn command(
+        id: impl Into<String>,
+        prompt: impl Into<String>,
+        runner: CommandRunner,
+    ) -> StepBuilder {
+        StepBuilder::new(id, prompt).node(Node::Component(Box::new(
+            runner.with_require_success(true).with_keep_output(true),
+        )))
+    }
+
+    pub(crate) fn replace_nodes(&mut self, nodes: Vec<Node>) {
+        self.binding_plan = StepBindingPlan::from_nodes(nodes.as_slice());
+        self.nodes = nodes;
+    }
 }
 
 pub struct StepBuilder {
     id: String,
     prompt: String,
     description: Option<String>,
+    help: Option<String>,
+    field_help: HashMap<String, String>,
+    aria_labels: HashMap<String, String>,
     nodes: Vec<Node>,
     validators: Vec<StepValidator>,
     navigation: StepNavigation,
     when: Option<StepCondition>,
+    submit_hooks: Vec<TaskId>,
+    expanders: Vec<StepExpander>,
+    auto_advance: Option<Duration>,
+    hooks: LifecycleHooks,
+    review: bool,
+    layout: Option<LayoutNode>,
 }
 
 impl StepBuilder {
@@ -270,10 +450,19 @@ impl StepBuilder {
             id: id.into(),
             prompt: prompt.into(),
             description: None,
+            help: None,
+            field_help: HashMap::new(),
+            aria_labels: HashMap::new(),
             nodes: Vec::new(),
             validators: Vec::new(),
             navigation: StepNavigation::default(),
             when: None,
+            submit_hooks: Vec::new(),
+            expanders: Vec::new(),
+            auto_advance: None,
+            hooks: LifecycleHooks::default(),
+            review: false,
+            layout: None,
         }
     }
 
@@ -282,6 +471,21 @@ impl StepBuilder {
         self
     }
 
+    pub fn help(mut self, help: impl Into<String>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+
+    pub fn field_help(mut self, widget_id: impl Into<String>, help: impl Into<String>) -> Self {
+        self.field_help.insert(widget_id.into(), help.into());
+        self
+    }
+
+    pub fn aria_label(mut self, widget_id: impl Into<String>, label: impl Into<String>) -> Self {
+        self.aria_labels.insert(widget_id.into(), label.into());
+        self
+    }
+
     pub fn node(mut self, node: Node) -> Self {
         self.nodes.push(node);
         self
@@ -346,17 +550,76 @@ impl StepBuilder {
         self
     }
 
+    pub fn submit_hook(mut self, task_id: impl Into<TaskId>) -> Self {
+        self.submit_hooks.push(task_id.into());
+        self
+    }
+
+    pub fn expand(mut self, f: impl Fn(&StepContext) -> Vec<Step> + Send + Sync + 'static) -> Self {
+        self.expanders.push(Box::new(f));
+        self
+    }
+
+    pub fn auto_advance(mut self, after: Duration) -> Self {
+        self.auto_advance = Some(after);
+        self
+    }
+
+    pub fn on_enter(
+        mut self,
+        f: impl Fn(&mut LifecycleContext) -> Result<(), String> + Send + Sync + 'static,
+    ) -> Self {
+        self.hooks.push(LifecycleEvent::Enter, Box::new(f));
+        self
+    }
+
+    pub fn on_exit(
+        mut self,
+        f: impl Fn(&mut LifecycleContext) -> Result<(), String> + Send + Sync + 'static,
+    ) -> Self {
+        self.hooks.push(LifecycleEvent::Exit, Box::new(f));
+        self
+    }
+
+    pub fn on_cancel(
+        mut self,
+        f: impl Fn(&mut LifecycleContext) -> Result<(), String> + Send + Sync + 'static,
+    ) -> Self {
+        self.hooks.push(LifecycleEvent::Cancel, Box::new(f));
+        self
+    }
+
+    pub fn review(mut self) -> Self {
+        self.review = true;
+        self
+    }
+
+    pub fn layout(mut self, layout: LayoutNode) -> Self {
+        self.layout = Some(layout);
+        self
+    }
+
     pub fn build(self) -> Step {
         let binding_plan = StepBindingPlan::from_nodes(self.nodes.as_slice());
         Step {
             id: self.id,
             prompt: self.prompt,
             description: self.description,
+            help: self.help,
+            field_help: self.field_help,
+            aria_labels: self.aria_labels,
             nodes: self.nodes,
             binding_plan,
             validators: self.validators,
             navigation: self.navigation,
             when: self.when,
+            submit_hooks: self.submit_hooks,
+            expanders: self.expanders,
+            inserted_by: None,
+            auto_advance: self.auto_advance,
+            hooks: self.hooks,
+            review: self.review,
+            layout: self.layout,
         }
     }
 }
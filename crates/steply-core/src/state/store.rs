@@ -48,9 +48,10 @@ impl fmt::Display for StoreWriteError {
 
 impl std::error::Error for StoreWriteError {}
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct ValueStore {
     values: HashMap<NodeId, Value>,
+    revision: u64,
 }
 
 impl ValueStore {
@@ -63,10 +64,12 @@ impl ValueStore {
         match self.values.get(id.as_str()) {
             None | Some(Value::None) => {
                 self.values.insert(id, value);
+                self.revision += 1;
                 Ok(())
             }
             Some(existing) if existing.kind_name() == value.kind_name() => {
                 self.values.insert(id, value);
+                self.revision += 1;
                 Ok(())
             }
             Some(existing) => Err(StoreWriteError::RootTypeConflict {
@@ -103,11 +106,24 @@ impl ValueStore {
                     .entry(root.clone())
                     .or_insert_with(|| default_root_for_path(path));
                 set_path_value_strict(entry, root.as_str(), path, value)?;
+                self.revision += 1;
             }
         }
         Ok(())
     }
 
+    /// Takes the values of `other`, counting it as one more write.
+    pub fn replace_values(&mut self, other: ValueStore) {
+        self.values = other.values;
+        self.revision += 1;
+    }
+
+    /// Grows with every write, so a caller can tell whether the values
+    /// changed without comparing them.
+    pub fn revision(&self) -> u64 {
+        self.revision
+    }
+
     pub fn get_selector(&self, selector: &str) -> Option<&Value> {
         let target = parse_store_selector(selector).ok()?;
         self.get_target(&target)
@@ -0,0 +1,96 @@
+use super::Announcer;
+use crate::config::load_from_yaml_str;
+use crate::runtime::event::SystemEvent;
+use crate::state::app::AppState;
+use crate::state::flow::Flow;
+use crate::state::step::Step;
+use crate::terminal::{KeyCode, KeyEvent, KeyModifiers};
+use crate::widgets::inputs::choice::ChoiceInput;
+use crate::widgets::inputs::text::TextInput;
+use crate::widgets::node::Node;
+use crate::widgets::validators::required;
+
+fn key(code: KeyCode) -> KeyEvent {
+    KeyEvent {
+        code,
+        modifiers: KeyModifiers::NONE,
+    }
+}
+
+#[test]
+fn announces_step_focus_choice_and_error_changes_once() {
+    let setup = Step::builder("setup", "Setup")
+        .node(Node::Input(Box::new(
+            TextInput::new("name", "Name").with_validator(required()),
+        )))
+        .node(Node::Input(Box::new(ChoiceInput::new(
+            "env",
+            "Env",
+            vec!["dev".to_string(), "prod".to_string()],
+        ))))
+        .aria_label("env", "Target environment")
+        .build();
+    let done = Step::builder("done", "Done").build();
+    let mut state = AppState::new(Flow::new(vec![setup, done])).expect("app state");
+    let mut announcer = Announcer::new();
+
+    assert_eq!(
+        announcer.observe(&state),
+        vec!["Step 1 of 2: Setup", "Name, edit text, blank"]
+    );
+    assert!(announcer.observe(&state).is_empty());
+
+    state.focus_next();
+    assert_eq!(announcer.observe(&state), vec!["Target environment, dev"]);
+    state.dispatch_key_to_focused(key(KeyCode::Right));
+    assert_eq!(announcer.observe(&state), vec!["prod"]);
+
+    state.handle_system_event(SystemEvent::RequestSubmit);
+    assert_eq!(
+        announcer.observe(&state),
+        vec![
+            "Name, edit text, blank",
+            "Error: Name: This field is required."
+        ]
+    );
+
+    // Typed text is left to the screen reader's own echo.
+    state.dispatch_key_to_focused(key(KeyCode::Char('a')));
+    assert!(announcer.observe(&state).is_empty());
+}
+
+#[test]
+fn announcements_use_the_active_locale() {
+    let yaml = r#"
+version: 1
+locale: de
+messages:
+  de:
+    announce-step-prompt: "Schritt { $position } von { $total }: { $prompt }"
+    announce-value: "{ $label }, { $value }"
+    announce-not-checked: nicht aktiviert
+    Setup: Einrichtung
+    Notify: Benachrichtigen
+steps:
+  - id: setup
+    title: Setup
+    widgets:
+      - type: checkbox
+        id: notify
+        label: Notify
+"#;
+
+    let state = load_from_yaml_str(yaml)
+        .expect("load config")
+        .into_app_state()
+        .expect("app state");
+    let mut announcer = Announcer::new();
+
+    assert_eq!(
+        announcer.observe(&state),
+        vec![
+            "Schritt 1 von 1: Einrichtung",
+            "Benachrichtigen, nicht aktiviert"
+        ]
+    );
+}
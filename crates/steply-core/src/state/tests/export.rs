@@ -0,0 +1,79 @@
+use super::{ExportFormat, ExportTarget, ResultExport};
+use crate::core::value::Value;
+use indexmap::IndexMap;
+
+fn object(entries: Vec<(&str, Value)>) -> Value {
+    Value::Object(
+        entries
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .collect::<IndexMap<_, _>>(),
+    )
+}
+
+fn sample_values() -> Value {
+    object(vec![
+        (
+            "profile",
+            object(vec![
+                ("name", Value::Text("Ada \"A\"".to_string())),
+                ("age", Value::Number(36.0)),
+                ("nickname", Value::None),
+            ]),
+        ),
+        ("agree", Value::Bool(true)),
+        (
+            "tags",
+            Value::List(vec![
+                Value::Text("a".to_string()),
+                Value::Text("b".to_string()),
+            ]),
+        ),
+        (
+            "servers",
+            Value::List(vec![
+                object(vec![("host", Value::Text("one".to_string()))]),
+                object(vec![("host", Value::Text("two".to_string()))]),
+            ]),
+        ),
+    ])
+}
+
+#[test]
+fn toml_export_writes_scalars_before_tables_and_skips_nulls() {
+    let export = ResultExport {
+        format: ExportFormat::Toml,
+        ..ResultExport::default()
+    };
+
+    let rendered = export.render(&sample_values()).expect("toml");
+
+    assert_eq!(
+        rendered,
+        "agree = true\n\
+         tags = [\"a\", \"b\"]\n\
+         \n\
+         [profile]\n\
+         name = 'Ada \"A\"'\n\
+         age = 36\n\
+         \n\
+         [[servers]]\n\
+         host = \"one\"\n\
+         \n\
+         [[servers]]\n\
+         host = \"two\"\n"
+    );
+}
+
+#[test]
+fn template_export_reads_selectors_from_collected_values() {
+    let export = ResultExport {
+        format: ExportFormat::Json,
+        target: ExportTarget::parse("-"),
+        template: Some("{{ profile.name }} is {{ profile.age }}; tags={{ tags }}".to_string()),
+    };
+
+    let rendered = export.render(&sample_values()).expect("template");
+
+    assert_eq!(rendered, "Ada \"A\" is 36; tags=[\"a\",\"b\"]");
+}
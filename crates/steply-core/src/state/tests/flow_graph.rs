@@ -0,0 +1,83 @@
+use super::GraphFormat;
+use crate::core::value::Value;
+use crate::state::flow::Flow;
+use crate::state::step::{Step, StepCondition};
+
+fn branching_flow() -> Flow {
+    Flow::new(vec![
+        Step::builder("plan", "Pick plan").build(),
+        Step::builder("billing", "Billing")
+            .when(StepCondition::Equals {
+                field: "plan.tier".to_string(),
+                value: Value::Text("pro".to_string()),
+            })
+            .build(),
+        Step::builder("done", "Finish").build(),
+    ])
+}
+
+#[test]
+fn mermaid_graph_draws_conditional_edges_and_skip_paths() {
+    let graph = branching_flow().to_graph(GraphFormat::Mermaid);
+
+    assert_eq!(
+        graph,
+        "flowchart TD\n\
+         \x20   s0[\"Pick plan\"]\n\
+         \x20   s1{\"Billing\"}\n\
+         \x20   s2[\"Finish\"]\n\
+         \x20   __end((\"Done\"))\n\
+         \x20   s0 -->|\"plan.tier == pro\"| s1\n\
+         \x20   s0 --> s2\n\
+         \x20   s1 --> s2\n\
+         \x20   s2 --> __end\n"
+    );
+}
+
+#[test]
+fn dot_graph_quotes_ids_and_labels() {
+    let graph = branching_flow().to_graph(GraphFormat::Dot);
+
+    assert!(graph.starts_with("digraph flow {\n"));
+    assert!(graph.contains("    \"billing\" [label=\"Billing\", shape=diamond];\n"));
+    assert!(graph.contains("    \"plan\" -> \"billing\" [label=\"plan.tier == pro\"];\n"));
+    assert!(graph.contains("    \"plan\" -> \"done\" [label=\"skips Billing\", style=dashed];\n"));
+    assert!(graph.contains("    \"done\" -> \"__end\";\n"));
+    assert!(graph.ends_with("}\n"));
+}
+
+#[test]
+fn dot_graph_labels_edges_that_skip_conditional_steps() {
+    let flow = Flow::new(vec![
+        Step::builder("plan", "Pick plan").build(),
+        Step::builder("billing", "Billing")
+            .when(StepCondition::Equals {
+                field: "plan.tier".to_string(),
+                value: Value::Text("pro".to_string()),
+            })
+            .build(),
+        Step::builder("invoice", "Invoice")
+            .when(StepCondition::Equals {
+                field: "plan.invoice".to_string(),
+                value: Value::Bool(true),
+            })
+            .build(),
+    ]);
+
+    assert_eq!(
+        flow.to_graph(GraphFormat::Dot),
+        "digraph flow {\n\
+         \x20   rankdir=TB;\n\
+         \x20   \"plan\" [label=\"Pick plan\", shape=box];\n\
+         \x20   \"billing\" [label=\"Billing\", shape=diamond];\n\
+         \x20   \"invoice\" [label=\"Invoice\", shape=diamond];\n\
+         \x20   \"__end\" [label=\"Done\", shape=doublecircle];\n\
+         \x20   \"plan\" -> \"billing\" [label=\"plan.tier == pro\"];\n\
+         \x20   \"plan\" -> \"invoice\" [label=\"plan.invoice == true; skips Billing\", style=dashed];\n\
+         \x20   \"plan\" -> \"__end\" [label=\"skips Billing, Invoice\", style=dashed];\n\
+         \x20   \"billing\" -> \"invoice\" [label=\"plan.invoice == true\"];\n\
+         \x20   \"billing\" -> \"__end\" [label=\"skips Invoice\", style=dashed];\n\
+         \x20   \"invoice\" -> \"__end\";\n\
+         }\n"
+    );
+}
@@ -0,0 +1,63 @@
+use super::{FlowReport, ReportEntry, ReportFormat, ReportStep, ReportTask};
+use crate::core::value::Value;
+use crate::task::TaskRunOutcome;
+use crate::time::Duration;
+use indexmap::IndexMap;
+
+fn sample_report() -> FlowReport {
+    FlowReport {
+        steps: vec![ReportStep {
+            id: "db".to_string(),
+            title: "Database".to_string(),
+            description: None,
+            entries: IndexMap::from([
+                (
+                    "host".to_string(),
+                    ReportEntry::new("Host", Value::Text("db|primary".to_string())),
+                ),
+                (
+                    "replicas".to_string(),
+                    ReportEntry::new(
+                        "Replicas",
+                        Value::List(vec![Value::Text("<a>".to_string())]),
+                    ),
+                ),
+            ]),
+        }],
+        tasks: vec![
+            ReportTask {
+                id: "migrate".to_string(),
+                runs: 2,
+                outcome: Some(TaskRunOutcome::Succeeded),
+                duration: Some(Duration::from_millis(1_250)),
+            },
+            ReportTask {
+                id: "seed".to_string(),
+                runs: 1,
+                outcome: Some(TaskRunOutcome::Failed("exit 1".to_string())),
+                duration: Some(Duration::from_millis(40)),
+            },
+        ],
+    }
+}
+
+#[test]
+fn markdown_escapes_cells_and_lists_task_runs() {
+    let rendered = sample_report().render(ReportFormat::Markdown);
+
+    assert!(rendered.contains("## Database"));
+    assert!(rendered.contains("| Host | db\\|primary |"));
+    assert!(rendered.contains("| Replicas | [\"<a>\"] |"));
+    assert!(rendered.contains("| migrate | 2 | succeeded | 1.2 s |"));
+    assert!(rendered.contains("| seed | 1 | failed: exit 1 | 40 ms |"));
+}
+
+#[test]
+fn html_escapes_values() {
+    let rendered = sample_report().render(ReportFormat::Html);
+
+    assert!(rendered.starts_with("<!DOCTYPE html>"));
+    assert!(rendered.contains("<h2>Database</h2>"));
+    assert!(rendered.contains("<td>[&quot;&lt;a&gt;&quot;]</td>"));
+    assert!(rendered.contains("<td>failed: exit 1</td>"));
+}
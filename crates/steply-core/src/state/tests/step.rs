@@ -104,6 +104,7 @@ fn derived_copy_text_input(
                 expr: WriteExpr::ScopeRef("value".to_string()),
             }],
             commit_policy: StoreCommitPolicy::Immediate,
+            transforms: Vec::new(),
         },
     )
 }
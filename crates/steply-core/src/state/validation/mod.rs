@@ -1,5 +1,6 @@
 use crate::core::{
     NodeId,
+    i18n::Message,
     value::Value,
     value_path::{ValuePath, ValueTarget},
 };
@@ -14,7 +15,7 @@ pub enum ErrorVisibility {
 
 #[derive(Debug, Clone)]
 pub struct ValidationEntry {
-    pub error: String,
+    pub error: Message,
     pub visibility: ErrorVisibility,
 }
 
@@ -108,9 +109,9 @@ pub type StepValidator = Box<dyn Fn(&StepContext) -> Option<StepIssue> + Send +
 #[derive(Debug, Default, Clone)]
 pub struct ValidationState {
     entries: HashMap<NodeId, ValidationEntry>,
-    step_errors: Vec<String>,
-    runtime_step_errors: IndexMap<String, String>,
-    combined_step_errors: Vec<String>,
+    step_errors: Vec<Message>,
+    runtime_step_errors: IndexMap<String, Message>,
+    combined_step_errors: Vec<Message>,
     step_warnings: Vec<String>,
     warnings_acknowledged: bool,
 }
@@ -119,7 +120,7 @@ impl ValidationState {
     pub fn set_error(
         &mut self,
         id: impl Into<NodeId>,
-        error: impl Into<String>,
+        error: impl Into<Message>,
         visibility: ErrorVisibility,
     ) {
         self.entries.insert(
@@ -135,7 +136,7 @@ impl ValidationState {
         self.entries.remove(id);
     }
 
-    pub fn set_step_errors(&mut self, errors: Vec<String>) {
+    pub fn set_step_errors(&mut self, errors: Vec<Message>) {
         self.step_errors = errors;
         self.rebuild_step_errors();
     }
@@ -146,11 +147,11 @@ impl ValidationState {
         self.rebuild_step_errors();
     }
 
-    pub fn step_errors(&self) -> &[String] {
+    pub fn step_errors(&self) -> &[Message] {
         self.combined_step_errors.as_slice()
     }
 
-    pub fn set_runtime_step_error(&mut self, key: impl Into<String>, error: impl Into<String>) {
+    pub fn set_runtime_step_error(&mut self, key: impl Into<String>, error: impl Into<Message>) {
         self.runtime_step_errors.insert(key.into(), error.into());
         self.rebuild_step_errors();
     }
@@ -185,9 +186,9 @@ impl ValidationState {
         self.step_warnings.as_slice()
     }
 
-    pub fn visible_error(&self, id: &str) -> Option<&str> {
+    pub fn visible_error(&self, id: &str) -> Option<&Message> {
         self.entries.get(id).and_then(|entry| {
-            matches!(entry.visibility, ErrorVisibility::Inline).then_some(entry.error.as_str())
+            matches!(entry.visibility, ErrorVisibility::Inline).then_some(&entry.error)
         })
     }
 
@@ -203,10 +204,10 @@ impl ValidationState {
         }
     }
 
-    pub fn visible_entries(&self) -> impl Iterator<Item = (&str, &str)> {
+    pub fn visible_entries(&self) -> impl Iterator<Item = (&str, &Message)> {
         self.entries.iter().filter_map(|(id, entry)| {
             matches!(entry.visibility, ErrorVisibility::Inline)
-                .then_some((id.as_str(), entry.error.as_str()))
+                .then_some((id.as_str(), &entry.error))
         })
     }
 
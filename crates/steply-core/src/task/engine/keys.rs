@@ -10,6 +10,10 @@ pub fn interval_key(task_id: &str, index: usize) -> String {
     format!("task:on-interval:{task_id}:{index}")
 }
 
+pub fn retry_key(task_id: &str) -> String {
+    format!("task:retry:{task_id}")
+}
+
 pub fn fingerprint_value(node_id: &str, value: &Value) -> u64 {
     let mut hasher = DefaultHasher::new();
     node_id.hash(&mut hasher);
@@ -1,9 +1,23 @@
 use super::{TaskEngineHost, TaskStartResult};
+use crate::core::value::Value;
 use crate::state::change::{StorePatch, StoreWriteOrigin};
-use crate::task::{ConcurrencyPolicy, TaskCompletion, TaskRequest};
+use crate::task::{
+    ConcurrencyPolicy, FailurePolicy, TaskCompletion, TaskId, TaskRequest, TaskRunOutcome,
+};
 use crate::time::Instant;
 
 pub fn request_task_run(host: &mut impl TaskEngineHost, request: TaskRequest) -> TaskStartResult {
+    request_run(host, request, true)
+}
+
+/// `retry_failed` re-runs dependencies that failed before; requests released
+/// after a run finishes pass `false`, so a failure blocks its dependents
+/// instead of being retried in a loop.
+fn request_run(
+    host: &mut impl TaskEngineHost,
+    request: TaskRequest,
+    retry_failed: bool,
+) -> TaskStartResult {
     let requested_task_id = request.task_id.clone();
 
     let Some(spec) = host.find_task_spec(&request.task_id) else {
@@ -14,6 +28,11 @@ pub fn request_task_run(host: &mut impl TaskEngineHost, request: TaskRequest) ->
         return result;
     };
 
+    // A new request takes the place of a retry still waiting out its backoff.
+    if request.attempt == 1 {
+        host.cancel_task_retry(&spec.id);
+    }
+
     if !spec.enabled {
         let result = TaskStartResult::Disabled {
             task_id: spec.id.clone(),
@@ -22,6 +41,27 @@ pub fn request_task_run(host: &mut impl TaskEngineHost, request: TaskRequest) ->
         return result;
     }
 
+    match dependency_gate(host, &spec.depends_on, retry_failed) {
+        DependencyGate::Ready => {}
+        DependencyGate::Waiting => {
+            host.park_task_request(request);
+            let result = TaskStartResult::Waiting {
+                task_id: spec.id.clone(),
+            };
+            host.emit_task_start_feedback(&result);
+            return result;
+        }
+        DependencyGate::Blocked(dependency) => {
+            host.block_task(&spec.id, &dependency);
+            let result = TaskStartResult::Blocked {
+                task_id: spec.id.clone(),
+                dependency,
+            };
+            host.emit_task_start_feedback(&result);
+            return result;
+        }
+    }
+
     if let Some(interval) = request.interval.as_ref() {
         host.schedule_interval_request(
             spec.id.as_str(),
@@ -33,7 +73,9 @@ pub fn request_task_run(host: &mut impl TaskEngineHost, request: TaskRequest) ->
     }
 
     let now = Instant::now();
-    if !host.should_start_run(&spec.id, spec.rerun_policy, now, request.fingerprint) {
+    if request.attempt == 1
+        && !host.should_start_run(&spec.id, spec.rerun_policy, now, request.fingerprint)
+    {
         let result = TaskStartResult::Skipped {
             task_id: spec.id.clone(),
         };
@@ -65,6 +107,15 @@ pub fn request_task_run(host: &mut impl TaskEngineHost, request: TaskRequest) ->
         ConcurrencyPolicy::Parallel => {}
     }
 
+    if !host.has_task_capacity() {
+        host.park_task_request(request);
+        let result = TaskStartResult::Waiting {
+            task_id: spec.id.clone(),
+        };
+        host.emit_task_start_feedback(&result);
+        return result;
+    }
+
     let stdin_json = match host.build_task_stdin_json(&spec) {
         Ok(stdin_json) => stdin_json,
         Err(reason) => {
@@ -78,28 +129,141 @@ pub fn request_task_run(host: &mut impl TaskEngineHost, request: TaskRequest) ->
     };
     let origin_step_id = host.current_step_id_if_any();
     let task_id = spec.id.clone();
-    let run_id =
-        host.start_task_invocation(spec, stdin_json, request.fingerprint, now, origin_step_id);
+    let run_id = host.start_task_invocation(
+        spec,
+        stdin_json,
+        request.fingerprint,
+        now,
+        origin_step_id,
+        request.attempt,
+    );
 
     let result = TaskStartResult::Started { task_id, run_id };
     host.emit_task_start_feedback(&result);
     result
 }
 
+enum DependencyGate {
+    Ready,
+    Waiting,
+    Blocked(TaskId),
+}
+
+/// Requests the dependencies that have not succeeded yet; the task may start
+/// once all of them have.
+fn dependency_gate(
+    host: &mut impl TaskEngineHost,
+    depends_on: &[TaskId],
+    retry_failed: bool,
+) -> DependencyGate {
+    let mut gate = DependencyGate::Ready;
+    for dependency in depends_on {
+        if host.is_task_running(dependency) || host.is_task_waiting(dependency) {
+            gate = DependencyGate::Waiting;
+            continue;
+        }
+        let failed = match host.last_task_outcome(dependency) {
+            Some(TaskRunOutcome::Succeeded | TaskRunOutcome::Skipped(_)) => continue,
+            Some(_) => true,
+            None => host.is_task_blocked(dependency),
+        };
+        if failed && !retry_failed {
+            return DependencyGate::Blocked(dependency.clone());
+        }
+        match request_run(host, TaskRequest::new(dependency.clone()), retry_failed) {
+            TaskStartResult::Started { .. }
+            | TaskStartResult::Queued { .. }
+            | TaskStartResult::Waiting { .. } => gate = DependencyGate::Waiting,
+            _ => return DependencyGate::Blocked(dependency.clone()),
+        }
+    }
+    gate
+}
+
+/// Re-requests runs held for their dependencies or a free slot, after
+/// something they were waiting on has settled.
+pub fn release_parked_task_requests(host: &mut impl TaskEngineHost) {
+    for request in host.take_parked_task_requests() {
+        let _ = request_run(host, request, false);
+    }
+}
+
+/// Writes progress reported by a running task to its `progress` target.
+/// Returns `false` for runs that already finished.
+pub fn report_task_progress(
+    host: &mut impl TaskEngineHost,
+    task_id: &TaskId,
+    run_id: u64,
+    progress: f64,
+) -> bool {
+    if !host.is_run_active(task_id, run_id) {
+        return false;
+    }
+    if let Some(target) = host.find_task_spec(task_id).and_then(|spec| spec.progress) {
+        host.apply_store_patch(StorePatch::single(
+            target,
+            Value::Number(progress),
+            StoreWriteOrigin::TaskResult {
+                task_id: task_id.clone(),
+            },
+        ));
+    }
+    true
+}
+
 pub fn complete_task_run(host: &mut impl TaskEngineHost, completion: TaskCompletion) -> bool {
     host.remove_running_cancel_token(&completion.task_id, completion.run_id);
 
-    let last_started_run_id =
-        host.on_run_finished(&completion.task_id, completion.run_id, Instant::now());
-
     let stale_restart_completion = completion.concurrency_policy == ConcurrencyPolicy::Restart
-        && last_started_run_id.is_some_and(|run_id| run_id != completion.run_id);
+        && host
+            .last_started_run_id(&completion.task_id)
+            .is_some_and(|run_id| run_id != completion.run_id);
+
+    let mut outcome = TaskRunOutcome::of(&completion);
+    let mut escalate = None;
+    if let TaskRunOutcome::Failed(error) = &outcome
+        && !stale_restart_completion
+        && let Some(spec) = host.find_task_spec(&completion.task_id)
+    {
+        let attempt = host.task_attempt(&completion.task_id);
+        if attempt < spec.retry_policy.max_attempts {
+            host.schedule_task_retry(
+                TaskRequest::new(completion.task_id.clone()).with_attempt(attempt + 1),
+                spec.retry_policy.delay_ms(attempt),
+            );
+        } else {
+            match spec.failure_policy {
+                FailurePolicy::Report => {}
+                FailurePolicy::Skip => outcome = TaskRunOutcome::Skipped(error.clone()),
+                policy @ (FailurePolicy::Abort | FailurePolicy::Prompt) => {
+                    escalate = Some((policy, error.clone()));
+                }
+            }
+        }
+    }
+    crate::trace_event!(
+        DEBUG,
+        task_id = %completion.task_id,
+        run_id = completion.run_id,
+        outcome = ?outcome,
+        "task run finished"
+    );
+    host.on_run_finished(
+        &completion.task_id,
+        completion.run_id,
+        Instant::now(),
+        outcome,
+    );
+    if let Some((policy, error)) = escalate {
+        host.escalate_task_failure(&completion.task_id, policy, error.as_str());
+    }
 
     if completion.concurrency_policy == ConcurrencyPolicy::Queue
         && let Some(request) = host.pop_queued_task_request(&completion.task_id)
     {
         let _ = request_task_run(host, request);
     }
+    release_parked_task_requests(host);
 
     host.refresh_current_step_running_status();
 
@@ -5,27 +5,55 @@ mod triggering;
 use crate::core::value::Value;
 use crate::core::value_path::ValueTarget;
 use crate::state::change::StorePatch;
-use crate::task::{RerunPolicy, TaskId, TaskRequest, TaskSpec, TaskTrigger};
+use crate::task::{
+    FailurePolicy, RerunPolicy, TaskId, TaskRequest, TaskRunOutcome, TaskSpec, TaskTrigger,
+};
 use crate::time::Instant;
 
-pub use keys::{fingerprint_value, interval_key, node_change_debounce_key};
-pub use lifecycle::{complete_task_run, request_task_run};
+pub use keys::{fingerprint_value, interval_key, node_change_debounce_key, retry_key};
+pub use lifecycle::{
+    complete_task_run, release_parked_task_requests, report_task_progress, request_task_run,
+};
 pub use triggering::{
     bootstrap_interval_tasks, cancel_interval_tasks, refresh_active_step_interval_tasks,
-    trigger_flow_end_tasks, trigger_flow_start_tasks, trigger_step_enter_tasks,
-    trigger_step_exit_tasks, trigger_store_value_changed_tasks, trigger_submit_after_tasks,
-    trigger_submit_before_tasks,
+    trigger_flow_end_tasks, trigger_flow_start_tasks, trigger_idle_timeout_tasks,
+    trigger_step_enter_tasks, trigger_step_exit_tasks, trigger_store_value_changed_tasks,
+    trigger_submit_after_tasks, trigger_submit_before_tasks,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TaskStartResult {
-    Started { task_id: TaskId, run_id: u64 },
-    Queued { task_id: TaskId },
-    SpecNotFound { task_id: TaskId },
-    Disabled { task_id: TaskId },
-    Skipped { task_id: TaskId },
-    Dropped { task_id: TaskId },
-    Rejected { task_id: TaskId, reason: String },
+    Started {
+        task_id: TaskId,
+        run_id: u64,
+    },
+    Queued {
+        task_id: TaskId,
+    },
+    /// Held until its dependencies succeed or a run slot frees up.
+    Waiting {
+        task_id: TaskId,
+    },
+    Blocked {
+        task_id: TaskId,
+        dependency: TaskId,
+    },
+    SpecNotFound {
+        task_id: TaskId,
+    },
+    Disabled {
+        task_id: TaskId,
+    },
+    Skipped {
+        task_id: TaskId,
+    },
+    Dropped {
+        task_id: TaskId,
+    },
+    Rejected {
+        task_id: TaskId,
+        reason: String,
+    },
 }
 
 pub trait TaskEngineHost {
@@ -58,6 +86,42 @@ pub trait TaskEngineHost {
 
     fn is_task_running(&self, task_id: &TaskId) -> bool;
 
+    fn is_run_active(&self, task_id: &TaskId, run_id: u64) -> bool;
+
+    fn last_task_outcome(&self, task_id: &TaskId) -> Option<TaskRunOutcome>;
+
+    fn last_started_run_id(&self, task_id: &TaskId) -> Option<u64>;
+
+    /// Retry attempt of the task's last started run, from 1.
+    fn task_attempt(&self, task_id: &TaskId) -> u32;
+
+    /// Requests `request` again once `delay_ms` has passed; the task counts
+    /// as waiting until then.
+    fn schedule_task_retry(&mut self, request: TaskRequest, delay_ms: u64);
+
+    fn cancel_task_retry(&mut self, task_id: &TaskId);
+
+    /// Carries out an `Abort` or `Prompt` failure policy for a task that
+    /// failed its last attempt.
+    fn escalate_task_failure(&mut self, task_id: &TaskId, policy: FailurePolicy, error: &str);
+
+    /// Whether another run may start under the parallel task limit.
+    fn has_task_capacity(&self) -> bool;
+
+    /// Whether the task is parked, waiting out a retry backoff or waiting for
+    /// the user to decide what to do about its failure.
+    fn is_task_waiting(&self, task_id: &TaskId) -> bool;
+
+    /// Holds a request until the next run finishes; a newer request for the
+    /// same task replaces it.
+    fn park_task_request(&mut self, request: TaskRequest);
+
+    fn take_parked_task_requests(&mut self) -> Vec<TaskRequest>;
+
+    fn is_task_blocked(&self, task_id: &TaskId) -> bool;
+
+    fn block_task(&mut self, task_id: &TaskId, dependency: &TaskId);
+
     fn enqueue_task_request(&mut self, task_id: TaskId, request: TaskRequest);
 
     fn cancel_running_task(&mut self, task_id: &TaskId);
@@ -73,13 +137,20 @@ pub trait TaskEngineHost {
         fingerprint: Option<u64>,
         now: Instant,
         origin_step_id: Option<String>,
+        attempt: u32,
     ) -> u64;
 
     fn emit_task_start_feedback(&mut self, result: &TaskStartResult);
 
     fn remove_running_cancel_token(&mut self, task_id: &TaskId, run_id: u64);
 
-    fn on_run_finished(&mut self, task_id: &TaskId, run_id: u64, now: Instant) -> Option<u64>;
+    fn on_run_finished(
+        &mut self,
+        task_id: &TaskId,
+        run_id: u64,
+        now: Instant,
+        outcome: TaskRunOutcome,
+    );
 
     fn pop_queued_task_request(&mut self, task_id: &TaskId) -> Option<TaskRequest>;
 
@@ -85,6 +85,10 @@ pub fn trigger_flow_end_tasks(host: &mut impl TaskEngineHost) {
     trigger_for(host, |t| matches!(t, TaskTrigger::FlowEnd), None);
 }
 
+pub fn trigger_idle_timeout_tasks(host: &mut impl TaskEngineHost) {
+    trigger_for(host, |t| matches!(t, TaskTrigger::IdleTimeout), None);
+}
+
 pub fn trigger_step_enter_tasks(host: &mut impl TaskEngineHost, step_id: &str) {
     trigger_for(
         host,
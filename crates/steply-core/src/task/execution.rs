@@ -11,6 +11,9 @@ pub struct TaskRequest {
     pub task_id: TaskId,
     pub fingerprint: Option<u64>,
     pub interval: Option<TaskIntervalRequest>,
+    /// Which attempt under the task's retry policy this run is, from 1.
+    /// Retries skip the rerun policy.
+    pub attempt: u32,
 }
 
 #[derive(Debug, Clone)]
@@ -26,6 +29,7 @@ impl TaskRequest {
             task_id: task_id.into(),
             fingerprint: None,
             interval: None,
+            attempt: 1,
         }
     }
 
@@ -34,6 +38,11 @@ impl TaskRequest {
         self
     }
 
+    pub fn with_attempt(mut self, attempt: u32) -> Self {
+        self.attempt = attempt.max(1);
+        self
+    }
+
     pub fn with_interval(
         mut self,
         key: impl Into<String>,
@@ -57,6 +66,7 @@ pub struct TaskInvocation {
     pub stdin_json: String,
     pub cancel_token: TaskCancelToken,
     pub log_tx: Option<Sender<String>>,
+    pub progress_tx: Option<Sender<f64>>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -86,12 +96,17 @@ pub struct TaskCompletion {
     pub result: Value,
     pub error: Option<String>,
     pub cancelled: bool,
+    /// Exit code of an exec task's process, when it ran to completion.
+    pub exit_code: Option<i32>,
 }
 
 impl TaskCompletion {
     pub fn scope_value(&self) -> Value {
         let mut map = IndexMap::<String, Value>::new();
         map.insert("result".to_string(), self.result.clone());
+        if let Some(code) = self.exit_code {
+            map.insert("exit_code".to_string(), Value::Number(code as f64));
+        }
         Value::Object(map)
     }
 }
@@ -0,0 +1,128 @@
+use std::collections::HashMap;
+
+use super::{TaskId, TaskSetupError, TaskSpec};
+
+/// Where a task stands in its dependency graph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskStatus {
+    /// Not run yet, or waiting for its dependencies or a free slot.
+    Pending,
+    Running,
+    /// Failed, waiting out the backoff before its next attempt.
+    Retrying,
+    Done,
+    Failed,
+    /// Failed, but its failure policy let the flow go on.
+    Skipped,
+    /// Will not run because a dependency did not succeed.
+    Blocked,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskGraphEntry {
+    pub id: TaskId,
+    pub depends_on: Vec<TaskId>,
+    pub status: TaskStatus,
+    /// Retry attempt of the last started run, from 1.
+    pub attempt: u32,
+    pub max_attempts: u32,
+}
+
+/// Tasks with their dependencies and live status, dependencies first.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TaskGraph {
+    entries: Vec<TaskGraphEntry>,
+}
+
+impl TaskGraph {
+    pub fn new(entries: Vec<TaskGraphEntry>) -> Self {
+        Self { entries }
+    }
+
+    pub fn entries(&self) -> &[TaskGraphEntry] {
+        self.entries.as_slice()
+    }
+
+    pub fn get(&self, task_id: &str) -> Option<&TaskGraphEntry> {
+        self.entries
+            .iter()
+            .find(|entry| entry.id.as_str() == task_id)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Orders `specs` so every task comes after its dependencies, keeping the
+/// given order otherwise. Fails on unknown dependencies and cycles.
+pub fn dependency_order(specs: &[TaskSpec]) -> Result<Vec<&TaskSpec>, TaskSetupError> {
+    let by_id = specs
+        .iter()
+        .map(|spec| (spec.id.as_str(), spec))
+        .collect::<HashMap<_, _>>();
+    for spec in specs {
+        if let Some(dependency) = spec
+            .depends_on
+            .iter()
+            .find(|dependency| !by_id.contains_key(dependency.as_str()))
+        {
+            return Err(TaskSetupError::UnknownDependency {
+                task_id: spec.id.to_string(),
+                dependency: dependency.to_string(),
+            });
+        }
+    }
+
+    let mut marks = HashMap::<&str, Mark>::new();
+    let mut ordered = Vec::with_capacity(specs.len());
+    let mut path = Vec::new();
+    for spec in specs {
+        visit(spec, &by_id, &mut marks, &mut path, &mut ordered)?;
+    }
+    Ok(ordered)
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mark {
+    Visiting,
+    Done,
+}
+
+fn visit<'a>(
+    spec: &'a TaskSpec,
+    by_id: &HashMap<&str, &'a TaskSpec>,
+    marks: &mut HashMap<&'a str, Mark>,
+    path: &mut Vec<&'a str>,
+    ordered: &mut Vec<&'a TaskSpec>,
+) -> Result<(), TaskSetupError> {
+    match marks.get(spec.id.as_str()) {
+        Some(Mark::Done) => return Ok(()),
+        Some(Mark::Visiting) => {
+            let start = path
+                .iter()
+                .position(|id| *id == spec.id.as_str())
+                .unwrap_or(0);
+            let mut task_ids = path[start..]
+                .iter()
+                .map(|id| id.to_string())
+                .collect::<Vec<_>>();
+            task_ids.push(spec.id.to_string());
+            return Err(TaskSetupError::DependencyCycle { task_ids });
+        }
+        None => {}
+    }
+    marks.insert(spec.id.as_str(), Mark::Visiting);
+    path.push(spec.id.as_str());
+    for dependency in &spec.depends_on {
+        visit(by_id[dependency.as_str()], by_id, marks, path, ordered)?;
+    }
+    path.pop();
+    marks.insert(spec.id.as_str(), Mark::Done);
+    ordered.push(spec);
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "tests/graph.rs"]
+mod tests;
@@ -12,6 +12,8 @@ use super::TaskSpec;
 pub enum TaskSetupError {
     DuplicateInlineTaskId { task_id: String },
     InlineTaskIdConflict { task_id: String },
+    UnknownDependency { task_id: String, dependency: String },
+    DependencyCycle { task_ids: Vec<String> },
 }
 
 impl fmt::Display for TaskSetupError {
@@ -26,6 +28,15 @@ impl fmt::Display for TaskSetupError {
                     "inline task id '{task_id}' conflicts with an explicit task id"
                 )
             }
+            Self::UnknownDependency {
+                task_id,
+                dependency,
+            } => {
+                write!(f, "task '{task_id}' depends on unknown task '{dependency}'")
+            }
+            Self::DependencyCycle { task_ids } => {
+                write!(f, "task dependency cycle: {}", task_ids.join(" -> "))
+            }
         }
     }
 }
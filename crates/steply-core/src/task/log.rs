@@ -0,0 +1,49 @@
+use std::collections::VecDeque;
+
+/// Output lines of a task's latest run, oldest first, kept so a failure can
+/// be looked into after the fact.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TaskLogBuffer {
+    run_id: u64,
+    lines: VecDeque<String>,
+    dropped: usize,
+}
+
+impl TaskLogBuffer {
+    pub fn new(run_id: u64) -> Self {
+        Self {
+            run_id,
+            ..Self::default()
+        }
+    }
+
+    pub fn run_id(&self) -> u64 {
+        self.run_id
+    }
+
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        self.lines.iter().map(String::as_str)
+    }
+
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    /// Lines dropped from the front to stay within the limit.
+    pub fn dropped(&self) -> usize {
+        self.dropped
+    }
+
+    /// Appends `line`, dropping the oldest ones beyond `limit`.
+    pub fn push(&mut self, line: String, limit: usize) {
+        self.lines.push_back(line);
+        while self.lines.len() > limit.max(1) {
+            self.lines.pop_front();
+            self.dropped += 1;
+        }
+    }
+}
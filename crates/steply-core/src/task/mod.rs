@@ -1,15 +1,23 @@
 pub mod engine;
 pub mod execution;
+pub mod graph;
 mod inline;
+pub mod log;
+pub mod native;
 pub mod policy;
 pub mod run_state;
 pub mod spec;
 
 pub use engine::TaskStartResult;
 pub use execution::{TaskCancelToken, TaskCompletion, TaskInvocation, TaskRequest};
-pub use policy::{ConcurrencyPolicy, RerunPolicy};
-pub use run_state::TaskRunState;
+pub use graph::{TaskGraph, TaskGraphEntry, TaskStatus};
+pub use log::TaskLogBuffer;
+pub use native::{NativeTask, TaskContext};
+pub use policy::{Backoff, ConcurrencyPolicy, FailurePolicy, RerunPolicy, RetryPolicy};
+pub use run_state::{TaskRunOutcome, TaskRunState};
 pub use spec::{TaskId, TaskKind, TaskSpec, TaskTrigger};
 
 pub use inline::TaskSetupError;
-pub(crate) use inline::{collect_inline_tasks_from_flow, validate_task_id_collisions};
+pub(crate) use inline::{
+    collect_inline_tasks_from_flow, collect_inline_tasks_from_steps, validate_task_id_collisions,
+};
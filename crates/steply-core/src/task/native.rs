@@ -0,0 +1,95 @@
+use crate::core::value::Value;
+use crate::task::execution::TaskCancelToken;
+use std::fmt;
+use std::sync::Arc;
+use std::sync::mpsc::Sender;
+
+pub type NativeTaskFn = dyn Fn(&TaskContext) -> Result<Value, String> + Send + Sync;
+
+/// A task implemented in Rust and run on the task executor's worker threads.
+#[derive(Clone)]
+pub struct NativeTask(Arc<NativeTaskFn>);
+
+impl NativeTask {
+    pub fn new(
+        run: impl Fn(&TaskContext) -> Result<Value, String> + Send + Sync + 'static,
+    ) -> Self {
+        Self(Arc::new(run))
+    }
+
+    pub fn run(&self, ctx: &TaskContext) -> Result<Value, String> {
+        (self.0)(ctx)
+    }
+}
+
+impl fmt::Debug for NativeTask {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("NativeTask(..)")
+    }
+}
+
+/// Handle a running task uses to read its input and report back.
+///
+/// Cancellation is cooperative: long-running work should poll
+/// [`TaskContext::is_cancelled`] and return early.
+pub struct TaskContext {
+    input: Value,
+    cancel_token: TaskCancelToken,
+    log_tx: Option<Sender<String>>,
+    progress_tx: Option<Sender<f64>>,
+}
+
+impl TaskContext {
+    pub fn new(
+        input: Value,
+        cancel_token: TaskCancelToken,
+        log_tx: Option<Sender<String>>,
+        progress_tx: Option<Sender<f64>>,
+    ) -> Self {
+        Self {
+            input,
+            cancel_token,
+            log_tx,
+            progress_tx,
+        }
+    }
+
+    /// The value resolved from the task's `reads` binding.
+    pub fn input(&self) -> &Value {
+        &self.input
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel_token.is_cancelled()
+    }
+
+    pub fn log(&self, line: impl Into<String>) {
+        if let Some(tx) = &self.log_tx {
+            let _ = tx.send(line.into());
+        }
+    }
+
+    /// Reports progress in the unit of the bound progress widget (0-100 by default).
+    pub fn progress(&self, value: f64) {
+        if let Some(tx) = &self.progress_tx
+            && value.is_finite()
+        {
+            let _ = tx.send(value);
+        }
+    }
+}
+
+/// Parses a `::progress <value>` line emitted on stderr by exec tasks.
+pub fn parse_progress_line(line: &str) -> Option<f64> {
+    let value = line.trim().strip_prefix("::progress")?;
+    if !value.starts_with(char::is_whitespace) {
+        return None;
+    }
+    let value = value.trim();
+    let value = value.strip_suffix('%').unwrap_or(value);
+    value.parse::<f64>().ok().filter(|value| value.is_finite())
+}
+
+#[cfg(test)]
+#[path = "tests/native.rs"]
+mod tests;
@@ -17,3 +17,69 @@ pub enum ConcurrencyPolicy {
     Queue,
     Parallel,
 }
+
+/// How often a failed run is tried again before the failure counts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Runs in total, the first one included; `1` never retries.
+    pub max_attempts: u32,
+    pub backoff: Backoff,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 1,
+            backoff: Backoff::Fixed { ms: 0 },
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn new(max_attempts: u32, backoff: Backoff) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            backoff,
+        }
+    }
+
+    /// The wait before the run that follows failed attempt `attempt`.
+    pub fn delay_ms(&self, attempt: u32) -> u64 {
+        match self.backoff {
+            Backoff::Fixed { ms } => ms,
+            Backoff::Exponential { initial_ms, max_ms } => {
+                let factor = 1u64
+                    .checked_shl(attempt.saturating_sub(1))
+                    .unwrap_or(u64::MAX);
+                initial_ms.saturating_mul(factor).min(max_ms)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backoff {
+    Fixed {
+        ms: u64,
+    },
+    /// Doubles after every failed attempt, up to `max_ms`.
+    Exponential {
+        initial_ms: u64,
+        max_ms: u64,
+    },
+}
+
+/// What happens once a task has failed its last attempt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FailurePolicy {
+    /// The failure is reported and tasks depending on it are blocked.
+    #[default]
+    Report,
+    /// The flow is cancelled.
+    Abort,
+    /// The failure is ignored: dependents and submit hooks go on as if the
+    /// task had succeeded, without its writes.
+    Skip,
+    /// The user chooses between retrying, skipping and aborting.
+    Prompt,
+}
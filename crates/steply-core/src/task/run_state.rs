@@ -1,6 +1,27 @@
+use crate::task::execution::TaskCompletion;
 use crate::task::policy::RerunPolicy;
 use crate::time::{Duration, Instant};
 
+/// How a task run ended.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TaskRunOutcome {
+    Succeeded,
+    Failed(String),
+    /// Failed, but the task's failure policy let the flow go on.
+    Skipped(String),
+    Cancelled,
+}
+
+impl TaskRunOutcome {
+    pub fn of(completion: &TaskCompletion) -> Self {
+        match (&completion.error, completion.cancelled) {
+            (_, true) => Self::Cancelled,
+            (Some(error), false) => Self::Failed(error.clone()),
+            (None, false) => Self::Succeeded,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct TaskRunState {
     running: usize,
@@ -9,6 +30,10 @@ pub struct TaskRunState {
     last_started_at: Option<Instant>,
     last_finished_at: Option<Instant>,
     last_fingerprint: Option<u64>,
+    last_outcome: Option<TaskRunOutcome>,
+    last_duration: Option<Duration>,
+    finished_runs: u64,
+    attempt: u32,
     sequence: u64,
 }
 
@@ -37,6 +62,24 @@ impl TaskRunState {
         self.last_finished_run_id
     }
 
+    pub fn last_outcome(&self) -> Option<&TaskRunOutcome> {
+        self.last_outcome.as_ref()
+    }
+
+    /// Time between the last finished run's start and its end.
+    pub fn last_duration(&self) -> Option<Duration> {
+        self.last_duration
+    }
+
+    pub fn finished_runs(&self) -> u64 {
+        self.finished_runs
+    }
+
+    /// Retry attempt of the last started run, from 1.
+    pub fn attempt(&self) -> u32 {
+        self.attempt.max(1)
+    }
+
     pub fn next_run_id(&mut self) -> u64 {
         self.sequence = self.sequence.saturating_add(1);
         self.sequence
@@ -66,8 +109,15 @@ impl TaskRunState {
         }
     }
 
-    pub fn on_started(&mut self, run_id: u64, now: Instant, fingerprint: Option<u64>) {
+    pub fn on_started(
+        &mut self,
+        run_id: u64,
+        now: Instant,
+        fingerprint: Option<u64>,
+        attempt: u32,
+    ) {
         self.running = self.running.saturating_add(1);
+        self.attempt = attempt;
         self.last_started_run_id = Some(run_id);
         self.last_started_at = Some(now);
         if let Some(fingerprint) = fingerprint {
@@ -75,9 +125,21 @@ impl TaskRunState {
         }
     }
 
-    pub fn on_finished(&mut self, run_id: u64, now: Instant) {
+    /// Lets the last failed run count as skipped instead.
+    pub fn skip_last_failure(&mut self) {
+        if let Some(TaskRunOutcome::Failed(error)) = &self.last_outcome {
+            self.last_outcome = Some(TaskRunOutcome::Skipped(error.clone()));
+        }
+    }
+
+    pub fn on_finished(&mut self, run_id: u64, now: Instant, outcome: TaskRunOutcome) {
         self.running = self.running.saturating_sub(1);
         self.last_finished_run_id = Some(run_id);
         self.last_finished_at = Some(now);
+        self.last_duration = self
+            .last_started_at
+            .map(|started| now.saturating_duration_since(started));
+        self.last_outcome = Some(outcome);
+        self.finished_runs = self.finished_runs.saturating_add(1);
     }
 }
@@ -1,5 +1,7 @@
+use crate::core::value::Value;
 use crate::core::value_path::ValueTarget;
-use crate::task::policy::{ConcurrencyPolicy, RerunPolicy};
+use crate::task::native::{NativeTask, TaskContext};
+use crate::task::policy::{ConcurrencyPolicy, FailurePolicy, RerunPolicy, RetryPolicy};
 use crate::widgets::shared::binding::ReadBinding;
 use crate::widgets::shared::binding::WriteBinding;
 use std::borrow::Borrow;
@@ -65,7 +67,22 @@ pub enum TaskKind {
         args: Vec<String>,
         reads: Option<ReadBinding>,
         timeout_ms: u64,
+        /// Forwards stdout lines to the task log as they arrive; the result
+        /// is stdout parsed as JSON, or the raw text when it is not JSON.
+        stream_stdout: bool,
     },
+    Native {
+        run: NativeTask,
+        reads: Option<ReadBinding>,
+    },
+}
+
+impl TaskKind {
+    pub fn reads(&self) -> Option<&ReadBinding> {
+        match self {
+            Self::Exec { reads, .. } | Self::Native { reads, .. } => reads.as_ref(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -92,6 +109,7 @@ pub enum TaskTrigger {
         every_ms: u64,
         only_when_step_active: bool,
     },
+    IdleTimeout,
 }
 
 #[derive(Debug, Clone)]
@@ -100,8 +118,15 @@ pub struct TaskSpec {
     pub kind: TaskKind,
     pub rerun_policy: RerunPolicy,
     pub concurrency_policy: ConcurrencyPolicy,
+    pub retry_policy: RetryPolicy,
+    pub failure_policy: FailurePolicy,
     pub triggers: Vec<TaskTrigger>,
     pub writes: Vec<WriteBinding>,
+    /// Store target that receives progress reported while the task runs.
+    pub progress: Option<ValueTarget>,
+    /// Tasks that must have succeeded before this one starts; requesting it
+    /// runs the ones that have not yet.
+    pub depends_on: Vec<TaskId>,
     pub enabled: bool,
 }
 
@@ -114,30 +139,64 @@ impl TaskSpec {
                 args,
                 reads: None,
                 timeout_ms: 2_000,
+                stream_stdout: false,
             },
             rerun_policy: RerunPolicy::default(),
             concurrency_policy: ConcurrencyPolicy::default(),
+            retry_policy: RetryPolicy::default(),
+            failure_policy: FailurePolicy::default(),
             triggers: Vec::new(),
             writes: Vec::new(),
+            progress: None,
+            depends_on: Vec::new(),
             enabled: true,
         }
     }
 
+    pub fn native(
+        id: impl Into<TaskId>,
+        run: impl Fn(&TaskContext) -> Result<Value, String> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            kind: TaskKind::Native {
+                run: NativeTask::new(run),
+                reads: None,
+            },
+            ..Self::exec(id, String::new(), Vec::new())
+        }
+    }
+
+    /// Only applies to exec tasks; native tasks are expected to honour cancellation.
     pub fn with_timeout_ms(mut self, timeout_ms: u64) -> Self {
-        let TaskKind::Exec {
+        if let TaskKind::Exec {
             timeout_ms: current,
             ..
-        } = &mut self.kind;
-        *current = timeout_ms.max(1);
+        } = &mut self.kind
+        {
+            *current = timeout_ms.max(1);
+        }
+        self
+    }
+
+    pub fn with_streamed_stdout(mut self) -> Self {
+        if let TaskKind::Exec { stream_stdout, .. } = &mut self.kind {
+            *stream_stdout = true;
+        }
         self
     }
 
     pub fn with_reads(mut self, reads: ReadBinding) -> Self {
-        let TaskKind::Exec { reads: current, .. } = &mut self.kind;
+        let (TaskKind::Exec { reads: current, .. } | TaskKind::Native { reads: current, .. }) =
+            &mut self.kind;
         *current = Some(reads);
         self
     }
 
+    pub fn with_progress(mut self, target: ValueTarget) -> Self {
+        self.progress = Some(target);
+        self
+    }
+
     pub fn with_rerun_policy(mut self, rerun_policy: RerunPolicy) -> Self {
         self.rerun_policy = rerun_policy;
         self
@@ -148,6 +207,16 @@ impl TaskSpec {
         self
     }
 
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    pub fn with_failure_policy(mut self, failure_policy: FailurePolicy) -> Self {
+        self.failure_policy = failure_policy;
+        self
+    }
+
     pub fn with_trigger(mut self, trigger: TaskTrigger) -> Self {
         self.triggers.push(trigger);
         self
@@ -163,6 +232,14 @@ impl TaskSpec {
         self
     }
 
+    pub fn with_depends_on(
+        mut self,
+        depends_on: impl IntoIterator<Item = impl Into<TaskId>>,
+    ) -> Self {
+        self.depends_on = depends_on.into_iter().map(Into::into).collect();
+        self
+    }
+
     pub fn with_enabled(mut self, enabled: bool) -> Self {
         self.enabled = enabled;
         self
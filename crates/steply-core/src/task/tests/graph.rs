@@ -0,0 +1,45 @@
+use super::dependency_order;
+use crate::task::{TaskSetupError, TaskSpec};
+
+fn task(id: &str, depends_on: &[&str]) -> TaskSpec {
+    TaskSpec::exec(id, "true", Vec::new()).with_depends_on(depends_on.iter().copied())
+}
+
+#[test]
+fn orders_dependencies_before_dependents() {
+    let specs = vec![
+        task("deploy", &["build", "test"]),
+        task("test", &["build"]),
+        task("build", &[]),
+    ];
+
+    let order = dependency_order(specs.as_slice())
+        .expect("acyclic")
+        .into_iter()
+        .map(|spec| spec.id.as_str())
+        .collect::<Vec<_>>();
+
+    assert_eq!(order, vec!["build", "test", "deploy"]);
+}
+
+#[test]
+fn rejects_unknown_dependencies_and_cycles() {
+    assert_eq!(
+        dependency_order(&[task("deploy", &["build"])]).err(),
+        Some(TaskSetupError::UnknownDependency {
+            task_id: "deploy".to_string(),
+            dependency: "build".to_string(),
+        })
+    );
+    assert_eq!(
+        dependency_order(&[task("a", &["b"]), task("b", &["c"]), task("c", &["a"])]).err(),
+        Some(TaskSetupError::DependencyCycle {
+            task_ids: vec![
+                "a".to_string(),
+                "b".to_string(),
+                "c".to_string(),
+                "a".to_string()
+            ],
+        })
+    );
+}
@@ -0,0 +1,10 @@
+use super::parse_progress_line;
+
+#[test]
+fn parses_progress_lines_with_optional_percent_sign() {
+    assert_eq!(parse_progress_line("::progress 42"), Some(42.0));
+    assert_eq!(parse_progress_line("  ::progress 12.5%\n"), Some(12.5));
+    assert_eq!(parse_progress_line("::progress"), None);
+    assert_eq!(parse_progress_line("::progressive 1"), None);
+    assert_eq!(parse_progress_line("progress 1"), None);
+}
@@ -0,0 +1,130 @@
+/// How many colors the terminal can show. Ordered from least to most.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum ColorDepth {
+    /// No colors at all, e.g. under `NO_COLOR`; contrast is kept with
+    /// attributes instead.
+    Monochrome,
+    Ansi16,
+    Ansi256,
+    #[default]
+    TrueColor,
+}
+
+/// What the attached terminal is known to support. The default describes a
+/// modern terminal, which is what rendering assumed before detection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TerminalCapabilities {
+    pub color_depth: ColorDepth,
+    /// The font and locale can show box drawing, arrows and other symbols.
+    pub unicode: bool,
+    /// East Asian ambiguous-width symbols (`●`, `›`, `…`) take two cells, so
+    /// layouts that count them as one would break.
+    pub ambiguous_wide: bool,
+    pub kitty_graphics: bool,
+    /// Frames can be wrapped in begin/end synchronized update (mode 2026).
+    pub synchronized_output: bool,
+    /// Curly, dotted and dashed underlines and underline colors (`4:3`,
+    /// `58`) are understood rather than misread as other attributes.
+    pub styled_underlines: bool,
+    /// Taskbar or tab progress can be reported with `OSC 9;4`.
+    pub progress_reporting: bool,
+}
+
+impl Default for TerminalCapabilities {
+    fn default() -> Self {
+        Self {
+            color_depth: ColorDepth::TrueColor,
+            unicode: true,
+            ambiguous_wide: false,
+            kitty_graphics: false,
+            synchronized_output: true,
+            styled_underlines: true,
+            progress_reporting: false,
+        }
+    }
+}
+
+const TRUECOLOR_PROGRAMS: &[&str] = &["iTerm.app", "WezTerm", "vscode", "ghostty", "Hyper"];
+const SYNC_PROGRAMS: &[&str] = &["iTerm.app", "WezTerm", "vscode", "ghostty"];
+const SYNC_TERMS: &[&str] = &["kitty", "foot", "alacritty", "contour"];
+const PROGRESS_PROGRAMS: &[&str] = &["iTerm.app", "WezTerm", "ghostty"];
+const BASIC_TERMS: &[&str] = &["dumb", "linux", "ansi", "cons25", "vt100", "vt220"];
+
+impl TerminalCapabilities {
+    /// Guesses capabilities from environment variables such as `TERM`,
+    /// `COLORTERM`, `TERM_PROGRAM` and the locale. `var` looks one up.
+    ///
+    /// `NO_COLOR` and `CLICOLOR=0` turn colors off unless
+    /// `CLICOLOR_FORCE` asks for them.
+    pub fn detect(var: impl Fn(&str) -> Option<String>) -> Self {
+        let var = |name: &str| var(name).filter(|value| !value.is_empty());
+        let term = var("TERM").unwrap_or_default();
+        let program = var("TERM_PROGRAM").unwrap_or_default();
+        let windows_terminal = var("WT_SESSION").is_some();
+        let kitty = var("KITTY_WINDOW_ID").is_some() || term.contains("kitty");
+
+        let color_forced = var("CLICOLOR_FORCE").is_some_and(|value| value != "0");
+        let color_disabled =
+            var("NO_COLOR").is_some() || var("CLICOLOR").is_some_and(|value| value == "0");
+
+        let color_depth = if color_disabled && !color_forced {
+            ColorDepth::Monochrome
+        } else if var("COLORTERM").is_some_and(|value| value == "truecolor" || value == "24bit")
+            || kitty
+            || windows_terminal
+            || TRUECOLOR_PROGRAMS.contains(&program.as_str())
+        {
+            ColorDepth::TrueColor
+        } else if term.contains("256color") {
+            ColorDepth::Ansi256
+        } else if BASIC_TERMS.contains(&term.as_str()) {
+            ColorDepth::Ansi16
+        } else if term.is_empty() {
+            // No TERM at all is the Windows console, which takes RGB.
+            ColorDepth::TrueColor
+        } else {
+            ColorDepth::Ansi256
+        };
+
+        let locale = ["LC_ALL", "LC_CTYPE", "LANG"].into_iter().find_map(var);
+        let unicode = term != "linux"
+            && term != "dumb"
+            && locale.as_deref().is_none_or(|locale| {
+                let upper = locale.to_ascii_uppercase();
+                upper.contains("UTF-8") || upper.contains("UTF8")
+            });
+        let ambiguous_wide = locale.as_deref().is_some_and(|locale| {
+            ["zh", "ja", "ko"]
+                .iter()
+                .any(|lang| locale.starts_with(lang))
+        });
+
+        // Terminals that took up synchronized updates also parse styled
+        // underlines.
+        let modern = kitty
+            || windows_terminal
+            || SYNC_PROGRAMS.contains(&program.as_str())
+            || SYNC_TERMS.iter().any(|name| term.contains(name));
+
+        Self {
+            color_depth,
+            unicode,
+            ambiguous_wide,
+            kitty_graphics: kitty || program == "WezTerm" || program == "ghostty",
+            synchronized_output: modern,
+            styled_underlines: modern,
+            progress_reporting: windows_terminal
+                || var("ConEmuANSI").is_some_and(|value| value == "ON")
+                || PROGRESS_PROGRAMS.contains(&program.as_str()),
+        }
+    }
+
+    /// Whether symbols should be drawn with their ASCII fallbacks.
+    pub fn ascii_glyphs(&self) -> bool {
+        !self.unicode || self.ambiguous_wide
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/capabilities.rs"]
+mod tests;
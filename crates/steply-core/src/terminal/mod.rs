@@ -1,3 +1,5 @@
+mod capabilities;
 mod types;
 
+pub use capabilities::*;
 pub use types::*;
@@ -0,0 +1,102 @@
+use super::{ColorDepth, TerminalCapabilities};
+use crate::ui::glyphs::use_ascii_glyphs;
+use crate::ui::span::Span;
+use crate::ui::style::{Color, Contrast};
+
+fn detect(vars: &[(&str, &str)]) -> TerminalCapabilities {
+    TerminalCapabilities::detect(|name| {
+        vars.iter()
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| value.to_string())
+    })
+}
+
+#[test]
+fn detects_color_depth_from_term_variables() {
+    let truecolor = detect(&[("TERM", "xterm-256color"), ("COLORTERM", "truecolor")]);
+    assert_eq!(truecolor.color_depth, ColorDepth::TrueColor);
+
+    let indexed = detect(&[("TERM", "xterm-256color")]);
+    assert_eq!(indexed.color_depth, ColorDepth::Ansi256);
+
+    let console = detect(&[("TERM", "linux"), ("LANG", "en_US.UTF-8")]);
+    assert_eq!(console.color_depth, ColorDepth::Ansi16);
+    assert!(!console.unicode);
+
+    let kitty = detect(&[("TERM", "xterm-kitty")]);
+    assert!(kitty.kitty_graphics && kitty.synchronized_output);
+    assert!(!kitty.progress_reporting);
+
+    let windows = detect(&[("WT_SESSION", "0f6c")]);
+    assert!(windows.progress_reporting);
+}
+
+#[test]
+fn no_color_conventions_turn_colors_off() {
+    let term = ("TERM", "xterm-256color");
+    assert_eq!(
+        detect(&[term, ("NO_COLOR", "1")]).color_depth,
+        ColorDepth::Monochrome
+    );
+    assert_eq!(
+        detect(&[term, ("CLICOLOR", "0")]).color_depth,
+        ColorDepth::Monochrome
+    );
+    assert_eq!(
+        detect(&[term, ("NO_COLOR", "")]).color_depth,
+        ColorDepth::Ansi256
+    );
+    assert_eq!(
+        detect(&[term, ("NO_COLOR", "1"), ("CLICOLOR_FORCE", "1")]).color_depth,
+        ColorDepth::Ansi256
+    );
+}
+
+#[test]
+fn contrast_separates_accents_from_dim_text() {
+    assert_eq!(Color::Cyan.contrast(), Contrast::Strong);
+    assert_eq!(Color::Rgb(255, 135, 0).contrast(), Contrast::Strong);
+    assert_eq!(Color::DarkGrey.contrast(), Contrast::Dim);
+    assert_eq!(Color::Indexed(244).contrast(), Contrast::Dim);
+    assert_eq!(Color::Rgb(230, 230, 230).contrast(), Contrast::Normal);
+    assert_eq!(Color::White.contrast(), Contrast::Normal);
+}
+
+#[test]
+fn locale_decides_between_unicode_and_ascii_glyphs() {
+    assert!(!detect(&[("TERM", "xterm"), ("LANG", "en_US.UTF-8")]).ascii_glyphs());
+    assert!(detect(&[("TERM", "xterm"), ("LC_ALL", "C")]).ascii_glyphs());
+    assert!(detect(&[("TERM", "xterm"), ("LANG", "ja_JP.UTF-8")]).ascii_glyphs());
+}
+
+#[test]
+fn colors_fall_back_to_what_the_terminal_can_show() {
+    let orange = Color::Rgb(255, 135, 0);
+    assert_eq!(orange.for_depth(ColorDepth::TrueColor), orange);
+    assert_eq!(orange.for_depth(ColorDepth::Ansi256), Color::Indexed(208));
+    assert_eq!(
+        Color::Rgb(10, 10, 240).for_depth(ColorDepth::Ansi16),
+        Color::Blue
+    );
+    assert_eq!(
+        Color::Indexed(46).for_depth(ColorDepth::Ansi16),
+        Color::Green
+    );
+    assert_eq!(Color::Cyan.for_depth(ColorDepth::Ansi16), Color::Cyan);
+}
+
+#[test]
+fn ascii_glyphs_keep_every_symbol_one_cell_wide() {
+    let mut lines = vec![vec![
+        Span::new("❯ ● Yes"),
+        Span::new("○ No ✓"),
+        Span::new("café"),
+        Span::new("◇ ◆ ▰▱ ▁▄▇"),
+    ]];
+    use_ascii_glyphs(&mut lines);
+    let texts = lines[0]
+        .iter()
+        .map(|span| span.text.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(texts, ["> * Yes", "o No v", "café", "o * #. _-="]);
+}
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum KeyCode {
     Unknown,
     Char(char),
@@ -20,7 +20,7 @@ pub enum KeyCode {
     PageDown,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct KeyModifiers(u8);
 
 impl KeyModifiers {
@@ -38,37 +38,57 @@ impl KeyModifiers {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct KeyEvent {
     pub code: KeyCode,
     pub modifiers: KeyModifiers,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TerminalEvent {
     Key(KeyEvent),
+    /// Text delivered through bracketed paste, in one piece.
+    Paste(String),
+    /// The terminal window gained or lost focus.
+    FocusGained,
+    FocusLost,
     Resize(TerminalSize),
     Scroll(i32),
     Pointer(PointerEvent),
     Tick,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum PointerButton {
     Left,
     Right,
     Middle,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum PointerKind {
     Move,
     Down(PointerButton),
     Up(PointerButton),
     Drag(PointerButton),
+    /// One notch of the mouse wheel.
+    ScrollUp,
+    ScrollDown,
+}
+
+impl PointerKind {
+    /// Items a wheel notch moves a scrollable widget by; `None` for
+    /// anything that is not the wheel.
+    pub fn wheel_delta(self) -> Option<isize> {
+        match self {
+            Self::ScrollUp => Some(-1),
+            Self::ScrollDown => Some(1),
+            _ => None,
+        }
+    }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum PointerSemantic {
     #[default]
     None,
@@ -77,7 +97,7 @@ pub enum PointerSemantic {
     Custom(u16),
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PointerEvent {
     pub kind: PointerKind,
     pub col: u16,
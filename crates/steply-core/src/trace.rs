@@ -0,0 +1,52 @@
+//! Diagnostics through [`tracing`](https://docs.rs/tracing) when the
+//! `tracing` feature is on; without it the macros expand to nothing, so
+//! instrumented code costs nothing in builds that do not ask for it.
+//!
+//! Events and spans keep the module path of the code that emits them as
+//! their target (`steply_core::runtime::reducer`, `steply_runtime::runner`,
+//! ...), so subscribers can filter them like any other crate's.
+
+#[cfg(feature = "tracing")]
+#[doc(hidden)]
+pub use tracing as __tracing;
+
+/// Emits a `tracing` event at the given level (`TRACE`, `DEBUG`, `INFO`,
+/// `WARN` or `ERROR`), taking the same fields and message as
+/// `tracing::event!`.
+#[cfg(feature = "tracing")]
+#[macro_export]
+macro_rules! trace_event {
+    ($level:ident, $($args:tt)+) => {
+        $crate::trace::__tracing::event!($crate::trace::__tracing::Level::$level, $($args)+)
+    };
+}
+
+#[cfg(not(feature = "tracing"))]
+#[macro_export]
+macro_rules! trace_event {
+    ($level:ident, $($args:tt)+) => {};
+}
+
+/// Enters a `tracing` span at the given level until the returned guard is
+/// dropped. Fields are written as for `tracing::span!`.
+#[cfg(feature = "tracing")]
+#[macro_export]
+macro_rules! trace_span {
+    ($level:ident, $($args:tt)+) => {
+        $crate::trace::__tracing::span!($crate::trace::__tracing::Level::$level, $($args)+)
+            .entered()
+    };
+}
+
+#[cfg(not(feature = "tracing"))]
+#[macro_export]
+macro_rules! trace_span {
+    ($level:ident, $($args:tt)+) => {
+        $crate::trace::NoSpan
+    };
+}
+
+/// Stands in for an entered span when tracing is compiled out.
+#[cfg(not(feature = "tracing"))]
+#[doc(hidden)]
+pub struct NoSpan;
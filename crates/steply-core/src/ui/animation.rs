@@ -0,0 +1,128 @@
+use std::collections::HashMap;
+
+use crate::time::{Duration, Instant};
+
+/// Shape of a transition over its normalized time `0.0..=1.0`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Easing {
+    Linear,
+    OutQuad,
+    #[default]
+    OutCubic,
+    InOutCubic,
+}
+
+impl Easing {
+    pub fn apply(self, t: f64) -> f64 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Self::Linear => t,
+            Self::OutQuad => 1.0 - (1.0 - t) * (1.0 - t),
+            Self::OutCubic => 1.0 - (1.0 - t).powi(3),
+            Self::InOutCubic if t < 0.5 => 4.0 * t * t * t,
+            Self::InOutCubic => 1.0 - (-2.0 * t + 2.0).powi(3) / 2.0,
+        }
+    }
+}
+
+/// A value moving from `from` to `to`. Tweens are sampled, not stepped:
+/// whatever the tick rate, a sample lands where the curve is at that
+/// instant, so slow ticks drop frames instead of slowing the motion.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tween {
+    pub from: f64,
+    pub to: f64,
+    pub started_at: Instant,
+    pub duration: Duration,
+    pub easing: Easing,
+}
+
+impl Tween {
+    pub fn new(from: f64, to: f64, duration: Duration, easing: Easing, now: Instant) -> Self {
+        Self {
+            from,
+            to,
+            started_at: now,
+            duration,
+            easing,
+        }
+    }
+
+    pub fn progress(&self, now: Instant) -> f64 {
+        let duration = self.duration.as_secs_f64();
+        if duration <= 0.0 {
+            return 1.0;
+        }
+        let elapsed = now.saturating_duration_since(self.started_at).as_secs_f64();
+        (elapsed / duration).clamp(0.0, 1.0)
+    }
+
+    pub fn value_at(&self, now: Instant) -> f64 {
+        let t = self.progress(now);
+        if t >= 1.0 {
+            return self.to;
+        }
+        self.from + (self.to - self.from) * self.easing.apply(t)
+    }
+
+    pub fn is_done(&self, now: Instant) -> bool {
+        self.progress(now) >= 1.0
+    }
+}
+
+/// Running tweens of one widget, keyed by whatever the widget animates
+/// (a row, a node id, `"scroll"`). Widgets sample them while drawing, ask
+/// for ticks while [`Self::is_running`], and drop finished ones in
+/// `on_tick`; the runtime's tick interval caps the frame rate.
+#[derive(Debug, Clone, Default)]
+pub struct Animations {
+    running: HashMap<String, Tween>,
+}
+
+impl Animations {
+    /// Starts `key` moving towards `to`. A key already in flight continues
+    /// from where it is now rather than jumping back to `from`.
+    pub fn start(
+        &mut self,
+        key: impl Into<String>,
+        from: f64,
+        to: f64,
+        duration: Duration,
+        easing: Easing,
+        now: Instant,
+    ) {
+        let key = key.into();
+        let from = self
+            .running
+            .get(key.as_str())
+            .map_or(from, |tween| tween.value_at(now));
+        self.running
+            .insert(key, Tween::new(from, to, duration, easing, now));
+    }
+
+    pub fn value(&self, key: &str, now: Instant) -> Option<f64> {
+        self.running.get(key).map(|tween| tween.value_at(now))
+    }
+
+    pub fn cancel(&mut self, key: &str) {
+        self.running.remove(key);
+    }
+
+    pub fn is_running(&self) -> bool {
+        !self.running.is_empty()
+    }
+
+    /// Drops finished tweens. Returns whether anything was running, i.e.
+    /// whether the widget needs redrawing.
+    pub fn tick(&mut self, now: Instant) -> bool {
+        if self.running.is_empty() {
+            return false;
+        }
+        self.running.retain(|_, tween| !tween.is_done(now));
+        true
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/animation.rs"]
+mod tests;
@@ -0,0 +1,36 @@
+/// Where the form sits in the terminal: at most `max_width` columns wide,
+/// `padding` columns in from the left edge, optionally centered in what is
+/// left. The renderer lays the frame out at the canvas width and moves it
+/// into place afterwards, so widgets never see the full terminal width.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Canvas {
+    pub max_width: Option<u16>,
+    pub align: CanvasAlign,
+    pub padding: u16,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CanvasAlign {
+    #[default]
+    Left,
+    Center,
+}
+
+impl Canvas {
+    /// Left offset and width of the canvas in a terminal `width` columns
+    /// wide. The canvas keeps at least one column however narrow it gets.
+    pub fn place(&self, width: u16) -> (u16, u16) {
+        let padding = self.padding.min(width.saturating_sub(1));
+        let room = width - padding;
+        let canvas = self.max_width.map_or(room, |max| max.clamp(1, room.max(1)));
+        let offset = match self.align {
+            CanvasAlign::Left => padding,
+            CanvasAlign::Center => padding + (room - canvas) / 2,
+        };
+        (offset, canvas)
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/canvas.rs"]
+mod tests;
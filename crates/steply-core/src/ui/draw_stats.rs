@@ -0,0 +1,51 @@
+use std::cell::RefCell;
+use std::time::{Duration, Instant};
+
+/// What the renderer drew while collection was on. Only top-level widgets
+/// are counted; a container's time includes its children.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DrawStats {
+    pub widgets_drawn: usize,
+    pub slowest: Option<(String, Duration)>,
+    /// Draw time of each widget, in the order they were drawn.
+    pub widgets: Vec<(String, Duration)>,
+}
+
+thread_local! {
+    static COLLECTING: RefCell<Option<DrawStats>> = const { RefCell::new(None) };
+}
+
+/// Starts counting widget draws on this thread, discarding earlier counts.
+pub fn begin_collecting() {
+    COLLECTING.with(|stats| *stats.borrow_mut() = Some(DrawStats::default()));
+}
+
+/// Stops counting and returns what was drawn since [`begin_collecting`].
+pub fn finish_collecting() -> Option<DrawStats> {
+    COLLECTING.with(|stats| stats.borrow_mut().take())
+}
+
+/// Returns a start time while collection is on, so renders without the
+/// metrics overlay never read the clock.
+pub(crate) fn draw_started() -> Option<Instant> {
+    COLLECTING.with(|stats| stats.borrow().is_some().then(Instant::now))
+}
+
+pub(crate) fn record_draw(id: &str, started: Instant) {
+    let elapsed = started.elapsed();
+    COLLECTING.with(|stats| {
+        let mut stats = stats.borrow_mut();
+        let Some(stats) = stats.as_mut() else {
+            return;
+        };
+        stats.widgets_drawn += 1;
+        if stats
+            .slowest
+            .as_ref()
+            .is_none_or(|(_, slowest)| elapsed > *slowest)
+        {
+            stats.slowest = Some((id.to_string(), elapsed));
+        }
+        stats.widgets.push((id.to_string(), elapsed));
+    });
+}
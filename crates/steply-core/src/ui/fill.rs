@@ -0,0 +1,34 @@
+use crate::ui::layout::Layout;
+use crate::ui::span::SpanLine;
+use crate::ui::style::Color;
+
+/// Background of the active row in lists, tables, trees and diffs.
+pub const SELECTION_BACKGROUND: Color = Color::Rgb(45, 45, 65);
+
+/// Pads `line` out to `width` and paints `background` behind every span
+/// that has no background of its own, so the color runs edge to edge
+/// instead of stopping where the text does. Overflowing spans are cut.
+pub fn fill_background(line: SpanLine, width: u16, background: Color) -> SpanLine {
+    let mut filled = Layout::clip_line(Layout::fit_line(line.as_slice(), width), width);
+    for span in &mut filled {
+        span.style.background.get_or_insert(background);
+    }
+    filled
+}
+
+/// Paints the selection bar behind one row of an already composed list.
+pub fn selection_bar(line: SpanLine, width: u16) -> SpanLine {
+    fill_background(line, width, SELECTION_BACKGROUND)
+}
+
+/// Paints the selection bar behind every row of an item that wrapped onto
+/// several lines.
+pub fn paint_selection(lines: &mut [SpanLine], width: u16) {
+    for line in lines {
+        *line = selection_bar(std::mem::take(line), width);
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/fill.rs"]
+mod tests;
@@ -1,7 +1,7 @@
 use crate::terminal::TerminalSize;
 use crate::ui::renderer::RenderFrame;
 use crate::ui::span::SpanLine;
-use crate::ui::style::{Color, Strike};
+use crate::ui::style::{Color, Strike, Style, Underline};
 use crate::widgets::traits::DrawOutput;
 use crate::widgets::traits::StickyPosition;
 
@@ -26,16 +26,7 @@ pub fn frame_to_json(frame: &RenderFrame, size: TerminalSize) -> serde_json::Val
                                 crate::ui::span::WrapMode::NoWrap => "no_wrap",
                                 crate::ui::span::WrapMode::Wrap => "wrap",
                             },
-                            "style": {
-                                "color": span.style.color.map(color_to_json),
-                                "background": span.style.background.map(color_to_json),
-                                "bold": span.style.bold,
-                                "strike": match span.style.strike {
-                                    Strike::Inherit => "inherit",
-                                    Strike::On => "on",
-                                    Strike::Off => "off",
-                                },
-                            }
+                            "style": style_to_json(span.style)
                         })
                     })
                     .collect(),
@@ -60,16 +51,7 @@ pub fn frame_to_json(frame: &RenderFrame, size: TerminalSize) -> serde_json::Val
                                         crate::ui::span::WrapMode::NoWrap => "no_wrap",
                                         crate::ui::span::WrapMode::Wrap => "wrap",
                                     },
-                                    "style": {
-                                        "color": span.style.color.map(color_to_json),
-                                        "background": span.style.background.map(color_to_json),
-                                        "bold": span.style.bold,
-                                        "strike": match span.style.strike {
-                                            Strike::Inherit => "inherit",
-                                            Strike::On => "on",
-                                            Strike::Off => "off",
-                                        },
-                                    }
+                                    "style": style_to_json(span.style)
                                 })
                             })
                             .collect(),
@@ -138,16 +120,7 @@ fn lines_to_json(lines: &[SpanLine]) -> Vec<serde_json::Value> {
                                 crate::ui::span::WrapMode::NoWrap => "no_wrap",
                                 crate::ui::span::WrapMode::Wrap => "wrap",
                             },
-                            "style": {
-                                "color": span.style.color.map(color_to_json),
-                                "background": span.style.background.map(color_to_json),
-                                "bold": span.style.bold,
-                                "strike": match span.style.strike {
-                                    Strike::Inherit => "inherit",
-                                    Strike::On => "on",
-                                    Strike::Off => "off",
-                                },
-                            }
+                            "style": style_to_json(span.style)
                         })
                     })
                     .collect(),
@@ -172,6 +145,31 @@ fn sticky_to_json(sticky: &[crate::widgets::traits::StickyBlock]) -> Vec<serde_j
         .collect::<Vec<_>>()
 }
 
+fn style_to_json(style: Style) -> serde_json::Value {
+    serde_json::json!({
+        "color": style.color.map(color_to_json),
+        "background": style.background.map(color_to_json),
+        "bold": style.bold,
+        "dim": style.dim,
+        "italic": style.italic,
+        "reverse": style.reverse,
+        "underline": match style.underline {
+            Underline::None => "none",
+            Underline::Straight => "straight",
+            Underline::Double => "double",
+            Underline::Curly => "curly",
+            Underline::Dotted => "dotted",
+            Underline::Dashed => "dashed",
+        },
+        "underline_color": style.underline_color.map(color_to_json),
+        "strike": match style.strike {
+            Strike::Inherit => "inherit",
+            Strike::On => "on",
+            Strike::Off => "off",
+        },
+    })
+}
+
 fn color_to_json(color: Color) -> serde_json::Value {
     match color {
         Color::Reset => serde_json::json!("reset"),
@@ -184,6 +182,7 @@ fn color_to_json(color: Color) -> serde_json::Value {
         Color::Magenta => serde_json::json!("magenta"),
         Color::Cyan => serde_json::json!("cyan"),
         Color::White => serde_json::json!("white"),
+        Color::Indexed(index) => serde_json::json!({ "indexed": index }),
         Color::Rgb(r, g, b) => serde_json::json!({
             "rgb": [r, g, b]
         }),
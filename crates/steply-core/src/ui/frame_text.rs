@@ -0,0 +1,200 @@
+use crate::terminal::TerminalSize;
+use crate::ui::layout::Layout;
+use crate::ui::renderer::RenderFrame;
+use crate::ui::span::SpanLine;
+use crate::ui::style::{Color, Strike, Style, Underline};
+use crate::ui::text::{clip_to_display_width_without_linebreaks, text_display_width};
+use crate::widgets::traits::{DrawOutput, StickyBlock, StickyPosition};
+use std::fmt::Write as _;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SnapshotFormat {
+    /// Text only, trailing whitespace trimmed from every line.
+    #[default]
+    Text,
+    /// Text with SGR escape sequences for colors, bold and strikethrough.
+    Ansi,
+}
+
+impl SnapshotFormat {
+    pub fn parse(raw: &str) -> Option<Self> {
+        match raw.trim().to_ascii_lowercase().as_str() {
+            "text" | "plain" | "txt" => Some(Self::Text),
+            "ansi" => Some(Self::Ansi),
+            _ => None,
+        }
+    }
+}
+
+/// Renders a frame as one line of text per row, clipped to `size.width`.
+///
+/// Every line of the frame is included, not just the rows that would fit on
+/// screen, so the output depends only on the state and the width.
+/// Top sticky blocks come first and bottom sticky blocks last, as on screen.
+pub fn frame_to_text(frame: &RenderFrame, size: TerminalSize, format: SnapshotFormat) -> String {
+    lines_with_sticky_to_text(
+        frame.lines.as_slice(),
+        frame.sticky.as_slice(),
+        size,
+        format,
+    )
+}
+
+pub fn draw_output_to_text(
+    output: &DrawOutput,
+    size: TerminalSize,
+    format: SnapshotFormat,
+) -> String {
+    lines_with_sticky_to_text(
+        output.lines.as_slice(),
+        output.sticky.as_slice(),
+        size,
+        format,
+    )
+}
+
+fn lines_with_sticky_to_text(
+    lines: &[SpanLine],
+    sticky: &[StickyBlock],
+    size: TerminalSize,
+    format: SnapshotFormat,
+) -> String {
+    let sticky_lines = |position: StickyPosition| {
+        let mut blocks = sticky
+            .iter()
+            .filter(|block| block.position == position)
+            .collect::<Vec<_>>();
+        blocks.sort_by_key(|block| block.priority);
+        blocks
+            .into_iter()
+            .flat_map(|block| block.lines.iter().cloned())
+            .collect::<Vec<_>>()
+    };
+
+    let mut rows = Layout::compose(sticky_lines(StickyPosition::Top).as_slice(), size.width);
+    rows.extend(Layout::compose(lines, size.width));
+    rows.extend(Layout::compose(
+        sticky_lines(StickyPosition::Bottom).as_slice(),
+        size.width,
+    ));
+
+    let mut out = String::new();
+    for row in &rows {
+        write_line(&mut out, row, size.width as usize, format);
+        out.push('\n');
+    }
+    out
+}
+
+fn write_line(out: &mut String, line: &SpanLine, width: usize, format: SnapshotFormat) {
+    let start = out.len();
+    let mut used = 0usize;
+    for span in line {
+        if used >= width {
+            break;
+        }
+        let clipped = clip_to_display_width_without_linebreaks(&span.text, width - used);
+        if clipped.is_empty() {
+            continue;
+        }
+        used = used.saturating_add(text_display_width(clipped.as_str()));
+        match format {
+            SnapshotFormat::Text => out.push_str(clipped.as_str()),
+            SnapshotFormat::Ansi => {
+                let sgr = style_sgr(span.style);
+                if sgr.is_empty() {
+                    out.push_str(clipped.as_str());
+                } else {
+                    let _ = write!(out, "\x1b[{sgr}m{clipped}\x1b[0m");
+                }
+            }
+        }
+    }
+    if format == SnapshotFormat::Text {
+        out.truncate(start + out[start..].trim_end().len());
+    }
+}
+
+fn style_sgr(style: Style) -> String {
+    let mut codes = Vec::<String>::new();
+    if let Some(color) = style.color {
+        codes.push(color_sgr(color, false));
+    }
+    if let Some(background) = style.background {
+        codes.push(color_sgr(background, true));
+    }
+    if style.bold {
+        codes.push("1".to_string());
+    } else if style.dim {
+        codes.push("2".to_string());
+    }
+    if style.italic {
+        codes.push("3".to_string());
+    }
+    if style.reverse {
+        codes.push("7".to_string());
+    }
+    let underline = match style.underline {
+        Underline::None => None,
+        Underline::Straight => Some("4"),
+        Underline::Double => Some("4:2"),
+        Underline::Curly => Some("4:3"),
+        Underline::Dotted => Some("4:4"),
+        Underline::Dashed => Some("4:5"),
+    };
+    if let Some(underline) = underline {
+        codes.push(underline.to_string());
+        if let Some(color) = style.underline_color {
+            codes.push(underline_color_sgr(color));
+        }
+    }
+    if style.strike == Strike::On {
+        codes.push("9".to_string());
+    }
+    codes.join(";")
+}
+
+/// Underline colors have no short codes, so basic colors use their index.
+fn underline_color_sgr(color: Color) -> String {
+    let index = match color {
+        Color::Reset => return "59".to_string(),
+        Color::Black => 0,
+        Color::DarkGrey => 8,
+        Color::Red => 9,
+        Color::Green => 10,
+        Color::Yellow => 3,
+        Color::Blue => 4,
+        Color::Magenta => 5,
+        Color::Cyan => 6,
+        Color::White => 15,
+        Color::Indexed(index) => index,
+        Color::Rgb(r, g, b) => return format!("58;2;{r};{g};{b}"),
+    };
+    format!("58;5;{index}")
+}
+
+/// Matches the codes the terminal writer emits for each color.
+fn color_sgr(color: Color, background: bool) -> String {
+    let base = match color {
+        Color::Reset => 39,
+        Color::Black => 30,
+        Color::DarkGrey => 90,
+        Color::Red => 91,
+        Color::Green => 92,
+        Color::Yellow => 33,
+        Color::Blue => 34,
+        Color::Magenta => 35,
+        Color::Cyan => 36,
+        Color::White => 97,
+        Color::Indexed(index) => {
+            let kind = if background { 48 } else { 38 };
+            return format!("{kind};5;{index}");
+        }
+        Color::Rgb(r, g, b) => {
+            let kind = if background { 48 } else { 38 };
+            return format!("{kind};2;{r};{g};{b}");
+        }
+    };
+    let offset = if background { 10 } else { 0 };
+    (base + offset).to_string()
+}
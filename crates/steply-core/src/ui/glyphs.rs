@@ -0,0 +1,54 @@
+use crate::ui::span::SpanLine;
+
+const SPINNER: [char; 4] = ['|', '/', '-', '\\'];
+
+/// Single-cell ASCII stand-in for a symbol widgets draw, so swapping glyphs
+/// never changes a layout. Text that is not a known symbol is kept.
+pub fn ascii_fallback(ch: char) -> Option<char> {
+    let replacement = match ch {
+        '❯' | '›' | '▸' | '▶' | '→' | '»' => '>',
+        '‹' | '◂' | '◀' | '←' | '«' => '<',
+        '▼' | '▾' | '↓' => 'v',
+        '▲' | '▴' | '↑' => '^',
+        '↔' => '-',
+        '↕' => '|',
+        '↗' => '/',
+        '⟳' => '@',
+        '●' | '◉' | '■' | '•' | '◆' | '◈' => '*',
+        '○' | '◯' | '□' | '◌' | '◇' => 'o',
+        '✓' | '✔' => 'v',
+        '✗' | '✘' | '×' => 'x',
+        '⚠' => '!',
+        '│' | '┃' | '║' | '┆' => '|',
+        '─' | '━' | '═' | '—' | '┄' => '-',
+        '┌' | '┐' | '└' | '┘' | '├' | '┤' | '┬' | '┴' | '┼' | '╭' | '╮' | '╰' | '╯' => {
+            '+'
+        }
+        '…' | '·' | '░' => '.',
+        '█' | '▓' | '▒' | '▰' | '▬' => '#',
+        '▱' => '.',
+        '▁' | '▂' => '_',
+        '▃' | '▄' | '▅' => '-',
+        '▆' | '▇' => '=',
+        // Spinner frames keep animating with the classic line spinner.
+        '◜' | '◠' | '◝' | '◞' | '◡' | '◟' | '\u{2800}'..='\u{28ff}' => {
+            SPINNER[ch as usize % SPINNER.len()]
+        }
+        _ => return None,
+    };
+    Some(replacement)
+}
+
+/// Replaces every known symbol in `lines` with its ASCII fallback.
+pub fn use_ascii_glyphs(lines: &mut [SpanLine]) {
+    for span in lines.iter_mut().flatten() {
+        if span.text.is_ascii() {
+            continue;
+        }
+        span.text = span
+            .text
+            .chars()
+            .map(|ch| ascii_fallback(ch).unwrap_or(ch))
+            .collect();
+    }
+}
@@ -17,6 +17,16 @@ pub struct HitRegion {
     pub local_semantic: PointerSemantic,
 }
 
+/// Rectangle covering every row a node was drawn on; `bottom` and `right`
+/// are exclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HitBounds {
+    pub top: u16,
+    pub bottom: u16,
+    pub left: u16,
+    pub right: u16,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 struct RowRange {
     row: u16,
@@ -166,6 +176,12 @@ impl FrameHitMap {
         }
     }
 
+    /// Drops everything at or below `rows`.
+    pub fn truncate_rows(&mut self, rows: u16) {
+        self.regions.retain(|region| region.row < rows);
+        self.selection_ranges.retain(|range| range.row < rows);
+    }
+
     pub fn insert_rows(&mut self, at: u16, count: u16) {
         if count == 0 {
             return;
@@ -244,6 +260,29 @@ impl FrameHitMap {
             .map(|region| (region.row, region.col_start))
     }
 
+    pub fn node_bounds(&self, node_id: &str) -> Option<HitBounds> {
+        self.regions
+            .iter()
+            .filter(|region| region.node_id == node_id)
+            .fold(None, |bounds: Option<HitBounds>, region| {
+                let row_bounds = HitBounds {
+                    top: region.row,
+                    bottom: region.row.saturating_add(1),
+                    left: region.col_start,
+                    right: region.col_end_exclusive,
+                };
+                Some(match bounds {
+                    None => row_bounds,
+                    Some(bounds) => HitBounds {
+                        top: bounds.top.min(row_bounds.top),
+                        bottom: bounds.bottom.max(row_bounds.bottom),
+                        left: bounds.left.min(row_bounds.left),
+                        right: bounds.right.max(row_bounds.right),
+                    },
+                })
+            })
+    }
+
     pub fn first_region(&self) -> Option<(u16, u16)> {
         self.regions
             .iter()
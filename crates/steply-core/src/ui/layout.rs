@@ -1,5 +1,11 @@
 use crate::ui::span::{Span, SpanLine, WrapMode};
-use crate::ui::text::{split_prefix_at_display_width, text_display_width};
+use crate::ui::text::{
+    clip_to_display_width_without_linebreaks, split_prefix_at_display_width, text_display_width,
+};
+
+mod flex;
+
+pub use flex::{Constraint, Direction, LayoutNode, Rect, split_extent};
 
 pub struct Layout;
 
@@ -43,6 +49,8 @@ impl Layout {
                 source_row,
                 source_col: &mut source_col,
                 max_width,
+                indent: leading_indent(line.as_slice()).min(max_width / 2),
+                continued: false,
                 out: &mut out,
                 current: &mut current,
                 current_width: &mut current_width,
@@ -156,6 +164,26 @@ impl Layout {
         }
         fitted
     }
+
+    /// Cuts spans that refused to wrap at `width`, for content that has a
+    /// border or another column right after it.
+    pub fn clip_line(line: SpanLine, width: u16) -> SpanLine {
+        let width = width as usize;
+        let mut used = 0usize;
+        let mut out = Vec::with_capacity(line.len());
+        for mut span in line {
+            if used >= width {
+                break;
+            }
+            if used + text_display_width(span.text.as_str()) > width {
+                span.text =
+                    clip_to_display_width_without_linebreaks(span.text.as_str(), width - used);
+            }
+            used += text_display_width(span.text.as_str());
+            out.push(span);
+        }
+        out
+    }
 }
 
 fn normalize_prefixes(first: &[Span], next: &[Span]) -> (SpanLine, SpanLine, usize) {
@@ -257,6 +285,11 @@ struct ComposeState<'a> {
     source_row: usize,
     source_col: &'a mut usize,
     max_width: usize,
+    /// Leading blank columns of the source line, repeated on each
+    /// continuation row.
+    indent: usize,
+    /// The current row is a continuation that has no content yet.
+    continued: bool,
     out: &'a mut Vec<SpanLine>,
     current: &'a mut SpanLine,
     current_width: &'a mut usize,
@@ -264,21 +297,66 @@ struct ComposeState<'a> {
     mapped_cursor: &'a mut Option<(usize, usize)>,
 }
 
+/// Starts a continuation row, indented like the source line.
+fn wrap_line(state: &mut ComposeState<'_>) {
+    push_line(state.out, state.current, state.current_width);
+    if state.indent > 0 {
+        state
+            .current
+            .push(Span::new(" ".repeat(state.indent)).no_wrap());
+        *state.current_width = state.indent;
+    }
+    state.continued = true;
+}
+
+/// Wraps at the last space that fits. Words longer than a whole row are
+/// split between characters, and spaces a wrap lands on are dropped rather
+/// than starting the next row.
 fn compose_wrap_span(span: Span, state: &mut ComposeState<'_>) {
     let mut rest = span.text.as_str();
     while !rest.is_empty() {
-        if *state.current_width >= state.max_width {
-            push_line(state.out, state.current, state.current_width);
+        if state.continued {
+            let trimmed = rest.trim_start_matches(' ');
+            let skipped = rest.len() - trimmed.len();
+            if skipped > 0 {
+                map_cursor_in_segment(
+                    state.cursor_target,
+                    state.source_row,
+                    *state.source_col,
+                    0,
+                    state.out.len(),
+                    *state.current_width,
+                    state.mapped_cursor,
+                );
+                *state.source_col = state.source_col.saturating_add(skipped);
+                rest = trimmed;
+                continue;
+            }
         }
 
         let remaining = state.max_width.saturating_sub(*state.current_width);
         if remaining == 0 {
-            push_line(state.out, state.current, state.current_width);
+            wrap_line(state);
             continue;
         }
 
-        let (left, tail) = split_prefix_at_display_width(rest, remaining);
+        let (mut left, mut tail) = split_prefix_at_display_width(rest, remaining);
+        let row_has_content = *state.current_width > state.indent && !state.continued;
+        if !tail.is_empty() && !tail.starts_with(' ') {
+            match left.rfind(' ') {
+                Some(space) => (left, tail) = rest.split_at(space + 1),
+                None if row_has_content && ends_at_word_boundary(state.current) => {
+                    wrap_line(state);
+                    continue;
+                }
+                None => {}
+            }
+        }
         let piece_width = text_display_width(left);
+        if piece_width > remaining && *state.current_width > state.indent {
+            wrap_line(state);
+            continue;
+        }
 
         map_cursor_in_segment(
             state.cursor_target,
@@ -295,12 +373,33 @@ fn compose_wrap_span(span: Span, state: &mut ComposeState<'_>) {
         *state.current_width = state.current_width.saturating_add(piece_width);
         state.current.push(piece);
         *state.source_col = state.source_col.saturating_add(piece_width);
+        state.continued = false;
 
         rest = tail;
         if !rest.is_empty() {
-            push_line(state.out, state.current, state.current_width);
+            wrap_line(state);
+        }
+    }
+}
+
+fn ends_at_word_boundary(line: &[Span]) -> bool {
+    line.iter()
+        .rev()
+        .find_map(|span| span.text.chars().next_back())
+        .is_none_or(char::is_whitespace)
+}
+
+/// Width of the spaces a line starts with, across spans.
+fn leading_indent(line: &[Span]) -> usize {
+    let mut indent = 0usize;
+    for span in line {
+        let trimmed = span.text.trim_start_matches(' ');
+        indent += span.text.len() - trimmed.len();
+        if !trimmed.is_empty() {
+            break;
         }
     }
+    indent
 }
 
 fn compose_nowrap_run(run: SpanLine, state: &mut ComposeState<'_>) {
@@ -308,13 +407,13 @@ fn compose_nowrap_run(run: SpanLine, state: &mut ComposeState<'_>) {
         return;
     }
     if *state.current_width >= state.max_width {
-        push_line(state.out, state.current, state.current_width);
+        wrap_line(state);
     }
 
     let run_width = spans_width(run.as_slice());
     let remaining = state.max_width.saturating_sub(*state.current_width);
-    if run_width > remaining && *state.current_width > 0 {
-        push_line(state.out, state.current, state.current_width);
+    if run_width > remaining && *state.current_width > state.indent {
+        wrap_line(state);
     }
 
     // No-wrap runs are atomic: when they do not fit, move the whole run to the next line.
@@ -331,4 +430,9 @@ fn compose_nowrap_run(run: SpanLine, state: &mut ComposeState<'_>) {
     *state.current_width = state.current_width.saturating_add(run_width);
     *state.source_col = state.source_col.saturating_add(run_width);
     state.current.extend(run);
+    state.continued = false;
 }
+
+#[cfg(test)]
+#[path = "layout/tests/wrap.rs"]
+mod tests;
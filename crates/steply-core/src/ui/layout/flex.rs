@@ -0,0 +1,222 @@
+use crate::ui::panel::Panel;
+
+/// How much of its parent a pane takes along the split direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Constraint {
+    /// Exactly this many cells.
+    Length(u16),
+    /// A share of the parent, `0..=100`.
+    Percentage(u16),
+    /// At least this many cells, growing into space nobody else wants.
+    Min(u16),
+    /// At most this many cells.
+    Max(u16),
+    /// A weighted share of the space left after the other constraints.
+    Fill(u16),
+}
+
+impl Default for Constraint {
+    fn default() -> Self {
+        Self::Fill(1)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Direction {
+    /// Side by side, splitting the width.
+    #[default]
+    Row,
+    /// Stacked, splitting the height.
+    Column,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Rect {
+    pub x: u16,
+    pub y: u16,
+    pub width: u16,
+    pub height: u16,
+}
+
+impl Rect {
+    pub fn new(x: u16, y: u16, width: u16, height: u16) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Cuts the rect into one rect per constraint along `direction`, with
+    /// `gap` cells between neighbours.
+    pub fn split(self, direction: Direction, constraints: &[Constraint], gap: u16) -> Vec<Rect> {
+        match direction {
+            Direction::Row => split_extent(self.width, constraints, gap)
+                .into_iter()
+                .map(|(offset, width)| Rect::new(self.x + offset, self.y, width, self.height))
+                .collect(),
+            Direction::Column => split_extent(self.height, constraints, gap)
+                .into_iter()
+                .map(|(offset, height)| Rect::new(self.x, self.y + offset, self.width, height))
+                .collect(),
+        }
+    }
+}
+
+/// Offset and size of each pane along an extent. Fixed sizes are handed out
+/// first and shrunk evenly when they overflow; what is left goes to `Fill`
+/// panes by weight, or to `Min` and `Max` panes when there are none.
+pub fn split_extent(extent: u16, constraints: &[Constraint], gap: u16) -> Vec<(u16, u16)> {
+    if constraints.is_empty() {
+        return Vec::new();
+    }
+    let gaps = gap.saturating_mul(constraints.len() as u16 - 1);
+    let available = extent.saturating_sub(gaps) as u32;
+
+    let mut sizes = constraints
+        .iter()
+        .map(|constraint| match *constraint {
+            Constraint::Length(cells) | Constraint::Min(cells) => cells as u32,
+            Constraint::Percentage(percent) => available * percent.min(100) as u32 / 100,
+            Constraint::Max(_) | Constraint::Fill(_) => 0,
+        })
+        .collect::<Vec<_>>();
+    let fixed = sizes.iter().sum::<u32>();
+    if fixed > available {
+        let mut scaled = sizes
+            .iter()
+            .map(|size| size * available / fixed)
+            .collect::<Vec<_>>();
+        let mut left = available - scaled.iter().sum::<u32>();
+        for (size, original) in scaled.iter_mut().zip(&sizes) {
+            if left == 0 {
+                break;
+            }
+            if *size < *original {
+                *size += 1;
+                left -= 1;
+            }
+        }
+        sizes = scaled;
+    }
+
+    let has_fill = constraints
+        .iter()
+        .any(|constraint| matches!(constraint, Constraint::Fill(_)));
+    let weight = |index: usize, size: u32| match constraints[index] {
+        Constraint::Fill(weight) => weight.max(1) as u32,
+        Constraint::Min(_) if !has_fill => 1,
+        Constraint::Max(cap) if !has_fill && size < cap as u32 => 1,
+        _ => 0,
+    };
+    let mut left = available.saturating_sub(sizes.iter().sum());
+    while left > 0 {
+        let total = (0..sizes.len())
+            .map(|index| weight(index, sizes[index]))
+            .sum::<u32>();
+        if total == 0 {
+            break;
+        }
+        let mut handed_out = 0;
+        for index in 0..sizes.len() {
+            let share = weight(index, sizes[index]);
+            if share == 0 {
+                continue;
+            }
+            let mut grow = (left * share / total).max(1).min(left - handed_out);
+            if let Constraint::Max(cap) = constraints[index] {
+                grow = grow.min((cap as u32).saturating_sub(sizes[index]));
+            }
+            sizes[index] += grow;
+            handed_out += grow;
+            if handed_out == left {
+                break;
+            }
+        }
+        if handed_out == 0 {
+            break;
+        }
+        left -= handed_out;
+    }
+
+    let mut offset = 0u16;
+    sizes
+        .into_iter()
+        .map(|size| {
+            let size = size as u16;
+            let placed = (offset, size);
+            offset = offset.saturating_add(size).saturating_add(gap);
+            placed
+        })
+        .collect()
+}
+
+/// Arrangement of a step's widgets. Leaves list widget ids drawn top to
+/// bottom; splits lay their children out along a direction; panels draw a
+/// box around their child.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LayoutNode {
+    Widgets(Vec<String>),
+    Split {
+        direction: Direction,
+        gap: u16,
+        children: Vec<(Constraint, LayoutNode)>,
+    },
+    Panel {
+        panel: Panel,
+        child: Box<LayoutNode>,
+    },
+}
+
+impl LayoutNode {
+    pub fn widgets(ids: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self::Widgets(ids.into_iter().map(Into::into).collect())
+    }
+
+    pub fn row(gap: u16, children: Vec<(Constraint, LayoutNode)>) -> Self {
+        Self::Split {
+            direction: Direction::Row,
+            gap,
+            children,
+        }
+    }
+
+    pub fn column(gap: u16, children: Vec<(Constraint, LayoutNode)>) -> Self {
+        Self::Split {
+            direction: Direction::Column,
+            gap,
+            children,
+        }
+    }
+
+    pub fn panel(panel: Panel, child: LayoutNode) -> Self {
+        Self::Panel {
+            panel,
+            child: Box::new(child),
+        }
+    }
+
+    /// Widget ids in the order they appear.
+    pub fn widget_ids(&self) -> Vec<&str> {
+        let mut ids = Vec::new();
+        self.collect_ids(&mut ids);
+        ids
+    }
+
+    fn collect_ids<'a>(&'a self, out: &mut Vec<&'a str>) {
+        match self {
+            Self::Widgets(ids) => out.extend(ids.iter().map(String::as_str)),
+            Self::Split { children, .. } => {
+                for (_, child) in children {
+                    child.collect_ids(out);
+                }
+            }
+            Self::Panel { child, .. } => child.collect_ids(out),
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/flex.rs"]
+mod tests;
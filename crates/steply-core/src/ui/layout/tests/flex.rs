@@ -0,0 +1,83 @@
+use super::{Constraint, Direction, LayoutNode, Rect, split_extent};
+use crate::ui::panel::Panel;
+
+#[test]
+fn fixed_sizes_come_first_and_fill_takes_the_rest() {
+    let split = split_extent(
+        80,
+        &[
+            Constraint::Length(20),
+            Constraint::Percentage(25),
+            Constraint::Fill(1),
+        ],
+        2,
+    );
+    assert_eq!(split, vec![(0, 20), (22, 19), (43, 37)]);
+}
+
+#[test]
+fn fill_weights_share_leftover_space() {
+    let split = split_extent(30, &[Constraint::Fill(1), Constraint::Fill(2)], 0);
+    assert_eq!(split, vec![(0, 10), (10, 20)]);
+}
+
+#[test]
+fn min_and_max_grow_only_without_fill() {
+    let split = split_extent(40, &[Constraint::Min(10), Constraint::Max(12)], 0);
+    assert_eq!(split, vec![(0, 28), (28, 12)]);
+
+    let with_fill = split_extent(
+        40,
+        &[
+            Constraint::Min(10),
+            Constraint::Max(12),
+            Constraint::Fill(1),
+        ],
+        0,
+    );
+    assert_eq!(with_fill, vec![(0, 10), (10, 0), (10, 30)]);
+}
+
+#[test]
+fn overflowing_fixed_sizes_shrink_evenly() {
+    let split = split_extent(30, &[Constraint::Length(30), Constraint::Length(30)], 0);
+    assert_eq!(split, vec![(0, 15), (15, 15)]);
+}
+
+#[test]
+fn rects_split_along_either_direction() {
+    let area = Rect::new(2, 1, 40, 10);
+    let constraints = [Constraint::Percentage(50), Constraint::Fill(1)];
+    assert_eq!(
+        area.split(Direction::Row, &constraints, 0),
+        vec![Rect::new(2, 1, 20, 10), Rect::new(22, 1, 20, 10)]
+    );
+    assert_eq!(
+        area.split(Direction::Column, &constraints, 2),
+        vec![Rect::new(2, 1, 40, 4), Rect::new(2, 7, 40, 4)]
+    );
+}
+
+#[test]
+fn widget_ids_are_listed_in_layout_order() {
+    let layout = LayoutNode::row(
+        1,
+        vec![
+            (
+                Constraint::Percentage(60),
+                LayoutNode::widgets(["name", "email"]),
+            ),
+            (
+                Constraint::Fill(1),
+                LayoutNode::column(
+                    0,
+                    vec![(
+                        Constraint::Fill(1),
+                        LayoutNode::panel(Panel::new(), LayoutNode::widgets(["preview"])),
+                    )],
+                ),
+            ),
+        ],
+    );
+    assert_eq!(layout.widget_ids(), ["name", "email", "preview"]);
+}
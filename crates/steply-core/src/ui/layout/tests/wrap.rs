@@ -0,0 +1,56 @@
+use super::Layout;
+use crate::ui::span::{Span, SpanLine};
+
+fn rows(lines: &[SpanLine], width: u16) -> Vec<String> {
+    Layout::compose(lines, width)
+        .iter()
+        .map(|line| line.iter().map(|span| span.text.as_str()).collect())
+        .collect()
+}
+
+#[test]
+fn wraps_at_word_boundaries() {
+    let line = vec![Span::new("the quick brown fox jumps")];
+    assert_eq!(rows(&[line], 10), ["the quick ", "brown fox ", "jumps"]);
+}
+
+#[test]
+fn splits_words_longer_than_a_row() {
+    let line = vec![Span::new("see supercalifragilistic")];
+    assert_eq!(rows(&[line], 8), ["see ", "supercal", "ifragili", "stic"]);
+}
+
+#[test]
+fn moves_no_wrap_runs_whole_and_breaks_before_words_in_new_spans() {
+    let line = vec![
+        Span::new("status "),
+        Span::new("[running]").no_wrap(),
+        Span::new(" done"),
+    ];
+    assert_eq!(rows(&[line], 12), ["status ", "[running] ", "done"]);
+
+    let line = vec![Span::new("one two "), Span::new("three")];
+    assert_eq!(rows(&[line], 10), ["one two ", "three"]);
+}
+
+#[test]
+fn counts_wide_characters_by_display_width() {
+    let line = vec![Span::new("日本語のテキスト")];
+    assert_eq!(rows(&[line], 7), ["日本語", "のテキ", "スト"]);
+}
+
+#[test]
+fn continuation_rows_keep_the_source_indentation() {
+    let line = vec![Span::new("  - "), Span::new("a list item that wraps")];
+    assert_eq!(
+        rows(&[line], 14),
+        ["  - a list ", "  item that ", "  wraps"]
+    );
+}
+
+#[test]
+fn maps_the_cursor_onto_wrapped_words() {
+    let line = vec![Span::new("hello world")];
+    let (_, cursor) = Layout::compose_with_cursor(&[line], 6, Some((0, 8)));
+    assert_eq!(cursor, Some((1, 2)));
+}
@@ -0,0 +1,252 @@
+use crate::ui::span::{Span, SpanLine};
+use crate::ui::style::{Color, Style};
+
+/// Parses the inline markup authors may use in labels, step descriptions
+/// and validation messages into spans styled on top of `base`:
+///
+/// - `**bold**`
+/// - `_dim_`, only around words, so `snake_case` stays as written
+/// - `[red]text[/]`, with any color [`Color::parse`] knows, e.g. `[#ff8700]`
+/// - `\*`, `\_`, `\[` for the literal characters
+///
+/// Markers without a partner, and brackets that don't name a color, are
+/// kept as text.
+pub fn parse(text: &str, base: Style) -> SpanLine {
+    if !has_markup(text) {
+        return vec![Span::styled(text, base)];
+    }
+    let mut tokens = tokenize(text);
+    pair_markers(tokens.as_mut_slice());
+
+    let mut spans = SpanLine::new();
+    let mut stack = Vec::<Style>::new();
+    let mut current = String::new();
+    for token in tokens {
+        let style = stack.last().copied().unwrap_or(base);
+        let next = match token {
+            Token::Text(text) => {
+                current.push_str(text.as_str());
+                continue;
+            }
+            Token::Marker {
+                raw, paired: false, ..
+            } => {
+                current.push_str(raw.as_str());
+                continue;
+            }
+            Token::Marker { closes: true, .. } => {
+                stack.pop();
+                stack.last().copied().unwrap_or(base)
+            }
+            Token::Marker { kind, .. } => {
+                let opened = match kind {
+                    Marker::Bold => style.bold(),
+                    Marker::Dim => style.dim(),
+                    Marker::Color(color) => style.color(color),
+                    Marker::Reset => style,
+                };
+                stack.push(opened);
+                opened
+            }
+        };
+        if next != style && !current.is_empty() {
+            spans.push(Span::styled(std::mem::take(&mut current), style));
+        }
+    }
+    if !current.is_empty() || spans.is_empty() {
+        let style = stack.last().copied().unwrap_or(base);
+        spans.push(Span::styled(current, style));
+    }
+    spans
+}
+
+/// [`parse`] for text that must stay on one row, such as a label in front
+/// of an input: the spans are kept together when lines wrap.
+pub fn parse_no_wrap(text: &str, base: Style) -> SpanLine {
+    parse(text, base)
+        .into_iter()
+        .enumerate()
+        .map(|(idx, span)| {
+            let span = span.no_wrap();
+            if idx == 0 {
+                span
+            } else {
+                span.join_no_wrap_with_prev()
+            }
+        })
+        .collect()
+}
+
+/// The text without its markup, e.g. for measuring a label.
+pub fn plain_text(text: &str) -> String {
+    parse(text, Style::default())
+        .into_iter()
+        .map(|span| span.text)
+        .collect()
+}
+
+pub fn has_markup(text: &str) -> bool {
+    text.contains(['*', '_', '[', '\\'])
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Marker {
+    Bold,
+    Dim,
+    Color(Color),
+    /// `[/]`, closing the innermost color.
+    Reset,
+}
+
+#[derive(Debug)]
+enum Token {
+    Text(String),
+    Marker {
+        kind: Marker,
+        raw: String,
+        /// Whether it could close where it stands; once paired, whether it
+        /// is the closing half.
+        closes: bool,
+        can_open: bool,
+        paired: bool,
+    },
+}
+
+fn tokenize(text: &str) -> Vec<Token> {
+    let chars = text.chars().collect::<Vec<_>>();
+    let mut tokens = Vec::new();
+    let mut literal = String::new();
+    let flush = |literal: &mut String, tokens: &mut Vec<Token>| {
+        if !literal.is_empty() {
+            tokens.push(Token::Text(std::mem::take(literal)));
+        }
+    };
+    let mut idx = 0usize;
+    while idx < chars.len() {
+        let ch = chars[idx];
+        let prev = idx.checked_sub(1).map(|prev| chars[prev]);
+        match ch {
+            '\\' if chars
+                .get(idx + 1)
+                .is_some_and(|next| matches!(next, '*' | '_' | '[' | '\\')) =>
+            {
+                literal.push(chars[idx + 1]);
+                idx += 2;
+            }
+            '*' if chars.get(idx + 1) == Some(&'*') => {
+                let next = chars.get(idx + 2).copied();
+                flush(&mut literal, &mut tokens);
+                tokens.push(Token::Marker {
+                    kind: Marker::Bold,
+                    raw: "**".to_string(),
+                    closes: prev.is_some_and(|prev| !prev.is_whitespace()),
+                    can_open: next.is_some_and(|next| !next.is_whitespace()),
+                    paired: false,
+                });
+                idx += 2;
+            }
+            '_' => {
+                let next = chars.get(idx + 1).copied();
+                let word = |ch: Option<char>| ch.is_some_and(char::is_alphanumeric);
+                let can_open = !word(prev) && next.is_some_and(|next| !next.is_whitespace());
+                let closes = !word(next) && prev.is_some_and(|prev| !prev.is_whitespace());
+                if can_open || closes {
+                    flush(&mut literal, &mut tokens);
+                    tokens.push(Token::Marker {
+                        kind: Marker::Dim,
+                        raw: "_".to_string(),
+                        closes,
+                        can_open,
+                        paired: false,
+                    });
+                } else {
+                    literal.push('_');
+                }
+                idx += 1;
+            }
+            '[' => {
+                let tag_end = chars[idx + 1..]
+                    .iter()
+                    .position(|ch| *ch == ']')
+                    .map(|offset| idx + 1 + offset);
+                let tag = tag_end.map(|end| chars[idx + 1..end].iter().collect::<String>());
+                let kind = match tag.as_deref() {
+                    Some("/") => Some(Marker::Reset),
+                    Some(name) => Color::parse(name).map(Marker::Color),
+                    None => None,
+                };
+                match (kind, tag_end) {
+                    (Some(kind), Some(end)) => {
+                        flush(&mut literal, &mut tokens);
+                        let reset = kind == Marker::Reset;
+                        tokens.push(Token::Marker {
+                            kind,
+                            raw: chars[idx..=end].iter().collect(),
+                            closes: reset,
+                            can_open: !reset,
+                            paired: false,
+                        });
+                        idx = end + 1;
+                    }
+                    _ => {
+                        literal.push('[');
+                        idx += 1;
+                    }
+                }
+            }
+            _ => {
+                literal.push(ch);
+                idx += 1;
+            }
+        }
+    }
+    flush(&mut literal, &mut tokens);
+    tokens
+}
+
+/// Matches openers with closers, innermost first. Markers that find no
+/// partner stay unpaired and are printed as typed.
+fn pair_markers(tokens: &mut [Token]) {
+    let mut open = Vec::<usize>::new();
+    for idx in 0..tokens.len() {
+        let Token::Marker {
+            kind,
+            closes,
+            can_open,
+            ..
+        } = tokens[idx]
+        else {
+            continue;
+        };
+        let partner = open.last().copied().filter(|&opener| {
+            let Token::Marker { kind: opened, .. } = tokens[opener] else {
+                return false;
+            };
+            closes
+                && match kind {
+                    Marker::Reset => matches!(opened, Marker::Color(_)),
+                    _ => opened == kind,
+                }
+        });
+        match partner {
+            Some(opener) => {
+                open.pop();
+                mark(&mut tokens[opener], true, false);
+                mark(&mut tokens[idx], true, true);
+            }
+            None if can_open => open.push(idx),
+            None => {}
+        }
+    }
+}
+
+fn mark(token: &mut Token, is_paired: bool, is_closer: bool) {
+    if let Token::Marker { paired, closes, .. } = token {
+        *paired = is_paired;
+        *closes = is_closer;
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/markup.rs"]
+mod tests;
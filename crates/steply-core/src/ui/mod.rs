@@ -1,11 +1,20 @@
+pub mod animation;
+pub mod canvas;
+pub mod draw_stats;
+pub mod fill;
 pub mod frame_json;
+pub mod frame_text;
+pub mod glyphs;
 pub mod highlight;
 pub mod hit_test;
 pub mod inline;
 pub mod layout;
+pub mod markup;
+pub mod panel;
 pub mod render_view;
 pub mod renderer;
 pub mod span;
 pub mod spinner;
 pub mod style;
 pub mod text;
+pub mod theme;
@@ -0,0 +1,113 @@
+use crate::ui::layout::Layout;
+use crate::ui::span::{Span, SpanLine};
+use crate::ui::style::{Color, Style, StyleRole};
+use crate::ui::text::{clip_to_display_width_without_linebreaks, text_display_width};
+use crate::widgets::traits::DrawOutput;
+
+/// A box drawn around content: a border, an optional title in the top edge
+/// and blank columns between the border and the content. The border turns
+/// cyan while something inside has focus.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Panel {
+    pub title: Option<String>,
+    pub padding: u16,
+}
+
+impl Panel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Columns between the panel's left edge and its content.
+    pub fn left_inset(&self) -> u16 {
+        self.padding.saturating_add(1)
+    }
+
+    /// Rows between the panel's top edge and its content.
+    pub fn top_inset(&self) -> u16 {
+        1
+    }
+
+    /// Width left for content in a panel `width` columns wide.
+    pub fn inner_width(&self, width: u16) -> u16 {
+        width.saturating_sub(self.left_inset().saturating_mul(2))
+    }
+
+    /// Boxes `output` into `width` columns, wrapping its lines to the inner
+    /// width. Sticky blocks pass through untouched.
+    pub fn render(&self, output: DrawOutput, width: u16, focused: bool) -> DrawOutput {
+        let inner = self.inner_width(width);
+        let lines = Layout::compose(output.lines.as_slice(), inner);
+        DrawOutput {
+            lines: self.frame(lines, width, focused),
+            sticky: output.sticky,
+        }
+    }
+
+    /// Boxes lines that are already composed to the inner width.
+    pub fn frame(&self, lines: Vec<SpanLine>, width: u16, focused: bool) -> Vec<SpanLine> {
+        let inner = self.inner_width(width);
+        let border = border_style(focused);
+        let padding = " ".repeat(self.padding as usize);
+        let mut out = Vec::with_capacity(lines.len() + 2);
+        out.push(self.top_border(width, focused));
+        for line in lines {
+            let mut row = vec![Span::styled(format!("│{padding}"), border).no_wrap()];
+            row.extend(Layout::clip_line(
+                Layout::fit_line(line.as_slice(), inner),
+                inner,
+            ));
+            row.push(Span::styled(format!("{padding}│"), border).no_wrap());
+            out.push(row);
+        }
+        let bottom = "─".repeat(width.saturating_sub(2) as usize);
+        out.push(vec![Span::styled(format!("└{bottom}┘"), border).no_wrap()]);
+        out
+    }
+
+    fn top_border(&self, width: u16, focused: bool) -> SpanLine {
+        let border = border_style(focused);
+        let edge = width.saturating_sub(2) as usize;
+        let title = self
+            .title
+            .as_deref()
+            .filter(|title| !title.is_empty() && edge > 4)
+            .map(|title| clip_to_display_width_without_linebreaks(title, edge - 4));
+        let Some(title) = title else {
+            return vec![Span::styled(format!("┌{}┐", "─".repeat(edge)), border).no_wrap()];
+        };
+        let title_style = if focused {
+            Style::new().color(Color::Cyan).bold()
+        } else {
+            Style::new()
+        };
+        let rest = edge - 3 - text_display_width(title.as_str());
+        vec![
+            Span::styled("┌─ ", border).no_wrap(),
+            Span::styled(title, title_style).no_wrap(),
+            Span::styled(format!(" {}┐", "─".repeat(rest)), border).no_wrap(),
+        ]
+    }
+}
+
+fn border_style(focused: bool) -> Style {
+    if focused {
+        Style::new().color(Color::Cyan)
+    } else {
+        StyleRole::Border.style()
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/panel.rs"]
+mod tests;
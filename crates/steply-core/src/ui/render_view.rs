@@ -1,8 +1,17 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use crate::core::i18n::{Message, MessageCatalog};
 use crate::state::app::{AppState, ExitConfirmChoice, ExitConfirmMode};
 use crate::state::step::{Step, StepStatus};
 use crate::state::validation::ValidationState;
+use crate::task::TaskGraph;
+use crate::terminal::TerminalCapabilities;
+use crate::time::Duration;
+use crate::ui::canvas::Canvas;
+use crate::ui::theme::Theme;
 use crate::widgets::node::Node;
-use crate::widgets::traits::OverlayPlacement;
+use crate::widgets::traits::{OverlayAnchor, OverlayPlacement};
 
 pub struct RenderView<'a> {
     pub steps: Vec<&'a Step>,
@@ -10,14 +19,26 @@ pub struct RenderView<'a> {
     pub step_statuses: Vec<StepStatus>,
     pub has_blocking_overlay: bool,
     pub focused_id: Option<&'a str>,
-    pub step_errors: &'a [String],
+    pub step_errors: &'a [Message],
     pub step_warnings: &'a [String],
     pub validation: &'a ValidationState,
     pub completion: Option<CompletionSnapshot>,
     pub overlays: Vec<OverlayView<'a>>,
+    /// Shown instead of the steps while set.
+    pub fullscreen: Option<FullscreenView<'a>>,
     pub back_confirm: Option<&'a str>,
     pub exit_confirm: Option<ExitConfirmView>,
     pub hints_visible: bool,
+    pub breadcrumb_visible: bool,
+    pub canvas: Canvas,
+    pub prefilled: HashSet<String>,
+    pub auto_advance_remaining: Option<Duration>,
+    /// The step's submit hooks are still running.
+    pub submit_pending: bool,
+    pub messages: Arc<MessageCatalog>,
+    pub capabilities: TerminalCapabilities,
+    pub theme: Arc<Theme>,
+    pub task_graph: Arc<TaskGraph>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -35,10 +56,16 @@ pub struct CompletionSnapshot {
 
 pub struct OverlayView<'a> {
     pub placement: OverlayPlacement,
+    pub anchor: Option<OverlayAnchor>,
     pub nodes: &'a [Node],
     pub is_topmost: bool,
 }
 
+pub struct FullscreenView<'a> {
+    pub title: &'a str,
+    pub node: &'a Node,
+}
+
 impl<'a> RenderView<'a> {
     pub fn from_state(state: &'a AppState) -> Self {
         let visible_indices = state.visible_step_indices();
@@ -49,10 +76,9 @@ impl<'a> RenderView<'a> {
         if step_index >= state.steps().len() {
             return None;
         }
-        Some(Self::from_state_with_visible_indices(
-            state,
-            vec![step_index],
-        ))
+        let mut view = Self::from_state_with_visible_indices(state, vec![step_index]);
+        view.breadcrumb_visible = false;
+        Some(view)
     }
 
     fn from_state_with_visible_indices(state: &'a AppState, visible_indices: Vec<usize>) -> Self {
@@ -83,8 +109,54 @@ impl<'a> RenderView<'a> {
             let nodes = overlay.persistent_children().unwrap_or(&[]);
             overlays.push(OverlayView {
                 placement,
+                anchor: overlay.overlay_anchor(),
                 nodes,
-                is_topmost: idx + 1 == overlay_count,
+                is_topmost: idx + 1 == overlay_count
+                    && !state.value_preview_active()
+                    && !state.task_details_active()
+                    && !state.validation_summary_active()
+                    && !state.inspector_active()
+                    && !state.task_failure_prompt_active(),
+            });
+        }
+        if let Some(preview) = state.value_preview_node() {
+            overlays.push(OverlayView {
+                placement: OverlayPlacement::appended(),
+                anchor: None,
+                nodes: std::slice::from_ref(preview),
+                is_topmost: !state.task_failure_prompt_active(),
+            });
+        }
+        if let Some(details) = state.task_details_node() {
+            overlays.push(OverlayView {
+                placement: OverlayPlacement::appended(),
+                anchor: None,
+                nodes: std::slice::from_ref(details),
+                is_topmost: !state.task_failure_prompt_active(),
+            });
+        }
+        if let Some(summary) = state.validation_summary_node() {
+            overlays.push(OverlayView {
+                placement: OverlayPlacement::appended(),
+                anchor: None,
+                nodes: std::slice::from_ref(summary),
+                is_topmost: !state.task_failure_prompt_active(),
+            });
+        }
+        if let Some(inspector) = state.inspector_node() {
+            overlays.push(OverlayView {
+                placement: OverlayPlacement::appended(),
+                anchor: None,
+                nodes: std::slice::from_ref(inspector),
+                is_topmost: !state.task_failure_prompt_active(),
+            });
+        }
+        if let Some(prompt) = state.task_failure_prompt_node() {
+            overlays.push(OverlayView {
+                placement: OverlayPlacement::appended(),
+                anchor: None,
+                nodes: std::slice::from_ref(prompt),
+                is_topmost: true,
             });
         }
 
@@ -101,19 +173,43 @@ impl<'a> RenderView<'a> {
             steps,
             current_step_index,
             step_statuses,
-            has_blocking_overlay: state.has_blocking_overlay(),
-            focused_id: state.focused_id(),
+            has_blocking_overlay: state.has_blocking_overlay()
+                || state.value_preview_active()
+                || state.task_details_active()
+                || state.validation_summary_active()
+                || state.inspector_active()
+                || state.expanded_value_active()
+                || state.task_failure_prompt_active(),
+            focused_id: state
+                .task_failure_prompt_node()
+                .or(state.task_details_node())
+                .or(state.validation_summary_node())
+                .map(|node| node.id())
+                .or(state.focused_id()),
             step_errors: state.current_step_errors(),
             step_warnings: state.current_step_warnings(),
             validation: state.validation_state(),
             completion,
             overlays,
+            fullscreen: state
+                .expanded_value()
+                .filter(|_| !state.task_failure_prompt_active())
+                .map(|(title, node)| FullscreenView { title, node }),
             back_confirm: state.back_confirm(),
             exit_confirm: state
                 .exit_confirm_choice()
                 .zip(state.exit_confirm_mode())
                 .map(|(choice, mode)| ExitConfirmView { mode, choice }),
             hints_visible: state.hints_visible(),
+            breadcrumb_visible: state.breadcrumb_visible(),
+            canvas: state.canvas(),
+            prefilled: state.prefilled_node_ids(),
+            auto_advance_remaining: state.auto_advance_remaining(),
+            submit_pending: state.has_pending_submit_hooks(),
+            messages: state.message_catalog().clone(),
+            capabilities: state.terminal_capabilities(),
+            theme: state.theme().clone(),
+            task_graph: Arc::new(state.task_graph()),
         }
     }
 }
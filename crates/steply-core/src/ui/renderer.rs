@@ -1,17 +1,24 @@
 use crate::state::step::StepStatus;
 use crate::terminal::{CursorPos, TerminalSize};
+use crate::ui::glyphs::use_ascii_glyphs;
 use crate::ui::hit_test::FrameHitMap;
+use crate::ui::layout::Layout;
 use crate::ui::render_view::RenderView;
-use crate::ui::span::SpanLine;
+use crate::ui::span::{Span, SpanLine};
 use crate::ui::spinner::{Spinner, SpinnerStyle};
-use crate::widgets::traits::StickyBlock;
+use crate::widgets::node::Node;
+use crate::widgets::traits::{RenderContext, StickyBlock};
 
+mod breadcrumb;
 mod content_render;
 mod focus_policy;
 mod frame_build;
+mod fullscreen;
 mod hints_panel;
+mod layout_render;
 mod overlay;
 mod overlay_geometry;
+mod popover;
 mod render_context;
 mod step_content;
 mod step_decoration;
@@ -22,7 +29,9 @@ pub(super) use focus_policy::{
     resolve_focus_anchor,
 };
 use frame_build::build_base_frame;
+use fullscreen::build_fullscreen_frame;
 use overlay::apply_overlay;
+use popover::apply_completion_popover;
 
 #[derive(Debug, Default, Clone)]
 pub struct RenderFrame {
@@ -55,6 +64,62 @@ impl Default for RendererConfig {
     }
 }
 
+/// A single widget drawn the way a step draws it, wrapped to the context
+/// width. See [`render_node`].
+#[derive(Debug, Default, Clone)]
+pub struct NodeRender {
+    pub lines: Vec<SpanLine>,
+    pub cursor: Option<CursorPos>,
+    pub cursor_visible: bool,
+}
+
+/// Draws `node` outside of a flow, with the input label, validation message
+/// and prefilled marker a step would add, for hosts that embed widgets in
+/// their own UI.
+pub fn render_node(node: &Node, ctx: &RenderContext) -> NodeRender {
+    let mut lines = Vec::new();
+    let mut cursor = None;
+    let mut focus_anchor = None;
+    let mut cursor_visible = false;
+    let mut row_offset = 0;
+    let width = ctx.terminal_size.width.max(1);
+    let mut state = DrawNodesState {
+        lines: &mut lines,
+        sticky: None,
+        cursor: &mut cursor,
+        focus_anchor: &mut focus_anchor,
+        cursor_visible: &mut cursor_visible,
+        row_offset: &mut row_offset,
+        hit_map: None,
+        hit_row_offset: None,
+        hit_col_start: 0,
+        compose_width: width,
+    };
+    draw_nodes(
+        std::iter::once(node),
+        ctx,
+        &mut state,
+        DrawNodesOptions {
+            track_cursor: true,
+            strikethrough_inputs: false,
+            collect_sticky: false,
+        },
+    );
+    let (lines, cursor) = Layout::compose_with_cursor(
+        &lines,
+        width,
+        cursor.map(|pos| (pos.row as usize, pos.col as usize)),
+    );
+    NodeRender {
+        lines,
+        cursor: cursor.map(|(row, col)| CursorPos {
+            col: col.min(u16::MAX as usize) as u16,
+            row: row.min(u16::MAX as usize) as u16,
+        }),
+        cursor_visible,
+    }
+}
+
 pub struct Renderer {
     config: RendererConfig,
     running_spinner: Spinner,
@@ -103,6 +168,8 @@ pub(super) struct StepContentRender {
 pub(super) struct StepHintsRender {
     pub has_hints: bool,
     pub panel_lines: Vec<SpanLine>,
+    /// Shown in the step footer while the panel is closed.
+    pub bar: SpanLine,
 }
 
 #[derive(Debug, Clone, Copy, Default)]
@@ -144,11 +211,33 @@ impl Renderer {
 
     pub fn render(&mut self, view: &RenderView, terminal_size: TerminalSize) -> RenderFrame {
         let layout_terminal_size = effective_layout_terminal_size(terminal_size);
+        let (canvas_offset, canvas_width) = view.canvas.place(layout_terminal_size.width);
+        let layout_terminal_size = TerminalSize {
+            width: canvas_width,
+            ..layout_terminal_size
+        };
         let running_marker = self.running_spinner.glyph();
         self.running_spinner.tick();
-        let mut frame = self.render_steps_pass(view, layout_terminal_size, running_marker);
-        self.apply_overlay_pass(view, layout_terminal_size, &mut frame);
+        let mut frame = if let Some(fullscreen) = view.fullscreen.as_ref() {
+            build_fullscreen_frame(view, fullscreen, layout_terminal_size)
+        } else {
+            let mut frame = self.render_steps_pass(view, layout_terminal_size, running_marker);
+            self.apply_overlay_pass(view, layout_terminal_size, &mut frame);
+            apply_completion_popover(view.completion.as_ref(), layout_terminal_size, &mut frame);
+            frame
+        };
         self.finalize_cursor_pass(layout_terminal_size, &mut frame);
+        view.theme.apply(&mut frame.lines);
+        for block in &mut frame.sticky {
+            view.theme.apply(&mut block.lines);
+        }
+        if view.capabilities.ascii_glyphs() {
+            use_ascii_glyphs(&mut frame.lines);
+            for block in &mut frame.sticky {
+                use_ascii_glyphs(&mut block.lines);
+            }
+        }
+        move_to_canvas(&mut frame, canvas_offset);
         frame
     }
 
@@ -174,11 +263,11 @@ impl Renderer {
                 None
             };
             apply_overlay(
-                view.validation,
-                view.completion.as_ref(),
+                view,
                 terminal_size,
                 overlay_view.nodes,
                 overlay_view.placement,
+                overlay_view.anchor.as_ref(),
                 focused_id,
                 frame,
             );
@@ -210,6 +299,27 @@ impl Default for Renderer {
     }
 }
 
+/// Shifts a frame laid out at the canvas width `offset` columns right.
+fn move_to_canvas(frame: &mut RenderFrame, offset: u16) {
+    if offset == 0 {
+        return;
+    }
+    let indent = || Span::new(" ".repeat(offset as usize)).no_wrap();
+    let blocks = frame.sticky.iter_mut().map(|block| &mut block.lines);
+    for line in std::iter::once(&mut frame.lines).chain(blocks).flatten() {
+        if !line.is_empty() {
+            line.insert(0, indent());
+        }
+    }
+    if let Some(cursor) = frame.cursor.as_mut() {
+        cursor.col = cursor.col.saturating_add(offset);
+    }
+    if let Some(col) = frame.focus_anchor_col.as_mut() {
+        *col = col.saturating_add(offset);
+    }
+    frame.hit_map.shift_cols(offset);
+}
+
 fn effective_layout_terminal_size(size: TerminalSize) -> TerminalSize {
     TerminalSize {
         width: if size.width > 1 {
@@ -0,0 +1,166 @@
+use crate::state::step::StepStatus;
+use crate::ui::render_view::RenderView;
+use crate::ui::span::{Span, SpanLine};
+use crate::ui::style::{Color, Style, StyleRole};
+use crate::ui::text::{clip_to_display_width, text_display_width};
+use std::borrow::Cow;
+
+const SEPARATOR: &str = " › ";
+const ELLIPSIS: &str = "…";
+/// Narrowest title worth keeping the "steps left" summary for.
+const MIN_TITLE_WIDTH: usize = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CrumbState {
+    Done,
+    Current,
+    Pending,
+    Error,
+}
+
+pub(super) fn render_breadcrumb(view: &RenderView, width: u16) -> Vec<SpanLine> {
+    if view.steps.is_empty() {
+        return Vec::new();
+    }
+
+    let crumbs = view
+        .steps
+        .iter()
+        .enumerate()
+        .map(|(idx, step)| {
+            (
+                crumb_state(view, idx),
+                view.messages.translate(&step.prompt),
+            )
+        })
+        .collect::<Vec<_>>();
+    let remaining = crumbs
+        .iter()
+        .skip(view.current_step_index.saturating_add(1))
+        .filter(|(state, _)| *state == CrumbState::Pending)
+        .count();
+    let summary = remaining_summary(remaining);
+    let width = width as usize;
+
+    let mut line = SpanLine::new();
+    for (idx, (state, title)) in crumbs.iter().enumerate() {
+        if idx > 0 {
+            line.push(Span::styled(SEPARATOR, separator_style()).no_wrap());
+        }
+        line.push(Span::styled(crumb_marker(*state), crumb_style(*state)).no_wrap());
+        line.push(Span::styled(title.as_ref(), title_style(*state)).no_wrap());
+    }
+    line.push(Span::styled(summary.clone(), separator_style()).no_wrap());
+
+    let full_width = line
+        .iter()
+        .map(|span| text_display_width(span.text.as_str()))
+        .sum::<usize>();
+    if full_width <= width {
+        return vec![line];
+    }
+
+    vec![compact_breadcrumb(view, &crumbs, summary.as_str(), width)]
+}
+
+/// `view.steps` only holds steps whose `when` holds, so the position and
+/// total count visible steps only.
+fn compact_breadcrumb(
+    view: &RenderView,
+    crumbs: &[(CrumbState, Cow<'_, str>)],
+    summary: &str,
+    width: usize,
+) -> SpanLine {
+    let current = view.current_step_index.min(crumbs.len().saturating_sub(1));
+    let (state, title) = &crumbs[current];
+    let state = *state;
+    let position = format!("{}/{} ", current + 1, crumbs.len());
+    let marker = crumb_marker(state);
+    let lead = text_display_width(position.as_str()) + text_display_width(marker);
+    let summary = if lead + text_display_width(summary) + MIN_TITLE_WIDTH <= width {
+        summary
+    } else {
+        ""
+    };
+    let title = ellipsize(
+        title,
+        width.saturating_sub(lead + text_display_width(summary)),
+    );
+
+    let mut remaining = width;
+    [
+        (position, separator_style()),
+        (marker.to_string(), crumb_style(state)),
+        (title, title_style(state)),
+        (summary.to_string(), separator_style()),
+    ]
+    .into_iter()
+    .filter_map(|(text, style)| {
+        let text = clip_to_display_width(text.as_str(), remaining);
+        remaining -= text_display_width(text.as_str());
+        (!text.is_empty()).then(|| Span::styled(text, style).no_wrap())
+    })
+    .collect()
+}
+
+fn ellipsize(text: &str, max_width: usize) -> String {
+    if text_display_width(text) <= max_width {
+        return text.to_string();
+    }
+    let mut clipped = clip_to_display_width(text, max_width.saturating_sub(1));
+    if max_width > 0 {
+        clipped.push_str(ELLIPSIS);
+    }
+    clipped
+}
+
+fn crumb_state(view: &RenderView, idx: usize) -> CrumbState {
+    let is_current = idx == view.current_step_index;
+    if is_current && !view.step_errors.is_empty() {
+        return CrumbState::Error;
+    }
+    match view.step_statuses.get(idx).copied() {
+        Some(StepStatus::Done) => CrumbState::Done,
+        Some(StepStatus::Cancelled) => CrumbState::Error,
+        _ if is_current => CrumbState::Current,
+        _ => CrumbState::Pending,
+    }
+}
+
+fn remaining_summary(remaining: usize) -> String {
+    match remaining {
+        0 => "  · last step".to_string(),
+        1 => "  · 1 step left".to_string(),
+        count => format!("  · {count} steps left"),
+    }
+}
+
+fn crumb_marker(state: CrumbState) -> &'static str {
+    match state {
+        CrumbState::Done => "◈ ",
+        CrumbState::Current => "◇ ",
+        CrumbState::Pending => "○ ",
+        CrumbState::Error => "◆ ",
+    }
+}
+
+fn crumb_style(state: CrumbState) -> Style {
+    match state {
+        CrumbState::Done => StyleRole::Hint.style(),
+        CrumbState::Current => Style::new().color(Color::Green),
+        CrumbState::Pending => StyleRole::Hint.style(),
+        CrumbState::Error => Style::new().color(Color::Red),
+    }
+}
+
+fn title_style(state: CrumbState) -> Style {
+    match state {
+        CrumbState::Current => Style::new().color(Color::White).bold(),
+        CrumbState::Error => Style::new().color(Color::Red).bold(),
+        CrumbState::Done | CrumbState::Pending => StyleRole::Hint.style(),
+    }
+}
+
+fn separator_style() -> Style {
+    StyleRole::Hint.style()
+}
@@ -1,30 +1,37 @@
 use super::{DrawNodesOptions, DrawNodesState};
 use crate::terminal::CursorPos;
+use crate::ui::draw_stats;
 use crate::ui::hit_test::{FrameHitMap, HitLocal};
 use crate::ui::layout::Layout;
+use crate::ui::markup;
 use crate::ui::span::{Span, SpanLine, WrapMode};
-use crate::ui::style::{Color, Strike, Style};
-use crate::ui::text::text_display_width;
+use crate::ui::style::{Color, Strike, StyleRole, Underline};
 use crate::widgets::node::Node;
 use crate::widgets::traits::{DrawOutput, PointerRowMap, RenderContext};
 use std::collections::HashMap;
 
-pub(crate) fn draw_nodes(
-    nodes: &[Node],
+pub(crate) fn draw_nodes<'a>(
+    nodes: impl IntoIterator<Item = &'a Node>,
     ctx: &RenderContext,
     state: &mut DrawNodesState<'_>,
     options: DrawNodesOptions,
 ) {
     for node in nodes {
-        let (label_prefix, label_offset) = input_label_prefix(node, ctx.focused_id.as_deref());
+        let (label_prefix, label_offset) = input_label_prefix(node, ctx);
         let draw_ctx = if label_offset > 0 {
             ctx.with_terminal_width(ctx.terminal_size.width.saturating_sub(label_offset))
         } else {
             ctx.with_focus(ctx.focused_id.clone())
         };
+        let draw_started = draw_stats::draw_started();
         let mut out = node.draw(&draw_ctx);
+        if let Some(started) = draw_started {
+            draw_stats::record_draw(node.id(), started);
+        }
 
+        mark_focused_label(node, ctx, &mut out);
         apply_input_validation_overlay(node, ctx, &mut out);
+        apply_prefilled_marker(node, ctx, &mut out);
         if options.strikethrough_inputs && matches!(node, Node::Input(_)) {
             for line in &mut out.lines {
                 let has_content = line
@@ -50,6 +57,12 @@ pub(crate) fn draw_nodes(
             }
         }
         enforce_input_nowrap_atoms(node, &mut out);
+        if let Some(palette) = ctx.theme.widget_palette(node.id()) {
+            palette.apply(&mut out.lines);
+        }
+        if let Some(styles) = ctx.theme.widget_styles(node.id()) {
+            styles.apply(&mut out.lines);
+        }
 
         if let Some(hit_map) = state.hit_map.as_deref_mut()
             && let Some(hit_row_offset) = state.hit_row_offset.as_deref_mut()
@@ -87,7 +100,11 @@ pub(crate) fn draw_nodes(
                         state.hit_col_start,
                         state.hit_col_start.saturating_add(width),
                         HitLocal::row(*local_row)
-                            .with_col_offset(*local_col_offset)
+                            .with_col_offset(if local_row_u16 == 0 {
+                                local_col_offset.saturating_add(label_offset)
+                            } else {
+                                *local_col_offset
+                            })
                             .with_semantic(*local_semantic),
                     );
                 }
@@ -157,44 +174,56 @@ pub(crate) fn register_block_selection_ranges(
     }
 }
 
-fn input_label_prefix(node: &Node, focused_id: Option<&str>) -> (Option<Vec<Span>>, u16) {
+fn input_label_prefix(node: &Node, ctx: &RenderContext) -> (Option<Vec<Span>>, u16) {
     let Node::Input(widget) = node else {
         return (None, 0);
     };
 
-    let label = widget.label();
+    let label = ctx.translate(widget.label());
     if label.is_empty() {
         return (None, 0);
     }
 
-    let label_style = if focused_id.is_some_and(|id| id == widget.id()) {
-        Style::new().color(Color::White)
+    let label_style = if ctx.focused_id.as_deref() == Some(widget.id()) {
+        StyleRole::Focus.style()
     } else {
-        Style::default()
+        StyleRole::Label.style()
     };
-    let prefix = vec![Span::styled(format!("{label}: "), label_style).no_wrap()];
-    let offset = text_display_width(label)
-        .saturating_add(2)
-        .min(u16::MAX as usize) as u16;
+    let prefix = markup::parse_no_wrap(format!("{label}: ").as_str(), label_style);
+    let offset = Layout::line_width(prefix.as_slice()).min(u16::MAX as usize) as u16;
 
     (Some(prefix), offset)
 }
 
+/// Moves the label of the focused component to the focus role, which
+/// inputs get in [`input_label_prefix`].
+fn mark_focused_label(node: &Node, ctx: &RenderContext, out: &mut DrawOutput) {
+    if !matches!(node, Node::Component(_)) || ctx.focused_id.as_deref() != Some(node.id()) {
+        return;
+    }
+    let Some(first) = out.lines.first_mut() else {
+        return;
+    };
+    for span in first
+        .iter_mut()
+        .filter(|span| span.style.role == Some(StyleRole::Label))
+    {
+        span.style = span.style.merge(StyleRole::Focus.style());
+    }
+}
+
 fn apply_input_validation_overlay(node: &Node, ctx: &RenderContext, out: &mut DrawOutput) {
     if !matches!(node, Node::Input(_)) {
         return;
     }
 
     if let Some(error) = ctx.visible_errors.get(node.id()) {
-        let error_span = Span::styled(
-            format!("✗ {}", error),
-            Style::new().color(Color::Red).bold(),
-        )
-        .no_wrap();
+        let error_line =
+            markup::parse_no_wrap(format!("✗ {error}").as_str(), StyleRole::Error.style());
         if let Some(first) = out.lines.first_mut() {
-            *first = vec![error_span];
+            *first = error_line;
         } else {
-            out.lines.push(vec![error_span]);
+            out.lines.push(error_line);
         }
         return;
     }
@@ -203,11 +232,28 @@ fn apply_input_validation_overlay(node: &Node, ctx: &RenderContext, out: &mut Dr
         for line in out.lines.iter_mut() {
             for span in line.iter_mut() {
                 span.style.color = Some(Color::Red);
+                if !span.text.trim().is_empty() {
+                    span.style.underline = Underline::Curly;
+                    span.style.underline_color = Some(Color::Red);
+                }
             }
         }
     }
 }
 
+fn apply_prefilled_marker(node: &Node, ctx: &RenderContext, out: &mut DrawOutput) {
+    if !matches!(node, Node::Input(_))
+        || !ctx.prefilled.contains(node.id())
+        || ctx.visible_errors.contains_key(node.id())
+    {
+        return;
+    }
+    let marker = Span::styled("  (prefilled)", StyleRole::Hint.style()).no_wrap();
+    if let Some(first) = out.lines.first_mut() {
+        first.push(marker);
+    }
+}
+
 fn enforce_input_nowrap_atoms(node: &Node, out: &mut DrawOutput) {
     if !matches!(node, Node::Input(_)) {
         return;
@@ -4,6 +4,7 @@ use crate::ui::render_view::RenderView;
 use crate::ui::span::Span;
 use crate::ui::style::{Color, Style};
 
+use super::breadcrumb::render_breadcrumb;
 use super::focus_policy::apply_focus_cursor_state;
 use super::step_content::{
     active_focus_id, append_step_hints_lines, apply_step_decoration, render_step_content,
@@ -55,6 +56,13 @@ pub(super) fn build_base_frame(
         .unwrap_or(current_idx);
     let render_up_to = last_visible_idx.max(current_idx);
 
+    if view.breadcrumb_visible {
+        frame
+            .lines
+            .extend(render_breadcrumb(view, terminal_size.width));
+        frame.lines.push(vec![Span::new("")]);
+    }
+
     for (idx, step) in steps
         .iter()
         .enumerate()
@@ -70,8 +78,8 @@ pub(super) fn build_base_frame(
             node_terminal_size,
             compose_width,
         );
-        let hints = render_step_hints(status, view, step.nodes.as_slice());
-        let footer = step_frame_footer(status, view, hints.has_hints);
+        let hints = render_step_hints(status, view, step.nodes.as_slice(), compose_width);
+        let footer = step_frame_footer(status, view, &hints);
         apply_step_decoration(
             &mut content,
             compose_width,
@@ -81,6 +89,7 @@ pub(super) fn build_base_frame(
             config,
             footer,
             running_marker,
+            focused_id,
         );
 
         let start_row = frame.lines.len() as u16;
@@ -0,0 +1,37 @@
+use super::RenderFrame;
+use super::overlay::apply_overlay;
+use crate::terminal::TerminalSize;
+use crate::ui::render_view::{FullscreenView, RenderView};
+use crate::ui::span::Span;
+use crate::ui::style::StyleRole;
+use crate::widgets::traits::{OverlayPlacement, OverlayRenderMode};
+
+/// Replaces the steps with a single node framed across the whole screen,
+/// under a title line.
+pub(super) fn build_fullscreen_frame(
+    view: &RenderView,
+    fullscreen: &FullscreenView,
+    terminal_size: TerminalSize,
+) -> RenderFrame {
+    let height = terminal_size.height.max(4);
+    let mut frame = RenderFrame {
+        lines: vec![Vec::new(); height as usize],
+        ..RenderFrame::default()
+    };
+    frame.lines[0] = vec![
+        Span::styled(fullscreen.title, StyleRole::Focus.style()).no_wrap(),
+        Span::styled("  Esc to close", StyleRole::Hint.style()).no_wrap(),
+    ];
+    let placement = OverlayPlacement::new(1, 0, terminal_size.width, height - 1)
+        .with_render_mode(OverlayRenderMode::Floating);
+    apply_overlay(
+        view,
+        terminal_size,
+        std::slice::from_ref(fullscreen.node),
+        placement,
+        None,
+        Some(fullscreen.node.id()),
+        &mut frame,
+    );
+    frame
+}
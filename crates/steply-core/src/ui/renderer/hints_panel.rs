@@ -1,24 +1,17 @@
 use std::collections::HashSet;
 
+use crate::core::i18n::MessageCatalog;
 use crate::ui::span::{Span, SpanLine};
-use crate::ui::style::{Color, Style};
+use crate::ui::style::StyleRole;
 use crate::ui::text::text_display_width;
-use crate::widgets::node::{Node, NodeWalkScope, walk_nodes};
+use crate::widgets::node::Node;
 use crate::widgets::traits::{HintContext, HintGroup, HintItem};
 
-pub(super) fn render_hints_panel_lines(mut hints: Vec<HintItem>) -> Vec<SpanLine> {
+pub(super) fn render_hints_panel_lines(hints: &[HintItem]) -> Vec<SpanLine> {
     if hints.is_empty() {
         return Vec::new();
     }
 
-    hints.sort_by(|a, b| {
-        a.priority
-            .cmp(&b.priority)
-            .then_with(|| a.group.cmp(&b.group))
-            .then_with(|| a.key.cmp(&b.key))
-            .then_with(|| a.label.cmp(&b.label))
-    });
-
     let mut grouped = Vec::<(HintGroup, Vec<HintItem>)>::new();
     for group in [
         HintGroup::Navigation,
@@ -41,21 +34,7 @@ pub(super) fn render_hints_panel_lines(mut hints: Vec<HintItem>) -> Vec<SpanLine
 
     let column_widths = grouped
         .iter()
-        .map(|(_, items)| {
-            items
-                .iter()
-                .map(|item| {
-                    let key_width = text_display_width(item.key.as_ref());
-                    let label_width = text_display_width(item.label.as_ref());
-                    if label_width == 0 {
-                        key_width
-                    } else {
-                        key_width + 1 + label_width
-                    }
-                })
-                .max()
-                .unwrap_or(0)
-        })
+        .map(|(_, items)| items.iter().map(hint_width).max().unwrap_or(0))
         .collect::<Vec<_>>();
 
     let max_rows = grouped
@@ -73,8 +52,8 @@ pub(super) fn render_hints_panel_lines(mut hints: Vec<HintItem>) -> Vec<SpanLine
             if let Some(item) = items.get(row) {
                 let key = item.key.to_string();
                 let label = item.label.to_string();
-                let key_style = Style::new().color(Color::DarkGrey).bold();
-                let text_style = Style::new().color(Color::DarkGrey);
+                let key_style = StyleRole::Hint.style().bold();
+                let text_style = StyleRole::Hint.style();
                 line.push(Span::styled(key.clone(), key_style).no_wrap());
                 if !label.is_empty() {
                     line.push(Span::styled(" ", text_style).no_wrap());
@@ -106,27 +85,117 @@ pub(super) fn render_hints_panel_lines(mut hints: Vec<HintItem>) -> Vec<SpanLine
     lines
 }
 
-pub(super) fn collect_hints(nodes: &[Node], focused_id: Option<&str>) -> Vec<HintItem> {
+/// One line of hints for when the help panel is closed: as many as fit in
+/// `width`, in the order [`collect_hints`] sorted them, then how many were
+/// left out.
+pub(super) fn render_hint_bar(hints: &[HintItem], width: usize) -> SpanLine {
+    let widths = hints.iter().map(hint_width).collect::<Vec<_>>();
+    let used = |count: usize| {
+        widths[..count].iter().sum::<usize>() + HINT_BAR_GAP.len() * count.saturating_sub(1)
+    };
+    let mut shown = hints.len();
+    while shown > 0 {
+        let rest = hints.len() - shown;
+        let more = if rest == 0 {
+            0
+        } else {
+            HINT_BAR_GAP.len() + text_display_width(more_text(rest).as_str())
+        };
+        if used(shown) + more <= width {
+            break;
+        }
+        shown -= 1;
+    }
+
+    let key_style = StyleRole::Hint.style().bold();
+    let text_style = StyleRole::Hint.style();
+    let mut line = Vec::<Span>::new();
+    for (idx, item) in hints[..shown].iter().enumerate() {
+        if idx > 0 {
+            line.push(Span::styled(HINT_BAR_GAP, text_style).no_wrap());
+        }
+        line.push(Span::styled(item.key.to_string(), key_style).no_wrap());
+        if !item.label.is_empty() {
+            line.push(Span::styled(format!(" {}", item.label), text_style).no_wrap());
+        }
+    }
+    if shown < hints.len() {
+        if shown > 0 {
+            line.push(Span::styled(HINT_BAR_GAP, text_style).no_wrap());
+        }
+        line.push(Span::styled(more_text(hints.len() - shown), text_style).no_wrap());
+    }
+    line
+}
+
+const HINT_BAR_GAP: &str = "  ";
+
+fn more_text(count: usize) -> String {
+    format!("… {count} more (Ctrl+h)")
+}
+
+fn hint_width(item: &HintItem) -> usize {
+    let key_width = text_display_width(item.key.as_ref());
+    let label_width = text_display_width(item.label.as_ref());
+    if label_width == 0 {
+        key_width
+    } else {
+        key_width + 1 + label_width
+    }
+}
+
+/// Hints of the focused widget and of the components around it, innermost
+/// first, so a key is listed once with the label of the widget that
+/// handles it. Sorted by group, then priority.
+pub(super) fn collect_hints(
+    nodes: &[Node],
+    focused_id: Option<&str>,
+    messages: &MessageCatalog,
+) -> Vec<HintItem> {
+    let mut chain = Vec::<&Node>::new();
+    if let Some(id) = focused_id {
+        focus_chain(nodes, id, &mut chain);
+    }
     let mut out = Vec::<HintItem>::new();
-    let mut seen = HashSet::<(String, String, HintGroup)>::new();
-    walk_nodes(nodes, NodeWalkScope::TopLevel, &mut |node| {
-        let focused = focused_id.is_some_and(|id| id == node.id());
+    let mut seen = HashSet::<String>::new();
+    for node in chain {
         for hint in node.hints(HintContext {
-            focused,
+            focused: true,
             expanded: true,
         }) {
-            let key = hint.key.to_string();
-            let label = hint.label.to_string();
-            let dedup_key = (key.clone(), label.clone(), hint.group);
-            if seen.insert(dedup_key) {
+            if seen.insert(hint.key.to_string()) {
+                let label = messages.translate_hint(hint.label.as_ref()).to_string();
                 out.push(HintItem {
-                    key: key.into(),
                     label: label.into(),
-                    priority: hint.priority,
-                    group: hint.group,
+                    ..hint
                 });
             }
         }
+    }
+    out.sort_by(|a, b| {
+        a.group
+            .cmp(&b.group)
+            .then_with(|| a.priority.cmp(&b.priority))
+            .then_with(|| a.key.cmp(&b.key))
     });
     out
 }
+
+/// Pushes the node with `id`, then each component holding it.
+fn focus_chain<'a>(nodes: &'a [Node], id: &str, chain: &mut Vec<&'a Node>) -> bool {
+    for node in nodes {
+        if node.id() == id
+            || node
+                .persistent_children()
+                .is_some_and(|children| focus_chain(children, id, chain))
+        {
+            chain.push(node);
+            return true;
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+#[path = "tests/hints_panel.rs"]
+mod tests;
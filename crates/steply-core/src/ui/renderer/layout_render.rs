@@ -0,0 +1,263 @@
+use crate::terminal::CursorPos;
+use crate::ui::hit_test::FrameHitMap;
+use crate::ui::layout::{Constraint, Direction, Layout, LayoutNode, split_extent};
+use crate::ui::panel::Panel;
+use crate::ui::span::{Span, SpanLine};
+use crate::widgets::node::Node;
+use crate::widgets::traits::{RenderContext, StickyBlock};
+
+use super::step_content::remap_block_layout_marker;
+use super::{DrawNodesOptions, DrawNodesState, draw_nodes};
+
+/// A pane drawn at its own width. Lines are already composed to that width;
+/// cursor, anchor and hit rows are relative to the pane's top left cell.
+#[derive(Debug, Clone, Default)]
+pub(super) struct PaneRender {
+    pub lines: Vec<SpanLine>,
+    pub sticky: Vec<StickyBlock>,
+    pub cursor: Option<CursorPos>,
+    pub focus_anchor: Option<u16>,
+    pub cursor_visible: bool,
+    pub hit_map: FrameHitMap,
+}
+
+/// Draws `nodes` arranged by `layout` into `width` columns. Nodes the layout
+/// does not name follow below it at full width, in step order.
+pub(super) fn render_layout(
+    layout: &LayoutNode,
+    nodes: &[Node],
+    ctx: &RenderContext,
+    width: u16,
+    options: DrawNodesOptions,
+) -> PaneRender {
+    let placed = layout.widget_ids();
+    let mut pane = render_pane(layout, nodes, ctx, width, options);
+    let rest = nodes
+        .iter()
+        .filter(|node| !placed.contains(&node.id()))
+        .collect::<Vec<_>>();
+    if !rest.is_empty() {
+        let below = render_widgets(rest, ctx, width, options);
+        stack(&mut pane, below, 0);
+    }
+    pane
+}
+
+fn render_pane(
+    layout: &LayoutNode,
+    nodes: &[Node],
+    ctx: &RenderContext,
+    width: u16,
+    options: DrawNodesOptions,
+) -> PaneRender {
+    match layout {
+        LayoutNode::Widgets(ids) => {
+            let pane_nodes = ids
+                .iter()
+                .filter_map(|id| nodes.iter().find(|node| node.id() == id));
+            render_widgets(pane_nodes, ctx, width, options)
+        }
+        LayoutNode::Split {
+            direction: Direction::Row,
+            gap,
+            children,
+        } => {
+            let constraints = children
+                .iter()
+                .map(|(constraint, _)| *constraint)
+                .collect::<Vec<_>>();
+            let columns = split_extent(width, &constraints, *gap)
+                .into_iter()
+                .zip(children)
+                .filter(|((_, child_width), _)| *child_width > 0)
+                .map(|((x, child_width), (_, child))| {
+                    (x, render_pane(child, nodes, ctx, child_width, options))
+                })
+                .collect();
+            side_by_side(columns)
+        }
+        LayoutNode::Split {
+            direction: Direction::Column,
+            gap,
+            children,
+        } => {
+            let mut pane = PaneRender::default();
+            for (index, (constraint, child)) in children.iter().enumerate() {
+                let mut child_pane = render_pane(child, nodes, ctx, width, options);
+                let height = pane_height(*constraint, child_pane.lines.len(), ctx);
+                fit_height(&mut child_pane, height);
+                stack(&mut pane, child_pane, if index == 0 { 0 } else { *gap });
+            }
+            pane
+        }
+        LayoutNode::Panel { panel, child } => {
+            let inner = render_pane(child, nodes, ctx, panel.inner_width(width), options);
+            let focused = ctx
+                .focused_id
+                .as_deref()
+                .is_some_and(|id| child.widget_ids().contains(&id));
+            boxed(panel, inner, width, focused)
+        }
+    }
+}
+
+fn render_widgets<'a>(
+    nodes: impl IntoIterator<Item = &'a Node>,
+    ctx: &RenderContext,
+    width: u16,
+    options: DrawNodesOptions,
+) -> PaneRender {
+    let ctx = ctx.with_terminal_width(width);
+    let mut pane = PaneRender::default();
+    let mut row_offset = 0u16;
+    let mut hit_row_offset = 0u16;
+    let mut state = DrawNodesState {
+        lines: &mut pane.lines,
+        sticky: Some(&mut pane.sticky),
+        cursor: &mut pane.cursor,
+        focus_anchor: &mut pane.focus_anchor,
+        cursor_visible: &mut pane.cursor_visible,
+        row_offset: &mut row_offset,
+        hit_map: Some(&mut pane.hit_map),
+        hit_row_offset: Some(&mut hit_row_offset),
+        hit_col_start: 0,
+        compose_width: width,
+    };
+    draw_nodes(nodes, &ctx, &mut state, options);
+    remap_block_layout_marker(
+        &mut pane.lines,
+        width,
+        &mut pane.cursor,
+        &mut pane.focus_anchor,
+    );
+    pane
+}
+
+/// Rows for a stacked pane. Heights have no fixed budget, so `Fill` keeps
+/// the pane's own height and percentages refer to the terminal height.
+fn pane_height(constraint: Constraint, natural: usize, ctx: &RenderContext) -> usize {
+    match constraint {
+        Constraint::Length(rows) => rows as usize,
+        Constraint::Percentage(percent) => {
+            ctx.terminal_size.height as usize * percent.min(100) as usize / 100
+        }
+        Constraint::Min(rows) => natural.max(rows as usize),
+        Constraint::Max(rows) => natural.min(rows as usize),
+        Constraint::Fill(_) => natural,
+    }
+}
+
+fn fit_height(pane: &mut PaneRender, height: usize) {
+    let rows = height.min(u16::MAX as usize) as u16;
+    pane.lines.resize_with(height, blank_line);
+    pane.hit_map.truncate_rows(rows);
+    if pane.cursor.is_some_and(|cursor| cursor.row >= rows) {
+        pane.cursor = None;
+    }
+    if pane.focus_anchor.is_some_and(|anchor| anchor >= rows) {
+        pane.focus_anchor = None;
+    }
+}
+
+fn stack(top: &mut PaneRender, mut below: PaneRender, gap: u16) {
+    if top.lines.is_empty() {
+        *top = below;
+        return;
+    }
+    if below.lines.is_empty() {
+        top.sticky.append(&mut below.sticky);
+        return;
+    }
+    top.lines
+        .extend(std::iter::repeat_with(blank_line).take(gap as usize));
+    let offset = top.lines.len().min(u16::MAX as usize) as u16;
+    below.hit_map.shift_rows(offset);
+    top.hit_map.extend(below.hit_map);
+    if top.cursor.is_none()
+        && let Some(cursor) = below.cursor
+    {
+        top.cursor = Some(CursorPos {
+            col: cursor.col,
+            row: cursor.row.saturating_add(offset),
+        });
+        top.cursor_visible = below.cursor_visible;
+    }
+    if top.focus_anchor.is_none() {
+        top.focus_anchor = below
+            .focus_anchor
+            .map(|anchor| anchor.saturating_add(offset));
+    }
+    top.lines.append(&mut below.lines);
+    top.sticky.append(&mut below.sticky);
+}
+
+/// Joins panes placed at column `x` into shared rows, padding each pane's
+/// lines out to where the next pane starts.
+fn side_by_side(columns: Vec<(u16, PaneRender)>) -> PaneRender {
+    let height = columns
+        .iter()
+        .map(|(_, pane)| pane.lines.len())
+        .max()
+        .unwrap_or(0);
+    let mut out = PaneRender {
+        lines: vec![Vec::new(); height],
+        ..PaneRender::default()
+    };
+    for (x, mut pane) in columns {
+        for (row, line) in out.lines.iter_mut().enumerate() {
+            let used = Layout::line_width(line.as_slice());
+            if let Some(pane_line) = pane.lines.get_mut(row)
+                && !pane_line.is_empty()
+            {
+                if used < x as usize {
+                    line.push(Span::new(" ".repeat(x as usize - used)).no_wrap());
+                }
+                line.append(pane_line);
+            }
+        }
+        pane.hit_map.shift_cols(x);
+        out.hit_map.extend(pane.hit_map);
+        if out.cursor.is_none()
+            && let Some(cursor) = pane.cursor
+        {
+            out.cursor = Some(CursorPos {
+                col: cursor.col.saturating_add(x),
+                row: cursor.row,
+            });
+            out.cursor_visible = pane.cursor_visible;
+        }
+        out.focus_anchor = match (out.focus_anchor, pane.focus_anchor) {
+            (Some(current), Some(anchor)) => Some(current.min(anchor)),
+            (current, anchor) => current.or(anchor),
+        };
+        out.sticky.append(&mut pane.sticky);
+    }
+    for line in &mut out.lines {
+        if line.is_empty() {
+            *line = blank_line();
+        }
+    }
+    out
+}
+
+/// Wraps a pane in `panel`, lit up while one of its widgets has focus.
+fn boxed(panel: &Panel, mut inner: PaneRender, width: u16, focused: bool) -> PaneRender {
+    let (rows, cols) = (panel.top_inset(), panel.left_inset());
+    inner.hit_map.shift_rows(rows);
+    inner.hit_map.shift_cols(cols);
+    PaneRender {
+        lines: panel.frame(inner.lines, width, focused),
+        sticky: inner.sticky,
+        cursor: inner.cursor.map(|cursor| CursorPos {
+            col: cursor.col.saturating_add(cols),
+            row: cursor.row.saturating_add(rows),
+        }),
+        focus_anchor: inner.focus_anchor.map(|anchor| anchor.saturating_add(rows)),
+        cursor_visible: inner.cursor_visible,
+        hit_map: inner.hit_map,
+    }
+}
+
+fn blank_line() -> SpanLine {
+    vec![Span::new("")]
+}
@@ -1,5 +1,6 @@
 use super::overlay_geometry::{
-    FloatingOverlayGeometry, InlineOverlayGeometry, OverlayGeometry, resolve_overlay_geometry,
+    FloatingOverlayGeometry, InlineOverlayGeometry, OverlayGeometry, anchor_overlay_placement,
+    resolve_overlay_geometry,
 };
 use super::render_context::render_context_for_nodes;
 use super::step_decoration::{
@@ -10,26 +11,41 @@ use super::{
     StepVisualStatus, apply_focus_cursor_state, draw_nodes, focused_cursor_in_hit_map,
     layout_marker_from_focus, resolve_focus_anchor,
 };
-use crate::state::validation::ValidationState;
 use crate::terminal::{CursorPos, TerminalSize};
 use crate::ui::hit_test::FrameHitMap;
 use crate::ui::layout::Layout;
-use crate::ui::render_view::CompletionSnapshot;
+use crate::ui::render_view::RenderView;
 use crate::ui::span::{Span, SpanLine};
 use crate::ui::style::{Color, Style};
 use crate::ui::text::char_display_width;
 use crate::widgets::node::Node;
-use crate::widgets::traits::OverlayPlacement;
+use crate::widgets::traits::{OverlayAnchor, OverlayPlacement, OverlayRenderMode};
 
 pub(super) fn apply_overlay(
-    validation: &ValidationState,
-    completion: Option<&CompletionSnapshot>,
+    view: &RenderView,
     terminal_size: TerminalSize,
     overlay_nodes: &[Node],
     placement: OverlayPlacement,
+    anchor: Option<&OverlayAnchor>,
     focused_id: Option<&str>,
     frame: &mut RenderFrame,
 ) {
+    let placement = match anchor {
+        Some(anchor) if placement.render_mode == OverlayRenderMode::Floating => frame
+            .hit_map
+            .node_bounds(anchor.target.as_str())
+            .map(|bounds| {
+                anchor_overlay_placement(
+                    placement,
+                    bounds,
+                    anchor.side,
+                    terminal_size,
+                    frame.lines.len(),
+                )
+            })
+            .unwrap_or(placement),
+        _ => placement,
+    };
     let geometry = resolve_overlay_geometry(
         placement,
         terminal_size,
@@ -40,8 +56,7 @@ pub(super) fn apply_overlay(
     match geometry {
         OverlayGeometry::Floating(geometry) => {
             apply_floating_overlay(
-                validation,
-                completion,
+                view,
                 terminal_size,
                 overlay_nodes,
                 focused_id,
@@ -51,8 +66,7 @@ pub(super) fn apply_overlay(
         }
         OverlayGeometry::Inline(geometry) => {
             apply_inline_overlay(
-                validation,
-                completion,
+                view,
                 terminal_size,
                 overlay_nodes,
                 focused_id,
@@ -64,8 +78,7 @@ pub(super) fn apply_overlay(
 }
 
 fn apply_floating_overlay(
-    validation: &ValidationState,
-    completion: Option<&CompletionSnapshot>,
+    view: &RenderView,
     terminal_size: TerminalSize,
     overlay_nodes: &[Node],
     focused_id: Option<&str>,
@@ -73,8 +86,7 @@ fn apply_floating_overlay(
     geometry: FloatingOverlayGeometry,
 ) {
     let mut body = render_overlay_body(
-        validation,
-        completion,
+        view,
         terminal_size,
         overlay_nodes,
         focused_id,
@@ -132,8 +144,7 @@ fn apply_floating_overlay(
 }
 
 fn apply_inline_overlay(
-    validation: &ValidationState,
-    completion: Option<&CompletionSnapshot>,
+    view: &RenderView,
     terminal_size: TerminalSize,
     overlay_nodes: &[Node],
     focused_id: Option<&str>,
@@ -141,8 +152,7 @@ fn apply_inline_overlay(
     geometry: InlineOverlayGeometry,
 ) {
     let mut body = render_overlay_body(
-        validation,
-        completion,
+        view,
         terminal_size,
         overlay_nodes,
         focused_id,
@@ -251,8 +261,7 @@ struct OverlayBody {
 }
 
 fn render_overlay_body(
-    validation: &ValidationState,
-    completion: Option<&CompletionSnapshot>,
+    view: &RenderView,
     terminal_size: TerminalSize,
     overlay_nodes: &[Node],
     focused_id: Option<&str>,
@@ -267,8 +276,7 @@ fn render_overlay_body(
     let mut hit_row_offset: u16 = 0;
 
     let ctx = render_context_for_nodes(
-        validation,
-        completion,
+        view,
         terminal_size,
         StepVisualStatus::Active,
         overlay_nodes,
@@ -330,7 +338,11 @@ fn render_overlay_body(
     }
 }
 
-fn render_overlay_box(width: usize, height: usize, content_lines: &[SpanLine]) -> Vec<SpanLine> {
+pub(super) fn render_overlay_box(
+    width: usize,
+    height: usize,
+    content_lines: &[SpanLine],
+) -> Vec<SpanLine> {
     if width == 0 || height == 0 {
         return Vec::new();
     }
@@ -384,7 +396,7 @@ fn border_line(width: usize, left: char, right: char, style: Style) -> SpanLine
     cells_to_span_line(cells.as_slice())
 }
 
-fn blend_overlay_lines(
+pub(super) fn blend_overlay_lines(
     base: &mut Vec<SpanLine>,
     row: usize,
     col: usize,
@@ -1,5 +1,6 @@
 use crate::terminal::TerminalSize;
-use crate::widgets::traits::{OverlayPlacement, OverlayRenderMode};
+use crate::ui::hit_test::HitBounds;
+use crate::widgets::traits::{OverlayPlacement, OverlayRenderMode, PopoverSide};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(super) enum OverlayGeometry {
@@ -44,16 +45,21 @@ pub(super) fn resolve_overlay_geometry(
             height: placement.height,
             content_width: placement.width.saturating_sub(2).max(1),
         }),
-        OverlayRenderMode::Inline => {
-            let left_padding_cols =
-                (placement.col as usize).saturating_sub(decoration_gutter_width);
+        OverlayRenderMode::Inline | OverlayRenderMode::Appended => {
+            let (insert_row, left_padding_cols) = match placement.render_mode {
+                OverlayRenderMode::Appended => (frame_line_count, 0),
+                _ => (
+                    (placement.row as usize).min(frame_line_count),
+                    (placement.col as usize).saturating_sub(decoration_gutter_width),
+                ),
+            };
             let content_width = terminal_size
                 .width
                 .saturating_sub((decoration_gutter_width.saturating_add(left_padding_cols)) as u16)
                 .max(1);
 
             OverlayGeometry::Inline(InlineOverlayGeometry {
-                insert_row: (placement.row as usize).min(frame_line_count),
+                insert_row,
                 gutter_width: decoration_gutter_width,
                 left_padding_cols,
                 content_width,
@@ -61,3 +67,50 @@ pub(super) fn resolve_overlay_geometry(
         }
     }
 }
+
+/// Moves a floating placement next to its anchor's rows. `Auto` opens below
+/// unless that would run past both the frame and the screen while the space
+/// above is larger. The popover is then cut down to the screen width and to
+/// the room on its side, keeping at least one row between its borders.
+/// Two border rows and one of content.
+const MIN_POPOVER_HEIGHT: u16 = 3;
+
+pub(super) fn anchor_overlay_placement(
+    placement: OverlayPlacement,
+    anchor: HitBounds,
+    side: PopoverSide,
+    terminal_size: TerminalSize,
+    frame_line_count: usize,
+) -> OverlayPlacement {
+    let bottom_edge = (frame_line_count.min(u16::MAX as usize) as u16).max(terminal_size.height);
+    let space_below = bottom_edge.saturating_sub(anchor.bottom);
+    let space_above = anchor.top;
+    let above = match side {
+        PopoverSide::Above => true,
+        PopoverSide::Below => false,
+        PopoverSide::Auto => placement.height > space_below && space_above > space_below,
+    };
+    let room = if above { space_above } else { space_below };
+    let height = placement
+        .height
+        .min(room.max(MIN_POPOVER_HEIGHT.min(placement.height)));
+    let width = placement.width.min(terminal_size.width);
+    let row = if above {
+        anchor.top.saturating_sub(height)
+    } else {
+        anchor.bottom
+    };
+    let col = anchor.left.min(terminal_size.width.saturating_sub(width));
+
+    OverlayPlacement {
+        row,
+        col,
+        width,
+        height,
+        ..placement
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/overlay_geometry.rs"]
+mod tests;
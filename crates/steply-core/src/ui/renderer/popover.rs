@@ -0,0 +1,95 @@
+use super::RenderFrame;
+use super::overlay::{blend_overlay_lines, render_overlay_box};
+use super::overlay_geometry::anchor_overlay_placement;
+use crate::terminal::TerminalSize;
+use crate::ui::hit_test::HitBounds;
+use crate::ui::render_view::CompletionSnapshot;
+use crate::ui::span::{Span, SpanLine};
+use crate::ui::style::StyleRole;
+use crate::ui::text::text_display_width;
+use crate::widgets::traits::{OverlayPlacement, OverlayRenderMode, PopoverSide};
+
+/// Matches shown at once; the list scrolls to keep the selection in view.
+const MAX_VISIBLE_MATCHES: usize = 8;
+
+/// Floats the matches of an open completion session in a box under the
+/// input's cursor, or above it when there is more room there. A single
+/// match is left to the input's ghost text.
+pub(super) fn apply_completion_popover(
+    completion: Option<&CompletionSnapshot>,
+    terminal_size: TerminalSize,
+    frame: &mut RenderFrame,
+) {
+    let Some(completion) = completion.filter(|completion| completion.matches.len() > 1) else {
+        return;
+    };
+    let Some(cursor) = frame.cursor else {
+        return;
+    };
+
+    let item_width = completion
+        .matches
+        .iter()
+        .map(|item| text_display_width(item))
+        .max()
+        .unwrap_or(0);
+    let visible = completion.matches.len().min(MAX_VISIBLE_MATCHES);
+    let placement = OverlayPlacement::new(
+        0,
+        0,
+        (item_width + 4).min(u16::MAX as usize) as u16,
+        (visible + 2) as u16,
+    )
+    .with_render_mode(OverlayRenderMode::Floating);
+    let anchor = HitBounds {
+        top: cursor.row,
+        bottom: cursor.row.saturating_add(1),
+        left: cursor.col,
+        right: cursor.col.saturating_add(1),
+    };
+    let placement = anchor_overlay_placement(
+        placement,
+        anchor,
+        PopoverSide::Auto,
+        terminal_size,
+        frame.lines.len(),
+    );
+
+    let rows = (placement.height as usize).saturating_sub(2).max(1);
+    let first = completion
+        .selected
+        .saturating_sub(rows - 1)
+        .min(completion.matches.len().saturating_sub(rows));
+    let lines = completion
+        .matches
+        .iter()
+        .enumerate()
+        .skip(first)
+        .take(rows)
+        .map(|(index, item)| match_line(item, index == completion.selected))
+        .collect::<Vec<_>>();
+    let box_lines = render_overlay_box(
+        placement.width as usize,
+        placement.height as usize,
+        lines.as_slice(),
+    );
+    blend_overlay_lines(
+        &mut frame.lines,
+        placement.row as usize,
+        placement.col as usize,
+        placement.width as usize,
+        box_lines.as_slice(),
+    );
+}
+
+fn match_line(item: &str, selected: bool) -> SpanLine {
+    if selected {
+        vec![Span::styled(format!(" {item} "), StyleRole::Active.style()).no_wrap()]
+    } else {
+        vec![Span::new(format!(" {item} ")).no_wrap()]
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/popover.rs"]
+mod tests;
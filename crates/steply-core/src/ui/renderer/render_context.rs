@@ -1,9 +1,8 @@
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
-use crate::state::validation::ValidationState;
 use crate::terminal::TerminalSize;
-use crate::ui::render_view::CompletionSnapshot;
+use crate::ui::render_view::RenderView;
 use crate::ui::span::SpanLine;
 use crate::ui::style::Color;
 use crate::widgets::node::{Node, NodeWalkScope, walk_nodes};
@@ -12,29 +11,42 @@ use crate::widgets::traits::{CompletionMenu, RenderContext};
 use super::{StepVisualStatus, status_allows_interaction};
 
 pub(super) fn render_context_for_nodes(
-    validation: &ValidationState,
-    completion: Option<&CompletionSnapshot>,
+    view: &RenderView,
     terminal_size: TerminalSize,
     status: StepVisualStatus,
     nodes: &[Node],
     focused_id: Option<&str>,
 ) -> RenderContext {
     if !status_allows_interaction(status) {
-        return RenderContext::empty(terminal_size);
+        return RenderContext {
+            messages: view.messages.clone(),
+            capabilities: view.capabilities,
+            theme: view.theme.clone(),
+            task_graph: view.task_graph.clone(),
+            ..RenderContext::empty(terminal_size)
+        };
     }
 
+    let validation = view.validation;
     let mut visible_errors = HashMap::<String, String>::new();
     let mut invalid_hidden = HashSet::<String>::new();
+    let mut prefilled = HashSet::<String>::new();
     let mut completion_menus = HashMap::<String, CompletionMenu>::new();
     walk_nodes(nodes, NodeWalkScope::Recursive, &mut |node| {
         if let Some(error) = validation.visible_error(node.id()) {
-            visible_errors.insert(node.id().to_string(), error.to_string());
+            visible_errors.insert(
+                node.id().to_string(),
+                view.messages.render(error).to_string(),
+            );
         } else if validation.is_hidden_invalid(node.id()) {
             invalid_hidden.insert(node.id().to_string());
         }
+        if view.prefilled.contains(node.id()) {
+            prefilled.insert(node.id().to_string());
+        }
     });
 
-    if let Some(snap) = completion {
+    if let Some(snap) = view.completion.as_ref() {
         completion_menus.insert(
             snap.owner.clone(),
             CompletionMenu {
@@ -50,7 +62,12 @@ pub(super) fn render_context_for_nodes(
         terminal_size,
         visible_errors: Arc::new(visible_errors),
         invalid_hidden: Arc::new(invalid_hidden),
+        prefilled: Arc::new(prefilled),
         completion_menus: Arc::new(completion_menus),
+        messages: view.messages.clone(),
+        capabilities: view.capabilities,
+        theme: view.theme.clone(),
+        task_graph: view.task_graph.clone(),
     }
 }
 
@@ -1,19 +1,22 @@
 use crate::state::step::Step;
 use crate::terminal::{CursorPos, TerminalSize};
+use crate::time::Duration;
 use crate::ui::layout::Layout;
+use crate::ui::markup;
 use crate::ui::render_view::RenderView;
 use crate::ui::span::{Span, SpanLine};
-use crate::ui::style::{Color, Style};
+use crate::ui::style::{Color, Style, StyleRole};
 use crate::widgets::node::Node;
 
 use super::focus_policy::{
     focused_cursor_in_hit_map, layout_marker_from_focus, resolve_focus_anchor,
 };
-use super::hints_panel::{collect_hints, render_hints_panel_lines};
+use super::hints_panel::{collect_hints, render_hint_bar, render_hints_panel_lines};
+use super::layout_render::render_layout;
 use super::render_context::{render_context_for_nodes, tint_block};
 use super::step_decoration::{
     StepFrameFooter, append_step_frame_footer_plain, apply_step_frame, decoration_gutter_width,
-    hint_line_prefix,
+    hint_line_prefix, paint_focus_bar,
 };
 use super::{
     DrawNodesOptions, DrawNodesState, FocusCursorState, RendererConfig, StepContentRender,
@@ -32,6 +35,12 @@ pub(super) fn active_focus_id(
     }
 }
 
+/// Terminals at least this wide show field help in a column beside the
+/// fields instead of below the focused one.
+const HELP_PANEL_MIN_WIDTH: u16 = 100;
+const HELP_PANEL_WIDTH: u16 = 36;
+const HELP_PANEL_GAP: u16 = 2;
+
 pub(super) fn render_step_content(
     view: &RenderView<'_>,
     step: &Step,
@@ -40,58 +49,99 @@ pub(super) fn render_step_content(
     node_terminal_size: TerminalSize,
     compose_width: u16,
 ) -> StepContentRender {
+    let help = focused_id.and_then(|id| step.help_for(id));
+    // The column is kept for every field of the step, so moving the focus
+    // does not reflow the fields.
+    let help_beside = status_allows_interaction(status)
+        && step.layout.is_none()
+        && step.has_help()
+        && compose_width >= HELP_PANEL_MIN_WIDTH;
+    let full_width = compose_width;
+    let (node_terminal_size, compose_width) = if help_beside {
+        let reserved = HELP_PANEL_WIDTH + HELP_PANEL_GAP;
+        (
+            TerminalSize {
+                width: node_terminal_size.width.saturating_sub(reserved),
+                height: node_terminal_size.height,
+            },
+            compose_width - reserved,
+        )
+    } else {
+        (node_terminal_size, compose_width)
+    };
+
     let mut content = StepContentRender::default();
     let mut row_offset: u16 = 0;
 
     if !step.prompt.trim().is_empty() {
-        content.lines.push(vec![Span::styled(
-            format!("{} [{}]", step.prompt, step.id),
+        let mut title = vec![Span::styled(
+            format!("{} [{}]", view.messages.translate(&step.prompt), step.id),
             step_title_style(status),
-        )]);
+        )];
+        if status_allows_interaction(status)
+            && let Some(remaining) = view.auto_advance_remaining
+        {
+            title.push(auto_advance_countdown_span(remaining));
+        }
+        if status == StepVisualStatus::Running && view.submit_pending {
+            title.push(Span::styled("  running checks…", StyleRole::Hint.style()).no_wrap());
+        }
+        content.lines.push(title);
         row_offset = row_offset.saturating_add(1);
     }
 
     if let Some(description) = step.description.as_deref() {
-        content.lines.push(vec![Span::styled(
-            format!("Description: {}", description),
+        content.lines.push(markup::parse(
+            format!("Description: {}", view.messages.translate(description)).as_str(),
             step_description_style(status),
-        )]);
+        ));
         row_offset = row_offset.saturating_add(1);
     }
 
     let is_active_interaction_pass =
         status_allows_interaction(status) && !view.has_blocking_overlay;
     let ctx = render_context_for_nodes(
-        view.validation,
-        view.completion.as_ref(),
+        view,
         node_terminal_size,
         status,
         step.nodes.as_slice(),
         focused_id,
     );
     let mut hit_row_offset = Layout::compose(&content.lines, compose_width).len() as u16;
-    let mut draw_state = DrawNodesState {
-        lines: &mut content.lines,
-        sticky: Some(&mut content.sticky),
-        cursor: &mut content.cursor,
-        focus_anchor: &mut content.focus_anchor,
-        cursor_visible: &mut content.cursor_visible,
-        row_offset: &mut row_offset,
-        hit_map: Some(&mut content.hit_map),
-        hit_row_offset: Some(&mut hit_row_offset),
-        hit_col_start: 0,
-        compose_width,
+    let options = DrawNodesOptions {
+        track_cursor: is_active_interaction_pass,
+        strikethrough_inputs: status == StepVisualStatus::Cancelled,
+        collect_sticky: is_active_interaction_pass,
     };
-    draw_nodes(
-        step.nodes.as_slice(),
-        &ctx,
-        &mut draw_state,
-        DrawNodesOptions {
-            track_cursor: is_active_interaction_pass,
-            strikethrough_inputs: status == StepVisualStatus::Cancelled,
-            collect_sticky: is_active_interaction_pass,
-        },
-    );
+    if let Some(layout) = step.layout.as_ref() {
+        let mut pane = render_layout(layout, step.nodes.as_slice(), &ctx, compose_width, options);
+        pane.hit_map.shift_rows(hit_row_offset);
+        content.hit_map.extend(pane.hit_map);
+        content.cursor = pane.cursor.map(|cursor| CursorPos {
+            col: cursor.col,
+            row: cursor.row.saturating_add(row_offset),
+        });
+        content.cursor_visible = pane.cursor_visible;
+        content.focus_anchor = pane
+            .focus_anchor
+            .map(|anchor| anchor.saturating_add(row_offset));
+        content.lines.append(&mut pane.lines);
+        content.sticky.append(&mut pane.sticky);
+    } else {
+        let mut draw_state = DrawNodesState {
+            lines: &mut content.lines,
+            sticky: Some(&mut content.sticky),
+            cursor: &mut content.cursor,
+            focus_anchor: &mut content.focus_anchor,
+            cursor_visible: &mut content.cursor_visible,
+            row_offset: &mut row_offset,
+            hit_map: Some(&mut content.hit_map),
+            hit_row_offset: Some(&mut hit_row_offset),
+            hit_col_start: 0,
+            compose_width,
+        };
+        draw_nodes(step.nodes.as_slice(), &ctx, &mut draw_state, options);
+    }
 
     remap_block_layout_marker(
         &mut content.lines,
@@ -100,6 +150,26 @@ pub(super) fn render_step_content(
         &mut content.focus_anchor,
     );
 
+    if let Some((owner_id, help)) = help {
+        let help = view.messages.translate(help);
+        if help_beside {
+            place_help_beside(&mut content, owner_id, &help, compose_width);
+        } else {
+            // Panes of a layout sit side by side, so rows cannot be inserted
+            // under a single field; the help follows the whole layout.
+            let at = if step.layout.is_some() {
+                None
+            } else {
+                content
+                    .hit_map
+                    .node_bounds(owner_id)
+                    .map(|bounds| bounds.bottom)
+            };
+            let lines = help_lines(&help, view.hints_visible);
+            insert_help_rows(&mut content, at, Layout::compose(&lines, full_width));
+        }
+    }
+
     if let Some(tint) = step_content_tint(status) {
         tint_block(&mut content.lines, tint);
     }
@@ -107,6 +177,7 @@ pub(super) fn render_step_content(
     content
 }
 
+#[allow(clippy::too_many_arguments)]
 pub(super) fn apply_step_decoration<'a>(
     content: &mut StepContentRender,
     compose_width: u16,
@@ -116,6 +187,7 @@ pub(super) fn apply_step_decoration<'a>(
     config: RendererConfig,
     footer: Option<StepFrameFooter<'a>>,
     running_marker: char,
+    focused_id: Option<&str>,
 ) {
     if config.chrome_enabled {
         let include_top = idx == 0;
@@ -126,7 +198,7 @@ pub(super) fn apply_step_decoration<'a>(
             idx < render_up_to,
             status,
             include_top,
-            footer,
+            footer.as_ref(),
             running_marker,
         );
         if include_top {
@@ -135,11 +207,17 @@ pub(super) fn apply_step_decoration<'a>(
                 *anchor = anchor.saturating_add(1);
             }
         }
+        paint_focus_bar(
+            &mut content.lines,
+            &content.hit_map,
+            focused_id,
+            footer.as_ref(),
+        );
         content
             .hit_map
             .shift_cols(decoration_gutter_width().min(u16::MAX as usize) as u16);
     } else {
-        append_step_frame_footer_plain(&mut content.lines, compose_width, footer);
+        append_step_frame_footer_plain(&mut content.lines, compose_width, footer.as_ref());
     }
 }
 
@@ -178,11 +256,12 @@ pub(super) fn resolve_step_focus_cursor(
 pub(super) fn step_frame_footer<'a>(
     status: StepVisualStatus,
     view: &'a RenderView<'a>,
-    has_hints: bool,
+    hints: &'a StepHintsRender,
 ) -> Option<StepFrameFooter<'a>> {
+    let has_hints = hints.has_hints;
     if status == StepVisualStatus::Cancelled {
         return Some(StepFrameFooter::Error {
-            message: "Application terminated.",
+            message: view.messages.translate("Application terminated."),
             description: None,
             show_help_toggle: false,
         });
@@ -201,7 +280,7 @@ pub(super) fn step_frame_footer<'a>(
 
     if let Some(msg) = view.back_confirm {
         return Some(StepFrameFooter::Warning {
-            message: msg,
+            message: view.messages.translate(msg),
             description: Some("[Enter] confirm  •  [Esc] cancel"),
             show_help_toggle: false,
         });
@@ -209,7 +288,7 @@ pub(super) fn step_frame_footer<'a>(
 
     if let Some(msg) = view.step_errors.first() {
         return Some(StepFrameFooter::Error {
-            message: msg.as_str(),
+            message: view.messages.render(msg),
             description: None,
             show_help_toggle: has_hints,
         });
@@ -217,32 +296,114 @@ pub(super) fn step_frame_footer<'a>(
 
     if let Some(msg) = view.step_warnings.first() {
         return Some(StepFrameFooter::Warning {
-            message: msg.as_str(),
+            message: view.messages.translate(msg),
             description: Some("[Enter] confirm  •  [Esc] cancel"),
             show_help_toggle: false,
         });
     }
 
-    has_hints.then_some(StepFrameFooter::HelpToggle)
+    if !has_hints {
+        None
+    } else if view.hints_visible {
+        Some(StepFrameFooter::HelpToggle)
+    } else {
+        Some(StepFrameFooter::HintBar(hints.bar.as_slice()))
+    }
+}
+
+/// Help under a field shows its first line until the hints panel is opened.
+fn help_lines(help: &str, expanded: bool) -> Vec<SpanLine> {
+    let style = StyleRole::Hint.style();
+    let mut rows = help.lines().map(str::trim_end).collect::<Vec<_>>();
+    let more = !expanded && rows.len() > 1;
+    if !expanded {
+        rows.truncate(1);
+    }
+    let mut lines = rows
+        .into_iter()
+        .map(|row| {
+            let mut line = vec![Span::styled("│ ", style).no_wrap()];
+            line.extend(markup::parse(row, style));
+            line
+        })
+        .collect::<Vec<_>>();
+    if more && let Some(first) = lines.first_mut() {
+        first.push(Span::styled(" …", style).no_wrap());
+    }
+    lines
+}
+
+/// Inserts `lines` at row `at`, or below everything when `at` is `None`.
+fn insert_help_rows(content: &mut StepContentRender, at: Option<u16>, lines: Vec<SpanLine>) {
+    let at = at
+        .map(usize::from)
+        .unwrap_or(content.lines.len())
+        .min(content.lines.len());
+    let count = lines.len().min(u16::MAX as usize) as u16;
+    let at_row = at.min(u16::MAX as usize) as u16;
+    content.hit_map.insert_rows(at_row, count);
+    if let Some(cursor) = content.cursor.as_mut()
+        && cursor.row >= at_row
+    {
+        cursor.row = cursor.row.saturating_add(count);
+    }
+    if let Some(anchor) = content.focus_anchor.as_mut()
+        && *anchor >= at_row
+    {
+        *anchor = anchor.saturating_add(count);
+    }
+    content.lines.splice(at..at, lines);
+}
+
+/// Draws the help in the column right of the fields, level with the top of
+/// the field it explains.
+fn place_help_beside(
+    content: &mut StepContentRender,
+    owner_id: &str,
+    help: &str,
+    fields_width: u16,
+) {
+    let top = content
+        .hit_map
+        .node_bounds(owner_id)
+        .map_or(0, |bounds| usize::from(bounds.top));
+    let lines = Layout::compose(&help_lines(help, true), HELP_PANEL_WIDTH);
+    let needed = top + lines.len();
+    if content.lines.len() < needed {
+        content.lines.resize_with(needed, Vec::new);
+    }
+    let fields_width = usize::from(fields_width);
+    for (row, help_line) in content.lines[top..needed].iter_mut().zip(lines) {
+        let pad = fields_width.saturating_sub(Layout::line_width(row.as_slice()))
+            + usize::from(HELP_PANEL_GAP);
+        row.push(Span::new(" ".repeat(pad)).no_wrap());
+        row.extend(help_line);
+    }
+}
+
+fn auto_advance_countdown_span(remaining: Duration) -> Span {
+    let secs = remaining.as_millis().div_ceil(1000);
+    Span::styled(format!("  ⏱ continues in {secs}s"), StyleRole::Hint.style()).no_wrap()
 }
 
 pub(super) fn render_step_hints(
     status: StepVisualStatus,
     view: &RenderView<'_>,
     nodes: &[Node],
+    width: u16,
 ) -> StepHintsRender {
     if !status_allows_interaction(status) {
         return StepHintsRender::default();
     }
 
-    let hints = collect_hints(nodes, view.focused_id);
+    let hints = collect_hints(nodes, view.focused_id, &view.messages);
     let has_hints = !hints.is_empty();
     let has_active_warning_or_error = view.exit_confirm.is_some()
         || view.back_confirm.is_some()
         || !view.step_errors.is_empty()
         || !view.step_warnings.is_empty();
     let panel_lines = if view.hints_visible && !has_active_warning_or_error {
-        render_hints_panel_lines(hints)
+        render_hints_panel_lines(hints.as_slice())
     } else {
         Vec::new()
     };
@@ -250,6 +411,7 @@ pub(super) fn render_step_hints(
     StepHintsRender {
         has_hints,
         panel_lines,
+        bar: render_hint_bar(hints.as_slice(), usize::from(width)),
     }
 }
 
@@ -274,7 +436,7 @@ pub(super) fn append_step_hints_lines(
     }
 }
 
-fn remap_block_layout_marker(
+pub(super) fn remap_block_layout_marker(
     block_lines: &mut Vec<SpanLine>,
     compose_width: u16,
     block_cursor: &mut Option<CursorPos>,
@@ -297,7 +459,7 @@ fn remap_block_layout_marker(
 fn step_title_style(status: StepVisualStatus) -> Style {
     match status {
         StepVisualStatus::Active | StepVisualStatus::Running => Style::new().color(Color::Cyan),
-        StepVisualStatus::Done | StepVisualStatus::Pending => Style::new().color(Color::DarkGrey),
+        StepVisualStatus::Done | StepVisualStatus::Pending => StyleRole::Hint.style(),
         StepVisualStatus::Cancelled => Style::new().color(Color::Red),
     }
 }
@@ -305,7 +467,7 @@ fn step_title_style(status: StepVisualStatus) -> Style {
 fn step_description_style(status: StepVisualStatus) -> Style {
     match status {
         StepVisualStatus::Active | StepVisualStatus::Running => Style::new().color(Color::Yellow),
-        StepVisualStatus::Done | StepVisualStatus::Pending => Style::new().color(Color::DarkGrey),
+        StepVisualStatus::Done | StepVisualStatus::Pending => StyleRole::Hint.style(),
         StepVisualStatus::Cancelled => Style::new().color(Color::Red),
     }
 }
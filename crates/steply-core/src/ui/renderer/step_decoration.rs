@@ -1,9 +1,12 @@
 use super::StepVisualStatus;
 use crate::state::app::{ExitConfirmChoice, ExitConfirmMode};
 use crate::terminal::CursorPos;
+use crate::ui::hit_test::FrameHitMap;
 use crate::ui::layout::{Layout, LineContinuation, RenderBlock};
+use crate::ui::markup;
 use crate::ui::span::{Span, SpanLine};
-use crate::ui::style::{Color, Style};
+use crate::ui::style::{Color, Style, StyleRole};
+use std::borrow::Cow;
 
 const DECOR_GUTTER: &str = "│  ";
 const DECOR_GUTTER_WIDTH: usize = 3;
@@ -12,15 +15,15 @@ const DECOR_BOTTOM: &str = "└  ";
 const DECOR_EMPTY_CONT: &str = "   ";
 const DECOR_BRANCH: &str = "├  ";
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub(super) enum StepFrameFooter<'a> {
     Error {
-        message: &'a str,
+        message: Cow<'a, str>,
         description: Option<&'a str>,
         show_help_toggle: bool,
     },
     Warning {
-        message: &'a str,
+        message: Cow<'a, str>,
         description: Option<&'a str>,
         show_help_toggle: bool,
     },
@@ -29,8 +32,12 @@ pub(super) enum StepFrameFooter<'a> {
         choice: ExitConfirmChoice,
     },
     HelpToggle,
+    /// The focused widget's hints on one line, while the help panel is
+    /// closed.
+    HintBar(&'a [Span]),
 }
 
+#[allow(clippy::too_many_arguments)]
 pub(super) fn apply_step_frame(
     lines: &mut Vec<SpanLine>,
     cursor: &mut Option<CursorPos>,
@@ -38,7 +45,7 @@ pub(super) fn apply_step_frame(
     connect_to_next: bool,
     status: StepVisualStatus,
     include_top_line: bool,
-    footer: Option<StepFrameFooter<'_>>,
+    footer: Option<&StepFrameFooter<'_>>,
     running_marker: char,
 ) {
     let (decor_style, marker) = frame_style_and_marker(status, footer, running_marker);
@@ -87,7 +94,7 @@ pub(super) fn apply_step_frame(
 
 fn frame_style_and_marker(
     status: StepVisualStatus,
-    footer: Option<StepFrameFooter<'_>>,
+    footer: Option<&StepFrameFooter<'_>>,
     running_marker: char,
 ) -> (Style, String) {
     match footer {
@@ -97,13 +104,13 @@ fn frame_style_and_marker(
         Some(StepFrameFooter::Warning { .. } | StepFrameFooter::ExitConfirm { .. }) => {
             return (Style::new().color(Color::Yellow), "▲  ".to_string());
         }
-        Some(StepFrameFooter::HelpToggle) | None => {}
+        Some(StepFrameFooter::HelpToggle | StepFrameFooter::HintBar(_)) | None => {}
     }
 
     let style = match status {
         StepVisualStatus::Active => Style::new().color(Color::Green),
         StepVisualStatus::Running => Style::new().color(Color::Blue),
-        StepVisualStatus::Done | StepVisualStatus::Pending => Style::new().color(Color::DarkGrey),
+        StepVisualStatus::Done | StepVisualStatus::Pending => StyleRole::Hint.style(),
         StepVisualStatus::Cancelled => Style::new().color(Color::Red),
     };
     let marker = match status {
@@ -116,10 +123,38 @@ fn frame_style_and_marker(
     (style, marker)
 }
 
+/// Restyles the gutter beside the rows of the focused widget.
+pub(super) fn paint_focus_bar(
+    lines: &mut [SpanLine],
+    hit_map: &FrameHitMap,
+    focused_id: Option<&str>,
+    footer: Option<&StepFrameFooter<'_>>,
+) {
+    if !matches!(
+        footer,
+        None | Some(StepFrameFooter::HelpToggle | StepFrameFooter::HintBar(_))
+    ) {
+        return;
+    }
+    let Some(bounds) = focused_id.and_then(|id| hit_map.node_bounds(id)) else {
+        return;
+    };
+    let style = StyleRole::FocusBar.style();
+    for line in lines
+        .iter_mut()
+        .take(bounds.bottom as usize)
+        .skip(bounds.top as usize)
+    {
+        if let Some(gutter) = line.first_mut().filter(|span| span.text == DECOR_GUTTER) {
+            gutter.style = style;
+        }
+    }
+}
+
 pub(super) fn append_step_frame_footer_plain(
     lines: &mut Vec<SpanLine>,
     compose_width: u16,
-    footer: Option<StepFrameFooter<'_>>,
+    footer: Option<&StepFrameFooter<'_>>,
 ) {
     if let Some(footer) = footer {
         lines.extend(compose_plain_footer_lines(
@@ -165,17 +200,17 @@ fn compose_plain_footer_lines(lines: Vec<SpanLine>, compose_width: u16) -> Vec<S
 
 pub(super) fn help_toggle_line() -> SpanLine {
     vec![
-        Span::styled("Ctrl+h", Style::new().color(Color::DarkGrey).bold()).no_wrap(),
-        Span::styled(" Toggle help", Style::new().color(Color::DarkGrey)).no_wrap(),
+        Span::styled("Ctrl+h", StyleRole::Hint.style().bold()).no_wrap(),
+        Span::styled(" Toggle help", StyleRole::Hint.style()).no_wrap(),
     ]
 }
 
 fn footer_prefixes(
-    footer: StepFrameFooter<'_>,
+    footer: &StepFrameFooter<'_>,
     connect_to_next: bool,
 ) -> (&'static str, &'static str) {
     let first = match footer {
-        StepFrameFooter::HelpToggle => bottom_prefix(connect_to_next),
+        StepFrameFooter::HelpToggle | StepFrameFooter::HintBar(_) => bottom_prefix(connect_to_next),
         _ => {
             if connect_to_next {
                 DECOR_BRANCH
@@ -192,7 +227,7 @@ fn footer_prefixes(
     (first, cont)
 }
 
-fn footer_plain_lines(footer: StepFrameFooter<'_>) -> Vec<SpanLine> {
+fn footer_plain_lines(footer: &StepFrameFooter<'_>) -> Vec<SpanLine> {
     let mut lines = Vec::<SpanLine>::new();
     match footer {
         StepFrameFooter::Error {
@@ -200,14 +235,14 @@ fn footer_plain_lines(footer: StepFrameFooter<'_>) -> Vec<SpanLine> {
             description,
             show_help_toggle,
         } => {
-            lines.push(vec![Span::styled(message, Style::new().color(Color::Red))]);
+            lines.push(markup::parse(
+                message.as_ref(),
+                Style::new().color(Color::Red),
+            ));
             if let Some(desc) = description {
-                lines.push(vec![Span::styled(
-                    desc,
-                    Style::new().color(Color::DarkGrey),
-                )]);
+                lines.push(vec![Span::styled(*desc, StyleRole::Hint.style())]);
             }
-            if show_help_toggle {
+            if *show_help_toggle {
                 lines.push(help_toggle_line());
             }
         }
@@ -216,33 +251,33 @@ fn footer_plain_lines(footer: StepFrameFooter<'_>) -> Vec<SpanLine> {
             description,
             show_help_toggle,
         } => {
-            lines.push(vec![Span::styled(
-                message,
+            lines.push(markup::parse(
+                message.as_ref(),
                 Style::new().color(Color::Yellow),
-            )]);
+            ));
             if let Some(desc) = description {
-                lines.push(vec![Span::styled(
-                    desc,
-                    Style::new().color(Color::DarkGrey),
-                )]);
+                lines.push(vec![Span::styled(*desc, StyleRole::Hint.style())]);
             }
-            if show_help_toggle {
+            if *show_help_toggle {
                 lines.push(help_toggle_line());
             }
             lines.push(vec![Span::new("")]);
         }
         StepFrameFooter::ExitConfirm { mode, choice } => {
-            lines.push(exit_confirm_line(mode, choice));
+            lines.push(exit_confirm_line(*mode, *choice));
         }
         StepFrameFooter::HelpToggle => {
             lines.push(help_toggle_line());
         }
+        StepFrameFooter::HintBar(line) => {
+            lines.push(line.to_vec());
+        }
     }
     lines
 }
 
 fn exit_confirm_line(mode: ExitConfirmMode, choice: ExitConfirmChoice) -> SpanLine {
-    let inactive = Style::new().color(Color::DarkGrey);
+    let inactive = StyleRole::Hint.style();
     let active = Style::new().color(Color::White).bold();
     let (no_style, yes_style) = match choice {
         ExitConfirmChoice::Stay => (active, inactive),
@@ -251,6 +286,7 @@ fn exit_confirm_line(mode: ExitConfirmMode, choice: ExitConfirmChoice) -> SpanLi
     let prompt = match mode {
         ExitConfirmMode::ExitApplication => "Exit application? ",
         ExitConfirmMode::FinishFlow => "Are we done? ",
+        ExitConfirmMode::RestoreSession => "Restore answers from the crashed session? ",
     };
 
     vec![
@@ -303,5 +339,5 @@ pub(super) fn inline_modal_separator_line(
     }
     let rule = chars.into_iter().collect::<String>();
 
-    vec![Span::styled(rule, Style::new().color(Color::DarkGrey)).no_wrap()]
+    vec![Span::styled(rule, StyleRole::Border.style()).no_wrap()]
 }
@@ -0,0 +1,32 @@
+use super::render_hint_bar;
+use crate::widgets::traits::{HintGroup, HintItem};
+
+fn text(line: &[crate::ui::span::Span]) -> String {
+    line.iter().map(|span| span.text.as_str()).collect()
+}
+
+fn hints() -> Vec<HintItem> {
+    vec![
+        HintItem::new("↑ ↓", "move", HintGroup::Navigation),
+        HintItem::new("Enter", "confirm", HintGroup::Action),
+        HintItem::new("Space", "toggle selection", HintGroup::Action),
+        HintItem::new("Ctrl+F", "toggle filter", HintGroup::View),
+    ]
+}
+
+#[test]
+fn hint_bar_shows_every_hint_that_fits() {
+    assert_eq!(
+        text(&render_hint_bar(&hints(), 80)),
+        "↑ ↓ move  Enter confirm  Space toggle selection  Ctrl+F toggle filter"
+    );
+}
+
+#[test]
+fn hint_bar_drops_the_last_hints_and_counts_them() {
+    assert_eq!(
+        text(&render_hint_bar(&hints(), 50)),
+        "↑ ↓ move  Enter confirm  … 2 more (Ctrl+h)"
+    );
+    assert_eq!(text(&render_hint_bar(&hints(), 10)), "… 4 more (Ctrl+h)");
+}
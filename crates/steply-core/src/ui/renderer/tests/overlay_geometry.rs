@@ -0,0 +1,58 @@
+use super::anchor_overlay_placement;
+use crate::terminal::TerminalSize;
+use crate::ui::hit_test::HitBounds;
+use crate::widgets::traits::{OverlayPlacement, PopoverSide};
+
+const SIZE: TerminalSize = TerminalSize {
+    width: 40,
+    height: 20,
+};
+
+fn anchor_at(row: u16, col: u16) -> HitBounds {
+    HitBounds {
+        top: row,
+        bottom: row + 1,
+        left: col,
+        right: col + 10,
+    }
+}
+
+#[test]
+fn auto_popover_opens_below_when_it_fits() {
+    let placement = OverlayPlacement::new(0, 0, 12, 6);
+    let anchored = anchor_overlay_placement(placement, anchor_at(4, 3), PopoverSide::Auto, SIZE, 8);
+    assert_eq!((anchored.row, anchored.col), (5, 3));
+    assert_eq!((anchored.width, anchored.height), (12, 6));
+}
+
+#[test]
+fn auto_popover_flips_above_near_the_bottom_edge() {
+    let placement = OverlayPlacement::new(0, 0, 12, 6);
+    let anchored =
+        anchor_overlay_placement(placement, anchor_at(17, 3), PopoverSide::Auto, SIZE, 18);
+    assert_eq!(anchored.row, 11);
+
+    let forced =
+        anchor_overlay_placement(placement, anchor_at(17, 3), PopoverSide::Below, SIZE, 18);
+    assert_eq!(forced.row, 18);
+}
+
+#[test]
+fn popover_is_pulled_left_to_stay_on_screen() {
+    let placement = OverlayPlacement::new(0, 0, 12, 6);
+    let anchored =
+        anchor_overlay_placement(placement, anchor_at(2, 35), PopoverSide::Above, SIZE, 8);
+    assert_eq!((anchored.row, anchored.col), (0, 28));
+}
+
+#[test]
+fn popover_is_clipped_to_the_room_on_its_side_and_the_screen_width() {
+    let placement = OverlayPlacement::new(0, 0, 60, 10);
+    let anchored =
+        anchor_overlay_placement(placement, anchor_at(3, 5), PopoverSide::Above, SIZE, 8);
+    assert_eq!((anchored.row, anchored.col), (0, 0));
+    assert_eq!((anchored.width, anchored.height), (40, 3));
+
+    let below = anchor_overlay_placement(placement, anchor_at(14, 5), PopoverSide::Below, SIZE, 15);
+    assert_eq!((below.row, below.height), (15, 5));
+}
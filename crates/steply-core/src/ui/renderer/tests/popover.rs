@@ -0,0 +1,75 @@
+use super::apply_completion_popover;
+use crate::terminal::{CursorPos, TerminalSize};
+use crate::ui::render_view::CompletionSnapshot;
+use crate::ui::renderer::RenderFrame;
+use crate::ui::span::Span;
+
+const SIZE: TerminalSize = TerminalSize {
+    width: 30,
+    height: 12,
+};
+
+fn frame_with_cursor(lines: usize, row: u16, col: u16) -> RenderFrame {
+    RenderFrame {
+        lines: (0..lines).map(|_| vec![Span::new("")]).collect(),
+        cursor: Some(CursorPos { row, col }),
+        ..RenderFrame::default()
+    }
+}
+
+fn snapshot(matches: &[&str], selected: usize) -> CompletionSnapshot {
+    CompletionSnapshot {
+        owner: "name".to_string(),
+        matches: matches.iter().map(|item| item.to_string()).collect(),
+        selected,
+        start: 0,
+    }
+}
+
+fn text(frame: &RenderFrame) -> Vec<String> {
+    frame
+        .lines
+        .iter()
+        .map(|line| line.iter().map(|span| span.text.as_str()).collect())
+        .collect()
+}
+
+#[test]
+fn matches_float_below_the_cursor_without_moving_other_lines() {
+    let mut frame = frame_with_cursor(8, 1, 4);
+    let completion = snapshot(&["alpha", "beta", "gamma"], 1);
+
+    apply_completion_popover(Some(&completion), SIZE, &mut frame);
+
+    let lines = text(&frame);
+    assert_eq!(lines.len(), 8);
+    assert!(lines[2].starts_with("    ┌"));
+    assert!(lines[3].contains("│ alpha │"));
+    assert!(lines[4].contains("│ beta  │"));
+    assert!(lines[5].contains("│ gamma │"));
+    assert!(lines[6].starts_with("    └"));
+}
+
+#[test]
+fn a_single_match_is_left_to_the_ghost_text() {
+    let mut frame = frame_with_cursor(3, 0, 0);
+
+    apply_completion_popover(Some(&snapshot(&["alpha"], 0)), SIZE, &mut frame);
+
+    assert!(text(&frame).iter().all(|line| line.is_empty()));
+}
+
+#[test]
+fn near_the_bottom_the_list_opens_above_and_follows_the_selection() {
+    let mut frame = frame_with_cursor(12, 10, 2);
+    let items = (0..20).map(|i| format!("item{i:02}")).collect::<Vec<_>>();
+    let items = items.iter().map(String::as_str).collect::<Vec<_>>();
+
+    apply_completion_popover(Some(&snapshot(&items, 15)), SIZE, &mut frame);
+
+    let lines = text(&frame);
+    assert!(lines[0].contains('┌'));
+    assert!(lines[1].contains("item08"));
+    assert!(lines[8].contains("item15"));
+    assert!(lines[9].contains('└'));
+}
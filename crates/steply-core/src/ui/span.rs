@@ -45,3 +45,71 @@ impl Span {
 }
 
 pub type SpanLine = Vec<Span>;
+
+/// Restyles the characters of `spans` inside `ranges` (character offsets
+/// across the whole line) with `highlight` merged over their style.
+pub fn highlight_span_line(spans: &mut SpanLine, ranges: &[(usize, usize)], highlight: Style) {
+    if ranges.is_empty() {
+        return;
+    }
+
+    let mut sorted = ranges.to_vec();
+    sorted.sort_unstable_by(|left, right| left.0.cmp(&right.0).then(left.1.cmp(&right.1)));
+    let mut merged = Vec::<(usize, usize)>::new();
+    for (start, end) in sorted {
+        if start >= end {
+            continue;
+        }
+        if let Some((_, last_end)) = merged.last_mut()
+            && start <= *last_end
+        {
+            *last_end = (*last_end).max(end);
+            continue;
+        }
+        merged.push((start, end));
+    }
+    if merged.is_empty() {
+        return;
+    }
+
+    let source = spans.clone();
+    let mut out = Vec::<Span>::new();
+    let mut global = 0usize;
+    for span in source {
+        let chars: Vec<char> = span.text.chars().collect();
+        if chars.is_empty() {
+            continue;
+        }
+        let mut idx = 0usize;
+        while idx < chars.len() {
+            let abs = global + idx;
+            let marked = merged
+                .iter()
+                .any(|(start, end)| abs >= *start && abs < *end);
+            let mut end_idx = idx + 1;
+            while end_idx < chars.len() {
+                let abs_next = global + end_idx;
+                let marked_next = merged
+                    .iter()
+                    .any(|(start, end)| abs_next >= *start && abs_next < *end);
+                if marked_next != marked {
+                    break;
+                }
+                end_idx += 1;
+            }
+            let text: String = chars[idx..end_idx].iter().collect();
+            let style = if marked {
+                span.style.merge(highlight)
+            } else {
+                span.style
+            };
+            out.push(Span::styled(text, style).no_wrap());
+            idx = end_idx;
+        }
+        global += chars.len();
+    }
+
+    if !out.is_empty() {
+        *spans = out;
+    }
+}
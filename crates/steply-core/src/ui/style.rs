@@ -1,3 +1,5 @@
+use crate::terminal::ColorDepth;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Color {
     Reset,
@@ -10,9 +12,162 @@ pub enum Color {
     Magenta,
     Cyan,
     White,
+    /// An entry of the 256-color palette.
+    Indexed(u8),
     Rgb(u8, u8, u8),
 }
 
+// Approximate RGB of the basic colors as the terminal writer emits them.
+const BASIC_PALETTE: &[(Color, (u8, u8, u8))] = &[
+    (Color::Black, (0, 0, 0)),
+    (Color::DarkGrey, (128, 128, 128)),
+    (Color::Red, (255, 85, 85)),
+    (Color::Green, (85, 255, 85)),
+    (Color::Yellow, (205, 205, 0)),
+    (Color::Blue, (0, 0, 205)),
+    (Color::Magenta, (205, 0, 205)),
+    (Color::Cyan, (0, 205, 205)),
+    (Color::White, (255, 255, 255)),
+];
+
+const XTERM_16: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+impl Color {
+    /// Parses a color name as used in frame JSON (`cyan`, `dark_grey`) or
+    /// a `#rrggbb` hex value.
+    pub fn parse(text: &str) -> Option<Color> {
+        let text = text.trim();
+        if let Some(hex) = text.strip_prefix('#') {
+            if hex.len() != 6 || !hex.is_ascii() {
+                return None;
+            }
+            let channel = |range| u8::from_str_radix(&hex[range], 16).ok();
+            return Some(Color::Rgb(channel(0..2)?, channel(2..4)?, channel(4..6)?));
+        }
+        let color = match text.to_ascii_lowercase().replace(['-', ' '], "_").as_str() {
+            "reset" => Color::Reset,
+            "black" => Color::Black,
+            "dark_grey" | "dark_gray" | "grey" | "gray" => Color::DarkGrey,
+            "red" => Color::Red,
+            "green" => Color::Green,
+            "yellow" => Color::Yellow,
+            "blue" => Color::Blue,
+            "magenta" => Color::Magenta,
+            "cyan" => Color::Cyan,
+            "white" => Color::White,
+            _ => return None,
+        };
+        Some(color)
+    }
+
+    /// How the color reads on a terminal without colors.
+    pub fn contrast(self) -> Contrast {
+        let (r, g, b) = match self {
+            Color::Reset | Color::White => return Contrast::Normal,
+            Color::Black | Color::DarkGrey => return Contrast::Dim,
+            Color::Indexed(index) => ansi256_to_rgb(index),
+            Color::Rgb(r, g, b) => (r, g, b),
+            _ => return Contrast::Strong,
+        };
+        let (max, min) = (r.max(g).max(b), r.min(g).min(b));
+        if max - min > 48 {
+            Contrast::Strong
+        } else if max < 160 {
+            Contrast::Dim
+        } else {
+            Contrast::Normal
+        }
+    }
+
+    /// The closest color the terminal can show at `depth`. Monochrome has
+    /// none to pick from; callers drop the color and use [`Color::contrast`].
+    pub fn for_depth(self, depth: ColorDepth) -> Color {
+        match (self, depth) {
+            (_, ColorDepth::TrueColor) => self,
+            (Color::Rgb(r, g, b), ColorDepth::Ansi256) => Color::Indexed(rgb_to_ansi256(r, g, b)),
+            (Color::Rgb(r, g, b), ColorDepth::Ansi16) => nearest_basic((r, g, b)),
+            (Color::Indexed(index), ColorDepth::Ansi16) => nearest_basic(ansi256_to_rgb(index)),
+            _ => self,
+        }
+    }
+}
+
+/// How text in a color stands out once colors are dropped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Contrast {
+    /// Hints, placeholders and disabled text.
+    Dim,
+    Normal,
+    /// Accents such as the focused row, errors and success marks.
+    Strong,
+}
+
+fn rgb_to_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    if r == g && g == b {
+        return match r {
+            0..8 => 16,
+            249.. => 231,
+            _ => 232 + ((r - 8) / 10).min(23),
+        };
+    }
+    let level = |value: u8| match value {
+        0..48 => 0,
+        48..115 => 1,
+        _ => (value - 35) / 40,
+    };
+    16 + 36 * level(r) + 6 * level(g) + level(b)
+}
+
+fn ansi256_to_rgb(index: u8) -> (u8, u8, u8) {
+    const LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+    match index {
+        0..16 => XTERM_16[index as usize],
+        16..232 => {
+            let cube = index - 16;
+            (
+                LEVELS[(cube / 36) as usize],
+                LEVELS[((cube / 6) % 6) as usize],
+                LEVELS[(cube % 6) as usize],
+            )
+        }
+        _ => {
+            let level = 8 + (index - 232) * 10;
+            (level, level, level)
+        }
+    }
+}
+
+fn nearest_basic((r, g, b): (u8, u8, u8)) -> Color {
+    let distance = |(pr, pg, pb): (u8, u8, u8)| {
+        let dr = r as i32 - pr as i32;
+        let dg = g as i32 - pg as i32;
+        let db = b as i32 - pb as i32;
+        dr * dr + dg * dg + db * db
+    };
+    BASIC_PALETTE
+        .iter()
+        .min_by_key(|(_, rgb)| distance(*rgb))
+        .map(|(color, _)| *color)
+        .unwrap_or(Color::White)
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum Strike {
     #[default]
@@ -21,13 +176,97 @@ pub enum Strike {
     Off,
 }
 
+/// Underline shape. Terminals without styled underlines draw every shape
+/// as a straight line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Underline {
+    #[default]
+    None,
+    Straight,
+    Double,
+    Curly,
+    Dotted,
+    Dashed,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct Style {
     pub color: Option<Color>,
     pub background: Option<Color>,
     pub bold: bool,
+    /// Faint intensity; overridden by `bold`.
+    pub dim: bool,
+    pub italic: bool,
+    /// Swaps the text and background colors.
+    pub reverse: bool,
+    pub underline: Underline,
+    /// Defaults to the text color.
+    pub underline_color: Option<Color>,
 
     pub strike: Strike,
+    /// What the text is for, so a theme can restyle it; see [`StyleRole`].
+    pub role: Option<StyleRole>,
+}
+
+/// What a piece of text is for. Widgets draw a role with its default look
+/// from [`StyleRole::style`]; themes restyle every span of a role at once,
+/// or only inside one widget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StyleRole {
+    Label,
+    /// Secondary text: descriptions, placeholders, inactive options.
+    Hint,
+    Error,
+    /// The marker in front of the active row.
+    Cursor,
+    /// The row or item under the cursor of a focused widget.
+    Active,
+    /// Markers of chosen options.
+    Selected,
+    Border,
+    /// The label of the focused widget.
+    Focus,
+    /// The step gutter beside the focused widget.
+    FocusBar,
+    /// The filled part of progress bars.
+    Progress,
+}
+
+impl StyleRole {
+    pub fn parse(text: &str) -> Option<Self> {
+        let role = match text.trim().to_ascii_lowercase().as_str() {
+            "label" => Self::Label,
+            "hint" => Self::Hint,
+            "error" => Self::Error,
+            "cursor" => Self::Cursor,
+            "active" => Self::Active,
+            "selected" => Self::Selected,
+            "border" => Self::Border,
+            "focus" => Self::Focus,
+            "focus_bar" => Self::FocusBar,
+            "progress" => Self::Progress,
+            _ => return None,
+        };
+        Some(role)
+    }
+
+    /// The role's default look, tagged so a theme can find it.
+    pub fn style(self) -> Style {
+        let style = match self {
+            Self::Label => Style::new(),
+            Self::Hint | Self::Border => Style::new().color(Color::DarkGrey),
+            Self::Error => Style::new().color(Color::Red).bold(),
+            Self::Cursor => Style::new().color(Color::Yellow),
+            Self::Active => Style::new().color(Color::Cyan).bold(),
+            Self::Selected | Self::FocusBar => Style::new().color(Color::Green),
+            Self::Focus => Style::new().color(Color::White),
+            Self::Progress => Style::new().color(Color::Green).bold(),
+        };
+        Style {
+            role: Some(self),
+            ..style
+        }
+    }
 }
 
 impl Style {
@@ -50,6 +289,31 @@ impl Style {
         self
     }
 
+    pub fn dim(mut self) -> Self {
+        self.dim = true;
+        self
+    }
+
+    pub fn italic(mut self) -> Self {
+        self.italic = true;
+        self
+    }
+
+    pub fn reverse(mut self) -> Self {
+        self.reverse = true;
+        self
+    }
+
+    pub fn underline(mut self, underline: Underline) -> Self {
+        self.underline = underline;
+        self
+    }
+
+    pub fn underline_color(mut self, color: Color) -> Self {
+        self.underline_color = Some(color);
+        self
+    }
+
     pub fn strikethrough(mut self) -> Self {
         self.strike = Strike::On;
         self
@@ -70,10 +334,19 @@ impl Style {
             color: extra.color.or(self.color),
             background: extra.background.or(self.background),
             bold: self.bold || extra.bold,
+            dim: self.dim || extra.dim,
+            italic: self.italic || extra.italic,
+            reverse: self.reverse || extra.reverse,
+            underline: match extra.underline {
+                Underline::None => self.underline,
+                underline => underline,
+            },
+            underline_color: extra.underline_color.or(self.underline_color),
             strike: match extra.strike {
                 Strike::Inherit => self.strike,
                 s => s,
             },
+            role: extra.role.or(self.role),
         }
     }
 
@@ -82,7 +355,13 @@ impl Style {
             color: extra.color.or(self.color),
             background: extra.background.or(self.background),
             bold: extra.bold,
+            dim: extra.dim,
+            italic: extra.italic,
+            reverse: extra.reverse,
+            underline: extra.underline,
+            underline_color: extra.underline_color,
             strike: extra.strike,
+            role: extra.role.or(self.role),
         }
     }
 }
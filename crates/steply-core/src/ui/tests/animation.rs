@@ -0,0 +1,61 @@
+use super::{Animations, Easing, Tween};
+use crate::time::{Duration, Instant};
+
+const MS: Duration = Duration::from_millis(1);
+
+#[test]
+fn easings_start_at_zero_and_end_at_one() {
+    for easing in [
+        Easing::Linear,
+        Easing::OutQuad,
+        Easing::OutCubic,
+        Easing::InOutCubic,
+    ] {
+        assert_eq!(easing.apply(0.0), 0.0);
+        assert_eq!(easing.apply(1.0), 1.0);
+        assert!(easing.apply(0.5) > 0.0 && easing.apply(0.5) < 1.0);
+    }
+    assert!(Easing::OutCubic.apply(0.25) > Easing::Linear.apply(0.25));
+    assert!(Easing::InOutCubic.apply(0.25) < Easing::Linear.apply(0.25));
+}
+
+#[test]
+fn tweens_are_sampled_at_any_instant() {
+    let start = Instant::now();
+    let tween = Tween::new(10.0, 20.0, MS * 100, Easing::Linear, start);
+    assert_eq!(tween.value_at(start), 10.0);
+    assert_eq!(tween.value_at(start + MS * 50), 15.0);
+    assert_eq!(tween.value_at(start + MS * 500), 20.0);
+    assert!(!tween.is_done(start + MS * 99));
+    assert!(tween.is_done(start + MS * 100));
+}
+
+#[test]
+fn restarting_a_key_continues_from_its_current_value() {
+    let start = Instant::now();
+    let mut animations = Animations::default();
+    animations.start("scroll", 0.0, 10.0, MS * 100, Easing::Linear, start);
+    animations.start(
+        "scroll",
+        0.0,
+        0.0,
+        MS * 100,
+        Easing::Linear,
+        start + MS * 50,
+    );
+    assert_eq!(animations.value("scroll", start + MS * 50), Some(5.0));
+    assert_eq!(animations.value("scroll", start + MS * 100), Some(2.5));
+}
+
+#[test]
+fn ticks_drop_finished_tweens() {
+    let start = Instant::now();
+    let mut animations = Animations::default();
+    assert!(!animations.tick(start));
+    animations.start("row", 0.0, 1.0, MS * 100, Easing::OutCubic, start);
+    assert!(animations.tick(start + MS * 50));
+    assert!(animations.is_running());
+    assert!(animations.tick(start + MS * 100));
+    assert!(!animations.is_running());
+    assert_eq!(animations.value("row", start + MS * 100), None);
+}
@@ -0,0 +1,32 @@
+use super::{Canvas, CanvasAlign};
+
+#[test]
+fn default_canvas_fills_the_terminal() {
+    assert_eq!(Canvas::default().place(120), (0, 120));
+}
+
+#[test]
+fn caps_the_width_and_centers_in_what_is_left() {
+    let canvas = Canvas {
+        max_width: Some(100),
+        align: CanvasAlign::Center,
+        padding: 2,
+    };
+    assert_eq!(canvas.place(202), (52, 100));
+    assert_eq!(canvas.place(80), (2, 78));
+
+    let left = Canvas {
+        align: CanvasAlign::Left,
+        ..canvas
+    };
+    assert_eq!(left.place(202), (2, 100));
+}
+
+#[test]
+fn padding_never_eats_the_last_column() {
+    let canvas = Canvas {
+        padding: 10,
+        ..Canvas::default()
+    };
+    assert_eq!(canvas.place(4), (3, 1));
+}
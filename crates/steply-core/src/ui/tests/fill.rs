@@ -0,0 +1,36 @@
+use super::{SELECTION_BACKGROUND, fill_background, selection_bar};
+use crate::ui::span::Span;
+use crate::ui::style::{Color, Style};
+
+#[test]
+fn pads_the_line_and_paints_every_cell() {
+    let line = fill_background(vec![Span::new("ab")], 5, Color::Blue);
+    let text: String = line.iter().map(|span| span.text.as_str()).collect();
+    assert_eq!(text, "ab   ");
+    assert!(
+        line.iter()
+            .all(|span| span.style.background == Some(Color::Blue))
+    );
+}
+
+#[test]
+fn keeps_backgrounds_spans_already_have() {
+    let line = fill_background(
+        vec![
+            Span::styled("x", Style::new().background(Color::Red)),
+            Span::new("y"),
+        ],
+        2,
+        Color::Blue,
+    );
+    assert_eq!(line[0].style.background, Some(Color::Red));
+    assert_eq!(line[1].style.background, Some(Color::Blue));
+}
+
+#[test]
+fn selection_bars_clip_overflowing_rows() {
+    let line = selection_bar(vec![Span::new("abcdef").no_wrap()], 4);
+    let text: String = line.iter().map(|span| span.text.as_str()).collect();
+    assert_eq!(text, "abcd");
+    assert_eq!(line[0].style.background, Some(SELECTION_BACKGROUND));
+}
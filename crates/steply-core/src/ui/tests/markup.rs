@@ -0,0 +1,50 @@
+use super::{parse, plain_text};
+use crate::ui::span::Span;
+use crate::ui::style::{Color, Style};
+
+fn texts(spans: &[Span]) -> Vec<&str> {
+    spans.iter().map(|span| span.text.as_str()).collect()
+}
+
+#[test]
+fn plain_text_stays_one_span() {
+    let base = Style::new().color(Color::Cyan);
+    assert_eq!(
+        parse("Project name", base),
+        vec![Span::styled("Project name", base)]
+    );
+}
+
+#[test]
+fn parses_bold_dim_and_colors_over_the_base_style() {
+    let base = Style::new().color(Color::Cyan);
+    let spans = parse("Pick **one** [red]region _only_[/] now", base);
+
+    assert_eq!(
+        texts(&spans),
+        ["Pick ", "one", " ", "region ", "only", " now"]
+    );
+    assert_eq!(spans[0].style, base);
+    assert!(spans[1].style.bold);
+    assert_eq!(spans[1].style.color, Some(Color::Cyan));
+    assert_eq!(spans[3].style.color, Some(Color::Red));
+    assert!(spans[4].style.dim);
+    assert_eq!(spans[4].style.color, Some(Color::Red));
+    assert_eq!(spans[5].style, base);
+}
+
+#[test]
+fn unmatched_markers_and_unknown_tags_stay_literal() {
+    assert_eq!(
+        plain_text("file_name and max_size"),
+        "file_name and max_size"
+    );
+    assert_eq!(plain_text("Name [optional]"), "Name [optional]");
+    assert_eq!(plain_text("2 ** 3"), "2 ** 3");
+    assert_eq!(plain_text("**open"), "**open");
+    assert_eq!(plain_text("[red]unclosed"), "[red]unclosed");
+    assert_eq!(
+        plain_text(r"\*\*not bold\*\* and \[red]"),
+        "**not bold** and [red]"
+    );
+}
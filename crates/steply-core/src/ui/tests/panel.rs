@@ -0,0 +1,41 @@
+use super::Panel;
+use crate::ui::span::{Span, SpanLine};
+use crate::ui::style::Color;
+use crate::widgets::traits::DrawOutput;
+
+fn text(lines: &[SpanLine]) -> Vec<String> {
+    lines
+        .iter()
+        .map(|line| line.iter().map(|span| span.text.as_str()).collect())
+        .collect()
+}
+
+#[test]
+fn boxes_content_with_title_and_padding() {
+    let panel = Panel::new().title("Name").padding(1);
+    let output = DrawOutput::with_lines(vec![vec![Span::new("Ada")]]);
+    let drawn = panel.render(output, 14, false);
+    assert_eq!(
+        text(&drawn.lines),
+        ["┌─ Name ─────┐", "│ Ada        │", "└────────────┘"]
+    );
+    assert_eq!(drawn.lines[0][0].style.color, Some(Color::DarkGrey));
+}
+
+#[test]
+fn focused_panels_highlight_the_border() {
+    let drawn = Panel::new().render(DrawOutput::default(), 6, true);
+    assert_eq!(text(&drawn.lines), ["┌────┐", "└────┘"]);
+    assert_eq!(drawn.lines[0][0].style.color, Some(Color::Cyan));
+}
+
+#[test]
+fn long_titles_and_lines_are_clipped_to_the_box() {
+    let panel = Panel::new().title("A very long title");
+    let output = DrawOutput::with_lines(vec![vec![Span::new("abcdefghij").no_wrap()]]);
+    let drawn = panel.render(output, 10, false);
+    assert_eq!(
+        text(&drawn.lines),
+        ["┌─ A ve ─┐", "│abcdefgh│", "└────────┘"]
+    );
+}
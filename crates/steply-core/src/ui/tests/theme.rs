@@ -0,0 +1,247 @@
+use super::Theme;
+use crate::ui::span::Span;
+use crate::ui::style::{Color, Style};
+
+const THEME: &str = r##"
+[palette]
+cyan = "#ff8700"
+dark_grey = 244
+
+[widgets.features]
+cyan = "magenta"
+
+[glyphs]
+"❯" = "▶"
+"##;
+
+#[test]
+fn parses_palette_widget_styles_and_glyphs() {
+    let theme = Theme::from_toml_str(THEME).expect("theme parses");
+
+    assert_eq!(theme.palette.map(Color::Cyan), Color::Rgb(0xff, 0x87, 0x00));
+    assert_eq!(theme.palette.map(Color::DarkGrey), Color::Indexed(244));
+    assert_eq!(theme.palette.map(Color::Green), Color::Green);
+    let features = theme.widget_palette("features").expect("widget palette");
+    assert_eq!(features.map(Color::Cyan), Color::Magenta);
+    assert!(theme.widget_palette("other").is_none());
+    assert_eq!(theme.glyphs.get(&'❯'), Some(&'▶'));
+}
+
+#[test]
+fn apply_swaps_colors_and_glyphs_in_a_frame() {
+    let theme = Theme::from_toml_str(THEME).expect("theme parses");
+    let mut lines = vec![vec![
+        Span::styled("❯ ", Style::new().color(Color::Cyan)),
+        Span::styled("name", Style::new().color(Color::White)),
+    ]];
+
+    theme.apply(&mut lines);
+
+    assert_eq!(lines[0][0].text, "▶ ");
+    assert_eq!(lines[0][0].style.color, Some(Color::Rgb(0xff, 0x87, 0x00)));
+    assert_eq!(lines[0][1].style.color, Some(Color::White));
+}
+
+#[test]
+fn rejects_invalid_themes() {
+    let unknown_key = Theme::from_toml_str("[colors]\ncyan = \"red\"").unwrap_err();
+    assert!(unknown_key.contains("colors"), "{unknown_key}");
+
+    let bad_color = Theme::from_toml_str("[palette]\ncyan = \"#12\"").unwrap_err();
+    assert!(bad_color.contains("#12"), "{bad_color}");
+
+    let wide_glyph = Theme::from_toml_str("[glyphs]\n\">\" = \"▶▶\"").unwrap_err();
+    assert!(wide_glyph.contains("single character"), "{wide_glyph}");
+
+    let width_mismatch = Theme::from_toml_str("[glyphs]\n\">\" = \"漢\"").unwrap_err();
+    assert!(width_mismatch.contains("as wide"), "{width_mismatch}");
+}
+
+#[test]
+fn role_styles_restyle_tagged_spans_per_widget_first() {
+    use crate::ui::style::StyleRole;
+
+    let theme = Theme::from_toml_str(
+        r##"
+[styles]
+hint = { color = 244, italic = true }
+"#features hint" = { color = "magenta" }
+"##,
+    )
+    .expect("theme parses");
+    let hint = || Span::styled("hint", StyleRole::Hint.style());
+    let mut widget = vec![vec![hint()]];
+    let mut frame = vec![vec![
+        hint(),
+        Span::styled("plain", Style::new().color(Color::DarkGrey)),
+    ]];
+
+    theme
+        .widget_styles("features")
+        .expect("widget styles")
+        .apply(&mut widget);
+    frame.extend(widget);
+    theme.apply(&mut frame);
+
+    assert_eq!(frame[0][0].style.color, Some(Color::Indexed(244)));
+    assert!(frame[0][0].style.italic);
+    assert_eq!(frame[0][1].style.color, Some(Color::DarkGrey));
+    assert!(!frame[0][1].style.italic);
+    assert_eq!(frame[1][0].style.color, Some(Color::Magenta));
+    assert!(!frame[1][0].style.italic);
+}
+
+#[test]
+fn rejects_unknown_style_selectors() {
+    let role = Theme::from_toml_str(
+        "[styles]
+heading = { bold = true }",
+    )
+    .unwrap_err();
+    assert!(role.contains("heading"), "{role}");
+
+    let selector = Theme::from_toml_str(
+        "[styles]
+\"a b c\" = { bold = true }",
+    )
+    .unwrap_err();
+    assert!(selector.contains("selector"), "{selector}");
+}
+
+#[test]
+fn picks_the_appearance_from_the_background_luminance() {
+    use super::Appearance;
+
+    assert_eq!(Appearance::from_background(0, 0, 0), Appearance::Dark);
+    assert_eq!(Appearance::from_background(40, 42, 54), Appearance::Dark);
+    assert_eq!(
+        Appearance::from_background(253, 246, 227),
+        Appearance::Light
+    );
+    assert_eq!(Appearance::parse(" Light "), Some(Appearance::Light));
+    assert_eq!(
+        Appearance::parse("high-contrast"),
+        Some(Appearance::HighContrast)
+    );
+    assert_eq!(Appearance::parse("auto"), None);
+}
+
+#[test]
+fn light_theme_darkens_hints_and_keeps_dark_unchanged() {
+    use super::Appearance;
+
+    let light = Theme::for_appearance(Appearance::Light);
+    assert_ne!(light.palette.map(Color::DarkGrey), Color::DarkGrey);
+    assert_eq!(light.palette.map(Color::Red), Color::Red);
+    assert_eq!(Theme::for_appearance(Appearance::Dark), Theme::default());
+}
+
+#[test]
+fn high_contrast_theme_brightens_hints_and_marks_the_cursor() {
+    use super::Appearance;
+    use crate::ui::style::{StyleRole, Underline};
+
+    let theme = Theme::for_appearance(Appearance::HighContrast);
+    let mut lines = vec![vec![
+        Span::styled("hint", StyleRole::Hint.style().dim()),
+        Span::styled("❯", StyleRole::Cursor.style()),
+        Span::styled("plain", Style::new().color(Color::DarkGrey)),
+    ]];
+
+    theme.apply(&mut lines);
+
+    assert_eq!(lines[0][0].style.color, Some(Color::White));
+    assert!(!lines[0][0].style.dim);
+    assert!(lines[0][1].style.bold);
+    assert_eq!(lines[0][1].style.underline, Underline::Straight);
+    assert_eq!(lines[0][1].style.color, Some(Color::Yellow));
+    assert_eq!(lines[0][2].style.color, Some(Color::White));
+}
+
+#[test]
+fn focus_styles_swap_the_cursor_glyph_and_reverse_the_active_row() {
+    use crate::ui::style::StyleRole;
+
+    let theme = Theme::from_toml_str(
+        r##"
+[styles]
+cursor = { glyph = "▶" }
+active = { reverse = true }
+"##,
+    )
+    .expect("theme parses");
+    let mut lines = vec![vec![
+        Span::styled("❯ ", StyleRole::Cursor.style()),
+        Span::styled("Rust", StyleRole::Active.style()),
+        Span::styled("❯ ", Style::new().color(Color::Yellow)),
+    ]];
+
+    theme.apply(&mut lines);
+
+    assert_eq!(lines[0][0].text, "▶ ");
+    assert!(lines[0][1].style.reverse);
+    assert_eq!(lines[0][1].text, "Rust");
+    assert_eq!(lines[0][2].text, "❯ ");
+
+    let wide = Theme::from_toml_str("[styles]\ncursor = { glyph = \"漢\" }").unwrap_err();
+    assert!(wide.contains("one column"), "{wide}");
+}
+
+#[test]
+fn focus_bar_and_label_follow_the_focused_widget() {
+    use crate::config::load_from_yaml_str;
+    use crate::terminal::TerminalSize;
+    use crate::ui::render_view::RenderView;
+    use crate::ui::renderer::{Renderer, RendererConfig};
+
+    let yaml = r#"
+steps:
+  - id: intro
+    title: Intro
+    widgets:
+      - type: text_input
+        id: name
+        label: Name
+      - type: text_input
+        id: email
+        label: Email
+"#;
+    let mut state = load_from_yaml_str(yaml)
+        .expect("load config")
+        .into_app_state()
+        .expect("app state");
+    state.set_theme(
+        Theme::from_toml_str(
+            r##"
+[styles]
+focus = { bold = true }
+focus_bar = { color = "magenta" }
+"##,
+        )
+        .expect("theme parses"),
+    );
+    let frame = Renderer::new(RendererConfig {
+        chrome_enabled: true,
+    })
+    .render(
+        &RenderView::from_state(&state),
+        TerminalSize {
+            width: 40,
+            height: 10,
+        },
+    );
+    let row = |label: &str| {
+        frame
+            .lines
+            .iter()
+            .find(|line| line.iter().any(|span| span.text.starts_with(label)))
+            .expect("row is drawn")
+    };
+
+    let name = row("Name");
+    assert_eq!(name[0].style.color, Some(Color::Magenta));
+    assert!(name[1].style.bold);
+    let email = row("Email");
+    assert_eq!(email[0].style.color, Some(Color::Green));
+    assert!(!email[1].style.bold);
+}
@@ -1,3 +1,4 @@
+use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 pub fn char_display_width(ch: char) -> usize {
@@ -8,22 +9,40 @@ pub fn text_display_width(text: &str) -> usize {
     UnicodeWidthStr::width(text)
 }
 
+/// User-perceived characters: an emoji ZWJ sequence, or a letter with its
+/// combining marks, is one cluster and is never split.
+pub fn graphemes(text: &str) -> impl DoubleEndedIterator<Item = &str> {
+    text.graphemes(true)
+}
+
+pub fn grapheme_display_width(grapheme: &str) -> usize {
+    UnicodeWidthStr::width(grapheme)
+}
+
+/// The first `char_count` chars of `text`.
+pub fn char_prefix(text: &str, char_count: usize) -> &str {
+    let end = text
+        .char_indices()
+        .nth(char_count)
+        .map_or(text.len(), |(idx, _)| idx);
+    &text[..end]
+}
+
 pub fn split_prefix_at_display_width(text: &str, max_width: usize) -> (&str, &str) {
     if max_width == 0 {
         return ("", text);
     }
 
     let mut used = 0usize;
-    for (byte_idx, ch) in text.char_indices() {
-        let ch_width = char_display_width(ch);
-        if used.saturating_add(ch_width) > max_width {
+    for (byte_idx, grapheme) in text.grapheme_indices(true) {
+        let width = grapheme_display_width(grapheme);
+        if used.saturating_add(width) > max_width {
             if byte_idx == 0 {
-                let next = ch.len_utf8();
-                return text.split_at(next);
+                return text.split_at(grapheme.len());
             }
             return text.split_at(byte_idx);
         }
-        used = used.saturating_add(ch_width);
+        used = used.saturating_add(width);
     }
 
     (text, "")
@@ -44,13 +63,13 @@ pub fn clip_to_display_width_without_linebreaks(text: &str, max_width: usize) ->
 
     let mut used = 0usize;
     let mut out = String::new();
-    for ch in text.chars().filter(|ch| !matches!(ch, '\n' | '\r')) {
-        let ch_width = char_display_width(ch);
-        if used.saturating_add(ch_width) > max_width {
+    for grapheme in graphemes(text).filter(|g| !matches!(*g, "\n" | "\r" | "\r\n")) {
+        let width = grapheme_display_width(grapheme);
+        if used.saturating_add(width) > max_width {
             break;
         }
-        out.push(ch);
-        used = used.saturating_add(ch_width);
+        out.push_str(grapheme);
+        used = used.saturating_add(width);
     }
     out
 }
@@ -0,0 +1,448 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use serde::Deserialize;
+use unicode_width::UnicodeWidthChar;
+
+use crate::ui::fill::SELECTION_BACKGROUND;
+use crate::ui::span::SpanLine;
+use crate::ui::style::{Color, Style, StyleRole, Underline};
+
+/// Whether the terminal draws on a dark or a light background. Widget
+/// colors are picked for dark backgrounds; light ones get [`Theme::light`].
+/// `HighContrast` is never detected, only asked for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Appearance {
+    #[default]
+    Dark,
+    Light,
+    HighContrast,
+}
+
+impl Appearance {
+    pub fn parse(text: &str) -> Option<Self> {
+        match text.trim().to_ascii_lowercase().as_str() {
+            "dark" => Some(Self::Dark),
+            "light" => Some(Self::Light),
+            "high-contrast" | "high_contrast" => Some(Self::HighContrast),
+            _ => None,
+        }
+    }
+
+    /// Light when the background's relative luminance is past the middle.
+    pub fn from_background(red: u8, green: u8, blue: u8) -> Self {
+        let luminance =
+            0.2126 * f64::from(red) + 0.7152 * f64::from(green) + 0.0722 * f64::from(blue);
+        if luminance > 127.5 {
+            Self::Light
+        } else {
+            Self::Dark
+        }
+    }
+}
+
+/// Colors and symbols swapped into every frame. Widgets keep drawing with
+/// their built-in colors; a theme maps those onto its own, so the default
+/// theme changes nothing.
+///
+/// ```toml
+/// [palette]
+/// cyan = "#ff8700"
+/// dark_grey = 244
+///
+/// [widgets.features]   # by widget id, applied before `palette`
+/// cyan = "magenta"
+///
+/// [styles]              # by role, or `#id role` for one widget
+/// hint = { color = 244, italic = true }
+/// active = { reverse = true }
+/// cursor = { glyph = "▶" }
+/// focus_bar = { color = "magenta", bold = true }
+/// "#features cursor" = { color = "magenta", bold = true }
+///
+/// [glyphs]
+/// "❯" = "▶"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Theme {
+    pub palette: Palette,
+    pub widgets: HashMap<String, Palette>,
+    pub styles: RoleStyles,
+    pub widget_styles: HashMap<String, RoleStyles>,
+    pub glyphs: HashMap<char, char>,
+}
+
+/// New look for a [`StyleRole`]. Attributes left out keep the role's
+/// default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RoleStyle {
+    pub color: Option<Color>,
+    pub background: Option<Color>,
+    pub bold: Option<bool>,
+    pub dim: Option<bool>,
+    pub italic: Option<bool>,
+    pub underline: Option<bool>,
+    pub reverse: Option<bool>,
+    /// Replaces the symbol of marker spans, those showing one character
+    /// such as the `❯` cursor.
+    pub glyph: Option<char>,
+}
+
+impl RoleStyle {
+    pub fn apply(&self, mut style: Style) -> Style {
+        style.color = self.color.or(style.color);
+        style.background = self.background.or(style.background);
+        style.bold = self.bold.unwrap_or(style.bold);
+        style.dim = self.dim.unwrap_or(style.dim);
+        style.italic = self.italic.unwrap_or(style.italic);
+        style.reverse = self.reverse.unwrap_or(style.reverse);
+        if let Some(underline) = self.underline {
+            style.underline = if underline {
+                Underline::Straight
+            } else {
+                Underline::None
+            };
+        }
+        style
+    }
+}
+
+/// Role looks of one theme layer.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RoleStyles {
+    styles: Vec<(StyleRole, RoleStyle)>,
+}
+
+impl RoleStyles {
+    pub fn set(&mut self, role: StyleRole, style: RoleStyle) {
+        match self.styles.iter_mut().find(|(known, _)| *known == role) {
+            Some(entry) => entry.1 = style,
+            None => self.styles.push((role, style)),
+        }
+    }
+
+    pub fn get(&self, role: StyleRole) -> Option<&RoleStyle> {
+        self.styles
+            .iter()
+            .find(|(known, _)| *known == role)
+            .map(|(_, style)| style)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.styles.is_empty()
+    }
+
+    /// Restyles spans tagged with a role this layer knows. Restyled spans
+    /// lose the tag, so an outer layer leaves them alone.
+    pub fn apply(&self, lines: &mut [SpanLine]) {
+        if self.is_empty() {
+            return;
+        }
+        for span in lines.iter_mut().flatten() {
+            if let Some(style) = span.style.role.and_then(|role| self.get(role)) {
+                span.style = style.apply(span.style);
+                span.style.role = None;
+                if let Some(glyph) = style.glyph {
+                    swap_marker_glyph(&mut span.text, glyph);
+                }
+            }
+        }
+    }
+}
+
+fn swap_marker_glyph(text: &mut String, glyph: char) {
+    let mut symbols = text.char_indices().filter(|(_, ch)| !ch.is_whitespace());
+    if let (Some((idx, symbol)), None) = (symbols.next(), symbols.next())
+        && symbol.width() == glyph.width()
+    {
+        text.replace_range(idx..idx + symbol.len_utf8(), glyph.encode_utf8(&mut [0; 4]));
+    }
+}
+
+/// Replacement colors for the ones widgets draw with.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Palette {
+    colors: Vec<(Color, Color)>,
+}
+
+impl Palette {
+    pub fn set(&mut self, from: Color, to: Color) {
+        match self.colors.iter_mut().find(|(color, _)| *color == from) {
+            Some(entry) => entry.1 = to,
+            None => self.colors.push((from, to)),
+        }
+    }
+
+    pub fn map(&self, color: Color) -> Color {
+        self.colors
+            .iter()
+            .find(|(from, _)| *from == color)
+            .map_or(color, |(_, to)| *to)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+
+    pub fn apply(&self, lines: &mut [SpanLine]) {
+        if self.is_empty() {
+            return;
+        }
+        for span in lines.iter_mut().flatten() {
+            span.style.color = span.style.color.map(|color| self.map(color));
+            span.style.background = span.style.background.map(|color| self.map(color));
+            span.style.underline_color = span.style.underline_color.map(|color| self.map(color));
+        }
+    }
+}
+
+impl Theme {
+    /// Built-in theme for the given background.
+    pub fn for_appearance(appearance: Appearance) -> Self {
+        match appearance {
+            Appearance::Dark => Self::default(),
+            Appearance::Light => Self::light(),
+            Appearance::HighContrast => Self::high_contrast(),
+        }
+    }
+
+    /// Darkens the colors widgets draw with for light backgrounds, where
+    /// grey hints, yellow accents and dark selection bars wash out.
+    pub fn light() -> Self {
+        let mut palette = Palette::default();
+        for (from, to) in [
+            (Color::DarkGrey, Color::Indexed(242)),
+            (Color::White, Color::Black),
+            (Color::Yellow, Color::Indexed(136)),
+            (Color::Cyan, Color::Indexed(31)),
+            (Color::Green, Color::Indexed(28)),
+            (SELECTION_BACKGROUND, Color::Rgb(215, 220, 240)),
+            (Color::Rgb(0, 35, 0), Color::Rgb(220, 245, 220)),
+            (Color::Rgb(40, 0, 0), Color::Rgb(250, 222, 222)),
+            (Color::Rgb(38, 32, 0), Color::Rgb(250, 240, 200)),
+            (Color::Rgb(200, 200, 200), Color::Rgb(60, 60, 60)),
+        ] {
+            palette.set(from, to);
+        }
+        Self {
+            palette,
+            ..Self::default()
+        }
+    }
+
+    /// Full-intensity colors for low-vision users: grey hints and borders
+    /// turn white, the selection bar turns blue, and the cursor, selection
+    /// and errors are marked with bold and underline as well as color.
+    pub fn high_contrast() -> Self {
+        let mut palette = Palette::default();
+        for (from, to) in [
+            (Color::DarkGrey, Color::White),
+            (SELECTION_BACKGROUND, Color::Blue),
+            (Color::Rgb(200, 200, 200), Color::White),
+        ] {
+            palette.set(from, to);
+        }
+        let mut styles = RoleStyles::default();
+        let plain = RoleStyle {
+            color: Some(Color::White),
+            dim: Some(false),
+            italic: Some(false),
+            ..RoleStyle::default()
+        };
+        let marked = RoleStyle {
+            bold: Some(true),
+            underline: Some(true),
+            ..RoleStyle::default()
+        };
+        styles.set(StyleRole::Hint, plain);
+        styles.set(StyleRole::Border, plain);
+        styles.set(StyleRole::Cursor, marked);
+        styles.set(StyleRole::Error, marked);
+        styles.set(
+            StyleRole::Focus,
+            RoleStyle {
+                bold: Some(true),
+                ..RoleStyle::default()
+            },
+        );
+        styles.set(
+            StyleRole::Selected,
+            RoleStyle {
+                bold: Some(true),
+                ..RoleStyle::default()
+            },
+        );
+        Self {
+            palette,
+            styles,
+            ..Self::default()
+        }
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let raw = std::fs::read_to_string(path)
+            .map_err(|err| format!("failed to read theme {}: {err}", path.display()))?;
+        Self::from_toml_str(raw.as_str()).map_err(|err| format!("{}: {err}", path.display()))
+    }
+
+    pub fn from_toml_str(raw: &str) -> Result<Self, String> {
+        let file: ThemeFile = toml::from_str(raw).map_err(|err| err.message().to_string())?;
+        let mut widgets = HashMap::new();
+        for (id, colors) in file.widgets {
+            widgets.insert(id, parse_palette(colors)?);
+        }
+        let mut styles = RoleStyles::default();
+        let mut widget_styles = HashMap::<String, RoleStyles>::new();
+        for (selector, def) in file.styles {
+            let (id, role) = parse_selector(selector.as_str())?;
+            let style = parse_role_style(def)?;
+            match id {
+                Some(id) => widget_styles.entry(id).or_default().set(role, style),
+                None => styles.set(role, style),
+            }
+        }
+        let mut glyphs = HashMap::new();
+        for (from, to) in file.glyphs {
+            let (from, to) = (single_char(from.as_str())?, single_char(to.as_str())?);
+            if from.width() != to.width() {
+                return Err(format!(
+                    "glyph `{to}` must be as wide as the `{from}` it replaces"
+                ));
+            }
+            glyphs.insert(from, to);
+        }
+        Ok(Self {
+            palette: parse_palette(file.palette)?,
+            widgets,
+            styles,
+            widget_styles,
+            glyphs,
+        })
+    }
+
+    /// Palette layered over `palette` for the widget with `id`, if any.
+    pub fn widget_palette(&self, id: &str) -> Option<&Palette> {
+        self.widgets.get(id).filter(|palette| !palette.is_empty())
+    }
+
+    /// Role looks layered over `styles` for the widget with `id`, if any.
+    pub fn widget_styles(&self, id: &str) -> Option<&RoleStyles> {
+        self.widget_styles
+            .get(id)
+            .filter(|styles| !styles.is_empty())
+    }
+
+    /// Applies role styles, the palette and the glyph set to a finished
+    /// frame.
+    pub fn apply(&self, lines: &mut [SpanLine]) {
+        self.styles.apply(lines);
+        self.palette.apply(lines);
+        if self.glyphs.is_empty() {
+            return;
+        }
+        for span in lines.iter_mut().flatten() {
+            if span.text.chars().any(|ch| self.glyphs.contains_key(&ch)) {
+                span.text = span
+                    .text
+                    .chars()
+                    .map(|ch| self.glyphs.get(&ch).copied().unwrap_or(ch))
+                    .collect();
+            }
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ThemeFile {
+    #[serde(default)]
+    palette: HashMap<String, ColorValue>,
+    #[serde(default)]
+    widgets: HashMap<String, HashMap<String, ColorValue>>,
+    #[serde(default)]
+    styles: HashMap<String, RoleStyleDef>,
+    #[serde(default)]
+    glyphs: HashMap<String, String>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RoleStyleDef {
+    color: Option<ColorValue>,
+    background: Option<ColorValue>,
+    bold: Option<bool>,
+    dim: Option<bool>,
+    italic: Option<bool>,
+    underline: Option<bool>,
+    reverse: Option<bool>,
+    glyph: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ColorValue {
+    Indexed(u8),
+    Named(String),
+}
+
+fn parse_palette(colors: HashMap<String, ColorValue>) -> Result<Palette, String> {
+    let mut palette = Palette::default();
+    for (from, to) in colors {
+        let from =
+            Color::parse(from.as_str()).ok_or_else(|| format!("unknown palette color `{from}`"))?;
+        palette.set(from, parse_color(to)?);
+    }
+    Ok(palette)
+}
+
+fn parse_color(value: ColorValue) -> Result<Color, String> {
+    match value {
+        ColorValue::Indexed(index) => Ok(Color::Indexed(index)),
+        ColorValue::Named(name) => {
+            Color::parse(name.as_str()).ok_or_else(|| format!("invalid color `{name}`"))
+        }
+    }
+}
+
+/// `role` or `#widget-id role`.
+fn parse_selector(selector: &str) -> Result<(Option<String>, StyleRole), String> {
+    let parts = selector.split_whitespace().collect::<Vec<_>>();
+    let (id, role) = match parts.as_slice() {
+        [role] => (None, *role),
+        [id, role] if id.len() > 1 && id.starts_with('#') => (Some(id[1..].to_string()), *role),
+        _ => return Err(format!("invalid style selector `{selector}`")),
+    };
+    let role = StyleRole::parse(role).ok_or_else(|| format!("unknown style role `{role}`"))?;
+    Ok((id, role))
+}
+
+fn parse_role_style(def: RoleStyleDef) -> Result<RoleStyle, String> {
+    let glyph = def.glyph.as_deref().map(single_char).transpose()?;
+    if let Some(glyph) = glyph
+        && glyph.width() != Some(1)
+    {
+        return Err(format!("glyph `{glyph}` must be one column wide"));
+    }
+    Ok(RoleStyle {
+        color: def.color.map(parse_color).transpose()?,
+        background: def.background.map(parse_color).transpose()?,
+        bold: def.bold,
+        dim: def.dim,
+        italic: def.italic,
+        underline: def.underline,
+        reverse: def.reverse,
+        glyph,
+    })
+}
+
+fn single_char(text: &str) -> Result<char, String> {
+    let mut chars = text.chars();
+    match (chars.next(), chars.next()) {
+        (Some(ch), None) => Ok(ch),
+        _ => Err(format!("glyph `{text}` must be a single character")),
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/theme.rs"]
+mod tests;
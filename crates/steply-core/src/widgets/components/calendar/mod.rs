@@ -1,7 +1,8 @@
+use crate::core::i18n::Message;
 use crate::core::value::Value;
 use crate::terminal::{KeyCode, KeyEvent, KeyModifiers};
 use crate::ui::span::Span;
-use crate::ui::style::{Color, Style};
+use crate::ui::style::{Color, Style, StyleRole};
 use crate::ui::text::text_display_width;
 use crate::widgets::base::WidgetBase;
 use crate::widgets::inputs::masked::MaskedInput;
@@ -223,9 +224,10 @@ impl Drawable for Calendar {
         let mut lines: Vec<Vec<Span>> = Vec::new();
 
         let marker = if focused { ">" } else { " " };
-        lines.push(vec![
-            Span::new(format!("{} {}:", marker, self.base.label())).no_wrap(),
-        ]);
+        let mut label = vec![Span::new(format!("{marker} ")).no_wrap()];
+        label.extend(ctx.label_line(self.base.label(), Style::default()));
+        label.push(Span::new(":").no_wrap());
+        lines.push(label);
 
         if self.mode != CalendarMode::Time {
             let month_st = if focused && self.section == Section::Month {
@@ -250,11 +252,7 @@ impl Drawable for Calendar {
             let mut weekday_line = vec![Span::new("  ").no_wrap()];
             for name in WEEKDAY_NAMES {
                 weekday_line.push(
-                    Span::styled(
-                        format!(" {:^2} ", name),
-                        Style::new().color(Color::DarkGrey),
-                    )
-                    .no_wrap(),
+                    Span::styled(format!(" {:^2} ", name), StyleRole::Hint.style()).no_wrap(),
                 );
             }
             lines.push(weekday_line);
@@ -305,7 +303,7 @@ impl Drawable for Calendar {
             let time_spans = self
                 .time_input
                 .render_spans_with_active(focused && self.is_time_section());
-            let dim = Style::new().color(Color::DarkGrey);
+            let dim = StyleRole::Hint.style();
             let mut line: Vec<Span> = Vec::new();
 
             if self.mode == CalendarMode::DateTime {
@@ -527,7 +525,7 @@ impl Interactive for Calendar {
         }
     }
 
-    fn validate(&self, _mode: ValidationMode) -> Result<(), String> {
+    fn validate(&self, _mode: ValidationMode) -> Result<(), Message> {
         run_validators(&self.validators, &Value::Text(self.formatted_value()))
     }
 }
@@ -1,10 +1,11 @@
+use crate::core::i18n::Message;
 use crate::core::value::Value;
 use crate::runtime::event::{SystemEvent, WidgetAction};
 use crate::task::{TaskId, TaskSpec};
-use crate::terminal::{KeyCode, KeyEvent};
+use crate::terminal::{KeyCode, KeyEvent, PointerEvent};
 use crate::ui::span::Span;
 use crate::ui::spinner::SpinnerStyle;
-use crate::ui::style::{Color, Style};
+use crate::ui::style::{Color, Style, StyleRole};
 use crate::widgets::base::WidgetBase;
 use crate::widgets::node::{Component, Node};
 use crate::widgets::outputs::task_log::{TaskLog, TaskLogStep};
@@ -16,6 +17,8 @@ use crate::widgets::traits::{
     OutputNode, RenderContext, ValidationMode,
 };
 
+const OUTPUT_HISTORY_LINES: usize = 1_000;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum RunMode {
     #[default]
@@ -47,6 +50,8 @@ pub struct CommandRunner {
     run_mode: RunMode,
     advance_on_success: bool,
     on_error: OnError,
+    require_success: bool,
+    succeeded: bool,
     auto_run_armed: bool,
     last_result: Option<Value>,
     children: Vec<Node>,
@@ -61,11 +66,15 @@ impl CommandRunner {
             commands: Vec::new(),
             log: TaskLog::new(format!("{id}__log"), Vec::new())
                 .with_spinner_style(SpinnerStyle::Dots)
-                .with_visible_lines(6),
+                .with_visible_lines(6)
+                .with_history_lines(OUTPUT_HISTORY_LINES)
+                .with_exit_status(true),
             last_error: None,
             run_mode: RunMode::default(),
             advance_on_success: false,
             on_error: OnError::default(),
+            require_success: false,
+            succeeded: false,
             auto_run_armed: true,
             last_result: None,
             children: Vec::new(),
@@ -147,6 +156,19 @@ impl CommandRunner {
         self
     }
 
+    /// Blocks submitting the step until every command has succeeded in the
+    /// latest run; takes precedence over `OnError::Continue`.
+    pub fn with_require_success(mut self, enabled: bool) -> Self {
+        self.require_success = enabled;
+        self
+    }
+
+    /// Leaves the command output on screen after a successful run.
+    pub fn with_keep_output(mut self, enabled: bool) -> Self {
+        self.log = self.log.with_keep_logs(enabled);
+        self
+    }
+
     fn status_line(&self, focused: bool) -> Option<Vec<Span>> {
         match self.log.status() {
             TaskWatcherStatus::Idle => Some(vec![
@@ -159,7 +181,7 @@ impl CommandRunner {
                     if focused {
                         Style::new().color(Color::Cyan)
                     } else {
-                        Style::new().color(Color::DarkGrey)
+                        StyleRole::Hint.style()
                     },
                 )
                 .no_wrap(),
@@ -190,6 +212,7 @@ impl CommandRunner {
             return InteractionResult::handled();
         }
         self.last_error = None;
+        self.succeeded = false;
         if let Some(request) = self.log.start_request() {
             return InteractionResult::with_action(
                 WidgetAction::ValidateCurrentStepSubmitAndTaskRequest { request },
@@ -249,20 +272,14 @@ impl Drawable for CommandRunner {
         let title_style = if focused {
             Style::new().color(Color::White)
         } else {
-            Style::new().color(Color::DarkGrey)
+            StyleRole::Hint.style()
         };
 
-        let mut lines = vec![vec![
-            Span::styled(self.base.label().to_string(), title_style).no_wrap(),
-        ]];
+        let mut lines = vec![ctx.label_line(self.base.label(), title_style)];
 
         if self.commands.is_empty() {
             lines.push(vec![
-                Span::styled(
-                    "  no commands configured",
-                    Style::new().color(Color::DarkGrey),
-                )
-                .no_wrap(),
+                Span::styled("  no commands configured", StyleRole::Hint.style()).no_wrap(),
             ]);
             return DrawOutput::with_lines(lines);
         }
@@ -297,9 +314,26 @@ impl Interactive for CommandRunner {
             self.auto_run_armed = false;
             return self.start_run();
         }
+        let delta = if keymap::is_plain_key(key, KeyCode::Up) {
+            -1
+        } else if keymap::is_plain_key(key, KeyCode::Down) {
+            1
+        } else {
+            return InteractionResult::ignored();
+        };
+        if self.log.scroll_logs(delta) {
+            return InteractionResult::handled();
+        }
         InteractionResult::ignored()
     }
 
+    fn on_pointer(&mut self, event: PointerEvent) -> InteractionResult {
+        match event.kind.wheel_delta() {
+            Some(delta) => InteractionResult::handled_if(self.log.scroll_logs(delta)),
+            None => InteractionResult::ignored(),
+        }
+    }
+
     fn on_system_event(&mut self, event: &SystemEvent) -> InteractionResult {
         let mut should_validate_step = false;
         let mut should_continue_on_error = false;
@@ -343,6 +377,12 @@ impl Interactive for CommandRunner {
                         self.last_error = Some(format!("{command_label}: {message}"));
                         should_validate_step = true;
                         should_continue_on_error = true;
+                    } else if self
+                        .commands
+                        .last()
+                        .is_some_and(|last| last.task_id == completion.task_id)
+                    {
+                        self.succeeded = true;
                     }
                 }
             }
@@ -371,6 +411,10 @@ impl Interactive for CommandRunner {
         self.log.on_tick()
     }
 
+    fn wants_tick(&self) -> bool {
+        self.log.wants_tick()
+    }
+
     fn task_specs(&self) -> Vec<TaskSpec> {
         self.commands
             .iter()
@@ -380,7 +424,8 @@ impl Interactive for CommandRunner {
                     command.program.clone(),
                     command.args.clone(),
                 )
-                .with_timeout_ms(command.timeout_ms);
+                .with_timeout_ms(command.timeout_ms)
+                .with_streamed_stdout();
                 if let Some(reads) = command.reads.clone() {
                     spec = spec.with_reads(reads);
                 }
@@ -393,12 +438,20 @@ impl Interactive for CommandRunner {
         self.last_result.clone()
     }
 
-    fn validate(&self, mode: ValidationMode) -> Result<(), String> {
+    fn validate(&self, mode: ValidationMode) -> Result<(), Message> {
         if mode == ValidationMode::Submit
             && self.on_error != OnError::Continue
             && let Some(error) = &self.last_error
         {
-            return Err(error.clone());
+            return Err(error.clone().into());
+        }
+        // Starting a run validates the step too; only a finished run counts.
+        if mode == ValidationMode::Submit
+            && self.require_success
+            && !self.succeeded
+            && self.log.status() != TaskWatcherStatus::Pending
+        {
+            return Err(format!("{} must succeed before continuing", self.base.label()).into());
         }
         Ok(())
     }
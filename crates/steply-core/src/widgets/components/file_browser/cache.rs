@@ -37,6 +37,16 @@ impl ScanCache {
         self.results.insert(key, result);
     }
 
+    /// Forgets every result, e.g. after the browser changed the filesystem.
+    pub fn clear(&mut self) {
+        self.results.clear();
+        self.in_flight = None;
+    }
+
+    pub fn has_in_flight(&self) -> bool {
+        self.in_flight.is_some()
+    }
+
     pub fn is_in_flight(&self, key: &CacheKey) -> bool {
         self.in_flight.as_ref() == Some(key)
     }
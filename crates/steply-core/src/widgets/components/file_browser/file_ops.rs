@@ -0,0 +1,354 @@
+use std::fs;
+
+use super::overlay_interaction::ActiveOverlayItem;
+use super::*;
+use crate::widgets::inputs::select::SelectInput;
+
+/// Create/rename/delete waiting for a name or a confirmation; it is edited
+/// on the row under the path input.
+pub(super) enum FileOp {
+    CreateDir {
+        dir: PathBuf,
+        input: TextInput,
+    },
+    Rename {
+        path: PathBuf,
+        input: TextInput,
+    },
+    ConfirmDelete {
+        paths: Vec<PathBuf>,
+        select: SelectInput,
+    },
+}
+
+impl FileOp {
+    fn prompt(&self) -> String {
+        match self {
+            Self::CreateDir { .. } => "New folder: ".to_string(),
+            Self::Rename { path, .. } => format!("Rename {}: ", file_name(path)),
+            Self::ConfirmDelete { paths, .. } => match paths.as_slice() {
+                [path] => format!("Delete {}? ", file_name(path)),
+                _ => format!("Delete {} items? ", paths.len()),
+            },
+        }
+    }
+}
+
+impl FileBrowserComponent {
+    pub(super) fn file_op_active(&self) -> bool {
+        self.file_op.is_some()
+    }
+
+    /// Starts an operation for `Ctrl+N`, `Ctrl+R` and `Ctrl+D`; `None` for
+    /// any other key.
+    pub(super) fn start_file_op(&mut self, key: KeyEvent) -> Option<InteractionResult> {
+        if !self.file_ops {
+            return None;
+        }
+        let op = if keymap::is_ctrl_char(key, 'n') {
+            self.create_dir_op()
+        } else if keymap::is_ctrl_char(key, 'r') {
+            self.rename_op()
+        } else if keymap::is_ctrl_char(key, 'd') {
+            self.delete_op()
+        } else {
+            return None;
+        };
+        self.file_op_error = None;
+        self.file_op = op;
+        Some(InteractionResult::handled())
+    }
+
+    pub(super) fn handle_file_op_key(&mut self, key: KeyEvent) -> InteractionResult {
+        match key.code {
+            KeyCode::Esc => {
+                self.file_op = None;
+                self.file_op_error = None;
+            }
+            KeyCode::Enter => self.commit_file_op(),
+            _ => match self.file_op.as_mut() {
+                Some(FileOp::CreateDir { input, .. } | FileOp::Rename { input, .. }) => {
+                    let _ = input.on_key(key);
+                }
+                Some(FileOp::ConfirmDelete { select, .. }) => {
+                    let _ = select.on_key(key);
+                }
+                None => {}
+            },
+        }
+        InteractionResult::handled()
+    }
+
+    fn op_input(&self, value: Option<String>) -> TextInput {
+        let input = TextInput::new(format!("{}__op", self.base.id()), "");
+        match value {
+            Some(value) => input.with_default(Value::Text(value)),
+            None => input,
+        }
+    }
+
+    fn active_entry_path(&self) -> Option<PathBuf> {
+        match self.active_item_in_mode(self.browser_mode)? {
+            ActiveOverlayItem::Entry { path, .. } => Some(path),
+            ActiveOverlayItem::Parent => None,
+        }
+    }
+
+    /// New folders go next to the active entry, so an expanded tree branch
+    /// receives them.
+    fn create_dir_op(&self) -> Option<FileOp> {
+        let dir = self
+            .active_entry_path()
+            .and_then(|path| path.parent().map(Path::to_path_buf))
+            .unwrap_or_else(|| self.browse_dir.clone());
+        Some(FileOp::CreateDir {
+            dir,
+            input: self.op_input(None),
+        })
+    }
+
+    fn rename_op(&self) -> Option<FileOp> {
+        let path = self.active_entry_path()?;
+        Some(FileOp::Rename {
+            input: self.op_input(Some(file_name(&path))),
+            path,
+        })
+    }
+
+    /// Deletes the marked files while multi-selecting, the active entry
+    /// otherwise.
+    fn delete_op(&self) -> Option<FileOp> {
+        let paths = if self.is_multi_select() && !self.selected_paths.is_empty() {
+            self.selected_paths.clone()
+        } else {
+            vec![self.active_entry_path()?]
+        };
+        Some(FileOp::ConfirmDelete {
+            paths,
+            select: SelectInput::new(
+                format!("{}__op", self.base.id()),
+                "",
+                vec!["No".into(), "Yes".into()],
+            ),
+        })
+    }
+
+    fn commit_file_op(&mut self) {
+        let Some(op) = self.file_op.take() else {
+            return;
+        };
+        let outcome = match &op {
+            FileOp::CreateDir { dir, input } => create_dir(dir, input_text(input).as_str())
+                .map(|path| (vec![dir.clone()], Some(path))),
+            FileOp::Rename { path, input } => self
+                .rename_entry(path, input_text(input).as_str())
+                .map(|renamed| (vec![parent_dir(path)], Some(renamed))),
+            FileOp::ConfirmDelete { paths, select } => {
+                if select.value().and_then(|v| v.to_text_scalar()).as_deref() != Some("Yes") {
+                    self.file_op_error = None;
+                    return;
+                }
+                let mut dirs = paths
+                    .iter()
+                    .map(|path| parent_dir(path))
+                    .collect::<Vec<_>>();
+                dirs.dedup();
+                self.delete_entries(paths).map(|()| (dirs, None))
+            }
+        };
+        match outcome {
+            Ok((dirs, focus)) => {
+                self.file_op_error = None;
+                self.refresh_after_file_op(dirs.as_slice(), focus);
+            }
+            Err(err) => {
+                self.file_op_error = Some(err);
+                self.file_op = Some(op);
+            }
+        }
+    }
+
+    fn rename_entry(&mut self, path: &Path, name: &str) -> Result<PathBuf, String> {
+        check_name(name)?;
+        let target = parent_dir(path).join(name);
+        if target == path {
+            return Ok(target);
+        }
+        if target.exists() {
+            return Err(format!("{name} already exists"));
+        }
+        fs::rename(path, &target).map_err(|err| err.to_string())?;
+        for selected in &mut self.selected_paths {
+            if let Ok(rest) = selected.strip_prefix(path) {
+                *selected = target.join(rest);
+            }
+        }
+        self.sync_selection_views();
+        Ok(target)
+    }
+
+    fn delete_entries(&mut self, paths: &[PathBuf]) -> Result<(), String> {
+        let mut result = Ok(());
+        for path in paths {
+            let removed = if path.is_dir() && !path.is_symlink() {
+                fs::remove_dir_all(path)
+            } else {
+                fs::remove_file(path)
+            };
+            if let Err(err) = removed {
+                result = Err(format!("{}: {err}", file_name(path)));
+                break;
+            }
+            self.selected_paths
+                .retain(|selected| !selected.starts_with(path));
+        }
+        self.sync_selection_views();
+        result
+    }
+
+    fn sync_selection_views(&mut self) {
+        self.sync_list_selection();
+        self.sync_tree_selection();
+        self.sync_multi_input_text(false);
+    }
+
+    /// Rescans the browsed directory and keeps the cursor on `focus`, or
+    /// near the old position when the entry is gone.
+    fn refresh_after_file_op(&mut self, dirs: &[PathBuf], focus: Option<PathBuf>) {
+        self.remember_active_focus_for_current_dir();
+        if let Some(memory) = self.focus_history.get_mut(&self.browse_dir) {
+            memory.path = focus;
+        }
+        for dir in dirs {
+            self.reload_tree_children(dir);
+        }
+        self.cache.clear();
+        self.browse_into(self.browse_dir.clone());
+    }
+
+    /// Relists an expanded tree branch, whose children are otherwise kept
+    /// as they were across rescans.
+    fn reload_tree_children(&mut self, dir: &Path) {
+        let Some(node_idx) = self.tree.as_ref().and_then(|tree| {
+            tree.nodes().iter().position(|node| {
+                node.expanded
+                    && node.item.entry.name != ".."
+                    && node.item.entry.path.as_ref() == dir
+            })
+        }) else {
+            return;
+        };
+        let children = self.tree_children(dir);
+        if let Some(tree) = self.tree.as_mut() {
+            tree.insert_children_after(node_idx, children);
+        }
+        self.sync_tree_selection();
+    }
+
+    pub(super) fn file_op_lines(&self, ctx: &RenderContext) -> Vec<Vec<Span>> {
+        let Some(op) = &self.file_op else {
+            return Vec::new();
+        };
+        let mut line = vec![Span::new("  ").no_wrap()];
+        match op {
+            FileOp::CreateDir { input, .. } | FileOp::Rename { input, .. } => {
+                line.push(Span::styled(op.prompt(), StyleRole::Hint.style()).no_wrap());
+                let input_ctx = ctx.with_focus(Some(input.id().to_string()));
+                line.extend(input.draw(&input_ctx).lines.into_iter().flatten());
+            }
+            FileOp::ConfirmDelete { select, .. } => {
+                let selected = select
+                    .value()
+                    .and_then(|v| v.to_text_scalar())
+                    .unwrap_or_else(|| "No".to_string());
+                line.push(Span::styled(op.prompt(), Style::new().color(Color::Red)).no_wrap());
+                line.push(
+                    Span::styled(format!("‹ {selected} ›"), Style::new().color(Color::Cyan))
+                        .no_wrap(),
+                );
+            }
+        }
+        let mut lines = vec![line];
+        if let Some(err) = &self.file_op_error {
+            lines.push(vec![
+                Span::styled(format!("  ✗ {err}"), StyleRole::Error.style()).no_wrap(),
+            ]);
+        }
+        lines
+    }
+
+    pub(super) fn file_op_cursor_pos(&self) -> Option<CursorPos> {
+        let (FileOp::CreateDir { input, .. } | FileOp::Rename { input, .. }) =
+            self.file_op.as_ref()?
+        else {
+            return None;
+        };
+        let prompt_width = 2 + text_display_width(self.file_op.as_ref()?.prompt().as_str());
+        let cursor = input.cursor_pos()?;
+        Some(CursorPos {
+            col: (prompt_width as u16).saturating_add(cursor.col),
+            row: 1,
+        })
+    }
+
+    pub(super) fn file_op_hints(&self) -> Vec<HintItem> {
+        match &self.file_op {
+            Some(FileOp::ConfirmDelete { .. }) => vec![
+                HintItem::new("← →", "choose No/Yes", HintGroup::Navigation).with_priority(10),
+                HintItem::new("Enter", "confirm", HintGroup::Action).with_priority(20),
+                HintItem::new("Esc", "cancel", HintGroup::Action).with_priority(21),
+            ],
+            Some(_) => vec![
+                HintItem::new("Enter", "apply", HintGroup::Action).with_priority(20),
+                HintItem::new("Esc", "cancel", HintGroup::Action).with_priority(21),
+            ],
+            None => vec![
+                HintItem::new(
+                    "Ctrl+N / Ctrl+R / Ctrl+D",
+                    "new folder/rename/delete",
+                    HintGroup::Action,
+                )
+                .with_priority(28),
+            ],
+        }
+    }
+}
+
+fn create_dir(dir: &Path, name: &str) -> Result<PathBuf, String> {
+    check_name(name)?;
+    let path = dir.join(name);
+    if path.exists() {
+        return Err(format!("{name} already exists"));
+    }
+    fs::create_dir(&path).map_err(|err| err.to_string())?;
+    Ok(path)
+}
+
+fn check_name(name: &str) -> Result<(), String> {
+    if name.is_empty() {
+        return Err("name is empty".to_string());
+    }
+    if matches!(name, "." | "..") || name.contains(['/', '\\']) {
+        return Err(format!("{name} is not a valid name"));
+    }
+    Ok(())
+}
+
+fn input_text(input: &TextInput) -> String {
+    input
+        .value()
+        .and_then(|v| v.to_text_scalar())
+        .unwrap_or_default()
+        .trim()
+        .to_string()
+}
+
+fn parent_dir(path: &Path) -> PathBuf {
+    path.parent().map(Path::to_path_buf).unwrap_or_default()
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.to_string_lossy().to_string())
+}
@@ -15,6 +15,60 @@ impl FileBrowserComponent {
             return InteractionResult::handled();
         }
         self.toggle_selected_path(path);
+        self.selection_changed()
+    }
+
+    /// Files the browser shows right now, in display order.
+    fn shown_files(&self) -> Vec<PathBuf> {
+        match self.browser_mode {
+            BrowserMode::List => self
+                .list_overlay_items
+                .iter()
+                .filter_map(|item| match item {
+                    ActiveOverlayItem::Entry {
+                        path,
+                        is_dir: false,
+                    } => Some(path.clone()),
+                    _ => None,
+                })
+                .collect(),
+            BrowserMode::Tree => self
+                .tree
+                .as_ref()
+                .map(|tree| {
+                    tree.visible()
+                        .iter()
+                        .filter_map(|idx| tree.nodes().get(*idx))
+                        .filter(|node| node.item.is_checkable_file())
+                        .map(|node| (*node.item.entry.path).clone())
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Marks every shown file, or unmarks them when all are marked already.
+    fn toggle_all_shown(&mut self) -> InteractionResult {
+        let shown = self.shown_files();
+        if shown.is_empty() {
+            return InteractionResult::handled();
+        }
+        let select = !shown.iter().all(|path| self.is_selected_path(path));
+        for path in shown {
+            self.set_selected_path(path, select);
+        }
+        self.selection_changed()
+    }
+
+    fn clear_selection(&mut self) -> InteractionResult {
+        if self.selected_paths.is_empty() {
+            return InteractionResult::handled();
+        }
+        self.selected_paths.clear();
+        self.selection_changed()
+    }
+
+    fn selection_changed(&mut self) -> InteractionResult {
         self.sync_list_selection();
         self.sync_tree_selection();
         self.sync_multi_input_text(true);
@@ -39,7 +93,7 @@ impl FileBrowserComponent {
         }
     }
 
-    fn active_item_in_mode(&self, mode: BrowserMode) -> Option<ActiveOverlayItem> {
+    pub(super) fn active_item_in_mode(&self, mode: BrowserMode) -> Option<ActiveOverlayItem> {
         match mode {
             BrowserMode::List => self.active_list_item(),
             BrowserMode::Tree => self.active_tree_item(),
@@ -57,6 +111,21 @@ impl FileBrowserComponent {
         self.navigate_item(item, allow_file_select)
     }
 
+    pub(super) fn tree_children(&self, dir: &Path) -> Vec<TreeNode<FileTreeItem>> {
+        filter_entries(
+            list_dir(dir, self.hide_hidden),
+            self.entry_filter,
+            self.ext_filter.as_ref(),
+        )
+        .into_iter()
+        .map(|entry| {
+            let is_dir = entry.kind.is_dir();
+            let selected = self.is_selected_path(entry.path.as_ref());
+            TreeNode::new(FileTreeItem::new(entry, Vec::new(), selected), 0, is_dir)
+        })
+        .collect()
+    }
+
     fn move_tree_active(&mut self, delta: isize) -> bool {
         self.tree
             .as_mut()
@@ -65,6 +134,12 @@ impl FileBrowserComponent {
     }
 
     pub(super) fn handle_browser_key(&mut self, key: KeyEvent) -> InteractionResult {
+        if self.file_op_active() {
+            return self.handle_file_op_key(key);
+        }
+        if let Some(result) = self.start_file_op(key) {
+            return result;
+        }
         if keymap::is_ctrl_char(key, 't') {
             let next = match self.browser_mode {
                 BrowserMode::List => BrowserMode::Tree,
@@ -73,6 +148,14 @@ impl FileBrowserComponent {
             self.set_browser_mode(next);
             return InteractionResult::handled();
         }
+        if self.is_multi_select() {
+            if keymap::is_ctrl_char(key, 'a') {
+                return self.toggle_all_shown();
+            }
+            if keymap::is_ctrl_char(key, 'x') {
+                return self.clear_selection();
+            }
+        }
 
         if self.browser_mode == BrowserMode::Tree {
             return self.handle_tree_key(key);
@@ -173,29 +256,11 @@ impl FileBrowserComponent {
                 }
 
                 if !children_loaded {
-                    let child_entries = filter_entries(
-                        list_dir(path.as_ref(), self.hide_hidden),
-                        self.entry_filter,
-                        self.ext_filter.as_ref(),
-                    );
-                    let children = child_entries
-                        .into_iter()
-                        .map(|entry| {
-                            let is_dir = entry.kind.is_dir();
-                            TreeNode::new(
-                                FileTreeItem::new(
-                                    entry.clone(),
-                                    Vec::new(),
-                                    self.is_selected_path(entry.path.as_ref()),
-                                ),
-                                0,
-                                is_dir,
-                            )
-                        })
-                        .collect::<Vec<_>>();
+                    let children = self.tree_children(path.as_ref());
                     if let Some(tree) = self.tree.as_mut() {
                         tree.insert_children_after(node_idx, children);
                     }
+                    self.sync_tree_selection();
                 } else if let Some(tree) = self.tree.as_mut() {
                     tree.expand_active();
                 }
@@ -1,5 +1,5 @@
-mod async_utils;
 mod cache;
+mod file_ops;
 mod interaction;
 mod model;
 mod overlay_interaction;
@@ -31,6 +31,8 @@ struct FileTreeItem {
     highlights: Vec<(usize, usize)>,
     leaf_count: usize,
     selected: bool,
+    /// Draws a checkbox; set on files while multi-selecting.
+    checkable: bool,
 }
 
 impl FileTreeItem {
@@ -40,8 +42,13 @@ impl FileTreeItem {
             highlights,
             leaf_count: 0,
             selected,
+            checkable: false,
         }
     }
+
+    fn is_checkable_file(&self) -> bool {
+        !self.entry.kind.is_dir() && self.entry.name != ".."
+    }
 }
 
 impl TreeItemLabel for FileTreeItem {
@@ -54,7 +61,7 @@ impl TreeItemLabel for FileTreeItem {
         state: crate::widgets::components::tree_view::TreeItemRenderState,
     ) -> Vec<Span> {
         let base_style = if state.focused && state.active {
-            Style::new().color(Color::Cyan).bold()
+            StyleRole::Active.style()
         } else if state.selected || self.selected {
             Style::new().color(Color::Yellow).bold()
         } else if self.entry.kind.is_dir() {
@@ -63,15 +70,19 @@ impl TreeItemLabel for FileTreeItem {
             Style::default()
         };
         let highlight_style = Style::new().color(Color::Yellow).bold();
-        let inactive_style = Style::new().color(Color::DarkGrey);
+        let inactive_style = StyleRole::Hint.style();
         let link_style = Style::new().color(Color::Green);
 
-        let mut spans = render_text_spans(
+        let mut spans = Vec::new();
+        if self.checkable {
+            spans.push(selection_marker(self.selected));
+        }
+        spans.extend(render_text_spans(
             self.label(),
             self.highlights.as_slice(),
             base_style,
             highlight_style,
-        );
+        ));
         if self.entry.kind.is_symlink() {
             spans.push(Span::styled("@", link_style).no_wrap());
         }
@@ -92,6 +103,7 @@ pub enum DisplayMode {
     Name,
 }
 
+use crate::core::i18n::Message;
 use crate::time::{Duration, Instant};
 use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
@@ -102,16 +114,17 @@ use crate::core::value::Value;
 use crate::terminal::{CursorPos, KeyCode, KeyEvent, KeyModifiers};
 use crate::ui::highlight::render_text_spans;
 use crate::ui::span::Span;
-use crate::ui::style::{Color, Style};
+use crate::ui::style::{Color, Style, StyleRole};
 use crate::ui::text::text_display_width;
 use crate::widgets::base::WidgetBase;
 use crate::widgets::components::select_list::{
-    SelectList, SelectMode, default_render_option_lines,
+    SelectItemRenderState, SelectList, SelectMode, default_render_option_lines,
 };
 use crate::widgets::components::tree_view::{TreeItemLabel, TreeNode, TreeView};
 use crate::widgets::inputs::text::TextInput;
 use crate::widgets::node::LeafComponent;
 use crate::widgets::shared::keymap;
+use crate::widgets::shared::timers::WidgetTimers;
 use crate::widgets::traits::{
     CompletionState, DrawOutput, Drawable, FocusMode, HintContext, HintGroup, HintItem,
     InteractionResult, Interactive, RenderContext, TextEditState, ValidationMode,
@@ -126,6 +139,7 @@ use scanner::{ScanRequest, ScannerHandle};
 use tree_scanner::TreeScannerHandle;
 
 const DEBOUNCE_MS: u64 = 120;
+const SCAN_TIMER: &str = "scan";
 const SPINNER_INTERVAL_MS: u64 = 80;
 const SPINNER_FRAMES: &[char] = &['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
 
@@ -152,7 +166,7 @@ pub struct FileBrowserComponent {
     cache: ScanCache,
     last_scan_result: Option<Arc<ScanResult>>,
 
-    debounce_deadline: Option<Instant>,
+    timers: WidgetTimers,
 
     overlay_open: bool,
     browse_dir: PathBuf,
@@ -171,6 +185,9 @@ pub struct FileBrowserComponent {
     focus_history: HashMap<PathBuf, FocusMemory>,
     selected_paths: Vec<PathBuf>,
     pending_selection_tokens: Option<Vec<String>>,
+    file_ops: bool,
+    file_op: Option<file_ops::FileOp>,
+    file_op_error: Option<String>,
 }
 
 pub type FileBrowserInput = FileBrowserComponent;
@@ -225,7 +242,7 @@ impl FileBrowserComponent {
             tree_scanner: TreeScannerHandle::new(),
             cache: ScanCache::new(),
             last_scan_result: None,
-            debounce_deadline: None,
+            timers: WidgetTimers::new(),
             overlay_open: true,
             spinner_frame: 0,
             spinner_last_tick: Instant::now(),
@@ -240,12 +257,12 @@ impl FileBrowserComponent {
             focus_history: HashMap::new(),
             selected_paths: Vec::new(),
             pending_selection_tokens: None,
+            file_ops: false,
+            file_op: None,
+            file_op_error: None,
         };
-        widget.list.set_option_renderer(|item, mut state| {
-            if state.selected && !(state.focused && state.active) {
-                state.base_style = Style::new().color(Color::Yellow).bold();
-            }
-            default_render_option_lines(item, state)
+        widget.list.set_option_renderer(|item, state| {
+            default_render_option_lines(item, highlight_selected_option(state))
         });
         widget
     }
@@ -314,6 +331,12 @@ impl FileBrowserComponent {
         self
     }
 
+    /// Lets the open browser create folders and rename or delete entries.
+    pub fn with_file_ops(mut self, enabled: bool) -> Self {
+        self.file_ops = enabled;
+        self
+    }
+
     fn current_input(&self) -> String {
         self.text
             .value()
@@ -446,13 +469,9 @@ impl FileBrowserComponent {
     }
 
     fn flush_debounce(&mut self) -> bool {
-        let Some(deadline) = self.debounce_deadline else {
-            return false;
-        };
-        if Instant::now() < deadline {
+        if !self.timers.fire(SCAN_TIMER) {
             return false;
         }
-        self.debounce_deadline = None;
         let parsed = parse_input(&self.query_input(), &self.cwd);
         self.browse_dir = parsed.view_dir.clone();
         let recursive = parsed.mode.recursive(self.recursive, parsed.query.as_str());
@@ -462,7 +481,8 @@ impl FileBrowserComponent {
     }
 
     fn schedule_scan(&mut self) {
-        self.debounce_deadline = Some(Instant::now() + Duration::from_millis(DEBOUNCE_MS));
+        self.timers
+            .schedule_after(SCAN_TIMER, Duration::from_millis(DEBOUNCE_MS));
     }
 
     fn browse_into(&mut self, dir: PathBuf) {
@@ -470,7 +490,7 @@ impl FileBrowserComponent {
     }
 
     fn browse_into_with_restore(&mut self, dir: PathBuf, fallback: Option<FocusRestore>) {
-        self.debounce_deadline = None;
+        self.timers.cancel(SCAN_TIMER);
         self.overlay_open = true;
         self.pending_focus_restore = self
             .focus_history
@@ -481,23 +501,7 @@ impl FileBrowserComponent {
             .or(Some(FocusRestore::FirstRealEntry));
         self.browse_dir = dir.clone();
 
-        let path_str = if let Ok(rel) = dir.strip_prefix(&self.cwd) {
-            let s = rel.to_string_lossy();
-            if s.is_empty() {
-                String::new()
-            } else {
-                format!("{}/", s)
-            }
-        } else {
-            let abs = dir.to_string_lossy();
-            if abs == "/" {
-                "/".to_string()
-            } else if abs.ends_with('/') {
-                abs.to_string()
-            } else {
-                format!("{abs}/")
-            }
-        };
+        let path_str = parser::dir_input_text(dir.as_path(), self.cwd.as_path());
         if self.is_multi_select() {
             self.set_active_query(path_str);
         } else {
@@ -507,7 +511,7 @@ impl FileBrowserComponent {
     }
 
     fn open_browser(&mut self) -> InteractionResult {
-        self.debounce_deadline = None;
+        self.timers.cancel(SCAN_TIMER);
         self.overlay_open = true;
         let parsed = parse_input(&self.query_input(), &self.cwd);
         self.browse_dir = parsed.view_dir.clone();
@@ -533,6 +537,8 @@ impl FileBrowserComponent {
 
     fn close_browser(&mut self) -> InteractionResult {
         self.overlay_open = false;
+        self.file_op = None;
+        self.file_op_error = None;
         self.tree_building = false;
         self.pending_tree_nodes = None;
         InteractionResult::handled()
@@ -584,13 +590,50 @@ impl FileBrowserComponent {
         self.list.set_value(values);
     }
 
+    /// Puts a checkbox before each file of the list; directories and `..`
+    /// are indented to line up.
+    fn sync_list_markers(&mut self) {
+        if !self.is_multi_select() {
+            return;
+        }
+        let files = self
+            .list_overlay_items
+            .iter()
+            .filter_map(|item| match item {
+                overlay_interaction::ActiveOverlayItem::Entry {
+                    path,
+                    is_dir: false,
+                } => Some(path.to_string_lossy().to_string()),
+                _ => None,
+            })
+            .collect::<HashSet<_>>();
+        self.list.set_option_renderer(move |item, state| {
+            let checkable = item
+                .value
+                .to_text_scalar()
+                .is_some_and(|value| files.contains(&value));
+            let marker = if checkable {
+                selection_marker(state.selected)
+            } else {
+                Span::new("  ").no_wrap()
+            };
+            let mut lines = default_render_option_lines(item, highlight_selected_option(state));
+            if let Some(first) = lines.first_mut() {
+                first.insert(0, marker);
+            }
+            lines
+        });
+    }
+
     fn sync_tree_selection(&mut self) {
         let selected = self.selected_paths.clone();
+        let multi = self.is_multi_select();
         if let Some(tree) = self.tree.as_mut() {
             for node in tree.nodes_mut() {
                 node.item.selected = selected
                     .iter()
                     .any(|path| path == node.item.entry.path.as_ref());
+                node.item.checkable = multi && node.item.is_checkable_file();
             }
         }
     }
@@ -762,6 +805,7 @@ impl Drawable for FileBrowserComponent {
         }
 
         if self.overlay_open {
+            lines.extend(self.file_op_lines(ctx));
             if self.browser_mode == BrowserMode::Tree {
                 if let Some(tree) = &self.tree {
                     lines.extend(tree.render_lines(true));
@@ -778,7 +822,7 @@ impl Drawable for FileBrowserComponent {
                         lines.push(vec![
                             Span::styled(
                                 format!("  … {} more (refine query to narrow down)", total - shown),
-                                Style::new().color(Color::DarkGrey),
+                                StyleRole::Hint.style(),
                             )
                             .no_wrap(),
                         ]);
@@ -787,6 +831,16 @@ impl Drawable for FileBrowserComponent {
             }
         }
 
+        if self.overlay_open && self.is_multi_select() && !self.selected_paths.is_empty() {
+            lines.push(vec![
+                Span::styled(
+                    format!("  {} selected", self.selected_paths.len()),
+                    StyleRole::Hint.style(),
+                )
+                .no_wrap(),
+            ]);
+        }
+
         DrawOutput::with_lines(lines)
     }
 
@@ -795,17 +849,12 @@ impl Drawable for FileBrowserComponent {
             return Vec::new();
         }
 
-        let mut hints = crate::widgets::traits::focused_static_hints(
-            ctx,
-            crate::widgets::static_hints::FILE_BROWSER_DOC_HINTS,
-        );
-        hints.retain(|hint| {
-            hint.key == "Tab"
-                || hint.key == "Ctrl+Space"
-                || hint.key == "Shift+Space / Alt+Space"
-                || hint.key == "Enter"
-        });
-
+        // Hints for the open browser come first, so its `Enter` wins over
+        // the input's; the first four documented hints always apply.
+        let mut hints = Vec::new();
+        if self.overlay_open && self.file_op_active() {
+            return self.file_op_hints();
+        }
         if self.overlay_open {
             hints.push(HintItem::new("Esc", "close browser", HintGroup::View).with_priority(21));
             hints.push(
@@ -818,6 +867,14 @@ impl Drawable for FileBrowserComponent {
                 hints.push(
                     HintItem::new("Space", "toggle file", HintGroup::Action).with_priority(25),
                 );
+                hints.push(
+                    HintItem::new(
+                        "Ctrl+A / Ctrl+X",
+                        "mark shown/clear marks",
+                        HintGroup::Action,
+                    )
+                    .with_priority(27),
+                );
             }
             if self.browser_mode == BrowserMode::Tree {
                 hints.push(
@@ -849,7 +906,16 @@ impl Drawable for FileBrowserComponent {
                     HintItem::new("Ctrl+T", "switch to tree", HintGroup::View).with_priority(22),
                 );
             }
+            if self.file_ops {
+                hints.extend(self.file_op_hints());
+            }
         }
+        hints.extend(
+            crate::widgets::static_hints::FILE_BROWSER_DOC_HINTS[..4]
+                .iter()
+                .copied()
+                .map(HintItem::from_static),
+        );
 
         hints
     }
@@ -913,7 +979,7 @@ impl Interactive for FileBrowserComponent {
             .map(|(i, _)| i)
             .unwrap_or(state.value.len());
         let start = state.value[query_start_byte..byte_end]
-            .rfind('/')
+            .rfind(['/', '\\'])
             .map(|relative| {
                 query_start
                     + state.value[query_start_byte..query_start_byte + relative + 1]
@@ -947,6 +1013,13 @@ impl Interactive for FileBrowserComponent {
         }
     }
 
+    fn wants_tick(&self) -> bool {
+        self.scanning
+            || self.tree_building
+            || self.timers.has_pending()
+            || self.cache.has_in_flight()
+    }
+
     fn value(&self) -> Option<Value> {
         if self.is_multi_select() {
             return Some(Value::List(self.selected_output_values()));
@@ -971,15 +1044,33 @@ impl Interactive for FileBrowserComponent {
         }
     }
 
-    fn validate(&self, _mode: ValidationMode) -> Result<(), String> {
+    fn validate(&self, _mode: ValidationMode) -> Result<(), Message> {
         run_validators(&self.validators, &Value::Text(self.current_input()))
     }
 
     fn cursor_pos(&self) -> Option<CursorPos> {
+        if self.overlay_open && self.file_op_active() {
+            return self.file_op_cursor_pos();
+        }
         self.text.cursor_pos()
     }
 }
 
+fn selection_marker(selected: bool) -> Span {
+    if selected {
+        Span::styled("■ ", Style::new().color(Color::Green)).no_wrap()
+    } else {
+        Span::styled("□ ", StyleRole::Hint.style()).no_wrap()
+    }
+}
+
+fn highlight_selected_option(mut state: SelectItemRenderState) -> SelectItemRenderState {
+    if state.selected && !(state.focused && state.active) {
+        state.base_style = Style::new().color(Color::Yellow).bold();
+    }
+    state
+}
+
 fn should_skip_expensive_typing_scan(overlay_open: bool, recursive: bool, query: &str) -> bool {
     if overlay_open || !recursive || !query.contains("**") {
         return false;
@@ -1006,7 +1097,7 @@ fn append_right_status(line: &mut Vec<Span>, status: &str, terminal_width: u16)
     }
 
     line.push(Span::new(" ".repeat(gap)).no_wrap());
-    line.push(Span::styled(status.to_string(), Style::new().color(Color::DarkGrey)).no_wrap());
+    line.push(Span::styled(status.to_string(), StyleRole::Hint.style()).no_wrap());
 }
 
 fn split_multi_input(raw: &str) -> MultiInputState {
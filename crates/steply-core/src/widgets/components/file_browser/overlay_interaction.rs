@@ -118,6 +118,7 @@ impl FileBrowserComponent {
         let preferred = self.preferred_list_active_index(items.as_slice());
         self.list.set_options(options);
         self.list_overlay_items = items;
+        self.sync_list_markers();
         self.sync_list_selection();
         if let Some(index) = preferred {
             self.list.set_active_index(index);
@@ -150,10 +151,7 @@ impl FileBrowserComponent {
             self.pending_tree_nodes = Some((result.seq, result.nodes));
         }
 
-        if self
-            .debounce_deadline
-            .is_some_and(|deadline| crate::time::Instant::now() < deadline)
-        {
+        if self.timers.is_waiting(SCAN_TIMER) {
             return false;
         }
 
@@ -292,7 +290,7 @@ impl FileBrowserComponent {
         }
     }
 
-    fn remember_active_focus_for_current_dir(&mut self) {
+    pub(super) fn remember_active_focus_for_current_dir(&mut self) {
         let memory = if self.browser_mode == BrowserMode::Tree {
             self.tree
                 .as_ref()
@@ -51,6 +51,20 @@ pub fn parse_input(raw: &str, cwd: &Path) -> ParsedInput {
     }
 }
 
+/// Text placed in the input when browsing into `dir`: relative to `cwd` when
+/// possible, always with `/` separators and a trailing `/`.
+pub fn dir_input_text(dir: &Path, cwd: &Path) -> String {
+    let text = match dir.strip_prefix(cwd) {
+        Ok(rel) => rel.to_string_lossy().replace('\\', "/"),
+        Err(_) => dir.to_string_lossy().replace('\\', "/"),
+    };
+    if text.is_empty() || text.ends_with('/') {
+        text
+    } else {
+        format!("{text}/")
+    }
+}
+
 fn expand_home(path: &str) -> String {
     if (path == "~" || path.starts_with("~/") || path.starts_with("~\\"))
         && let Some(home) = home_dir()
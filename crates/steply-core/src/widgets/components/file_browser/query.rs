@@ -3,7 +3,7 @@ use std::path::Path;
 use globset::{Glob, GlobBuilder, GlobSetBuilder};
 
 use crate::core::value::Value;
-use crate::ui::style::{Color, Style};
+use crate::ui::style::{Color, Style, StyleRole};
 use crate::widgets::components::select_list::{SelectItem, SelectItemView};
 use crate::widgets::shared::list_policy;
 
@@ -224,7 +224,7 @@ fn entry_option(
     mode: DisplayMode,
 ) -> SelectItem {
     let dir_style = Style::new().color(Color::Blue).bold();
-    let prefix_style = Style::new().color(Color::DarkGrey);
+    let prefix_style = StyleRole::Hint.style();
     let link_style = Style::new().color(Color::Green);
     let value = Value::Text(entry.path.to_string_lossy().to_string());
 
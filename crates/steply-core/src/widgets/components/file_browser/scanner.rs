@@ -5,10 +5,10 @@ use std::sync::Arc;
 use std::sync::mpsc::{self, Receiver, Sender};
 
 use super::DisplayMode;
-use super::async_utils::{drain_receiver, recv_latest};
 use super::cache::CacheKey;
 use super::model::{EntryFilter, filter_entries, list_dir, list_dir_recursive};
 use super::query::{ScanResult, fuzzy_search, glob_search, list_dir_recursive_glob, plain_result};
+use crate::widgets::shared::async_utils::{drain_receiver, recv_latest};
 
 pub struct ScanRequest {
     pub key: CacheKey,
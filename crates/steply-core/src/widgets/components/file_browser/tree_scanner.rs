@@ -7,9 +7,9 @@ use std::sync::mpsc::{self, Receiver, Sender};
 use crate::widgets::components::tree_view::TreeNode;
 
 use super::FileTreeItem;
-use super::async_utils::{drain_receiver, recv_latest};
 use super::query::ScanResult;
 use super::tree_builder::build_tree_nodes_for;
+use crate::widgets::shared::async_utils::{drain_receiver, recv_latest};
 
 pub(super) struct TreeBuildRequest {
     pub seq: u64,
@@ -6,5 +6,6 @@ pub mod repeater;
 pub mod select_list;
 pub mod snippet;
 pub mod table;
+pub mod task_details;
 pub mod textarea;
 pub mod tree_view;
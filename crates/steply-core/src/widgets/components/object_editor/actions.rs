@@ -35,17 +35,26 @@ impl ObjectEditor {
         };
     }
 
-    pub(super) fn commit_edit_value(&mut self) {
+    /// Writes the edited value back; `false` when the schema refuses it and
+    /// the editor stays open.
+    pub(super) fn commit_edit_value(&mut self) -> bool {
         let Mode::EditValue {
             visible_index,
             ref key_value,
         } = self.mode
         else {
-            return;
+            return false;
         };
         let text = key_value.value_text();
         let new_val = Self::parse_scalar(&text);
         let path = self.path_at_visible_index(visible_index);
+        if let Some(error) = self
+            .schema_at(&path)
+            .and_then(|schema| schema.value_error(&new_val))
+        {
+            self.notice = Some(error);
+            return false;
+        }
         let ppath = Self::parent_path(&path);
         let key = Self::leaf_key(&path);
         if let Some(parent) = Self::value_at_path_mut(&mut self.value, &ppath) {
@@ -65,6 +74,7 @@ impl ObjectEditor {
         }
         self.mode = Mode::Normal;
         self.rebuild();
+        true
     }
 
     pub(super) fn start_edit_key(&mut self) {
@@ -91,22 +101,30 @@ impl ObjectEditor {
         };
     }
 
-    pub(super) fn commit_edit_key(&mut self) {
+    /// Renames the key; `false` when the schema refuses the new key and the
+    /// editor stays open.
+    pub(super) fn commit_edit_key(&mut self) -> bool {
         let Mode::EditKey {
             visible_index,
             ref key_value,
         } = self.mode
         else {
-            return;
+            return false;
         };
         let new_key = key_value.key();
         if new_key.is_empty() {
             self.mode = Mode::Normal;
-            return;
+            return true;
         }
         let path = self.path_at_visible_index(visible_index);
         let ppath = Self::parent_path(&path);
         let old_key = Self::leaf_key(&path);
+        if old_key != new_key
+            && let Err(error) = self.child_schema(&ppath, &new_key)
+        {
+            self.notice = Some(error);
+            return false;
+        }
         let mut remap_paths: Option<(String, String)> = None;
         if let Some(parent) = Self::value_at_path_mut(&mut self.value, &ppath)
             && let Value::Object(map) = parent
@@ -134,6 +152,7 @@ impl ObjectEditor {
         }
         self.mode = Mode::Normal;
         self.rebuild();
+        true
     }
 
     pub(super) fn start_insert(&mut self) {
@@ -173,16 +192,86 @@ impl ObjectEditor {
             };
             return;
         }
+        let mut key_value = InlineKeyValueEditor::new(
+            format!("{}_ikv", self.base.id()),
+            "",
+            self.insert_type_options(),
+        );
+        // Keys the schema declares but the object lacks are offered, the
+        // first one prefilled along with a type it allows.
+        if let Some(schema) = self.schema_at(&parent_path) {
+            let missing = schema.missing_fields(Self::value_at_path(&self.value, &parent_path));
+            if let Some(first) = missing.first() {
+                let kind = self
+                    .child_schema(&parent_path, first)
+                    .ok()
+                    .flatten()
+                    .and_then(Self::insert_type_for);
+                key_value = key_value.with_default_key(*first);
+                if let Some(kind) = kind {
+                    key_value = key_value.with_default_value(kind);
+                }
+            }
+            key_value =
+                key_value.with_key_suggestions(missing.into_iter().map(String::from).collect());
+        }
         self.mode = Mode::InsertType {
             after_visible_index,
-            key_value: InlineKeyValueEditor::new(
-                format!("{}_ikv", self.base.id()),
-                "",
-                self.insert_type_options(),
-            ),
+            key_value,
         };
     }
 
+    /// The built-in insert type producing a value `schema` allows, if one of
+    /// them does.
+    fn insert_type_for(schema: &ObjectSchema) -> Option<&'static str> {
+        [
+            (SchemaType::Text, "text"),
+            (SchemaType::Number, "number"),
+            (SchemaType::Object, "object"),
+            (SchemaType::List, "array"),
+        ]
+        .into_iter()
+        .find(|(kind, _)| schema.allows_type(*kind))
+        .map(|(_, name)| name)
+    }
+
+    /// Parent of a row inserted after `visible_index`; an empty-container
+    /// placeholder inserts into its container.
+    fn insert_parent_path(&self, visible_index: usize) -> String {
+        match self.object_at_visible_index(visible_index) {
+            Some(obj) if obj.is_placeholder => obj
+                .placeholder_parent
+                .clone()
+                .unwrap_or_else(|| Self::parent_path(&obj.path)),
+            Some(obj) => Self::parent_path(&obj.path),
+            None => String::new(),
+        }
+    }
+
+    /// Schema for a new child `key` of `parent_path`, or why the schema
+    /// refuses that key.
+    pub(super) fn child_schema(
+        &self,
+        parent_path: &str,
+        key: &str,
+    ) -> Result<Option<&ObjectSchema>, String> {
+        let Some(parent) = self.schema_at(parent_path) else {
+            return Ok(None);
+        };
+        let segment = if matches!(
+            Self::value_at_path(&self.value, parent_path),
+            Some(Value::List(_))
+        ) {
+            PathSegment::Index(0)
+        } else {
+            if let Some(error) = parent.key_error(key) {
+                return Err(error);
+            }
+            PathSegment::Key(key.to_string())
+        };
+        Ok(parent.at(&ValuePath::new(vec![segment])))
+    }
+
     pub(super) fn commit_insert_type(&mut self) {
         let Mode::InsertType {
             after_visible_index,
@@ -201,6 +290,22 @@ impl ObjectEditor {
         let av = after_visible_index;
         let k = key.clone();
         let tv = type_val.clone();
+        let kind = match tv.as_str() {
+            "object" => Some(SchemaType::Object),
+            "array" => Some(SchemaType::List),
+            "number" => Some(SchemaType::Number),
+            _ => None,
+        };
+        let parent_path = self.insert_parent_path(av);
+        let refused = match self.child_schema(&parent_path, &k) {
+            Err(error) => Some(error),
+            Ok(Some(field)) => kind.and_then(|kind| field.type_error(kind)),
+            Ok(None) => None,
+        };
+        if let Some(error) = refused {
+            self.notice = Some(error);
+            return;
+        }
 
         match tv.as_str() {
             "object" | "array" => {
@@ -257,6 +362,16 @@ impl ObjectEditor {
         };
         let av = after_visible_index;
         let k = key_value.key();
+        let parent_path = self.insert_parent_path(av);
+        let refused = match self.child_schema(&parent_path, &k) {
+            Err(error) => Some(error),
+            Ok(Some(field)) => field.value_error(&new_val),
+            Ok(None) => None,
+        };
+        if let Some(error) = refused {
+            self.notice = Some(error);
+            return;
+        }
         let inserted_path = self.do_insert(av, k, new_val);
         self.mode = Mode::Normal;
         self.rebuild();
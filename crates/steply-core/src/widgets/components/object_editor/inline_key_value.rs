@@ -1,11 +1,11 @@
 use crate::core::value::Value;
 use crate::terminal::{CursorPos, KeyCode, KeyEvent};
 use crate::ui::span::Span;
-use crate::ui::style::{Color, Style};
+use crate::ui::style::{Color, Style, StyleRole};
 use crate::widgets::inputs::masked::MaskedInput;
 use crate::widgets::inputs::select::SelectInput;
 use crate::widgets::inputs::text::TextInput;
-use crate::widgets::traits::{InteractionResult, Interactive, TextAction};
+use crate::widgets::traits::{CompletionState, InteractionResult, Interactive, TextAction};
 use unicode_width::UnicodeWidthChar;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -93,6 +93,12 @@ impl InlineKeyValueEditor {
         self
     }
 
+    /// Keys offered as completions while the key is being typed.
+    pub fn with_key_suggestions(mut self, keys: Vec<String>) -> Self {
+        self.key_input.set_completion_items(keys);
+        self
+    }
+
     pub fn with_default_value(mut self, value: impl Into<String>) -> Self {
         let value = Value::Text(value.into());
         match &mut self.value_field {
@@ -185,10 +191,28 @@ impl InlineKeyValueEditor {
         }
     }
 
+    pub fn key_completion(&mut self) -> Option<CompletionState<'_>> {
+        if self.focus != InlineKeyValueFocus::Key {
+            return None;
+        }
+        self.key_input.completion()
+    }
+
+    pub fn on_paste(&mut self, text: &str) -> InteractionResult {
+        match self.focus {
+            InlineKeyValueFocus::Key => self.key_input.on_paste(text),
+            InlineKeyValueFocus::Value => match &mut self.value_field {
+                InlineValueField::Select(_) => InteractionResult::ignored(),
+                InlineValueField::Text(input) => input.on_paste(text),
+                InlineValueField::Masked(input) => input.on_paste(text),
+            },
+        }
+    }
+
     pub fn inline_spans(&self) -> Vec<Span> {
         let key = self.key();
         let active = Style::new().color(Color::Cyan);
-        let inactive = Style::new().color(Color::DarkGrey);
+        let inactive = StyleRole::Hint.style();
         let key_style = if self.focus == InlineKeyValueFocus::Key {
             active
         } else {
@@ -1,4 +1,5 @@
 use super::*;
+use crate::core::i18n::Message;
 use crate::widgets::shared::keymap;
 
 impl Interactive for ObjectEditor {
@@ -23,6 +24,7 @@ impl Interactive for ObjectEditor {
             return self.handle_filter_key(key);
         }
 
+        self.notice = None;
         match &self.mode {
             Mode::Normal => self.handle_normal(key),
             Mode::EditValue { .. } => self.handle_edit_value(key),
@@ -31,6 +33,7 @@ impl Interactive for ObjectEditor {
             Mode::InsertValue { .. } => self.handle_insert_value(key),
             Mode::ConfirmDelete { .. } => self.handle_confirm_delete(key),
             Mode::Move { .. } => self.handle_move(key),
+            Mode::PasteJson { .. } => self.handle_paste_json(key),
         }
     }
 
@@ -46,14 +49,74 @@ impl Interactive for ObjectEditor {
         self.rebuild();
     }
 
-    fn validate(&self, mode: ValidationMode) -> Result<(), String> {
+    fn validate(&self, mode: ValidationMode) -> Result<(), Message> {
         if mode == ValidationMode::Submit
             && let Some(error) = self.pending_insert_value_error()
         {
-            return Err(error);
+            return Err(error.into());
+        }
+        if mode == ValidationMode::Submit
+            && let Some(violation) = self.schema_violations().into_iter().next()
+        {
+            return Err(violation.describe().into());
         }
         Ok(())
     }
+
+    fn validation_issues(&self, mode: ValidationMode) -> Vec<ValidationIssue> {
+        if mode != ValidationMode::Submit {
+            return Vec::new();
+        }
+        self.schema_violations()
+            .into_iter()
+            .map(|violation| {
+                let location = if violation.path.is_empty() {
+                    self.base.label().to_string()
+                } else {
+                    violation.path.clone()
+                };
+                ValidationIssue::new(violation.message).at(location, violation.path)
+            })
+            .collect()
+    }
+
+    /// Targets are value paths, empty for the whole value. Collapsed
+    /// ancestors are expanded and a filter hiding the row is cleared.
+    fn reveal(&mut self, target: &str) -> bool {
+        let Some(path) = Self::parse_path(target) else {
+            return false;
+        };
+        if Self::value_at_path(&self.value, target).is_none() {
+            return false;
+        }
+        let segments = path.segments();
+        for len in 1..=segments.len() {
+            self.expanded
+                .insert(ValuePath::new(segments[..len].to_vec()).to_string());
+        }
+        self.mode = Mode::Normal;
+        self.rebuild();
+        let find = |this: &Self| {
+            if target.is_empty() {
+                (!this.tree.visible().is_empty()).then_some(0)
+            } else {
+                this.visible_index_of_path(target)
+            }
+        };
+        let visible_index = match find(self) {
+            Some(index) => Some(index),
+            None => {
+                self.filter.clear();
+                self.tree.clear_filter();
+                find(self)
+            }
+        };
+        if let Some(visible_index) = visible_index {
+            self.tree.set_active_visible_index(visible_index);
+        }
+        true
+    }
+
     fn cursor_pos(&self) -> Option<CursorPos> {
         if self.filter.is_focused() {
             let mut row: u16 = 0;
@@ -110,6 +173,18 @@ impl Interactive for ObjectEditor {
                         .saturating_add(if inline_on_placeholder { 0 } else { 1 }),
                 })
             }
+            Mode::PasteJson { buffer, .. } => {
+                let local = buffer.cursor_pos()?;
+                // Below the tree and the line naming the target.
+                let row = header_rows
+                    .saturating_add(tree_lines.len() as u16)
+                    .saturating_add(1)
+                    .saturating_add(local.row);
+                Some(CursorPos {
+                    col: local.col,
+                    row,
+                })
+            }
             _ => None,
         }
     }
@@ -127,15 +202,42 @@ impl Interactive for ObjectEditor {
             | Mode::EditKey { key_value, .. }
             | Mode::InsertType { key_value, .. }
             | Mode::InsertValue { key_value, .. } => key_value.on_text_action(action),
+            Mode::PasteJson { buffer, .. } => buffer.on_text_action(action),
+            _ => InteractionResult::ignored(),
+        }
+    }
+
+    fn on_paste(&mut self, text: &str) -> InteractionResult {
+        if self.filter.is_focused() {
+            return self
+                .filter
+                .handle_paste(text)
+                .refresh_if_changed(|| self.apply_filter_from_input());
+        }
+
+        match &mut self.mode {
+            Mode::EditValue { key_value, .. }
+            | Mode::EditKey { key_value, .. }
+            | Mode::InsertType { key_value, .. }
+            | Mode::InsertValue { key_value, .. } => key_value.on_paste(text),
+            Mode::PasteJson { buffer, error, .. } => {
+                *error = None;
+                buffer.on_paste(text)
+            }
             _ => InteractionResult::ignored(),
         }
     }
 
     fn completion(&mut self) -> Option<crate::widgets::traits::CompletionState<'_>> {
-        if !self.filter.is_focused() {
-            return None;
+        if self.filter.is_focused() {
+            return self.filter.completion();
+        }
+        match &mut self.mode {
+            Mode::InsertType { key_value, .. } | Mode::InsertValue { key_value, .. } => {
+                key_value.key_completion()
+            }
+            _ => None,
         }
-        self.filter.completion()
     }
 }
 
@@ -154,6 +256,9 @@ impl ObjectEditor {
             Mode::ConfirmDelete { select, .. } => {
                 let _ = select.on_key(key);
             }
+            Mode::PasteJson { buffer, .. } => {
+                let _ = buffer.on_key(key);
+            }
             Mode::Normal | Mode::Move { .. } => {}
         }
     }
@@ -167,7 +272,7 @@ impl ObjectEditor {
     }
 
     fn handle_normal(&mut self, key: KeyEvent) -> InteractionResult {
-        if !keymap::has_no_modifiers(key) {
+        if !keymap::has_no_modifiers(key) && !keymap::is_typed_char(key) {
             return InteractionResult::ignored();
         }
         match key.code {
@@ -183,6 +288,19 @@ impl ObjectEditor {
                 self.toggle_expand();
                 InteractionResult::handled()
             }
+            KeyCode::Char('y') => self.yank_json(false),
+            KeyCode::Char('Y') => self.yank_json(true),
+            KeyCode::Char('e' | 'r' | 'i' | 'd' | 'm' | 'p' | 'P') if self.read_only => {
+                InteractionResult::ignored()
+            }
+            KeyCode::Char('p') => {
+                self.start_paste_json(false);
+                InteractionResult::handled()
+            }
+            KeyCode::Char('P') => {
+                self.start_paste_json(true);
+                InteractionResult::handled()
+            }
             KeyCode::Char('e') => {
                 if let Some(obj) = self.active_obj() {
                     if !obj.is_index
@@ -224,8 +342,9 @@ impl ObjectEditor {
                 InteractionResult::handled()
             }
             KeyCode::Tab => {
-                self.commit_edit_value();
-                self.start_edit_key();
+                if self.commit_edit_value() {
+                    self.start_edit_key();
+                }
                 InteractionResult::handled()
             }
             KeyCode::Esc => self.back_to_normal_mode(),
@@ -243,8 +362,9 @@ impl ObjectEditor {
                 InteractionResult::handled()
             }
             KeyCode::Tab => {
-                self.commit_edit_key();
-                self.start_edit_value();
+                if self.commit_edit_key() {
+                    self.start_edit_value();
+                }
                 InteractionResult::handled()
             }
             KeyCode::Esc => self.back_to_normal_mode(),
@@ -0,0 +1,114 @@
+use super::*;
+use crate::runtime::event::{ValueChange, WidgetAction};
+use crate::widgets::shared::keymap;
+
+const PASTE_BUFFER_MIN_HEIGHT: usize = 3;
+const PASTE_BUFFER_MAX_HEIGHT: usize = 8;
+
+impl ObjectEditor {
+    /// The value under the cursor, or the whole value with `whole`, as pretty
+    /// JSON. It goes to `yank_target` when one is set, else to the clipboard.
+    pub(super) fn yank_json(&self, whole: bool) -> InteractionResult {
+        let path = if whole {
+            Some(String::new())
+        } else {
+            self.json_path()
+        };
+        let Some(value) = path.and_then(|path| Self::value_at_path(&self.value, &path)) else {
+            return InteractionResult::ignored();
+        };
+        let Ok(text) = value.to_json_string_pretty() else {
+            return InteractionResult::ignored();
+        };
+        let action = match &self.yank_target {
+            Some(target) => WidgetAction::ValueChanged {
+                source: self.base.id().into(),
+                change: ValueChange::with_target(target.clone(), Value::Text(text)),
+            },
+            None => WidgetAction::CopyToClipboard { text },
+        };
+        InteractionResult::with_action(action)
+    }
+
+    /// Opens an empty multiline buffer whose JSON replaces the value under
+    /// the cursor, or the whole value with `whole`.
+    pub(super) fn start_paste_json(&mut self, whole: bool) {
+        let path = if whole {
+            Some(String::new())
+        } else {
+            self.json_path()
+        };
+        let Some(path) = path else { return };
+        let buffer = TextAreaComponent::new(format!("{}__json", self.base.id()))
+            .with_min_height(PASTE_BUFFER_MIN_HEIGHT)
+            .with_max_height(PASTE_BUFFER_MAX_HEIGHT);
+        self.mode = Mode::PasteJson {
+            path,
+            buffer: Box::new(buffer),
+            error: None,
+        };
+    }
+
+    /// Parses the buffer into place; a parse error keeps the buffer open
+    /// with the message under it.
+    pub(super) fn commit_paste_json(&mut self) {
+        let Mode::PasteJson {
+            path,
+            buffer,
+            error,
+        } = &mut self.mode
+        else {
+            return;
+        };
+        let text = buffer
+            .value()
+            .and_then(|value| value.into_text())
+            .unwrap_or_default();
+        let parsed = match Value::from_json(text.as_str()) {
+            Ok(parsed) => parsed,
+            Err(err) => {
+                *error = Some(err);
+                return;
+            }
+        };
+        let path = std::mem::take(path);
+        if path.is_empty() {
+            self.value = parsed;
+            self.expanded.clear();
+            self.array_item_names.clear();
+            self.expand_all_top_level();
+        } else {
+            self.remove_array_name_subtree(&path);
+            if let Some(slot) = Self::value_at_path_mut(&mut self.value, &path) {
+                *slot = parsed;
+            }
+            self.expanded.insert(path);
+        }
+        self.mode = Mode::Normal;
+        self.rebuild();
+    }
+
+    pub(super) fn handle_paste_json(&mut self, key: KeyEvent) -> InteractionResult {
+        if keymap::is_plain_key(key, KeyCode::Enter) {
+            self.commit_paste_json();
+            return InteractionResult::handled();
+        }
+        if key.code == KeyCode::Esc {
+            self.mode = Mode::Normal;
+            return InteractionResult::handled();
+        }
+        let Mode::PasteJson { buffer, error, .. } = &mut self.mode else {
+            return InteractionResult::ignored();
+        };
+        *error = None;
+        buffer.on_key(key);
+        InteractionResult::handled()
+    }
+
+    /// Path of the row under the cursor; `None` on an empty-container
+    /// placeholder, which has no value of its own.
+    fn json_path(&self) -> Option<String> {
+        let obj = self.active_obj()?;
+        (!obj.is_placeholder).then(|| obj.path.clone())
+    }
+}
@@ -5,13 +5,14 @@ use std::sync::Arc;
 use indexmap::IndexMap;
 
 use crate::core::value::Value;
-use crate::core::value_path::{PathSegment, ValuePath};
+use crate::core::value_path::{PathSegment, ValuePath, ValueTarget};
 
 use crate::terminal::{CursorPos, KeyCode, KeyEvent};
 use crate::ui::highlight::render_text_spans;
 use crate::ui::span::Span;
-use crate::ui::style::{Color, Style};
+use crate::ui::style::{Color, Style, StyleRole};
 use crate::widgets::base::WidgetBase;
+use crate::widgets::components::textarea::TextAreaComponent;
 use crate::widgets::components::tree_view::{TreeItemLabel, TreeNode, TreeView};
 use crate::widgets::inputs::select::SelectInput;
 use crate::widgets::node::LeafComponent;
@@ -19,9 +20,11 @@ use crate::widgets::shared::filter;
 use crate::widgets::shared::list_policy;
 use crate::widgets::traits::{
     DrawOutput, Drawable, FocusMode, HintContext, HintGroup, HintItem, InteractionResult,
-    Interactive, RenderContext, ValidationMode,
+    Interactive, RenderContext, ValidationIssue, ValidationMode,
 };
 use inline_key_value::{CustomValueInput, InlineKeyValueEditor, InlineKeyValueFocus};
+use schema::SchemaViolation;
+pub use schema::{ObjectSchema, SchemaType};
 use unicode_width::UnicodeWidthChar;
 
 #[derive(Clone)]
@@ -127,6 +130,12 @@ enum Mode {
     Move {
         visible_index: usize,
     },
+    PasteJson {
+        /// Where the parsed JSON goes; empty for the whole value.
+        path: String,
+        buffer: Box<TextAreaComponent>,
+        error: Option<String>,
+    },
 }
 
 #[derive(Clone, Copy)]
@@ -172,6 +181,13 @@ pub struct ObjectEditor {
     filter: filter::ListFilter,
     insert_types: Vec<InsertType>,
     mode: Mode,
+    /// Browsing only: the edit, insert, delete and move keys do nothing.
+    read_only: bool,
+    /// Store target yanked JSON is written to instead of the clipboard.
+    yank_target: Option<ValueTarget>,
+    schema: Option<ObjectSchema>,
+    /// Why the last insert or edit was refused; cleared on the next key.
+    notice: Option<String>,
 }
 
 impl ObjectEditor {
@@ -206,7 +222,7 @@ impl ObjectEditor {
         let icon_style = tree_line
             .get(icon_pos)
             .map(|span| span.style)
-            .unwrap_or_else(|| Style::new().color(Color::DarkGrey));
+            .unwrap_or_else(|| StyleRole::Hint.style());
         prefix.push(Span::styled("  ", icon_style).no_wrap());
         prefix
     }
@@ -240,6 +256,10 @@ impl ObjectEditor {
             filter: filter::ListFilter::new(filter_id, filter::FilterEscBehavior::Blur, false),
             insert_types: Vec::new(),
             mode: Mode::Normal,
+            read_only: false,
+            yank_target: None,
+            schema: None,
+            notice: None,
         };
         this.rebuild();
         this
@@ -261,6 +281,24 @@ impl ObjectEditor {
         self
     }
 
+    pub fn with_read_only(mut self) -> Self {
+        self.read_only = true;
+        self
+    }
+
+    /// Writes yanked JSON to `target` in the store instead of the clipboard.
+    pub fn with_yank_target(mut self, target: ValueTarget) -> Self {
+        self.yank_target = Some(target);
+        self
+    }
+
+    /// Constrains the value: inserts suggest the keys it declares, edits
+    /// it rejects are refused and violations render in red.
+    pub fn with_schema(mut self, schema: ObjectSchema) -> Self {
+        self.schema = Some(schema);
+        self
+    }
+
     pub fn with_insert_type(mut self, insert_type: InsertType) -> Self {
         self.insert_types.push(insert_type);
         self
@@ -369,6 +407,17 @@ impl ObjectEditor {
         None
     }
 
+    fn schema_at(&self, path: &str) -> Option<&ObjectSchema> {
+        self.schema.as_ref()?.at(&Self::parse_path(path)?)
+    }
+
+    fn schema_violations(&self) -> Vec<SchemaViolation> {
+        self.schema
+            .as_ref()
+            .map(|schema| schema.check(&self.value))
+            .unwrap_or_default()
+    }
+
     fn draft_value(&self) -> Value {
         let mut draft = self.value.clone();
         if let Mode::EditValue {
@@ -403,5 +452,11 @@ impl ObjectEditor {
 mod actions;
 mod inline_key_value;
 mod interaction;
+mod json;
 mod model;
 mod render;
+mod schema;
+
+#[cfg(test)]
+#[path = "../tests/object_editor.rs"]
+mod tests;
@@ -13,15 +13,9 @@ impl ObjectEditor {
                 (s, Style::new().color(Color::Cyan))
             }
             Value::Bool(b) => (b.to_string(), Style::new().color(Color::Yellow)),
-            Value::None => ("null".to_string(), Style::new().color(Color::DarkGrey)),
-            Value::Object(m) => (
-                format!("{{{}}}", m.len()),
-                Style::new().color(Color::DarkGrey),
-            ),
-            Value::List(a) => (
-                format!("[{}]", a.len()),
-                Style::new().color(Color::DarkGrey),
-            ),
+            Value::None => ("null".to_string(), StyleRole::Hint.style()),
+            Value::Object(m) => (format!("{{{}}}", m.len()), StyleRole::Hint.style()),
+            Value::List(a) => (format!("[{}]", a.len()), StyleRole::Hint.style()),
         }
     }
 
@@ -36,7 +30,7 @@ impl ObjectEditor {
         let red_st = Style::new().color(Color::Red);
         let yellow_st = Style::new().color(Color::Yellow);
         let key_st = Style::new().color(Color::White).bold();
-        let key_dim = Style::new().color(Color::DarkGrey);
+        let key_dim = StyleRole::Hint.style();
         let cyan_st = Style::new().color(Color::Cyan);
         let highlight_st = Style::new().color(Color::Yellow).bold();
         let query = self.tree.filter_query().trim();
@@ -141,12 +135,12 @@ impl ObjectEditor {
             let key_style = if key_value.focus() == InlineKeyValueFocus::Key {
                 Style::new().color(Color::Cyan)
             } else {
-                Style::new().color(Color::DarkGrey)
+                StyleRole::Hint.style()
             };
             return vec![
                 Span::styled(key_value.key(), key_style).no_wrap(),
                 Span::new(": ").no_wrap(),
-                Span::styled(format!("✗ {error}"), Style::new().color(Color::Red).bold()).no_wrap(),
+                Span::styled(format!("✗ {error}"), StyleRole::Error.style()).no_wrap(),
             ];
         }
         key_value.inline_spans()
@@ -160,9 +154,12 @@ impl Drawable for ObjectEditor {
         self.base.id()
     }
 
+    fn label(&self) -> &str {
+        self.base.label()
+    }
+
     fn draw(&self, ctx: &RenderContext) -> DrawOutput {
         let focused = self.base.is_focused(ctx);
-        let inactive = Style::new().color(Color::DarkGrey);
         let insert_value_error = matches!(self.mode, Mode::InsertValue { .. })
             .then(|| ctx.visible_errors.get(self.base.id()).map(String::as_str))
             .flatten();
@@ -180,10 +177,12 @@ impl Drawable for ObjectEditor {
             _ => None,
         };
 
+        let violations = self.schema_violations();
+
         let mut lines: Vec<Vec<Span>> = Vec::new();
 
         if !self.base.label().is_empty() {
-            lines.push(vec![Span::new(self.base.label()).no_wrap()]);
+            lines.push(ctx.label_line(self.base.label(), StyleRole::Label.style()));
         }
 
         if self.filter.is_visible() {
@@ -236,7 +235,6 @@ impl Drawable for ObjectEditor {
                     }
                     Mode::InsertValue { key_value, .. } => {
                         row.extend(self.insert_value_spans(key_value, insert_value_error));
-                        row.push(Span::styled("  Enter confirm  Esc cancel", inactive).no_wrap());
                     }
                     _ => {}
                 }
@@ -244,7 +242,7 @@ impl Drawable for ObjectEditor {
                 continue;
             }
             if insert_after_this_row && !tree_line.is_empty() {
-                tree_line[0] = Span::styled(" ", Style::new().color(Color::DarkGrey)).no_wrap();
+                tree_line[0] = Span::styled(" ", StyleRole::Hint.style()).no_wrap();
             }
             tree_line.truncate(icon_idx);
 
@@ -261,7 +259,29 @@ impl Drawable for ObjectEditor {
                 }
             }
 
-            tree_line.extend(self.row_spans(visible_index, obj, in_red, in_yellow, focused));
+            let violation = (!obj.is_placeholder)
+                .then(|| {
+                    violations
+                        .iter()
+                        .find(|violation| violation.path == obj.path)
+                })
+                .flatten();
+            tree_line.extend(self.row_spans(
+                visible_index,
+                obj,
+                in_red || violation.is_some(),
+                in_yellow,
+                focused,
+            ));
+            if let Some(violation) = violation {
+                tree_line.push(
+                    Span::styled(
+                        format!("  ✗ {}", violation.message),
+                        StyleRole::Error.style(),
+                    )
+                    .no_wrap(),
+                );
+            }
             lines.push(tree_line);
 
             if let Mode::InsertType {
@@ -284,28 +304,38 @@ impl Drawable for ObjectEditor {
             {
                 let mut row = insert_prefix.clone();
                 row.extend(self.insert_value_spans(key_value, insert_value_error));
-                row.push(Span::styled("  Enter confirm  Esc cancel", inactive).no_wrap());
                 lines.push(row);
             }
         }
 
-        if focused {
-            let hint = match &self.mode {
-                Mode::Normal if self.filter.is_focused() => {
-                    "  Type to filter  Enter/Esc back to tree"
-                }
-                Mode::Normal => {
-                    "  ↑↓ nav  Space expand  e edit  r rename  i insert  d delete  m move"
-                }
-                Mode::EditValue { .. } | Mode::EditKey { .. } => {
-                    "  Enter confirm  Tab key↔val  Esc cancel"
-                }
-                Mode::InsertType { .. } => "  Tab key↔type  ←→ type  Enter confirm  Esc cancel",
-                Mode::InsertValue { .. } => "  Enter confirm  Tab key↔val  Esc cancel",
-                Mode::ConfirmDelete { .. } => "  ←→ No/Yes  Enter confirm",
-                Mode::Move { .. } => "  ↑↓ move  m or Esc done",
-            };
-            lines.push(vec![Span::styled(hint, inactive).no_wrap()]);
+        if let Mode::PasteJson {
+            path,
+            buffer,
+            error,
+        } = &self.mode
+        {
+            let target = if path.is_empty() { "whole value" } else { path };
+            lines.push(vec![
+                Span::styled(format!("JSON for {target}:"), StyleRole::Hint.style()).no_wrap(),
+            ]);
+            let buffer_ctx = ctx.with_focus(focused.then(|| buffer.id().to_string()));
+            lines.extend(buffer.draw(&buffer_ctx).lines);
+            if let Some(error) = error {
+                lines.push(vec![
+                    Span::styled(format!("✗ {error}"), StyleRole::Error.style()).no_wrap(),
+                ]);
+            }
+        }
+
+        // Violations of the value as a whole have no row to sit on.
+        let root_messages = violations
+            .iter()
+            .filter(|violation| violation.path.is_empty())
+            .map(|violation| violation.message.as_str());
+        for message in root_messages.chain(self.notice.as_deref()) {
+            lines.push(vec![
+                Span::styled(format!("✗ {message}"), StyleRole::Error.style()).no_wrap(),
+            ]);
         }
 
         DrawOutput::with_lines(lines)
@@ -324,11 +354,10 @@ impl Drawable for ObjectEditor {
             ];
         }
 
-        let mut hints = crate::widgets::traits::focused_static_hints(
-            ctx,
-            crate::widgets::static_hints::OBJECT_EDITOR_DOC_HINTS,
-        );
-        hints.retain(|hint| hint.key == "Ctrl+F" || hint.key == "Enter");
+        let mut hints = vec![
+            HintItem::from_static(crate::widgets::static_hints::OBJECT_EDITOR_DOC_HINTS[0]),
+            HintItem::new("Enter", "confirm", HintGroup::Action).with_priority(20),
+        ];
 
         match self.mode {
             Mode::Normal => {
@@ -338,19 +367,29 @@ impl Drawable for ObjectEditor {
                         .with_priority(11),
                 );
                 hints.push(
-                    HintItem::new("e / r", "edit value/key", HintGroup::Action).with_priority(20),
-                );
-                hints.push(
-                    HintItem::new("i / d / m", "insert/delete/move", HintGroup::Action)
-                        .with_priority(21),
+                    HintItem::new("y / Y", "copy JSON (row/all)", HintGroup::Action)
+                        .with_priority(22),
                 );
+                if !self.read_only {
+                    hints.push(
+                        HintItem::new("e / r", "edit value/key", HintGroup::Action)
+                            .with_priority(20),
+                    );
+                    hints.push(
+                        HintItem::new("p / P", "paste JSON (row/all)", HintGroup::Action)
+                            .with_priority(23),
+                    );
+                    hints.push(
+                        HintItem::new("i / d / m", "insert/delete/move", HintGroup::Action)
+                            .with_priority(21),
+                    );
+                }
             }
             Mode::EditValue { .. } | Mode::EditKey { .. } => {
                 hints.push(
                     HintItem::new("Tab", "switch key/value", HintGroup::Navigation)
                         .with_priority(10),
                 );
-                hints.push(HintItem::new("Enter", "confirm", HintGroup::Action).with_priority(20));
                 hints.push(HintItem::new("Esc", "cancel", HintGroup::Action).with_priority(21));
             }
             Mode::InsertType { .. } => {
@@ -361,7 +400,6 @@ impl Drawable for ObjectEditor {
                     HintItem::new("Tab", "switch key/type", HintGroup::Navigation)
                         .with_priority(11),
                 );
-                hints.push(HintItem::new("Enter", "confirm", HintGroup::Action).with_priority(20));
                 hints.push(HintItem::new("Esc", "cancel", HintGroup::Action).with_priority(21));
             }
             Mode::InsertValue { .. } => {
@@ -369,14 +407,18 @@ impl Drawable for ObjectEditor {
                     HintItem::new("Tab", "switch key/value", HintGroup::Navigation)
                         .with_priority(10),
                 );
-                hints.push(HintItem::new("Enter", "confirm", HintGroup::Action).with_priority(20));
                 hints.push(HintItem::new("Esc", "cancel", HintGroup::Action).with_priority(21));
             }
             Mode::ConfirmDelete { .. } => {
                 hints.push(
                     HintItem::new("← →", "choose No/Yes", HintGroup::Navigation).with_priority(10),
                 );
-                hints.push(HintItem::new("Enter", "confirm", HintGroup::Action).with_priority(20));
+                hints.push(HintItem::new("Esc", "cancel", HintGroup::Action).with_priority(21));
+            }
+            Mode::PasteJson { .. } => {
+                hints.push(
+                    HintItem::new("Shift+Enter", "new line", HintGroup::Edit).with_priority(10),
+                );
                 hints.push(HintItem::new("Esc", "cancel", HintGroup::Action).with_priority(21));
             }
             Mode::Move { .. } => {
@@ -0,0 +1,268 @@
+use indexmap::IndexMap;
+
+use crate::core::value::Value;
+use crate::core::value_path::{PathSegment, ValuePath};
+
+/// The kinds of value a schema can allow, named as [`Value::kind_name`]
+/// names them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SchemaType {
+    None,
+    Text,
+    Bool,
+    Number,
+    List,
+    Object,
+}
+
+impl SchemaType {
+    pub fn parse(text: &str) -> Option<Self> {
+        let kind = match text.trim().to_ascii_lowercase().as_str() {
+            "none" | "null" => Self::None,
+            "text" | "string" => Self::Text,
+            "bool" | "boolean" => Self::Bool,
+            "number" => Self::Number,
+            "list" | "array" => Self::List,
+            "object" => Self::Object,
+            _ => return None,
+        };
+        Some(kind)
+    }
+
+    pub fn of(value: &Value) -> Self {
+        match value {
+            Value::None => Self::None,
+            Value::Text(_) => Self::Text,
+            Value::Bool(_) => Self::Bool,
+            Value::Number(_) => Self::Number,
+            Value::List(_) => Self::List,
+            Value::Object(_) => Self::Object,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::Text => "text",
+            Self::Bool => "bool",
+            Self::Number => "number",
+            Self::List => "list",
+            Self::Object => "object",
+        }
+    }
+}
+
+/// What the value of an [`ObjectEditor`](super::ObjectEditor), or a part of
+/// it, may hold. An empty schema allows anything.
+#[derive(Debug, Clone, Default)]
+pub struct ObjectSchema {
+    types: Vec<SchemaType>,
+    choices: Vec<Value>,
+    fields: IndexMap<String, SchemaField>,
+    /// Only the keys in `fields` are allowed.
+    closed: bool,
+    items: Option<Box<ObjectSchema>>,
+}
+
+#[derive(Debug, Clone)]
+struct SchemaField {
+    schema: ObjectSchema,
+    required: bool,
+}
+
+/// A place where the value breaks its schema. Missing keys are reported on
+/// the object that lacks them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct SchemaViolation {
+    pub(super) path: String,
+    pub(super) message: String,
+}
+
+impl SchemaViolation {
+    pub(super) fn describe(&self) -> String {
+        if self.path.is_empty() {
+            self.message.clone()
+        } else {
+            format!("{}: {}", self.path, self.message)
+        }
+    }
+}
+
+impl ObjectSchema {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn of_type(kind: SchemaType) -> Self {
+        Self::new().with_type(kind)
+    }
+
+    /// Allows `kind`; with none given, any kind is allowed.
+    pub fn with_type(mut self, kind: SchemaType) -> Self {
+        if !self.types.contains(&kind) {
+            self.types.push(kind);
+        }
+        self
+    }
+
+    /// Limits the value to one of `choices`.
+    pub fn with_choices(mut self, choices: Vec<Value>) -> Self {
+        self.choices = choices;
+        self
+    }
+
+    pub fn with_field(mut self, key: impl Into<String>, schema: ObjectSchema) -> Self {
+        self.fields.insert(
+            key.into(),
+            SchemaField {
+                schema,
+                required: false,
+            },
+        );
+        self
+    }
+
+    pub fn with_required_field(mut self, key: impl Into<String>, schema: ObjectSchema) -> Self {
+        self.fields.insert(
+            key.into(),
+            SchemaField {
+                schema,
+                required: true,
+            },
+        );
+        self
+    }
+
+    /// Rejects keys that are not declared with `with_field`.
+    pub fn closed(mut self) -> Self {
+        self.closed = true;
+        self
+    }
+
+    /// Schema of every item of a list.
+    pub fn with_items(mut self, schema: ObjectSchema) -> Self {
+        self.items = Some(Box::new(schema));
+        self
+    }
+
+    pub fn allows_type(&self, kind: SchemaType) -> bool {
+        self.types.is_empty() || self.types.contains(&kind)
+    }
+
+    /// The schema for the part of the value at `path`, if it says anything
+    /// about it.
+    pub(super) fn at(&self, path: &ValuePath) -> Option<&ObjectSchema> {
+        let mut schema = self;
+        for segment in path.segments() {
+            schema = match segment {
+                PathSegment::Key(key) => &schema.fields.get(key.as_str())?.schema,
+                PathSegment::Index(_) => schema.items.as_deref()?,
+            };
+        }
+        Some(schema)
+    }
+
+    /// Declared keys missing from `value`, in declaration order.
+    pub(super) fn missing_fields<'a>(&'a self, value: Option<&Value>) -> Vec<&'a str> {
+        let present = |key: &str| match value {
+            Some(Value::Object(map)) => map.contains_key(key),
+            _ => false,
+        };
+        self.fields
+            .keys()
+            .map(String::as_str)
+            .filter(|key| !present(key))
+            .collect()
+    }
+
+    /// Why `key` may not be added here, if it may not.
+    pub(super) fn key_error(&self, key: &str) -> Option<String> {
+        (self.closed && !self.fields.contains_key(key))
+            .then(|| format!("`{key}` is not allowed here"))
+    }
+
+    pub(super) fn check(&self, value: &Value) -> Vec<SchemaViolation> {
+        let mut violations = Vec::new();
+        self.check_at(value, &mut Vec::new(), &mut violations);
+        violations
+    }
+
+    fn check_at(&self, value: &Value, path: &mut Vec<PathSegment>, out: &mut Vec<SchemaViolation>) {
+        let here = ValuePath::new(path.clone()).to_string();
+        if let Some(message) = self.value_error(value) {
+            out.push(SchemaViolation {
+                path: here,
+                message,
+            });
+            return;
+        }
+        match value {
+            Value::Object(map) => {
+                for (key, field) in &self.fields {
+                    if field.required && !map.contains_key(key.as_str()) {
+                        out.push(SchemaViolation {
+                            path: here.clone(),
+                            message: format!("missing required key `{key}`"),
+                        });
+                    }
+                }
+                for (key, item) in map {
+                    path.push(PathSegment::Key(key.clone()));
+                    match self.fields.get(key.as_str()) {
+                        Some(field) => field.schema.check_at(item, path, out),
+                        None if self.closed => out.push(SchemaViolation {
+                            path: ValuePath::new(path.clone()).to_string(),
+                            message: format!("`{key}` is not allowed here"),
+                        }),
+                        None => {}
+                    }
+                    path.pop();
+                }
+            }
+            Value::List(items) => {
+                if let Some(schema) = self.items.as_deref() {
+                    for (index, item) in items.iter().enumerate() {
+                        path.push(PathSegment::Index(index));
+                        schema.check_at(item, path, out);
+                        path.pop();
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Type and choice checks of this value alone.
+    pub(super) fn value_error(&self, value: &Value) -> Option<String> {
+        if let Some(error) = self.type_error(SchemaType::of(value)) {
+            return Some(error);
+        }
+        if !self.choices.is_empty() && !self.choices.contains(value) {
+            let choices = self
+                .choices
+                .iter()
+                .map(|choice| {
+                    choice
+                        .to_text_scalar()
+                        .unwrap_or_else(|| choice.kind_name().into())
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            return Some(format!("must be one of {choices}"));
+        }
+        None
+    }
+
+    pub(super) fn type_error(&self, kind: SchemaType) -> Option<String> {
+        if self.allows_type(kind) {
+            return None;
+        }
+        let expected = self
+            .types
+            .iter()
+            .map(|kind| kind.name())
+            .collect::<Vec<_>>()
+            .join(" or ");
+        Some(format!("expected {expected}, found {}", kind.name()))
+    }
+}
@@ -1,5 +1,6 @@
 use indexmap::IndexMap;
 
+use crate::core::i18n::Message;
 use crate::core::value::Value;
 use crate::core::value_path::{PathSegment, ValuePath, ValueTarget};
 use crate::runtime::event::{SystemEvent, ValueChange, WidgetAction};
@@ -7,7 +8,7 @@ use crate::state::step::StepCondition;
 use crate::state::store::ValueStore;
 use crate::terminal::{CursorPos, KeyCode, KeyEvent, PointerEvent};
 use crate::ui::span::{Span, SpanLine};
-use crate::ui::style::{Color, Style};
+use crate::ui::style::{Color, Style, StyleRole};
 use crate::ui::text::text_display_width;
 use crate::widgets::base::WidgetBase;
 use crate::widgets::node::{LeafComponent, Node};
@@ -16,7 +17,8 @@ use crate::widgets::shared::render_ctx::child_context_for;
 use crate::widgets::shared::validation::decorate_component_validation;
 use crate::widgets::traits::{
     CompletionState, DrawOutput, Drawable, FocusMode, HintContext, HintGroup, HintItem,
-    InteractionResult, Interactive, RenderContext, StoreSyncPolicy, TextAction, ValidationMode,
+    InteractionResult, Interactive, RenderContext, StoreSyncPolicy, TextAction, ValidationIssue,
+    ValidationMode,
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -431,9 +433,9 @@ impl Repeater {
                 Span::styled(
                     "❯ ",
                     if focused {
-                        Style::new().color(Color::Cyan).bold()
+                        StyleRole::Cursor.style().bold()
                     } else {
-                        Style::new().color(Color::DarkGrey)
+                        StyleRole::Hint.style()
                     },
                 )
                 .no_wrap(),
@@ -464,9 +466,9 @@ impl Repeater {
                     Span::styled(
                         if is_active { "❯ " } else { "  " },
                         if focused && is_active {
-                            Style::new().color(Color::Cyan).bold()
+                            StyleRole::Cursor.style().bold()
                         } else {
-                            Style::new().color(Color::DarkGrey)
+                            StyleRole::Hint.style()
                         },
                     )
                     .no_wrap(),
@@ -553,10 +555,7 @@ impl Repeater {
                 self.active_widget = next_index;
                 result.handled = true;
                 result.request_render = true;
-            } else if self.begin_finish_resolution() {
-                result.handled = true;
-                result.request_render = true;
-            } else if self.next_iteration() {
+            } else if self.begin_finish_resolution() || self.next_iteration() {
                 result.handled = true;
                 result.request_render = true;
             } else {
@@ -668,7 +667,7 @@ impl Drawable for Repeater {
         let mut lines = Vec::<SpanLine>::new();
 
         if self.show_label && !self.base.label().is_empty() {
-            lines.push(vec![Span::new(self.base.label()).no_wrap()]);
+            lines.push(ctx.label_line(self.base.label(), StyleRole::Label.style()));
         }
         if let Some(header) = self.header_line() {
             lines.push(vec![
@@ -678,7 +677,7 @@ impl Drawable for Repeater {
 
         if let Some(progress) = self.progress_line() {
             lines.push(vec![
-                Span::styled(progress, Style::new().color(Color::DarkGrey)).no_wrap(),
+                Span::styled(progress, StyleRole::Hint.style()).no_wrap(),
             ]);
         }
 
@@ -783,6 +782,10 @@ impl Interactive for Repeater {
         InteractionResult::input_done()
     }
 
+    fn wants_tick(&self) -> bool {
+        self.pending_finish_done
+    }
+
     fn on_text_action(&mut self, action: TextAction) -> InteractionResult {
         let Some(widget) = self.active_widget_mut() else {
             return InteractionResult::ignored();
@@ -795,6 +798,18 @@ impl Interactive for Repeater {
         }
     }
 
+    fn on_paste(&mut self, text: &str) -> InteractionResult {
+        let Some(widget) = self.active_widget_mut() else {
+            return InteractionResult::ignored();
+        };
+        let result = widget.on_paste(text);
+        if result.handled {
+            self.process_child_result(result)
+        } else {
+            InteractionResult::ignored()
+        }
+    }
+
     fn completion(&mut self) -> Option<CompletionState<'_>> {
         self.active_widget_mut()?.completion()
     }
@@ -844,13 +859,32 @@ impl Interactive for Repeater {
         changed || child_changed || finish_changed
     }
 
-    fn validate(&self, mode: ValidationMode) -> Result<(), String> {
+    fn validate(&self, mode: ValidationMode) -> Result<(), Message> {
         for widget in &self.widgets {
             widget.validate(mode)?;
         }
         Ok(())
     }
 
+    fn validation_issues(&self, mode: ValidationMode) -> Vec<ValidationIssue> {
+        self.widgets
+            .iter()
+            .filter_map(|widget| {
+                let error = widget.validate(mode).err()?;
+                Some(ValidationIssue::new(error).at(widget.label(), widget.id()))
+            })
+            .collect()
+    }
+
+    /// Targets are the ids of the current item's widgets.
+    fn reveal(&mut self, target: &str) -> bool {
+        let Some(index) = self.widgets.iter().position(|widget| widget.id() == target) else {
+            return false;
+        };
+        self.active_widget = index;
+        true
+    }
+
     fn task_specs(&self) -> Vec<crate::task::TaskSpec> {
         self.widgets.iter().flat_map(Node::task_specs).collect()
     }
@@ -886,7 +920,7 @@ fn empty_row() -> Value {
 }
 
 fn empty_line(text: &str) -> SpanLine {
-    vec![Span::styled(text.to_string(), Style::new().color(Color::DarkGrey)).no_wrap()]
+    vec![Span::styled(text.to_string(), StyleRole::Hint.style()).no_wrap()]
 }
 
 fn display_scalar_or_json(value: &Value) -> String {
@@ -0,0 +1,106 @@
+use super::{SelectItem, SelectList};
+
+impl SelectList {
+    pub(super) fn is_group_at(&self, index: usize) -> bool {
+        self.options.get(index).is_some_and(SelectItem::is_group)
+    }
+
+    pub(super) fn first_selectable(&self) -> Option<usize> {
+        self.options.iter().position(|option| !option.is_group())
+    }
+
+    /// Moves by `delta` rows, wrapping around and stepping over group
+    /// headers. The header above the landing row is scrolled into view too.
+    pub(super) fn move_active_selectable(&mut self, delta: isize) -> bool {
+        let total = self.options.len();
+        let start = self.active_index;
+        let mut moved = false;
+        for _ in 0..total {
+            moved = self
+                .scroll
+                .move_active_wrapped(&mut self.active_index, total, delta);
+            if !moved || !self.is_group_at(self.active_index) {
+                break;
+            }
+        }
+        if self.is_group_at(self.active_index) {
+            self.active_index = start;
+            return false;
+        }
+        self.reveal_group_header();
+        moved && self.active_index != start
+    }
+
+    /// Moves the cursor off a group header onto the next option, or the
+    /// previous one at the end of the list.
+    pub(super) fn skip_group_header(&mut self) {
+        if !self.is_group_at(self.active_index) {
+            return;
+        }
+        let next = (self.active_index..self.options.len())
+            .find(|index| !self.is_group_at(*index))
+            .or_else(|| {
+                (0..self.active_index)
+                    .rev()
+                    .find(|index| !self.is_group_at(*index))
+            });
+        if let Some(index) = next {
+            self.set_active_index(index);
+            self.reveal_group_header();
+        }
+    }
+
+    fn reveal_group_header(&mut self) {
+        let total = self.options.len();
+        if let Some(header) = self.active_index.checked_sub(1)
+            && self.is_group_at(header)
+        {
+            self.scroll.ensure_visible(header, total);
+            self.scroll.ensure_visible(self.active_index, total);
+        }
+    }
+}
+
+/// Orders filter matches by their group, in source order, and puts each
+/// group's header above its first match. Matches keep their rank within a
+/// group.
+pub(super) fn regroup<T>(
+    ranked: Vec<(usize, T)>,
+    source_options: &[SelectItem],
+    header: impl Fn(usize) -> T,
+) -> Vec<(usize, T)> {
+    let mut current = None;
+    let group_of = source_options
+        .iter()
+        .enumerate()
+        .map(|(index, option)| {
+            if option.is_group() {
+                current = Some(index);
+            }
+            current
+        })
+        .collect::<Vec<_>>();
+    if group_of.iter().all(Option::is_none) {
+        return ranked;
+    }
+
+    let mut ranked = ranked
+        .into_iter()
+        .filter(|(index, _)| !source_options[*index].is_group())
+        .collect::<Vec<_>>();
+    ranked.sort_by_key(|(index, _)| group_of[*index]);
+
+    let mut grouped = Vec::with_capacity(ranked.len());
+    let mut last_group = None;
+    for (index, item) in ranked {
+        let group = group_of[index];
+        if let Some(group_index) = group
+            && group != last_group
+        {
+            grouped.push((group_index, header(group_index)));
+        }
+        last_group = group;
+        grouped.push((index, item));
+    }
+    grouped
+}
@@ -1,17 +1,25 @@
+mod groups;
 mod model;
 mod render;
+mod selection;
+mod source;
 mod state;
+#[cfg(test)]
+#[path = "../tests/select_list.rs"]
+mod tests;
 
 use std::sync::Arc;
 
+use crate::core::i18n::Message;
 use crate::core::value::Value;
 use crate::runtime::event::WidgetAction;
 use crate::terminal::{
     CursorPos, KeyCode, KeyEvent, PointerButton, PointerEvent, PointerKind, PointerSemantic,
 };
+use crate::ui::fill::paint_selection;
 use crate::ui::layout::{Layout, LineContinuation, RenderBlock};
 use crate::ui::span::Span;
-use crate::ui::style::{Color, Style};
+use crate::ui::style::{Color, Style, StyleRole};
 use crate::widgets::base::WidgetBase;
 use crate::widgets::node::LeafComponent;
 use crate::widgets::shared::filter;
@@ -21,6 +29,7 @@ use crate::widgets::shared::scroll::ScrollState;
 use crate::widgets::traits::{
     CompletionState, DrawOutput, Drawable, FocusMode, HintContext, HintGroup, HintItem,
     InteractionResult, Interactive, PointerRowMap, RenderContext, StoreSyncPolicy, TextAction,
+    ValidationMode,
 };
 use model::item_search_text;
 use render::{OptionRenderer, default_option_renderer};
@@ -28,6 +37,7 @@ use state::marker_symbol;
 
 pub use model::{SelectItem, SelectItemView, SelectMode};
 pub use render::{SelectItemRenderState, default_render_option_lines};
+pub use source::OptionLoader;
 
 pub struct SelectList {
     base: WidgetBase,
@@ -38,9 +48,12 @@ pub struct SelectList {
     selected: Vec<usize>,
     active_index: usize,
     scroll: ScrollState,
+    scrollbar: bool,
     show_label: bool,
     filter: filter::ListFilter,
     option_renderer: OptionRenderer,
+    source: Option<source::AsyncSource>,
+    max_selected: Option<usize>,
 }
 
 impl SelectList {
@@ -56,6 +69,7 @@ impl SelectList {
             selected: Vec::new(),
             active_index: 0,
             scroll: ScrollState::new(None),
+            scrollbar: false,
             show_label: true,
             filter: filter::ListFilter::new(
                 format!("{id}__filter"),
@@ -63,6 +77,8 @@ impl SelectList {
                 true,
             ),
             option_renderer: default_option_renderer(),
+            source: None,
+            max_selected: None,
         };
         this.apply_filter(None);
         this
@@ -100,6 +116,22 @@ impl SelectList {
         self
     }
 
+    /// Draws a scrollbar beside the options instead of the `[1-5 of 20]`
+    /// footer.
+    pub fn with_scrollbar(mut self, scrollbar: bool) -> Self {
+        self.scrollbar = scrollbar;
+        self
+    }
+
+    /// Width left for options once the scrollbar column is taken.
+    fn options_width(&self, width: u16) -> u16 {
+        if self.scrollbar && self.scroll.scrollbar(self.options.len()).is_some() {
+            width.saturating_sub(1).max(1)
+        } else {
+            width
+        }
+    }
+
     pub fn set_max_visible(&mut self, max_visible: usize) {
         self.scroll.set_max_visible(max_visible);
         self.scroll.offset = 0;
@@ -156,7 +188,11 @@ impl SelectList {
     pub fn with_selected(mut self, selected: Vec<usize>) -> Self {
         self.selected = selected
             .into_iter()
-            .filter(|index| *index < self.source_options.len())
+            .filter(|index| {
+                self.source_options
+                    .get(*index)
+                    .is_some_and(|option| !option.is_group())
+            })
             .collect();
         self.ensure_radio_selection();
         self.apply_filter(None);
@@ -203,13 +239,19 @@ impl SelectList {
     fn ensure_radio_selection(&mut self) {
         if self.mode == SelectMode::Radio
             && self.selected.is_empty()
-            && !self.source_options.is_empty()
+            && let Some(first) = self
+                .source_options
+                .iter()
+                .position(|option| !option.is_group())
         {
-            self.selected.push(0);
+            self.selected.push(first);
         }
     }
 
     fn apply_filter_on_change(&mut self, outcome: filter::ListFilterUpdate) -> InteractionResult {
+        if self.source.is_some() {
+            return outcome.refresh_if_changed(|| self.schedule_load());
+        }
         outcome.refresh_if_changed(|| self.apply_filter(None))
     }
 
@@ -219,7 +261,8 @@ impl SelectList {
         let query = self.filter.query();
         let query = query.trim();
 
-        if query.is_empty() {
+        // A loader has already matched its options against the query.
+        if query.is_empty() || self.source.is_some() {
             self.options = self.source_options.clone();
             self.visible_to_source = (0..self.source_options.len()).collect();
         } else {
@@ -253,13 +296,17 @@ impl SelectList {
         let active = self.active_index;
         self.scroll
             .set_active_clamped(&mut self.active_index, self.options.len(), active);
+        self.skip_group_header();
     }
 
     fn option_line_count_for_pointer(&self, index: usize, wrap_width: u16) -> usize {
         let Some(option) = self.options.get(index) else {
             return 0;
         };
-        let inactive_style = Style::new().color(Color::DarkGrey);
+        if option.is_group() {
+            return 1;
+        }
+        let inactive_style = StyleRole::Hint.style();
         let selected = self
             .visible_to_source
             .get(index)
@@ -319,6 +366,9 @@ impl SelectList {
         let Some(source_index) = self.visible_to_source.get(index).copied() else {
             return;
         };
+        if self.is_group_at(index) {
+            return;
+        }
 
         match self.mode {
             SelectMode::Multi => {
@@ -343,8 +393,7 @@ impl SelectList {
     }
 
     fn move_active(&mut self, delta: isize) -> bool {
-        self.scroll
-            .move_active_wrapped(&mut self.active_index, self.options.len(), delta)
+        self.move_active_selectable(delta)
     }
 
     fn activate_current(&mut self) -> bool {
@@ -364,7 +413,7 @@ impl SelectList {
 
         self.filter.set_focused(false);
         let index = event.row as usize;
-        if index >= self.options.len() {
+        if index >= self.options.len() || self.is_group_at(index) {
             return InteractionResult::ignored();
         }
         self.set_active_index(index);
@@ -384,6 +433,9 @@ impl SelectList {
             rows.push(PointerRowMap::new(rendered_row, 0).with_semantic(PointerSemantic::Filter));
             rendered_row = rendered_row.saturating_add(1);
         }
+        if self.source_status_line().is_some() {
+            rendered_row = rendered_row.saturating_add(1);
+        }
 
         let total = self.options.len();
         let (start, end) = self.scroll.visible_range(total);
@@ -449,18 +501,25 @@ impl SelectList {
 
     fn line_items(&self, focused: bool, wrap_width: u16) -> Vec<Vec<Span>> {
         let mut lines = Vec::<Vec<Span>>::new();
-        let inactive_style = Style::new().color(Color::DarkGrey);
-        let marker_selected_style = Style::new().color(Color::Green);
-        let cursor_style = Style::new().color(Color::Yellow);
+        let inactive_style = StyleRole::Hint.style();
+        let marker_selected_style = StyleRole::Selected.style();
+        let cursor_style = StyleRole::Cursor.style();
         let highlight_style = Style::new().color(Color::Yellow).bold();
 
         let total = self.options.len();
         let (start, end) = self.scroll.visible_range(total);
 
         for index in start..end {
+            let option_start = lines.len();
             let Some(option) = self.options.get(index) else {
                 continue;
             };
+            if let SelectItemView::Group { title } = &option.view {
+                lines.push(vec![
+                    Span::styled(title.as_str(), StyleRole::Label.style().bold()).no_wrap(),
+                ]);
+                continue;
+            }
             let active = index == self.active_index;
             let selected = self
                 .visible_to_source
@@ -470,7 +529,7 @@ impl SelectList {
 
             if self.mode == SelectMode::List {
                 let base_style = if focused && active {
-                    Style::new().color(Color::Cyan).bold()
+                    StyleRole::Active.style()
                 } else if selected {
                     marker_selected_style
                 } else {
@@ -508,6 +567,9 @@ impl SelectList {
                         }),
                     ));
                 }
+                if focused && active {
+                    paint_selection(&mut lines[option_start..], wrap_width);
+                }
                 continue;
             }
 
@@ -560,6 +622,9 @@ impl SelectList {
                     }),
                 ));
             }
+            if focused && active {
+                paint_selection(&mut lines[option_start..], wrap_width);
+            }
         }
 
         let placeholders = self.scroll.placeholder_count(total);
@@ -567,10 +632,12 @@ impl SelectList {
             lines.push(vec![Span::new(" ").no_wrap()]);
         }
 
-        if let Some(text) = self.scroll.footer(total) {
-            lines.push(vec![
-                Span::styled(text, Style::new().color(Color::DarkGrey)).no_wrap(),
-            ]);
+        if self.scrollbar {
+            if let Some(scrollbar) = self.scroll.scrollbar(total) {
+                scrollbar.attach(&mut lines, wrap_width);
+            }
+        } else if let Some(text) = self.scroll.footer(total) {
+            lines.push(vec![Span::styled(text, StyleRole::Hint.style()).no_wrap()]);
         }
 
         lines
@@ -588,7 +655,11 @@ impl SelectList {
 
         match key.code {
             KeyCode::Up => {
-                if self.filter.is_visible() && self.active_index == 0 {
+                if self.filter.is_visible()
+                    && self
+                        .first_selectable()
+                        .is_none_or(|first| first == self.active_index)
+                {
                     self.filter.set_focused(true);
                     return InteractionResult::handled();
                 }
@@ -629,20 +700,21 @@ impl Drawable for SelectList {
 
         let mut lines = Vec::<Vec<Span>>::new();
         if self.show_label && !self.base.label().is_empty() {
-            lines.push(vec![Span::new(self.base.label()).no_wrap()]);
+            lines.push(ctx.label_line(self.base.label(), StyleRole::Label.style()));
         }
 
         if self.filter.is_visible() {
             lines.push(self.filter.draw_line(ctx, focused));
         }
+        lines.extend(self.source_status_line());
 
-        let wrap_width = ctx.terminal_size.width.max(1);
+        let wrap_width = self.options_width(ctx.terminal_size.width.max(1));
         lines.extend(self.line_items(focused && !self.filter.is_focused(), wrap_width));
         DrawOutput::with_lines(lines)
     }
 
     fn pointer_rows(&self, ctx: &RenderContext) -> Vec<PointerRowMap> {
-        self.pointer_rows_for_draw(ctx.terminal_size.width.max(1))
+        self.pointer_rows_for_draw(self.options_width(ctx.terminal_size.width.max(1)))
     }
 
     fn hints(&self, ctx: HintContext) -> Vec<HintItem> {
@@ -653,6 +725,9 @@ impl Drawable for SelectList {
         if hints.is_empty() {
             return hints;
         }
+        if self.mode != SelectMode::Multi {
+            hints.retain(|hint| hint.key != "Ctrl+A / Ctrl+I");
+        }
         if self.mode != SelectMode::List {
             hints.retain(|hint| hint.key != "Space");
         } else {
@@ -683,6 +758,11 @@ impl Interactive for SelectList {
             return self.apply_filter_on_change(outcome);
         }
 
+        // Works from the filter too, to pick everything it matched.
+        if let Some(changed) = self.handle_bulk_select_key(key) {
+            return InteractionResult::handled_if(changed);
+        }
+
         if self.filter.is_focused() {
             return self.handle_filter_key(key);
         }
@@ -691,6 +771,13 @@ impl Interactive for SelectList {
     }
 
     fn on_pointer(&mut self, event: PointerEvent) -> InteractionResult {
+        if let Some(delta) = event.kind.wheel_delta() {
+            return InteractionResult::handled_if(self.scroll.move_active_clamped(
+                &mut self.active_index,
+                self.options.len(),
+                delta,
+            ));
+        }
         match event.kind {
             PointerKind::Down(PointerButton::Left) => self.handle_pointer_left_down(event),
             _ => InteractionResult::ignored(),
@@ -706,6 +793,15 @@ impl Interactive for SelectList {
         self.apply_filter_on_change(outcome)
     }
 
+    fn on_paste(&mut self, text: &str) -> InteractionResult {
+        if !self.filter.is_focused() {
+            return InteractionResult::ignored();
+        }
+
+        let outcome = self.filter.handle_paste(text);
+        self.apply_filter_on_change(outcome)
+    }
+
     fn completion(&mut self) -> Option<CompletionState<'_>> {
         if !self.filter.is_focused() {
             return None;
@@ -713,6 +809,14 @@ impl Interactive for SelectList {
         self.filter.completion()
     }
 
+    fn on_tick(&mut self) -> InteractionResult {
+        InteractionResult::handled_if(self.tick_source())
+    }
+
+    fn wants_tick(&self) -> bool {
+        self.source_wants_tick()
+    }
+
     fn cursor_pos(&self) -> Option<CursorPos> {
         if self.filter.is_focused() {
             let row = if self.show_label && !self.base.label().is_empty() {
@@ -741,13 +845,14 @@ impl Interactive for SelectList {
     }
 
     fn set_value(&mut self, value: Value) {
+        self.ensure_source_options(&value);
         if let Some(values) = value.as_list() {
             self.selected.clear();
             for value in values {
                 if let Some(index) = self
                     .source_options
                     .iter()
-                    .position(|option| option.value == *value)
+                    .position(|option| !option.is_group() && option.value == *value)
                     && !self.selected.contains(&index)
                 {
                     self.selected.push(index);
@@ -757,7 +862,7 @@ impl Interactive for SelectList {
         } else if let Some(index) = self
             .source_options
             .iter()
-            .position(|option| option.value == value)
+            .position(|option| !option.is_group() && option.value == value)
         {
             self.selected.clear();
             self.selected.push(index);
@@ -773,10 +878,18 @@ impl Interactive for SelectList {
         self.set_options(options);
         true
     }
+
+    fn validate(&self, _mode: ValidationMode) -> Result<(), Message> {
+        self.validate_selection_count()
+    }
 }
 
 fn filter_options(query: &str, source_options: &[SelectItem]) -> (Vec<SelectItem>, Vec<usize>) {
-    let ranked = list_policy::rank_by_filter(query, source_options, filter_fields_for_item);
+    let ranked = groups::regroup(
+        list_policy::rank_by_filter(query, source_options, filter_fields_for_item),
+        source_options,
+        |_| Vec::new(),
+    );
     let mut mapping = Vec::<usize>::with_capacity(ranked.len());
     let mut options = Vec::<SelectItem>::with_capacity(ranked.len());
 
@@ -812,7 +925,8 @@ fn filter_fields_for_item(item: &SelectItem) -> Vec<list_policy::FilterField<'_>
         | SelectItemView::Styled { .. }
         | SelectItemView::Split { .. }
         | SelectItemView::Suffix { .. }
-        | SelectItemView::SplitSuffix { .. } => vec![search],
+        | SelectItemView::SplitSuffix { .. }
+        | SelectItemView::Group { .. } => vec![search],
     }
 }
 
@@ -846,6 +960,7 @@ fn with_highlights(
         } => {
             *highlights = list_policy::text_match_ranges(query, text.as_str());
         }
+        SelectItemView::Group { .. } => {}
     }
     highlighted
 }
@@ -883,6 +998,9 @@ fn option_from_object_value(value: &Value) -> Option<SelectItem> {
     let Value::Object(map) = value else {
         return None;
     };
+    if let Some(group) = map.get("group").and_then(Value::to_text_scalar) {
+        return Some(SelectItem::group(group));
+    }
 
     let value_text = map
         .get("value")
@@ -1,5 +1,5 @@
 use crate::core::value::Value;
-use crate::ui::style::{Color, Style};
+use crate::ui::style::{Style, StyleRole};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SelectMode {
@@ -58,6 +58,9 @@ pub enum SelectItemView {
         name_style: Style,
         suffix_style: Style,
     },
+    /// Section header: not selectable, and kept above its matching options
+    /// while filtering.
+    Group { title: String },
 }
 
 impl SelectItem {
@@ -99,11 +102,26 @@ impl SelectItem {
                 title_highlights: Vec::new(),
                 description_highlights: Vec::new(),
                 title_style: Style::new().bold(),
-                description_style: Style::new().color(Color::DarkGrey),
+                description_style: StyleRole::Hint.style(),
             },
         }
     }
 
+    /// Header for the options listed after it, up to the next group.
+    pub fn group(title: impl Into<String>) -> Self {
+        Self {
+            value: Value::None,
+            search_text: String::new(),
+            view: SelectItemView::Group {
+                title: title.into(),
+            },
+        }
+    }
+
+    pub fn is_group(&self) -> bool {
+        matches!(self.view, SelectItemView::Group { .. })
+    }
+
     pub fn with_value(mut self, value: Value) -> Self {
         self.value = value;
         self
@@ -129,5 +147,6 @@ fn search_text_from_view(view: &SelectItemView) -> String {
         SelectItemView::Detailed {
             title, description, ..
         } => format!("{title} {description}"),
+        SelectItemView::Group { .. } => String::new(),
     }
 }
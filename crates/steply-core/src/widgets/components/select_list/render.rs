@@ -147,6 +147,9 @@ pub fn default_render_option_lines(
             }
             vec![spans]
         }
+        SelectItemView::Group { title } => vec![vec![
+            Span::styled(title.as_str(), base_style.merge(Style::new().bold())).no_wrap(),
+        ]],
     }
 }
 
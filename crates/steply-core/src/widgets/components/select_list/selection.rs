@@ -0,0 +1,65 @@
+use super::{SelectList, SelectMode};
+use crate::core::i18n::Message;
+use crate::terminal::KeyEvent;
+use crate::widgets::shared::keymap;
+
+impl SelectList {
+    /// Most options a multi-select list accepts; selecting more is allowed
+    /// but fails validation.
+    pub fn with_max_selected(mut self, max_selected: usize) -> Self {
+        self.max_selected = Some(max_selected);
+        self
+    }
+
+    /// `Ctrl+A` selects every visible option, or clears them when all are
+    /// already selected; `Ctrl+I` inverts them. Multi mode only.
+    pub(super) fn handle_bulk_select_key(&mut self, key: KeyEvent) -> Option<bool> {
+        if self.mode != SelectMode::Multi {
+            return None;
+        }
+        if keymap::is_ctrl_char(key, 'a') {
+            let all_selected = self
+                .visible_selectable()
+                .all(|source| self.selected.contains(&source));
+            return Some(self.set_visible_selected(|_| !all_selected));
+        }
+        if keymap::is_ctrl_char(key, 'i') {
+            return Some(self.set_visible_selected(|selected| !selected));
+        }
+        None
+    }
+
+    pub(super) fn validate_selection_count(&self) -> Result<(), Message> {
+        match self.max_selected {
+            Some(max) if self.mode == SelectMode::Multi && self.selected.len() > max => {
+                Err(format!("select at most {max} (selected {})", self.selected.len()).into())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn visible_selectable(&self) -> impl Iterator<Item = usize> + '_ {
+        self.visible_to_source.iter().copied().filter(|source| {
+            self.source_options
+                .get(*source)
+                .is_some_and(|option| !option.is_group())
+        })
+    }
+
+    /// Sets each visible option to `select(currently_selected)`; options
+    /// hidden by the filter keep their state.
+    fn set_visible_selected(&mut self, select: impl Fn(bool) -> bool) -> bool {
+        let visible = self.visible_selectable().collect::<Vec<_>>();
+        let before = self.selected.clone();
+        for source in visible {
+            let selected = self.selected.contains(&source);
+            if select(selected) && !selected {
+                self.selected.push(source);
+            } else if !select(selected) && selected {
+                self.selected.retain(|index| *index != source);
+            }
+        }
+        self.selected.sort_unstable();
+        self.selected != before
+    }
+}
@@ -0,0 +1,250 @@
+use std::sync::Arc;
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::mpsc::{self, Receiver, Sender};
+
+use super::{SelectItem, SelectList};
+use crate::core::value::Value;
+use crate::time::Duration;
+use crate::ui::span::Span;
+use crate::ui::spinner::Spinner;
+use crate::ui::style::StyleRole;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::widgets::shared::async_utils::{drain_receiver, recv_latest};
+use crate::widgets::shared::timers::WidgetTimers;
+
+/// Loads the options matching a filter query. Runs off the UI thread, so
+/// it may block on network or database calls.
+pub type OptionLoader = Arc<dyn Fn(&str) -> Result<Vec<SelectItem>, String> + Send + Sync>;
+
+pub(super) struct LoadRequest {
+    pub seq: u64,
+    pub query: String,
+}
+
+pub(super) struct LoadResult {
+    pub seq: u64,
+    pub options: Result<Vec<SelectItem>, String>,
+}
+
+/// Worker running an [`OptionLoader`]; only the newest queued query is
+/// loaded.
+pub(super) struct SourceHandle {
+    #[cfg(not(target_arch = "wasm32"))]
+    tx: Sender<LoadRequest>,
+    #[cfg(not(target_arch = "wasm32"))]
+    rx: Receiver<LoadResult>,
+    #[cfg(target_arch = "wasm32")]
+    loader: OptionLoader,
+    #[cfg(target_arch = "wasm32")]
+    done: Vec<LoadResult>,
+}
+
+impl SourceHandle {
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn new(loader: OptionLoader) -> Self {
+        let (req_tx, req_rx) = mpsc::channel::<LoadRequest>();
+        let (res_tx, res_rx) = mpsc::channel::<LoadResult>();
+        std::thread::spawn(move || worker(loader, req_rx, res_tx));
+        Self {
+            tx: req_tx,
+            rx: res_rx,
+        }
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub fn new(loader: OptionLoader) -> Self {
+        Self {
+            loader,
+            done: Vec::new(),
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn submit(&mut self, request: LoadRequest) {
+        let _ = self.tx.send(request);
+    }
+
+    /// Without threads the loader runs in place; the result is still only
+    /// picked up by the next poll.
+    #[cfg(target_arch = "wasm32")]
+    pub fn submit(&mut self, request: LoadRequest) {
+        self.done.push(LoadResult {
+            seq: request.seq,
+            options: (self.loader)(request.query.as_str()),
+        });
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn try_recv_all(&mut self) -> Vec<LoadResult> {
+        drain_receiver(&self.rx)
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub fn try_recv_all(&mut self) -> Vec<LoadResult> {
+        std::mem::take(&mut self.done)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn worker(loader: OptionLoader, rx: Receiver<LoadRequest>, tx: Sender<LoadResult>) {
+    while let Some(req) = recv_latest(&rx) {
+        let options = loader(req.query.as_str());
+        if tx
+            .send(LoadResult {
+                seq: req.seq,
+                options,
+            })
+            .is_err()
+        {
+            break;
+        }
+    }
+}
+
+const LOAD_DEBOUNCE_MS: u64 = 150;
+const LOAD_TIMER: &str = "load";
+const SPINNER_TIMER: &str = "spinner";
+const SPINNER_INTERVAL_MS: u64 = 80;
+
+pub(super) struct AsyncSource {
+    handle: SourceHandle,
+    seq: u64,
+    loading: bool,
+    error: Option<String>,
+    timers: WidgetTimers,
+    spinner: Spinner,
+}
+
+impl SelectList {
+    /// Fetches options with `loader` instead of taking them up front: once
+    /// at start, then for each filter query after typing pauses. Selected
+    /// options stay listed when a new query no longer returns them.
+    pub fn with_async_source<F>(mut self, loader: F) -> Self
+    where
+        F: Fn(&str) -> Result<Vec<SelectItem>, String> + Send + Sync + 'static,
+    {
+        self.source = Some(AsyncSource {
+            handle: SourceHandle::new(Arc::new(loader)),
+            seq: 0,
+            loading: false,
+            error: None,
+            timers: WidgetTimers::new(),
+            spinner: Spinner::default(),
+        });
+        self.load_options();
+        self
+    }
+
+    pub(super) fn schedule_load(&mut self) {
+        if let Some(source) = self.source.as_mut() {
+            source
+                .timers
+                .schedule_after(LOAD_TIMER, Duration::from_millis(LOAD_DEBOUNCE_MS));
+        }
+    }
+
+    fn load_options(&mut self) {
+        let query = self.filter.query().trim().to_string();
+        let Some(source) = self.source.as_mut() else {
+            return;
+        };
+        source.seq = source.seq.wrapping_add(1);
+        source.loading = true;
+        source.handle.submit(LoadRequest {
+            seq: source.seq,
+            query,
+        });
+    }
+
+    /// Fires a due reload, applies the newest result and advances the
+    /// spinner; `true` when something visible changed.
+    pub(super) fn tick_source(&mut self) -> bool {
+        let Some(source) = self.source.as_mut() else {
+            return false;
+        };
+        let reload = source.timers.fire(LOAD_TIMER);
+        let latest = source
+            .handle
+            .try_recv_all()
+            .into_iter()
+            .rfind(|result| result.seq == source.seq);
+        let spun = source.loading
+            && source
+                .timers
+                .throttle(SPINNER_TIMER, Duration::from_millis(SPINNER_INTERVAL_MS));
+        if spun {
+            source.spinner.tick();
+        }
+
+        let loaded = latest.is_some();
+        if let Some(result) = latest {
+            source.loading = false;
+            match result.options {
+                Ok(options) => {
+                    source.error = None;
+                    self.apply_loaded_options(options);
+                }
+                Err(error) => source.error = Some(error),
+            }
+        }
+        if reload {
+            self.load_options();
+        }
+        reload || loaded || spun
+    }
+
+    pub(super) fn source_wants_tick(&self) -> bool {
+        self.source
+            .as_ref()
+            .is_some_and(|source| source.loading || source.timers.has_pending())
+    }
+
+    fn apply_loaded_options(&mut self, options: Vec<SelectItem>) {
+        let mut merged = self
+            .selected
+            .iter()
+            .filter_map(|index| self.source_options.get(*index))
+            .filter(|kept| !options.iter().any(|option| option.value == kept.value))
+            .cloned()
+            .collect::<Vec<_>>();
+        merged.extend(options);
+        self.set_options(merged);
+    }
+
+    /// Lists values set before the loader has returned them, so they can
+    /// still be selected.
+    pub(super) fn ensure_source_options(&mut self, value: &Value) {
+        if self.source.is_none() {
+            return;
+        }
+        let values = value.as_list().unwrap_or(std::slice::from_ref(value));
+        for value in values {
+            if self
+                .source_options
+                .iter()
+                .any(|option| option.value == *value)
+            {
+                continue;
+            }
+            if let Some(text) = value.to_text_scalar() {
+                self.source_options
+                    .push(SelectItem::plain(text).with_value(value.clone()));
+            }
+        }
+    }
+
+    /// Spinner while loading, or the loader's error.
+    pub(super) fn source_status_line(&self) -> Option<Vec<Span>> {
+        let source = self.source.as_ref()?;
+        if source.loading {
+            return Some(vec![
+                source.spinner.span(),
+                Span::styled(" loading…", StyleRole::Hint.style()).no_wrap(),
+            ]);
+        }
+        let error = source.error.as_ref()?;
+        Some(vec![
+            Span::styled(format!("✗ {error}"), StyleRole::Error.style()).no_wrap(),
+        ])
+    }
+}
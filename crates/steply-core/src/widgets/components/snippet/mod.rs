@@ -1,8 +1,9 @@
+use crate::core::i18n::Message;
 use crate::core::value::Value;
 use crate::terminal::{CursorPos, KeyCode, KeyEvent, KeyModifiers};
 use crate::ui::span::Span;
-use crate::ui::style::{Color, Style};
-use crate::ui::text::{char_display_width, text_display_width};
+use crate::ui::style::{Color, Style, StyleRole};
+use crate::ui::text::{grapheme_display_width, graphemes, text_display_width};
 use crate::widgets::base::WidgetBase;
 use crate::widgets::node::{Component, Node};
 use crate::widgets::traits::{
@@ -119,7 +120,7 @@ impl Snippet {
         focused: bool,
         ctx: &RenderContext,
     ) -> (Vec<Vec<Span>>, Option<(u16, u16)>) {
-        let dim = Style::new().color(Color::DarkGrey);
+        let dim = StyleRole::Hint.style();
         let active_st = Style::new().color(Color::Cyan);
         let inactive_input_st = Style::new().color(Color::White);
 
@@ -262,12 +263,13 @@ impl Interactive for Snippet {
         for chunk in &self.chunks {
             match chunk {
                 Chunk::Text(text) => {
-                    for ch in text.chars() {
-                        if ch == '\n' {
+                    for grapheme in graphemes(text) {
+                        if grapheme == "\n" {
                             row += 1;
                             col = 0;
                         } else {
-                            let width = char_display_width(ch).min(u16::MAX as usize) as u16;
+                            let width =
+                                grapheme_display_width(grapheme).min(u16::MAX as usize) as u16;
                             col = col.saturating_add(width);
                         }
                     }
@@ -337,7 +339,7 @@ impl Interactive for Snippet {
 
     fn set_value(&mut self, _value: Value) {}
 
-    fn validate(&self, mode: ValidationMode) -> Result<(), String> {
+    fn validate(&self, mode: ValidationMode) -> Result<(), Message> {
         for input in &self.inputs {
             input.validate(mode)?;
         }
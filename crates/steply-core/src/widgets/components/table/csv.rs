@@ -0,0 +1,185 @@
+use super::*;
+
+impl Table {
+    /// Replaces every row with the records of `csv`. The first record is
+    /// the header; its fields are matched to columns by key or header text,
+    /// and fields under unknown headers are dropped.
+    pub fn set_value_from_csv(&mut self, csv: &str) -> Result<(), String> {
+        let records = parse_records(csv, detect_delimiter(csv).unwrap_or(','));
+        let Some((header, body)) = records.split_first() else {
+            self.set_value(Value::List(Vec::new()));
+            return Ok(());
+        };
+        let mapping = self.csv_header_mapping(header);
+        if mapping.iter().all(Option::is_none) {
+            let expected = self
+                .columns
+                .iter()
+                .map(|col| col.header.as_str())
+                .collect::<Vec<_>>()
+                .join(", ");
+            return Err(format!(
+                "CSV header matches no table column (expected {expected})"
+            ));
+        }
+        let rows = body
+            .iter()
+            .map(|record| self.csv_record_value(record, mapping.as_slice()))
+            .collect();
+        self.set_value(Value::List(rows));
+        Ok(())
+    }
+
+    /// Renders every row as comma-separated values under a header line of
+    /// column headers.
+    pub fn to_csv(&self) -> String {
+        let mut out = String::new();
+        write_record(&mut out, self.columns.iter().map(|col| col.header.clone()));
+        for row in &self.rows {
+            write_record(
+                &mut out,
+                (0..self.columns.len()).map(|col_idx| {
+                    row.cells
+                        .get(col_idx)
+                        .and_then(|cell| cell.value())
+                        .map(|value| csv_field_text(&value))
+                        .unwrap_or_default()
+                }),
+            );
+        }
+        out
+    }
+
+    /// Appends pasted spreadsheet rows. A first line naming the columns is
+    /// read as a header; otherwise fields fill the columns in order.
+    pub(super) fn paste_rows(&mut self, text: &str, delimiter: char) {
+        let records = parse_records(text, delimiter);
+        let Some(first) = records.first() else {
+            return;
+        };
+        let header_mapping = self.csv_header_mapping(first);
+        let (mapping, body) = if header_mapping.iter().all(Option::is_some) {
+            (header_mapping, &records[1..])
+        } else {
+            let positional = (0..first.len())
+                .map(|idx| (idx < self.columns.len()).then_some(idx))
+                .collect();
+            (positional, &records[..])
+        };
+
+        let mut first_id = None;
+        for record in body {
+            let row_id = self.next_row_id;
+            self.next_row_id = self.next_row_id.saturating_add(1);
+            let seed = self.csv_record_value(record, mapping.as_slice());
+            self.rows.push(self.build_row(row_id, Some(&seed)));
+            first_id.get_or_insert(row_id);
+        }
+        if first_id.is_none() {
+            return;
+        }
+        self.refresh_column_widths();
+        self.focus = TableFocus::Body;
+        self.set_body_mode(TableBodyMode::Navigate);
+        self.apply_sort_preserving_focus(first_id);
+    }
+
+    fn csv_header_mapping(&self, header: &[String]) -> Vec<Option<usize>> {
+        header
+            .iter()
+            .map(|name| {
+                let name = name.trim();
+                let key = normalize_ascii_key(name, "");
+                self.columns
+                    .iter()
+                    .position(|col| col.key == key || col.header.eq_ignore_ascii_case(name))
+            })
+            .collect()
+    }
+
+    fn csv_record_value(&self, record: &[String], mapping: &[Option<usize>]) -> Value {
+        let mut map = IndexMap::<String, Value>::new();
+        for (field, col_idx) in record.iter().zip(mapping) {
+            if let Some(col) = col_idx.and_then(|idx| self.columns.get(idx))
+                && !field.is_empty()
+            {
+                map.insert(col.key.clone(), Value::Text(field.clone()));
+            }
+        }
+        Value::Object(map)
+    }
+}
+
+/// Tab when the first line has one (spreadsheet clipboards), else comma;
+/// `None` for text that does not look tabular.
+pub(super) fn detect_delimiter(text: &str) -> Option<char> {
+    let first_line = text.lines().next()?;
+    ['\t', ','].into_iter().find(|ch| first_line.contains(*ch))
+}
+
+/// Splits `text` into records, honouring double-quoted fields with `""`
+/// escapes and line breaks inside them. Blank lines are skipped.
+fn parse_records(text: &str, delimiter: char) -> Vec<Vec<String>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = text.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        if quoted {
+            match ch {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => quoted = false,
+                _ => field.push(ch),
+            }
+            continue;
+        }
+        match ch {
+            '"' if field.is_empty() => quoted = true,
+            '\r' => {}
+            '\n' => {
+                record.push(std::mem::take(&mut field));
+                push_record(&mut records, std::mem::take(&mut record));
+            }
+            _ if ch == delimiter => record.push(std::mem::take(&mut field)),
+            _ => field.push(ch),
+        }
+    }
+    record.push(field);
+    push_record(&mut records, record);
+    records
+}
+
+fn push_record(records: &mut Vec<Vec<String>>, record: Vec<String>) {
+    if !(record.len() == 1 && record[0].is_empty()) {
+        records.push(record);
+    }
+}
+
+fn write_record(out: &mut String, fields: impl Iterator<Item = String>) {
+    for (idx, field) in fields.enumerate() {
+        if idx > 0 {
+            out.push(',');
+        }
+        if field.contains([',', '"', '\n', '\r']) {
+            out.push('"');
+            out.push_str(field.replace('"', "\"\"").as_str());
+            out.push('"');
+        } else {
+            out.push_str(field.as_str());
+        }
+    }
+    out.push('\n');
+}
+
+fn csv_field_text(value: &Value) -> String {
+    match value {
+        Value::None => String::new(),
+        Value::List(_) | Value::Object(_) => value.to_json(),
+        scalar => scalar.to_text_scalar().unwrap_or_default(),
+    }
+}
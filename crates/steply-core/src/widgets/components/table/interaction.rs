@@ -1,4 +1,5 @@
 use super::*;
+use crate::core::i18n::Message;
 use crate::runtime::event::WidgetAction;
 use crate::terminal::{PointerButton, PointerEvent, PointerKind};
 use crate::widgets::shared::keymap;
@@ -61,7 +62,7 @@ impl Table {
     }
 
     fn pointer_column_at(&self, col: u16) -> Option<usize> {
-        let col_widths = self.compute_column_widths(&self.fallback_context());
+        let col_widths = self.column_widths(&self.fallback_context());
         let starts = self.body_col_starts(col_widths.as_slice());
         let mut selected = None;
         for (col_idx, start) in starts.iter().copied().enumerate() {
@@ -101,8 +102,8 @@ impl Table {
         if event.row < body_start {
             return InteractionResult::ignored();
         }
-        let visible_pos = event.row.saturating_sub(body_start) as usize;
-        let Some(row_idx) = self.visible_rows.get(visible_pos).copied() else {
+        let window_pos = event.row.saturating_sub(body_start) as usize;
+        let Some(row_idx) = self.window_rows().get(window_pos).copied() else {
             return InteractionResult::ignored();
         };
         self.focus = TableFocus::Body;
@@ -132,6 +133,7 @@ impl Table {
                     self.focus = TableFocus::Body;
                     self.active_row = self.visible_rows.first().copied().unwrap_or(0);
                     self.set_body_mode(TableBodyMode::Navigate);
+                    self.sync_scroll();
                     return InteractionResult::handled();
                 }
                 InteractionResult::ignored()
@@ -187,7 +189,7 @@ impl Table {
                     };
                     let result = filter_utils::sanitize_interaction_result(cell.on_key(key));
                     if result.handled {
-                        self.apply_filter(self.active_row_id());
+                        self.after_cell_edit();
                     }
                     result
                 }
@@ -220,7 +222,7 @@ impl Table {
         };
         let result = filter_utils::sanitize_interaction_result(cell.on_key(key));
         if result.handled {
-            self.apply_filter(self.active_row_id());
+            self.after_cell_edit();
             return result;
         }
         match key.code {
@@ -232,7 +234,23 @@ impl Table {
         }
     }
 
+    fn after_cell_edit(&mut self) {
+        self.refresh_column_width(self.active_col);
+        self.apply_filter(self.active_row_id());
+    }
+
     fn handle_filter_key(&mut self, key: KeyEvent) -> InteractionResult {
+        match key.code {
+            KeyCode::Tab if keymap::has_no_modifiers(key) => {
+                self.cycle_filter_column(false);
+                return InteractionResult::handled();
+            }
+            KeyCode::BackTab => {
+                self.cycle_filter_column(true);
+                return InteractionResult::handled();
+            }
+            _ => {}
+        }
         self.filter
             .handle_key(key)
             .refresh_if_changed(|| self.apply_filter(self.active_row_id()))
@@ -263,6 +281,12 @@ impl Interactive for Table {
     }
 
     fn on_pointer(&mut self, event: PointerEvent) -> InteractionResult {
+        if let Some(delta) = event.kind.wheel_delta() {
+            if self.is_body_edit_mode() {
+                return InteractionResult::ignored();
+            }
+            return InteractionResult::handled_if(self.move_active_visible(delta));
+        }
         match event.kind {
             PointerKind::Down(PointerButton::Left) => self.handle_pointer_left_down(event),
             _ => InteractionResult::ignored(),
@@ -284,7 +308,36 @@ impl Interactive for Table {
         };
         let result = filter_utils::sanitize_interaction_result(cell.on_text_action(action));
         if result.handled {
-            self.apply_filter(self.active_row_id());
+            self.after_cell_edit();
+        }
+        result
+    }
+
+    /// Tab- or comma-separated text becomes new rows unless a cell is
+    /// being edited and the text is a single line.
+    fn on_paste(&mut self, text: &str) -> InteractionResult {
+        if self.filter.is_focused() {
+            return self
+                .filter
+                .handle_paste(text)
+                .refresh_if_changed(|| self.apply_filter(self.active_row_id()));
+        }
+        let multi_line = text.trim_end().contains('\n');
+        if let Some(delimiter) = csv::detect_delimiter(text)
+            && (multi_line || !self.is_body_edit_mode())
+        {
+            self.paste_rows(text, delimiter);
+            return InteractionResult::handled();
+        }
+        if !self.is_body_edit_mode() {
+            return InteractionResult::ignored();
+        }
+        let Some(cell) = self.active_cell_mut() else {
+            return InteractionResult::ignored();
+        };
+        let result = filter_utils::sanitize_interaction_result(cell.on_paste(text));
+        if result.handled {
+            self.after_cell_edit();
         }
         result
     }
@@ -307,7 +360,7 @@ impl Interactive for Table {
         if self.focus != TableFocus::Body {
             return None;
         }
-        let col_widths = self.compute_column_widths(&self.fallback_context());
+        let col_widths = self.column_widths(&self.fallback_context());
         let col_starts = self.body_col_starts(col_widths.as_slice());
         let marker_offset = if !self.show_row_numbers && self.active_col == 0 {
             2
@@ -319,7 +372,11 @@ impl Interactive for Table {
             .copied()
             .unwrap_or_default()
             .saturating_add(marker_offset);
-        let row_offset = self.active_visible_pos().unwrap_or(0) as u16;
+        let (window_start, _) = self.scroll.visible_range(self.visible_rows.len());
+        let row_offset = self
+            .active_visible_pos()
+            .unwrap_or(0)
+            .saturating_sub(window_start) as u16;
         let base_row = self.body_row_start().saturating_add(row_offset);
 
         if !self.is_body_edit_mode() {
@@ -370,6 +427,7 @@ impl Interactive for Table {
                 self.rows.push(self.build_row(row_id, Some(&other)));
             }
         }
+        self.refresh_column_widths();
 
         self.clamp_focus();
         self.apply_sort_preserving_focus(self.active_row_id());
@@ -386,7 +444,7 @@ impl Interactive for Table {
         self.apply_filter(self.active_row_id());
     }
 
-    fn validate(&self, mode: ValidationMode) -> Result<(), String> {
+    fn validate(&self, mode: ValidationMode) -> Result<(), Message> {
         for (row_idx, row) in self.rows.iter().enumerate() {
             for (col_idx, cell) in row.cells.iter().enumerate() {
                 if let Err(error) = cell.validate(mode) {
@@ -395,10 +453,55 @@ impl Interactive for Table {
                         .get(col_idx)
                         .map(|col| col.header.as_str())
                         .unwrap_or("column");
-                    return Err(format!("row {}, {}: {}", row_idx + 1, header, error));
+                    return Err(error.located(format!("row {}, {}", row_idx + 1, header)));
                 }
             }
         }
         Ok(())
     }
+
+    fn validation_issues(&self, mode: ValidationMode) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+        for (row_idx, row) in self.rows.iter().enumerate() {
+            for (col_idx, cell) in row.cells.iter().enumerate() {
+                if let Err(error) = cell.validate(mode) {
+                    let header = self
+                        .columns
+                        .get(col_idx)
+                        .map(|col| col.header.as_str())
+                        .unwrap_or("column");
+                    issues.push(
+                        ValidationIssue::new(error)
+                            .at(format!("row {}, {}", row_idx + 1, header), cell.id()),
+                    );
+                }
+            }
+        }
+        issues
+    }
+
+    /// Targets are cell ids. A filter hiding the row is cleared.
+    fn reveal(&mut self, target: &str) -> bool {
+        let Some((row_idx, col_idx)) = self.rows.iter().enumerate().find_map(|(row_idx, row)| {
+            row.cells
+                .iter()
+                .position(|cell| cell.id() == target)
+                .map(|col_idx| (row_idx, col_idx))
+        }) else {
+            return false;
+        };
+        if !self.visible_rows.contains(&row_idx) {
+            self.filter.clear();
+        }
+        self.focus = TableFocus::Body;
+        self.active_row = row_idx;
+        self.active_col = col_idx;
+        self.apply_filter(self.active_row_id());
+        self.set_body_mode(TableBodyMode::Edit);
+        true
+    }
 }
+
+#[cfg(test)]
+#[path = "../tests/table.rs"]
+mod tests;
@@ -8,18 +8,21 @@ use crate::core::value::Value;
 use crate::terminal::{CursorPos, KeyCode, KeyEvent, TerminalSize};
 use crate::ui::layout::Layout;
 use crate::ui::span::{Span, SpanLine};
-use crate::ui::style::{Color, Style};
+use crate::ui::style::{Color, Style, StyleRole};
 use crate::widgets::base::WidgetBase;
 use crate::widgets::node::LeafComponent;
 use crate::widgets::shared::filter as filter_utils;
 use crate::widgets::shared::list_policy;
+use crate::widgets::shared::scroll::ScrollState;
 use crate::widgets::shared::validation::decorate_component_validation;
 use crate::widgets::shared::value_seed::{normalize_ascii_key, seed_value_from_record};
 use crate::widgets::traits::{
     CompletionState, DrawOutput, Drawable, FocusMode, HintContext, HintGroup, HintItem,
-    InteractionResult, Interactive, InteractiveNode, RenderContext, TextAction, ValidationMode,
+    InteractionResult, Interactive, InteractiveNode, RenderContext, TextAction, ValidationIssue,
+    ValidationMode,
 };
 
+mod csv;
 mod interaction;
 mod render;
 
@@ -75,7 +78,13 @@ pub struct Table {
     filter: filter_utils::ListFilter,
     visible_rows: Vec<usize>,
     sort: Option<(usize, SortDirection)>,
+    /// Column the filter looks at; `None` matches across all columns.
+    filter_column: Option<usize>,
     next_row_id: u64,
+    scroll: ScrollState,
+    /// Widest unfocused cell per column over every row; refreshed on edits
+    /// instead of on each frame.
+    column_widths: Vec<usize>,
 }
 
 impl Table {
@@ -99,7 +108,10 @@ impl Table {
             ),
             visible_rows: Vec::new(),
             sort: None,
+            filter_column: None,
             next_row_id: 1,
+            scroll: ScrollState::new(None),
+            column_widths: Vec::new(),
         };
         this.apply_filter(None);
         this
@@ -115,6 +127,14 @@ impl Table {
         self
     }
 
+    /// Shows at most `rows` body rows and scrolls to keep the active one in
+    /// view.
+    pub fn with_max_visible_rows(mut self, rows: usize) -> Self {
+        self.scroll.set_max_visible(rows);
+        self.sync_scroll();
+        self
+    }
+
     pub fn with_initial_rows(mut self, rows: usize) -> Self {
         for _ in 0..rows {
             self.add_row();
@@ -151,6 +171,7 @@ impl Table {
         self.next_row_id = self.next_row_id.saturating_add(1);
         let row = self.build_row(row_id, None);
         self.rows.push(row);
+        self.refresh_column_widths();
         self.apply_sort_preserving_focus(Some(row_id));
 
         self.focus = TableFocus::Body;
@@ -171,6 +192,7 @@ impl Table {
         let row = self.build_row(row_id, None);
         let insert_at = self.active_row.saturating_add(1).min(self.rows.len());
         self.rows.insert(insert_at, row);
+        self.refresh_column_widths();
         self.sort = None;
         self.focus = TableFocus::Body;
         self.active_row = insert_at;
@@ -191,6 +213,7 @@ impl Table {
             None
         };
         self.rows.remove(self.active_row);
+        self.refresh_column_widths();
         self.body_mode = TableBodyMode::Navigate;
         if self.rows.is_empty() {
             self.focus = TableFocus::Header;
@@ -232,6 +255,7 @@ impl Table {
             row.cells
                 .push(make_cell(cell_id, self.columns[col_idx].header.clone()));
         }
+        self.refresh_column_widths();
         self.clamp_focus();
         self.apply_filter(self.active_row_id());
     }
@@ -336,6 +360,33 @@ impl Table {
         self.filter.query()
     }
 
+    fn is_filtered_column(&self, col_idx: usize) -> bool {
+        self.filter_column
+            .is_none_or(|filtered| filtered == col_idx)
+    }
+
+    /// Steps the filter through all columns, then each column in turn.
+    fn cycle_filter_column(&mut self, back: bool) {
+        let len = self.columns.len();
+        self.filter_column = match (self.filter_column, back) {
+            (None, false) => (len > 0).then_some(0),
+            (None, true) => len.checked_sub(1),
+            (Some(col_idx), false) => (col_idx + 1 < len).then_some(col_idx + 1),
+            (Some(col_idx), true) => col_idx.checked_sub(1),
+        };
+        self.apply_filter(self.active_row_id());
+    }
+
+    fn filter_scope_label(&self) -> String {
+        match self
+            .filter_column
+            .and_then(|col_idx| self.columns.get(col_idx))
+        {
+            Some(col) => format!("in {}", col.header),
+            None => "in all columns".to_string(),
+        }
+    }
+
     fn cell_filter_text(&self, row_idx: usize, col_idx: usize) -> String {
         self.rows
             .get(row_idx)
@@ -346,6 +397,11 @@ impl Table {
     }
 
     fn apply_filter(&mut self, preferred_row_id: Option<u64>) {
+        self.filter_rows(preferred_row_id);
+        self.sync_scroll();
+    }
+
+    fn filter_rows(&mut self, preferred_row_id: Option<u64>) {
         let query = self.filter_query();
         let query = query.trim();
 
@@ -355,10 +411,12 @@ impl Table {
         } else {
             self.visible_rows = (0..self.rows.len())
                 .filter(|&row_idx| {
-                    (0..self.columns.len()).any(|col_idx| {
-                        let text = self.cell_filter_text(row_idx, col_idx);
-                        list_policy::text_matches(query, text.as_str())
-                    })
+                    (0..self.columns.len())
+                        .filter(|&col_idx| self.is_filtered_column(col_idx))
+                        .any(|col_idx| {
+                            let text = self.cell_filter_text(row_idx, col_idx);
+                            list_policy::text_matches(query, text.as_str())
+                        })
                 })
                 .collect();
         }
@@ -412,9 +470,31 @@ impl Table {
             return false;
         }
         self.active_row = next_row;
+        self.sync_scroll();
         true
     }
 
+    /// Keeps the scroll window inside the filtered rows and over the active
+    /// one.
+    fn sync_scroll(&mut self) {
+        let total = self.visible_rows.len();
+        let Some(max) = self.scroll.max_visible else {
+            return;
+        };
+        self.scroll.offset = self.scroll.offset.min(total.saturating_sub(max));
+        if self.focus == TableFocus::Body
+            && let Some(pos) = self.active_visible_pos()
+        {
+            self.scroll.ensure_visible(pos, total);
+        }
+    }
+
+    /// Filtered rows inside the scroll window, in display order.
+    fn window_rows(&self) -> &[usize] {
+        let (start, end) = self.scroll.visible_range(self.visible_rows.len());
+        &self.visible_rows[start..end]
+    }
+
     fn active_cell(&self) -> Option<&dyn InteractiveNode> {
         let row = self.rows.get(self.active_row)?;
         let cell = row.cells.get(self.active_col)?;
@@ -1,4 +1,6 @@
 use super::*;
+use crate::ui::fill::{SELECTION_BACKGROUND, selection_bar};
+use crate::ui::span::highlight_span_line;
 use crate::widgets::shared::render_ctx::child_context_for;
 
 impl Table {
@@ -18,7 +20,7 @@ impl Table {
         let active = self.focus == TableFocus::Body && self.active_row == row_idx;
         let marker = if active { '❯' } else { ' ' };
         let marker_style = if active {
-            Style::new().color(Color::Yellow).bold()
+            StyleRole::Cursor.style().bold()
         } else {
             Style::default()
         };
@@ -33,11 +35,15 @@ impl Table {
         ]
     }
 
+    fn is_selected_row(&self, row_idx: usize, focused: bool) -> bool {
+        focused && self.focus == TableFocus::Body && self.active_row == row_idx
+    }
+
     fn row_marker_prefix(&self, row_idx: usize) -> SpanLine {
         let active = self.focus == TableFocus::Body && self.active_row == row_idx;
         let marker = if active { '❯' } else { ' ' };
         let marker_style = if active {
-            Style::new().color(Color::Yellow).bold()
+            StyleRole::Cursor.style().bold()
         } else {
             Style::default()
         };
@@ -63,6 +69,20 @@ impl Table {
         )
     }
 
+    fn filter_line(&self, ctx: &RenderContext, focused: bool) -> SpanLine {
+        let mut line = self.filter.draw_line_with(ctx, focused, |ctx, focused_id| {
+            self.child_context(ctx, focused_id)
+        });
+        line.push(
+            Span::styled(
+                format!("  {}", self.filter_scope_label()),
+                StyleRole::Hint.style(),
+            )
+            .no_wrap(),
+        );
+        line
+    }
+
     fn child_context(&self, ctx: &RenderContext, focused_cell_id: Option<String>) -> RenderContext {
         child_context_for(self.base.id(), ctx, focused_cell_id)
     }
@@ -107,7 +127,7 @@ impl Table {
 
         let query = self.filter_query();
         let query = query.trim();
-        if !query.is_empty() {
+        if !query.is_empty() && self.is_filtered_column(col_idx) {
             let text = span_line_text(line.as_slice());
             let ranges = list_policy::text_match_ranges(query, text.as_str());
             highlight_span_line(
@@ -125,24 +145,45 @@ impl Table {
         line
     }
 
-    pub(super) fn compute_column_widths(&self, ctx: &RenderContext) -> Vec<usize> {
-        self.columns
-            .iter()
-            .enumerate()
-            .map(|(col_idx, col)| {
-                let mut width = col
-                    .min_width
-                    .max(UnicodeWidthStr::width(self.header_text(col_idx).as_str()));
-                for row_idx in self.visible_rows.iter().copied() {
-                    let focused = self.is_body_edit_mode()
-                        && self.active_row == row_idx
-                        && self.active_col == col_idx;
-                    let line = self.render_cell_line(row_idx, col_idx, ctx, focused);
-                    width = width.max(Layout::line_width(line.as_slice()));
-                }
-                width
+    pub(super) fn refresh_column_widths(&mut self) {
+        self.column_widths = (0..self.columns.len())
+            .map(|col_idx| self.measure_column(col_idx))
+            .collect();
+    }
+
+    pub(super) fn refresh_column_width(&mut self, col_idx: usize) {
+        let width = self.measure_column(col_idx);
+        if let Some(slot) = self.column_widths.get_mut(col_idx) {
+            *slot = width;
+        }
+    }
+
+    fn measure_column(&self, col_idx: usize) -> usize {
+        let ctx = self.fallback_context();
+        (0..self.rows.len())
+            .map(|row_idx| {
+                let line = self.render_cell_line(row_idx, col_idx, &ctx, false);
+                Layout::line_width(line.as_slice())
             })
-            .collect()
+            .fold(
+                self.columns[col_idx]
+                    .min_width
+                    .max(UnicodeWidthStr::width(self.header_text(col_idx).as_str())),
+                usize::max,
+            )
+    }
+
+    /// Cached widths, widened for the cell being edited, which may draw
+    /// wider while focused.
+    pub(super) fn column_widths(&self, ctx: &RenderContext) -> Vec<usize> {
+        let mut widths = self.column_widths.clone();
+        if self.is_body_edit_mode()
+            && let Some(width) = widths.get_mut(self.active_col)
+        {
+            let line = self.render_cell_line(self.active_row, self.active_col, ctx, true);
+            *width = (*width).max(Layout::line_width(line.as_slice()));
+        }
+        widths
     }
 
     fn render_grid(
@@ -153,12 +194,10 @@ impl Table {
     ) -> Vec<SpanLine> {
         let mut lines = Vec::<SpanLine>::new();
         if !self.base.label().is_empty() {
-            lines.push(vec![Span::new(self.base.label()).no_wrap()]);
+            lines.push(ctx.label_line(self.base.label(), StyleRole::Label.style()));
         }
         if self.filter.is_visible() {
-            lines.push(self.filter.draw_line_with(ctx, focused, |ctx, focused_id| {
-                self.child_context(ctx, focused_id)
-            }));
+            lines.push(self.filter_line(ctx, focused));
         }
 
         let mut widths = Vec::<usize>::new();
@@ -187,7 +226,7 @@ impl Table {
         lines.push(grid_row(header_cells, widths.as_slice()));
         lines.push(grid_border_line('├', '┼', '┤', widths.as_slice()));
 
-        for row_idx in self.visible_rows.iter().copied() {
+        for row_idx in self.window_rows().iter().copied() {
             let mut row_cells = Vec::<SpanLine>::with_capacity(widths.len());
             if self.show_row_numbers {
                 row_cells.push(self.row_index_line(row_idx));
@@ -198,13 +237,22 @@ impl Table {
                     && self.active_col == col_idx;
                 row_cells.push(self.render_cell_line(row_idx, col_idx, ctx, focused));
             }
-            lines.push(grid_row(row_cells, widths.as_slice()));
+            let mut line = grid_row(row_cells, widths.as_slice());
+            if self.is_selected_row(row_idx, focused) {
+                // Inside the outer borders only, so the grid stays closed.
+                let inner = line.len().saturating_sub(1);
+                for span in &mut line[1..inner] {
+                    span.style.background.get_or_insert(SELECTION_BACKGROUND);
+                }
+            }
+            lines.push(line);
         }
         if self.rows.is_empty() {
             lines.push(grid_empty_row(widths.as_slice(), "(empty)"));
         }
 
         lines.push(grid_border_line('└', '┴', '┘', widths.as_slice()));
+        lines.extend(self.scroll_footer());
         lines
     }
 
@@ -216,12 +264,10 @@ impl Table {
     ) -> Vec<SpanLine> {
         let mut lines = Vec::<SpanLine>::new();
         if !self.base.label().is_empty() {
-            lines.push(vec![Span::new(self.base.label()).no_wrap()]);
+            lines.push(ctx.label_line(self.base.label(), StyleRole::Label.style()));
         }
         if self.filter.is_visible() {
-            lines.push(self.filter.draw_line_with(ctx, focused, |ctx, focused_id| {
-                self.child_context(ctx, focused_id)
-            }));
+            lines.push(self.filter_line(ctx, focused));
         }
 
         let mut header_cells = Vec::<SpanLine>::new();
@@ -245,7 +291,7 @@ impl Table {
         }
         lines.push(clean_row(header_cells, clean_widths.as_slice()));
 
-        for row_idx in self.visible_rows.iter().copied() {
+        for row_idx in self.window_rows().iter().copied() {
             let mut row_cells = Vec::<SpanLine>::new();
             if self.show_row_numbers {
                 row_cells.push(self.row_index_line(row_idx));
@@ -256,14 +302,25 @@ impl Table {
                     && self.active_col == col_idx;
                 row_cells.push(self.render_cell_line(row_idx, col_idx, ctx, focused));
             }
-            lines.push(clean_row(row_cells, clean_widths.as_slice()));
+            let mut line = clean_row(row_cells, clean_widths.as_slice());
+            if self.is_selected_row(row_idx, focused) {
+                let width = Layout::line_width(line.as_slice()).min(u16::MAX as usize) as u16;
+                line = selection_bar(line, width);
+            }
+            lines.push(line);
         }
         if self.rows.is_empty() {
             lines.push(clean_empty_row(clean_widths.as_slice(), "(empty)"));
         }
+        lines.extend(self.scroll_footer());
         lines
     }
 
+    fn scroll_footer(&self) -> Option<SpanLine> {
+        let text = self.scroll.footer(self.visible_rows.len())?;
+        Some(vec![Span::styled(text, StyleRole::Hint.style()).no_wrap()])
+    }
+
     pub(super) fn body_row_start(&self) -> u16 {
         let label_rows = if self.base.label().is_empty() { 0 } else { 1 };
         let filter_rows = if self.filter.is_visible() { 1 } else { 0 };
@@ -326,9 +383,13 @@ impl Drawable for Table {
         self.base.id()
     }
 
+    fn label(&self) -> &str {
+        self.base.label()
+    }
+
     fn draw(&self, ctx: &RenderContext) -> DrawOutput {
         let focused = self.base.is_focused(ctx);
-        let col_widths = self.compute_column_widths(ctx);
+        let col_widths = self.column_widths(ctx);
         let mut lines = match self.style {
             TableStyle::Grid => self.render_grid(ctx, col_widths.as_slice(), focused),
             TableStyle::Clean => self.render_clean(ctx, col_widths.as_slice(), focused),
@@ -352,7 +413,11 @@ impl Drawable for Table {
 
         if self.filter.is_focused() {
             hints.push(HintItem::new("Type", "filter rows", HintGroup::Edit).with_priority(10));
-            hints.push(HintItem::new("Esc", "close filter", HintGroup::View).with_priority(11));
+            hints.push(
+                HintItem::new("Tab / Shift+Tab", "filter column", HintGroup::Navigation)
+                    .with_priority(11),
+            );
+            hints.push(HintItem::new("Esc", "close filter", HintGroup::View).with_priority(12));
             return hints;
         }
 
@@ -424,74 +489,8 @@ fn span_line_text(spans: &[Span]) -> String {
     out
 }
 
-fn highlight_span_line(spans: &mut SpanLine, ranges: &[(usize, usize)], highlight: Style) {
-    if ranges.is_empty() {
-        return;
-    }
-
-    let mut sorted = ranges.to_vec();
-    sorted.sort_unstable_by(|left, right| left.0.cmp(&right.0).then(left.1.cmp(&right.1)));
-    let mut merged = Vec::<(usize, usize)>::new();
-    for (start, end) in sorted {
-        if start >= end {
-            continue;
-        }
-        if let Some((_, last_end)) = merged.last_mut()
-            && start <= *last_end
-        {
-            *last_end = (*last_end).max(end);
-            continue;
-        }
-        merged.push((start, end));
-    }
-    if merged.is_empty() {
-        return;
-    }
-
-    let source = spans.clone();
-    let mut out = Vec::<Span>::new();
-    let mut global = 0usize;
-    for span in source {
-        let chars: Vec<char> = span.text.chars().collect();
-        if chars.is_empty() {
-            continue;
-        }
-        let mut idx = 0usize;
-        while idx < chars.len() {
-            let abs = global + idx;
-            let marked = merged
-                .iter()
-                .any(|(start, end)| abs >= *start && abs < *end);
-            let mut end_idx = idx + 1;
-            while end_idx < chars.len() {
-                let abs_next = global + end_idx;
-                let marked_next = merged
-                    .iter()
-                    .any(|(start, end)| abs_next >= *start && abs_next < *end);
-                if marked_next != marked {
-                    break;
-                }
-                end_idx += 1;
-            }
-            let text: String = chars[idx..end_idx].iter().collect();
-            let style = if marked {
-                span.style.merge(highlight)
-            } else {
-                span.style
-            };
-            out.push(Span::styled(text, style).no_wrap());
-            idx = end_idx;
-        }
-        global += chars.len();
-    }
-
-    if !out.is_empty() {
-        *spans = out;
-    }
-}
-
 fn grid_border_line(left: char, middle: char, right: char, widths: &[usize]) -> SpanLine {
-    let border_style = Style::new().color(Color::DarkGrey);
+    let border_style = StyleRole::Border.style();
     let mut line = Vec::<Span>::new();
     line.push(Span::styled(left.to_string(), border_style).no_wrap());
     for (idx, width) in widths.iter().enumerate() {
@@ -505,7 +504,7 @@ fn grid_border_line(left: char, middle: char, right: char, widths: &[usize]) ->
 }
 
 fn grid_row(cells: Vec<SpanLine>, widths: &[usize]) -> SpanLine {
-    let border_style = Style::new().color(Color::DarkGrey);
+    let border_style = StyleRole::Border.style();
     let mut line = Vec::<Span>::new();
     for (idx, width) in widths.iter().enumerate() {
         line.push(Span::styled("│ ", border_style).no_wrap());
@@ -556,8 +555,8 @@ fn centered_label_line(text: &str, width: usize, style: Style) -> SpanLine {
 }
 
 fn grid_empty_row(widths: &[usize], text: &str) -> SpanLine {
-    let border_style = Style::new().color(Color::DarkGrey);
-    let text_style = Style::new().color(Color::DarkGrey);
+    let border_style = StyleRole::Border.style();
+    let text_style = StyleRole::Hint.style();
     let border_width = Layout::line_width(grid_border_line('┌', '┬', '┐', widths).as_slice());
     let inner_width = border_width.saturating_sub(2);
 
@@ -568,7 +567,7 @@ fn grid_empty_row(widths: &[usize], text: &str) -> SpanLine {
 }
 
 fn clean_empty_row(widths: &[usize], text: &str) -> SpanLine {
-    let text_style = Style::new().color(Color::DarkGrey);
+    let text_style = StyleRole::Hint.style();
     let gaps = widths.len().saturating_sub(1).saturating_mul(2);
     let content_width: usize = widths.iter().copied().sum::<usize>().saturating_add(gaps);
     centered_label_line(text, content_width.max(1), text_style)
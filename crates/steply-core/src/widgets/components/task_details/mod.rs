@@ -0,0 +1,402 @@
+use crate::runtime::event::SystemEvent;
+use crate::task::{TaskId, TaskLogBuffer, TaskStatus};
+use crate::terminal::{KeyCode, KeyEvent, PointerEvent};
+use crate::ui::span::Span;
+use crate::ui::style::{Color, Style, StyleRole};
+use crate::widgets::base::WidgetBase;
+use crate::widgets::node::LeafComponent;
+use crate::widgets::static_hints::{TASK_DETAILS_LIST_HINTS, TASK_DETAILS_LOG_HINTS};
+use crate::widgets::traits::{
+    DrawOutput, Drawable, FocusMode, HintContext, HintItem, InteractionResult, Interactive,
+    RenderContext, focused_static_hints,
+};
+use std::collections::VecDeque;
+
+const DEFAULT_VISIBLE_LINES: usize = 15;
+const DEFAULT_HISTORY_LINES: usize = 1000;
+
+struct TaskPane {
+    task_id: TaskId,
+    run_id: u64,
+    lines: VecDeque<String>,
+}
+
+/// Lists the flow's tasks and shows the output of the selected one, keeping
+/// up with new lines until scrolled back.
+pub struct TaskDetailsView {
+    base: WidgetBase,
+    panes: Vec<TaskPane>,
+    selected: usize,
+    showing_log: bool,
+    /// Lines scrolled back from the end; `0` follows new output.
+    scroll_offset: usize,
+    visible_lines: usize,
+    history_lines: usize,
+}
+
+impl TaskDetailsView {
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            base: WidgetBase::new(id, label),
+            panes: Vec::new(),
+            selected: 0,
+            showing_log: false,
+            scroll_offset: 0,
+            visible_lines: DEFAULT_VISIBLE_LINES,
+            history_lines: DEFAULT_HISTORY_LINES,
+        }
+    }
+
+    /// Adds a task to the list, with the output its latest run printed so
+    /// far.
+    pub fn with_task(mut self, task_id: impl Into<TaskId>, log: Option<&TaskLogBuffer>) -> Self {
+        self.panes.push(TaskPane {
+            task_id: task_id.into(),
+            run_id: log.map_or(0, TaskLogBuffer::run_id),
+            lines: log
+                .map(|log| log.lines().map(str::to_string).collect())
+                .unwrap_or_default(),
+        });
+        self
+    }
+
+    pub fn with_visible_lines(mut self, n: usize) -> Self {
+        self.visible_lines = n.max(1);
+        self
+    }
+
+    /// Lines kept per task for scrolling back.
+    pub fn with_history_lines(mut self, n: usize) -> Self {
+        self.history_lines = n.max(1);
+        self
+    }
+
+    pub fn selected_task(&self) -> Option<&TaskId> {
+        self.panes.get(self.selected).map(|pane| &pane.task_id)
+    }
+
+    pub fn showing_log(&self) -> bool {
+        self.showing_log
+    }
+
+    pub fn is_following(&self) -> bool {
+        self.scroll_offset == 0
+    }
+
+    /// Opens the log of `task_id`, following its output.
+    pub fn show_log(&mut self, task_id: &str) -> bool {
+        let Some(index) = self
+            .panes
+            .iter()
+            .position(|pane| pane.task_id.as_str() == task_id)
+        else {
+            return false;
+        };
+        self.selected = index;
+        self.showing_log = true;
+        self.scroll_offset = 0;
+        true
+    }
+
+    /// The lines of the open log that are scrolled into view.
+    pub fn visible_log_lines(&self) -> Vec<&str> {
+        let Some(pane) = self.panes.get(self.selected) else {
+            return Vec::new();
+        };
+        let end = pane.lines.len().saturating_sub(self.scroll_offset);
+        let start = end.saturating_sub(self.visible_lines);
+        pane.lines.range(start..end).map(String::as_str).collect()
+    }
+
+    /// Scrolls towards older lines for negative `delta`. Returns whether the
+    /// view moved.
+    pub fn scroll(&mut self, delta: isize) -> bool {
+        let next = self
+            .scroll_offset
+            .saturating_add_signed(-delta)
+            .min(self.max_scroll_offset());
+        let moved = next != self.scroll_offset;
+        self.scroll_offset = next;
+        moved
+    }
+
+    fn max_scroll_offset(&self) -> usize {
+        self.panes.get(self.selected).map_or(0, |pane| {
+            pane.lines.len().saturating_sub(self.visible_lines)
+        })
+    }
+
+    fn follow(&mut self) -> bool {
+        let moved = self.scroll_offset != 0;
+        self.scroll_offset = 0;
+        moved
+    }
+
+    fn pane_mut(&mut self, task_id: &TaskId) -> (usize, &mut TaskPane) {
+        let index = match self.panes.iter().position(|pane| &pane.task_id == task_id) {
+            Some(index) => index,
+            None => {
+                self.panes.push(TaskPane {
+                    task_id: task_id.clone(),
+                    run_id: 0,
+                    lines: VecDeque::new(),
+                });
+                self.panes.len() - 1
+            }
+        };
+        (index, &mut self.panes[index])
+    }
+
+    fn start_run(&mut self, task_id: &TaskId, run_id: u64) -> bool {
+        let (index, pane) = self.pane_mut(task_id);
+        if run_id < pane.run_id {
+            return false;
+        }
+        pane.run_id = run_id;
+        pane.lines.clear();
+        if index == self.selected {
+            self.scroll_offset = 0;
+        }
+        true
+    }
+
+    fn append_line(&mut self, task_id: &TaskId, run_id: u64, line: &str) -> bool {
+        let history_lines = self.history_lines;
+        let (index, pane) = self.pane_mut(task_id);
+        if run_id < pane.run_id {
+            return false;
+        }
+        if run_id > pane.run_id {
+            pane.run_id = run_id;
+            pane.lines.clear();
+        }
+        pane.lines.push_back(line.to_string());
+        while pane.lines.len() > history_lines {
+            pane.lines.pop_front();
+        }
+        if index != self.selected {
+            // Only the line count in the list changes.
+            return !self.showing_log;
+        }
+        // Keeps a scrolled-back view on the same lines as output arrives.
+        if self.scroll_offset > 0 {
+            self.scroll_offset = (self.scroll_offset + 1).min(self.max_scroll_offset());
+        }
+        true
+    }
+
+    fn status_span(status: Option<TaskStatus>) -> Span {
+        match status {
+            None | Some(TaskStatus::Pending) => {
+                Span::styled("○", StyleRole::Hint.style()).no_wrap()
+            }
+            Some(TaskStatus::Running) => {
+                Span::styled("●", Style::new().color(Color::Cyan).bold()).no_wrap()
+            }
+            Some(TaskStatus::Retrying) => {
+                Span::styled("↻", Style::new().color(Color::Yellow)).no_wrap()
+            }
+            Some(TaskStatus::Done) => {
+                Span::styled("✓", Style::new().color(Color::Green).bold()).no_wrap()
+            }
+            Some(TaskStatus::Failed) => {
+                Span::styled("✗", Style::new().color(Color::Red).bold()).no_wrap()
+            }
+            Some(TaskStatus::Skipped) => Span::styled("↷", StyleRole::Hint.style()).no_wrap(),
+            Some(TaskStatus::Blocked) => {
+                Span::styled("⊘", Style::new().color(Color::Yellow)).no_wrap()
+            }
+        }
+    }
+
+    fn status_label(status: Option<TaskStatus>) -> &'static str {
+        match status {
+            None | Some(TaskStatus::Pending) => "pending",
+            Some(TaskStatus::Running) => "running",
+            Some(TaskStatus::Retrying) => "retrying",
+            Some(TaskStatus::Done) => "done",
+            Some(TaskStatus::Failed) => "failed",
+            Some(TaskStatus::Skipped) => "skipped",
+            Some(TaskStatus::Blocked) => "blocked",
+        }
+    }
+
+    fn draw_list(&self, ctx: &RenderContext, lines: &mut Vec<Vec<Span>>) {
+        let dim = StyleRole::Hint.style();
+        if self.panes.is_empty() {
+            lines.push(vec![Span::styled("No tasks in this flow", dim).no_wrap()]);
+            return;
+        }
+        for (index, pane) in self.panes.iter().enumerate() {
+            let status = ctx
+                .task_graph
+                .get(pane.task_id.as_str())
+                .map(|entry| entry.status);
+            let selected = index == self.selected;
+            let marker = if selected { "❯ " } else { "  " };
+            let name_style = if selected {
+                Style::new().color(Color::Cyan).bold()
+            } else {
+                Style::new().color(Color::White)
+            };
+            let mut line = vec![
+                Span::styled(marker, Style::new().color(Color::Cyan)).no_wrap(),
+                Self::status_span(status),
+                Span::new(" ").no_wrap(),
+                Span::styled(pane.task_id.to_string(), name_style).no_wrap(),
+            ];
+            if !pane.lines.is_empty() {
+                let count = pane.lines.len();
+                let noun = if count == 1 { "line" } else { "lines" };
+                line.push(Span::styled(format!("  {count} {noun}"), dim).no_wrap());
+            }
+            lines.push(line);
+        }
+    }
+
+    fn draw_log(&self, ctx: &RenderContext, lines: &mut Vec<Vec<Span>>) {
+        let Some(pane) = self.panes.get(self.selected) else {
+            return;
+        };
+        let dim = StyleRole::Hint.style();
+        let status = ctx
+            .task_graph
+            .get(pane.task_id.as_str())
+            .map(|entry| entry.status);
+        let follow = if self.is_following() {
+            "following"
+        } else {
+            "paused, F to follow"
+        };
+        lines.push(vec![
+            Self::status_span(status),
+            Span::new(" ").no_wrap(),
+            Span::styled(
+                pane.task_id.to_string(),
+                Style::new().color(Color::White).bold(),
+            )
+            .no_wrap(),
+            Span::styled(format!("  {} · {follow}", Self::status_label(status)), dim).no_wrap(),
+        ]);
+        if pane.lines.is_empty() {
+            lines.push(vec![Span::styled("  No output yet", dim).no_wrap()]);
+            return;
+        }
+        let below = self.scroll_offset.min(pane.lines.len());
+        let above = pane
+            .lines
+            .len()
+            .saturating_sub(below)
+            .saturating_sub(self.visible_lines);
+        if above > 0 {
+            lines.push(vec![
+                Span::styled(format!("  ↑ {above} more"), dim).no_wrap(),
+            ]);
+        }
+        for line in self.visible_log_lines() {
+            lines.push(vec![Span::new(format!("  {line}")).no_wrap()]);
+        }
+        if below > 0 {
+            lines.push(vec![
+                Span::styled(format!("  ↓ {below} more"), dim).no_wrap(),
+            ]);
+        }
+    }
+}
+
+impl Drawable for TaskDetailsView {
+    fn id(&self) -> &str {
+        self.base.id()
+    }
+
+    fn draw(&self, ctx: &RenderContext) -> DrawOutput {
+        let mut lines = Vec::new();
+        if !self.base.label().is_empty() {
+            lines.push(ctx.label_line(self.base.label(), Style::new().color(Color::White).bold()));
+        }
+        if self.showing_log {
+            self.draw_log(ctx, &mut lines);
+        } else {
+            self.draw_list(ctx, &mut lines);
+        }
+        DrawOutput::with_lines(lines)
+    }
+
+    fn hints(&self, ctx: HintContext) -> Vec<HintItem> {
+        if self.showing_log {
+            focused_static_hints(ctx, TASK_DETAILS_LOG_HINTS)
+        } else {
+            focused_static_hints(ctx, TASK_DETAILS_LIST_HINTS)
+        }
+    }
+}
+
+impl Interactive for TaskDetailsView {
+    fn focus_mode(&self) -> FocusMode {
+        FocusMode::Leaf
+    }
+
+    fn on_key(&mut self, key: KeyEvent) -> InteractionResult {
+        if !self.showing_log {
+            return match key.code {
+                KeyCode::Up if self.selected > 0 => {
+                    self.selected -= 1;
+                    InteractionResult::handled()
+                }
+                KeyCode::Down if self.selected + 1 < self.panes.len() => {
+                    self.selected += 1;
+                    InteractionResult::handled()
+                }
+                KeyCode::Up | KeyCode::Down => InteractionResult::handled(),
+                KeyCode::Enter if !self.panes.is_empty() => {
+                    self.showing_log = true;
+                    self.scroll_offset = 0;
+                    InteractionResult::handled()
+                }
+                _ => InteractionResult::ignored(),
+            };
+        }
+        match key.code {
+            KeyCode::Up => InteractionResult::handled_if(self.scroll(-1)),
+            KeyCode::Down => InteractionResult::handled_if(self.scroll(1)),
+            KeyCode::Home => {
+                let oldest = self.max_scroll_offset() as isize;
+                InteractionResult::handled_if(self.scroll(-oldest))
+            }
+            KeyCode::End | KeyCode::Char('f') | KeyCode::Char('F') => {
+                InteractionResult::handled_if(self.follow())
+            }
+            KeyCode::Esc => {
+                self.showing_log = false;
+                self.scroll_offset = 0;
+                InteractionResult::handled()
+            }
+            _ => InteractionResult::ignored(),
+        }
+    }
+
+    fn on_pointer(&mut self, event: PointerEvent) -> InteractionResult {
+        match event.kind.wheel_delta() {
+            Some(delta) if self.showing_log => InteractionResult::handled_if(self.scroll(delta)),
+            _ => InteractionResult::ignored(),
+        }
+    }
+
+    fn on_system_event(&mut self, event: &SystemEvent) -> InteractionResult {
+        match event {
+            SystemEvent::TaskStarted { task_id, run_id } => {
+                InteractionResult::handled_if(self.start_run(task_id, *run_id))
+            }
+            SystemEvent::TaskLogLine {
+                task_id,
+                run_id,
+                line,
+            } => InteractionResult::handled_if(self.append_line(task_id, *run_id, line)),
+            // Status glyphs are read from the task graph when drawn.
+            SystemEvent::TaskCompleted { .. } => InteractionResult::handled(),
+            _ => InteractionResult::ignored(),
+        }
+    }
+}
+
+impl LeafComponent for TaskDetailsView {}
@@ -64,3 +64,204 @@ fn multi_select_preserves_active_directory_query_after_file_toggle() {
     assert_eq!(browser.query_input(), "src/");
     assert_eq!(browser.current_input(), "src/main.rs, src/");
 }
+
+#[test]
+fn browsed_directory_text_uses_forward_slashes() {
+    use super::super::parser::{dir_input_text, parse_input};
+
+    let cwd = PathBuf::from("/work");
+    assert_eq!(dir_input_text(&cwd.join("src"), &cwd), "src/");
+    assert_eq!(dir_input_text(&cwd, &cwd), "");
+    assert_eq!(dir_input_text(&PathBuf::from("/"), &cwd), "/");
+    assert_eq!(dir_input_text(&PathBuf::from("/etc"), &cwd), "/etc/");
+
+    let parsed = parse_input("src\\widgets\\fil", &cwd);
+    assert_eq!(parsed.view_dir, cwd.join("src").join("widgets"));
+    assert_eq!(parsed.query, "fil");
+}
+
+fn ctrl(ch: char) -> KeyEvent {
+    KeyEvent {
+        code: KeyCode::Char(ch),
+        modifiers: KeyModifiers::CONTROL,
+    }
+}
+
+fn multi_browser_with(entries: &[(&str, bool)]) -> FileBrowserComponent {
+    let mut browser = FileBrowserComponent::new("files", "Files")
+        .with_selection_mode(SelectionMode::Multi)
+        .with_browser_mode(BrowserMode::List);
+    browser.overlay_open = true;
+    browser.list_overlay_items = entries
+        .iter()
+        .map(|(path, is_dir)| ActiveOverlayItem::Entry {
+            path: PathBuf::from(path),
+            is_dir: *is_dir,
+        })
+        .collect();
+    browser
+}
+
+#[test]
+fn ctrl_a_marks_every_shown_file_then_unmarks_them() {
+    let mut browser = multi_browser_with(&[("src", true), ("a.rs", false), ("b.rs", false)]);
+    browser.selected_paths = vec![PathBuf::from("b.rs")];
+
+    browser.handle_browser_key(ctrl('a'));
+    assert_eq!(
+        browser.selected_paths,
+        vec![PathBuf::from("b.rs"), PathBuf::from("a.rs")]
+    );
+    assert_eq!(
+        browser.value(),
+        Some(Value::List(vec![
+            Value::Text("b.rs".into()),
+            Value::Text("a.rs".into()),
+        ]))
+    );
+
+    browser.handle_browser_key(ctrl('a'));
+    assert!(browser.selected_paths.is_empty());
+}
+
+#[test]
+fn ctrl_x_clears_every_mark() {
+    let mut browser = multi_browser_with(&[("a.rs", false)]);
+    browser.selected_paths = vec![PathBuf::from("a.rs"), PathBuf::from("elsewhere/c.rs")];
+
+    browser.handle_browser_key(ctrl('x'));
+
+    assert!(browser.selected_paths.is_empty());
+    assert_eq!(browser.value(), Some(Value::List(Vec::new())));
+}
+
+#[test]
+fn marked_files_show_a_filled_checkbox() {
+    let mut browser = multi_browser_with(&[("a.rs", false), ("b.rs", false)]);
+    browser.list.set_options(vec![
+        crate::widgets::components::select_list::SelectItem::plain("a.rs"),
+        crate::widgets::components::select_list::SelectItem::plain("b.rs"),
+    ]);
+    browser.sync_list_markers();
+    browser.handle_browser_key(space_key());
+
+    let ctx = RenderContext::empty(crate::terminal::TerminalSize {
+        width: 40,
+        height: 20,
+    })
+    .with_focus(Some("files".to_string()));
+    let lines = browser
+        .draw(&ctx)
+        .lines
+        .iter()
+        .map(|line| {
+            line.iter()
+                .map(|span| span.text.as_str())
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>();
+
+    assert!(lines.iter().any(|line| line.contains("■ a.rs")));
+    assert!(lines.iter().any(|line| line.contains("□ b.rs")));
+    assert!(lines.iter().any(|line| line.contains("1 selected")));
+}
+
+fn key(code: KeyCode) -> KeyEvent {
+    KeyEvent {
+        code,
+        modifiers: KeyModifiers::NONE,
+    }
+}
+
+fn type_text(browser: &mut FileBrowserComponent, text: &str) {
+    for ch in text.chars() {
+        browser.handle_browser_key(key(KeyCode::Char(ch)));
+    }
+}
+
+/// Browser with file operations over a fresh directory holding `files`.
+fn file_ops_browser(name: &str, files: &[&str]) -> (FileBrowserComponent, PathBuf) {
+    let dir = std::env::temp_dir().join(format!("steply-file-ops-{name}-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).expect("create temp dir");
+    for file in files {
+        std::fs::write(dir.join(file), "").expect("write temp file");
+    }
+    let mut browser = FileBrowserComponent::new("files", "Files")
+        .with_cwd(dir.clone())
+        .with_file_ops(true);
+    browser.overlay_open = true;
+    browser.list_overlay_items = files
+        .iter()
+        .map(|file| ActiveOverlayItem::Entry {
+            path: dir.join(file),
+            is_dir: false,
+        })
+        .collect();
+    (browser, dir)
+}
+
+#[test]
+fn ctrl_n_creates_a_folder_in_the_browsed_directory() {
+    let (mut browser, dir) = file_ops_browser("mkdir", &[]);
+
+    browser.handle_browser_key(ctrl('n'));
+    type_text(&mut browser, "assets");
+    browser.handle_browser_key(key(KeyCode::Enter));
+
+    assert!(dir.join("assets").is_dir());
+    assert!(!browser.file_op_active());
+
+    browser.handle_browser_key(ctrl('n'));
+    type_text(&mut browser, "assets");
+    browser.handle_browser_key(key(KeyCode::Enter));
+    assert!(browser.file_op_active());
+    assert_eq!(
+        browser.file_op_error.as_deref(),
+        Some("assets already exists")
+    );
+
+    let _ = std::fs::remove_dir_all(dir);
+}
+
+#[test]
+fn ctrl_r_renames_the_active_entry_and_its_mark() {
+    let (browser, dir) = file_ops_browser("rename", &["old.txt"]);
+    let mut browser = browser.with_selection_mode(SelectionMode::Multi);
+    browser.selected_paths = vec![dir.join("old.txt")];
+
+    browser.handle_browser_key(ctrl('r'));
+    for _ in 0.."old.txt".len() {
+        browser.handle_browser_key(key(KeyCode::Backspace));
+    }
+    type_text(&mut browser, "new.txt");
+    browser.handle_browser_key(key(KeyCode::Enter));
+
+    assert!(!dir.join("old.txt").exists());
+    assert!(dir.join("new.txt").is_file());
+    assert_eq!(browser.selected_paths, vec![dir.join("new.txt")]);
+
+    let _ = std::fs::remove_dir_all(dir);
+}
+
+#[test]
+fn ctrl_d_deletes_marked_files_only_after_confirmation() {
+    let (browser, dir) = file_ops_browser("delete", &["a.txt", "b.txt", "c.txt"]);
+    let mut browser = browser.with_selection_mode(SelectionMode::Multi);
+    browser.selected_paths = vec![dir.join("a.txt"), dir.join("b.txt")];
+
+    browser.handle_browser_key(ctrl('d'));
+    browser.handle_browser_key(key(KeyCode::Enter));
+    assert!(dir.join("a.txt").exists());
+    assert_eq!(browser.selected_paths.len(), 2);
+
+    browser.handle_browser_key(ctrl('d'));
+    browser.handle_browser_key(key(KeyCode::Right));
+    browser.handle_browser_key(key(KeyCode::Enter));
+    assert!(!dir.join("a.txt").exists());
+    assert!(!dir.join("b.txt").exists());
+    assert!(dir.join("c.txt").exists());
+    assert!(browser.selected_paths.is_empty());
+
+    let _ = std::fs::remove_dir_all(dir);
+}
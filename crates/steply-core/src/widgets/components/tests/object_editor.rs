@@ -0,0 +1,216 @@
+use super::{ObjectEditor, ObjectSchema, SchemaType};
+use crate::core::value::Value;
+use crate::core::value_path::ValueTarget;
+use crate::runtime::event::WidgetAction;
+use crate::terminal::{KeyCode, KeyEvent, KeyModifiers, TerminalSize};
+use crate::widgets::traits::{
+    Drawable, InteractionResult, Interactive, RenderContext, ValidationMode,
+};
+
+fn json(text: &str) -> Value {
+    Value::from_json(text).expect("valid json")
+}
+
+fn editor() -> ObjectEditor {
+    ObjectEditor::new("payload", "Payload")
+        .with_value(json(r#"{"db": {"port": 5432}, "name": "x"}"#))
+}
+
+fn press(editor: &mut ObjectEditor, code: KeyCode) -> InteractionResult {
+    editor.on_key(KeyEvent {
+        code,
+        modifiers: KeyModifiers::NONE,
+    })
+}
+
+fn copied(result: InteractionResult) -> Option<String> {
+    result.actions.into_iter().find_map(|action| match action {
+        WidgetAction::CopyToClipboard { text } => Some(text),
+        _ => None,
+    })
+}
+
+fn drawn(editor: &ObjectEditor) -> String {
+    let ctx = RenderContext::empty(TerminalSize {
+        width: 60,
+        height: 20,
+    })
+    .with_focus(Some("payload".to_string()));
+    editor
+        .draw(&ctx)
+        .lines
+        .iter()
+        .flat_map(|line| line.iter().map(|span| span.text.as_str()))
+        .collect()
+}
+
+#[test]
+fn y_copies_the_row_and_shift_y_the_whole_value() {
+    let mut editor = editor();
+
+    let row = copied(press(&mut editor, KeyCode::Char('y'))).expect("row copied");
+    assert_eq!(json(row.as_str()), json(r#"{"port": 5432}"#));
+
+    let whole = copied(editor.on_key(KeyEvent {
+        code: KeyCode::Char('Y'),
+        modifiers: KeyModifiers::SHIFT,
+    }))
+    .expect("value copied");
+    assert_eq!(json(whole.as_str()), editor.value().expect("value"));
+}
+
+#[test]
+fn a_yank_target_receives_the_json_instead_of_the_clipboard() {
+    let target = ValueTarget::parse_selector("export.json").expect("selector");
+    let mut editor = editor().with_yank_target(target.clone());
+
+    let result = press(&mut editor, KeyCode::Char('y'));
+
+    let Some(WidgetAction::ValueChanged { change, .. }) = result.actions.first() else {
+        panic!("expected a value change");
+    };
+    assert_eq!(change.target, target);
+    assert!(matches!(&change.value, Value::Text(text) if text.contains("5432")));
+}
+
+#[test]
+fn pasted_json_replaces_the_row_and_bad_json_keeps_the_buffer_open() {
+    let mut editor = editor();
+
+    press(&mut editor, KeyCode::Char('p'));
+    editor.on_paste("{\"port\": ");
+    press(&mut editor, KeyCode::Enter);
+    assert!(drawn(&editor).contains('✗'));
+
+    editor.on_paste("6543, \"tls\": true}");
+    press(&mut editor, KeyCode::Enter);
+
+    assert!(!drawn(&editor).contains("JSON for"));
+    assert_eq!(
+        editor.value(),
+        Some(json(r#"{"db": {"port": 6543, "tls": true}, "name": "x"}"#))
+    );
+}
+
+#[test]
+fn shift_p_replaces_everything_and_esc_discards_the_buffer() {
+    let mut editor = editor();
+
+    editor.on_key(KeyEvent {
+        code: KeyCode::Char('P'),
+        modifiers: KeyModifiers::SHIFT,
+    });
+    editor.on_paste("[1, 2]");
+    press(&mut editor, KeyCode::Esc);
+    assert_eq!(
+        editor.value(),
+        Some(json(r#"{"db": {"port": 5432}, "name": "x"}"#))
+    );
+
+    editor.on_key(KeyEvent {
+        code: KeyCode::Char('P'),
+        modifiers: KeyModifiers::SHIFT,
+    });
+    editor.on_paste("[1, 2]");
+    press(&mut editor, KeyCode::Enter);
+    assert_eq!(editor.value(), Some(json("[1, 2]")));
+}
+
+#[test]
+fn read_only_editors_copy_but_do_not_paste() {
+    let mut editor = editor().with_read_only();
+
+    assert!(copied(press(&mut editor, KeyCode::Char('y'))).is_some());
+    assert!(!press(&mut editor, KeyCode::Char('p')).handled);
+}
+
+fn schema() -> ObjectSchema {
+    ObjectSchema::of_type(SchemaType::Object)
+        .with_required_field(
+            "db",
+            ObjectSchema::of_type(SchemaType::Object)
+                .with_required_field("port", ObjectSchema::of_type(SchemaType::Number))
+                .with_field("host", ObjectSchema::of_type(SchemaType::Text))
+                .closed(),
+        )
+        .with_field("name", ObjectSchema::of_type(SchemaType::Text))
+        .with_required_field(
+            "env",
+            ObjectSchema::of_type(SchemaType::Text)
+                .with_choices(vec![Value::Text("dev".into()), Value::Text("prod".into())]),
+        )
+}
+
+fn type_text(editor: &mut ObjectEditor, text: &str) {
+    for ch in text.chars() {
+        press(editor, KeyCode::Char(ch));
+    }
+}
+
+#[test]
+fn violations_block_submit_and_render_in_red() {
+    let editor = editor().with_schema(schema());
+
+    let issues = editor.validation_issues(ValidationMode::Submit);
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].message.to_string(), "missing required key `env`");
+    assert_eq!(issues[0].target.as_deref(), Some(""));
+    assert!(editor.validate(ValidationMode::Submit).is_err());
+    assert!(editor.validate(ValidationMode::Live).is_ok());
+    assert!(drawn(&editor).contains("✗ missing required key `env`"));
+}
+
+#[test]
+fn a_value_edit_the_schema_refuses_stays_open() {
+    let mut editor = editor().with_schema(schema());
+    assert!(editor.reveal("db.port"));
+
+    press(&mut editor, KeyCode::Char('e'));
+    for _ in 0..4 {
+        press(&mut editor, KeyCode::Backspace);
+    }
+    type_text(&mut editor, "abc");
+    press(&mut editor, KeyCode::Enter);
+
+    assert!(drawn(&editor).contains("expected number, found text"));
+    assert_eq!(
+        editor.value(),
+        Some(json(r#"{"db": {"port": "abc"}, "name": "x"}"#))
+    );
+    press(&mut editor, KeyCode::Esc);
+    assert_eq!(
+        editor.value(),
+        Some(json(r#"{"db": {"port": 5432}, "name": "x"}"#))
+    );
+}
+
+#[test]
+fn inserts_suggest_missing_keys_and_refuse_undeclared_ones() {
+    let mut editor = editor().with_schema(schema());
+
+    press(&mut editor, KeyCode::Char('i'));
+    let candidates = editor
+        .completion()
+        .map(|completion| completion.candidates.to_vec());
+    assert_eq!(candidates, Some(vec!["env".to_string()]));
+    // `env` is prefilled with a type it allows.
+    press(&mut editor, KeyCode::Enter);
+    type_text(&mut editor, "staging");
+    press(&mut editor, KeyCode::Enter);
+    assert!(drawn(&editor).contains("must be one of dev, prod"));
+    for _ in 0.."staging".len() {
+        press(&mut editor, KeyCode::Backspace);
+    }
+    type_text(&mut editor, "dev");
+    press(&mut editor, KeyCode::Enter);
+    assert!(editor.validate(ValidationMode::Submit).is_ok());
+
+    assert!(editor.reveal("db.port"));
+    press(&mut editor, KeyCode::Char('i'));
+    for _ in 0.."host".len() {
+        press(&mut editor, KeyCode::Backspace);
+    }
+    type_text(&mut editor, "user");
+    press(&mut editor, KeyCode::Enter);
+    assert!(drawn(&editor).contains("`user` is not allowed here"));
+}
@@ -0,0 +1,189 @@
+use std::time::Instant;
+
+use super::*;
+use crate::terminal::{KeyModifiers, TerminalSize};
+
+const FRUITS: [&str; 4] = ["apple", "apricot", "banana", "cherry"];
+
+fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+    KeyEvent { code, modifiers }
+}
+
+fn fruit_list() -> SelectList {
+    SelectList::new("fruit", "", Vec::new())
+        .with_mode(SelectMode::Multi)
+        .with_async_source(|query| {
+            if query == "boom" {
+                return Err("backend down".to_string());
+            }
+            Ok(FRUITS
+                .iter()
+                .filter(|fruit| fruit.starts_with(query))
+                .map(|fruit| SelectItem::plain(*fruit))
+                .collect())
+        })
+}
+
+/// Ticks until the loader has answered and no reload is pending.
+fn settle(list: &mut SelectList) {
+    let deadline = Instant::now() + std::time::Duration::from_secs(5);
+    while list.wants_tick() {
+        assert!(Instant::now() < deadline, "loader did not settle");
+        let _ = list.on_tick();
+        std::thread::sleep(std::time::Duration::from_millis(5));
+    }
+}
+
+fn option_texts(list: &SelectList) -> Vec<String> {
+    list.options
+        .iter()
+        .filter_map(|option| option.value.to_text_scalar())
+        .collect()
+}
+
+fn type_filter(list: &mut SelectList, text: &str) {
+    if !list.filter.is_visible() {
+        let _ = list.on_key(key(KeyCode::Char('f'), KeyModifiers::CONTROL));
+    }
+    for ch in text.chars() {
+        let _ = list.on_key(key(KeyCode::Char(ch), KeyModifiers::NONE));
+    }
+}
+
+#[test]
+fn async_source_loads_options_for_filter_query() {
+    let mut list = fruit_list();
+    assert!(list.source_status_line().is_some());
+    settle(&mut list);
+    assert_eq!(option_texts(&list), FRUITS);
+    assert!(list.source_status_line().is_none());
+
+    type_filter(&mut list, "ap");
+    assert!(list.wants_tick());
+    settle(&mut list);
+    assert_eq!(option_texts(&list), ["apple", "apricot"]);
+}
+
+#[test]
+fn async_source_keeps_selection_and_reports_errors() {
+    let mut list = fruit_list();
+    list.set_value(Value::List(vec![Value::Text("cherry".to_string())]));
+    settle(&mut list);
+
+    type_filter(&mut list, "b");
+    settle(&mut list);
+    assert_eq!(option_texts(&list), ["cherry", "banana"]);
+    assert_eq!(
+        list.value(),
+        Some(Value::List(vec![Value::Text("cherry".to_string())]))
+    );
+
+    let _ = list.on_key(key(KeyCode::Backspace, KeyModifiers::NONE));
+    type_filter(&mut list, "boom");
+    settle(&mut list);
+    let status = list.source_status_line().expect("error line");
+    assert!(status[0].text.contains("backend down"));
+}
+
+fn grouped_list() -> SelectList {
+    SelectList::new(
+        "fruit",
+        "",
+        vec![
+            SelectItem::group("Pome"),
+            SelectItem::plain("apple"),
+            SelectItem::plain("pear"),
+            SelectItem::group("Stone"),
+            SelectItem::plain("apricot"),
+            SelectItem::plain("cherry"),
+        ],
+    )
+    .with_mode(SelectMode::Multi)
+}
+
+fn active_text(list: &SelectList) -> Option<String> {
+    list.options
+        .get(list.active_index())
+        .and_then(|option| option.value.to_text_scalar())
+}
+
+#[test]
+fn group_headers_are_skipped_by_navigation_and_selection() {
+    let mut list = grouped_list();
+    assert_eq!(active_text(&list).as_deref(), Some("apple"));
+
+    let _ = list.on_key(key(KeyCode::Down, KeyModifiers::NONE));
+    let _ = list.on_key(key(KeyCode::Down, KeyModifiers::NONE));
+    assert_eq!(active_text(&list).as_deref(), Some("apricot"));
+    let _ = list.on_key(key(KeyCode::Up, KeyModifiers::NONE));
+    assert_eq!(active_text(&list).as_deref(), Some("pear"));
+
+    let _ = list.on_key(key(KeyCode::Char(' '), KeyModifiers::NONE));
+    assert_eq!(
+        list.value(),
+        Some(Value::List(vec![Value::Text("pear".to_string())]))
+    );
+    let header_click = PointerEvent {
+        kind: PointerKind::Down(PointerButton::Left),
+        row: 3,
+        col: 0,
+        modifiers: KeyModifiers::NONE,
+        semantic: PointerSemantic::None,
+    };
+    assert!(!list.on_pointer(header_click).handled);
+}
+
+#[test]
+fn filter_keeps_matches_under_their_group_header() {
+    let mut list = grouped_list();
+    type_filter(&mut list, "ap");
+
+    let rows = list
+        .options
+        .iter()
+        .map(|option| match &option.view {
+            SelectItemView::Group { title } => format!("# {title}"),
+            _ => option.value.to_text_scalar().unwrap_or_default(),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(rows, ["# Pome", "apple", "# Stone", "apricot"]);
+    assert_eq!(list.visible_to_source, [0, 1, 3, 4]);
+}
+
+#[test]
+fn bulk_selection_covers_visible_options_and_cap_fails_validation() {
+    let mut list = grouped_list().with_max_selected(3);
+    type_filter(&mut list, "ap");
+    let _ = list.on_key(key(KeyCode::Char('a'), KeyModifiers::CONTROL));
+    assert_eq!(list.selected_indices(), [1, 4]);
+
+    let _ = list.on_key(key(KeyCode::Char('f'), KeyModifiers::CONTROL));
+    let _ = list.on_key(key(KeyCode::Char('i'), KeyModifiers::CONTROL));
+    assert_eq!(list.selected_indices(), [2, 5]);
+    assert!(list.validate(ValidationMode::Submit).is_ok());
+
+    let _ = list.on_key(key(KeyCode::Char('a'), KeyModifiers::CONTROL));
+    assert_eq!(list.selected_indices(), [1, 2, 4, 5]);
+    assert_eq!(
+        list.validate(ValidationMode::Submit),
+        Err("select at most 3 (selected 4)".into())
+    );
+    let _ = list.on_key(key(KeyCode::Char('a'), KeyModifiers::CONTROL));
+    assert!(list.selected_indices().is_empty());
+}
+
+#[test]
+fn label_is_translated_and_marked_up_when_drawn() {
+    let list = SelectList::new("fruit", "Fruit", vec![SelectItem::plain("apple")]);
+    let mut ctx = RenderContext::empty(TerminalSize {
+        width: 40,
+        height: 10,
+    });
+    ctx.messages =
+        Arc::new(crate::core::i18n::MessageCatalog::new("de").with_message("Fruit", "**Obst**"));
+
+    let label = &list.draw(&ctx).lines[0];
+    assert_eq!(label.len(), 1);
+    assert_eq!(label[0].text, "Obst");
+    assert!(label[0].style.bold);
+}
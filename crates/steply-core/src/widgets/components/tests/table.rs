@@ -0,0 +1,142 @@
+use super::*;
+use crate::terminal::KeyModifiers;
+use crate::widgets::inputs::text::TextInput;
+
+fn key(code: KeyCode) -> KeyEvent {
+    KeyEvent {
+        code,
+        modifiers: KeyModifiers::NONE,
+    }
+}
+
+fn hosts(rows: usize) -> Table {
+    let mut table = Table::new("hosts", "")
+        .with_style(TableStyle::Clean)
+        .with_row_numbers(false)
+        .column("Host", TextInput::new);
+    table.set_value(Value::List(
+        (1..=rows)
+            .map(|n| Value::Text(format!("host-{n}")))
+            .collect(),
+    ));
+    table
+}
+
+fn rendered(table: &Table) -> Vec<String> {
+    let ctx = RenderContext::empty(TerminalSize {
+        width: 60,
+        height: 20,
+    })
+    .with_focus(Some("hosts".to_string()));
+    table
+        .draw(&ctx)
+        .lines
+        .iter()
+        .map(|line| line.iter().map(|span| span.text.as_str()).collect())
+        .collect()
+}
+
+#[test]
+fn long_tables_render_only_a_scrolled_window() {
+    let mut table = hosts(10).with_max_visible_rows(3);
+    table.on_key(key(KeyCode::Esc));
+
+    let lines = rendered(&table);
+    assert_eq!(lines.len(), 5, "header, three rows and the footer");
+    assert!(lines[1].contains("host-1"));
+    assert!(lines[4].contains("[1-3 of 10] ↓"));
+
+    for _ in 0..4 {
+        table.on_key(key(KeyCode::Down));
+    }
+    let lines = rendered(&table);
+    assert!(lines[1].contains("host-3"));
+    assert!(lines[3].contains("❯ host-5"));
+    assert!(lines[4].contains("[3-5 of 10] ↑↓"));
+}
+
+#[test]
+fn column_widths_follow_cell_edits() {
+    let mut table = hosts(2);
+    assert_eq!(table.column_widths, vec![10]);
+
+    for ch in "-with-a-long-suffix".chars() {
+        table.on_key(key(KeyCode::Char(ch)));
+    }
+    assert_eq!(
+        table.column_widths,
+        vec!["host-1-with-a-long-suffix".len() + 2]
+    );
+}
+
+fn ctrl_f() -> KeyEvent {
+    KeyEvent {
+        code: KeyCode::Char('f'),
+        modifiers: KeyModifiers::CONTROL,
+    }
+}
+
+#[test]
+fn tab_in_the_filter_narrows_matching_to_one_column() {
+    let mut table = Table::new("hosts", "")
+        .column("Host", TextInput::new)
+        .column("Role", TextInput::new);
+    table.set_value(
+        Value::from_json(
+            r#"[{"host":"db-1","role":"web"},{"host":"web-1","role":"db"},{"host":"cache","role":"store"}]"#,
+        )
+        .expect("rows"),
+    );
+
+    table.on_key(ctrl_f());
+    for ch in "web".chars() {
+        table.on_key(key(KeyCode::Char(ch)));
+    }
+    assert_eq!(table.visible_rows, vec![0, 1]);
+    assert!(rendered(&table)[0].contains("in all columns"));
+
+    table.on_key(key(KeyCode::Tab));
+    assert_eq!(table.visible_rows, vec![1]);
+    assert!(rendered(&table)[0].contains("in Host"));
+
+    table.on_key(key(KeyCode::Tab));
+    assert_eq!(table.visible_rows, vec![0]);
+
+    table.on_key(key(KeyCode::Tab));
+    assert_eq!(table.filter_column, None);
+    assert_eq!(table.visible_rows, vec![0, 1]);
+}
+
+#[test]
+fn csv_round_trips_through_header_keys() {
+    let mut table = Table::new("hosts", "")
+        .column("Host", TextInput::new)
+        .column("Port", TextInput::new);
+
+    table
+        .set_value_from_csv("port,HOST,notes\r\n22,\"bastion, eu\",x\n\n8080,\"say \"\"hi\"\"\",\n")
+        .expect("import");
+    assert_eq!(
+        table.value().expect("rows").to_json(),
+        r#"[{"host":"bastion, eu","port":"22"},{"host":"say \"hi\"","port":"8080"}]"#
+    );
+    assert_eq!(
+        table.to_csv(),
+        "Host,Port\n\"bastion, eu\",22\n\"say \"\"hi\"\"\",8080\n"
+    );
+
+    assert!(table.set_value_from_csv("name,role\na,b").is_err());
+}
+
+#[test]
+fn pasting_spreadsheet_rows_appends_them() {
+    let mut table = Table::new("hosts", "")
+        .column("Host", TextInput::new)
+        .column("Port", TextInput::new);
+    table.on_paste("db\t5432\ncache\t6379\n");
+    assert_eq!(
+        table.value().expect("rows").to_json(),
+        r#"[{"host":"db","port":"5432"},{"host":"cache","port":"6379"}]"#
+    );
+    assert!(!table.is_body_edit_mode());
+}
@@ -0,0 +1,61 @@
+use super::{TextAreaComponent, wrap_ranges};
+use crate::core::value::Value;
+use crate::runtime::event::WidgetAction;
+use crate::terminal::{KeyCode, KeyEvent, KeyModifiers, TerminalSize};
+use crate::widgets::traits::{Drawable, Interactive, RenderContext};
+
+fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+    KeyEvent { code, modifiers }
+}
+
+fn rendered(area: &TextAreaComponent, width: u16) -> Vec<String> {
+    let ctx = RenderContext::empty(TerminalSize { width, height: 20 });
+    area.draw(&ctx)
+        .lines
+        .iter()
+        .map(|line| line.iter().map(|span| span.text.as_str()).collect())
+        .collect()
+}
+
+#[test]
+fn wrap_breaks_after_spaces_and_mid_word_when_needed() {
+    assert_eq!(wrap_ranges("alpha beta gamma", 11), [(0, 11), (11, 16)]);
+    assert_eq!(wrap_ranges("abcdefgh", 3), [(0, 3), (3, 6), (6, 8)]);
+    assert_eq!(wrap_ranges("abc", 3), [(0, 3), (3, 3)]);
+    assert_eq!(wrap_ranges("", 3), [(0, 0)]);
+}
+
+#[test]
+fn soft_wrapped_rows_continue_without_a_line_number() {
+    let mut area = TextAreaComponent::new("notes")
+        .with_label("Notes")
+        .with_soft_wrap(true)
+        .with_min_height(1)
+        .with_default(Value::Text("alpha beta gamma\nend".to_string()));
+    area.on_key(key(KeyCode::End, KeyModifiers::NONE));
+
+    assert_eq!(
+        rendered(&area, 16),
+        ["Notes", "│ 1  alpha beta ", "│    gamma", "│ 2  end"]
+    );
+    let cursor = area.cursor_pos_with_width(16).expect("cursor");
+    assert_eq!((cursor.row, cursor.col), (2, 10));
+}
+
+#[test]
+fn enter_inserts_a_line_and_ctrl_enter_submits_when_configured() {
+    let mut area = TextAreaComponent::new("notes")
+        .with_newline_on_enter(true)
+        .with_line_numbers(false);
+    area.on_paste("key: 1");
+    area.on_key(key(KeyCode::Enter, KeyModifiers::NONE));
+    area.on_paste("other: 2");
+
+    assert_eq!(
+        area.value(),
+        Some(Value::Text("key: 1\nother: 2".to_string()))
+    );
+    assert_eq!(rendered(&area, 40)[1], "│ other: 2");
+    let done = area.on_key(key(KeyCode::Enter, KeyModifiers::CONTROL));
+    assert!(matches!(done.actions.as_slice(), [WidgetAction::InputDone]));
+}
@@ -0,0 +1,111 @@
+use super::*;
+use crate::terminal::KeyModifiers;
+
+fn key(code: KeyCode) -> KeyEvent {
+    KeyEvent {
+        code,
+        modifiers: KeyModifiers::NONE,
+    }
+}
+
+fn modules() -> TreeView<String> {
+    let nodes = vec![
+        TreeNode::new("crates".to_string(), 0, true).expanded(),
+        TreeNode::new("core".to_string(), 1, false),
+        TreeNode::new("cli".to_string(), 1, false),
+        TreeNode::new("docs".to_string(), 0, false),
+    ];
+    TreeView::new("modules", "", nodes).with_checkboxes(true)
+}
+
+fn paths(tree: &TreeView<String>) -> Vec<Value> {
+    tree.value()
+        .and_then(|value| value.as_list().map(<[Value]>::to_vec))
+        .unwrap_or_default()
+}
+
+#[test]
+fn checking_a_parent_checks_its_subtree() {
+    let mut tree = modules();
+    assert!(tree.on_key(key(KeyCode::Char(' '))).handled);
+    assert_eq!(
+        paths(&tree),
+        ["crates", "crates/core", "crates/cli"].map(|path| Value::Text(path.to_string()))
+    );
+
+    let _ = tree.on_key(key(KeyCode::Down));
+    let _ = tree.on_key(key(KeyCode::Char(' ')));
+    assert_eq!(tree.check_state(0), checks::CheckState::Partial);
+    assert_eq!(paths(&tree), [Value::Text("crates/cli".to_string())]);
+
+    let _ = tree.on_key(key(KeyCode::Char(' ')));
+    assert_eq!(tree.check_state(0), checks::CheckState::Checked);
+    let _ = tree.on_key(key(KeyCode::Up));
+    let _ = tree.on_key(key(KeyCode::Char(' ')));
+    assert!(paths(&tree).is_empty());
+}
+
+#[test]
+fn set_value_checks_listed_paths_and_children_inherit() {
+    let mut tree = modules();
+    tree.set_value(Value::List(vec![
+        Value::Text("crates/core".to_string()),
+        Value::Text("crates/cli".to_string()),
+    ]));
+    assert_eq!(tree.check_state(0), checks::CheckState::Checked);
+
+    tree.insert_children_after(1, vec![TreeNode::new("src".to_string(), 0, false)]);
+    assert!(tree.nodes()[2].checked);
+    assert_eq!(tree.checked_paths()[2], "crates/core/src");
+}
+
+fn numbered_children(item: &String) -> Vec<TreeNode<String>> {
+    (1..=2)
+        .map(|n| TreeNode::new(format!("{item}.{n}"), 0, true))
+        .collect()
+}
+
+fn labels(tree: &TreeView<String>) -> Vec<&str> {
+    tree.visible()
+        .iter()
+        .map(|idx| tree.nodes()[*idx].item.as_str())
+        .collect()
+}
+
+#[test]
+fn lazy_children_load_on_first_expand() {
+    let mut tree = TreeView::new("lazy", "", vec![TreeNode::new("a".to_string(), 0, true)])
+        .with_lazy_children(numbered_children);
+    assert!(tree.on_key(key(KeyCode::Right)).handled);
+    assert_eq!(labels(&tree), ["a", "a.1", "a.2"]);
+    assert_eq!(tree.nodes()[1].depth, 1);
+
+    let _ = tree.on_key(key(KeyCode::Left));
+    let _ = tree.on_key(key(KeyCode::Right));
+    assert_eq!(tree.nodes().len(), 3);
+}
+
+#[test]
+fn async_children_show_pending_until_they_arrive() {
+    let mut tree = TreeView::new("lazy", "", vec![TreeNode::new("a".to_string(), 0, true)])
+        .with_async_children(numbered_children);
+    let _ = tree.on_key(key(KeyCode::Right));
+    assert_eq!(tree.pending_expand, Some(0));
+    assert!(
+        tree.render_lines(true)[0]
+            .iter()
+            .any(|span| span.text.contains('⟳'))
+    );
+
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(5);
+    while tree.wants_tick() {
+        assert!(
+            std::time::Instant::now() < deadline,
+            "children never arrived"
+        );
+        let _ = tree.on_tick();
+        std::thread::sleep(std::time::Duration::from_millis(5));
+    }
+    assert_eq!(labels(&tree), ["a", "a.1", "a.2"]);
+    assert_eq!(tree.pending_expand, None);
+}
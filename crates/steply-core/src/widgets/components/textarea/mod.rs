@@ -1,7 +1,9 @@
+use crate::core::i18n::Message;
 use crate::core::value::Value;
 use crate::terminal::{CursorPos, KeyCode, KeyEvent, KeyModifiers};
 use crate::ui::span::Span;
-use crate::ui::style::{Color, Style};
+use crate::ui::style::StyleRole;
+use crate::ui::text::{char_display_width, text_display_width};
 use crate::widgets::node::LeafComponent;
 use crate::widgets::shared::scroll::ScrollState;
 use crate::widgets::shared::text_edit;
@@ -10,10 +12,18 @@ use crate::widgets::traits::{
     RenderContext, StoreSyncPolicy, TextAction, TextEditState, ValidationMode,
 };
 use crate::widgets::validators::{Validator, run_validators};
-use unicode_width::UnicodeWidthChar;
+
+/// One screen row of a line: chars `start..end` of `lines[line]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct VisualRow {
+    line: usize,
+    start: usize,
+    end: usize,
+}
 
 pub struct TextAreaComponent {
     id: String,
+    label: String,
 
     lines: Vec<String>,
 
@@ -24,6 +34,13 @@ pub struct TextAreaComponent {
     min_height: usize,
     max_height: usize,
     validators: Vec<Validator>,
+    /// Only moves the cursor and scrolls; the text cannot change.
+    read_only: bool,
+    /// Long lines continue on the next rows instead of running off.
+    soft_wrap: bool,
+    line_numbers: bool,
+    /// Enter starts a new line and Ctrl+Enter submits.
+    newline_on_enter: bool,
 }
 
 impl TextAreaComponent {
@@ -31,6 +48,7 @@ impl TextAreaComponent {
         let max_height = 8;
         Self {
             id: id.into(),
+            label: String::new(),
             lines: vec![String::new()],
             row: 0,
             col: 0,
@@ -38,9 +56,35 @@ impl TextAreaComponent {
             min_height: 3,
             max_height,
             validators: Vec::new(),
+            read_only: false,
+            soft_wrap: false,
+            line_numbers: true,
+            newline_on_enter: false,
         }
     }
 
+    /// Draws `label` on a line above the text.
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    pub fn with_soft_wrap(mut self, soft_wrap: bool) -> Self {
+        self.soft_wrap = soft_wrap;
+        self
+    }
+
+    pub fn with_line_numbers(mut self, line_numbers: bool) -> Self {
+        self.line_numbers = line_numbers;
+        self
+    }
+
+    /// Makes plain Enter start a new line; Ctrl+Enter or Esc submit.
+    pub fn with_newline_on_enter(mut self, newline_on_enter: bool) -> Self {
+        self.newline_on_enter = newline_on_enter;
+        self
+    }
+
     pub fn with_min_height(mut self, n: usize) -> Self {
         self.min_height = n.max(1);
         self
@@ -57,6 +101,11 @@ impl TextAreaComponent {
         self
     }
 
+    pub fn with_read_only(mut self) -> Self {
+        self.read_only = true;
+        self
+    }
+
     pub fn with_validator(mut self, v: Validator) -> Self {
         self.validators.push(v);
         self
@@ -67,13 +116,74 @@ impl TextAreaComponent {
     }
 
     fn gutter_width(&self) -> usize {
-        1 + 1 + self.num_width() + 2
+        if self.line_numbers {
+            1 + 1 + self.num_width() + 2
+        } else {
+            2
+        }
     }
 
     fn visible_height(&self) -> usize {
         self.lines.len().clamp(self.min_height, self.max_height)
     }
 
+    fn header_rows(&self) -> usize {
+        usize::from(!self.label.is_empty())
+    }
+
+    /// Rows for every line, wrapped to `width` columns when soft wrap is
+    /// on and a width is known.
+    fn visual_rows(&self, width: Option<usize>) -> Vec<VisualRow> {
+        let width = width.filter(|_| self.soft_wrap);
+        let mut rows = Vec::with_capacity(self.lines.len());
+        for (line, text) in self.lines.iter().enumerate() {
+            match width {
+                Some(width) => rows.extend(
+                    wrap_ranges(text, width)
+                        .into_iter()
+                        .map(|(start, end)| VisualRow { line, start, end }),
+                ),
+                None => rows.push(VisualRow {
+                    line,
+                    start: 0,
+                    end: text_edit::char_count(text),
+                }),
+            }
+        }
+        rows
+    }
+
+    fn cursor_row(&self, rows: &[VisualRow]) -> usize {
+        rows.iter()
+            .rposition(|row| row.line == self.row && row.start <= self.col)
+            .unwrap_or(0)
+    }
+
+    /// The first shown row and how many rows are shown. Starts at the
+    /// scrolled-to line, moving on as far as needed to keep the cursor's
+    /// row in view.
+    fn window(&self, rows: &[VisualRow]) -> (usize, usize) {
+        let visible = rows.len().clamp(self.min_height, self.max_height);
+        let (start_line, _) = self.scroll.visible_range(self.lines.len());
+        let mut first = rows
+            .iter()
+            .position(|row| row.line == start_line)
+            .unwrap_or(0);
+        let cursor = self.cursor_row(rows);
+        if cursor >= first + visible {
+            first = cursor + 1 - visible;
+        } else if cursor < first {
+            first = cursor;
+        }
+        (first, visible)
+    }
+
+    fn content_width(&self, available_width: u16) -> usize {
+        (available_width as usize)
+            .saturating_sub(self.gutter_width())
+            .max(1)
+    }
+
     fn split_line(&mut self) {
         let col = self.col.min(text_edit::char_count(&self.lines[self.row]));
         let line = &self.lines[self.row];
@@ -111,26 +221,81 @@ impl TextAreaComponent {
         text_edit::char_count(&self.lines[self.row])
     }
 
-    fn build_gutter_span(&self, line_idx: usize, _focused: bool) -> Span {
-        let num_w = self.num_width();
-        let num_str = format!("{:>width$}", line_idx + 1, width = num_w);
-        let text = format!("│ {}  ", num_str);
-        Span::styled(text, Style::new().color(Color::DarkGrey).no_strikethrough()).no_wrap()
+    /// Line number on a line's first row, blank on rows it wraps onto.
+    fn build_gutter_span(&self, row: VisualRow) -> Span {
+        let text = if !self.line_numbers {
+            "│ ".to_string()
+        } else if row.start == 0 {
+            format!("│ {:>width$}  ", row.line + 1, width = self.num_width())
+        } else {
+            format!("│ {:width$}  ", "", width = self.num_width())
+        };
+        Span::styled(text, StyleRole::Hint.style().no_strikethrough()).no_wrap()
     }
 
     fn build_tilde_span(&self) -> Span {
-        let num_w = self.num_width();
-        let pad = num_w + 1;
+        let pad = self.gutter_width().saturating_sub(3);
         let text = format!("│ ~{:pad$}", "", pad = pad);
-        Span::styled(text, Style::new().color(Color::DarkGrey).no_strikethrough()).no_wrap()
+        Span::styled(text, StyleRole::Hint.style().no_strikethrough()).no_wrap()
+    }
+
+    fn row_text(&self, row: VisualRow) -> String {
+        self.lines[row.line]
+            .chars()
+            .skip(row.start)
+            .take(row.end - row.start)
+            .collect()
     }
 
-    fn line_display_col(&self, row: usize, col: usize) -> usize {
-        self.lines[row]
+    fn cursor_at(&self, width: Option<usize>) -> CursorPos {
+        let rows = self.visual_rows(width);
+        let (first, _) = self.window(rows.as_slice());
+        let cursor_row = self.cursor_row(rows.as_slice());
+        let row = rows[cursor_row];
+        let before: String = self.lines[self.row]
             .chars()
-            .take(col.min(text_edit::char_count(&self.lines[row])))
-            .map(|ch| UnicodeWidthChar::width(ch).unwrap_or(1))
-            .sum()
+            .skip(row.start)
+            .take(self.col.saturating_sub(row.start))
+            .collect();
+        CursorPos {
+            row: (self.header_rows() + cursor_row - first) as u16,
+            col: (self.gutter_width() + text_display_width(before.as_str())) as u16,
+        }
+    }
+}
+
+/// Char ranges of the rows `line` takes at `width` columns, breaking after
+/// the last space that fits or mid-word when none does. A full last row
+/// gets an empty one after it for the cursor.
+fn wrap_ranges(line: &str, width: usize) -> Vec<(usize, usize)> {
+    let chars = line.chars().collect::<Vec<_>>();
+    let mut rows = Vec::new();
+    let mut start = 0;
+    loop {
+        let mut used = 0;
+        let mut end = start;
+        let mut after_space = None;
+        while end < chars.len() {
+            let char_width = char_display_width(chars[end]);
+            if used + char_width > width && end > start {
+                break;
+            }
+            used += char_width;
+            end += 1;
+            if chars[end - 1] == ' ' {
+                after_space = Some(end);
+            }
+        }
+        if end >= chars.len() {
+            rows.push((start, end));
+            if used >= width && end > start {
+                rows.push((end, end));
+            }
+            return rows;
+        }
+        let split = after_space.unwrap_or(end);
+        rows.push((start, split));
+        start = split;
     }
 }
 
@@ -139,25 +304,34 @@ impl Drawable for TextAreaComponent {
         &self.id
     }
 
+    // The label gets its own line above the text rather than the inline
+    // prefix inputs are given, which would push the first row out of line.
     fn label(&self) -> &str {
         ""
     }
 
     fn draw(&self, ctx: &RenderContext) -> DrawOutput {
         let focused = ctx.focused_id.as_deref().is_some_and(|id| id == self.id);
-        let visible = self.visible_height();
-        let (start, _) = self.scroll.visible_range(self.lines.len());
+        let rows = self.visual_rows(Some(self.content_width(ctx.terminal_size.width)));
+        let (first, visible) = self.window(rows.as_slice());
 
-        let mut output_lines = Vec::with_capacity(visible + 1);
+        let mut output_lines = Vec::with_capacity(visible + 2);
+        if !self.label.is_empty() {
+            let style = if focused {
+                StyleRole::Focus.style()
+            } else {
+                StyleRole::Label.style()
+            };
+            output_lines.push(ctx.label_line(self.label.as_str(), style));
+        }
 
         for i in 0..visible {
-            let real_idx = start + i;
-            if real_idx < self.lines.len() {
-                let gutter = self.build_gutter_span(real_idx, focused);
-                let content = Span::new(self.lines[real_idx].clone()).no_wrap();
-                output_lines.push(vec![gutter, content]);
-            } else {
-                output_lines.push(vec![self.build_tilde_span()]);
+            match rows.get(first + i) {
+                Some(row) => output_lines.push(vec![
+                    self.build_gutter_span(*row),
+                    Span::new(self.row_text(*row)).no_wrap(),
+                ]),
+                None => output_lines.push(vec![self.build_tilde_span()]),
             }
         }
 
@@ -165,7 +339,7 @@ impl Drawable for TextAreaComponent {
             output_lines.push(vec![
                 Span::styled(
                     format!("  {}", footer),
-                    Style::new().color(Color::DarkGrey).no_strikethrough(),
+                    StyleRole::Hint.style().no_strikethrough(),
                 )
                 .no_wrap(),
             ]);
@@ -177,7 +351,13 @@ impl Drawable for TextAreaComponent {
     fn hints(&self, ctx: HintContext) -> Vec<HintItem> {
         crate::widgets::traits::focused_static_hints(
             ctx,
-            crate::widgets::static_hints::TEXTAREA_HINTS,
+            if self.read_only {
+                crate::widgets::static_hints::TEXTAREA_READ_ONLY_HINTS
+            } else if self.newline_on_enter {
+                crate::widgets::static_hints::TEXTAREA_NEWLINE_HINTS
+            } else {
+                crate::widgets::static_hints::TEXTAREA_HINTS
+            },
         )
     }
 }
@@ -194,10 +374,22 @@ impl Interactive for TextAreaComponent {
     }
 
     fn on_key(&mut self, key: KeyEvent) -> InteractionResult {
+        if self.read_only
+            && matches!(
+                key.code,
+                KeyCode::Char(_) | KeyCode::Backspace | KeyCode::Delete
+            )
+        {
+            return InteractionResult::ignored();
+        }
         match key.code {
             KeyCode::Esc => InteractionResult::input_done(),
+            KeyCode::Enter if self.read_only || key.modifiers.contains(KeyModifiers::CONTROL) => {
+                InteractionResult::input_done()
+            }
             KeyCode::Enter
-                if key.modifiers.contains(KeyModifiers::SHIFT)
+                if self.newline_on_enter
+                    || key.modifiers.contains(KeyModifiers::SHIFT)
                     || key.modifiers.contains(KeyModifiers::ALT) =>
             {
                 self.split_line();
@@ -266,19 +458,49 @@ impl Interactive for TextAreaComponent {
                 }
                 InteractionResult::handled()
             }
+            KeyCode::PageUp | KeyCode::PageDown => {
+                let page = self.visible_height().saturating_sub(1).max(1);
+                self.row = if key.code == KeyCode::PageUp {
+                    self.row.saturating_sub(page)
+                } else {
+                    (self.row + page).min(self.lines.len() - 1)
+                };
+                self.col = self.col.min(self.current_line_len());
+                self.scroll.ensure_visible(self.row, self.lines.len());
+                InteractionResult::handled()
+            }
 
             _ => InteractionResult::ignored(),
         }
     }
 
     fn text_editing(&mut self) -> Option<TextEditState<'_>> {
+        if self.read_only {
+            return None;
+        }
         Some(TextEditState {
             value: &mut self.lines[self.row],
             cursor: &mut self.col,
         })
     }
 
+    fn on_paste(&mut self, text: &str) -> InteractionResult {
+        if self.read_only {
+            return InteractionResult::ignored();
+        }
+        for (index, line) in text_edit::clean_paste(text).split('\n').enumerate() {
+            if index > 0 {
+                self.split_line();
+            }
+            text_edit::insert_str(&mut self.lines[self.row], &mut self.col, line);
+        }
+        InteractionResult::handled()
+    }
+
     fn on_text_action(&mut self, action: TextAction) -> InteractionResult {
+        if self.read_only {
+            return InteractionResult::ignored();
+        }
         if action == TextAction::DeleteWordLeft && self.col == 0 && self.row > 0 {
             self.merge_with_prev();
             return InteractionResult::handled();
@@ -326,18 +548,19 @@ impl Interactive for TextAreaComponent {
         }
     }
 
-    fn validate(&self, _mode: ValidationMode) -> Result<(), String> {
+    fn validate(&self, _mode: ValidationMode) -> Result<(), Message> {
         run_validators(&self.validators, &Value::Text(self.lines.join("\n")))
     }
 
     fn cursor_pos(&self) -> Option<CursorPos> {
-        let (start, _) = self.scroll.visible_range(self.lines.len());
-        let visible_row = self.row.saturating_sub(start);
-        let display_col = self.line_display_col(self.row, self.col);
-        let col = self.gutter_width() + display_col;
-        Some(CursorPos {
-            row: visible_row as u16,
-            col: col as u16,
-        })
+        Some(self.cursor_at(None))
+    }
+
+    fn cursor_pos_with_width(&self, available_width: u16) -> Option<CursorPos> {
+        Some(self.cursor_at(Some(self.content_width(available_width))))
     }
 }
+
+#[cfg(test)]
+#[path = "../tests/textarea.rs"]
+mod tests;
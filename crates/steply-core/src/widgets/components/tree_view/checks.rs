@@ -0,0 +1,161 @@
+use super::{TreeItemLabel, TreeNode, TreeView};
+use crate::core::value::Value;
+use crate::ui::span::Span;
+use crate::ui::style::{Style, StyleRole};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum CheckState {
+    Unchecked,
+    Partial,
+    Checked,
+}
+
+impl CheckState {
+    fn marker(self) -> &'static str {
+        match self {
+            Self::Unchecked => "□ ",
+            Self::Partial => "▣ ",
+            Self::Checked => "■ ",
+        }
+    }
+}
+
+impl<T: TreeItemLabel> TreeView<T> {
+    /// Puts a checkbox in front of every node. Checking a parent checks its
+    /// whole subtree, and `value()` lists the paths of the checked nodes.
+    pub fn with_checkboxes(mut self, checkboxes: bool) -> Self {
+        self.checkboxes = checkboxes;
+        self.normalize_checks();
+        self
+    }
+
+    pub fn has_checkboxes(&self) -> bool {
+        self.checkboxes
+    }
+
+    /// Checks `node_idx` and its subtree unless all of it is checked already,
+    /// in which case it is cleared.
+    pub fn toggle_checked(&mut self, node_idx: usize) -> bool {
+        if !self.checkboxes || node_idx >= self.nodes.len() {
+            return false;
+        }
+        let checked = self.check_state(node_idx) != CheckState::Checked;
+        let end = self.subtree_end(node_idx);
+        for node in &mut self.nodes[node_idx..end] {
+            node.checked = checked;
+        }
+        self.sync_parent_checks();
+        true
+    }
+
+    /// Slash-joined labels from the root down to each checked node, in tree
+    /// order.
+    pub fn checked_paths(&self) -> Vec<String> {
+        (0..self.nodes.len())
+            .filter(|idx| self.nodes[*idx].checked)
+            .map(|idx| self.node_path(idx))
+            .collect()
+    }
+
+    pub fn set_checked_paths(&mut self, paths: &[String]) {
+        for idx in 0..self.nodes.len() {
+            self.nodes[idx].checked = paths.contains(&self.node_path(idx));
+        }
+        self.normalize_checks();
+    }
+
+    /// Spreads checked parents over their subtrees, then settles parents
+    /// on their children.
+    pub(super) fn normalize_checks(&mut self) {
+        let mut idx = 0;
+        while idx < self.nodes.len() {
+            if !self.nodes[idx].checked {
+                idx += 1;
+                continue;
+            }
+            let end = self.subtree_end(idx);
+            for node in &mut self.nodes[idx..end] {
+                node.checked = true;
+            }
+            idx = end;
+        }
+        self.sync_parent_checks();
+    }
+
+    pub(super) fn check_state(&self, node_idx: usize) -> CheckState {
+        if self.nodes[node_idx].checked {
+            return CheckState::Checked;
+        }
+        let end = self.subtree_end(node_idx);
+        if self.nodes[node_idx + 1..end]
+            .iter()
+            .any(|node| node.checked)
+        {
+            CheckState::Partial
+        } else {
+            CheckState::Unchecked
+        }
+    }
+
+    pub(super) fn check_marker_span(&self, node_idx: usize, highlight: bool) -> Span {
+        let state = self.check_state(node_idx);
+        let style = match state {
+            CheckState::Checked | CheckState::Partial => StyleRole::Selected.style(),
+            CheckState::Unchecked if highlight => Style::default(),
+            CheckState::Unchecked => StyleRole::Hint.style(),
+        };
+        Span::styled(state.marker(), style).no_wrap()
+    }
+
+    pub(super) fn checked_paths_value(&self) -> Value {
+        Value::List(self.checked_paths().into_iter().map(Value::Text).collect())
+    }
+
+    /// One past the last descendant of `node_idx`.
+    fn subtree_end(&self, node_idx: usize) -> usize {
+        let depth = self.nodes[node_idx].depth;
+        self.nodes[node_idx + 1..]
+            .iter()
+            .position(|node| node.depth <= depth)
+            .map_or(self.nodes.len(), |offset| node_idx + 1 + offset)
+    }
+
+    fn node_path(&self, node_idx: usize) -> String {
+        let mut labels = vec![self.nodes[node_idx].item.label()];
+        let mut depth = self.nodes[node_idx].depth;
+        for node in self.nodes[..node_idx].iter().rev() {
+            if depth == 0 {
+                break;
+            }
+            if node.depth < depth {
+                labels.push(node.item.label());
+                depth = node.depth;
+            }
+        }
+        labels.reverse();
+        labels.join("/")
+    }
+
+    /// A parent whose children are listed counts as checked exactly when
+    /// all of them are.
+    fn sync_parent_checks(&mut self) {
+        for idx in (0..self.nodes.len()).rev() {
+            let depth = self.nodes[idx].depth;
+            let mut children = self.nodes[idx + 1..]
+                .iter()
+                .take_while(|node| node.depth > depth)
+                .filter(|node| node.depth == depth + 1)
+                .peekable();
+            if children.peek().is_some() {
+                self.nodes[idx].checked = children.all(|child| child.checked);
+            }
+        }
+    }
+}
+
+impl<T: TreeItemLabel> TreeNode<T> {
+    pub fn checked(mut self) -> Self {
+        self.checked = true;
+        self
+    }
+}
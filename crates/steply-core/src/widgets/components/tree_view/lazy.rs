@@ -0,0 +1,195 @@
+use std::sync::Arc;
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::mpsc::{self, Receiver, Sender};
+
+use super::{TreeItemLabel, TreeNode, TreeView};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::widgets::shared::async_utils::{drain_receiver, recv_latest};
+
+/// Lists the children of a collapsed node the first time it is expanded.
+/// Child depths are set by the tree.
+pub type ChildLoader<T> = Arc<dyn Fn(&T) -> Vec<TreeNode<T>> + Send + Sync>;
+
+pub(super) enum LazyChildren<T> {
+    Sync(ChildLoader<T>),
+    Async(ChildHandle<T>),
+}
+
+struct ChildRequest<T> {
+    seq: u64,
+    item: T,
+}
+
+struct ChildResult<T> {
+    seq: u64,
+    children: Vec<TreeNode<T>>,
+}
+
+/// Worker running a [`ChildLoader`] off the UI thread; only the newest
+/// queued node is loaded.
+pub(super) struct ChildHandle<T> {
+    seq: u64,
+    clone_item: fn(&T) -> T,
+    #[cfg(not(target_arch = "wasm32"))]
+    tx: Sender<ChildRequest<T>>,
+    #[cfg(not(target_arch = "wasm32"))]
+    rx: Receiver<ChildResult<T>>,
+    #[cfg(target_arch = "wasm32")]
+    loader: ChildLoader<T>,
+    #[cfg(target_arch = "wasm32")]
+    done: Vec<ChildResult<T>>,
+}
+
+impl<T: Send + 'static> ChildHandle<T> {
+    #[cfg(not(target_arch = "wasm32"))]
+    fn new(loader: ChildLoader<T>, clone_item: fn(&T) -> T) -> Self {
+        let (req_tx, req_rx) = mpsc::channel::<ChildRequest<T>>();
+        let (res_tx, res_rx) = mpsc::channel::<ChildResult<T>>();
+        std::thread::spawn(move || worker(loader, req_rx, res_tx));
+        Self {
+            seq: 0,
+            clone_item,
+            tx: req_tx,
+            rx: res_rx,
+        }
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn new(loader: ChildLoader<T>, clone_item: fn(&T) -> T) -> Self {
+        Self {
+            seq: 0,
+            clone_item,
+            loader,
+            done: Vec::new(),
+        }
+    }
+
+    fn submit(&mut self, item: &T) {
+        self.seq = self.seq.wrapping_add(1);
+        self.send(ChildRequest {
+            seq: self.seq,
+            item: (self.clone_item)(item),
+        });
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn send(&mut self, request: ChildRequest<T>) {
+        let _ = self.tx.send(request);
+    }
+
+    /// Without threads the loader runs in place; the result is still only
+    /// picked up by the next poll.
+    #[cfg(target_arch = "wasm32")]
+    fn send(&mut self, request: ChildRequest<T>) {
+        self.done.push(ChildResult {
+            seq: request.seq,
+            children: (self.loader)(&request.item),
+        });
+    }
+
+    /// Children for the newest request, once they have arrived.
+    fn try_recv_latest(&mut self) -> Option<Vec<TreeNode<T>>> {
+        #[cfg(not(target_arch = "wasm32"))]
+        let results = drain_receiver(&self.rx);
+        #[cfg(target_arch = "wasm32")]
+        let results = std::mem::take(&mut self.done);
+        results
+            .into_iter()
+            .rfind(|result| result.seq == self.seq)
+            .map(|result| result.children)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn worker<T>(loader: ChildLoader<T>, rx: Receiver<ChildRequest<T>>, tx: Sender<ChildResult<T>>) {
+    while let Some(req) = recv_latest(&rx) {
+        let children = loader(&req.item);
+        if tx
+            .send(ChildResult {
+                seq: req.seq,
+                children,
+            })
+            .is_err()
+        {
+            break;
+        }
+    }
+}
+
+impl<T: TreeItemLabel> TreeView<T> {
+    /// Calls `loader` for the children of a node the first time it is
+    /// expanded, instead of listing every node up front.
+    pub fn with_lazy_children<F>(mut self, loader: F) -> Self
+    where
+        F: Fn(&T) -> Vec<TreeNode<T>> + Send + Sync + 'static,
+    {
+        self.lazy = Some(LazyChildren::Sync(Arc::new(loader)));
+        self
+    }
+
+    /// Like [`Self::with_lazy_children`], but `loader` runs on a worker
+    /// thread; the node shows `⟳` until its children arrive.
+    pub fn with_async_children<F>(mut self, loader: F) -> Self
+    where
+        T: Clone,
+        F: Fn(&T) -> Vec<TreeNode<T>> + Send + Sync + 'static,
+    {
+        self.lazy = Some(LazyChildren::Async(ChildHandle::new(
+            Arc::new(loader),
+            T::clone,
+        )));
+        self
+    }
+
+    /// Whether expanding `node_idx` has to ask the loader for children:
+    /// none are listed yet and none were loaded before.
+    pub(super) fn needs_children(&self, node_idx: usize) -> bool {
+        let Some(node) = self.nodes.get(node_idx) else {
+            return false;
+        };
+        self.lazy.is_some()
+            && node.has_children
+            && !node.children_loaded
+            && self
+                .nodes
+                .get(node_idx + 1)
+                .is_none_or(|next| next.depth <= node.depth)
+    }
+
+    /// Starts loading the children of `node_idx`. Sync loaders insert them
+    /// right away; async ones mark the node pending until [`Self::poll_children`].
+    pub(super) fn load_children(&mut self, node_idx: usize) {
+        let item = &self.nodes[node_idx].item;
+        let children = match self.lazy.as_mut() {
+            Some(LazyChildren::Sync(loader)) => loader(item),
+            Some(LazyChildren::Async(handle)) => {
+                handle.submit(item);
+                self.pending_expand = Some(node_idx);
+                return;
+            }
+            None => return,
+        };
+        self.insert_children_after(node_idx, children);
+    }
+
+    pub(super) fn waiting_for_children(&self) -> bool {
+        self.pending_expand.is_some() && matches!(self.lazy, Some(LazyChildren::Async(_)))
+    }
+
+    /// Inserts children that arrived for the pending node; `true` when the
+    /// tree changed.
+    pub(super) fn poll_children(&mut self) -> bool {
+        let Some(node_idx) = self.pending_expand else {
+            return false;
+        };
+        let Some(LazyChildren::Async(handle)) = self.lazy.as_mut() else {
+            return false;
+        };
+        let Some(children) = handle.try_recv_latest() else {
+            return false;
+        };
+        self.pending_expand = None;
+        self.insert_children_after(node_idx, children);
+        true
+    }
+}
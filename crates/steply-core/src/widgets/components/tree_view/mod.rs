@@ -1,4 +1,9 @@
+mod checks;
+mod lazy;
 mod state;
+#[cfg(test)]
+#[path = "../tests/tree_view.rs"]
+mod tests;
 
 use std::borrow::Cow;
 
@@ -7,20 +12,24 @@ use crate::runtime::event::WidgetAction;
 use crate::terminal::{
     CursorPos, KeyCode, KeyEvent, PointerButton, PointerEvent, PointerKind, PointerSemantic,
 };
+use crate::time::{Duration, Instant};
+use crate::ui::animation::{Animations, Easing};
+use crate::ui::fill::paint_selection;
 use crate::ui::highlight::render_text_spans;
 use crate::ui::layout::Layout;
 use crate::ui::span::Span;
-use crate::ui::style::{Color, Style};
+use crate::ui::style::{Color, Style, StyleRole};
 use crate::widgets::base::WidgetBase;
 use crate::widgets::node::LeafComponent;
 use crate::widgets::shared::filter;
 use crate::widgets::shared::keymap;
 use crate::widgets::shared::list_policy;
-use crate::widgets::shared::scroll::ScrollState;
+use crate::widgets::shared::scroll::{ScrollState, Scrollbar};
 use crate::widgets::traits::{
     CompletionState, DrawOutput, Drawable, FocusMode, HintContext, HintGroup, HintItem,
     InteractionResult, Interactive, PointerRowMap, RenderContext, TextAction,
 };
+pub use lazy::ChildLoader;
 use state::{rebuild_visible, rebuild_visible_filtered};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -43,7 +52,7 @@ pub trait TreeItemLabel: Send + 'static {
 
     fn render_spans(&self, state: TreeItemRenderState) -> Vec<Span> {
         let style = if state.focused && state.active {
-            Style::new().color(Color::Cyan).bold()
+            StyleRole::Active.style()
         } else if state.selected {
             Style::new().color(Color::Yellow).bold()
         } else if state.has_children {
@@ -80,6 +89,7 @@ pub struct TreeNode<T> {
     pub expanded: bool,
 
     pub children_loaded: bool,
+    pub checked: bool,
 }
 
 impl<T: TreeItemLabel> TreeNode<T> {
@@ -90,6 +100,7 @@ impl<T: TreeItemLabel> TreeNode<T> {
             has_children,
             expanded: false,
             children_loaded: false,
+            checked: false,
         }
     }
 
@@ -105,14 +116,21 @@ pub struct TreeView<T: TreeItemLabel> {
     visible: Vec<usize>,
     active_index: usize,
     scroll: ScrollState,
+    scrollbar: bool,
     show_label: bool,
     show_indent_guides: bool,
     filter: filter::ListFilter,
     filter_query: String,
+    animate: bool,
+    reveals: Animations,
+    checkboxes: bool,
+    lazy: Option<lazy::LazyChildren<T>>,
 
     pub pending_expand: Option<usize>,
 }
 
+const REVEAL_DURATION: Duration = Duration::from_millis(180);
+
 impl<T: TreeItemLabel> TreeView<T> {
     pub fn new(id: impl Into<String>, label: impl Into<String>, nodes: Vec<TreeNode<T>>) -> Self {
         let id = id.into();
@@ -122,6 +140,7 @@ impl<T: TreeItemLabel> TreeView<T> {
             visible: Vec::new(),
             active_index: 0,
             scroll: ScrollState::new(None),
+            scrollbar: false,
             show_label: true,
             show_indent_guides: false,
             filter: filter::ListFilter::new(
@@ -130,6 +149,10 @@ impl<T: TreeItemLabel> TreeView<T> {
                 false,
             ),
             filter_query: String::new(),
+            animate: false,
+            reveals: Animations::default(),
+            checkboxes: false,
+            lazy: None,
             pending_expand: None,
         };
         this.rebuild();
@@ -159,6 +182,83 @@ impl<T: TreeItemLabel> TreeView<T> {
         &self.visible
     }
 
+    /// Draws a scrollbar beside the rows instead of the `[1-5 of 20]`
+    /// footer.
+    pub fn with_scrollbar(mut self, scrollbar: bool) -> Self {
+        self.scrollbar = scrollbar;
+        self
+    }
+
+    fn visible_scrollbar(&self) -> Option<Scrollbar> {
+        self.scrollbar
+            .then(|| self.scroll.scrollbar(self.visible.len()))
+            .flatten()
+    }
+
+    /// Width left for rows once the scrollbar column is taken.
+    fn rows_width(&self, width: u16) -> u16 {
+        if self.visible_scrollbar().is_some() {
+            width.saturating_sub(1).max(1)
+        } else {
+            width
+        }
+    }
+
+    /// Rolls newly expanded children in row by row instead of showing them
+    /// all at once.
+    pub fn with_animation(mut self, animate: bool) -> Self {
+        self.animate = animate;
+        self
+    }
+
+    fn start_reveal(&mut self, node_idx: usize) {
+        if !self.animate {
+            return;
+        }
+        let Some(pos) = self.visible.iter().position(|idx| *idx == node_idx) else {
+            return;
+        };
+        let depth = self.nodes[node_idx].depth;
+        let rows = self.visible[pos + 1..]
+            .iter()
+            .take_while(|idx| self.nodes[**idx].depth > depth)
+            .count();
+        if rows > 1 {
+            self.reveals.start(
+                node_idx.to_string(),
+                0.0,
+                rows as f64,
+                REVEAL_DURATION,
+                Easing::OutCubic,
+                Instant::now(),
+            );
+        }
+    }
+
+    /// Whether a row is still waiting for an expanding ancestor to roll it in.
+    fn is_unrevealed(&self, vis_pos: usize, now: Instant) -> bool {
+        if !self.reveals.is_running() {
+            return false;
+        }
+        let Some(mut depth) = self.visible.get(vis_pos).map(|idx| self.nodes[*idx].depth) else {
+            return false;
+        };
+        for ancestor_pos in (0..vis_pos).rev() {
+            let node_idx = self.visible[ancestor_pos];
+            let ancestor_depth = self.nodes[node_idx].depth;
+            if ancestor_depth >= depth {
+                continue;
+            }
+            depth = ancestor_depth;
+            if let Some(revealed) = self.reveals.value(node_idx.to_string().as_str(), now)
+                && (vis_pos - ancestor_pos - 1) as f64 >= revealed.ceil()
+            {
+                return true;
+            }
+        }
+        false
+    }
+
     pub fn with_show_label(mut self, show_label: bool) -> Self {
         self.show_label = show_label;
         self
@@ -175,6 +275,10 @@ impl<T: TreeItemLabel> TreeView<T> {
 
     pub fn set_nodes(&mut self, nodes: Vec<TreeNode<T>>) {
         self.nodes = nodes;
+        self.pending_expand = None;
+        if self.checkboxes {
+            self.normalize_checks();
+        }
         self.rebuild();
     }
 
@@ -246,6 +350,7 @@ impl<T: TreeItemLabel> TreeView<T> {
             return;
         };
         let child_depth = parent.depth + 1;
+        let parent_checked = parent.checked;
         parent.children_loaded = true;
         parent.expanded = true;
 
@@ -261,10 +366,12 @@ impl<T: TreeItemLabel> TreeView<T> {
 
         for (i, mut child) in children.into_iter().enumerate() {
             child.depth = child_depth;
+            child.checked |= parent_checked;
             self.nodes.insert(parent_idx + 1 + i, child);
         }
 
         self.rebuild();
+        self.start_reveal(parent_idx);
     }
 
     fn rebuild(&mut self) {
@@ -317,9 +424,17 @@ impl<T: TreeItemLabel> TreeView<T> {
             return false;
         };
         let node = &self.nodes[node_idx];
+        if self.needs_children(node_idx) {
+            if self.pending_expand == Some(node_idx) {
+                return false;
+            }
+            self.load_children(node_idx);
+            return true;
+        }
         if node.has_children && !node.expanded {
             self.nodes[node_idx].expanded = true;
             self.rebuild();
+            self.start_reveal(node_idx);
             true
         } else {
             false
@@ -330,6 +445,9 @@ impl<T: TreeItemLabel> TreeView<T> {
         let Some(&node_idx) = self.visible.get(self.active_index) else {
             return false;
         };
+        if self.pending_expand.take() == Some(node_idx) {
+            return true;
+        }
         let node = &self.nodes[node_idx];
 
         if node.has_children && node.expanded {
@@ -357,10 +475,26 @@ impl<T: TreeItemLabel> TreeView<T> {
         false
     }
 
+    /// Puts the selection bar behind the active row of `rows` as returned by
+    /// [`Self::render_lines`], wrapping it first so every line it takes is
+    /// painted.
+    fn paint_active_row(&self, rows: &mut Vec<Vec<Span>>, width: u16) {
+        let (start, end) = self.scroll.visible_range(self.visible.len());
+        if !(start..end).contains(&self.active_index)
+            || self.is_unrevealed(self.active_index, Instant::now())
+        {
+            return;
+        }
+        let row = self.active_index - start;
+        let mut active = Layout::compose(&rows[row..=row], width);
+        paint_selection(&mut active, width);
+        rows.splice(row..=row, active);
+    }
+
     fn render_visible_line(&self, vis_pos: usize, focused: bool) -> Vec<Span> {
-        let inactive_style = Style::new().color(Color::DarkGrey);
-        let cursor_style = Style::new().color(Color::Yellow);
-        let active_style = Style::new().color(Color::Cyan).bold();
+        let inactive_style = StyleRole::Hint.style();
+        let cursor_style = StyleRole::Cursor.style();
+        let active_style = StyleRole::Active.style();
         let loading_style = Style::new().color(Color::Yellow);
 
         let Some(node_idx) = self.visible.get(vis_pos).copied() else {
@@ -399,6 +533,9 @@ impl<T: TreeItemLabel> TreeView<T> {
         let mut line = vec![cursor_span];
         line.extend(self.render_indent_spans(node_idx, node.depth, focused, active));
         line.push(icon_span);
+        if self.checkboxes {
+            line.push(self.check_marker_span(node_idx, focused && active));
+        }
         let highlights = if self.filter_query.trim().is_empty() {
             Vec::new()
         } else {
@@ -407,7 +544,7 @@ impl<T: TreeItemLabel> TreeView<T> {
         line.extend(node.item.render_spans(TreeItemRenderState {
             focused,
             active,
-            selected: false,
+            selected: self.checkboxes && node.checked,
             has_children: node.has_children,
             expanded: node.expanded,
             loading,
@@ -420,8 +557,13 @@ impl<T: TreeItemLabel> TreeView<T> {
         let mut lines = Vec::new();
         let total = self.visible.len();
         let (start, end) = self.scroll.visible_range(total);
+        let now = Instant::now();
         for vis_pos in start..end {
-            lines.push(self.render_visible_line(vis_pos, focused));
+            if self.is_unrevealed(vis_pos, now) {
+                lines.push(vec![Span::new(" ").no_wrap()]);
+            } else {
+                lines.push(self.render_visible_line(vis_pos, focused));
+            }
         }
 
         let placeholders = self.scroll.placeholder_count(total);
@@ -429,10 +571,10 @@ impl<T: TreeItemLabel> TreeView<T> {
             lines.push(vec![Span::new(" ").no_wrap()]);
         }
 
-        if let Some(text) = self.scroll.footer(total) {
-            lines.push(vec![
-                Span::styled(text, Style::new().color(Color::DarkGrey)).no_wrap(),
-            ]);
+        if !self.scrollbar
+            && let Some(text) = self.scroll.footer(total)
+        {
+            lines.push(vec![Span::styled(text, StyleRole::Hint.style()).no_wrap()]);
         }
 
         lines
@@ -445,7 +587,7 @@ impl<T: TreeItemLabel> TreeView<T> {
         focused: bool,
         active: bool,
     ) -> Vec<Span> {
-        let inactive_style = Style::new().color(Color::DarkGrey);
+        let inactive_style = StyleRole::Hint.style();
         let mut spans = Vec::with_capacity(depth + 1);
         if focused && active {
             spans.push(Span::new(" ").no_wrap());
@@ -548,17 +690,37 @@ impl<T: TreeItemLabel> TreeView<T> {
             .set_active_clamped(&mut self.active_index, self.visible.len(), vis_pos);
 
         let depth = self.nodes.get(node_idx).map(|node| node.depth).unwrap_or(0);
+        let (_, marker_start) = Self::icon_col_range(depth);
+        if self.checkboxes
+            && !continuation
+            && (marker_start..marker_start.saturating_add(2)).contains(&event.col)
+        {
+            self.toggle_checked(node_idx);
+            return self.handled_with_focus();
+        }
         let has_children = self
             .nodes
             .get(node_idx)
             .is_some_and(|node| node.has_children);
         if has_children {
             let (icon_start, icon_end) = Self::icon_col_range(depth);
-            if !continuation && event.col >= icon_start && event.col < icon_end {
-                if let Some(node) = self.nodes.get_mut(node_idx) {
-                    node.expanded = !node.expanded;
+            if !continuation
+                && event.col >= icon_start
+                && event.col < icon_end
+                && self.needs_children(node_idx)
+            {
+                if self.pending_expand != Some(node_idx) {
+                    self.load_children(node_idx);
                 }
+            } else if !continuation && event.col >= icon_start && event.col < icon_end {
+                let expanded = self.nodes.get_mut(node_idx).is_some_and(|node| {
+                    node.expanded = !node.expanded;
+                    node.expanded
+                });
                 self.rebuild();
+                if expanded {
+                    self.start_reveal(node_idx);
+                }
                 if let Some(pos) = self.visible.iter().position(|idx| *idx == node_idx) {
                     self.scroll
                         .set_active_clamped(&mut self.active_index, self.visible.len(), pos);
@@ -582,19 +744,31 @@ impl<T: TreeItemLabel> Drawable for TreeView<T> {
         let mut lines = Vec::new();
 
         if self.show_label && !self.base.label().is_empty() {
-            lines.push(vec![Span::new(self.base.label()).no_wrap()]);
+            lines.push(ctx.label_line(self.base.label(), StyleRole::Label.style()));
         }
 
         if self.filter.is_visible() {
             lines.push(self.filter.draw_line(ctx, focused));
         }
 
-        lines.extend(self.render_lines(focused));
+        let width = self.rows_width(ctx.terminal_size.width.max(1));
+        let mut rows = self.render_lines(focused);
+        if focused {
+            self.paint_active_row(&mut rows, width);
+        }
+        match self.visible_scrollbar() {
+            Some(scrollbar) => {
+                let mut rows = Layout::compose(rows.as_slice(), width);
+                scrollbar.attach(&mut rows, width);
+                lines.extend(rows);
+            }
+            None => lines.extend(rows),
+        }
         DrawOutput::with_lines(lines)
     }
 
     fn pointer_rows(&self, ctx: &RenderContext) -> Vec<PointerRowMap> {
-        self.pointer_rows_for_draw(ctx.terminal_size.width.max(1))
+        self.pointer_rows_for_draw(self.rows_width(ctx.terminal_size.width.max(1)))
     }
 
     fn hints(&self, ctx: HintContext) -> Vec<HintItem> {
@@ -609,6 +783,9 @@ impl<T: TreeItemLabel> Drawable for TreeView<T> {
         } else {
             hints.retain(|hint| hint.key != "Esc");
         }
+        if !self.checkboxes {
+            hints.retain(|hint| hint.key != "Space");
+        }
         hints
     }
 }
@@ -636,24 +813,57 @@ impl<T: TreeItemLabel> Interactive for TreeView<T> {
             KeyCode::Down => InteractionResult::handled_if(self.move_active(1)),
             KeyCode::Right => InteractionResult::handled_if(self.expand_active()),
             KeyCode::Left => InteractionResult::handled_if(self.collapse_active()),
+            KeyCode::Char(' ') if self.checkboxes => InteractionResult::handled_if(
+                self.active_node_idx()
+                    .is_some_and(|node_idx| self.toggle_checked(node_idx)),
+            ),
             KeyCode::Enter => InteractionResult::input_done(),
             _ => InteractionResult::ignored(),
         }
     }
 
     fn on_pointer(&mut self, event: PointerEvent) -> InteractionResult {
+        if let Some(delta) = event.kind.wheel_delta() {
+            return InteractionResult::handled_if(self.scroll.move_active_clamped(
+                &mut self.active_index,
+                self.visible.len(),
+                delta,
+            ));
+        }
         match event.kind {
             PointerKind::Down(PointerButton::Left) => self.handle_pointer_left_down(event),
             _ => InteractionResult::ignored(),
         }
     }
 
+    fn on_tick(&mut self) -> InteractionResult {
+        let loaded = self.poll_children();
+        InteractionResult::handled_if(self.reveals.tick(Instant::now()) || loaded)
+    }
+
+    fn wants_tick(&self) -> bool {
+        self.reveals.is_running() || self.waiting_for_children()
+    }
+
     fn value(&self) -> Option<Value> {
+        if self.checkboxes {
+            return Some(self.checked_paths_value());
+        }
         self.active_node()
             .map(|node| Value::Text(node.item.label().to_string()))
     }
 
     fn set_value(&mut self, value: Value) {
+        if self.checkboxes
+            && let Some(paths) = value.as_list()
+        {
+            let paths = paths
+                .iter()
+                .filter_map(Value::to_text_scalar)
+                .collect::<Vec<_>>();
+            self.set_checked_paths(paths.as_slice());
+            return;
+        }
         let Some(text) = value.to_text_scalar() else {
             return;
         };
@@ -1,7 +1,8 @@
+use crate::core::i18n::Message;
 use crate::core::value::Value;
 use crate::terminal::{CursorPos, KeyCode, KeyEvent};
 use crate::ui::span::Span;
-use crate::ui::style::{Color, Style};
+use crate::ui::style::{Style, StyleRole};
 use crate::widgets::base::WidgetBase;
 use crate::widgets::shared::horizontal_viewport::render_single_line;
 use crate::widgets::shared::text_edit;
@@ -232,7 +233,7 @@ impl ArrayInput {
             };
             let is_active = focused && idx == self.active;
             let style = if is_active {
-                Style::new().color(Color::Cyan).bold()
+                StyleRole::Active.style()
             } else {
                 Style::default()
             };
@@ -381,7 +382,7 @@ impl Interactive for ArrayInput {
         }
     }
 
-    fn validate(&self, _mode: ValidationMode) -> Result<(), String> {
+    fn validate(&self, _mode: ValidationMode) -> Result<(), Message> {
         for item in &self.items {
             let trimmed = item.trim();
             if !trimmed.is_empty() {
@@ -1,9 +1,10 @@
+use crate::core::i18n::Message;
 use crate::core::value::Value;
 use crate::runtime::event::WidgetAction;
 use crate::task::{TaskId, TaskRequest};
 use crate::terminal::{KeyCode, KeyEvent};
 use crate::ui::span::Span;
-use crate::ui::style::{Color, Style};
+use crate::ui::style::{Color, Style, StyleRole};
 use crate::widgets::base::WidgetBase;
 use crate::widgets::traits::{
     DrawOutput, Drawable, FocusMode, InteractionResult, Interactive, RenderContext, ValidationMode,
@@ -16,6 +17,7 @@ pub struct ButtonInput {
     clicks: i64,
     validators: Vec<Validator>,
     task_request: Option<TaskRequest>,
+    action: Option<WidgetAction>,
 }
 
 impl ButtonInput {
@@ -27,6 +29,7 @@ impl ButtonInput {
             clicks: 0,
             validators: Vec::new(),
             task_request: None,
+            action: None,
         }
     }
 
@@ -50,6 +53,12 @@ impl ButtonInput {
         self
     }
 
+    /// Action emitted on press when no task request is set.
+    pub fn with_action(mut self, action: WidgetAction) -> Self {
+        self.action = Some(action);
+        self
+    }
+
     pub fn with_task_id(mut self, task_id: impl Into<TaskId>) -> Self {
         self.task_request = Some(TaskRequest::new(task_id));
         self
@@ -68,9 +77,9 @@ impl Drawable for ButtonInput {
     fn draw(&self, ctx: &RenderContext) -> DrawOutput {
         let focused = self.base.is_focused(ctx);
         let label = if self.text.is_empty() {
-            " "
+            " ".into()
         } else {
-            &self.text
+            ctx.translate(&self.text)
         };
         let style = if focused {
             Style::new()
@@ -78,7 +87,7 @@ impl Drawable for ButtonInput {
                 .background(Color::Blue)
                 .bold()
         } else {
-            Style::new().color(Color::DarkGrey)
+            StyleRole::Hint.style()
         };
 
         DrawOutput::with_lines(vec![vec![Span::styled(label, style).no_wrap()]])
@@ -97,6 +106,9 @@ impl Interactive for ButtonInput {
                 if let Some(request) = self.task_request.clone() {
                     return InteractionResult::with_action(WidgetAction::TaskRequested { request });
                 }
+                if let Some(action) = self.action.clone() {
+                    return InteractionResult::with_action(action);
+                }
                 InteractionResult::handled()
             }
             _ => InteractionResult::ignored(),
@@ -113,7 +125,7 @@ impl Interactive for ButtonInput {
         }
     }
 
-    fn validate(&self, _mode: ValidationMode) -> Result<(), String> {
+    fn validate(&self, _mode: ValidationMode) -> Result<(), Message> {
         run_validators(&self.validators, &Value::Number(self.clicks as f64))
     }
 }
@@ -1,3 +1,4 @@
+use crate::core::i18n::Message;
 use crate::core::value::Value;
 use crate::terminal::{KeyCode, KeyEvent};
 use crate::ui::span::Span;
@@ -96,7 +97,7 @@ impl Interactive for CheckboxInput {
         }
     }
 
-    fn validate(&self, _mode: ValidationMode) -> Result<(), String> {
+    fn validate(&self, _mode: ValidationMode) -> Result<(), Message> {
         run_validators(&self.validators, &Value::Bool(self.checked))
     }
 }
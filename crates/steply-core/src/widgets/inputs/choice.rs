@@ -1,7 +1,8 @@
+use crate::core::i18n::Message;
 use crate::core::value::Value;
 use crate::terminal::{KeyCode, KeyEvent};
 use crate::ui::span::Span;
-use crate::ui::style::{Color, Style};
+use crate::ui::style::{Color, Style, StyleRole};
 use crate::widgets::base::WidgetBase;
 use crate::widgets::shared::horizontal_viewport::render_single_line;
 use crate::widgets::shared::list_nav;
@@ -93,8 +94,8 @@ impl Drawable for ChoiceInput {
         let focused = self.base.is_focused(ctx);
 
         let (spans, active_range) = if focused {
-            let active_style = Style::new().color(Color::Cyan).bold();
-            let inactive_style = Style::new().color(Color::DarkGrey);
+            let active_style = StyleRole::Active.style();
+            let inactive_style = StyleRole::Hint.style();
             let mut s = vec![];
             let mut active_range = (0usize, 0usize);
             let mut width = 0usize;
@@ -211,7 +212,7 @@ impl Interactive for ChoiceInput {
         true
     }
 
-    fn validate(&self, _mode: ValidationMode) -> Result<(), String> {
+    fn validate(&self, _mode: ValidationMode) -> Result<(), Message> {
         run_validators(
             &self.validators,
             &Value::Text(self.selected_text().to_string()),
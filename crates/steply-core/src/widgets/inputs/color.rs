@@ -1,7 +1,8 @@
+use crate::core::i18n::Message;
 use crate::core::value::Value;
 use crate::terminal::{CursorPos, KeyCode, KeyEvent, KeyModifiers};
 use crate::ui::span::Span;
-use crate::ui::style::{Color, Style};
+use crate::ui::style::{Color, Style, StyleRole};
 use crate::widgets::base::WidgetBase;
 use crate::widgets::traits::{
     DrawOutput, Drawable, FocusMode, InteractionResult, Interactive, RenderContext,
@@ -259,7 +260,7 @@ impl ColorInput {
             }
         }
 
-        let active_style = Style::new().color(Color::Cyan).bold();
+        let active_style = StyleRole::Active.style();
         let inactive_style = Style::default();
 
         let mut spans = Vec::<Span>::new();
@@ -371,7 +372,7 @@ impl Interactive for ColorInput {
         }
     }
 
-    fn validate(&self, _mode: ValidationMode) -> Result<(), String> {
+    fn validate(&self, _mode: ValidationMode) -> Result<(), Message> {
         run_validators(&self.validators, &Value::Text(rgb_to_hex(self.rgb)))
     }
 
@@ -1,7 +1,8 @@
+use crate::core::i18n::Message;
 use crate::core::value::Value;
 use crate::terminal::{CursorPos, KeyCode, KeyEvent};
 use crate::ui::span::Span;
-use crate::ui::style::{Color, Style};
+use crate::ui::style::{Color, Style, StyleRole};
 use crate::widgets::base::WidgetBase;
 use crate::widgets::shared::text_edit;
 use crate::widgets::traits::{
@@ -22,9 +23,9 @@ pub enum ConfirmMode {
 pub struct ConfirmInput {
     base: WidgetBase,
 
-    yes_label: String,
+    yes_label: Message,
 
-    no_label: String,
+    no_label: Message,
 
     confirmed: Option<bool>,
     mode: ConfirmMode,
@@ -39,8 +40,8 @@ impl ConfirmInput {
     pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
         Self {
             base: WidgetBase::new(id, label),
-            yes_label: "yes".into(),
-            no_label: "no".into(),
+            yes_label: Message::builtin("confirm-yes"),
+            no_label: Message::builtin("confirm-no"),
             confirmed: None,
             mode: ConfirmMode::Relaxed,
             buffer: String::new(),
@@ -59,8 +60,8 @@ impl ConfirmInput {
         yes_label: impl Into<String>,
         no_label: impl Into<String>,
     ) -> Self {
-        self.yes_label = yes_label.into();
-        self.no_label = no_label.into();
+        self.yes_label = Message::Text(yes_label.into());
+        self.no_label = Message::Text(no_label.into());
         self
     }
 
@@ -93,37 +94,40 @@ impl Drawable for ConfirmInput {
 
     fn draw(&self, ctx: &RenderContext) -> DrawOutput {
         let focused = self.base.is_focused(ctx);
+        let yes_label = ctx.render(&self.yes_label).into_owned();
+        let no_label = ctx.render(&self.no_label).into_owned();
 
         let spans = if !focused {
             match self.confirmed {
-                Some(true) => vec![
-                    Span::styled(self.yes_label.clone(), Style::new().color(Color::Green))
-                        .no_wrap(),
-                ],
-                Some(false) => vec![
-                    Span::styled(self.no_label.clone(), Style::new().color(Color::Red)).no_wrap(),
-                ],
+                Some(true) => {
+                    vec![Span::styled(yes_label, Style::new().color(Color::Green)).no_wrap()]
+                }
+                Some(false) => {
+                    vec![Span::styled(no_label, Style::new().color(Color::Red)).no_wrap()]
+                }
                 None => vec![
-                    Span::new(self.yes_label.clone()).no_wrap(),
-                    Span::styled(" / ", Style::new().color(Color::DarkGrey)).no_wrap(),
-                    Span::new(self.no_label.clone()).no_wrap(),
+                    Span::new(yes_label).no_wrap(),
+                    Span::styled(" / ", StyleRole::Hint.style()).no_wrap(),
+                    Span::new(no_label).no_wrap(),
                 ],
             }
         } else {
             match &self.mode {
                 ConfirmMode::Relaxed => {
-                    let active_style = Style::new().color(Color::DarkGrey).bold();
-                    let inactive_style = Style::new().color(Color::DarkGrey);
+                    let active_style = StyleRole::Hint.style().bold();
+                    let inactive_style = StyleRole::Hint.style();
                     vec![
-                        Span::styled(self.yes_label.clone(), active_style).no_wrap(),
+                        Span::styled(yes_label, active_style).no_wrap(),
                         Span::styled(" / ", inactive_style).no_wrap(),
-                        Span::styled(self.no_label.clone(), inactive_style).no_wrap(),
+                        Span::styled(no_label, inactive_style).no_wrap(),
                     ]
                 }
                 ConfirmMode::Strict { word } => {
-                    let prompt = format!("Type \"{}\" to confirm: ", word);
+                    let prompt = ctx
+                        .render(&Message::builtin("confirm-type-word").with_arg("word", word))
+                        .into_owned();
                     let mut s = vec![
-                        Span::styled(prompt, Style::new().color(Color::DarkGrey)).no_wrap(),
+                        Span::styled(prompt, StyleRole::Hint.style()).no_wrap(),
                         Span::new(self.buffer.clone()).no_wrap(),
                     ];
                     if self.strict_error {
@@ -220,7 +224,7 @@ impl Interactive for ConfirmInput {
         }
     }
 
-    fn validate(&self, _mode: ValidationMode) -> Result<(), String> {
+    fn validate(&self, _mode: ValidationMode) -> Result<(), Message> {
         Ok(())
     }
 
@@ -0,0 +1,349 @@
+use crate::core::i18n::Message;
+use crate::core::value::Value;
+use crate::terminal::{CursorPos, KeyCode, KeyEvent, KeyModifiers};
+use crate::ui::span::Span;
+use crate::ui::style::{Color, Style, StyleRole};
+use crate::ui::text::{char_prefix, text_display_width};
+use crate::widgets::base::WidgetBase;
+use crate::widgets::shared::calendar::{
+    Date, MonthGrid, Weekday, fmt, format_date, locale_date_pattern, parse_date, today,
+};
+use crate::widgets::shared::horizontal_viewport::render_single_line;
+use crate::widgets::shared::text_edit;
+use crate::widgets::traits::{
+    DrawOutput, Drawable, FocusMode, HintContext, HintItem, InteractionResult, Interactive,
+    RenderContext, StoreSyncPolicy, ValidationMode,
+};
+use crate::widgets::validators::{Validator, run_validators};
+
+/// A date typed into a text field or picked from a month calendar opened
+/// below it with ↓. The field shows the date in a display pattern such as
+/// `DD.MM.YYYY`; the value is always ISO-8601 (`2026-03-14`).
+pub struct DateInput {
+    base: WidgetBase,
+    text: String,
+    cursor: usize,
+    date: Option<Date>,
+    pattern: String,
+    min: Option<Date>,
+    max: Option<Date>,
+    calendar: Option<Date>,
+    validators: Vec<Validator>,
+}
+
+impl DateInput {
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            base: WidgetBase::new(id, label),
+            text: String::new(),
+            cursor: 0,
+            date: None,
+            pattern: fmt::DATE_YMD.to_string(),
+            min: None,
+            max: None,
+            calendar: None,
+            validators: Vec::new(),
+        }
+    }
+
+    /// Shows and reads the date in `pattern`, made of `YYYY`, `MM` and `DD`
+    /// fields and separators.
+    pub fn with_format(mut self, pattern: impl Into<String>) -> Self {
+        self.pattern = pattern.into();
+        if let Some(date) = self.date {
+            self.set_date(date);
+        }
+        self
+    }
+
+    /// Uses the usual date pattern of `locale`, e.g. `MM/DD/YYYY` for
+    /// `en-US` or `DD.MM.YYYY` for `de`.
+    pub fn with_locale(self, locale: &str) -> Self {
+        self.with_format(locale_date_pattern(locale))
+    }
+
+    pub fn with_min(mut self, min: Date) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    pub fn with_max(mut self, max: Date) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    pub fn with_validator(mut self, validator: Validator) -> Self {
+        self.validators.push(validator);
+        self
+    }
+
+    pub fn with_default(mut self, value: impl Into<Value>) -> Self {
+        self.set_value(value.into());
+        self
+    }
+
+    pub fn date(&self) -> Option<Date> {
+        self.date
+    }
+
+    pub fn is_calendar_open(&self) -> bool {
+        self.calendar.is_some()
+    }
+
+    fn set_date(&mut self, date: Date) {
+        self.date = Some(date);
+        self.text = format_date(date, self.pattern.as_str());
+        self.cursor = text_edit::char_count(&self.text);
+    }
+
+    /// Typed text is read in the display pattern, or as ISO.
+    fn reparse(&mut self) {
+        self.date = parse_date(self.text.as_str(), self.pattern.as_str())
+            .or_else(|| parse_date(self.text.as_str(), fmt::DATE_YMD));
+    }
+
+    fn clamp(&self, date: Date) -> Date {
+        let date = self.min.map_or(date, |min| date.max(min));
+        self.max.map_or(date, |max| date.min(max))
+    }
+
+    fn in_range(&self, date: Date) -> bool {
+        self.min.is_none_or(|min| date >= min) && self.max.is_none_or(|max| date <= max)
+    }
+
+    fn open_calendar(&mut self) {
+        self.calendar = Some(self.clamp(self.date.unwrap_or_else(today)));
+    }
+
+    fn move_calendar(&mut self, step: impl FnOnce(Date) -> Date) -> InteractionResult {
+        let Some(cursor) = self.calendar else {
+            return InteractionResult::ignored();
+        };
+        self.calendar = Some(self.clamp(step(cursor)));
+        InteractionResult::handled()
+    }
+
+    fn on_calendar_key(&mut self, key: KeyEvent) -> Option<InteractionResult> {
+        let shift = key.modifiers.contains(KeyModifiers::SHIFT);
+        let months = if shift { 12 } else { 1 };
+        let result = match key.code {
+            KeyCode::Left => self.move_calendar(|date| date.add_days(-1)),
+            KeyCode::Right => self.move_calendar(|date| date.add_days(1)),
+            KeyCode::Up => self.move_calendar(|date| date.add_days(-7)),
+            KeyCode::Down => self.move_calendar(|date| date.add_days(7)),
+            KeyCode::PageUp => self.move_calendar(|date| date.add_months(-months)),
+            KeyCode::PageDown => self.move_calendar(|date| date.add_months(months)),
+            KeyCode::Enter => {
+                if let Some(date) = self.calendar.take() {
+                    self.set_date(date);
+                }
+                InteractionResult::handled()
+            }
+            KeyCode::Esc => {
+                self.calendar = None;
+                InteractionResult::handled()
+            }
+            // Typing goes back to the text field.
+            _ => {
+                self.calendar = None;
+                return None;
+            }
+        };
+        Some(result)
+    }
+
+    fn calendar_lines(&self, cursor: Date) -> Vec<Vec<Span>> {
+        let grid = MonthGrid::new(cursor.year, cursor.month);
+        let dim = StyleRole::Hint.style();
+        let mut lines = vec![vec![
+            Span::styled(
+                format!(
+                    "  ‹ {:^22} ›",
+                    format!("{} {}", grid.month_name(), cursor.year)
+                ),
+                Style::new().color(Color::Cyan),
+            )
+            .no_wrap(),
+        ]];
+
+        let mut weekdays = vec![Span::new("  ").no_wrap()];
+        for weekday in 0..7 {
+            weekdays.push(
+                Span::styled(format!(" {:^2} ", Weekday(weekday).short_name()), dim).no_wrap(),
+            );
+        }
+        lines.push(weekdays);
+
+        for row in grid
+            .cells
+            .iter()
+            .filter(|row| row.iter().any(Option::is_some))
+        {
+            let mut line = vec![Span::new("  ").no_wrap()];
+            for cell in row {
+                let Some(day) = *cell else {
+                    line.push(Span::new("    ").no_wrap());
+                    continue;
+                };
+                let date = Date { day, ..cursor };
+                let style = if date == cursor {
+                    Style::new().color(Color::Yellow).bold()
+                } else if Some(date) == self.date {
+                    Style::new().color(Color::Cyan).bold()
+                } else if !self.in_range(date) {
+                    dim
+                } else {
+                    Style::default()
+                };
+                let (l, r) = if date == cursor {
+                    ("[", "]")
+                } else {
+                    (" ", " ")
+                };
+                line.push(Span::styled(format!("{l}{day:2}{r}"), style).no_wrap());
+            }
+            lines.push(line);
+        }
+        lines
+    }
+}
+
+impl Drawable for DateInput {
+    fn id(&self) -> &str {
+        self.base.id()
+    }
+
+    fn label(&self) -> &str {
+        self.base.label()
+    }
+
+    fn draw(&self, ctx: &RenderContext) -> DrawOutput {
+        let focused = self.base.is_focused(ctx);
+        let field = if self.text.is_empty() {
+            vec![Span::styled(self.pattern.clone(), StyleRole::Hint.style().italic()).no_wrap()]
+        } else {
+            vec![Span::new(self.text.clone()).no_wrap()]
+        };
+        let cursor = text_edit::clamp_cursor(self.cursor, &self.text);
+        let mut lines = vec![
+            render_single_line(
+                field.as_slice(),
+                ctx.terminal_size.width,
+                focused.then_some((cursor, cursor.saturating_add(1))),
+                None,
+            )
+            .spans,
+        ];
+        if focused && let Some(cursor) = self.calendar {
+            lines.extend(self.calendar_lines(cursor));
+        }
+        DrawOutput::with_lines(lines)
+    }
+
+    fn hints(&self, ctx: HintContext) -> Vec<HintItem> {
+        let specs = if self.calendar.is_some() {
+            crate::widgets::static_hints::DATE_INPUT_CALENDAR_HINTS
+        } else {
+            crate::widgets::static_hints::DATE_INPUT_HINTS
+        };
+        crate::widgets::traits::focused_static_hints(ctx, specs)
+    }
+}
+
+impl Interactive for DateInput {
+    fn focus_mode(&self) -> FocusMode {
+        FocusMode::Leaf
+    }
+
+    fn store_sync_policy(&self) -> StoreSyncPolicy {
+        StoreSyncPolicy::PreserveLocalStateWhileFocused
+    }
+
+    fn on_key(&mut self, key: KeyEvent) -> InteractionResult {
+        if self.calendar.is_some()
+            && let Some(result) = self.on_calendar_key(key)
+        {
+            return result;
+        }
+        if key.code == KeyCode::Down {
+            self.open_calendar();
+            return InteractionResult::handled();
+        }
+        match text_edit::apply_single_line_key(&mut self.text, &mut self.cursor, key) {
+            text_edit::TextKeyOutcome::Changed => {
+                self.reparse();
+                InteractionResult::handled()
+            }
+            text_edit::TextKeyOutcome::CursorMoved => InteractionResult::handled(),
+            text_edit::TextKeyOutcome::Submit => {
+                // Reformat what was typed as ISO or without leading zeros.
+                if let Some(date) = self.date {
+                    self.set_date(date);
+                }
+                InteractionResult::input_done()
+            }
+            _ => InteractionResult::ignored(),
+        }
+    }
+
+    fn on_paste(&mut self, text: &str) -> InteractionResult {
+        self.calendar = None;
+        let text = text_edit::single_line_paste(text);
+        text_edit::insert_str(&mut self.text, &mut self.cursor, text.as_str());
+        self.reparse();
+        InteractionResult::handled()
+    }
+
+    fn value(&self) -> Option<Value> {
+        self.date.map(|date| Value::Text(date.to_iso()))
+    }
+
+    fn set_value(&mut self, value: Value) {
+        let Some(text) = value.to_text_scalar() else {
+            self.text.clear();
+            self.cursor = 0;
+            self.date = None;
+            return;
+        };
+        let iso_date = text.get(..10).unwrap_or(text.as_str());
+        if let Some(date) = parse_date(iso_date, fmt::DATE_YMD) {
+            self.set_date(date);
+        }
+    }
+
+    fn validate(&self, _mode: ValidationMode) -> Result<(), Message> {
+        if self.date.is_none() && !self.text.trim().is_empty() {
+            return Err(format!("Enter a date as {}", self.pattern).into());
+        }
+        if let Some(date) = self.date {
+            if let Some(min) = self.min.filter(|min| date < *min) {
+                return Err(format!(
+                    "Date must be on or after {}",
+                    format_date(min, self.pattern.as_str())
+                )
+                .into());
+            }
+            if let Some(max) = self.max.filter(|max| date > *max) {
+                return Err(format!(
+                    "Date must be on or before {}",
+                    format_date(max, self.pattern.as_str())
+                )
+                .into());
+            }
+        }
+        let value = self.date.map(Date::to_iso).unwrap_or_default();
+        run_validators(&self.validators, &Value::Text(value))
+    }
+
+    fn cursor_pos(&self) -> Option<CursorPos> {
+        let cursor = text_edit::clamp_cursor(self.cursor, &self.text);
+        Some(CursorPos {
+            col: text_display_width(char_prefix(&self.text, cursor)) as u16,
+            row: 0,
+        })
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/date_input.rs"]
+mod tests;
@@ -0,0 +1,382 @@
+use crate::core::i18n::Message;
+use crate::core::value::Value;
+use crate::terminal::{CursorPos, KeyCode, KeyEvent, KeyModifiers};
+use crate::ui::span::Span;
+use crate::ui::style::{Style, StyleRole};
+use crate::widgets::base::WidgetBase;
+use crate::widgets::traits::{
+    DrawOutput, Drawable, FocusMode, HintContext, HintItem, InteractionResult, Interactive,
+    RenderContext, StoreSyncPolicy, ValidationMode,
+};
+use crate::widgets::validators::{Validator, run_validators};
+use unicode_width::UnicodeWidthStr;
+
+/// Longest duration the field holds, so hours never overflow and the value
+/// stays exact as an `f64`.
+const MAX_SECONDS: u64 = 9_999_999 * 3600 + 59 * 60 + 59;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Segment {
+    Hours,
+    Minutes,
+    Seconds,
+}
+
+impl Segment {
+    fn seconds(self) -> u64 {
+        match self {
+            Self::Hours => 3600,
+            Self::Minutes => 60,
+            Self::Seconds => 1,
+        }
+    }
+
+    fn part_of(self, total: u64) -> u64 {
+        match self {
+            Self::Hours => total / 3600,
+            Self::Minutes => total / 60 % 60,
+            Self::Seconds => total % 60,
+        }
+    }
+
+    fn next(self) -> Self {
+        match self {
+            Self::Hours => Self::Minutes,
+            Self::Minutes | Self::Seconds => Self::Seconds,
+        }
+    }
+
+    fn prev(self) -> Self {
+        match self {
+            Self::Hours | Self::Minutes => Self::Hours,
+            Self::Seconds => Self::Minutes,
+        }
+    }
+}
+
+/// A length of time edited as hour, minute and second fields. ↑↓ step
+/// the active field with carry; typed text such as `1h30m`, `90s` or
+/// `2 days` replaces the whole duration. The value is a number of seconds.
+pub struct DurationInput {
+    base: WidgetBase,
+    total: u64,
+    segment: Segment,
+    entry: String,
+    entry_base: u64,
+    validators: Vec<Validator>,
+}
+
+impl DurationInput {
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            base: WidgetBase::new(id, label),
+            total: 0,
+            segment: Segment::Hours,
+            entry: String::new(),
+            entry_base: 0,
+            validators: Vec::new(),
+        }
+    }
+
+    pub fn with_seconds(mut self, seconds: u64) -> Self {
+        self.total = seconds.min(MAX_SECONDS);
+        self
+    }
+
+    pub fn with_validator(mut self, validator: Validator) -> Self {
+        self.validators.push(validator);
+        self
+    }
+
+    pub fn with_default(mut self, value: impl Into<Value>) -> Self {
+        self.set_value(value.into());
+        self
+    }
+
+    pub fn seconds(&self) -> u64 {
+        self.total
+    }
+
+    fn part(&self, segment: Segment) -> u64 {
+        segment.part_of(self.total)
+    }
+
+    fn commit_entry(&mut self) {
+        self.entry.clear();
+    }
+
+    fn step(&mut self, delta: i64) {
+        self.commit_entry();
+        let change = delta.unsigned_abs().saturating_mul(self.segment.seconds());
+        self.total = if delta < 0 {
+            self.total.saturating_sub(change)
+        } else {
+            self.total.saturating_add(change).min(MAX_SECONDS)
+        };
+    }
+
+    /// Digits alone fill the active field; with a unit anywhere the entry
+    /// is read as a whole duration.
+    fn apply_entry(&mut self) {
+        if self.entry.is_empty() {
+            self.total = self.entry_base;
+            return;
+        }
+        if self.entry.chars().all(|ch| ch.is_ascii_digit()) {
+            let number = self.entry.parse::<u64>().unwrap_or(u64::MAX);
+            let unit = self.segment.seconds();
+            let current = self.segment.part_of(self.entry_base);
+            self.total = (self.entry_base - current * unit)
+                .saturating_add(number.saturating_mul(unit))
+                .min(MAX_SECONDS);
+        } else if let Some(seconds) = parse_duration(self.entry.as_str()) {
+            self.total = seconds.min(MAX_SECONDS);
+        }
+    }
+
+    fn push_entry(&mut self, ch: char) {
+        if self.entry.is_empty() {
+            self.entry_base = self.total;
+        }
+        self.entry.push(ch);
+        self.apply_entry();
+    }
+
+    fn entry_is_invalid(&self) -> bool {
+        !self.entry.is_empty()
+            && !self.entry.chars().all(|ch| ch.is_ascii_digit())
+            && parse_duration(self.entry.as_str()).is_none()
+    }
+
+    fn render_parts(&self, focused: bool) -> (Vec<Span>, usize) {
+        let parts = [
+            (
+                format!("{:02}", self.part(Segment::Hours)),
+                Some(Segment::Hours),
+            ),
+            ("h ".to_string(), None),
+            (
+                format!("{:02}", self.part(Segment::Minutes)),
+                Some(Segment::Minutes),
+            ),
+            ("m ".to_string(), None),
+            (
+                format!("{:02}", self.part(Segment::Seconds)),
+                Some(Segment::Seconds),
+            ),
+            ("s".to_string(), None),
+        ];
+
+        let mut spans = Vec::new();
+        let mut offset = 0usize;
+        let mut cursor_offset = 0usize;
+        for (text, segment) in parts {
+            let style = if focused && segment == Some(self.segment) {
+                cursor_offset = offset;
+                StyleRole::Active.style()
+            } else if segment.is_none() {
+                StyleRole::Hint.style()
+            } else {
+                Style::default()
+            };
+            offset += UnicodeWidthStr::width(text.as_str());
+            spans.push(Span::styled(text, style).no_wrap());
+        }
+        if focused && !self.entry.is_empty() {
+            let style = if self.entry_is_invalid() {
+                StyleRole::Error.style()
+            } else {
+                StyleRole::Hint.style().italic()
+            };
+            spans.push(Span::styled(format!("  {}", self.entry), style).no_wrap());
+        }
+        (spans, cursor_offset)
+    }
+}
+
+impl Drawable for DurationInput {
+    fn id(&self) -> &str {
+        self.base.id()
+    }
+
+    fn label(&self) -> &str {
+        self.base.label()
+    }
+
+    fn draw(&self, ctx: &RenderContext) -> DrawOutput {
+        let (spans, _) = self.render_parts(self.base.is_focused(ctx));
+        DrawOutput::with_lines(vec![spans])
+    }
+
+    fn hints(&self, ctx: HintContext) -> Vec<HintItem> {
+        crate::widgets::traits::focused_static_hints(
+            ctx,
+            crate::widgets::static_hints::DURATION_INPUT_HINTS,
+        )
+    }
+}
+
+impl Interactive for DurationInput {
+    fn focus_mode(&self) -> FocusMode {
+        FocusMode::Leaf
+    }
+
+    fn store_sync_policy(&self) -> StoreSyncPolicy {
+        StoreSyncPolicy::PreserveLocalStateWhileFocused
+    }
+
+    fn on_key(&mut self, key: KeyEvent) -> InteractionResult {
+        let step = if key.modifiers.contains(KeyModifiers::SHIFT) {
+            10
+        } else {
+            1
+        };
+        match key.code {
+            KeyCode::Left => {
+                self.commit_entry();
+                self.segment = self.segment.prev();
+                InteractionResult::handled()
+            }
+            KeyCode::Right => {
+                self.commit_entry();
+                self.segment = self.segment.next();
+                InteractionResult::handled()
+            }
+            KeyCode::Up => {
+                self.step(step);
+                InteractionResult::handled()
+            }
+            KeyCode::Down => {
+                self.step(-step);
+                InteractionResult::handled()
+            }
+            KeyCode::Backspace if !self.entry.is_empty() => {
+                self.entry.pop();
+                self.apply_entry();
+                InteractionResult::handled()
+            }
+            KeyCode::Backspace => {
+                let part = self.part(self.segment);
+                self.total -= part * self.segment.seconds();
+                InteractionResult::handled()
+            }
+            KeyCode::Esc if !self.entry.is_empty() => {
+                self.total = self.entry_base;
+                self.commit_entry();
+                InteractionResult::handled()
+            }
+            KeyCode::Char(ch)
+                if !key.modifiers.contains(KeyModifiers::CONTROL)
+                    && (ch.is_ascii_alphanumeric() || matches!(ch, ' ' | '.' | ':')) =>
+            {
+                self.push_entry(ch);
+                InteractionResult::handled()
+            }
+            // An unreadable entry is kept so validation can point at it.
+            KeyCode::Enter => {
+                if !self.entry_is_invalid() {
+                    self.commit_entry();
+                }
+                InteractionResult::input_done()
+            }
+            _ => InteractionResult::ignored(),
+        }
+    }
+
+    fn on_paste(&mut self, text: &str) -> InteractionResult {
+        for ch in text.trim().chars() {
+            self.push_entry(ch);
+        }
+        InteractionResult::handled()
+    }
+
+    fn value(&self) -> Option<Value> {
+        Some(Value::Number(self.total as f64))
+    }
+
+    fn set_value(&mut self, value: Value) {
+        let seconds = match &value {
+            Value::Number(number) if number.is_finite() => Some(number.max(0.0).round() as u64),
+            Value::Text(text) => parse_duration(text),
+            _ => None,
+        };
+        if let Some(seconds) = seconds {
+            self.total = seconds.min(MAX_SECONDS);
+            self.entry.clear();
+        }
+    }
+
+    fn validate(&self, _mode: ValidationMode) -> Result<(), Message> {
+        if self.entry_is_invalid() {
+            return Err("Enter a duration like 1h30m, 90s or 2 days".into());
+        }
+        run_validators(&self.validators, &Value::Number(self.total as f64))
+    }
+
+    fn cursor_pos(&self) -> Option<CursorPos> {
+        let (_, local) = self.render_parts(true);
+        Some(CursorPos {
+            col: local as u16,
+            row: 0,
+        })
+    }
+}
+
+/// Reads a human duration into whole seconds: unit amounts such as `1h30m`,
+/// `1.5 hours` or `2 days`, a clock form `1:30` or `1:30:15`, or a bare
+/// number of seconds.
+pub fn parse_duration(text: &str) -> Option<u64> {
+    let text = text.trim().to_ascii_lowercase();
+    if text.is_empty() {
+        return None;
+    }
+    if text.contains(':') {
+        let parts = text
+            .split(':')
+            .map(|part| part.trim().parse::<u64>().ok())
+            .collect::<Option<Vec<_>>>()?;
+        let seconds = match parts.as_slice() {
+            [h, m] if *m < 60 => h.checked_mul(3600)?.checked_add(m * 60)?,
+            [h, m, s] if *m < 60 && *s < 60 => h.checked_mul(3600)?.checked_add(m * 60 + s)?,
+            _ => return None,
+        };
+        return Some(seconds);
+    }
+    if let Ok(seconds) = text.parse::<f64>() {
+        return (seconds >= 0.0).then(|| seconds.round() as u64);
+    }
+
+    let mut total = 0.0;
+    let mut rest = text.as_str();
+    while !rest.is_empty() {
+        rest = rest
+            .trim_start_matches([' ', ','])
+            .trim_start_matches("and ");
+        let number_len = rest
+            .find(|ch: char| !(ch.is_ascii_digit() || ch == '.'))
+            .unwrap_or(rest.len());
+        let amount = rest[..number_len].parse::<f64>().ok()?;
+        rest = rest[number_len..].trim_start();
+        let unit_len = rest
+            .find(|ch: char| !ch.is_ascii_alphabetic())
+            .unwrap_or(rest.len());
+        total += amount * unit_seconds(&rest[..unit_len])?;
+        rest = rest[unit_len..].trim_start_matches([' ', ',']);
+    }
+    Some(total.round() as u64)
+}
+
+fn unit_seconds(unit: &str) -> Option<f64> {
+    match unit {
+        "s" | "sec" | "secs" | "second" | "seconds" => Some(1.0),
+        "m" | "min" | "mins" | "minute" | "minutes" => Some(60.0),
+        "h" | "hr" | "hrs" | "hour" | "hours" => Some(3600.0),
+        "d" | "day" | "days" => Some(86_400.0),
+        "w" | "week" | "weeks" => Some(604_800.0),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/duration.rs"]
+mod tests;
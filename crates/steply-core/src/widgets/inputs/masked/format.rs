@@ -1,6 +1,6 @@
 use super::model::{MaskToken, SegmentKind, SegmentRole};
 use crate::ui::span::Span;
-use crate::ui::style::{Color, Style};
+use crate::ui::style::StyleRole;
 use crate::widgets::shared::calendar;
 use crate::widgets::shared::text_edit;
 
@@ -45,8 +45,8 @@ pub(super) fn next_literal_char(tokens: &[MaskToken], current_token: usize) -> O
 
 pub(super) fn render_spans(tokens: &[MaskToken], active_segment: Option<usize>) -> Vec<Span> {
     let mut spans = Vec::<Span>::new();
-    let active_style = Style::new().color(Color::Cyan).bold();
-    let placeholder_style = Style::new().color(Color::DarkGrey);
+    let active_style = StyleRole::Active.style();
+    let placeholder_style = StyleRole::Hint.style();
     for (token_idx, token) in tokens.iter().enumerate() {
         let is_active = active_segment.is_some_and(|idx| idx == token_idx);
         match token {
@@ -2,6 +2,7 @@ mod format;
 mod model;
 mod parser;
 
+use crate::core::i18n::Message;
 use crate::core::value::Value;
 use crate::runtime::event::SystemEvent;
 use crate::terminal::{CursorPos, KeyCode, KeyEvent, KeyModifiers};
@@ -446,6 +447,14 @@ impl Interactive for MaskedInput {
         }
     }
 
+    fn on_paste(&mut self, text: &str) -> InteractionResult {
+        let mut changed = false;
+        for ch in text_edit::single_line_paste(text).chars() {
+            changed |= self.insert_char(ch);
+        }
+        InteractionResult::handled_if(changed)
+    }
+
     fn on_system_event(&mut self, event: &SystemEvent) -> InteractionResult {
         if let SystemEvent::RequestFocus { target } = event
             && target
@@ -471,7 +480,7 @@ impl Interactive for MaskedInput {
         self.clamp_cursor();
     }
 
-    fn validate(&self, _mode: ValidationMode) -> Result<(), String> {
+    fn validate(&self, _mode: ValidationMode) -> Result<(), Message> {
         let value = self.validated_value()?;
         run_validators(&self.validators, &Value::Text(value))
     }
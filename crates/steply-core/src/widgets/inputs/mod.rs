@@ -4,6 +4,8 @@ pub mod checkbox;
 pub mod choice;
 pub mod color;
 pub mod confirm;
+pub mod date_input;
+pub mod duration;
 pub mod masked;
 pub mod select;
 pub mod slider;
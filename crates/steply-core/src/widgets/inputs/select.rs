@@ -1,3 +1,4 @@
+use crate::core::i18n::Message;
 use crate::core::value::Value;
 use crate::terminal::{KeyCode, KeyEvent};
 use crate::ui::inline::{Inline, InlineGroup};
@@ -161,7 +162,7 @@ impl Interactive for SelectInput {
         true
     }
 
-    fn validate(&self, _mode: ValidationMode) -> Result<(), String> {
+    fn validate(&self, _mode: ValidationMode) -> Result<(), Message> {
         run_validators(
             &self.validators,
             &Value::Text(self.selected_text().to_string()),
@@ -1,13 +1,20 @@
+use crate::core::i18n::Message;
 use crate::core::value::Value;
-use crate::terminal::{KeyCode, KeyEvent};
+use crate::terminal::{
+    KeyCode, KeyEvent, PointerButton, PointerEvent, PointerKind, PointerSemantic,
+};
 use crate::ui::span::Span;
-use crate::ui::style::{Color, Style};
+use crate::ui::style::{Color, Style, StyleRole};
 use crate::widgets::base::WidgetBase;
 use crate::widgets::traits::{
-    DrawOutput, Drawable, FocusMode, InteractionResult, Interactive, RenderContext, ValidationMode,
+    DrawOutput, Drawable, FocusMode, InteractionResult, Interactive, PointerRowMap, RenderContext,
+    ValidationMode,
 };
 use crate::widgets::validators::{Validator, run_validators};
 
+/// Marks pointer events on the drawn track; the column is the track cell.
+const TRACK: PointerSemantic = PointerSemantic::Custom(0);
+
 pub struct SliderInput {
     base: WidgetBase,
     min: i64,
@@ -86,6 +93,18 @@ impl SliderInput {
         let raw = (ratio * (self.track_len as f64 - 1.0)).round();
         raw.clamp(0.0, (self.track_len - 1) as f64) as usize
     }
+
+    /// The value whose knob sits on track cell `position`, snapped to the
+    /// step.
+    fn value_at_track(&self, position: usize) -> i64 {
+        let last = self.track_len - 1;
+        let ratio = position.min(last) as f64 / last as f64;
+        let offset = ratio * (self.max - self.min) as f64;
+        let steps = (offset / self.step as f64).round() as i64;
+        self.min
+            .saturating_add(steps.saturating_mul(self.step))
+            .clamp(self.min, self.max)
+    }
 }
 
 impl Drawable for SliderInput {
@@ -115,20 +134,33 @@ impl Drawable for SliderInput {
             s.push(Span::styled(self.value.to_string(), Style::default()).no_wrap());
             if let Some(unit) = &self.unit {
                 s.push(Span::new(" ").no_wrap());
-                s.push(Span::styled(unit.clone(), Style::new().color(Color::DarkGrey)).no_wrap());
+                s.push(Span::styled(unit.clone(), StyleRole::Hint.style()).no_wrap());
             }
             s
         } else {
             let mut s = vec![Span::new(self.value.to_string()).no_wrap()];
             if let Some(unit) = &self.unit {
                 s.push(Span::new(" ").no_wrap());
-                s.push(Span::styled(unit.clone(), Style::new().color(Color::DarkGrey)).no_wrap());
+                s.push(Span::styled(unit.clone(), StyleRole::Hint.style()).no_wrap());
             }
             s
         };
 
         DrawOutput::with_lines(vec![spans])
     }
+
+    /// Only the focused slider draws a track to click on; it starts after
+    /// the `‹`.
+    fn pointer_rows(&self, ctx: &RenderContext) -> Vec<PointerRowMap> {
+        if !self.base.is_focused(ctx) {
+            return Vec::new();
+        }
+        vec![
+            PointerRowMap::new(0, 0)
+                .with_local_col_offset(1)
+                .with_semantic(TRACK),
+        ]
+    }
 }
 
 impl Interactive for SliderInput {
@@ -163,6 +195,27 @@ impl Interactive for SliderInput {
         }
     }
 
+    /// A click on the track moves the knob there and dragging follows the
+    /// pointer; the arrows and value beside the track ignore clicks.
+    fn on_pointer(&mut self, event: PointerEvent) -> InteractionResult {
+        if event.semantic != TRACK {
+            return InteractionResult::ignored();
+        }
+        let position = usize::from(event.col);
+        match event.kind {
+            PointerKind::Down(PointerButton::Left) if position < self.track_len => {
+                self.value = self.value_at_track(position);
+                InteractionResult::handled()
+            }
+            PointerKind::Drag(PointerButton::Left) => {
+                let previous = self.value;
+                self.value = self.value_at_track(position);
+                self.value_changed_result(previous)
+            }
+            _ => InteractionResult::ignored(),
+        }
+    }
+
     fn value(&self) -> Option<Value> {
         Some(Value::Number(self.value as f64))
     }
@@ -174,7 +227,7 @@ impl Interactive for SliderInput {
         }
     }
 
-    fn validate(&self, _mode: ValidationMode) -> Result<(), String> {
+    fn validate(&self, _mode: ValidationMode) -> Result<(), Message> {
         run_validators(&self.validators, &Value::Number(self.value as f64))
     }
 
@@ -0,0 +1,104 @@
+use super::DateInput;
+use crate::core::value::Value;
+use crate::terminal::{KeyCode, KeyEvent, KeyModifiers, TerminalSize};
+use crate::widgets::shared::calendar::{Date, format_date, locale_date_pattern, parse_date};
+use crate::widgets::traits::{Drawable, Interactive, RenderContext, ValidationMode};
+
+fn key(code: KeyCode) -> KeyEvent {
+    KeyEvent {
+        code,
+        modifiers: KeyModifiers::NONE,
+    }
+}
+
+fn date(year: i32, month: u8, day: u8) -> Date {
+    Date { year, month, day }
+}
+
+fn type_text(input: &mut DateInput, text: &str) {
+    for ch in text.chars() {
+        input.on_key(key(KeyCode::Char(ch)));
+    }
+}
+
+#[test]
+fn typed_date_in_locale_pattern_becomes_iso_value() {
+    let mut input = DateInput::new("start", "Start").with_locale("de-DE");
+    type_text(&mut input, "5.3.2026");
+
+    assert_eq!(input.value(), Some(Value::Text("2026-03-05".to_string())));
+    input.on_key(key(KeyCode::Enter));
+    input.set_value(Value::Text("2026-12-24".to_string()));
+    assert_eq!(input.date(), Some(date(2026, 12, 24)));
+}
+
+#[test]
+fn calendar_moves_by_day_week_and_month_within_bounds() {
+    let mut input = DateInput::new("start", "Start")
+        .with_default(Value::Text("2026-01-30".to_string()))
+        .with_max(date(2026, 2, 20));
+
+    input.on_key(key(KeyCode::Down));
+    assert!(input.is_calendar_open());
+    input.on_key(key(KeyCode::Right));
+    input.on_key(key(KeyCode::Down));
+    input.on_key(key(KeyCode::PageDown));
+    input.on_key(key(KeyCode::Enter));
+
+    assert!(!input.is_calendar_open());
+    assert_eq!(input.value(), Some(Value::Text("2026-02-20".to_string())));
+}
+
+#[test]
+fn invalid_text_and_out_of_range_dates_fail_validation() {
+    let mut input = DateInput::new("start", "Start")
+        .with_format("MM/DD/YYYY")
+        .with_min(date(2026, 1, 1));
+    type_text(&mut input, "13/01/2026");
+    assert_eq!(
+        input.validate(ValidationMode::Submit),
+        Err("Enter a date as MM/DD/YYYY".into())
+    );
+
+    input.set_value(Value::Text("2025-12-31".to_string()));
+    assert_eq!(
+        input.validate(ValidationMode::Submit),
+        Err("Date must be on or after 01/01/2026".into())
+    );
+}
+
+#[test]
+fn open_calendar_draws_month_grid_under_the_field() {
+    let mut input =
+        DateInput::new("start", "Start").with_default(Value::Text("2026-03-14".to_string()));
+    input.on_key(key(KeyCode::Down));
+    let ctx = RenderContext::empty(TerminalSize {
+        width: 40,
+        height: 20,
+    })
+    .with_focus(Some("start".to_string()));
+
+    let lines = input
+        .draw(&ctx)
+        .lines
+        .iter()
+        .map(|line| {
+            line.iter()
+                .map(|span| span.text.as_str())
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(lines[0].trim_end(), "2026-03-14");
+    assert!(lines[1].contains("March 2026"));
+    assert!(lines.iter().any(|line| line.contains("[14]")));
+}
+
+#[test]
+fn date_patterns_follow_the_locale() {
+    assert_eq!(locale_date_pattern("en_US.UTF-8"), "MM/DD/YYYY");
+    assert_eq!(locale_date_pattern("en-GB"), "DD/MM/YYYY");
+    assert_eq!(locale_date_pattern("sv"), "YYYY-MM-DD");
+    assert_eq!(format_date(date(2026, 3, 5), "DD.MM.YYYY"), "05.03.2026");
+    assert_eq!(parse_date("20260305", "YYYYMMDD"), Some(date(2026, 3, 5)));
+    assert_eq!(parse_date("2026-02-30", "YYYY-MM-DD"), None);
+}
@@ -0,0 +1,116 @@
+use super::{DurationInput, parse_duration};
+use crate::config::load_from_yaml_str;
+use crate::core::value::Value;
+use crate::terminal::{KeyCode, KeyEvent, KeyModifiers};
+use crate::widgets::traits::{Interactive, ValidationMode};
+
+fn key(code: KeyCode) -> KeyEvent {
+    KeyEvent {
+        code,
+        modifiers: KeyModifiers::NONE,
+    }
+}
+
+fn type_text(input: &mut DurationInput, text: &str) {
+    for ch in text.chars() {
+        input.on_key(key(KeyCode::Char(ch)));
+    }
+}
+
+#[test]
+fn human_formats_parse_to_seconds() {
+    assert_eq!(parse_duration("1h30m"), Some(5400));
+    assert_eq!(parse_duration("90s"), Some(90));
+    assert_eq!(parse_duration("2 days"), Some(172_800));
+    assert_eq!(
+        parse_duration("1 hour, 15 minutes and 5 seconds"),
+        Some(4505)
+    );
+    assert_eq!(parse_duration("1.5h"), Some(5400));
+    assert_eq!(parse_duration("1:30"), Some(5400));
+    assert_eq!(parse_duration("45"), Some(45));
+    assert_eq!(parse_duration("5 parsecs"), None);
+    assert_eq!(parse_duration("1:75"), None);
+}
+
+#[test]
+fn arrows_step_the_active_field_with_carry() {
+    let mut input = DurationInput::new("timeout", "Timeout").with_seconds(59 * 60);
+
+    input.on_key(key(KeyCode::Right));
+    input.on_key(key(KeyCode::Up));
+    assert_eq!(input.seconds(), 3600);
+
+    input.on_key(key(KeyCode::Right));
+    input.on_key(key(KeyCode::Down));
+    assert_eq!(input.seconds(), 3599);
+    assert_eq!(input.value(), Some(Value::Number(3599.0)));
+}
+
+#[test]
+fn typed_digits_fill_the_field_and_units_replace_the_duration() {
+    let mut input = DurationInput::new("timeout", "Timeout").with_seconds(3600 + 5);
+    input.on_key(key(KeyCode::Right));
+    type_text(&mut input, "45");
+    assert_eq!(input.seconds(), 3600 + 45 * 60 + 5);
+
+    input.on_key(key(KeyCode::Right));
+    type_text(&mut input, "2 days");
+    assert_eq!(input.seconds(), 172_800);
+
+    input.on_key(key(KeyCode::Right));
+    type_text(&mut input, "soon");
+    assert!(input.validate(ValidationMode::Submit).is_err());
+    input.on_key(key(KeyCode::Esc));
+    assert_eq!(input.seconds(), 172_800);
+    assert!(input.validate(ValidationMode::Submit).is_ok());
+}
+
+#[test]
+fn long_digit_entries_are_clamped_instead_of_overflowing() {
+    let mut input = DurationInput::new("timeout", "Timeout").with_seconds(30);
+    type_text(&mut input, "99999999999999999");
+    let max = input.seconds();
+    assert!(max >= 9_999_999 * 3600);
+
+    type_text(&mut input, "9999");
+    assert_eq!(input.seconds(), max);
+    input.on_key(key(KeyCode::Up));
+    assert_eq!(input.seconds(), max);
+
+    assert_eq!(parse_duration("99999999999999999:00"), None);
+    input.on_key(key(KeyCode::Esc));
+    type_text(&mut input, "99999999999999999 days");
+    assert_eq!(input.seconds(), max);
+}
+
+#[test]
+fn config_default_accepts_text_or_seconds() {
+    let yaml = r#"
+version: 1
+steps:
+  - id: demo
+    title: Demo
+    widgets:
+      - type: duration_input
+        id: timeout
+        label: Timeout
+        default: 1h30m
+        value: timeout
+      - type: duration_input
+        id: retry
+        label: Retry after
+        default: 90
+"#;
+
+    let loaded = load_from_yaml_str(yaml).expect("load config");
+    let mut state = loaded.into_app_state().expect("app state");
+    assert_eq!(state.focused_id(), Some("timeout"));
+
+    state.dispatch_key_to_focused(key(KeyCode::Up));
+    assert_eq!(state.store_value("timeout"), Some(&Value::Number(9000.0)));
+    assert!(
+        !load_from_yaml_str(yaml.replace("default: 90", "default: -5").as_str())
+            .is_ok_and(|loaded| loaded.into_app_state().is_ok())
+    );
+}
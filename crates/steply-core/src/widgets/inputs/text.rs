@@ -1,7 +1,10 @@
+use crate::core::i18n::Message;
+use crate::core::secrets::SecretKey;
 use crate::core::value::Value;
 use crate::terminal::{CursorPos, KeyEvent};
 use crate::ui::span::Span;
-use crate::ui::style::{Color, Style};
+use crate::ui::style::{Style, StyleRole};
+use crate::ui::text::{char_prefix, text_display_width};
 use crate::widgets::base::WidgetBase;
 use crate::widgets::shared::horizontal_viewport::render_single_line;
 use crate::widgets::shared::text_edit;
@@ -10,7 +13,6 @@ use crate::widgets::traits::{
     RenderContext, StoreSyncPolicy, TextAction, TextEditState, ValidationMode,
 };
 use crate::widgets::validators::{Validator, run_validators};
-use unicode_width::UnicodeWidthChar;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum TextMode {
@@ -30,6 +32,7 @@ pub struct TextInput {
     placeholder: Option<String>,
     validators: Vec<Validator>,
     completion_items: Vec<String>,
+    secret: Option<SecretKey>,
 }
 
 impl TextInput {
@@ -42,6 +45,7 @@ impl TextInput {
             placeholder: None,
             validators: Vec::new(),
             completion_items: Vec::new(),
+            secret: None,
         }
     }
 
@@ -65,6 +69,13 @@ impl TextInput {
         self
     }
 
+    /// Keeps the value in a secret store: the stored secret becomes the
+    /// default and the submitted one is written back, never exported.
+    pub fn with_secret(mut self, key: SecretKey) -> Self {
+        self.secret = Some(key);
+        self
+    }
+
     pub fn with_completion_items(mut self, items: Vec<String>) -> Self {
         self.completion_items = items;
         self
@@ -122,7 +133,7 @@ impl Drawable for TextInput {
 
         let mut first_line = if self.value.is_empty() && ghost_suffix.is_none() {
             if let Some(ph) = &self.placeholder {
-                vec![Span::styled(ph.clone(), Style::new().color(Color::DarkGrey)).no_wrap()]
+                vec![Span::styled(ph.clone(), StyleRole::Hint.style().italic()).no_wrap()]
             } else {
                 vec![Span::new(self.display_value()).no_wrap()]
             }
@@ -131,7 +142,7 @@ impl Drawable for TextInput {
         };
 
         if let Some(suffix) = ghost_suffix {
-            first_line.push(Span::styled(suffix, Style::new().color(Color::DarkGrey)).no_wrap());
+            first_line.push(Span::styled(suffix, StyleRole::Hint.style()).no_wrap());
         }
 
         DrawOutput::with_lines(vec![
@@ -158,6 +169,14 @@ impl Interactive for TextInput {
         StoreSyncPolicy::PreserveLocalStateWhileFocused
     }
 
+    fn secret(&self) -> Option<&SecretKey> {
+        self.secret.as_ref()
+    }
+
+    fn value_masked(&self) -> bool {
+        self.mode != TextMode::Plain
+    }
+
     fn on_key(&mut self, key: KeyEvent) -> InteractionResult {
         match text_edit::apply_single_line_key(&mut self.value, &mut self.cursor, key) {
             text_edit::TextKeyOutcome::Ignored => InteractionResult::ignored(),
@@ -168,6 +187,16 @@ impl Interactive for TextInput {
         }
     }
 
+    // Unlike `text_editing`, this also covers password and secret fields.
+    fn on_paste(&mut self, text: &str) -> InteractionResult {
+        let text = text_edit::single_line_paste(text);
+        if text.is_empty() {
+            return InteractionResult::handled();
+        }
+        text_edit::insert_str(&mut self.value, &mut self.cursor, text.as_str());
+        self.edited_result()
+    }
+
     fn text_editing(&mut self) -> Option<TextEditState<'_>> {
         if self.mode != TextMode::Plain {
             return None;
@@ -212,7 +241,7 @@ impl Interactive for TextInput {
         }
     }
 
-    fn validate(&self, _mode: ValidationMode) -> Result<(), String> {
+    fn validate(&self, _mode: ValidationMode) -> Result<(), Message> {
         run_validators(&self.validators, &Value::Text(self.value.clone()))
     }
 
@@ -221,13 +250,8 @@ impl Interactive for TextInput {
             TextMode::Secret => 0,
             TextMode::Password => text_edit::clamp_cursor(self.cursor, &self.value) as u16,
             TextMode::Plain => {
-                let value_width: usize = self
-                    .value
-                    .chars()
-                    .take(text_edit::clamp_cursor(self.cursor, &self.value))
-                    .map(|ch| UnicodeWidthChar::width(ch).unwrap_or(0))
-                    .sum();
-                value_width as u16
+                let cursor = text_edit::clamp_cursor(self.cursor, &self.value);
+                text_display_width(char_prefix(&self.value, cursor)) as u16
             }
         };
         Some(CursorPos { col, row: 0 })
@@ -7,4 +7,5 @@ pub mod outputs;
 pub mod shared;
 pub mod static_hints;
 pub mod traits;
+pub mod transforms;
 pub mod validators;
@@ -1,3 +1,5 @@
+use crate::core::i18n::Message;
+use crate::core::secrets::SecretKey;
 use crate::core::value::Value;
 use crate::runtime::event::SystemEvent;
 use crate::runtime::event::ValueChange;
@@ -7,8 +9,8 @@ use crate::task::TaskSpec;
 use crate::terminal::{CursorPos, KeyEvent, PointerEvent};
 use crate::widgets::traits::{
     CompletionState, DrawOutput, FocusMode, HintContext, HintItem, InteractionResult,
-    InteractiveNode, OutputNode, OverlayMode, OverlayPlacement, PointerRowMap, RenderContext,
-    TextAction, ValidationMode,
+    InteractiveNode, OutputNode, OverlayAnchor, OverlayMode, OverlayPlacement, PointerRowMap,
+    RenderContext, TextAction, ValidationIssue, ValidationMode,
 };
 
 pub trait Component: InteractiveNode {
@@ -149,6 +151,14 @@ impl Node {
         }
     }
 
+    pub fn label(&self) -> &str {
+        match self {
+            Self::Input(w) => w.label(),
+            Self::Component(w) => w.label(),
+            Self::Output(w) => w.label(),
+        }
+    }
+
     pub fn draw(&self, ctx: &RenderContext) -> DrawOutput {
         match self {
             Self::Input(w) => w.draw(ctx),
@@ -195,6 +205,20 @@ impl Node {
             .unwrap_or(StoreCommitPolicy::Immediate)
     }
 
+    pub fn secret(&self) -> Option<&SecretKey> {
+        self.interactive_ref().and_then(|widget| widget.secret())
+    }
+
+    pub fn value_masked(&self) -> bool {
+        self.interactive_ref()
+            .is_some_and(|widget| widget.value_masked())
+    }
+
+    pub fn expanded_view(&self, id: &str, rows: usize) -> Option<Node> {
+        self.interactive_ref()
+            .and_then(|widget| widget.expanded_view(id, rows))
+    }
+
     pub fn is_focusable(&self) -> bool {
         matches!(self.focus_mode(), FocusMode::Leaf | FocusMode::Group)
     }
@@ -221,6 +245,12 @@ impl Node {
             .unwrap_or_else(InteractionResult::ignored)
     }
 
+    pub fn on_paste(&mut self, text: &str) -> InteractionResult {
+        self.interactive_mut()
+            .map(|widget| widget.on_paste(text))
+            .unwrap_or_else(InteractionResult::ignored)
+    }
+
     pub fn on_text_edited(&mut self) {
         if let Some(widget) = self.interactive_mut() {
             widget.on_text_edited();
@@ -237,6 +267,11 @@ impl Node {
         }
     }
 
+    pub fn edits_text(&mut self) -> bool {
+        self.interactive_mut()
+            .is_some_and(|widget| widget.text_editing().is_some())
+    }
+
     pub fn completion(&mut self) -> Option<CompletionState<'_>> {
         self.interactive_mut()
             .and_then(|widget| widget.completion())
@@ -252,6 +287,16 @@ impl Node {
         }
     }
 
+    pub fn wants_tick(&self) -> bool {
+        if let Some(widget) = self.interactive_ref() {
+            widget.wants_tick()
+        } else if let Some(widget) = self.output_ref() {
+            widget.wants_tick()
+        } else {
+            false
+        }
+    }
+
     pub fn cursor_pos(&self) -> Option<CursorPos> {
         self.interactive_ref()
             .and_then(|widget| widget.cursor_pos())
@@ -278,6 +323,15 @@ impl Node {
         }
     }
 
+    /// The value as the store receives it, after the binding's transforms.
+    pub fn committed_value(&self) -> Option<Value> {
+        let value = self.value()?;
+        Some(match self.store_binding() {
+            Some(binding) => binding.committed_value(value),
+            None => value,
+        })
+    }
+
     pub fn set_value(&mut self, value: Value) {
         if let Some(widget) = self.interactive_mut() {
             widget.set_value(value);
@@ -307,7 +361,7 @@ impl Node {
         Vec::new()
     }
 
-    pub fn validate(&self, mode: ValidationMode) -> Result<(), String> {
+    pub fn validate(&self, mode: ValidationMode) -> Result<(), Message> {
         if let Some(widget) = self.interactive_ref() {
             widget.validate(mode)
         } else if let Some(widget) = self.output_ref() {
@@ -317,11 +371,33 @@ impl Node {
         }
     }
 
+    pub fn validation_issues(&self, mode: ValidationMode) -> Vec<ValidationIssue> {
+        if let Some(widget) = self.interactive_ref() {
+            widget.validation_issues(mode)
+        } else {
+            self.validate(mode)
+                .err()
+                .map(ValidationIssue::new)
+                .into_iter()
+                .collect()
+        }
+    }
+
+    pub fn reveal(&mut self, target: &str) -> bool {
+        self.interactive_mut()
+            .is_some_and(|widget| widget.reveal(target))
+    }
+
     pub fn overlay_placement(&self) -> Option<OverlayPlacement> {
         self.interactive_ref()
             .and_then(|widget| widget.overlay_placement())
     }
 
+    pub fn overlay_anchor(&self) -> Option<OverlayAnchor> {
+        self.interactive_ref()
+            .and_then(|widget| widget.overlay_anchor())
+    }
+
     pub fn overlay_open(&mut self, saved_focus_id: Option<String>) -> bool {
         self.interactive_mut()
             .is_some_and(|widget| widget.overlay_open(saved_focus_id))
@@ -1,6 +1,6 @@
 use crate::core::value::Value;
 use crate::ui::span::Span;
-use crate::ui::style::{Color, Style};
+use crate::ui::style::{Color, Style, StyleRole};
 use crate::widgets::traits::{DrawOutput, Drawable, OutputNode, RenderContext};
 use std::collections::VecDeque;
 
@@ -9,8 +9,14 @@ pub enum ChartRenderMode {
     Braille,
     Dots,
     Sparkline,
+    /// Vertical bars `height` rows tall, one per point.
+    Bars,
 }
 
+const BAR_CHARS: [char; 9] = [' ', '▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+/// Widest a bar grows when few points share the width.
+const MAX_BAR_CELLS: usize = 6;
+
 pub struct ChartOutput {
     id: String,
     label: String,
@@ -21,6 +27,8 @@ pub struct ChartOutput {
     fixed_max: Option<f64>,
     unit: Option<String>,
     gradient: bool,
+    height: usize,
+    axis: bool,
 }
 
 impl ChartOutput {
@@ -35,6 +43,8 @@ impl ChartOutput {
             fixed_max: None,
             unit: None,
             gradient: false,
+            height: 6,
+            axis: true,
         }
     }
 
@@ -65,6 +75,18 @@ impl ChartOutput {
         self
     }
 
+    /// Rows the bar chart takes.
+    pub fn with_height(mut self, height: usize) -> Self {
+        self.height = height.max(1);
+        self
+    }
+
+    /// Labels the bar chart's top and bottom with the range it spans.
+    pub fn with_axis(mut self, axis: bool) -> Self {
+        self.axis = axis;
+        self
+    }
+
     fn push_point(&mut self, value: f64) {
         if self.capacity == 0 {
             return;
@@ -118,14 +140,69 @@ impl ChartOutput {
             .collect()
     }
 
-    fn render_series(&self, normalized: &[f64]) -> Vec<(char, f64)> {
+    /// One line for the single-row modes; the points are squeezed into
+    /// `width` columns when there are more.
+    fn render_series(&self, normalized: &[f64], width: usize) -> Vec<(char, f64)> {
         match self.mode {
-            ChartRenderMode::Braille => render_braille(normalized),
-            ChartRenderMode::Dots => render_dots(normalized),
-            ChartRenderMode::Sparkline => render_sparkline(normalized),
+            ChartRenderMode::Braille => render_braille(&fit_to_width(normalized, width * 2)),
+            ChartRenderMode::Dots => render_dots(&fit_to_width(normalized, width)),
+            ChartRenderMode::Sparkline | ChartRenderMode::Bars => {
+                render_sparkline(&fit_to_width(normalized, width))
+            }
         }
     }
 
+    fn render_bars(&self, normalized: &[f64], min: f64, max: f64, width: usize) -> Vec<Vec<Span>> {
+        let unit = self.unit_suffix();
+        let labels = if self.axis {
+            [format!("{max:.1}{unit}"), format!("{min:.1}{unit}")]
+        } else {
+            [String::new(), String::new()]
+        };
+        let label_width = labels.iter().map(|label| label.chars().count()).max();
+        let gutter = label_width
+            .filter(|width| *width > 0)
+            .map_or(0, |width| width + 2);
+        let points = fit_to_width(normalized, width.saturating_sub(gutter).max(1));
+        let cells = (width.saturating_sub(gutter) / points.len().max(1)).clamp(1, MAX_BAR_CELLS);
+        let gap = usize::from(cells > 1);
+
+        (0..self.height)
+            .map(|row| {
+                let mut line = Vec::new();
+                if gutter > 0 {
+                    let (label, tick) = match row {
+                        0 => (labels[0].as_str(), '┤'),
+                        row if row + 1 == self.height => (labels[1].as_str(), '┤'),
+                        _ => ("", '│'),
+                    };
+                    let label_width = gutter - 2;
+                    line.push(
+                        Span::styled(
+                            format!("{label:>label_width$} {tick}"),
+                            StyleRole::Hint.style(),
+                        )
+                        .no_wrap(),
+                    );
+                }
+                let floor = (self.height - 1 - row) * 8;
+                let series = points
+                    .iter()
+                    .map(|level| {
+                        let eighths = (level * (self.height * 8) as f64).round() as usize;
+                        (BAR_CHARS[eighths.saturating_sub(floor).min(8)], *level)
+                    })
+                    .flat_map(|cell| {
+                        std::iter::repeat_n(cell, cells - gap)
+                            .chain(std::iter::repeat_n((' ', 0.0), gap))
+                    })
+                    .collect::<Vec<_>>();
+                line.extend(self.render_series_line(series.as_slice()));
+                line
+            })
+            .collect()
+    }
+
     fn render_series_line(&self, series: &[(char, f64)]) -> Vec<Span> {
         if !self.gradient {
             let text = series.iter().map(|(ch, _)| *ch).collect::<String>();
@@ -165,13 +242,13 @@ impl Drawable for ChartOutput {
         self.id.as_str()
     }
 
-    fn draw(&self, _ctx: &RenderContext) -> DrawOutput {
+    fn draw(&self, ctx: &RenderContext) -> DrawOutput {
         let mut lines = Vec::new();
         lines.push(vec![Span::new(self.label.clone()).no_wrap()]);
 
         if self.points.is_empty() {
             lines.push(vec![
-                Span::styled("No data yet", Style::new().color(Color::DarkGrey)).no_wrap(),
+                Span::styled("No data yet", StyleRole::Hint.style()).no_wrap(),
             ]);
             return DrawOutput::with_lines(lines);
         }
@@ -180,7 +257,7 @@ impl Drawable for ChartOutput {
             return DrawOutput::with_lines(lines);
         };
         let normalized = self.normalized_points(min, max);
-        let series = self.render_series(normalized.as_slice());
+        let width = usize::from(ctx.terminal_size.width).max(1);
 
         let now = *self.points.back().unwrap_or(&0.0);
         let avg = self.points.iter().sum::<f64>() / self.points.len() as f64;
@@ -202,11 +279,16 @@ impl Drawable for ChartOutput {
                     "now: {:.1}{}   avg: {:.1}{}   min: {:.1}{}   max: {:.1}{}",
                     now, unit, avg, unit, min_seen, unit, max_seen, unit
                 ),
-                Style::new().color(Color::DarkGrey),
+                StyleRole::Hint.style(),
             )
             .no_wrap(),
         ]);
-        lines.push(self.render_series_line(series.as_slice()));
+        if self.mode == ChartRenderMode::Bars {
+            lines.extend(self.render_bars(normalized.as_slice(), min, max, width));
+        } else {
+            let series = self.render_series(normalized.as_slice(), width);
+            lines.push(self.render_series_line(series.as_slice()));
+        }
 
         DrawOutput::with_lines(lines)
     }
@@ -284,6 +366,23 @@ fn render_braille(points: &[f64]) -> Vec<(char, f64)> {
     out
 }
 
+/// Averages neighbouring points into `columns` buckets when there are more
+/// points than columns.
+fn fit_to_width(points: &[f64], columns: usize) -> Vec<f64> {
+    let columns = columns.max(1);
+    if points.len() <= columns {
+        return points.to_vec();
+    }
+    (0..columns)
+        .map(|column| {
+            let start = column * points.len() / columns;
+            let end = ((column + 1) * points.len() / columns).max(start + 1);
+            let bucket = &points[start..end];
+            bucket.iter().sum::<f64>() / bucket.len() as f64
+        })
+        .collect()
+}
+
 fn gradient_color(level: f64) -> Color {
     if level < 0.2 {
         Color::Rgb(110, 116, 124)
@@ -326,3 +425,7 @@ fn column_mask(level: u8, left: bool) -> u8 {
     }
     mask
 }
+
+#[cfg(test)]
+#[path = "tests/chart.rs"]
+mod tests;
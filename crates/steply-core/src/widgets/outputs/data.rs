@@ -1,7 +1,12 @@
 use crate::core::value::Value;
+use crate::terminal::{KeyCode, KeyEvent, PointerEvent};
 use crate::ui::span::{Span, SpanLine};
-use crate::ui::style::{Color, Style};
-use crate::widgets::traits::{DrawOutput, Drawable, OutputNode, RenderContext};
+use crate::ui::style::{Color, Style, StyleRole};
+use crate::widgets::node::LeafComponent;
+use crate::widgets::shared::scroll::ScrollState;
+use crate::widgets::traits::{
+    DrawOutput, Drawable, FocusMode, InteractionResult, Interactive, OutputNode, RenderContext,
+};
 use indexmap::IndexMap;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -16,6 +21,7 @@ pub struct DataOutput {
     label: Option<String>,
     format: DataOutputFormat,
     value: Value,
+    scroll: ScrollState,
 }
 
 impl DataOutput {
@@ -25,9 +31,30 @@ impl DataOutput {
             label,
             format,
             value: Value::None,
+            scroll: ScrollState::new(None),
         }
     }
 
+    /// Shows at most `rows` lines at a time; the rest are scrolled to.
+    pub fn with_max_visible(mut self, rows: usize) -> Self {
+        self.scroll.set_max_visible(rows);
+        self
+    }
+
+    /// Moves the window by `delta` lines, stopping at either end.
+    pub fn scroll(&mut self, delta: isize) -> bool {
+        let total = self.render_lines().len();
+        let visible = self.scroll.max_visible.unwrap_or(total);
+        let next = self
+            .scroll
+            .offset
+            .saturating_add_signed(delta)
+            .min(total.saturating_sub(visible));
+        let moved = next != self.scroll.offset;
+        self.scroll.offset = next;
+        moved
+    }
+
     fn render_lines(&self) -> Vec<SpanLine> {
         match self.format {
             DataOutputFormat::Text => render_text_lines(&self.value),
@@ -46,18 +73,57 @@ impl Drawable for DataOutput {
         self.label.as_deref().unwrap_or("")
     }
 
-    fn draw(&self, _ctx: &RenderContext) -> DrawOutput {
+    fn draw(&self, ctx: &RenderContext) -> DrawOutput {
         let mut lines = Vec::new();
         if let Some(label) = &self.label
             && !label.is_empty()
         {
-            lines.push(vec![Span::new(label.clone()).no_wrap()]);
+            lines.push(vec![Span::new(ctx.translate(label).to_string()).no_wrap()]);
+        }
+        let body = self.render_lines();
+        let (start, end) = self.scroll.visible_range(body.len());
+        let footer = self.scroll.footer(body.len());
+        lines.extend(body.into_iter().skip(start).take(end - start));
+        if let Some(footer) = footer {
+            lines.push(vec![
+                Span::styled(footer, StyleRole::Hint.style()).no_wrap(),
+            ]);
         }
-        lines.extend(self.render_lines());
         DrawOutput::with_lines(lines)
     }
 }
 
+impl LeafComponent for DataOutput {}
+
+/// Shown as a component, e.g. in the value preview, the output scrolls
+/// with the arrow keys, PageUp/PageDown, Home/End and the mouse wheel.
+impl Interactive for DataOutput {
+    fn focus_mode(&self) -> FocusMode {
+        FocusMode::Leaf
+    }
+
+    fn on_key(&mut self, key: KeyEvent) -> InteractionResult {
+        let page = self.scroll.max_visible.unwrap_or(1) as isize;
+        let delta = match key.code {
+            KeyCode::Up => -1,
+            KeyCode::Down => 1,
+            KeyCode::PageUp => -page,
+            KeyCode::PageDown => page,
+            KeyCode::Home => isize::MIN,
+            KeyCode::End => isize::MAX,
+            _ => return InteractionResult::ignored(),
+        };
+        InteractionResult::handled_if(self.scroll(delta))
+    }
+
+    fn on_pointer(&mut self, event: PointerEvent) -> InteractionResult {
+        match event.kind.wheel_delta() {
+            Some(delta) => InteractionResult::handled_if(self.scroll(delta)),
+            None => InteractionResult::ignored(),
+        }
+    }
+}
+
 impl OutputNode for DataOutput {
     fn value(&self) -> Option<Value> {
         Some(self.value.clone())
@@ -444,11 +510,11 @@ fn render_scalar_yaml(value: &Value) -> Span {
 }
 
 fn punctuation(text: &str) -> Span {
-    Span::styled(text.to_string(), Style::new().color(Color::DarkGrey))
+    Span::styled(text.to_string(), StyleRole::Hint.style())
 }
 
 fn styled_null(text: &str) -> Span {
-    Span::styled(text.to_string(), Style::new().color(Color::DarkGrey).bold())
+    Span::styled(text.to_string(), StyleRole::Hint.style().bold())
 }
 
 fn escape_json_string(text: &str) -> String {
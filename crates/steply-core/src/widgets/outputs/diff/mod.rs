@@ -1,11 +1,17 @@
+mod unified;
+
+use std::collections::{HashMap, HashSet};
+
 use similar::{DiffOp, TextDiff};
 
-use crate::terminal::{KeyCode, KeyEvent};
+use crate::core::i18n::Message;
+use crate::terminal::{KeyCode, KeyEvent, PointerEvent};
+use crate::ui::fill::SELECTION_BACKGROUND;
 use crate::ui::layout::{Layout, LineContinuation, RenderBlock};
 use crate::ui::span::Span;
-use crate::ui::style::{Color, Style};
+use crate::ui::style::{Color, Style, StyleRole};
 use crate::widgets::base::WidgetBase;
-use crate::widgets::node::LeafComponent;
+use crate::widgets::node::{LeafComponent, Node};
 use crate::widgets::shared::keymap;
 use crate::widgets::shared::scroll::CursorNav;
 use crate::widgets::traits::{
@@ -32,21 +38,32 @@ enum DiffRow {
         left: Side,
         right: Side,
         kind: RowKind,
+        /// Index of the change this row belongs to; `None` for context.
+        hunk: Option<usize>,
     },
 
-    Gap {
-        hidden: usize,
-    },
+    /// Unchanged lines left out, starting at old line index `old_start`.
+    Gap { hidden: usize, old_start: usize },
 }
 
+/// Unchanged lines one Enter on a gap brings back.
+const GAP_REVEAL_STEP: usize = 10;
+
 pub struct DiffOutput {
     base: WidgetBase,
     old: String,
     new: String,
 
     context: usize,
+    /// Lines shown from the top of each gap, keyed by its `old_start`.
+    revealed: HashMap<usize, usize>,
     rows: Vec<DiffRow>,
     nav: CursorNav,
+    scrollbar: bool,
+    unified: bool,
+    review: bool,
+    /// Hunks left out of [`DiffOutput::accepted_text`] while reviewing.
+    rejected: HashSet<usize>,
 }
 
 impl DiffOutput {
@@ -61,8 +78,13 @@ impl DiffOutput {
             old: old.into(),
             new: new.into(),
             context: 3,
+            revealed: HashMap::new(),
             rows: Vec::new(),
             nav: CursorNav::new(Some(20)),
+            scrollbar: false,
+            unified: false,
+            review: false,
+            rejected: HashSet::new(),
         };
         this.rebuild();
         this
@@ -73,18 +95,68 @@ impl DiffOutput {
         self
     }
 
+    /// Draws a scrollbar beside the diff instead of the `[1-20 of 80]`
+    /// footer.
+    pub fn with_scrollbar(mut self, scrollbar: bool) -> Self {
+        self.scrollbar = scrollbar;
+        self
+    }
+
+    /// Starts in the single-column unified layout instead of side by side;
+    /// `u` switches between them.
+    pub fn with_unified(mut self, unified: bool) -> Self {
+        self.unified = unified;
+        self
+    }
+
+    /// Turns the diff into a patch review: Space stages or unstages the
+    /// hunk under the cursor, and the value is the old text with the staged
+    /// hunks applied. Every hunk starts staged.
+    pub fn with_review(mut self, review: bool) -> Self {
+        self.review = review;
+        self
+    }
+
+    /// The old text with every staged hunk applied; unstaged hunks keep the
+    /// old lines.
+    pub fn accepted_text(&self) -> String {
+        let diff = TextDiff::from_lines(self.old.as_str(), self.new.as_str());
+        let mut text = String::with_capacity(self.new.len());
+        let mut hunk = 0usize;
+        for op in diff.ops() {
+            let staged = !matches!(op, DiffOp::Equal { .. }) && {
+                hunk += 1;
+                !self.rejected.contains(&(hunk - 1))
+            };
+            let lines = if staged {
+                &diff.new_slices()[op.new_range()]
+            } else {
+                &diff.old_slices()[op.old_range()]
+            };
+            text.extend(lines.iter().copied());
+        }
+        text
+    }
+
     pub fn set_texts(&mut self, old: impl Into<String>, new: impl Into<String>) {
         self.old = old.into();
         self.new = new.into();
+        self.revealed.clear();
+        self.rejected.clear();
         self.rebuild();
     }
 
     fn rebuild(&mut self) {
-        self.rows = Self::build_rows(&self.old, &self.new, self.context);
+        self.rows = Self::build_rows(&self.old, &self.new, self.context, &self.revealed);
         self.nav.clamp(self.rows.len());
     }
 
-    fn build_rows(old: &str, new: &str, context: usize) -> Vec<DiffRow> {
+    fn build_rows(
+        old: &str,
+        new: &str,
+        context: usize,
+        revealed: &HashMap<usize, usize>,
+    ) -> Vec<DiffRow> {
         let old_lines: Vec<&str> = old.lines().collect();
         let new_lines: Vec<&str> = new.lines().collect();
 
@@ -93,43 +165,35 @@ impl DiffOutput {
 
         let mut rows: Vec<DiffRow> = Vec::new();
         let mut prev_old_end = 0usize;
+        let mut prev_new_end = 0usize;
+        let mut next_hunk = 0usize;
 
         for group in &groups {
             let group_old_start = group.first().map(|op| op.old_range().start).unwrap_or(0);
-            let hidden = group_old_start.saturating_sub(prev_old_end);
-            if hidden > 0 {
-                rows.push(DiffRow::Gap { hidden });
-            }
+            Self::push_gap(
+                &mut rows,
+                (&old_lines, &new_lines),
+                (prev_old_end, prev_new_end),
+                group_old_start.saturating_sub(prev_old_end),
+                revealed,
+            );
 
             for op in group {
+                let hunk = (!matches!(op, DiffOp::Equal { .. })).then(|| {
+                    next_hunk += 1;
+                    next_hunk - 1
+                });
                 match op {
                     DiffOp::Equal {
                         old_index,
                         new_index,
                         len,
-                    } => {
-                        for i in 0..*len {
-                            rows.push(DiffRow::Line {
-                                left: Side::Line {
-                                    no: old_index + i + 1,
-                                    text: old_lines
-                                        .get(old_index + i)
-                                        .copied()
-                                        .unwrap_or("")
-                                        .to_string(),
-                                },
-                                right: Side::Line {
-                                    no: new_index + i + 1,
-                                    text: new_lines
-                                        .get(new_index + i)
-                                        .copied()
-                                        .unwrap_or("")
-                                        .to_string(),
-                                },
-                                kind: RowKind::Context,
-                            });
-                        }
-                    }
+                    } => Self::push_context(
+                        &mut rows,
+                        (&old_lines, &new_lines),
+                        (*old_index, *new_index),
+                        *len,
+                    ),
                     DiffOp::Delete {
                         old_index, old_len, ..
                     } => {
@@ -145,6 +209,7 @@ impl DiffOutput {
                                 },
                                 right: Side::Empty,
                                 kind: RowKind::Removed,
+                                hunk,
                             });
                         }
                     }
@@ -163,6 +228,7 @@ impl DiffOutput {
                                         .to_string(),
                                 },
                                 kind: RowKind::Added,
+                                hunk,
                             });
                         }
                     }
@@ -193,6 +259,7 @@ impl DiffOutput {
                                         .to_string(),
                                 },
                                 kind: RowKind::Changed,
+                                hunk,
                             });
                         }
 
@@ -208,6 +275,7 @@ impl DiffOutput {
                                 },
                                 right: Side::Empty,
                                 kind: RowKind::Removed,
+                                hunk,
                             });
                         }
 
@@ -223,6 +291,7 @@ impl DiffOutput {
                                         .to_string(),
                                 },
                                 kind: RowKind::Added,
+                                hunk,
                             });
                         }
                     }
@@ -233,16 +302,75 @@ impl DiffOutput {
                 .last()
                 .map(|op| op.old_range().end)
                 .unwrap_or(prev_old_end);
+            prev_new_end = group
+                .last()
+                .map(|op| op.new_range().end)
+                .unwrap_or(prev_new_end);
         }
 
-        let trailing = old_lines.len().saturating_sub(prev_old_end);
-        if trailing > 0 {
-            rows.push(DiffRow::Gap { hidden: trailing });
-        }
+        Self::push_gap(
+            &mut rows,
+            (&old_lines, &new_lines),
+            (prev_old_end, prev_new_end),
+            old_lines.len().saturating_sub(prev_old_end),
+            revealed,
+        );
 
         rows
     }
 
+    /// Unchanged lines at `start` (old, new): the revealed top part as
+    /// context rows, the rest as a gap.
+    fn push_gap(
+        rows: &mut Vec<DiffRow>,
+        lines: (&[&str], &[&str]),
+        start: (usize, usize),
+        len: usize,
+        revealed: &HashMap<usize, usize>,
+    ) {
+        if len == 0 {
+            return;
+        }
+        let shown = revealed.get(&start.0).copied().unwrap_or(0).min(len);
+        Self::push_context(rows, lines, start, shown);
+        if shown < len {
+            rows.push(DiffRow::Gap {
+                hidden: len - shown,
+                old_start: start.0,
+            });
+        }
+    }
+
+    fn push_context(
+        rows: &mut Vec<DiffRow>,
+        (old_lines, new_lines): (&[&str], &[&str]),
+        (old_index, new_index): (usize, usize),
+        len: usize,
+    ) {
+        for i in 0..len {
+            rows.push(DiffRow::Line {
+                left: Side::Line {
+                    no: old_index + i + 1,
+                    text: old_lines
+                        .get(old_index + i)
+                        .copied()
+                        .unwrap_or("")
+                        .to_string(),
+                },
+                right: Side::Line {
+                    no: new_index + i + 1,
+                    text: new_lines
+                        .get(new_index + i)
+                        .copied()
+                        .unwrap_or("")
+                        .to_string(),
+                },
+                kind: RowKind::Context,
+                hunk: None,
+            });
+        }
+    }
+
     fn move_cursor(&mut self, delta: isize) {
         self.nav.move_by(delta, self.rows.len());
     }
@@ -277,12 +405,58 @@ impl DiffOutput {
             return false;
         }
         self.context += 3;
+        self.revealed.clear();
+        let old_active = self.nav.active();
+        self.rebuild();
+        self.nav.set_active(old_active, self.rows.len());
+        true
+    }
+
+    /// Shows the next [`GAP_REVEAL_STEP`] lines of the active gap only; the
+    /// cursor lands on the first of them.
+    fn reveal_gap(&mut self) -> bool {
+        let Some(DiffRow::Gap { old_start, .. }) = self.rows.get(self.nav.active()) else {
+            return false;
+        };
+        *self.revealed.entry(*old_start).or_default() += GAP_REVEAL_STEP;
         let old_active = self.nav.active();
         self.rebuild();
         self.nav.set_active(old_active, self.rows.len());
         true
     }
 
+    fn active_hunk(&self) -> Option<usize> {
+        match self.rows.get(self.nav.active())? {
+            DiffRow::Line { hunk, .. } => *hunk,
+            DiffRow::Gap { .. } => None,
+        }
+    }
+
+    fn toggle_active_hunk(&mut self) -> bool {
+        let Some(hunk) = self.active_hunk().filter(|_| self.review) else {
+            return false;
+        };
+        if !self.rejected.remove(&hunk) {
+            self.rejected.insert(hunk);
+        }
+        true
+    }
+
+    fn hunk_count(&self) -> usize {
+        self.rows
+            .iter()
+            .filter_map(|row| match row {
+                DiffRow::Line { hunk, .. } => *hunk,
+                DiffRow::Gap { .. } => None,
+            })
+            .max()
+            .map_or(0, |last| last + 1)
+    }
+
+    fn is_rejected(&self, hunk: Option<usize>) -> bool {
+        hunk.is_some_and(|hunk| self.rejected.contains(&hunk))
+    }
+
     fn line_no_width(&self) -> usize {
         self.old
             .lines()
@@ -361,7 +535,7 @@ impl Drawable for DiffOutput {
         let total = self.rows.len();
         let (start, end) = self.nav.visible_range(total);
 
-        let dim = Style::new().color(Color::DarkGrey);
+        let dim = StyleRole::Hint.style();
         let no_st = Style::new().color(Color::Rgb(80, 80, 80));
         let ctx_st = Style::new().color(Color::Rgb(200, 200, 200));
         let add_st = Style::new()
@@ -373,11 +547,15 @@ impl Drawable for DiffOutput {
         let chg_st = Style::new()
             .color(Color::Yellow)
             .background(Color::Rgb(38, 32, 0));
-        let active_bg = Style::new().background(Color::Rgb(45, 45, 65));
+        let active_bg = Style::new().background(SELECTION_BACKGROUND);
         let active_dim = Style::new()
             .color(Color::Rgb(120, 120, 140))
-            .background(Color::Rgb(45, 45, 65));
-        let wrap_width = ctx.terminal_size.width.max(1) as usize;
+            .background(SELECTION_BACKGROUND);
+        let scrollbar = self.scrollbar.then(|| self.nav.scrollbar(total)).flatten();
+        let mut wrap_width = ctx.terminal_size.width.max(1) as usize;
+        if scrollbar.is_some() {
+            wrap_width = wrap_width.saturating_sub(1).max(1);
+        }
         let marker_col_width = 3usize;
         let separator_width = 3usize;
         let side_total = wrap_width
@@ -396,7 +574,7 @@ impl Drawable for DiffOutput {
                 .filter(|r| matches!(r, DiffRow::Gap { .. }))
                 .count()
                 + 1;
-            lines.push(vec![
+            let mut header = vec![
                 Span::styled(format!("─── {} ", self.base.label()), dim).no_wrap(),
                 Span::styled(
                     format!(
@@ -407,23 +585,33 @@ impl Drawable for DiffOutput {
                     dim,
                 )
                 .no_wrap(),
-            ]);
+            ];
+            if self.review {
+                let total = self.hunk_count();
+                let staged = total.saturating_sub(self.rejected.len());
+                header.push(Span::styled(format!(" {staged}/{total} hunks staged"), dim).no_wrap());
+            }
+            lines.push(header);
         }
 
+        let body_start = lines.len();
         for vis in start..end {
             let is_active = focused && vis == self.nav.active();
 
             match &self.rows[vis] {
-                DiffRow::Gap { hidden } => {
+                DiffRow::Gap { hidden, .. } => {
                     let st = if is_active {
                         Style::new()
                             .color(Color::Cyan)
-                            .background(Color::Rgb(45, 45, 65))
+                            .background(SELECTION_BACKGROUND)
                     } else {
                         dim
                     };
                     let label = if is_active {
-                        format!(" {} lines hidden [Space: +3] ", hidden)
+                        format!(
+                            " {} lines hidden [Enter: +{GAP_REVEAL_STEP}, Space: +3] ",
+                            hidden
+                        )
                     } else {
                         format!(" {} lines hidden [+3] ", hidden)
                     };
@@ -434,8 +622,32 @@ impl Drawable for DiffOutput {
                         Span::styled(fill, st).no_wrap(),
                     ]);
                 }
-                DiffRow::Line { left, right, kind } => {
+                DiffRow::Line {
+                    left,
+                    right,
+                    kind,
+                    hunk,
+                } if self.unified => {
+                    lines.extend(Self::render_unified_row(
+                        left,
+                        right,
+                        *kind,
+                        is_active,
+                        self.is_rejected(*hunk),
+                        wrap_width,
+                        line_no_width,
+                    ));
+                }
+                DiffRow::Line {
+                    left,
+                    right,
+                    kind,
+                    hunk,
+                } => {
+                    let rejected = self.is_rejected(*hunk);
+                    let rejected_st = if is_active { active_bg } else { dim };
                     let (marker, l_st, r_st) = match kind {
+                        _ if rejected => ("✗", rejected_st, rejected_st),
                         RowKind::Context => (
                             " ",
                             if is_active { active_bg } else { ctx_st },
@@ -461,7 +673,9 @@ impl Drawable for DiffOutput {
                     let marker_st = if is_active {
                         Style::new()
                             .color(Color::Yellow)
-                            .background(Color::Rgb(45, 45, 65))
+                            .background(SELECTION_BACKGROUND)
+                    } else if rejected {
+                        dim
                     } else {
                         match kind {
                             RowKind::Removed => Style::new().color(Color::Red),
@@ -471,9 +685,7 @@ impl Drawable for DiffOutput {
                         }
                     };
                     let cursor_st = if is_active {
-                        Style::new()
-                            .color(Color::Yellow)
-                            .background(Color::Rgb(45, 45, 65))
+                        StyleRole::Cursor.style().background(SELECTION_BACKGROUND)
                     } else {
                         dim
                     };
@@ -526,14 +738,25 @@ impl Drawable for DiffOutput {
             }
         }
 
-        if let Some(text) = self.nav.footer(total) {
+        if let Some(scrollbar) = scrollbar {
+            scrollbar.attach(&mut lines[body_start..], wrap_width as u16);
+        } else if !self.scrollbar
+            && let Some(text) = self.nav.footer(total)
+        {
             lines.push(vec![Span::styled(text, dim).no_wrap()]);
         }
 
         if focused {
+            let space = if self.review {
+                "Space stage hunk / more context"
+            } else {
+                "Space more context"
+            };
             lines.push(vec![
                 Span::styled(
-                    "  ↑↓ navigate  Tab next chunk  Shift+Tab prev  Space expand gap  Enter submit step",
+                    format!(
+                        "  ↑↓ navigate  Tab next chunk  Shift+Tab prev  {space}  u unified/split  Enter expand gap / submit step"
+                    ),
                     dim,
                 )
                 .no_wrap(),
@@ -568,23 +791,56 @@ impl Interactive for DiffOutput {
                 InteractionResult::handled()
             }
             KeyCode::Char(' ') if keymap::has_no_modifiers(key) => {
-                InteractionResult::handled_if(self.expand_gap())
+                InteractionResult::handled_if(self.toggle_active_hunk() || self.expand_gap())
+            }
+            KeyCode::Char('u') if keymap::has_no_modifiers(key) => {
+                self.unified = !self.unified;
+                InteractionResult::handled()
             }
             KeyCode::Enter if keymap::is_plain_key(key, KeyCode::Enter) => {
+                if self.reveal_gap() {
+                    return InteractionResult::handled();
+                }
                 InteractionResult::input_done()
             }
             _ => InteractionResult::ignored(),
         }
     }
 
+    fn on_pointer(&mut self, event: PointerEvent) -> InteractionResult {
+        match event.kind.wheel_delta() {
+            Some(delta) => {
+                InteractionResult::handled_if(self.nav.scroll_by(delta, self.rows.len()))
+            }
+            None => InteractionResult::ignored(),
+        }
+    }
+
+    fn expanded_view(&self, id: &str, rows: usize) -> Option<Node> {
+        let mut view = DiffOutput::new(id, self.base.label(), self.old.as_str(), self.new.as_str())
+            .with_max_visible(rows)
+            .with_scrollbar(true)
+            .with_unified(self.unified);
+        view.context = self.context;
+        view.rejected = self.rejected.clone();
+        view.revealed = self.revealed.clone();
+        view.rebuild();
+        Some(Node::Component(Box::new(view)))
+    }
+
     fn value(&self) -> Option<crate::core::value::Value> {
-        None
+        self.review
+            .then(|| crate::core::value::Value::Text(self.accepted_text()))
     }
     fn set_value(&mut self, _: crate::core::value::Value) {}
-    fn validate(&self, _: ValidationMode) -> Result<(), String> {
+    fn validate(&self, _: ValidationMode) -> Result<(), Message> {
         Ok(())
     }
     fn cursor_pos(&self) -> Option<crate::terminal::CursorPos> {
         None
     }
 }
+
+#[cfg(test)]
+#[path = "../tests/diff.rs"]
+mod tests;
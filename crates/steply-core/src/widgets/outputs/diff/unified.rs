@@ -0,0 +1,118 @@
+use super::{DiffOutput, RowKind, Side};
+use crate::ui::fill::SELECTION_BACKGROUND;
+use crate::ui::layout::{Layout, LineContinuation, RenderBlock};
+use crate::ui::span::Span;
+use crate::ui::style::{Color, Style, StyleRole};
+
+impl DiffOutput {
+    /// One diff row as single-column lines: `-` for the old side, `+` for
+    /// the new one, both numbers on unchanged lines. A changed row takes a
+    /// `-` and a `+` line; an unstaged one is dimmed.
+    pub(super) fn render_unified_row(
+        left: &Side,
+        right: &Side,
+        kind: RowKind,
+        is_active: bool,
+        rejected: bool,
+        width: usize,
+        line_no_width: usize,
+    ) -> Vec<Vec<Span>> {
+        let ctx_st = Style::new().color(Color::Rgb(200, 200, 200));
+        let add_st = Style::new()
+            .color(Color::Green)
+            .background(Color::Rgb(0, 35, 0));
+        let del_st = Style::new()
+            .color(Color::Red)
+            .background(Color::Rgb(40, 0, 0));
+
+        let parts = match kind {
+            RowKind::Context => vec![(" ", line_no(left), line_no(right), text(right), ctx_st)],
+            RowKind::Removed => vec![("-", line_no(left), None, text(left), del_st)],
+            RowKind::Added => vec![("+", None, line_no(right), text(right), add_st)],
+            RowKind::Changed => vec![
+                ("-", line_no(left), None, text(left), del_st),
+                ("+", None, line_no(right), text(right), add_st),
+            ],
+        };
+
+        let mut lines = Vec::new();
+        for (part_idx, (marker, old_no, new_no, text, style)) in parts.into_iter().enumerate() {
+            let style = if rejected {
+                StyleRole::Hint.style()
+            } else {
+                style
+            };
+            let text_style = if is_active {
+                style.background(SELECTION_BACKGROUND)
+            } else {
+                style
+            };
+            let no_style = if is_active {
+                Style::new()
+                    .color(Color::Rgb(120, 120, 140))
+                    .background(SELECTION_BACKGROUND)
+            } else {
+                Style::new().color(Color::Rgb(80, 80, 80))
+            };
+            let cursor = if is_active && part_idx == 0 {
+                Span::styled(
+                    "❯",
+                    StyleRole::Cursor.style().background(SELECTION_BACKGROUND),
+                )
+            } else {
+                Span::styled(" ", no_style)
+            };
+            let numbers = format!(
+                "{} {} ",
+                number_cell(old_no, line_no_width),
+                number_cell(new_no, line_no_width)
+            );
+            let gutter = 3 + numbers.chars().count();
+            let first_prefix = vec![
+                cursor.no_wrap(),
+                Span::styled(format!("{marker} "), text_style).no_wrap(),
+                Span::styled(numbers, no_style).no_wrap(),
+            ];
+            let next_prefix = vec![Span::styled(" ".repeat(gutter), no_style).no_wrap()];
+            let wrapped = Layout::compose_block(
+                &RenderBlock {
+                    start_col: 0,
+                    end_col: Some(width.min(u16::MAX as usize) as u16),
+                    lines: vec![vec![Span::styled(text, text_style).no_wrap()]],
+                },
+                width.min(u16::MAX as usize) as u16,
+                Some(&LineContinuation {
+                    first_prefix,
+                    next_prefix,
+                }),
+            );
+            lines.extend(
+                wrapped
+                    .into_iter()
+                    .map(|line| Self::fit_line_with_style(line, width, text_style)),
+            );
+        }
+        lines
+    }
+}
+
+fn line_no(side: &Side) -> Option<usize> {
+    match side {
+        Side::Line { no, .. } => Some(*no),
+        Side::Empty => None,
+    }
+}
+
+fn text(side: &Side) -> String {
+    match side {
+        Side::Line { text, .. } => text.clone(),
+        Side::Empty => String::new(),
+    }
+}
+
+fn number_cell(no: Option<usize>, width: usize) -> String {
+    match no {
+        Some(no) => format!("{no:>width$}"),
+        None => " ".repeat(width),
+    }
+}
@@ -0,0 +1,138 @@
+use crate::ui::span::{Span, SpanLine};
+use crate::ui::style::{Color, Strike, Style, Underline};
+
+const BASIC_COLORS: [Color; 8] = [
+    Color::Black,
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::White,
+];
+
+/// Turns text with SGR escapes (`\x1b[1;31m`) into styled spans. The style
+/// carries over from one line to the next, as it does in a terminal; other
+/// escape sequences and control characters are dropped.
+#[derive(Default)]
+pub(super) struct AnsiParser {
+    style: Style,
+}
+
+impl AnsiParser {
+    /// Parses one line; text before a carriage return is overwritten, so
+    /// only what follows the last one is kept.
+    pub fn parse_line(&mut self, line: &str) -> SpanLine {
+        let line = line.trim_end_matches('\r');
+        let line = line.rsplit('\r').next().unwrap_or(line);
+        let mut spans = Vec::new();
+        let mut text = String::new();
+        let mut chars = line.chars().peekable();
+
+        while let Some(ch) = chars.next() {
+            match ch {
+                '\x1b' => match chars.next() {
+                    Some('[') => {
+                        let mut params = String::new();
+                        let mut command = None;
+                        for ch in chars.by_ref() {
+                            if ('\x40'..='\x7e').contains(&ch) {
+                                command = Some(ch);
+                                break;
+                            }
+                            params.push(ch);
+                        }
+                        if command == Some('m') {
+                            push_text(&mut spans, &mut text, self.style);
+                            self.apply_sgr(params.as_str());
+                        }
+                    }
+                    // OSC (titles, hyperlinks) ends with BEL or `ESC \`.
+                    Some(']') => {
+                        while let Some(ch) = chars.next() {
+                            if ch == '\x07' || (ch == '\x1b' && chars.next_if_eq(&'\\').is_some()) {
+                                break;
+                            }
+                        }
+                    }
+                    _ => {}
+                },
+                '\t' => text.push_str("    "),
+                ch if ch.is_control() => {}
+                ch => text.push(ch),
+            }
+        }
+        push_text(&mut spans, &mut text, self.style);
+        spans
+    }
+
+    fn apply_sgr(&mut self, params: &str) {
+        let codes = params
+            .split([';', ':'])
+            .map(|code| code.parse::<u16>().unwrap_or(0))
+            .collect::<Vec<_>>();
+        if codes.is_empty() {
+            self.style = Style::default();
+            return;
+        }
+        let mut codes = codes.into_iter();
+        while let Some(code) = codes.next() {
+            let style = &mut self.style;
+            match code {
+                0 => *style = Style::default(),
+                1 => style.bold = true,
+                2 => style.dim = true,
+                3 => style.italic = true,
+                4 => style.underline = Underline::Straight,
+                7 => style.reverse = true,
+                9 => style.strike = Strike::On,
+                22 => {
+                    style.bold = false;
+                    style.dim = false;
+                }
+                23 => style.italic = false,
+                24 => style.underline = Underline::None,
+                27 => style.reverse = false,
+                29 => style.strike = Strike::Inherit,
+                30..=37 => style.color = Some(BASIC_COLORS[usize::from(code - 30)]),
+                38 => style.color = extended_color(&mut codes),
+                39 => style.color = None,
+                40..=47 => style.background = Some(BASIC_COLORS[usize::from(code - 40)]),
+                48 => style.background = extended_color(&mut codes),
+                49 => style.background = None,
+                90..=97 => style.color = Some(bright_color(code - 90)),
+                100..=107 => style.background = Some(bright_color(code - 100)),
+                _ => {}
+            }
+        }
+    }
+}
+
+/// The color after `38` or `48`: `5;n` from the 256-color palette or
+/// `2;r;g;b`.
+fn extended_color(codes: &mut impl Iterator<Item = u16>) -> Option<Color> {
+    let mut channel = || codes.next().map(|code| code.min(255) as u8);
+    match channel()? {
+        5 => channel().map(Color::Indexed),
+        2 => Some(Color::Rgb(channel()?, channel()?, channel()?)),
+        _ => None,
+    }
+}
+
+fn bright_color(offset: u16) -> Color {
+    match offset {
+        0 => Color::DarkGrey,
+        offset => Color::Indexed(8 + offset as u8),
+    }
+}
+
+fn push_text(spans: &mut SpanLine, text: &mut String, style: Style) {
+    if !text.is_empty() {
+        spans.push(Span::styled(std::mem::take(text), style).no_wrap());
+    }
+}
+
+#[cfg(test)]
+#[path = "../tests/log_ansi.rs"]
+mod tests;
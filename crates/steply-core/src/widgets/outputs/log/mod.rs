@@ -0,0 +1,545 @@
+mod ansi;
+
+use std::collections::VecDeque;
+
+use ansi::AnsiParser;
+
+use crate::core::value::Value;
+use crate::runtime::event::SystemEvent;
+use crate::task::TaskId;
+use crate::terminal::{CursorPos, KeyCode, KeyEvent, PointerEvent};
+use crate::time::{Duration, Instant};
+use crate::ui::animation::{Animations, Easing};
+use crate::ui::span::{Span, SpanLine, highlight_span_line};
+use crate::ui::style::{Color, Style, StyleRole};
+use crate::widgets::base::WidgetBase;
+use crate::widgets::inputs::text::TextInput;
+use crate::widgets::node::LeafComponent;
+use crate::widgets::shared::filter::sanitize_interaction_result;
+use crate::widgets::shared::keymap;
+use crate::widgets::static_hints::{LOG_OUTPUT_HINTS, LOG_OUTPUT_SEARCH_HINTS};
+use crate::widgets::traits::{
+    DrawOutput, Drawable, FocusMode, HintContext, HintItem, InteractionResult, Interactive,
+    RenderContext, TextAction, focused_static_hints,
+};
+
+const DEFAULT_VISIBLE_LINES: usize = 15;
+const DEFAULT_HISTORY_LINES: usize = 1000;
+const SCROLL_DURATION: Duration = Duration::from_millis(150);
+const SCROLL_KEY: &str = "scroll";
+
+struct LogLine {
+    spans: SpanLine,
+    /// The line without styles, for searching.
+    text: String,
+}
+
+/// A scrolling console for streamed output. Lines are appended with
+/// [`LogOutput::push_line`] or taken from the watched tasks; ANSI colors
+/// are kept, and only the newest `history_lines` are remembered.
+pub struct LogOutput {
+    base: WidgetBase,
+    lines: VecDeque<LogLine>,
+    parser: AnsiParser,
+    tasks: Vec<TaskId>,
+    visible_lines: usize,
+    history_lines: usize,
+    /// Lines scrolled back from the end.
+    scroll_offset: usize,
+    /// Keeps the newest line in view as output arrives.
+    follow: bool,
+    search: TextInput,
+    searching: bool,
+    /// Line of the match the cursor is on.
+    current_match: Option<usize>,
+    animate: bool,
+    scrolling: Animations,
+}
+
+impl LogOutput {
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        let id = id.into();
+        Self {
+            search: TextInput::new(format!("{id}__search"), ""),
+            base: WidgetBase::new(id, label),
+            lines: VecDeque::new(),
+            parser: AnsiParser::default(),
+            tasks: Vec::new(),
+            visible_lines: DEFAULT_VISIBLE_LINES,
+            history_lines: DEFAULT_HISTORY_LINES,
+            scroll_offset: 0,
+            follow: true,
+            searching: false,
+            current_match: None,
+            animate: false,
+            scrolling: Animations::default(),
+        }
+    }
+
+    /// Appends the output lines of `task_id` while it runs.
+    pub fn with_task(mut self, task_id: impl Into<TaskId>) -> Self {
+        self.tasks.push(task_id.into());
+        self
+    }
+
+    pub fn with_visible_lines(mut self, n: usize) -> Self {
+        self.visible_lines = n.max(1);
+        self
+    }
+
+    /// Lines kept for scrolling back; older ones are dropped.
+    pub fn with_history_lines(mut self, n: usize) -> Self {
+        self.history_lines = n.max(1);
+        self
+    }
+
+    /// Starts paused on the oldest lines instead of following new output.
+    pub fn with_follow(mut self, follow: bool) -> Self {
+        self.follow = follow;
+        self
+    }
+
+    /// Glides to a new scroll position instead of jumping when the view
+    /// moves by more than a line.
+    pub fn with_animation(mut self, animate: bool) -> Self {
+        self.animate = animate;
+        self
+    }
+
+    pub fn is_following(&self) -> bool {
+        self.follow
+    }
+
+    /// Appends `text`, one line per `\n`.
+    pub fn push_line(&mut self, text: &str) {
+        for line in text.split('\n') {
+            let spans = self.parser.parse_line(line);
+            let text = spans.iter().map(|span| span.text.as_str()).collect();
+            self.lines.push_back(LogLine { spans, text });
+            if !self.follow {
+                // Keeps a paused view on the same lines.
+                self.scroll_offset += 1;
+            }
+        }
+        while self.lines.len() > self.history_lines {
+            self.lines.pop_front();
+            self.current_match = self.current_match.and_then(|line| line.checked_sub(1));
+        }
+        self.scroll_offset = self.scroll_offset.min(self.max_scroll_offset());
+    }
+
+    pub fn clear(&mut self) {
+        self.lines.clear();
+        self.parser = AnsiParser::default();
+        self.scroll_offset = 0;
+        self.current_match = None;
+        self.scrolling.cancel(SCROLL_KEY);
+    }
+
+    /// The lines scrolled into view, without styles.
+    pub fn visible_text(&self) -> Vec<&str> {
+        let (start, end) = self.visible_range();
+        self.lines
+            .range(start..end)
+            .map(|line| line.text.as_str())
+            .collect()
+    }
+
+    /// Scrolls towards older lines for negative `delta`, which pauses
+    /// following. Returns whether the view moved.
+    pub fn scroll(&mut self, delta: isize) -> bool {
+        let next = self
+            .scroll_offset
+            .saturating_add_signed(-delta)
+            .min(self.max_scroll_offset());
+        let moved = next != self.scroll_offset;
+        self.move_to(next);
+        if delta < 0 && moved {
+            self.follow = false;
+        }
+        moved
+    }
+
+    fn set_follow(&mut self, follow: bool) {
+        self.follow = follow;
+        if follow {
+            self.move_to(0);
+        }
+    }
+
+    fn move_to(&mut self, offset: usize) {
+        if self.animate && offset.abs_diff(self.scroll_offset) > 1 {
+            let now = Instant::now();
+            let from = self.drawn_offset(now) as f64;
+            self.scrolling.start(
+                SCROLL_KEY,
+                from,
+                offset as f64,
+                SCROLL_DURATION,
+                Easing::OutCubic,
+                now,
+            );
+        }
+        self.scroll_offset = offset;
+    }
+
+    /// The offset drawn at `now`, which trails `scroll_offset` while a
+    /// scroll glides.
+    fn drawn_offset(&self, now: Instant) -> usize {
+        self.scrolling
+            .value(SCROLL_KEY, now)
+            .map_or(self.scroll_offset, |offset| offset.round() as usize)
+            .min(self.max_scroll_offset())
+    }
+
+    fn max_scroll_offset(&self) -> usize {
+        self.lines.len().saturating_sub(self.visible_lines)
+    }
+
+    fn visible_range(&self) -> (usize, usize) {
+        self.range_at(self.scroll_offset)
+    }
+
+    fn range_at(&self, offset: usize) -> (usize, usize) {
+        let end = self.lines.len().saturating_sub(offset);
+        (end.saturating_sub(self.visible_lines), end)
+    }
+
+    fn query(&self) -> String {
+        self.search
+            .value()
+            .and_then(|value| value.to_text_scalar())
+            .unwrap_or_default()
+    }
+
+    fn line_matches(&self, index: usize, query: &str) -> bool {
+        self.lines
+            .get(index)
+            .is_some_and(|line| !match_ranges(query, line.text.as_str()).is_empty())
+    }
+
+    /// Moves to the next match after `from` in the given direction,
+    /// wrapping around; `from` itself counts when `inclusive`.
+    fn jump_to_match(&mut self, from: usize, forward: bool, inclusive: bool) -> bool {
+        let query = self.query();
+        let len = self.lines.len();
+        if query.is_empty() || len == 0 {
+            return false;
+        }
+        let first = if inclusive { 0 } else { 1 };
+        let found = (first..len + first)
+            .map(|step| {
+                if forward {
+                    (from + step) % len
+                } else {
+                    (from + len - step % len) % len
+                }
+            })
+            .find(|index| self.line_matches(*index, query.as_str()));
+        let Some(index) = found else {
+            self.current_match = None;
+            return false;
+        };
+        self.current_match = Some(index);
+        self.reveal(index);
+        true
+    }
+
+    fn reveal(&mut self, index: usize) {
+        let (start, end) = self.visible_range();
+        if index < start {
+            let end = (index + self.visible_lines).min(self.lines.len());
+            self.move_to(self.lines.len() - end);
+        } else if index >= end {
+            self.move_to(self.lines.len() - (index + 1));
+        }
+        self.follow = false;
+    }
+
+    fn next_match(&mut self, forward: bool) -> bool {
+        let from = self.current_match.unwrap_or_else(|| self.visible_range().0);
+        self.jump_to_match(from, forward, self.current_match.is_none())
+    }
+
+    fn close_search(&mut self, keep_query: bool) {
+        self.searching = false;
+        if !keep_query {
+            self.search.set_value(Value::Text(String::new()));
+            self.current_match = None;
+        }
+    }
+
+    fn search_edited(&mut self, result: InteractionResult, before: &str) -> InteractionResult {
+        let result = sanitize_interaction_result(result);
+        if self.query() != before {
+            let from = self.visible_range().0;
+            if !self.jump_to_match(from, true, true) {
+                self.current_match = None;
+            }
+        }
+        result
+    }
+
+    fn handle_search_key(&mut self, key: KeyEvent) -> InteractionResult {
+        match key.code {
+            KeyCode::Enter => self.close_search(true),
+            KeyCode::Esc => self.close_search(false),
+            _ => {
+                let before = self.query();
+                let result = self.search.on_key(key);
+                return self.search_edited(result, before.as_str());
+            }
+        }
+        InteractionResult::handled()
+    }
+
+    fn status_text(&self) -> String {
+        let count = self.lines.len();
+        let noun = if count == 1 { "line" } else { "lines" };
+        let follow = if self.follow {
+            "following"
+        } else {
+            "paused, F to follow"
+        };
+        let mut status = format!("{count} {noun} · {follow}");
+        let query = self.query();
+        if !query.is_empty() {
+            let matching = (0..count)
+                .filter(|index| self.line_matches(*index, query.as_str()))
+                .collect::<Vec<_>>();
+            match self
+                .current_match
+                .and_then(|line| matching.iter().position(|index| *index == line))
+            {
+                Some(position) => {
+                    status.push_str(&format!(" · match {}/{}", position + 1, matching.len()))
+                }
+                None if matching.is_empty() => status.push_str(" · no matches"),
+                None => status.push_str(&format!(" · {} matches", matching.len())),
+            }
+        }
+        status
+    }
+
+    fn show_search_line(&self) -> bool {
+        self.searching || !self.query().is_empty()
+    }
+}
+
+/// Character ranges of every case-insensitive occurrence of `query`.
+fn match_ranges(query: &str, text: &str) -> Vec<(usize, usize)> {
+    let query = query
+        .chars()
+        .map(|ch| ch.to_ascii_lowercase())
+        .collect::<Vec<_>>();
+    let text = text
+        .chars()
+        .map(|ch| ch.to_ascii_lowercase())
+        .collect::<Vec<_>>();
+    if query.is_empty() || query.len() > text.len() {
+        return Vec::new();
+    }
+    let mut ranges = Vec::new();
+    let mut start = 0;
+    while start + query.len() <= text.len() {
+        if text[start..start + query.len()] == query[..] {
+            ranges.push((start, start + query.len()));
+            start += query.len();
+        } else {
+            start += 1;
+        }
+    }
+    ranges
+}
+
+impl LeafComponent for LogOutput {}
+
+impl Drawable for LogOutput {
+    fn id(&self) -> &str {
+        self.base.id()
+    }
+
+    fn draw(&self, ctx: &RenderContext) -> DrawOutput {
+        let focused = self.base.is_focused(ctx);
+        let dim = StyleRole::Hint.style();
+        let mut lines = Vec::new();
+
+        let mut header = Vec::new();
+        if !self.base.label().is_empty() {
+            header.push(
+                Span::styled(
+                    ctx.translate(self.base.label()),
+                    Style::new().color(Color::White).bold(),
+                )
+                .no_wrap(),
+            );
+            header.push(Span::new("  ").no_wrap());
+        }
+        header.push(Span::styled(self.status_text(), dim).no_wrap());
+        lines.push(header);
+
+        if self.show_search_line() {
+            let search_ctx =
+                ctx.with_focus((focused && self.searching).then(|| self.search.id().to_string()));
+            let mut line = vec![Span::styled("/", Style::new().color(Color::Cyan)).no_wrap()];
+            line.extend(
+                self.search
+                    .draw(&search_ctx)
+                    .lines
+                    .into_iter()
+                    .next()
+                    .unwrap_or_default(),
+            );
+            lines.push(line);
+        }
+
+        if self.lines.is_empty() {
+            lines.push(vec![Span::styled("  No output yet", dim).no_wrap()]);
+            return DrawOutput::with_lines(lines);
+        }
+
+        let (start, end) = self.range_at(self.drawn_offset(Instant::now()));
+        if start > 0 {
+            lines.push(vec![
+                Span::styled(format!("  ↑ {start} more"), dim).no_wrap(),
+            ]);
+        }
+        let query = self.query();
+        let highlight = Style::new().color(Color::Black).background(Color::Yellow);
+        for index in start..end {
+            let line = &self.lines[index];
+            let marker = if self.current_match == Some(index) {
+                Span::styled("❯ ", Style::new().color(Color::Cyan)).no_wrap()
+            } else {
+                Span::new("  ").no_wrap()
+            };
+            let mut spans = line.spans.clone();
+            highlight_span_line(
+                &mut spans,
+                match_ranges(query.as_str(), line.text.as_str()).as_slice(),
+                highlight,
+            );
+            let mut rendered = vec![marker];
+            rendered.extend(spans);
+            lines.push(rendered);
+        }
+        let below = self.lines.len() - end;
+        if below > 0 {
+            lines.push(vec![
+                Span::styled(format!("  ↓ {below} more"), dim).no_wrap(),
+            ]);
+        }
+        DrawOutput::with_lines(lines)
+    }
+
+    fn hints(&self, ctx: HintContext) -> Vec<HintItem> {
+        if self.searching {
+            focused_static_hints(ctx, LOG_OUTPUT_SEARCH_HINTS)
+        } else {
+            focused_static_hints(ctx, LOG_OUTPUT_HINTS)
+        }
+    }
+}
+
+impl Interactive for LogOutput {
+    fn focus_mode(&self) -> FocusMode {
+        FocusMode::Leaf
+    }
+
+    fn on_key(&mut self, key: KeyEvent) -> InteractionResult {
+        if self.searching {
+            return self.handle_search_key(key);
+        }
+        let page = self.visible_lines as isize;
+        match key.code {
+            KeyCode::Up => InteractionResult::handled_if(self.scroll(-1)),
+            KeyCode::Down => InteractionResult::handled_if(self.scroll(1)),
+            KeyCode::PageUp => InteractionResult::handled_if(self.scroll(-page)),
+            KeyCode::PageDown => InteractionResult::handled_if(self.scroll(page)),
+            KeyCode::Home => {
+                InteractionResult::handled_if(self.scroll(-(self.lines.len() as isize)))
+            }
+            KeyCode::End => {
+                self.set_follow(true);
+                InteractionResult::handled()
+            }
+            KeyCode::Char('f' | 'F') if keymap::is_typed_char(key) => {
+                self.set_follow(!self.follow);
+                InteractionResult::handled()
+            }
+            KeyCode::Char('/') if keymap::is_typed_char(key) => {
+                self.searching = true;
+                InteractionResult::handled()
+            }
+            KeyCode::Char('n') if keymap::is_typed_char(key) => {
+                InteractionResult::handled_if(self.next_match(true))
+            }
+            KeyCode::Char('N') if keymap::is_typed_char(key) => {
+                InteractionResult::handled_if(self.next_match(false))
+            }
+            KeyCode::Esc if !self.query().is_empty() => {
+                self.close_search(false);
+                InteractionResult::handled()
+            }
+            _ => InteractionResult::ignored(),
+        }
+    }
+
+    fn on_pointer(&mut self, event: PointerEvent) -> InteractionResult {
+        match event.kind.wheel_delta() {
+            Some(delta) => InteractionResult::handled_if(self.scroll(delta)),
+            None => InteractionResult::ignored(),
+        }
+    }
+
+    fn on_text_action(&mut self, action: TextAction) -> InteractionResult {
+        if !self.searching {
+            return InteractionResult::ignored();
+        }
+        let before = self.query();
+        let result = self.search.on_text_action(action);
+        self.search_edited(result, before.as_str())
+    }
+
+    fn on_paste(&mut self, text: &str) -> InteractionResult {
+        if !self.searching {
+            return InteractionResult::ignored();
+        }
+        let before = self.query();
+        let result = self.search.on_paste(text);
+        self.search_edited(result, before.as_str())
+    }
+
+    fn on_system_event(&mut self, event: &SystemEvent) -> InteractionResult {
+        match event {
+            SystemEvent::TaskLogLine { task_id, line, .. } if self.tasks.contains(task_id) => {
+                self.push_line(line.as_str());
+                InteractionResult::handled()
+            }
+            _ => InteractionResult::ignored(),
+        }
+    }
+
+    fn on_tick(&mut self) -> InteractionResult {
+        InteractionResult::handled_if(self.scrolling.tick(Instant::now()))
+    }
+
+    fn wants_tick(&self) -> bool {
+        self.scrolling.is_running()
+    }
+
+    fn cursor_pos(&self) -> Option<CursorPos> {
+        if !self.searching {
+            return None;
+        }
+        let cursor = self.search.cursor_pos()?;
+        Some(CursorPos {
+            col: cursor.col.saturating_add(1),
+            row: 1,
+        })
+    }
+}
+
+#[cfg(test)]
+#[path = "../tests/log.rs"]
+mod tests;
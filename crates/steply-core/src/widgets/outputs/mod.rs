@@ -1,8 +1,11 @@
 pub mod chart;
 pub mod data;
 pub mod diff;
+pub mod log;
 pub mod progress;
+pub mod spinner;
 pub mod table;
+pub mod task_graph;
 pub mod task_log;
 pub mod text;
 pub mod thinking;
@@ -1,15 +1,13 @@
 use crate::core::value::Value;
+use crate::runtime::event::SystemEvent;
+use crate::task::TaskId;
 use crate::time::{Duration, Instant};
+use crate::ui::animation::Tween;
 use crate::ui::span::Span;
-use crate::ui::style::{Color, Style};
+use crate::ui::style::{Color, Style, StyleRole};
 use crate::widgets::traits::{DrawOutput, Drawable, InteractionResult, OutputNode, RenderContext};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum Easing {
-    Linear,
-    OutQuad,
-    OutCubic,
-}
+pub use crate::ui::animation::Easing;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ProgressTransition {
@@ -23,15 +21,6 @@ pub enum ProgressStyle {
     BlockClassic,
 }
 
-#[derive(Debug, Clone, Copy)]
-struct ProgressAnimation {
-    from: f64,
-    to: f64,
-    started_at: Instant,
-    duration: Duration,
-    easing: Easing,
-}
-
 pub struct ProgressOutput {
     id: String,
     label: String,
@@ -42,8 +31,11 @@ pub struct ProgressOutput {
     target_value: f64,
     display_value: f64,
     transition: ProgressTransition,
-    animation: Option<ProgressAnimation>,
+    animation: Option<Tween>,
     style: ProgressStyle,
+    task_id: Option<TaskId>,
+    /// When the current run began, for the remaining time estimate.
+    started_at: Option<Instant>,
 }
 
 impl ProgressOutput {
@@ -63,6 +55,8 @@ impl ProgressOutput {
             },
             animation: None,
             style: ProgressStyle::ClassicLine,
+            task_id: None,
+            started_at: None,
         }
     }
 
@@ -103,6 +97,12 @@ impl ProgressOutput {
         self
     }
 
+    /// Follows progress reported by `task_id`, resetting when a new run starts.
+    pub fn with_task(mut self, task_id: impl Into<TaskId>) -> Self {
+        self.task_id = Some(task_id.into());
+        self
+    }
+
     fn clamp(&self, value: f64) -> f64 {
         value.clamp(self.min, self.max)
     }
@@ -137,14 +137,14 @@ impl ProgressOutput {
     fn set_target(&mut self, target: f64) {
         let target = self.clamp(target);
         self.target_value = target;
+        if target > self.min {
+            self.started_at.get_or_insert_with(Instant::now);
+        }
 
         if let Some(animation) = self.animation {
-            let elapsed = animation.started_at.elapsed();
-            let duration = animation.duration.as_secs_f64().max(f64::EPSILON);
-            let t = (elapsed.as_secs_f64() / duration).clamp(0.0, 1.0);
-            let eased = apply_easing(t, animation.easing);
-            self.display_value = animation.from + (animation.to - animation.from) * eased;
-            if t >= 1.0 {
+            let now = Instant::now();
+            self.display_value = animation.value_at(now);
+            if animation.is_done(now) {
                 self.animation = None;
             }
         }
@@ -165,7 +165,7 @@ impl ProgressOutput {
         }
     }
 
-    fn transition(&self) -> Option<ProgressAnimation> {
+    fn transition(&self) -> Option<Tween> {
         let ProgressTransition::Tween {
             duration_ms,
             easing,
@@ -173,13 +173,27 @@ impl ProgressOutput {
         else {
             return None;
         };
-        Some(ProgressAnimation {
-            from: self.display_value,
-            to: self.target_value,
-            started_at: Instant::now(),
-            duration: Duration::from_millis(duration_ms.max(1)),
+        Some(Tween::new(
+            self.display_value,
+            self.target_value,
+            Duration::from_millis(duration_ms.max(1)),
             easing,
-        })
+            Instant::now(),
+        ))
+    }
+
+    /// Remaining time at the pace so far; `None` before any progress, once
+    /// done, or within the first second when the pace means little.
+    fn eta(&self) -> Option<Duration> {
+        let ratio = self.ratio(self.target_value);
+        if ratio <= 0.0 || ratio >= 1.0 {
+            return None;
+        }
+        let elapsed = self.started_at?.elapsed().as_secs_f64();
+        if elapsed < 1.0 {
+            return None;
+        }
+        Some(Duration::from_secs_f64(elapsed * (1.0 - ratio) / ratio))
     }
 
     fn glyphs(&self) -> (char, char) {
@@ -209,12 +223,15 @@ impl Drawable for ProgressOutput {
             Span::new("[").no_wrap(),
             Span::styled(
                 filled_glyph.to_string().repeat(filled),
-                Style::new().color(value_color).bold(),
+                Style {
+                    role: Some(StyleRole::Progress),
+                    ..Style::new().color(value_color).bold()
+                },
             )
             .no_wrap(),
             Span::styled(
                 empty_glyph.to_string().repeat(empty),
-                Style::new().color(Color::DarkGrey),
+                StyleRole::Hint.style(),
             )
             .no_wrap(),
             Span::new("] ").no_wrap(),
@@ -226,7 +243,7 @@ impl Drawable for ProgressOutput {
             Span::new("  ").no_wrap(),
             Span::styled(
                 format!("target {:.1}", self.target_value),
-                Style::new().color(Color::DarkGrey),
+                StyleRole::Hint.style(),
             )
             .no_wrap(),
             Span::new("  ").no_wrap(),
@@ -236,6 +253,17 @@ impl Drawable for ProgressOutput {
             )
             .no_wrap(),
         ]);
+        if let Some(eta) = self.eta()
+            && let Some(bar) = lines.last_mut()
+        {
+            bar.push(
+                Span::styled(
+                    format!("  eta {}", format_eta(eta)),
+                    StyleRole::Hint.style(),
+                )
+                .no_wrap(),
+            );
+        }
 
         DrawOutput::with_lines(lines)
     }
@@ -255,6 +283,7 @@ impl OutputNode for ProgressOutput {
         }
         if matches!(value, Value::None) {
             self.set_target(self.min);
+            self.started_at = None;
         }
     }
 
@@ -267,27 +296,54 @@ impl OutputNode for ProgressOutput {
             return InteractionResult::ignored();
         };
 
-        let elapsed = animation.started_at.elapsed();
-        let duration = animation.duration.as_secs_f64().max(f64::EPSILON);
-        let t = (elapsed.as_secs_f64() / duration).clamp(0.0, 1.0);
-        let eased = apply_easing(t, animation.easing);
-        let next = animation.from + (animation.to - animation.from) * eased;
-        self.display_value = if t >= 1.0 { animation.to } else { next };
-        if t >= 1.0 {
+        let now = Instant::now();
+        self.display_value = animation.value_at(now);
+        if animation.is_done(now) {
             self.animation = None;
         }
         InteractionResult::handled()
     }
 
+    fn wants_tick(&self) -> bool {
+        self.animation.is_some() && !matches!(self.transition, ProgressTransition::Immediate)
+    }
+
     fn value(&self) -> Option<Value> {
         Some(Value::Number(self.target_value))
     }
+
+    fn on_system_event(&mut self, event: &SystemEvent) -> InteractionResult {
+        let Some(watched) = self.task_id.as_ref() else {
+            return InteractionResult::ignored();
+        };
+        match event {
+            SystemEvent::TaskStarted { task_id, .. } if task_id == watched => {
+                self.display_value = self.min;
+                self.animation = None;
+                self.target_value = self.min;
+                self.started_at = Some(Instant::now());
+                InteractionResult::handled()
+            }
+            SystemEvent::TaskProgress {
+                task_id, progress, ..
+            } if task_id == watched => {
+                self.set_target(*progress);
+                InteractionResult::handled()
+            }
+            _ => InteractionResult::ignored(),
+        }
+    }
 }
 
-fn apply_easing(t: f64, easing: Easing) -> f64 {
-    match easing {
-        Easing::Linear => t,
-        Easing::OutQuad => 1.0 - (1.0 - t) * (1.0 - t),
-        Easing::OutCubic => 1.0 - (1.0 - t).powi(3),
+fn format_eta(eta: Duration) -> String {
+    let secs = eta.as_secs();
+    match secs {
+        0..60 => format!("{secs}s"),
+        60..3600 => format!("{}m{:02}s", secs / 60, secs % 60),
+        _ => format!("{}h{:02}m", secs / 3600, secs % 3600 / 60),
     }
 }
+
+#[cfg(test)]
+#[path = "tests/progress.rs"]
+mod tests;
@@ -0,0 +1,151 @@
+use crate::runtime::event::SystemEvent;
+use crate::task::TaskId;
+use crate::time::{Duration, Instant};
+use crate::ui::span::Span;
+use crate::ui::spinner::{Spinner, SpinnerStyle};
+use crate::ui::style::{Color, Style, StyleRole};
+use crate::widgets::traits::{DrawOutput, Drawable, InteractionResult, OutputNode, RenderContext};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SpinnerState {
+    Waiting,
+    Running,
+    Done,
+    Failed,
+}
+
+/// A spinner beside a label for work without measurable progress. Without
+/// a task it spins for as long as it is shown; with one it follows the
+/// task's runs and ends in a check mark or a cross.
+pub struct SpinnerOutput {
+    id: String,
+    label: String,
+    spinner: Spinner,
+    task_id: Option<TaskId>,
+    state: SpinnerState,
+    started_at: Option<Instant>,
+    finished_secs: Option<f64>,
+    tick_interval: Duration,
+    last_tick: Instant,
+}
+
+impl SpinnerOutput {
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            spinner: Spinner::default(),
+            task_id: None,
+            state: SpinnerState::Running,
+            started_at: None,
+            finished_secs: None,
+            tick_interval: Duration::from_millis(80),
+            last_tick: Instant::now(),
+        }
+    }
+
+    pub fn with_spinner_style(mut self, style: SpinnerStyle) -> Self {
+        self.spinner = Spinner::new(style);
+        self
+    }
+
+    /// Spins while `task_id` runs, waiting for its first run until then.
+    pub fn with_task(mut self, task_id: impl Into<TaskId>) -> Self {
+        self.task_id = Some(task_id.into());
+        self.state = SpinnerState::Waiting;
+        self
+    }
+
+    fn finish(&mut self, succeeded: bool) {
+        self.state = if succeeded {
+            SpinnerState::Done
+        } else {
+            SpinnerState::Failed
+        };
+        self.finished_secs = self.started_at.map(|t| t.elapsed().as_secs_f64());
+    }
+
+    fn is_watched(&self, task_id: &TaskId) -> bool {
+        self.task_id.as_ref() == Some(task_id)
+    }
+}
+
+impl Drawable for SpinnerOutput {
+    fn id(&self) -> &str {
+        self.id.as_str()
+    }
+
+    fn label(&self) -> &str {
+        self.label.as_str()
+    }
+
+    fn draw(&self, _ctx: &RenderContext) -> DrawOutput {
+        let dim = StyleRole::Hint.style();
+        let (marker, elapsed) = match self.state {
+            SpinnerState::Waiting => (Span::styled("○", dim).no_wrap(), None),
+            SpinnerState::Running => (
+                self.spinner.span(),
+                self.started_at.map(|t| t.elapsed().as_secs_f64()),
+            ),
+            SpinnerState::Done => (
+                Span::styled("✓", Style::new().color(Color::Green).bold()).no_wrap(),
+                self.finished_secs,
+            ),
+            SpinnerState::Failed => (
+                Span::styled("✗", Style::new().color(Color::Red).bold()).no_wrap(),
+                self.finished_secs,
+            ),
+        };
+        let mut line = vec![marker];
+        if !self.label.is_empty() {
+            line.push(Span::new(format!(" {}", self.label)).no_wrap());
+        }
+        if let Some(secs) = elapsed {
+            line.push(Span::styled(format!("  {secs:.1}s"), dim).no_wrap());
+        }
+        DrawOutput::with_lines(vec![line])
+    }
+}
+
+impl OutputNode for SpinnerOutput {
+    fn on_tick(&mut self) -> InteractionResult {
+        if self.state != SpinnerState::Running {
+            return InteractionResult::ignored();
+        }
+        let now = Instant::now();
+        if now.duration_since(self.last_tick) < self.tick_interval {
+            return InteractionResult::ignored();
+        }
+        self.last_tick = now;
+        self.spinner.tick();
+        InteractionResult::handled()
+    }
+
+    fn wants_tick(&self) -> bool {
+        self.state == SpinnerState::Running
+    }
+
+    fn on_system_event(&mut self, event: &SystemEvent) -> InteractionResult {
+        match event {
+            SystemEvent::TaskStarted { task_id, .. } if self.is_watched(task_id) => {
+                self.state = SpinnerState::Running;
+                self.started_at = Some(Instant::now());
+                self.finished_secs = None;
+                InteractionResult::handled()
+            }
+            SystemEvent::TaskStartRejected { task_id, .. } if self.is_watched(task_id) => {
+                self.finish(false);
+                InteractionResult::handled()
+            }
+            SystemEvent::TaskCompleted { completion } if self.is_watched(&completion.task_id) => {
+                self.finish(completion.error.is_none() && !completion.cancelled);
+                InteractionResult::handled()
+            }
+            _ => InteractionResult::ignored(),
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/spinner.rs"]
+mod tests;
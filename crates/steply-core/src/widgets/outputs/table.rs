@@ -1,7 +1,7 @@
 use crate::core::value::Value;
 use crate::ui::layout::Layout;
 use crate::ui::span::Span;
-use crate::ui::style::{Color, Style};
+use crate::ui::style::{Color, Style, StyleRole};
 use crate::widgets::traits::{DrawOutput, Drawable, OutputNode, RenderContext};
 use unicode_width::UnicodeWidthStr;
 
@@ -183,7 +183,7 @@ impl Drawable for TableOutput {
                 lines.push(vec![Span::new(self.label.clone()).no_wrap()]);
             }
             lines.push(vec![
-                Span::styled("No rows", Style::new().color(Color::DarkGrey)).no_wrap(),
+                Span::styled("No rows", StyleRole::Hint.style()).no_wrap(),
             ]);
             return DrawOutput::with_lines(lines);
         }
@@ -296,12 +296,12 @@ fn border_line(left: char, sep: char, right: char, widths: &[usize]) -> Vec<Span
         }
     }
     text.push(right);
-    vec![Span::styled(text, Style::new().color(Color::DarkGrey)).no_wrap()]
+    vec![Span::styled(text, StyleRole::Hint.style()).no_wrap()]
 }
 
 fn grid_row(cells: Vec<Vec<Span>>, widths: &[usize]) -> Vec<Span> {
     let mut line = Vec::<Span>::new();
-    line.push(Span::styled("│", Style::new().color(Color::DarkGrey)).no_wrap());
+    line.push(Span::styled("│", StyleRole::Border.style()).no_wrap());
     for (idx, width) in widths.iter().enumerate() {
         line.push(Span::new(" ").no_wrap());
         let cell = cells
@@ -313,7 +313,7 @@ fn grid_row(cells: Vec<Vec<Span>>, widths: &[usize]) -> Vec<Span> {
             (*width).min(u16::MAX as usize) as u16,
         ));
         line.push(Span::new(" ").no_wrap());
-        line.push(Span::styled("│", Style::new().color(Color::DarkGrey)).no_wrap());
+        line.push(Span::styled("│", StyleRole::Border.style()).no_wrap());
     }
     line
 }
@@ -322,7 +322,7 @@ fn clean_row(cells: Vec<Vec<Span>>, widths: &[usize]) -> Vec<Span> {
     let mut line = Vec::<Span>::new();
     for (idx, width) in widths.iter().enumerate() {
         if idx > 0 {
-            line.push(Span::styled("  ", Style::new().color(Color::DarkGrey)).no_wrap());
+            line.push(Span::styled("  ", StyleRole::Border.style()).no_wrap());
         }
         let cell = cells
             .get(idx)
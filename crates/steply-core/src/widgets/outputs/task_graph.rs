@@ -0,0 +1,162 @@
+use crate::core::value::Value;
+use crate::runtime::event::SystemEvent;
+use crate::task::{TaskGraphEntry, TaskId, TaskStatus};
+use crate::ui::span::Span;
+use crate::ui::spinner::{Spinner, SpinnerStyle};
+use crate::ui::style::{Color, Style, StyleRole};
+use crate::widgets::traits::{DrawOutput, Drawable, InteractionResult, OutputNode, RenderContext};
+use std::collections::HashSet;
+
+/// Live status of tasks linked by `depends_on`, one line per task with the
+/// tasks it waits for and, once it has been retried, its attempt.
+pub struct TaskGraphOutput {
+    id: String,
+    label: String,
+    tasks: Vec<TaskId>,
+    spinner: Spinner,
+    running: HashSet<TaskId>,
+}
+
+impl TaskGraphOutput {
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            tasks: Vec::new(),
+            spinner: Spinner::new(SpinnerStyle::Braille),
+            running: HashSet::new(),
+        }
+    }
+
+    /// Shows only these tasks, in this order, instead of every task that
+    /// takes part in a dependency.
+    pub fn with_tasks(mut self, tasks: impl IntoIterator<Item = impl Into<TaskId>>) -> Self {
+        self.tasks = tasks.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn with_spinner_style(mut self, style: SpinnerStyle) -> Self {
+        self.spinner = Spinner::new(style);
+        self
+    }
+
+    fn entries<'a>(&self, ctx: &'a RenderContext) -> Vec<&'a TaskGraphEntry> {
+        let graph = ctx.task_graph.as_ref();
+        if !self.tasks.is_empty() {
+            return self
+                .tasks
+                .iter()
+                .filter_map(|task_id| graph.get(task_id.as_str()))
+                .collect();
+        }
+        let linked = graph
+            .entries()
+            .iter()
+            .flat_map(|entry| entry.depends_on.iter())
+            .collect::<HashSet<_>>();
+        graph
+            .entries()
+            .iter()
+            .filter(|entry| !entry.depends_on.is_empty() || linked.contains(&entry.id))
+            .collect()
+    }
+
+    fn status_span(&self, status: TaskStatus) -> Span {
+        match status {
+            TaskStatus::Pending => Span::styled("○", StyleRole::Hint.style()).no_wrap(),
+            TaskStatus::Running => self.spinner.span(),
+            TaskStatus::Retrying => Span::styled("↻", Style::new().color(Color::Yellow)).no_wrap(),
+            TaskStatus::Done => {
+                Span::styled("✓", Style::new().color(Color::Green).bold()).no_wrap()
+            }
+            TaskStatus::Failed => {
+                Span::styled("✗", Style::new().color(Color::Red).bold()).no_wrap()
+            }
+            TaskStatus::Skipped => Span::styled("↷", StyleRole::Hint.style()).no_wrap(),
+            TaskStatus::Blocked => Span::styled("⊘", Style::new().color(Color::Yellow)).no_wrap(),
+        }
+    }
+}
+
+impl Drawable for TaskGraphOutput {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn draw(&self, ctx: &RenderContext) -> DrawOutput {
+        let entries = self.entries(ctx);
+        let width = entries
+            .iter()
+            .map(|entry| entry.id.as_str().chars().count())
+            .max()
+            .unwrap_or(0);
+        let dim = StyleRole::Hint.style();
+
+        let mut lines = Vec::with_capacity(entries.len() + 1);
+        if !self.label.is_empty() {
+            lines.push(vec![
+                Span::new(ctx.translate(self.label.as_str())).no_wrap(),
+            ]);
+        }
+        for entry in entries {
+            // A retrying task shows the attempt it is about to make.
+            let attempt = entry.attempt + u32::from(entry.status == TaskStatus::Retrying);
+            let attempt = (attempt > 1).then(|| format!(" ({attempt}/{})", entry.max_attempts));
+            let id = if entry.depends_on.is_empty() {
+                entry.id.to_string()
+            } else {
+                format!("{:<width$}", entry.id.as_str())
+            };
+            let mut line = vec![
+                self.status_span(entry.status),
+                Span::new(" ").no_wrap(),
+                Span::styled(id, Style::new().color(Color::White)).no_wrap(),
+            ];
+            if !entry.depends_on.is_empty() {
+                let depends_on = entry
+                    .depends_on
+                    .iter()
+                    .map(TaskId::as_str)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                line.push(Span::styled(format!("  ← {depends_on}"), dim).no_wrap());
+            }
+            if let Some(attempt) = attempt {
+                line.push(Span::styled(attempt, Style::new().color(Color::Yellow)).no_wrap());
+            }
+            lines.push(line);
+        }
+        DrawOutput::with_lines(lines)
+    }
+}
+
+impl OutputNode for TaskGraphOutput {
+    fn on_tick(&mut self) -> InteractionResult {
+        if self.running.is_empty() {
+            return InteractionResult::ignored();
+        }
+        self.spinner.tick();
+        InteractionResult::handled()
+    }
+
+    fn wants_tick(&self) -> bool {
+        !self.running.is_empty()
+    }
+
+    fn on_system_event(&mut self, event: &SystemEvent) -> InteractionResult {
+        match event {
+            SystemEvent::TaskStarted { task_id, .. } => {
+                self.running.insert(task_id.clone());
+                InteractionResult::handled()
+            }
+            SystemEvent::TaskCompleted { completion } => {
+                InteractionResult::handled_if(self.running.remove(&completion.task_id))
+            }
+            _ => InteractionResult::ignored(),
+        }
+    }
+
+    fn value(&self) -> Option<Value> {
+        None
+    }
+}
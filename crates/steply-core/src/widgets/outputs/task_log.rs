@@ -1,10 +1,11 @@
 use crate::core::value::Value;
 use crate::runtime::event::{SystemEvent, WidgetAction};
 use crate::task::{TaskId, TaskRequest};
+use crate::terminal::PointerEvent;
 use crate::time::Instant;
 use crate::ui::span::Span;
 use crate::ui::spinner::SpinnerStyle;
-use crate::ui::style::{Color, Style};
+use crate::ui::style::{Color, Style, StyleRole};
 use crate::widgets::shared::task_watcher::{TaskWatcherState, TaskWatcherStatus};
 use crate::widgets::traits::{DrawOutput, Drawable, InteractionResult, OutputNode, RenderContext};
 
@@ -36,6 +37,7 @@ struct StepState {
     status: TaskStepStatus,
     started_at: Option<Instant>,
     elapsed_secs: Option<f64>,
+    exit_code: Option<i32>,
 }
 
 pub struct TaskLog {
@@ -43,6 +45,9 @@ pub struct TaskLog {
     steps: Vec<StepState>,
     active: usize,
     watcher: TaskWatcherState,
+    keep_logs: bool,
+    show_exit_status: bool,
+    scrollbar: bool,
 }
 
 impl TaskLog {
@@ -55,6 +60,7 @@ impl TaskLog {
                 status: TaskStepStatus::Pending,
                 started_at: None,
                 elapsed_secs: None,
+                exit_code: None,
             })
             .collect();
         Self {
@@ -62,6 +68,9 @@ impl TaskLog {
             steps,
             active: 0,
             watcher: TaskWatcherState::new(5, SpinnerStyle::Braille),
+            keep_logs: false,
+            show_exit_status: false,
+            scrollbar: false,
         }
     }
 
@@ -79,6 +88,34 @@ impl TaskLog {
         self
     }
 
+    /// Lines kept for scrolling back; only `visible_lines` are drawn at once.
+    pub fn with_history_lines(mut self, n: usize) -> Self {
+        self.watcher.set_history_lines(n);
+        self
+    }
+
+    /// Keeps the output on screen after a successful run instead of hiding it.
+    pub fn with_keep_logs(mut self, keep: bool) -> Self {
+        self.keep_logs = keep;
+        self
+    }
+
+    pub fn with_exit_status(mut self, show: bool) -> Self {
+        self.show_exit_status = show;
+        self
+    }
+
+    /// Draws a scrollbar beside the output instead of the `↑ 3 more`
+    /// markers.
+    pub fn with_scrollbar(mut self, scrollbar: bool) -> Self {
+        self.scrollbar = scrollbar;
+        self
+    }
+
+    pub fn scroll_logs(&mut self, delta: isize) -> bool {
+        self.watcher.scroll(delta)
+    }
+
     pub fn status(&self) -> TaskWatcherStatus {
         self.watcher.status()
     }
@@ -90,6 +127,7 @@ impl TaskLog {
             status: TaskStepStatus::Pending,
             started_at: None,
             elapsed_secs: None,
+            exit_code: None,
         });
     }
 
@@ -125,6 +163,7 @@ impl TaskLog {
             step.status = TaskStepStatus::Pending;
             step.started_at = None;
             step.elapsed_secs = None;
+            step.exit_code = None;
             let task_id = step.task_id.clone();
             self.watcher.request_start();
             Some(TaskRequest::new(task_id))
@@ -151,10 +190,20 @@ impl TaskLog {
         self.watcher.mark_rejected(reason.to_string());
     }
 
+    fn exit_status_span(&self, step: &StepState) -> Option<Span> {
+        let code = step.exit_code.filter(|_| self.show_exit_status)?;
+        let color = if code == 0 {
+            Color::DarkGrey
+        } else {
+            Color::Red
+        };
+        Some(Span::styled(format!("  exit {code}"), Style::new().color(color)).no_wrap())
+    }
+
     fn render_step_line(&self, step: &StepState, index: usize, total: usize) -> Vec<Span> {
         let counter = format!("[{}/{}]", index + 1, total);
         let show_counter = total > 1;
-        let dim = Style::new().color(Color::DarkGrey);
+        let dim = StyleRole::Hint.style();
         let normal = Style::new().color(Color::White);
 
         match step.status {
@@ -203,6 +252,7 @@ impl TaskLog {
                 line.push(Span::new(" ").no_wrap());
                 line.push(Span::styled(step.label.clone(), normal).no_wrap());
                 line.push(Span::styled(elapsed, dim).no_wrap());
+                line.extend(self.exit_status_span(step));
                 line
             }
             TaskStepStatus::Error => {
@@ -219,6 +269,7 @@ impl TaskLog {
                 line.push(Span::new(" ").no_wrap());
                 line.push(Span::styled(step.label.clone(), normal).no_wrap());
                 line.push(Span::styled(elapsed, dim).no_wrap());
+                line.extend(self.exit_status_span(step));
                 line
             }
         }
@@ -230,7 +281,7 @@ impl Drawable for TaskLog {
         &self.id
     }
 
-    fn draw(&self, _ctx: &RenderContext) -> DrawOutput {
+    fn draw(&self, ctx: &RenderContext) -> DrawOutput {
         let total = self.steps.len();
         let single_mode = total == 1 && self.steps[0].label.is_empty();
         let mut lines = Vec::new();
@@ -253,25 +304,59 @@ impl Drawable for TaskLog {
                     }
                 }
                 TaskStepStatus::Running => {}
-                TaskStepStatus::Done => lines.push(vec![
-                    Span::styled("✓", Style::new().color(Color::Green).bold()).no_wrap(),
-                    Span::new(" Done").no_wrap(),
-                ]),
-                TaskStepStatus::Error => lines.push(vec![
-                    Span::styled("✗", Style::new().color(Color::Red).bold()).no_wrap(),
-                    Span::new(" Failed").no_wrap(),
-                ]),
+                TaskStepStatus::Done => lines.push(
+                    [
+                        Span::styled("✓", Style::new().color(Color::Green).bold()).no_wrap(),
+                        Span::new(" Done").no_wrap(),
+                    ]
+                    .into_iter()
+                    .chain(self.exit_status_span(step))
+                    .collect(),
+                ),
+                TaskStepStatus::Error => lines.push(
+                    [
+                        Span::styled("✗", Style::new().color(Color::Red).bold()).no_wrap(),
+                        Span::new(" Failed").no_wrap(),
+                    ]
+                    .into_iter()
+                    .chain(self.exit_status_span(step))
+                    .collect(),
+                ),
             }
         }
 
         let show_logs = self.active_step().is_some_and(|s| {
-            s.status == TaskStepStatus::Running || s.status == TaskStepStatus::Error
+            s.status == TaskStepStatus::Running
+                || s.status == TaskStepStatus::Error
+                || (self.keep_logs && s.status == TaskStepStatus::Done)
         });
-        if show_logs {
-            for line in self.watcher.logs() {
+        let scrollbar = self.scrollbar.then(|| self.watcher.scrollbar()).flatten();
+        if show_logs && let Some(scrollbar) = scrollbar {
+            let dim = StyleRole::Hint.style();
+            let mut log_lines = self
+                .watcher
+                .visible_logs()
+                .map(|line| vec![Span::styled(format!("  {line}"), dim).no_wrap()])
+                .collect::<Vec<_>>();
+            scrollbar.attach(
+                &mut log_lines,
+                ctx.terminal_size.width.saturating_sub(1).max(1),
+            );
+            lines.extend(log_lines);
+        } else if show_logs {
+            let dim = StyleRole::Hint.style();
+            let (above, below) = self.watcher.hidden_log_lines();
+            if above > 0 {
+                lines.push(vec![
+                    Span::styled(format!("  ↑ {above} more"), dim).no_wrap(),
+                ]);
+            }
+            for line in self.watcher.visible_logs() {
+                lines.push(vec![Span::styled(format!("  {line}"), dim).no_wrap()]);
+            }
+            if below > 0 {
                 lines.push(vec![
-                    Span::styled(format!("  {line}"), Style::new().color(Color::DarkGrey))
-                        .no_wrap(),
+                    Span::styled(format!("  ↓ {below} more"), dim).no_wrap(),
                 ]);
             }
         }
@@ -281,6 +366,13 @@ impl Drawable for TaskLog {
 }
 
 impl OutputNode for TaskLog {
+    fn on_pointer(&mut self, event: PointerEvent) -> InteractionResult {
+        match event.kind.wheel_delta() {
+            Some(delta) => InteractionResult::handled_if(self.scroll_logs(delta)),
+            None => InteractionResult::ignored(),
+        }
+    }
+
     fn on_tick(&mut self) -> InteractionResult {
         if self.watcher.tick() {
             return InteractionResult::handled();
@@ -288,6 +380,10 @@ impl OutputNode for TaskLog {
         InteractionResult::ignored()
     }
 
+    fn wants_tick(&self) -> bool {
+        self.watcher.is_running()
+    }
+
     fn on_system_event(&mut self, event: &SystemEvent) -> InteractionResult {
         match event {
             SystemEvent::TaskStarted { task_id, run_id } => {
@@ -333,6 +429,9 @@ impl OutputNode for TaskLog {
                 if !self.watcher.mark_completed(completion.run_id, succeeded) {
                     return InteractionResult::ignored();
                 }
+                if let Some(step) = self.active_step_mut() {
+                    step.exit_code = completion.exit_code;
+                }
 
                 if let Some(request) = self.advance(succeeded) {
                     return InteractionResult::with_action(WidgetAction::TaskRequested { request });
@@ -361,6 +460,7 @@ impl TaskLog {
             step.status = TaskStepStatus::Pending;
             step.started_at = None;
             step.elapsed_secs = None;
+            step.exit_code = None;
         }
         self.watcher.request_start();
         Some(TaskRequest::new(self.steps[0].task_id.clone()))
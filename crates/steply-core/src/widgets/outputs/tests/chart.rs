@@ -0,0 +1,45 @@
+use super::{ChartOutput, ChartRenderMode, fit_to_width};
+use crate::core::value::Value;
+use crate::terminal::TerminalSize;
+use crate::widgets::traits::{Drawable, OutputNode, RenderContext};
+
+fn rendered(chart: &ChartOutput, width: u16) -> Vec<String> {
+    let ctx = RenderContext::empty(TerminalSize { width, height: 20 });
+    chart
+        .draw(&ctx)
+        .lines
+        .iter()
+        .map(|line| line.iter().map(|span| span.text.as_str()).collect())
+        .collect()
+}
+
+#[test]
+fn more_points_than_columns_are_averaged() {
+    assert_eq!(fit_to_width(&[0.0, 1.0, 0.5, 0.5], 2), [0.5, 0.5]);
+    assert_eq!(fit_to_width(&[0.2, 0.4], 5), [0.2, 0.4]);
+}
+
+#[test]
+fn bars_stack_rows_beside_range_labels() {
+    let mut chart = ChartOutput::new("c", "Latency")
+        .with_mode(ChartRenderMode::Bars)
+        .with_height(2)
+        .with_unit("ms");
+    chart.set_value(Value::List(vec![
+        Value::Number(0.0),
+        Value::Number(5.0),
+        Value::Number(10.0),
+    ]));
+    let lines = rendered(&chart, 14);
+    assert_eq!(lines[2], "10.0ms ┤    █ ");
+    assert_eq!(lines[3], " 0.0ms ┤  █ █ ");
+}
+
+#[test]
+fn sparkline_fits_the_width() {
+    let mut chart = ChartOutput::new("c", "").with_mode(ChartRenderMode::Sparkline);
+    chart.set_value(Value::List(
+        (0..40).map(|n| Value::Number(n as f64)).collect(),
+    ));
+    assert_eq!(rendered(&chart, 10)[2].chars().count(), 10);
+}
@@ -0,0 +1,104 @@
+use super::{DiffOutput, DiffRow, GAP_REVEAL_STEP};
+use crate::core::value::Value;
+use crate::terminal::{KeyCode, KeyEvent, KeyModifiers, TerminalSize};
+use crate::widgets::traits::{Drawable, Interactive, RenderContext};
+
+fn key(code: KeyCode) -> KeyEvent {
+    KeyEvent {
+        code,
+        modifiers: KeyModifiers::NONE,
+    }
+}
+
+fn numbered(lines: usize, changed: usize) -> String {
+    (1..=lines)
+        .map(|n| {
+            if n == changed {
+                "changed\n".to_string()
+            } else {
+                format!("line {n}\n")
+            }
+        })
+        .collect()
+}
+
+fn rendered(diff: &DiffOutput) -> Vec<String> {
+    let ctx = RenderContext::empty(TerminalSize {
+        width: 60,
+        height: 30,
+    });
+    diff.draw(&ctx)
+        .lines
+        .iter()
+        .map(|line| line.iter().map(|span| span.text.as_str()).collect())
+        .collect()
+}
+
+#[test]
+fn enter_reveals_only_the_active_gap() {
+    let mut diff = DiffOutput::new("d", "", numbered(40, 20), numbered(40, 0));
+    let gaps = |diff: &DiffOutput| {
+        diff.rows
+            .iter()
+            .filter_map(|row| match row {
+                DiffRow::Gap { hidden, .. } => Some(*hidden),
+                DiffRow::Line { .. } => None,
+            })
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(gaps(&diff), [16, 17]);
+
+    assert!(diff.on_key(key(KeyCode::Enter)).handled);
+    assert_eq!(gaps(&diff), [16 - GAP_REVEAL_STEP, 17]);
+    assert!(!matches!(diff.rows[0], DiffRow::Gap { .. }));
+}
+
+#[test]
+fn u_switches_to_a_single_column_with_both_sides_of_a_change() {
+    let mut diff = DiffOutput::new("d", "", "a\nold\nc\n", "a\nnew\nc\n");
+    assert!(rendered(&diff).iter().any(|line| line.contains('│')));
+
+    let _ = diff.on_key(key(KeyCode::Char('u')));
+    let lines = rendered(&diff);
+    assert!(lines.iter().all(|line| !line.contains('│')));
+    assert!(
+        lines
+            .iter()
+            .any(|line| line.contains("- ") && line.contains("old"))
+    );
+    assert!(
+        lines
+            .iter()
+            .any(|line| line.contains("+ ") && line.contains("new"))
+    );
+    assert_eq!(lines.len(), 4);
+}
+
+#[test]
+fn space_unstages_the_active_hunk_from_the_merged_text() {
+    let mut diff =
+        DiffOutput::new("d", "Patch", "a\nb\nc\nd\ne\n", "a\nB\nc\nd\nE\n").with_review(true);
+    assert_eq!(diff.accepted_text(), "a\nB\nc\nd\nE\n");
+
+    let _ = diff.on_key(key(KeyCode::Down));
+    assert!(diff.on_key(key(KeyCode::Char(' '))).handled);
+    assert_eq!(diff.accepted_text(), "a\nb\nc\nd\nE\n");
+    assert_eq!(
+        diff.value(),
+        Some(Value::Text("a\nb\nc\nd\nE\n".to_string()))
+    );
+    assert!(
+        rendered(&diff)
+            .iter()
+            .any(|line| line.contains("1/2 hunks staged"))
+    );
+
+    let _ = diff.on_key(key(KeyCode::Char(' ')));
+    assert_eq!(diff.accepted_text(), "a\nB\nc\nd\nE\n");
+}
+
+#[test]
+fn value_is_only_reported_in_review_mode() {
+    let diff = DiffOutput::new("d", "", "a\n", "b\n");
+    assert_eq!(diff.value(), None);
+}
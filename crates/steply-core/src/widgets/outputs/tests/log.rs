@@ -0,0 +1,89 @@
+use super::LogOutput;
+use crate::runtime::event::SystemEvent;
+use crate::terminal::{KeyCode, KeyEvent, KeyModifiers};
+use crate::time::{Duration, Instant};
+use crate::widgets::traits::Interactive;
+
+fn key(code: KeyCode) -> KeyEvent {
+    KeyEvent {
+        code,
+        modifiers: KeyModifiers::NONE,
+    }
+}
+
+fn filled(n: usize) -> LogOutput {
+    let mut log = LogOutput::new("log", "Build")
+        .with_visible_lines(3)
+        .with_history_lines(10);
+    for index in 1..=n {
+        log.push_line(format!("line {index}").as_str());
+    }
+    log
+}
+
+#[test]
+fn history_is_capped_and_the_view_follows_new_lines() {
+    let log = filled(12);
+    assert_eq!(log.lines.len(), 10);
+    assert_eq!(log.visible_text(), ["line 10", "line 11", "line 12"]);
+}
+
+#[test]
+fn paused_view_stays_on_its_lines_until_follow_is_toggled() {
+    let mut log = filled(5);
+    assert!(log.on_key(key(KeyCode::Up)).handled);
+    assert!(!log.is_following());
+    log.push_line("line 6");
+    assert_eq!(log.visible_text(), ["line 2", "line 3", "line 4"]);
+
+    let _ = log.on_key(key(KeyCode::Char('f')));
+    assert_eq!(log.visible_text(), ["line 4", "line 5", "line 6"]);
+}
+
+#[test]
+fn search_jumps_between_matching_lines() {
+    let mut log = LogOutput::new("log", "").with_visible_lines(3);
+    log.push_line("\x1b[31mError\x1b[0m one\nok\nok\nok\nok\nerror two\nok\nok");
+    let _ = log.on_key(key(KeyCode::Char('/')));
+    for ch in "error".chars() {
+        let _ = log.on_key(key(KeyCode::Char(ch)));
+    }
+    assert_eq!(log.current_match, Some(5));
+
+    let _ = log.on_key(key(KeyCode::Enter));
+    assert!(!log.searching);
+    assert!(log.on_key(key(KeyCode::Char('n'))).handled);
+    assert_eq!(log.current_match, Some(0));
+    assert_eq!(log.visible_text(), ["Error one", "ok", "ok"]);
+    assert!(log.on_key(key(KeyCode::Char('N'))).handled);
+    assert_eq!(log.current_match, Some(5));
+}
+
+#[test]
+fn watched_task_lines_are_appended() {
+    let mut log = LogOutput::new("log", "").with_task("build");
+    for task_id in ["build", "test"] {
+        let _ = log.on_system_event(&SystemEvent::TaskLogLine {
+            task_id: task_id.into(),
+            run_id: 1,
+            line: format!("{task_id} output"),
+        });
+    }
+    assert_eq!(log.visible_text(), ["build output"]);
+}
+
+#[test]
+fn animated_page_scrolls_glide_to_the_new_position() {
+    let mut log = filled(10).with_animation(true);
+
+    assert!(log.scroll(-1));
+    assert!(!log.wants_tick(), "single lines move at once");
+
+    let now = Instant::now();
+    assert!(log.on_key(key(KeyCode::PageUp)).handled);
+    assert_eq!(log.visible_text(), ["line 4", "line 5", "line 6"]);
+    assert!(log.wants_tick());
+    let midway = log.drawn_offset(now + Duration::from_millis(40));
+    assert!((1..4).contains(&midway), "offset {midway}");
+    assert_eq!(log.drawn_offset(now + Duration::from_secs(1)), 4);
+}
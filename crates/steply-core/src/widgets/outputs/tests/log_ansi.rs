@@ -0,0 +1,40 @@
+use super::AnsiParser;
+use crate::ui::style::{Color, Style};
+
+#[test]
+fn sgr_codes_style_the_following_text() {
+    let mut parser = AnsiParser::default();
+    let spans = parser.parse_line("ok \x1b[1;32mpassed\x1b[0m done");
+    let parts = spans
+        .iter()
+        .map(|span| (span.text.as_str(), span.style))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        parts,
+        [
+            ("ok ", Style::default()),
+            ("passed", Style::new().color(Color::Green).bold()),
+            (" done", Style::default()),
+        ]
+    );
+}
+
+#[test]
+fn style_carries_over_lines_and_extended_colors_parse() {
+    let mut parser = AnsiParser::default();
+    let _ = parser.parse_line("\x1b[38;5;208m");
+    let spans = parser.parse_line("warn\x1b[48;2;1;2;3mbg");
+    assert_eq!(spans[0].style.color, Some(Color::Indexed(208)));
+    assert_eq!(spans[1].style.background, Some(Color::Rgb(1, 2, 3)));
+}
+
+#[test]
+fn other_escapes_and_overwritten_text_are_dropped() {
+    let mut parser = AnsiParser::default();
+    let spans = parser.parse_line("10%\r\x1b[2K\x1b]0;title\x07100%\r");
+    let text = spans
+        .iter()
+        .map(|span| span.text.as_str())
+        .collect::<String>();
+    assert_eq!(text, "100%");
+}
@@ -0,0 +1,9 @@
+use super::format_eta;
+use crate::time::Duration;
+
+#[test]
+fn eta_uses_the_two_largest_units() {
+    assert_eq!(format_eta(Duration::from_secs(42)), "42s");
+    assert_eq!(format_eta(Duration::from_secs(125)), "2m05s");
+    assert_eq!(format_eta(Duration::from_secs(3 * 3600 + 7 * 60)), "3h07m");
+}
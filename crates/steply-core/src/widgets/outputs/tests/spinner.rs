@@ -0,0 +1,28 @@
+use super::{SpinnerOutput, SpinnerState};
+use crate::runtime::event::SystemEvent;
+use crate::widgets::traits::OutputNode;
+
+#[test]
+fn watched_task_runs_drive_the_spinner() {
+    let mut spinner = SpinnerOutput::new("spin", "Deploying").with_task("deploy");
+    assert!(!spinner.wants_tick());
+
+    let _ = spinner.on_system_event(&SystemEvent::TaskStarted {
+        task_id: "other".into(),
+        run_id: 1,
+    });
+    assert_eq!(spinner.state, SpinnerState::Waiting);
+
+    let _ = spinner.on_system_event(&SystemEvent::TaskStarted {
+        task_id: "deploy".into(),
+        run_id: 1,
+    });
+    assert!(spinner.wants_tick());
+
+    let _ = spinner.on_system_event(&SystemEvent::TaskStartRejected {
+        task_id: "deploy".into(),
+        reason: "busy".to_string(),
+    });
+    assert_eq!(spinner.state, SpinnerState::Failed);
+    assert!(!spinner.wants_tick());
+}
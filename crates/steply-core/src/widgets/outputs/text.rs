@@ -20,8 +20,8 @@ impl Drawable for TextOutput {
         &self.id
     }
 
-    fn draw(&self, _ctx: &RenderContext) -> DrawOutput {
-        DrawOutput::plain_lines(vec![self.text.clone()])
+    fn draw(&self, ctx: &RenderContext) -> DrawOutput {
+        DrawOutput::plain_lines(vec![ctx.translate(&self.text).to_string()])
     }
 }
 
@@ -145,6 +145,10 @@ impl OutputNode for ThinkingOutput {
         self.frame = (self.frame + 1) % self.chars.len();
         InteractionResult::handled()
     }
+
+    fn wants_tick(&self) -> bool {
+        !self.chars.is_empty()
+    }
 }
 
 fn lerp_channel(a: u8, b: u8, t: f32) -> u8 {
@@ -2,7 +2,7 @@ use crate::core::value::Value;
 use crate::runtime::event::WidgetAction;
 use crate::terminal::{PointerButton, PointerEvent, PointerKind};
 use crate::ui::span::Span;
-use crate::ui::style::{Color, Style};
+use crate::ui::style::{Color, Style, StyleRole};
 use crate::widgets::traits::{DrawOutput, Drawable, InteractionResult, OutputNode, RenderContext};
 
 pub struct UrlOutput {
@@ -45,7 +45,7 @@ impl Drawable for UrlOutput {
         let linked = Self::osc8_link(self.url.as_str(), label.as_str());
         DrawOutput::with_lines(vec![vec![
             Span::styled(linked, Style::new().color(Color::Blue).bold()).no_wrap(),
-            Span::styled("↗", Style::new().color(Color::DarkGrey)).no_wrap(),
+            Span::styled("↗", StyleRole::Hint.style()).no_wrap(),
         ]])
     }
 }
@@ -1,6 +1,6 @@
 use std::sync::mpsc::Receiver;
 
-pub(super) fn drain_receiver<T>(rx: &Receiver<T>) -> Vec<T> {
+pub fn drain_receiver<T>(rx: &Receiver<T>) -> Vec<T> {
     let mut out = Vec::new();
     while let Ok(item) = rx.try_recv() {
         out.push(item);
@@ -8,7 +8,7 @@ pub(super) fn drain_receiver<T>(rx: &Receiver<T>) -> Vec<T> {
     out
 }
 
-pub(super) fn recv_latest<T>(rx: &Receiver<T>) -> Option<T> {
+pub fn recv_latest<T>(rx: &Receiver<T>) -> Option<T> {
     let mut latest = rx.recv().ok()?;
     while let Ok(next) = rx.try_recv() {
         latest = next;
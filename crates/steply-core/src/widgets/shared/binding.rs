@@ -1,3 +1,5 @@
+use crate::core::i18n::Message;
+use crate::core::secrets::SecretKey;
 use crate::core::store_refs::{
     parse_store_selector, render_template as render_resolved_template, resolve_template_value,
 };
@@ -11,9 +13,10 @@ use crate::terminal::{CursorPos, KeyEvent, PointerEvent};
 use crate::widgets::node::{Component, Node};
 use crate::widgets::traits::{
     CompletionState, DrawOutput, Drawable, FocusMode, InteractionResult, Interactive, OutputNode,
-    OverlayMode, OverlayPlacement, PointerRowMap, RenderContext, StoreSyncPolicy, TextAction,
-    TextEditState, ValidationMode,
+    OverlayAnchor, OverlayMode, OverlayPlacement, PointerRowMap, RenderContext, StoreSyncPolicy,
+    TextAction, TextEditState, ValidationIssue, ValidationMode,
 };
+use crate::widgets::transforms::{Transform, run_transforms};
 use indexmap::IndexMap;
 
 #[derive(Debug, Clone, Default)]
@@ -23,6 +26,8 @@ pub struct StoreBinding {
     pub reads: Option<ReadBinding>,
     pub writes: Vec<WriteBinding>,
     pub commit_policy: StoreCommitPolicy,
+    /// Applied in order to the widget value whenever it is committed.
+    pub transforms: Vec<Transform>,
 }
 
 impl StoreBinding {
@@ -33,6 +38,19 @@ impl StoreBinding {
             && self.writes.is_empty()
     }
 
+    pub fn with_transform(
+        mut self,
+        transform: impl Fn(Value) -> Value + Send + Sync + 'static,
+    ) -> Self {
+        self.transforms.push(Transform::new(transform));
+        self
+    }
+
+    /// The form of `value` that is written to the store.
+    pub fn committed_value(&self, value: Value) -> Value {
+        run_transforms(&self.transforms, value)
+    }
+
     pub fn read_value(&self, store: &ValueStore) -> Option<Value> {
         self.reads.as_ref().and_then(|reads| reads.resolve(store))
     }
@@ -52,7 +70,7 @@ impl StoreBinding {
             return Vec::new();
         }
 
-        let value = value.unwrap_or(Value::None);
+        let value = self.committed_value(value.unwrap_or(Value::None));
         let scope = build_scope(self.reads.as_ref(), &value);
         self.writes
             .iter()
@@ -323,10 +341,26 @@ impl Interactive for BoundInteractiveNode {
         self.inner.overlay_placement()
     }
 
+    fn overlay_anchor(&self) -> Option<OverlayAnchor> {
+        self.inner.overlay_anchor()
+    }
+
     fn commit_policy(&self) -> crate::state::change::StoreCommitPolicy {
         self.binding.commit_policy
     }
 
+    fn secret(&self) -> Option<&SecretKey> {
+        self.inner.secret()
+    }
+
+    fn value_masked(&self) -> bool {
+        self.inner.value_masked()
+    }
+
+    fn expanded_view(&self, id: &str, rows: usize) -> Option<Node> {
+        self.inner.expanded_view(id, rows)
+    }
+
     fn overlay_open(&mut self, saved_focus_id: Option<String>) -> bool {
         self.inner.overlay_open(saved_focus_id)
     }
@@ -368,6 +402,13 @@ impl Interactive for BoundInteractiveNode {
         self.wrap_result(before, result, after)
     }
 
+    fn on_paste(&mut self, text: &str) -> InteractionResult {
+        let before = self.inner.value();
+        let result = self.inner.on_paste(text);
+        let after = self.inner.value();
+        self.wrap_result(before, result, after)
+    }
+
     fn completion(&mut self) -> Option<CompletionState<'_>> {
         self.inner.completion()
     }
@@ -386,6 +427,10 @@ impl Interactive for BoundInteractiveNode {
         self.wrap_result(before, result, after)
     }
 
+    fn wants_tick(&self) -> bool {
+        self.inner.wants_tick()
+    }
+
     fn cursor_pos(&self) -> Option<CursorPos> {
         self.inner.cursor_pos()
     }
@@ -406,10 +451,18 @@ impl Interactive for BoundInteractiveNode {
         BoundInteractiveNode::sync_from_store(self, store)
     }
 
-    fn validate(&self, mode: ValidationMode) -> Result<(), String> {
+    fn validate(&self, mode: ValidationMode) -> Result<(), Message> {
         self.inner.validate(mode)
     }
 
+    fn validation_issues(&self, mode: ValidationMode) -> Vec<ValidationIssue> {
+        self.inner.validation_issues(mode)
+    }
+
+    fn reveal(&mut self, target: &str) -> bool {
+        self.inner.reveal(target)
+    }
+
     fn task_specs(&self) -> Vec<TaskSpec> {
         self.inner.task_specs()
     }
@@ -453,10 +506,26 @@ impl Interactive for BoundComponentNode {
         self.inner.overlay_placement()
     }
 
+    fn overlay_anchor(&self) -> Option<OverlayAnchor> {
+        self.inner.overlay_anchor()
+    }
+
     fn commit_policy(&self) -> crate::state::change::StoreCommitPolicy {
         self.binding.commit_policy
     }
 
+    fn secret(&self) -> Option<&SecretKey> {
+        self.inner.secret()
+    }
+
+    fn value_masked(&self) -> bool {
+        self.inner.value_masked()
+    }
+
+    fn expanded_view(&self, id: &str, rows: usize) -> Option<Node> {
+        self.inner.expanded_view(id, rows)
+    }
+
     fn overlay_open(&mut self, saved_focus_id: Option<String>) -> bool {
         self.inner.overlay_open(saved_focus_id)
     }
@@ -498,6 +567,13 @@ impl Interactive for BoundComponentNode {
         self.wrap_result(before, result, after)
     }
 
+    fn on_paste(&mut self, text: &str) -> InteractionResult {
+        let before = self.inner.value();
+        let result = self.inner.on_paste(text);
+        let after = self.inner.value();
+        self.wrap_result(before, result, after)
+    }
+
     fn completion(&mut self) -> Option<CompletionState<'_>> {
         self.inner.completion()
     }
@@ -516,6 +592,10 @@ impl Interactive for BoundComponentNode {
         self.wrap_result(before, result, after)
     }
 
+    fn wants_tick(&self) -> bool {
+        self.inner.wants_tick()
+    }
+
     fn cursor_pos(&self) -> Option<CursorPos> {
         self.inner.cursor_pos()
     }
@@ -536,10 +616,18 @@ impl Interactive for BoundComponentNode {
         BoundComponentNode::sync_from_store(self, store)
     }
 
-    fn validate(&self, mode: ValidationMode) -> Result<(), String> {
+    fn validate(&self, mode: ValidationMode) -> Result<(), Message> {
         self.inner.validate(mode)
     }
 
+    fn validation_issues(&self, mode: ValidationMode) -> Vec<ValidationIssue> {
+        self.inner.validation_issues(mode)
+    }
+
+    fn reveal(&mut self, target: &str) -> bool {
+        self.inner.reveal(target)
+    }
+
     fn task_specs(&self) -> Vec<TaskSpec> {
         self.inner.task_specs()
     }
@@ -624,11 +712,15 @@ impl OutputNode for BoundOutputNode {
         self.inner.on_tick()
     }
 
+    fn wants_tick(&self) -> bool {
+        self.inner.wants_tick()
+    }
+
     fn on_system_event(&mut self, event: &SystemEvent) -> InteractionResult {
         self.inner.on_system_event(event)
     }
 
-    fn validate(&self) -> Result<(), String> {
+    fn validate(&self) -> Result<(), Message> {
         self.inner.validate()
     }
 
@@ -785,6 +877,15 @@ fn resolve_store_ref(store: &ValueStore, path: &str) -> Option<Value> {
     store.get_target(&target).cloned()
 }
 
+/// Renders `{{ selector }}` references in `template` against the store.
+pub(crate) fn render_store_template(store: &ValueStore, template: &str) -> String {
+    render_resolved_template(
+        template,
+        |expr| resolve_store_ref(store, expr),
+        |value| value.to_text_scalar().unwrap_or_else(|| value.to_json()),
+    )
+}
+
 fn resolve_store_template(store: &ValueStore, template: &str) -> Value {
     resolve_template_value(template, |expr| resolve_store_ref(store, expr))
 }
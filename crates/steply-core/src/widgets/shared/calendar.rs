@@ -257,12 +257,83 @@ pub mod fmt {
     pub const DATE_DMY: &str = "DD/MM/YYYY";
     pub const DATE_MDY: &str = "MM/DD/YYYY";
     pub const DATE_YMD: &str = "YYYY-MM-DD";
+    pub const DATE_DMY_DOT: &str = "DD.MM.YYYY";
+    pub const DATE_YMD_SLASH: &str = "YYYY/MM/DD";
     pub const TIME_HM: &str = "HH:mm";
     pub const TIME_HMS: &str = "HH:mm:ss";
     pub const DATETIME_DMY_HM: &str = "DD/MM/YYYY HH:mm";
     pub const DATETIME_YMD_HM: &str = "YYYY-MM-DD HH:mm";
     pub const DATETIME_DMY_HMS: &str = "DD/MM/YYYY HH:mm:ss";
 }
+
+/// The usual numeric date pattern for `locale` (`en-US`, `de_DE.UTF-8`),
+/// falling back to ISO for languages without a known convention.
+pub fn locale_date_pattern(locale: &str) -> &'static str {
+    let locale = locale.split('.').next().unwrap_or(locale);
+    let mut parts = locale.split(['-', '_']);
+    let language = parts.next().unwrap_or_default().to_ascii_lowercase();
+    let region = parts.next().unwrap_or_default().to_ascii_uppercase();
+    match (language.as_str(), region.as_str()) {
+        ("en", "US" | "PH") => fmt::DATE_MDY,
+        ("en" | "fr" | "es" | "it" | "pt" | "nl" | "el" | "ga", _) => fmt::DATE_DMY,
+        ("de" | "ru" | "pl" | "cs" | "sk" | "fi" | "nb" | "no" | "da" | "tr" | "uk" | "ro", _) => {
+            fmt::DATE_DMY_DOT
+        }
+        ("ja" | "zh" | "ko", _) => fmt::DATE_YMD_SLASH,
+        _ => fmt::DATE_YMD,
+    }
+}
+
+/// Writes `date` in a pattern of `YYYY`, `MM` and `DD` fields; any other
+/// character is copied as a separator.
+pub fn format_date(date: Date, pattern: &str) -> String {
+    pattern
+        .replace("YYYY", format!("{:04}", date.year).as_str())
+        .replace("MM", format!("{:02}", date.month).as_str())
+        .replace("DD", format!("{:02}", date.day).as_str())
+}
+
+/// Reads a date written in `pattern`. Fields may drop their leading zeros
+/// as long as separators split them; the result must be a real date.
+pub fn parse_date(text: &str, pattern: &str) -> Option<Date> {
+    let text = text.trim();
+    let (mut year, mut month, mut day) = (None, None, None);
+    let mut rest = text;
+    let mut pattern_rest = pattern;
+    while !pattern_rest.is_empty() {
+        let field = ["YYYY", "MM", "DD"]
+            .into_iter()
+            .find(|field| pattern_rest.starts_with(field));
+        let Some(field) = field else {
+            let sep = pattern_rest.chars().next()?;
+            rest = rest.strip_prefix(sep)?;
+            pattern_rest = &pattern_rest[sep.len_utf8()..];
+            continue;
+        };
+        pattern_rest = &pattern_rest[field.len()..];
+        // Fields written back to back have fixed widths.
+        let adjacent = pattern_rest.starts_with(|ch: char| ch.is_ascii_alphabetic());
+        let run = rest
+            .find(|ch: char| !ch.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let digits = if adjacent { run.min(field.len()) } else { run };
+        if digits == 0 || digits > field.len() {
+            return None;
+        }
+        let number = &rest[..digits];
+        rest = &rest[digits..];
+        match field {
+            "YYYY" => year = number.parse::<i32>().ok(),
+            "MM" => month = number.parse::<u8>().ok(),
+            _ => day = number.parse::<u8>().ok(),
+        }
+    }
+    if !rest.is_empty() {
+        return None;
+    }
+    validate_date(year?, month?, day?).ok()
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 use std::time::{SystemTime, UNIX_EPOCH};
 #[cfg(target_arch = "wasm32")]
@@ -1,3 +1,5 @@
+use crate::core::i18n::Message;
+use crate::core::secrets::SecretKey;
 use crate::runtime::event::SystemEvent;
 use crate::state::step::StepCondition;
 use crate::state::store::ValueStore;
@@ -6,8 +8,8 @@ use crate::terminal::{CursorPos, KeyEvent, PointerEvent};
 use crate::widgets::node::{Component, Node};
 use crate::widgets::traits::{
     CompletionState, DrawOutput, Drawable, FocusMode, HintContext, HintItem, InteractionResult,
-    Interactive, InteractiveNode, OutputNode, OverlayMode, OverlayPlacement, PointerRowMap,
-    RenderContext, StoreSyncPolicy, TextAction, ValidationMode,
+    Interactive, InteractiveNode, OutputNode, OverlayAnchor, OverlayMode, OverlayPlacement,
+    PointerRowMap, RenderContext, StoreSyncPolicy, TextAction, ValidationIssue, ValidationMode,
 };
 
 pub fn wrap_node_when(node: Node, when: StepCondition) -> Node {
@@ -106,6 +108,14 @@ impl Interactive for ConditionalInputNode {
         }
     }
 
+    fn overlay_anchor(&self) -> Option<OverlayAnchor> {
+        if self.visible {
+            self.inner.overlay_anchor()
+        } else {
+            None
+        }
+    }
+
     fn overlay_open(&mut self, saved_focus_id: Option<String>) -> bool {
         self.visible && self.inner.overlay_open(saved_focus_id)
     }
@@ -160,6 +170,14 @@ impl Interactive for ConditionalInputNode {
         }
     }
 
+    fn on_paste(&mut self, text: &str) -> InteractionResult {
+        if self.visible {
+            self.inner.on_paste(text)
+        } else {
+            InteractionResult::ignored()
+        }
+    }
+
     fn completion(&mut self) -> Option<CompletionState<'_>> {
         if self.visible {
             self.inner.completion()
@@ -184,6 +202,10 @@ impl Interactive for ConditionalInputNode {
         }
     }
 
+    fn wants_tick(&self) -> bool {
+        self.visible && self.inner.wants_tick()
+    }
+
     fn cursor_pos(&self) -> Option<CursorPos> {
         self.visible.then(|| self.inner.cursor_pos()).flatten()
     }
@@ -216,7 +238,7 @@ impl Interactive for ConditionalInputNode {
         visibility_changed || inner_changed
     }
 
-    fn validate(&self, mode: ValidationMode) -> Result<(), String> {
+    fn validate(&self, mode: ValidationMode) -> Result<(), Message> {
         if self.visible {
             self.inner.validate(mode)
         } else {
@@ -224,6 +246,34 @@ impl Interactive for ConditionalInputNode {
         }
     }
 
+    fn validation_issues(&self, mode: ValidationMode) -> Vec<ValidationIssue> {
+        if self.visible {
+            self.inner.validation_issues(mode)
+        } else {
+            Vec::new()
+        }
+    }
+
+    fn reveal(&mut self, target: &str) -> bool {
+        self.visible && self.inner.reveal(target)
+    }
+
+    fn secret(&self) -> Option<&SecretKey> {
+        self.inner.secret()
+    }
+
+    fn value_masked(&self) -> bool {
+        self.inner.value_masked()
+    }
+
+    fn expanded_view(&self, id: &str, rows: usize) -> Option<Node> {
+        if self.visible {
+            self.inner.expanded_view(id, rows)
+        } else {
+            None
+        }
+    }
+
     fn task_specs(&self) -> Vec<TaskSpec> {
         self.inner.task_specs()
     }
@@ -317,6 +367,14 @@ impl Interactive for ConditionalComponentNode {
         }
     }
 
+    fn overlay_anchor(&self) -> Option<OverlayAnchor> {
+        if self.visible {
+            self.inner.overlay_anchor()
+        } else {
+            None
+        }
+    }
+
     fn overlay_open(&mut self, saved_focus_id: Option<String>) -> bool {
         self.visible && self.inner.overlay_open(saved_focus_id)
     }
@@ -371,6 +429,14 @@ impl Interactive for ConditionalComponentNode {
         }
     }
 
+    fn on_paste(&mut self, text: &str) -> InteractionResult {
+        if self.visible {
+            self.inner.on_paste(text)
+        } else {
+            InteractionResult::ignored()
+        }
+    }
+
     fn completion(&mut self) -> Option<CompletionState<'_>> {
         if self.visible {
             self.inner.completion()
@@ -395,6 +461,10 @@ impl Interactive for ConditionalComponentNode {
         }
     }
 
+    fn wants_tick(&self) -> bool {
+        self.visible && self.inner.wants_tick()
+    }
+
     fn cursor_pos(&self) -> Option<CursorPos> {
         self.visible.then(|| self.inner.cursor_pos()).flatten()
     }
@@ -427,7 +497,7 @@ impl Interactive for ConditionalComponentNode {
         visibility_changed || inner_changed
     }
 
-    fn validate(&self, mode: ValidationMode) -> Result<(), String> {
+    fn validate(&self, mode: ValidationMode) -> Result<(), Message> {
         if self.visible {
             self.inner.validate(mode)
         } else {
@@ -435,6 +505,34 @@ impl Interactive for ConditionalComponentNode {
         }
     }
 
+    fn validation_issues(&self, mode: ValidationMode) -> Vec<ValidationIssue> {
+        if self.visible {
+            self.inner.validation_issues(mode)
+        } else {
+            Vec::new()
+        }
+    }
+
+    fn reveal(&mut self, target: &str) -> bool {
+        self.visible && self.inner.reveal(target)
+    }
+
+    fn secret(&self) -> Option<&SecretKey> {
+        self.inner.secret()
+    }
+
+    fn value_masked(&self) -> bool {
+        self.inner.value_masked()
+    }
+
+    fn expanded_view(&self, id: &str, rows: usize) -> Option<Node> {
+        if self.visible {
+            self.inner.expanded_view(id, rows)
+        } else {
+            None
+        }
+    }
+
     fn task_specs(&self) -> Vec<TaskSpec> {
         self.inner.task_specs()
     }
@@ -556,6 +654,10 @@ impl OutputNode for ConditionalOutputNode {
         }
     }
 
+    fn wants_tick(&self) -> bool {
+        self.visible && self.inner.wants_tick()
+    }
+
     fn on_system_event(&mut self, event: &SystemEvent) -> InteractionResult {
         if self.visible {
             self.inner.on_system_event(event)
@@ -564,7 +666,7 @@ impl OutputNode for ConditionalOutputNode {
         }
     }
 
-    fn validate(&self) -> Result<(), String> {
+    fn validate(&self) -> Result<(), Message> {
         if self.visible {
             self.inner.validate()
         } else {
@@ -2,7 +2,7 @@ use crate::core::value::Value;
 use crate::runtime::event::WidgetAction;
 use crate::terminal::{CursorPos, KeyCode, KeyEvent, KeyModifiers};
 use crate::ui::span::{Span, SpanLine};
-use crate::ui::style::{Color, Style};
+use crate::ui::style::StyleRole;
 use crate::widgets::inputs::text::TextInput;
 use crate::widgets::shared::keymap;
 use crate::widgets::traits::{
@@ -115,8 +115,7 @@ impl ListFilter {
                 None
             },
         );
-        let mut line =
-            vec![Span::styled("Filter: ", Style::new().color(Color::DarkGrey)).no_wrap()];
+        let mut line = vec![Span::styled("Filter: ", StyleRole::Hint.style()).no_wrap()];
         line.extend(
             self.input
                 .draw(&filter_ctx)
@@ -217,6 +216,16 @@ impl ListFilter {
             blurred: false,
         }
     }
+
+    pub fn handle_paste(&mut self, text: &str) -> ListFilterUpdate {
+        let before = self.query();
+        ListFilterUpdate {
+            result: sanitize_interaction_result(self.input.on_paste(text)),
+            query_changed: self.query() != before,
+            hidden: false,
+            blurred: false,
+        }
+    }
 }
 
 pub fn sanitize_interaction_result(mut result: InteractionResult) -> InteractionResult {
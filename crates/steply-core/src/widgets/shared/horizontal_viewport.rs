@@ -1,6 +1,6 @@
 use crate::terminal::CursorPos;
 use crate::ui::span::{Span, SpanLine};
-use crate::ui::style::{Color, Style};
+use crate::ui::style::StyleRole;
 use crate::ui::text::{split_prefix_at_display_width, text_display_width};
 
 #[derive(Debug, Clone)]
@@ -73,7 +73,7 @@ pub fn render_single_line(
 }
 
 fn overflow_indicator() -> Span {
-    Span::styled("…", Style::new().color(Color::DarkGrey)).no_wrap()
+    Span::styled("…", StyleRole::Hint.style()).no_wrap()
 }
 
 fn normalize_nowrap_spans(mut spans: SpanLine) -> SpanLine {
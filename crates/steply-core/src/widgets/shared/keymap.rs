@@ -12,6 +12,17 @@ pub fn is_plain_key(key: KeyEvent, code: KeyCode) -> bool {
     has_no_modifiers(key) && key.code == code
 }
 
+/// A typed character: no modifiers, or just Shift for an uppercase letter.
+pub fn is_typed_char(key: KeyEvent) -> bool {
+    match key.code {
+        KeyCode::Char(ch) if ch.is_uppercase() => {
+            has_no_modifiers(key) || has_exact_modifiers(key, KeyModifiers::SHIFT)
+        }
+        KeyCode::Char(_) => has_no_modifiers(key),
+        _ => false,
+    }
+}
+
 pub fn is_ctrl_char(key: KeyEvent, ch: char) -> bool {
     if !key.modifiers.contains(KeyModifiers::CONTROL) || key.modifiers.contains(KeyModifiers::ALT) {
         return false;
@@ -1,3 +1,4 @@
+pub mod async_utils;
 pub mod binding;
 pub mod calendar;
 pub mod condition;
@@ -11,5 +12,6 @@ pub mod render_ctx;
 pub mod scroll;
 pub mod task_watcher;
 pub mod text_edit;
+pub mod timers;
 pub mod validation;
 pub mod value_seed;
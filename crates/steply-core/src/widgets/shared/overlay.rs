@@ -3,14 +3,15 @@ use crate::terminal::{KeyCode, KeyEvent};
 use crate::widgets::base::OverlayBase;
 use crate::widgets::node::{Node, find_node_mut};
 use crate::widgets::traits::{
-    DrawOutput, Drawable, FocusMode, InteractionResult, Interactive, OverlayMode, OverlayPlacement,
-    OverlayRenderMode, RenderContext, TextAction,
+    DrawOutput, Drawable, FocusMode, InteractionResult, Interactive, OverlayAnchor, OverlayMode,
+    OverlayPlacement, OverlayRenderMode, PopoverSide, RenderContext, TextAction,
 };
 
 pub struct Overlay {
     base: OverlayBase,
     nodes: Vec<Node>,
     group_focus_id: Option<String>,
+    anchor: Option<OverlayAnchor>,
 }
 
 impl Overlay {
@@ -24,6 +25,7 @@ impl Overlay {
             base: OverlayBase::new(id, label, placement),
             nodes,
             group_focus_id: None,
+            anchor: None,
         }
     }
 
@@ -45,6 +47,14 @@ impl Overlay {
         self.base.set_render_mode(render_mode);
         self
     }
+
+    /// Opens as a popover next to widget `target`, e.g. a dropdown under its
+    /// input. The placement's width and height are kept; its row and column
+    /// are only used while `target` is not on screen.
+    pub fn anchored_to(mut self, target: impl Into<String>, side: PopoverSide) -> Self {
+        self.anchor = Some(OverlayAnchor::new(target, side));
+        self
+    }
 }
 
 impl crate::widgets::node::Component for Overlay {
@@ -76,6 +86,10 @@ impl Interactive for Overlay {
         Some(self.placement())
     }
 
+    fn overlay_anchor(&self) -> Option<OverlayAnchor> {
+        self.anchor.clone()
+    }
+
     fn overlay_open(&mut self, _saved_focus_id: Option<String>) -> bool {
         self.group_focus_id = first_focusable_id(&self.nodes);
         true
@@ -148,6 +162,20 @@ impl Interactive for Overlay {
         InteractionResult::ignored()
     }
 
+    fn on_paste(&mut self, text: &str) -> InteractionResult {
+        if self.base.focus_mode() != FocusMode::Group {
+            return InteractionResult::ignored();
+        }
+
+        if let Some(focus_id) = self.group_focus_id.clone()
+            && let Some(node) = find_node_mut(&mut self.nodes, &focus_id)
+        {
+            return node.on_paste(text);
+        }
+
+        InteractionResult::ignored()
+    }
+
     fn on_system_event(&mut self, event: &SystemEvent) -> InteractionResult {
         let targeted_lifecycle = matches!(
             event,
@@ -1,3 +1,7 @@
+use crate::ui::layout::Layout;
+use crate::ui::span::{Span, SpanLine};
+use crate::ui::style::StyleRole;
+
 #[derive(Debug, Clone)]
 pub struct CursorNav {
     active: usize,
@@ -30,6 +34,11 @@ impl CursorNav {
         self.scroll.set_active_clamped(&mut self.active, total, idx);
     }
 
+    pub fn scroll_by(&mut self, delta: isize, total: usize) -> bool {
+        self.scroll
+            .move_active_clamped(&mut self.active, total, delta)
+    }
+
     pub fn clamp(&mut self, total: usize) {
         self.scroll.clamp_and_ensure(&mut self.active, total);
     }
@@ -42,6 +51,10 @@ impl CursorNav {
         self.scroll.footer(total)
     }
 
+    pub fn scrollbar(&self, total: usize) -> Option<Scrollbar> {
+        self.scroll.scrollbar(total)
+    }
+
     pub fn placeholder_count(&self, total: usize) -> usize {
         self.scroll.placeholder_count(total)
     }
@@ -131,6 +144,23 @@ impl ScrollState {
         true
     }
 
+    /// Like [`Self::move_active_wrapped`], but stops at either end. Used for
+    /// the mouse wheel, where wrapping around would be disorienting.
+    pub fn move_active_clamped(&mut self, active: &mut usize, total: usize, delta: isize) -> bool {
+        if total == 0 {
+            return false;
+        }
+        let next = active
+            .saturating_add_signed(delta)
+            .min(total.saturating_sub(1));
+        if next == *active {
+            return false;
+        }
+        *active = next;
+        self.ensure_visible(*active, total);
+        true
+    }
+
     pub fn visible_range(&self, total: usize) -> (usize, usize) {
         match self.max_visible {
             Some(limit) => {
@@ -169,4 +199,65 @@ impl ScrollState {
         let reserved = self.max_visible.map_or(total, |max| total.min(max));
         reserved.saturating_sub(visible)
     }
+
+    /// Scrollbar for the current window, or `None` when everything fits.
+    pub fn scrollbar(&self, total: usize) -> Option<Scrollbar> {
+        let (start, end) = self.visible_range(total);
+        Scrollbar::new(start, end.saturating_sub(start), total)
+    }
 }
+
+/// A vertical scrollbar drawn as one glyph column to the right of a
+/// scrolled window: `visible` items starting at `offset` out of `total`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Scrollbar {
+    pub offset: usize,
+    pub visible: usize,
+    pub total: usize,
+}
+
+impl Scrollbar {
+    pub fn new(offset: usize, visible: usize, total: usize) -> Option<Self> {
+        (visible > 0 && total > visible).then_some(Self {
+            offset: offset.min(total - visible),
+            visible,
+            total,
+        })
+    }
+
+    /// First row and length of the thumb on a track `track` rows tall. The
+    /// thumb only touches either end when the window does.
+    pub fn thumb(&self, track: usize) -> (usize, usize) {
+        if track == 0 {
+            return (0, 0);
+        }
+        let len = (track * self.visible).div_ceil(self.total).clamp(1, track);
+        let travel = track - len;
+        let scrollable = self.total - self.visible;
+        let start = match self.offset {
+            0 => 0,
+            offset if offset >= scrollable => travel,
+            offset if travel < 2 => (offset * travel + scrollable / 2) / scrollable,
+            offset => ((offset * travel + scrollable / 2) / scrollable).clamp(1, travel - 1),
+        };
+        (start, len)
+    }
+
+    /// Fits every line to `width` and appends the track glyph, so the
+    /// scrollbar sits in the column right after.
+    pub fn attach(&self, lines: &mut [SpanLine], width: u16) {
+        let (start, len) = self.thumb(lines.len());
+        for (row, line) in lines.iter_mut().enumerate() {
+            *line = Layout::clip_line(Layout::fit_line(line.as_slice(), width), width);
+            line.push(if (start..start + len).contains(&row) {
+                Span::new("█").no_wrap()
+            } else {
+                Span::styled("│", StyleRole::Border.style()).no_wrap()
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/scroll.rs"]
+mod tests;
@@ -1,4 +1,5 @@
 use crate::ui::spinner::{Spinner, SpinnerStyle};
+use crate::widgets::shared::scroll::Scrollbar;
 use std::collections::VecDeque;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -15,6 +16,8 @@ pub struct TaskWatcherState {
     active_run_id: Option<u64>,
     logs: VecDeque<String>,
     visible_lines: usize,
+    history_lines: usize,
+    scroll_offset: usize,
     spinner: Spinner,
 }
 
@@ -25,6 +28,8 @@ impl TaskWatcherState {
             active_run_id: None,
             logs: VecDeque::new(),
             visible_lines: visible_lines.max(1),
+            history_lines: visible_lines.max(1),
+            scroll_offset: 0,
             spinner: Spinner::new(spinner_style),
         }
     }
@@ -41,11 +46,53 @@ impl TaskWatcherState {
         &self.logs
     }
 
+    /// The window of log lines currently scrolled into view.
+    pub fn visible_logs(&self) -> impl Iterator<Item = &String> {
+        let end = self.logs.len().saturating_sub(self.scroll_offset);
+        let start = end.saturating_sub(self.visible_lines);
+        self.logs.range(start..end)
+    }
+
+    /// Lines above and below the visible window.
+    pub fn hidden_log_lines(&self) -> (usize, usize) {
+        let below = self.scroll_offset.min(self.logs.len());
+        let above = self
+            .logs
+            .len()
+            .saturating_sub(below)
+            .saturating_sub(self.visible_lines);
+        (above, below)
+    }
+
+    pub fn scrollbar(&self) -> Option<Scrollbar> {
+        let (above, below) = self.hidden_log_lines();
+        let visible = self.logs.len().saturating_sub(above + below);
+        Scrollbar::new(above, visible, self.logs.len())
+    }
+
     pub fn set_visible_lines(&mut self, visible_lines: usize) {
         self.visible_lines = visible_lines.max(1);
-        while self.logs.len() > self.visible_lines {
-            self.logs.pop_front();
-        }
+        self.history_lines = self.history_lines.max(self.visible_lines);
+        self.trim_history();
+    }
+
+    /// Keeps up to `history_lines` lines so output can be scrolled back.
+    pub fn set_history_lines(&mut self, history_lines: usize) {
+        self.history_lines = history_lines.max(self.visible_lines);
+        self.trim_history();
+    }
+
+    /// Scrolls towards older lines for negative `delta`; `0` offset follows
+    /// new output. Returns whether the view moved.
+    pub fn scroll(&mut self, delta: isize) -> bool {
+        let max_offset = self.logs.len().saturating_sub(self.visible_lines);
+        let next = self
+            .scroll_offset
+            .saturating_add_signed(-delta)
+            .min(max_offset);
+        let moved = next != self.scroll_offset;
+        self.scroll_offset = next;
+        moved
     }
 
     pub fn set_spinner_style(&mut self, style: SpinnerStyle) {
@@ -56,6 +103,7 @@ impl TaskWatcherState {
         self.status = TaskWatcherStatus::Pending;
         self.active_run_id = None;
         self.logs.clear();
+        self.scroll_offset = 0;
     }
 
     pub fn mark_started(&mut self, run_id: u64) {
@@ -93,8 +141,12 @@ impl TaskWatcherState {
         true
     }
 
+    pub fn is_running(&self) -> bool {
+        self.status == TaskWatcherStatus::Running
+    }
+
     pub fn tick(&mut self) -> bool {
-        if self.status != TaskWatcherStatus::Running {
+        if !self.is_running() {
             return false;
         }
         self.spinner.tick();
@@ -103,8 +155,17 @@ impl TaskWatcherState {
 
     fn push_log(&mut self, line: String) {
         self.logs.push_back(line);
-        while self.logs.len() > self.visible_lines {
+        if self.scroll_offset > 0 {
+            self.scroll_offset += 1;
+        }
+        self.trim_history();
+    }
+
+    fn trim_history(&mut self) {
+        while self.logs.len() > self.history_lines {
             self.logs.pop_front();
         }
+        let max_offset = self.logs.len().saturating_sub(self.visible_lines);
+        self.scroll_offset = self.scroll_offset.min(max_offset);
     }
 }
@@ -0,0 +1,39 @@
+use super::{ScrollState, Scrollbar};
+use crate::ui::span::Span;
+
+#[test]
+fn no_scrollbar_when_everything_fits() {
+    let scroll = ScrollState::new(Some(5));
+    assert_eq!(scroll.scrollbar(5), None);
+    assert_eq!(ScrollState::new(None).scrollbar(50), None);
+}
+
+#[test]
+fn thumb_size_and_position_follow_the_window() {
+    let at = |offset| Scrollbar::new(offset, 5, 20).expect("scrollable");
+    assert_eq!(at(0).thumb(5), (0, 2));
+    assert_eq!(at(1).thumb(5), (1, 2));
+    assert_eq!(at(14).thumb(5), (2, 2));
+    assert_eq!(at(15).thumb(5), (3, 2));
+}
+
+#[test]
+fn attach_pads_rows_and_draws_the_track() {
+    let mut lines = vec![
+        vec![Span::new("alpha")],
+        vec![Span::new("beta")],
+        vec![Span::new("gamma")],
+    ];
+    Scrollbar::new(0, 3, 9)
+        .expect("scrollable")
+        .attach(&mut lines, 6);
+    let rows = lines
+        .iter()
+        .map(|line| {
+            line.iter()
+                .map(|span| span.text.as_str())
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(rows, ["alpha █", "beta  │", "gamma │"]);
+}
@@ -0,0 +1,55 @@
+use super::{backspace_char, clamp_cursor, delete_char, insert_char, move_left, move_right};
+use crate::ui::text::{clip_to_display_width, split_prefix_at_display_width, text_display_width};
+
+const FAMILY: &str = "👩\u{200d}👩\u{200d}👧";
+
+#[test]
+fn cursor_steps_over_whole_clusters() {
+    let value = format!("a{FAMILY}e\u{301}");
+    let mut cursor = 0;
+    assert!(move_right(&mut cursor, &value));
+    assert!(move_right(&mut cursor, &value));
+    assert_eq!(cursor, 6);
+    assert!(move_right(&mut cursor, &value));
+    assert_eq!(cursor, 8);
+    assert!(!move_right(&mut cursor, &value));
+    assert!(move_left(&mut cursor, &value));
+    assert_eq!(cursor, 6);
+    assert!(move_left(&mut cursor, &value));
+    assert_eq!(cursor, 1);
+}
+
+#[test]
+fn deleting_removes_the_whole_cluster() {
+    let mut value = format!("a{FAMILY}e\u{301}");
+    let mut cursor = 8;
+    assert!(backspace_char(&mut value, &mut cursor));
+    assert_eq!((value.as_str(), cursor), (format!("a{FAMILY}").as_str(), 6));
+
+    let mut cursor = 1;
+    assert!(delete_char(&mut value, &mut cursor));
+    assert_eq!((value.as_str(), cursor), ("a", 1));
+}
+
+#[test]
+fn cursors_inside_a_cluster_snap_to_its_start() {
+    assert_eq!(clamp_cursor(3, FAMILY), 0);
+    let mut value = "e\u{301}".to_string();
+    let mut cursor = 1;
+    insert_char(&mut value, &mut cursor, 'x');
+    assert_eq!(value, "xe\u{301}");
+}
+
+#[test]
+fn clipping_keeps_clusters_intact() {
+    assert_eq!(text_display_width(FAMILY), 2);
+    assert_eq!(
+        clip_to_display_width(&format!("{FAMILY}ab"), 3),
+        format!("{FAMILY}a")
+    );
+    assert_eq!(clip_to_display_width(&format!("a{FAMILY}"), 2), "a");
+    assert_eq!(
+        split_prefix_at_display_width("ne\u{301}e", 2),
+        ("ne\u{301}", "e")
+    );
+}
@@ -0,0 +1,51 @@
+use super::WidgetTimers;
+use crate::time::{Duration, Instant};
+
+#[test]
+fn rescheduling_moves_the_deadline() {
+    let start = Instant::now();
+    let mut timers = WidgetTimers::new();
+    timers.schedule_at("scan", start + Duration::from_millis(100));
+    timers.schedule_at("scan", start + Duration::from_millis(200));
+
+    assert!(!timers.fire_at("scan", start + Duration::from_millis(150)));
+    assert!(timers.is_pending("scan"));
+    assert!(timers.fire_at("scan", start + Duration::from_millis(200)));
+    assert!(!timers.fire_at("scan", start + Duration::from_millis(300)));
+    assert!(!timers.has_pending());
+}
+
+#[test]
+fn cancelled_timers_never_fire() {
+    let start = Instant::now();
+    let mut timers = WidgetTimers::new();
+    timers.schedule_at("save", start);
+    assert!(timers.cancel("save"));
+    assert!(!timers.cancel("save"));
+    assert!(!timers.fire_at("save", start + Duration::from_secs(1)));
+}
+
+#[test]
+fn take_due_returns_due_timers_in_order() {
+    let start = Instant::now();
+    let mut timers = WidgetTimers::new();
+    timers.schedule_at("validate", start + Duration::from_millis(20));
+    timers.schedule_at("scan", start + Duration::from_millis(10));
+    timers.schedule_at("save", start + Duration::from_millis(500));
+
+    assert_eq!(
+        timers.take_due(start + Duration::from_millis(50)),
+        vec!["scan", "validate"]
+    );
+    assert!(timers.is_pending("save"));
+}
+
+#[test]
+fn throttle_lets_one_call_through_per_window() {
+    let start = Instant::now();
+    let window = Duration::from_millis(100);
+    let mut timers = WidgetTimers::new();
+    assert!(timers.throttle_at("redraw", window, start));
+    assert!(!timers.throttle_at("redraw", window, start + Duration::from_millis(50)));
+    assert!(timers.throttle_at("redraw", window, start + window));
+}
@@ -1,9 +1,42 @@
 use crate::terminal::{KeyCode, KeyEvent, KeyModifiers};
+use crate::ui::text::graphemes;
 
 pub fn char_count(value: &str) -> usize {
     value.chars().count()
 }
 
+/// Char offsets where each grapheme cluster starts, plus the end. Cursors
+/// stay char indices but only ever rest on these.
+fn grapheme_boundaries(value: &str) -> Vec<usize> {
+    let mut boundaries = vec![0];
+    let mut offset = 0usize;
+    for grapheme in graphemes(value) {
+        offset += grapheme.chars().count();
+        boundaries.push(offset);
+    }
+    boundaries
+}
+
+fn prev_boundary(value: &str, pos: usize) -> usize {
+    grapheme_boundaries(value)
+        .into_iter()
+        .rev()
+        .find(|boundary| *boundary < pos)
+        .unwrap_or(0)
+}
+
+fn next_boundary(value: &str, pos: usize) -> usize {
+    grapheme_boundaries(value)
+        .into_iter()
+        .find(|boundary| *boundary > pos)
+        .unwrap_or(pos)
+}
+
+fn remove_chars(value: &mut String, start: usize, end: usize) {
+    let range = byte_index_at_char(value, start)..byte_index_at_char(value, end);
+    value.replace_range(range, "");
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TextKeyOutcome {
     Ignored,
@@ -60,8 +93,15 @@ pub fn apply_single_line_key(
     }
 }
 
+/// Clamps to the text and moves back to the start of the grapheme cluster
+/// the cursor would otherwise split.
 pub fn clamp_cursor(cursor: usize, value: &str) -> usize {
-    cursor.min(char_count(value))
+    let cursor = cursor.min(char_count(value));
+    grapheme_boundaries(value)
+        .into_iter()
+        .rev()
+        .find(|boundary| *boundary <= cursor)
+        .unwrap_or(0)
 }
 
 pub fn insert_char(value: &mut String, cursor: &mut usize, ch: char) {
@@ -71,14 +111,38 @@ pub fn insert_char(value: &mut String, cursor: &mut usize, ch: char) {
     *cursor = pos + 1;
 }
 
+pub fn insert_str(value: &mut String, cursor: &mut usize, text: &str) {
+    let pos = clamp_cursor(*cursor, value);
+    let byte_pos = byte_index_at_char(value, pos);
+    value.insert_str(byte_pos, text);
+    *cursor = pos + char_count(text);
+}
+
+/// Pasted text with line endings normalized to `\n`, tabs turned into
+/// spaces and any other control characters dropped.
+pub fn clean_paste(text: &str) -> String {
+    text.replace("\r\n", "\n")
+        .replace('\r', "\n")
+        .chars()
+        .map(|ch| if ch == '\t' { ' ' } else { ch })
+        .filter(|ch| *ch == '\n' || !ch.is_control())
+        .collect()
+}
+
+/// Pasted text for a single-line field: trailing line breaks are dropped and
+/// the remaining ones become spaces.
+pub fn single_line_paste(text: &str) -> String {
+    clean_paste(text).trim_end_matches('\n').replace('\n', " ")
+}
+
 pub fn backspace_char(value: &mut String, cursor: &mut usize) -> bool {
     let pos = clamp_cursor(*cursor, value);
     if pos == 0 {
         return false;
     }
-    let byte_pos = byte_index_at_char(value, pos - 1);
-    value.remove(byte_pos);
-    *cursor = pos - 1;
+    let start = prev_boundary(value, pos);
+    remove_chars(value, start, pos);
+    *cursor = start;
     true
 }
 
@@ -90,8 +154,8 @@ pub fn delete_char(value: &mut String, cursor: &mut usize) -> bool {
         return false;
     }
 
-    let byte_pos = byte_index_at_char(value, pos);
-    value.remove(byte_pos);
+    let end = next_boundary(value, pos);
+    remove_chars(value, pos, end);
     *cursor = pos;
     true
 }
@@ -101,7 +165,7 @@ pub fn move_left(cursor: &mut usize, value: &str) -> bool {
     if pos == 0 {
         return false;
     }
-    *cursor = pos - 1;
+    *cursor = prev_boundary(value, pos);
     true
 }
 
@@ -111,7 +175,7 @@ pub fn move_right(cursor: &mut usize, value: &str) -> bool {
     if pos >= len {
         return false;
     }
-    *cursor = pos + 1;
+    *cursor = next_boundary(value, pos);
     true
 }
 
@@ -263,3 +327,7 @@ fn move_cursor_to(cursor: &mut usize, next: usize) -> TextKeyOutcome {
         TextKeyOutcome::CursorMoved
     }
 }
+
+#[cfg(test)]
+#[path = "tests/text_edit.rs"]
+mod tests;
@@ -0,0 +1,109 @@
+use crate::time::{Duration, Instant};
+
+/// Named one-shot timers and throttle windows for a widget, polled from
+/// `on_tick`. Scheduling a timer that is already pending moves its deadline,
+/// which is all a debounce needs; while any timer is pending the widget
+/// should report `wants_tick` so the runtime keeps ticking.
+#[derive(Debug, Clone, Default)]
+pub struct WidgetTimers {
+    deadlines: Vec<(&'static str, Instant)>,
+    windows: Vec<(&'static str, Instant)>,
+}
+
+impl WidgetTimers {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fires `id` once `delay` has passed, replacing an earlier deadline.
+    pub fn schedule_after(&mut self, id: &'static str, delay: Duration) {
+        self.schedule_at(id, Instant::now() + delay);
+    }
+
+    pub fn schedule_at(&mut self, id: &'static str, deadline: Instant) {
+        match self.deadlines.iter_mut().find(|(timer, _)| *timer == id) {
+            Some((_, due)) => *due = deadline,
+            None => self.deadlines.push((id, deadline)),
+        }
+    }
+
+    /// Drops a pending timer; returns whether there was one.
+    pub fn cancel(&mut self, id: &'static str) -> bool {
+        let before = self.deadlines.len();
+        self.deadlines.retain(|(timer, _)| *timer != id);
+        self.deadlines.len() != before
+    }
+
+    pub fn cancel_all(&mut self) {
+        self.deadlines.clear();
+    }
+
+    pub fn is_pending(&self, id: &'static str) -> bool {
+        self.deadlines.iter().any(|(timer, _)| *timer == id)
+    }
+
+    pub fn has_pending(&self) -> bool {
+        !self.deadlines.is_empty()
+    }
+
+    /// Whether `id` is pending and its deadline has not come yet.
+    pub fn is_waiting(&self, id: &'static str) -> bool {
+        self.deadlines
+            .iter()
+            .any(|(timer, due)| *timer == id && Instant::now() < *due)
+    }
+
+    /// Returns `true` once when `id` is due, clearing it.
+    pub fn fire(&mut self, id: &'static str) -> bool {
+        self.fire_at(id, Instant::now())
+    }
+
+    pub fn fire_at(&mut self, id: &'static str, now: Instant) -> bool {
+        let Some(index) = self
+            .deadlines
+            .iter()
+            .position(|(timer, due)| *timer == id && *due <= now)
+        else {
+            return false;
+        };
+        self.deadlines.swap_remove(index);
+        true
+    }
+
+    /// Clears and returns every timer that is due, earliest first.
+    pub fn take_due(&mut self, now: Instant) -> Vec<&'static str> {
+        let mut due = self
+            .deadlines
+            .iter()
+            .filter(|(_, deadline)| *deadline <= now)
+            .copied()
+            .collect::<Vec<_>>();
+        due.sort_by_key(|(_, deadline)| *deadline);
+        self.deadlines.retain(|(_, deadline)| *deadline > now);
+        due.into_iter().map(|(id, _)| id).collect()
+    }
+
+    /// Lets `id` through at most once per `window`: returns `true` and opens
+    /// a new window when the last one has closed.
+    pub fn throttle(&mut self, id: &'static str, window: Duration) -> bool {
+        self.throttle_at(id, window, Instant::now())
+    }
+
+    pub fn throttle_at(&mut self, id: &'static str, window: Duration, now: Instant) -> bool {
+        match self.windows.iter_mut().find(|(timer, _)| *timer == id) {
+            Some((_, until)) if now < *until => false,
+            Some((_, until)) => {
+                *until = now + window;
+                true
+            }
+            None => {
+                self.windows.push((id, now + window));
+                true
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/timers.rs"]
+mod tests;
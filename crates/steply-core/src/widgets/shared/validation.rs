@@ -1,16 +1,14 @@
-use crate::ui::span::{Span, SpanLine};
-use crate::ui::style::{Color, Style};
+use crate::ui::markup;
+use crate::ui::span::SpanLine;
+use crate::ui::style::{Color, StyleRole};
 use crate::widgets::traits::RenderContext;
 
 pub fn decorate_component_validation(lines: &mut Vec<SpanLine>, ctx: &RenderContext, id: &str) {
     if let Some(error) = ctx.visible_errors.get(id) {
-        lines.push(vec![
-            Span::styled(
-                format!("✗ {}", error),
-                Style::new().color(Color::Red).bold(),
-            )
-            .no_wrap(),
-        ]);
+        lines.push(markup::parse_no_wrap(
+            format!("✗ {error}").as_str(),
+            StyleRole::Error.style(),
+        ));
         return;
     }
 
@@ -25,12 +25,48 @@ pub const TEXTAREA_HINTS: &[StaticHintSpec] = &[
     StaticHintSpec::new("Home / End", "line start/end", HintGroup::Navigation, 12),
 ];
 
-pub const COMMAND_RUNNER_HINTS: &[StaticHintSpec] = &[StaticHintSpec::new(
-    "Enter",
-    "run command",
-    HintGroup::Action,
-    20,
-)];
+pub const TEXTAREA_NEWLINE_HINTS: &[StaticHintSpec] = &[
+    StaticHintSpec::new("Enter", "new line", HintGroup::Edit, 10),
+    StaticHintSpec::new("Ctrl+Enter / Esc", "finish", HintGroup::Action, 20),
+    StaticHintSpec::new("← → ↑ ↓", "move cursor", HintGroup::Navigation, 11),
+    StaticHintSpec::new("Home / End", "line start/end", HintGroup::Navigation, 12),
+];
+
+pub const TEXTAREA_READ_ONLY_HINTS: &[StaticHintSpec] = &[
+    StaticHintSpec::new("↑ ↓", "scroll", HintGroup::Navigation, 10),
+    StaticHintSpec::new("PgUp / PgDn", "page", HintGroup::Navigation, 11),
+    StaticHintSpec::new("Home / End", "line start/end", HintGroup::Navigation, 12),
+];
+
+pub const TASK_DETAILS_LIST_HINTS: &[StaticHintSpec] = &[
+    StaticHintSpec::new("↑ ↓", "select task", HintGroup::Navigation, 10),
+    StaticHintSpec::new("Enter", "show log", HintGroup::Action, 20),
+    StaticHintSpec::new("Esc / Ctrl+T", "close", HintGroup::View, 30),
+];
+
+pub const TASK_DETAILS_LOG_HINTS: &[StaticHintSpec] = &[
+    StaticHintSpec::new("↑ ↓", "scroll", HintGroup::Navigation, 10),
+    StaticHintSpec::new("Home", "oldest line", HintGroup::Navigation, 12),
+    StaticHintSpec::new("F / End", "follow output", HintGroup::Navigation, 11),
+    StaticHintSpec::new("Esc", "back to tasks", HintGroup::View, 30),
+];
+
+pub const LOG_OUTPUT_HINTS: &[StaticHintSpec] = &[
+    StaticHintSpec::new("↑ ↓", "scroll", HintGroup::Navigation, 10),
+    StaticHintSpec::new("F / End", "follow output", HintGroup::Navigation, 11),
+    StaticHintSpec::new("/", "search", HintGroup::Action, 20),
+    StaticHintSpec::new("n / N", "next/previous match", HintGroup::Navigation, 12),
+];
+
+pub const LOG_OUTPUT_SEARCH_HINTS: &[StaticHintSpec] = &[
+    StaticHintSpec::new("Enter", "keep search", HintGroup::Action, 20),
+    StaticHintSpec::new("Esc", "clear search", HintGroup::Action, 21),
+];
+
+pub const COMMAND_RUNNER_HINTS: &[StaticHintSpec] = &[
+    StaticHintSpec::new("Enter", "run command", HintGroup::Action, 20),
+    StaticHintSpec::new("↑ ↓", "scroll output", HintGroup::Navigation, 10),
+];
 
 pub const CALENDAR_COMMON_HINTS: &[StaticHintSpec] = &[
     StaticHintSpec::new("Enter", "select / submit", HintGroup::Action, 20),
@@ -53,6 +89,31 @@ pub const CALENDAR_TIME_HINTS: &[StaticHintSpec] = &[
     StaticHintSpec::new("Enter", "select / submit", HintGroup::Action, 20),
 ];
 
+pub const DATE_INPUT_HINTS: &[StaticHintSpec] = &[
+    StaticHintSpec::new("Type", "enter date", HintGroup::Edit, 10),
+    StaticHintSpec::new("↓", "open calendar", HintGroup::Navigation, 11),
+    StaticHintSpec::new("Enter", "confirm", HintGroup::Action, 20),
+];
+
+pub const DATE_INPUT_CALENDAR_HINTS: &[StaticHintSpec] = &[
+    StaticHintSpec::new("← → ↑ ↓", "move day cursor", HintGroup::Navigation, 10),
+    StaticHintSpec::new(
+        "PgUp / PgDn",
+        "previous/next month",
+        HintGroup::Navigation,
+        11,
+    ),
+    StaticHintSpec::new("Enter", "pick date", HintGroup::Action, 20),
+    StaticHintSpec::new("Esc", "close calendar", HintGroup::Action, 21),
+];
+
+pub const DURATION_INPUT_HINTS: &[StaticHintSpec] = &[
+    StaticHintSpec::new("← →", "switch field", HintGroup::Navigation, 10),
+    StaticHintSpec::new("↑ ↓", "increase/decrease", HintGroup::Edit, 11),
+    StaticHintSpec::new("Type", "enter duration (1h30m)", HintGroup::Edit, 12),
+    StaticHintSpec::new("Enter", "confirm", HintGroup::Action, 20),
+];
+
 pub const FILE_BROWSER_DOC_HINTS: &[StaticHintSpec] = &[
     StaticHintSpec::new("Tab", "completion", HintGroup::Completion, 10),
     StaticHintSpec::new("Ctrl+Space", "toggle completion", HintGroup::Completion, 11),
@@ -68,6 +129,18 @@ pub const FILE_BROWSER_DOC_HINTS: &[StaticHintSpec] = &[
     StaticHintSpec::new("↑ ↓", "move entries", HintGroup::Navigation, 13),
     StaticHintSpec::new("Space", "expand/collapse", HintGroup::Navigation, 14),
     StaticHintSpec::new("Ctrl+T", "switch tree/list", HintGroup::View, 22),
+    StaticHintSpec::new(
+        "Ctrl+A / Ctrl+X",
+        "mark shown/clear marks (multi)",
+        HintGroup::Action,
+        27,
+    ),
+    StaticHintSpec::new(
+        "Ctrl+N / Ctrl+R / Ctrl+D",
+        "new folder/rename/delete (file_ops)",
+        HintGroup::Action,
+        28,
+    ),
 ];
 
 pub const REPEATER_HINTS: &[StaticHintSpec] = &[
@@ -80,6 +153,12 @@ pub const SELECT_LIST_DOC_HINTS: &[StaticHintSpec] = &[
     StaticHintSpec::new("↑ ↓", "move", HintGroup::Navigation, 10),
     StaticHintSpec::new("Enter", "confirm", HintGroup::Action, 20),
     StaticHintSpec::new("Space", "toggle selection", HintGroup::Action, 21),
+    StaticHintSpec::new(
+        "Ctrl+A / Ctrl+I",
+        "select all / invert",
+        HintGroup::Action,
+        22,
+    ),
     StaticHintSpec::new("Ctrl+F", "toggle filter", HintGroup::View, 30),
     StaticHintSpec::new("Esc", "close filter", HintGroup::View, 31),
 ];
@@ -94,6 +173,7 @@ pub const TREE_VIEW_DOC_HINTS: &[StaticHintSpec] = &[
     StaticHintSpec::new("→", "expand", HintGroup::Navigation, 11),
     StaticHintSpec::new("←", "collapse / parent", HintGroup::Navigation, 12),
     StaticHintSpec::new("Enter", "select", HintGroup::Action, 20),
+    StaticHintSpec::new("Space", "check / uncheck", HintGroup::Action, 21),
     StaticHintSpec::new("Ctrl+F", "toggle filter", HintGroup::View, 30),
     StaticHintSpec::new("Esc", "leave filter", HintGroup::View, 31),
 ];
@@ -112,6 +192,12 @@ pub const TABLE_DOC_HINTS: &[StaticHintSpec] = &[
     StaticHintSpec::new("i / d", "insert/delete row", HintGroup::Action, 21),
     StaticHintSpec::new("m", "move row", HintGroup::Action, 22),
     StaticHintSpec::new("Space", "sort column", HintGroup::Action, 20),
+    StaticHintSpec::new(
+        "Tab (in filter)",
+        "filter one column/all",
+        HintGroup::Navigation,
+        12,
+    ),
 ];
 
 pub const OBJECT_EDITOR_DOC_HINTS: &[StaticHintSpec] = &[
@@ -120,6 +206,8 @@ pub const OBJECT_EDITOR_DOC_HINTS: &[StaticHintSpec] = &[
     StaticHintSpec::new("Space / ← →", "expand/collapse", HintGroup::Navigation, 11),
     StaticHintSpec::new("e / r", "edit value/key", HintGroup::Action, 20),
     StaticHintSpec::new("i / d / m", "insert/delete/move", HintGroup::Action, 21),
+    StaticHintSpec::new("y / Y", "copy JSON (row/all)", HintGroup::Action, 22),
+    StaticHintSpec::new("p / P", "paste JSON (row/all)", HintGroup::Action, 23),
     StaticHintSpec::new("Enter", "confirm", HintGroup::Action, 20),
     StaticHintSpec::new("Esc", "cancel", HintGroup::Action, 21),
 ];
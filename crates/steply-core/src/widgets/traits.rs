@@ -1,11 +1,19 @@
+use crate::core::i18n::{Message, MessageCatalog};
+use crate::core::secrets::SecretKey;
 use crate::core::value::Value;
 use crate::runtime::event::{SystemEvent, WidgetAction};
 use crate::state::change::StoreCommitPolicy;
 use crate::state::store::ValueStore;
-use crate::task::TaskSpec;
-use crate::terminal::{CursorPos, KeyEvent, PointerEvent, PointerSemantic, TerminalSize};
+use crate::task::{TaskGraph, TaskSpec};
+use crate::terminal::{
+    CursorPos, KeyEvent, PointerEvent, PointerSemantic, TerminalCapabilities, TerminalSize,
+};
 use crate::ui::inline::{InlineLine, flatten_lines};
+use crate::ui::markup;
 use crate::ui::span::{Span, SpanLine};
+use crate::ui::style::Style;
+use crate::ui::theme::Theme;
+use crate::widgets::node::Node;
 use crate::widgets::shared::binding::StoreBinding;
 use crate::widgets::shared::text_edit;
 use serde::Serialize;
@@ -35,6 +43,9 @@ pub enum OverlayMode {
 pub enum OverlayRenderMode {
     Floating,
     Inline,
+    /// Inline after the last line of the frame; the placement's row and
+    /// column are ignored.
+    Appended,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -57,18 +68,76 @@ impl OverlayPlacement {
         }
     }
 
+    /// A full-width panel below everything else on screen.
+    pub fn appended() -> Self {
+        Self::new(0, 0, 0, 0).with_render_mode(OverlayRenderMode::Appended)
+    }
+
     pub fn with_render_mode(mut self, render_mode: OverlayRenderMode) -> Self {
         self.render_mode = render_mode;
         self
     }
 }
 
+/// Which side of its anchor a popover opens on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PopoverSide {
+    /// Below, unless it would run past the bottom edge and fits above.
+    #[default]
+    Auto,
+    Below,
+    Above,
+}
+
+/// Positions a floating overlay next to the rows another widget was drawn
+/// on, instead of at the fixed row and column of its placement.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OverlayAnchor {
+    pub target: String,
+    pub side: PopoverSide,
+}
+
+impl OverlayAnchor {
+    pub fn new(target: impl Into<String>, side: PopoverSide) -> Self {
+        Self {
+            target: target.into(),
+            side,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ValidationMode {
     Live,
     Submit,
 }
 
+/// One problem found by validation, as listed in the error summary.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationIssue {
+    pub message: Message,
+    /// Where inside the widget the problem is, e.g. `row 2, Port`.
+    pub location: Option<String>,
+    /// Handed to [`Interactive::reveal`] to move to the offending part.
+    pub target: Option<String>,
+}
+
+impl ValidationIssue {
+    pub fn new(message: impl Into<Message>) -> Self {
+        Self {
+            message: message.into(),
+            location: None,
+            target: None,
+        }
+    }
+
+    pub fn at(mut self, location: impl Into<String>, target: impl Into<String>) -> Self {
+        self.location = Some(location.into());
+        self.target = Some(target.into());
+        self
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct CompletionMenu {
     pub matches: Vec<String>,
@@ -84,7 +153,13 @@ pub struct RenderContext {
     pub visible_errors: Arc<HashMap<String, String>>,
 
     pub invalid_hidden: Arc<HashSet<String>>,
+    pub prefilled: Arc<HashSet<String>>,
     pub completion_menus: Arc<HashMap<String, CompletionMenu>>,
+    pub messages: Arc<MessageCatalog>,
+    pub capabilities: TerminalCapabilities,
+    pub theme: Arc<Theme>,
+    /// Live status of the tasks linked by dependencies.
+    pub task_graph: Arc<TaskGraph>,
 }
 
 impl RenderContext {
@@ -94,7 +169,12 @@ impl RenderContext {
             terminal_size,
             visible_errors: Arc::new(HashMap::new()),
             invalid_hidden: Arc::new(HashSet::new()),
+            prefilled: Arc::new(HashSet::new()),
             completion_menus: Arc::new(HashMap::new()),
+            messages: Arc::new(MessageCatalog::default()),
+            capabilities: TerminalCapabilities::default(),
+            theme: Arc::new(Theme::default()),
+            task_graph: Arc::new(TaskGraph::default()),
         }
     }
 
@@ -104,7 +184,12 @@ impl RenderContext {
             terminal_size: self.terminal_size,
             visible_errors: self.visible_errors.clone(),
             invalid_hidden: self.invalid_hidden.clone(),
+            prefilled: self.prefilled.clone(),
             completion_menus: self.completion_menus.clone(),
+            messages: self.messages.clone(),
+            capabilities: self.capabilities,
+            theme: self.theme.clone(),
+            task_graph: self.task_graph.clone(),
         }
     }
 
@@ -117,7 +202,12 @@ impl RenderContext {
             },
             visible_errors: self.visible_errors.clone(),
             invalid_hidden: self.invalid_hidden.clone(),
+            prefilled: self.prefilled.clone(),
             completion_menus: self.completion_menus.clone(),
+            messages: self.messages.clone(),
+            capabilities: self.capabilities,
+            theme: self.theme.clone(),
+            task_graph: self.task_graph.clone(),
         }
     }
 
@@ -137,10 +227,30 @@ impl RenderContext {
             terminal_size: self.terminal_size,
             visible_errors: self.visible_errors.clone(),
             invalid_hidden: self.invalid_hidden.clone(),
+            prefilled: self.prefilled.clone(),
             completion_menus: Arc::new(completion_menus),
+            messages: self.messages.clone(),
+            capabilities: self.capabilities,
+            theme: self.theme.clone(),
+            task_graph: self.task_graph.clone(),
         }
     }
 
+    /// Translates a label or message through the active locale's catalog.
+    pub fn translate<'a>(&'a self, text: &'a str) -> Cow<'a, str> {
+        self.messages.translate(text)
+    }
+
+    /// A widget's own label line: translated, with its markup applied.
+    pub fn label_line(&self, label: &str, style: Style) -> SpanLine {
+        markup::parse_no_wrap(self.translate(label).as_ref(), style)
+    }
+
+    /// Formats a message in the active locale.
+    pub fn render<'a>(&'a self, message: &'a Message) -> Cow<'a, str> {
+        self.messages.render(message)
+    }
+
     pub fn for_child(&self, parent_id: &str, focused_child_id: Option<String>) -> Self {
         self.with_focus(focused_child_id.clone())
             .with_completion_owner(parent_id, focused_child_id.as_deref())
@@ -434,10 +544,28 @@ pub trait Interactive: Send {
     fn commit_policy(&self) -> StoreCommitPolicy {
         StoreCommitPolicy::Immediate
     }
+    /// The secret store entry this widget's value belongs in, kept out of
+    /// exported answers.
+    fn secret(&self) -> Option<&SecretKey> {
+        None
+    }
+    /// Whether the value is hidden on screen, as a password is. Masked
+    /// values are never shown in the fullscreen preview.
+    fn value_masked(&self) -> bool {
+        false
+    }
+    /// A read-only view of the value to fill the fullscreen preview, `rows`
+    /// tall. `None` lets the app pick one from the value itself.
+    fn expanded_view(&self, _id: &str, _rows: usize) -> Option<Node> {
+        None
+    }
 
     fn overlay_placement(&self) -> Option<OverlayPlacement> {
         None
     }
+    fn overlay_anchor(&self) -> Option<OverlayAnchor> {
+        None
+    }
     fn overlay_open(&mut self, _saved_focus_id: Option<String>) -> bool {
         false
     }
@@ -469,6 +597,19 @@ pub trait Interactive: Send {
         }
     }
 
+    fn on_paste(&mut self, text: &str) -> InteractionResult {
+        let text = text_edit::single_line_paste(text);
+        let Some(state) = self.text_editing() else {
+            return InteractionResult::ignored();
+        };
+        if text.is_empty() {
+            return InteractionResult::handled();
+        }
+        text_edit::insert_str(state.value, state.cursor, text.as_str());
+        self.on_text_edited();
+        InteractionResult::handled()
+    }
+
     fn completion(&mut self) -> Option<CompletionState<'_>> {
         None
     }
@@ -479,6 +620,11 @@ pub trait Interactive: Send {
     fn on_tick(&mut self) -> InteractionResult {
         InteractionResult::ignored()
     }
+    /// Whether `on_tick` has work to do soon (async polls, debounces,
+    /// animations). The runtime slows its tick rate while no widget does.
+    fn wants_tick(&self) -> bool {
+        false
+    }
     fn cursor_pos(&self) -> Option<CursorPos> {
         None
     }
@@ -513,10 +659,26 @@ pub trait Interactive: Send {
         self.sync_from_store(store)
     }
 
-    fn validate(&self, _mode: ValidationMode) -> Result<(), String> {
+    fn validate(&self, _mode: ValidationMode) -> Result<(), Message> {
         Ok(())
     }
 
+    /// Every problem `validate` stands for, one per offending part. Widgets
+    /// that hold several fields override this to point at each of them.
+    fn validation_issues(&self, mode: ValidationMode) -> Vec<ValidationIssue> {
+        self.validate(mode)
+            .err()
+            .map(ValidationIssue::new)
+            .into_iter()
+            .collect()
+    }
+
+    /// Moves the widget's own cursor to the part an issue's `target` names.
+    /// Returns whether it was found.
+    fn reveal(&mut self, _target: &str) -> bool {
+        false
+    }
+
     fn task_specs(&self) -> Vec<TaskSpec> {
         Vec::new()
     }
@@ -555,10 +717,13 @@ pub trait OutputNode: Drawable {
     fn on_tick(&mut self) -> InteractionResult {
         InteractionResult::ignored()
     }
+    fn wants_tick(&self) -> bool {
+        false
+    }
     fn on_system_event(&mut self, _event: &SystemEvent) -> InteractionResult {
         InteractionResult::ignored()
     }
-    fn validate(&self) -> Result<(), String> {
+    fn validate(&self) -> Result<(), Message> {
         Ok(())
     }
 
@@ -0,0 +1,97 @@
+use std::fmt;
+use std::path::{Component, PathBuf};
+use std::sync::Arc;
+
+use crate::core::value::Value;
+
+/// Rewrites a widget value into its canonical form as it is committed to
+/// the store, so later steps and the result only ever see that form.
+#[derive(Clone)]
+pub struct Transform(Arc<dyn Fn(Value) -> Value + Send + Sync>);
+
+impl Transform {
+    pub fn new(transform: impl Fn(Value) -> Value + Send + Sync + 'static) -> Self {
+        Self(Arc::new(transform))
+    }
+
+    pub fn apply(&self, value: Value) -> Value {
+        (self.0)(value)
+    }
+}
+
+impl fmt::Debug for Transform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Transform(..)")
+    }
+}
+
+pub fn run_transforms(transforms: &[Transform], value: Value) -> Value {
+    transforms
+        .iter()
+        .fold(value, |value, transform| transform.apply(value))
+}
+
+/// Applies `f` to text, and to each text item of a list.
+fn map_text(value: Value, f: impl Fn(&str) -> String) -> Value {
+    match value {
+        Value::Text(text) => Value::Text(f(&text)),
+        Value::List(items) => Value::List(
+            items
+                .into_iter()
+                .map(|item| match item {
+                    Value::Text(text) => Value::Text(f(&text)),
+                    other => other,
+                })
+                .collect(),
+        ),
+        other => other,
+    }
+}
+
+pub fn trim() -> Transform {
+    Transform::new(|value| map_text(value, |text| text.trim().to_string()))
+}
+
+pub fn lowercase() -> Transform {
+    Transform::new(|value| map_text(value, str::to_lowercase))
+}
+
+pub fn uppercase() -> Transform {
+    Transform::new(|value| map_text(value, str::to_uppercase))
+}
+
+/// Drops `.` segments, repeated and trailing separators, and folds `..`
+/// into the segment before it where there is one. The file system is not
+/// consulted, so symlinks are left alone.
+pub fn normalize_path() -> Transform {
+    Transform::new(|value| map_text(value, normalize_path_text))
+}
+
+fn normalize_path_text(text: &str) -> String {
+    if text.is_empty() {
+        return String::new();
+    }
+    let mut out = PathBuf::new();
+    let mut depth = 0usize;
+    for component in PathBuf::from(text).components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir if depth > 0 => {
+                out.pop();
+                depth -= 1;
+            }
+            // Nothing is above the root.
+            Component::ParentDir if out.has_root() => {}
+            Component::ParentDir => out.push(".."),
+            Component::Normal(part) => {
+                out.push(part);
+                depth += 1;
+            }
+            root => out.push(root.as_os_str()),
+        }
+    }
+    if out.as_os_str().is_empty() {
+        return ".".to_string();
+    }
+    out.to_string_lossy().into_owned()
+}
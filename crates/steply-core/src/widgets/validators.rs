@@ -1,8 +1,9 @@
+use crate::core::i18n::Message;
 use crate::core::value::Value;
 
-pub type Validator = Box<dyn Fn(&Value) -> Result<(), String> + Send + Sync>;
+pub type Validator = Box<dyn Fn(&Value) -> Result<(), Message> + Send + Sync>;
 
-pub fn run_validators(validators: &[Validator], value: &Value) -> Result<(), String> {
+pub fn run_validators(validators: &[Validator], value: &Value) -> Result<(), Message> {
     for v in validators {
         v(value)?;
     }
@@ -12,7 +13,7 @@ pub fn run_validators(validators: &[Validator], value: &Value) -> Result<(), Str
 pub fn required() -> Validator {
     Box::new(|v| {
         if v.is_empty() {
-            Err("This field is required.".into())
+            Err(Message::builtin("validation-required"))
         } else {
             Ok(())
         }
@@ -23,7 +24,7 @@ pub fn required_msg(msg: impl Into<String> + 'static) -> Validator {
     let msg = msg.into();
     Box::new(move |v| {
         if v.is_empty() {
-            Err(msg.clone())
+            Err(msg.clone().into())
         } else {
             Ok(())
         }
@@ -35,7 +36,7 @@ pub fn min_length(n: usize) -> Validator {
         if let Value::Text(s) = v
             && s.chars().count() < n
         {
-            return Err(format!("Minimum {n} characters required."));
+            return Err(Message::builtin("validation-min-length").with_arg("min", n));
         }
         Ok(())
     })
@@ -46,7 +47,7 @@ pub fn max_length(n: usize) -> Validator {
         if let Value::Text(s) = v
             && s.chars().count() > n
         {
-            return Err(format!("Maximum {n} characters allowed."));
+            return Err(Message::builtin("validation-max-length").with_arg("max", n));
         }
         Ok(())
     })
@@ -57,7 +58,7 @@ pub fn min_selections(n: usize) -> Validator {
         if let Value::List(items) = v
             && items.len() < n
         {
-            return Err(format!("Select at least {n} option(s)."));
+            return Err(Message::builtin("validation-min-selections").with_arg("min", n));
         }
         Ok(())
     })
@@ -68,7 +69,7 @@ pub fn max_selections(n: usize) -> Validator {
         if let Value::List(items) = v
             && items.len() > n
         {
-            return Err(format!("Select at most {n} option(s)."));
+            return Err(Message::builtin("validation-max-selections").with_arg("max", n));
         }
         Ok(())
     })
@@ -77,7 +78,7 @@ pub fn max_selections(n: usize) -> Validator {
 pub fn must_be_checked() -> Validator {
     Box::new(|v| match v {
         Value::Bool(true) => Ok(()),
-        _ => Err("This field must be checked.".into()),
+        _ => Err(Message::builtin("validation-must-be-checked")),
     })
 }
 
@@ -86,7 +87,7 @@ pub fn min_value(n: f64) -> Validator {
         if let Some(num) = v.as_number()
             && num < n
         {
-            return Err(format!("Value must be at least {n}."));
+            return Err(Message::builtin("validation-min-value").with_arg("min", n));
         }
         Ok(())
     })
@@ -97,7 +98,7 @@ pub fn max_value(n: f64) -> Validator {
         if let Some(num) = v.as_number()
             && num > n
         {
-            return Err(format!("Value must be at most {n}."));
+            return Err(Message::builtin("validation-max-value").with_arg("max", n));
         }
         Ok(())
     })
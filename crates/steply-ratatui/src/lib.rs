@@ -0,0 +1,14 @@
+//! Embeds steply widgets in ratatui applications without the steply runtime.
+//!
+//! Wrap any [`Node`](steply_core::widgets::node::Node) in a [`WidgetState`],
+//! draw it with [`SteplyWidget`] (or the state itself, as a plain `Widget`)
+//! and feed it crossterm events through [`WidgetState::handle_crossterm`].
+
+mod state;
+mod style;
+mod widget;
+
+pub use state::WidgetState;
+pub use steply_runtime::terminal::{map_event, map_key_event};
+pub use style::{ratatui_color, ratatui_style};
+pub use widget::SteplyWidget;
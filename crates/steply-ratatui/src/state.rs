@@ -0,0 +1,185 @@
+use std::cell::Cell;
+use std::sync::Arc;
+
+use ratatui_core::layout::{Position, Rect};
+use steply_core::core::value::Value;
+use steply_core::terminal::{KeyEvent, TerminalCapabilities, TerminalEvent, TerminalSize};
+use steply_core::ui::glyphs::use_ascii_glyphs;
+use steply_core::ui::renderer::{NodeRender, render_node};
+use steply_core::ui::span::SpanLine;
+use steply_core::ui::theme::Theme;
+use steply_core::widgets::node::Node;
+use steply_core::widgets::traits::{InteractionResult, RenderContext, ValidationMode};
+
+use crate::map_event;
+
+/// One steply widget living inside a ratatui app: the widget itself, whether
+/// it has focus, and the area it was last drawn in so pointer events and the
+/// cursor can be placed.
+pub struct WidgetState {
+    node: Node,
+    focused: bool,
+    theme: Arc<Theme>,
+    capabilities: TerminalCapabilities,
+    error: Option<String>,
+    area: Cell<Rect>,
+    cursor: Cell<Option<Position>>,
+}
+
+impl WidgetState {
+    /// Wraps `node` focused, with the default theme.
+    pub fn new(node: Node) -> Self {
+        Self {
+            node,
+            focused: true,
+            theme: Arc::new(Theme::default()),
+            capabilities: TerminalCapabilities::default(),
+            error: None,
+            area: Cell::new(Rect::default()),
+            cursor: Cell::new(None),
+        }
+    }
+
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = Arc::new(theme);
+        self
+    }
+
+    /// Draws with ASCII fallbacks when `capabilities` lack unicode.
+    pub fn with_capabilities(mut self, capabilities: TerminalCapabilities) -> Self {
+        self.capabilities = capabilities;
+        self
+    }
+
+    pub fn node(&self) -> &Node {
+        &self.node
+    }
+
+    pub fn node_mut(&mut self) -> &mut Node {
+        &mut self.node
+    }
+
+    pub fn into_node(self) -> Node {
+        self.node
+    }
+
+    pub fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    /// Unfocused widgets draw without cursor or active styles and ignore
+    /// input.
+    pub fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+
+    pub fn value(&self) -> Option<Value> {
+        self.node.value()
+    }
+
+    pub fn set_value(&mut self, value: Value) {
+        self.node.set_value(value);
+        self.error = None;
+    }
+
+    /// Runs the widget's validators, showing the message (in English) under
+    /// the widget until the next edit.
+    pub fn validate(&mut self) -> Result<(), String> {
+        let result = self
+            .node
+            .validate(ValidationMode::Submit)
+            .map_err(|error| error.to_string());
+        self.error = result.as_ref().err().cloned();
+        result
+    }
+
+    /// Feeds a crossterm event to the widget; events steply has no use for
+    /// are ignored.
+    pub fn handle_crossterm(&mut self, event: crossterm::event::Event) -> InteractionResult {
+        match map_event(event) {
+            Some(event) => self.handle_event(event),
+            None => InteractionResult::ignored(),
+        }
+    }
+
+    pub fn handle_event(&mut self, event: TerminalEvent) -> InteractionResult {
+        if !self.focused {
+            return InteractionResult::ignored();
+        }
+        let result = match event {
+            TerminalEvent::Key(key) => return self.handle_key(key),
+            TerminalEvent::Paste(text) => self.node.on_paste(text.as_str()),
+            TerminalEvent::Pointer(mut pointer) => {
+                let area = self.area.get();
+                if !area.contains(Position::new(pointer.col, pointer.row)) {
+                    return InteractionResult::ignored();
+                }
+                pointer.col -= area.x;
+                pointer.row -= area.y;
+                self.node.on_pointer(pointer)
+            }
+            _ => InteractionResult::ignored(),
+        };
+        self.after_input(result)
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> InteractionResult {
+        if !self.focused {
+            return InteractionResult::ignored();
+        }
+        let result = self.node.on_key(key);
+        self.after_input(result)
+    }
+
+    /// Where the terminal cursor belongs after the last draw, for
+    /// `Frame::set_cursor_position`.
+    pub fn cursor_position(&self) -> Option<Position> {
+        if !self.focused {
+            return None;
+        }
+        self.cursor.get()
+    }
+
+    pub(crate) fn draw_lines(&self, area: Rect) -> Vec<SpanLine> {
+        self.area.set(area);
+        let mut ctx = RenderContext::empty(TerminalSize {
+            width: area.width,
+            height: area.height,
+        });
+        ctx.capabilities = self.capabilities;
+        ctx.theme = self.theme.clone();
+        if let Some(error) = self.error.as_ref() {
+            ctx.visible_errors = Arc::new(
+                [(self.node.id().to_string(), error.clone())]
+                    .into_iter()
+                    .collect(),
+            );
+        }
+        let ctx = ctx.with_focus(self.focused.then(|| self.node.id().to_string()));
+        let NodeRender {
+            mut lines,
+            cursor,
+            cursor_visible,
+        } = render_node(&self.node, &ctx);
+        let cursor = cursor.filter(|_| cursor_visible).map(|cursor| {
+            Position::new(
+                area.x.saturating_add(cursor.col),
+                area.y.saturating_add(cursor.row),
+            )
+        });
+        self.cursor
+            .set(cursor.filter(|position| area.contains(*position)));
+        self.theme.apply(&mut lines);
+        if self.capabilities.ascii_glyphs() {
+            use_ascii_glyphs(&mut lines);
+        }
+        lines
+    }
+
+    fn after_input(&mut self, result: InteractionResult) -> InteractionResult {
+        if result.handled {
+            self.error = None;
+        }
+        result
+    }
+}
@@ -0,0 +1,53 @@
+use ratatui_core::style::{Color as RatatuiColor, Modifier, Style as RatatuiStyle};
+use steply_core::ui::style::{Color, Strike, Style, Underline};
+
+/// The ratatui color a steply color is drawn with by the steply runtime.
+pub fn ratatui_color(color: Color) -> RatatuiColor {
+    match color {
+        Color::Reset => RatatuiColor::Reset,
+        Color::Black => RatatuiColor::Black,
+        Color::DarkGrey => RatatuiColor::DarkGray,
+        // The runtime writes the bright variants of red and green.
+        Color::Red => RatatuiColor::LightRed,
+        Color::Green => RatatuiColor::LightGreen,
+        Color::Yellow => RatatuiColor::Yellow,
+        Color::Blue => RatatuiColor::Blue,
+        Color::Magenta => RatatuiColor::Magenta,
+        Color::Cyan => RatatuiColor::Cyan,
+        Color::White => RatatuiColor::White,
+        Color::Indexed(index) => RatatuiColor::Indexed(index),
+        Color::Rgb(r, g, b) => RatatuiColor::Rgb(r, g, b),
+    }
+}
+
+pub fn ratatui_style(style: &Style) -> RatatuiStyle {
+    let mut out = RatatuiStyle::default();
+    if let Some(color) = style.color {
+        out = out.fg(ratatui_color(color));
+    }
+    if let Some(background) = style.background {
+        out = out.bg(ratatui_color(background));
+    }
+    if let Some(color) = style.underline_color {
+        out = out.underline_color(ratatui_color(color));
+    }
+    let mut modifiers = Modifier::empty();
+    if style.bold {
+        modifiers |= Modifier::BOLD;
+    } else if style.dim {
+        modifiers |= Modifier::DIM;
+    }
+    if style.italic {
+        modifiers |= Modifier::ITALIC;
+    }
+    if style.reverse {
+        modifiers |= Modifier::REVERSED;
+    }
+    if style.underline != Underline::None {
+        modifiers |= Modifier::UNDERLINED;
+    }
+    if style.strike == Strike::On {
+        modifiers |= Modifier::CROSSED_OUT;
+    }
+    out.add_modifier(modifiers)
+}
@@ -0,0 +1,69 @@
+use crate::{SteplyWidget, WidgetState};
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::{Position, Rect};
+use ratatui_core::style::Color;
+use ratatui_core::widgets::StatefulWidget;
+use steply_core::core::value::Value;
+use steply_core::widgets::inputs::text::TextInput;
+use steply_core::widgets::node::Node;
+
+fn name_input() -> WidgetState {
+    WidgetState::new(Node::Input(Box::new(TextInput::new("name", "Name"))))
+}
+
+fn row_text(buf: &Buffer, row: u16) -> String {
+    (0..buf.area.width)
+        .map(|col| buf[(col, row)].symbol())
+        .collect::<String>()
+        .trim_end()
+        .to_string()
+}
+
+#[test]
+fn crossterm_keys_edit_the_widget_and_render_into_the_buffer() {
+    let mut state = name_input();
+    for ch in "ada".chars() {
+        let result = state.handle_crossterm(Event::Key(KeyEvent::new(
+            KeyCode::Char(ch),
+            KeyModifiers::NONE,
+        )));
+        assert!(result.handled);
+    }
+    assert_eq!(state.value(), Some(Value::Text("ada".to_string())));
+
+    let area = Rect::new(2, 1, 20, 2);
+    let mut buf = Buffer::empty(Rect::new(0, 0, 24, 4));
+    SteplyWidget.render(area, &mut buf, &mut state);
+
+    assert_eq!(row_text(&buf, 1), "  Name: ada");
+    assert_eq!(state.cursor_position(), Some(Position::new(11, 1)));
+}
+
+#[test]
+fn unfocused_widgets_ignore_input_and_hide_the_cursor() {
+    let mut state = name_input();
+    state.set_focused(false);
+    let result = state.handle_crossterm(Event::Key(KeyEvent::new(
+        KeyCode::Char('x'),
+        KeyModifiers::NONE,
+    )));
+
+    assert!(!result.handled);
+    assert_eq!(state.cursor_position(), None);
+}
+
+#[test]
+fn validation_errors_render_in_red_until_the_next_edit() {
+    let mut state = WidgetState::new(Node::Input(Box::new(
+        TextInput::new("name", "Name").with_validator(steply_core::widgets::validators::required()),
+    )));
+    assert!(state.validate().is_err());
+
+    let mut buf = Buffer::empty(Rect::new(0, 0, 30, 3));
+    SteplyWidget.render(buf.area, &mut buf, &mut state);
+    let error_cell = (0..3)
+        .flat_map(|row| (0..30).map(move |col| (col, row)))
+        .find(|&(col, row)| buf[(col, row)].fg == Color::LightRed);
+    assert!(error_cell.is_some(), "{buf:?}");
+}
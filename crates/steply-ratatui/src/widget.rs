@@ -0,0 +1,56 @@
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::Rect;
+use ratatui_core::widgets::{StatefulWidget, Widget};
+use steply_core::ui::text::text_display_width;
+
+use crate::WidgetState;
+use crate::style::ratatui_style;
+
+/// Draws a [`WidgetState`] into a ratatui buffer. Lines longer than the area
+/// are clipped and rows past its height are dropped.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SteplyWidget;
+
+impl StatefulWidget for SteplyWidget {
+    type State = WidgetState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        (&*state).render(area, buf);
+    }
+}
+
+impl Widget for &WidgetState {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+        for (row, line) in self.draw_lines(area).iter().enumerate() {
+            if row >= area.height as usize {
+                break;
+            }
+            let y = area.y + row as u16;
+            let mut x = area.x;
+            for span in line {
+                let remaining = area.right().saturating_sub(x);
+                if remaining == 0 {
+                    break;
+                }
+                if text_display_width(span.text.as_str()) == 0 {
+                    continue;
+                }
+                (x, _) = buf.set_stringn(
+                    x,
+                    y,
+                    span.text.as_str(),
+                    remaining as usize,
+                    ratatui_style(&span.style),
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/widget.rs"]
+mod tests;
@@ -2,26 +2,82 @@ use std::io;
 use std::io::Read;
 use std::path::PathBuf;
 
+#[cfg(feature = "cli")]
+use crate::cli_answers::{CliAnswers, field_args};
+use crate::recording::InputReplay;
+use crate::secrets::SystemSecretStore;
 use crate::terminal::{RenderMode, Terminal};
-use crate::{RenderJsonRequest, Runtime};
-use steply_core::config::{load_from_yaml_file, load_from_yaml_str};
+use crate::{RenderJsonRequest, RunOutcome, Runtime};
+use steply_core::config::{load_from_yaml_file, load_from_yaml_str, parse_values_document};
+use steply_core::core::action_bindings::{ActionBindings, KeymapPreset};
+use steply_core::core::i18n::env_locales;
+use steply_core::core::value::Value;
+use steply_core::preview::SnapshotFormat;
 use steply_core::state::demo::{build_demo_flow, build_demo_tasks};
+use steply_core::state::export::{ExportFormat, ExportTarget};
+use steply_core::state::prefill::Prefill;
+use steply_core::terminal::TerminalSize;
 use steply_core::ui::renderer::RendererConfig;
+use steply_core::ui::theme::Appearance;
 use steply_core::{HostContext, set_host_context};
 
+const UNATTENDED_SIZE: TerminalSize = TerminalSize {
+    width: 80,
+    height: 24,
+};
+
 #[derive(Debug, Clone, Default)]
 pub struct StartOptions {
     pub config_path: Option<String>,
+    pub prefill_path: Option<String>,
+    /// `.env` files written under the `env` store root before the first
+    /// render, later files overriding earlier ones.
+    pub env_files: Vec<String>,
+    /// `--<field-id> value` pairs that pre-answer fields, see
+    /// `cli_answers::field_args`. Needs the `cli` feature.
+    pub field_args: Vec<String>,
+    /// Submits leading steps whose fields are all prefilled.
+    pub skip_answered_steps: bool,
+    /// JSON or YAML answers; the flow is completed with them without a TTY.
+    pub answers_path: Option<String>,
+    pub output_path: Option<String>,
+    pub output_format: Option<ExportFormat>,
+    /// Prints the collected values as JSON on stdout and draws the UI on
+    /// stderr; also turned on by `STEPLY_RESULT_JSON=1`.
+    pub json_result: bool,
+    pub locale: Option<String>,
+    /// Window title; the current step and step count are appended.
+    pub window_title: Option<String>,
+    /// TOML theme, reloaded when it changes; falls back to `STEPLY_THEME`.
+    pub theme_path: Option<String>,
+    /// Light, dark or high-contrast default theme; asks the terminal when
+    /// unset. Falls back to `STEPLY_APPEARANCE`.
+    pub appearance: Option<Appearance>,
+    /// Draws symbols as plain ASCII; also turned on by `STEPLY_ASCII=1`.
+    pub ascii_glyphs: bool,
+    pub keymap: Option<KeymapPreset>,
+    /// File (often a FIFO) that screen-reader announcements are appended
+    /// to; falls back to `STEPLY_ANNOUNCE`.
+    pub announce_path: Option<String>,
+    /// Records state snapshots for the Alt+I inspector; also turned on by
+    /// `STEPLY_INSPECT=1`.
+    pub inspect: bool,
+    pub record_input_path: Option<String>,
+    pub replay_input_path: Option<String>,
+    /// Playback speed for `replay_input_path`; `0` replays without delays.
+    pub replay_speed: Option<f64>,
     pub render_json: Option<RenderJsonRequest>,
+    /// Prints `render_json` as a text snapshot instead of JSON.
+    pub render_format: Option<SnapshotFormat>,
 }
 
-pub fn run_with_options(options: StartOptions) -> io::Result<()> {
+pub fn run_with_options(options: StartOptions) -> io::Result<RunOutcome> {
     let _ = set_host_context(HostContext {
         cwd: std::env::current_dir().unwrap_or_else(|_| PathBuf::from("/")),
         home_dir: std::env::var_os("HOME").map(PathBuf::from),
     });
 
-    let state = if let Some(config_path) = options.config_path {
+    let mut state = if let Some(config_path) = options.config_path {
         let loaded = if config_path == "-" {
             let mut raw = String::new();
             io::stdin().read_to_string(&mut raw)?;
@@ -42,18 +98,155 @@ pub fn run_with_options(options: StartOptions) -> io::Result<()> {
         steply_core::state::app::AppState::with_tasks(flow, task_specs)
             .map_err(|err| io::Error::other(format!("app init error: {err}")))?
     };
-    let terminal = Terminal::new()?;
+    // A secret that cannot be read only loses its default; the run goes on.
+    for err in state.load_secrets(&SystemSecretStore) {
+        eprintln!("{err}");
+    }
+    if !options.env_files.is_empty() {
+        let prefill = Prefill::default()
+            .with_dotenv_files(options.env_files.as_slice())
+            .map_err(|err| io::Error::other(format!("env file error: {err}")))?;
+        state
+            .apply_prefill(&prefill)
+            .map_err(|err| io::Error::other(format!("env file error: {err}")))?;
+    }
+    if let Some(prefill_path) = options.prefill_path {
+        let raw = std::fs::read_to_string(prefill_path.as_str())?;
+        let values = Value::from_json(raw.as_str())
+            .map_err(|err| io::Error::other(format!("prefill error: {err}")))?;
+        state
+            .prefill_values(&values)
+            .map_err(|err| io::Error::other(format!("prefill error: {err}")))?;
+    }
+    #[cfg(feature = "cli")]
+    if !options.field_args.is_empty() {
+        let matches = clap::Command::new("fields")
+            .no_binary_name(true)
+            .args(field_args(&state))
+            .try_get_matches_from(options.field_args.iter())
+            .map_err(|err| io::Error::other(format!("field arguments error: {err}")))?;
+        CliAnswers::from_matches(&matches)
+            .apply(&mut state)
+            .map_err(|err| io::Error::other(format!("field arguments error: {err}")))?;
+    }
+    #[cfg(not(feature = "cli"))]
+    if !options.field_args.is_empty() {
+        return Err(io::Error::other(
+            "field arguments error: built without the `cli` feature",
+        ));
+    }
+    if options.skip_answered_steps {
+        state.skip_answered_steps();
+    }
+    if options.output_path.is_some() || options.output_format.is_some() {
+        let mut export = state.result_export().cloned().unwrap_or_default();
+        if let Some(path) = options.output_path.as_deref() {
+            export.target = ExportTarget::parse(path);
+        }
+        if let Some(format) = options.output_format {
+            export.format = format;
+            export.template = None;
+        }
+        state.set_result_export(Some(export));
+    }
+    let answers = match options.answers_path.as_deref() {
+        Some(path) => {
+            let raw = std::fs::read_to_string(path)?;
+            Some(
+                parse_values_document(raw.as_str())
+                    .map_err(|err| io::Error::other(format!("answers error: {err}")))?,
+            )
+        }
+        None => None,
+    };
+    let terminal = if answers.is_some() {
+        Terminal::headless(UNATTENDED_SIZE)
+    } else {
+        Terminal::new()?
+    };
+    // An explicit locale wins over the flow's; the environment only fills in
+    // when neither names one.
+    let negotiate_locale = options.locale.is_none() && state.localization().locale().is_none();
     let mut runtime = Runtime::new(state, terminal)
         .with_render_mode(RenderMode::AltScreen)
         .with_renderer_config(RendererConfig {
             chrome_enabled: true,
         });
+    if let Some(locale) = options.locale.as_deref() {
+        runtime = runtime.with_locale(locale);
+    } else if negotiate_locale {
+        runtime = runtime.with_preferred_locales(&env_locales(|name| std::env::var(name).ok()));
+    }
+    if let Some(title) = options.window_title {
+        runtime = runtime.with_window_title(title);
+    }
+    if let Some(path) = options.theme_path.or_else(|| {
+        std::env::var("STEPLY_THEME")
+            .ok()
+            .filter(|path| !path.is_empty())
+    }) {
+        runtime = runtime.with_theme_file(path)?;
+    }
+    if let Some(appearance) = options.appearance.or_else(|| {
+        std::env::var("STEPLY_APPEARANCE")
+            .ok()
+            .and_then(|value| Appearance::parse(value.as_str()))
+    }) {
+        runtime = runtime.with_appearance(appearance);
+    }
+    if options.ascii_glyphs || std::env::var("STEPLY_ASCII").is_ok_and(|value| value == "1") {
+        runtime = runtime.with_ascii_glyphs();
+    }
+    if options.json_result || std::env::var("STEPLY_RESULT_JSON").is_ok_and(|value| value == "1") {
+        runtime = runtime.with_json_result();
+    }
+    if let Some(preset) = options.keymap {
+        runtime = runtime.with_action_bindings(ActionBindings::preset(preset));
+    }
+
+    if let Some(path) = options.announce_path.or_else(|| {
+        std::env::var("STEPLY_ANNOUNCE")
+            .ok()
+            .filter(|path| !path.is_empty())
+    }) {
+        let out = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .map_err(|err| io::Error::other(format!("failed to open '{path}': {err}")))?;
+        runtime = runtime.with_announcements(out);
+    }
+    if options.inspect || std::env::var("STEPLY_INSPECT").is_ok_and(|value| value == "1") {
+        runtime = runtime.with_inspector();
+    }
+
+    if let Some(path) = options.record_input_path.as_deref() {
+        runtime = runtime.with_input_recording(path)?;
+    }
+    if let Some(path) = options.replay_input_path.as_deref() {
+        let replay = InputReplay::load(path)?.with_speed(options.replay_speed.unwrap_or(1.0));
+        runtime = runtime.with_input_replay(replay);
+    }
 
     if let Some(request) = options.render_json {
-        return runtime.print_render_json_with_request(request);
+        match options.render_format {
+            Some(format) => runtime.print_render_snapshot_with_request(request, format)?,
+            None => runtime.print_render_json_with_request(request)?,
+        }
+        return Ok(RunOutcome::Completed);
     }
 
-    runtime.run()
+    let outcome = match answers {
+        Some(answers) => runtime.run_unattended(&answers)?,
+        None => runtime.run()?,
+    };
+    let errors = runtime.state().save_secrets(&SystemSecretStore);
+    if !errors.is_empty() {
+        return Err(io::Error::other(errors.join("\n")));
+    }
+    runtime.state().save_previous_run_answers()?;
+    runtime.state().write_result_export()?;
+    Ok(outcome)
 }
 
 fn is_http_url(value: &str) -> bool {
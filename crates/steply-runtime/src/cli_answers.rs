@@ -0,0 +1,124 @@
+use clap::parser::ValueSource;
+use clap::{Arg, ArgAction, ArgMatches};
+use steply_core::core::value::Value;
+use steply_core::state::app::AppState;
+use steply_core::widgets::node::{NodeWalkScope, walk_nodes};
+
+/// One `--<field-id>` option per bound widget of the flow, so a host binary
+/// can accept `--name foo --port 8080` without declaring each field itself.
+/// Underscores in ids become dashes in the option name.
+pub fn field_args(state: &AppState) -> Vec<Arg> {
+    let mut args: Vec<Arg> = Vec::new();
+    for step in state.steps() {
+        walk_nodes(
+            step.nodes.as_slice(),
+            NodeWalkScope::Recursive,
+            &mut |node| {
+                let bound = node
+                    .store_binding()
+                    .is_some_and(|binding| binding.value.is_some());
+                if !bound || args.iter().any(|arg| arg.get_id() == node.id()) {
+                    return;
+                }
+                args.push(
+                    Arg::new(node.id().to_string())
+                        .long(node.id().replace('_', "-"))
+                        .value_name("VALUE")
+                        .action(ArgAction::Set)
+                        .help(node.label().to_string()),
+                );
+            },
+        );
+    }
+    args
+}
+
+/// Answers taken from parsed command line arguments and keyed by field id.
+///
+/// Works with any `ArgMatches`: for a `#[derive(Parser)]` struct, parse with
+/// `Cli::command().get_matches()` and hand the matches here before calling
+/// `Cli::from_arg_matches`. Arguments left at their default are not answers.
+#[derive(Debug, Clone, Default)]
+pub struct CliAnswers {
+    answers: Vec<(String, Value)>,
+    renames: Vec<(String, String)>,
+    skip_answered_steps: bool,
+}
+
+impl CliAnswers {
+    pub fn from_matches(matches: &ArgMatches) -> Self {
+        let mut answers = Vec::new();
+        for id in matches.ids() {
+            let id = id.as_str();
+            if matches!(
+                matches.value_source(id),
+                None | Some(ValueSource::DefaultValue)
+            ) {
+                continue;
+            }
+            if let Ok(Some(flag)) = matches.try_get_one::<bool>(id) {
+                answers.push((id.to_string(), Value::Bool(*flag)));
+                continue;
+            }
+            let Ok(Some(raw)) = matches.try_get_raw(id) else {
+                continue;
+            };
+            let mut values = raw
+                .map(|value| Value::Text(value.to_string_lossy().into_owned()))
+                .collect::<Vec<_>>();
+            let value = if values.len() == 1 {
+                values.remove(0)
+            } else {
+                Value::List(values)
+            };
+            answers.push((id.to_string(), value));
+        }
+        Self {
+            answers,
+            ..Self::default()
+        }
+    }
+
+    /// Sends the argument `arg_id` to the field `field_id` instead of the
+    /// field of the same name; an unknown target field is an error.
+    pub fn rename(mut self, arg_id: impl Into<String>, field_id: impl Into<String>) -> Self {
+        self.renames.push((arg_id.into(), field_id.into()));
+        self
+    }
+
+    /// Submits leading steps that are fully answered once applied, see
+    /// [`AppState::skip_answered_steps`].
+    pub fn skip_answered_steps(mut self, skip: bool) -> Self {
+        self.skip_answered_steps = skip;
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.answers.is_empty()
+    }
+
+    /// Prefills the answered fields. Arguments without a matching field are
+    /// ignored, so the host's own flags can share the same matches.
+    pub fn apply(&self, state: &mut AppState) -> Result<(), String> {
+        for (arg_id, value) in &self.answers {
+            let renamed = self
+                .renames
+                .iter()
+                .find(|(from, _)| from == arg_id)
+                .map(|(_, to)| to.as_str());
+            if let Err(err) = state.prefill_field(renamed.unwrap_or(arg_id), value.clone())
+                && renamed.is_some()
+            {
+                return Err(err);
+            }
+        }
+        if self.skip_answered_steps {
+            state.skip_answered_steps();
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/cli_answers.rs"]
+mod tests;
@@ -1,7 +1,7 @@
 use base64::Engine;
 use base64::engine::general_purpose::STANDARD;
 use std::io;
-use std::io::Write;
+use std::io::{IsTerminal, Write};
 use std::process::{Command, Stdio};
 
 pub fn copy_text_to_clipboard(text: &str) -> io::Result<()> {
@@ -86,11 +86,22 @@ fn run_clipboard_command(program: &str, args: &[&str], text: &str) -> io::Result
     }
 }
 
+/// Asks the terminal itself to set the clipboard, which works across SSH.
+pub(crate) fn osc52_sequence(text: &str) -> String {
+    format!("\x1b]52;c;{}\x07", STANDARD.encode(text.as_bytes()))
+}
+
 fn copy_via_osc52(text: &str) -> io::Result<()> {
-    let payload = STANDARD.encode(text.as_bytes());
-    let sequence = format!("\x1b]52;c;{payload}\x07");
-    let mut out = io::stdout();
-    out.write_all(sequence.as_bytes())?;
-    out.flush()?;
-    Ok(())
+    let sequence = osc52_sequence(text);
+    // Stdout may carry a JSON result; only use it while it is the terminal.
+    if io::stdout().is_terminal() {
+        write_and_flush(&mut io::stdout(), sequence.as_bytes())
+    } else {
+        write_and_flush(&mut io::stderr(), sequence.as_bytes())
+    }
+}
+
+fn write_and_flush(out: &mut impl Write, bytes: &[u8]) -> io::Result<()> {
+    out.write_all(bytes)?;
+    out.flush()
 }
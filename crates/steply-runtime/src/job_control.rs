@@ -0,0 +1,63 @@
+use std::io;
+
+/// Whether the platform can stop the process and resume it later.
+pub(crate) const SUPPORTED: bool = cfg!(unix);
+
+/// Stops the process with SIGTSTP and returns once it receives SIGCONT.
+#[cfg(unix)]
+pub(crate) fn stop_process() -> io::Result<()> {
+    // SAFETY: raise only delivers a signal to the calling thread; SIGTSTP's
+    // default action stops the whole process until SIGCONT.
+    if unsafe { libc::raise(libc::SIGTSTP) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+#[cfg(not(unix))]
+pub(crate) fn stop_process() -> io::Result<()> {
+    Ok(())
+}
+
+#[cfg(unix)]
+static INTERRUPTED: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+
+#[cfg(unix)]
+extern "C" fn note_interrupt(_signal: libc::c_int) {
+    INTERRUPTED.store(true, std::sync::atomic::Ordering::SeqCst);
+}
+
+/// Turns SIGINT into a flag the run loop polls, so a signal sent from outside
+/// (raw mode already turns Ctrl+C into a key) cancels the flow instead of
+/// killing the process in raw mode. Returns the handler to restore.
+#[cfg(unix)]
+pub(crate) fn catch_interrupts() -> libc::sighandler_t {
+    INTERRUPTED.store(false, std::sync::atomic::Ordering::SeqCst);
+    let handler: extern "C" fn(libc::c_int) = note_interrupt;
+    // SAFETY: the handler only stores to an atomic, which is signal-safe.
+    unsafe { libc::signal(libc::SIGINT, handler as libc::sighandler_t) }
+}
+
+#[cfg(unix)]
+pub(crate) fn restore_interrupts(previous: libc::sighandler_t) {
+    if previous != libc::SIG_ERR {
+        // SAFETY: reinstalls the handler that was active before the run.
+        unsafe { libc::signal(libc::SIGINT, previous) };
+    }
+}
+
+#[cfg(unix)]
+pub(crate) fn take_interrupt() -> bool {
+    INTERRUPTED.swap(false, std::sync::atomic::Ordering::SeqCst)
+}
+
+#[cfg(not(unix))]
+pub(crate) fn catch_interrupts() {}
+
+#[cfg(not(unix))]
+pub(crate) fn restore_interrupts(_previous: ()) {}
+
+#[cfg(not(unix))]
+pub(crate) fn take_interrupt() -> bool {
+    false
+}
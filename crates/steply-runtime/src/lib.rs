@@ -1,13 +1,26 @@
 pub mod app_entry;
+#[cfg(feature = "cli")]
+pub mod cli_answers;
 mod clipboard;
+mod job_control;
+pub mod metrics;
+pub mod prompt;
+pub mod recording;
+pub mod recovery;
 pub mod runner;
+pub mod secrets;
 pub mod selection;
 mod task_execution;
 mod task_executor;
+pub mod tasks;
 pub mod terminal;
+pub mod testing;
+mod theme_reload;
 
 pub use app_entry::{StartOptions, run_with_options};
-pub use runner::Runtime;
-pub use steply_core::preview::{RenderJsonRequest, RenderJsonScope};
+#[cfg(feature = "cli")]
+pub use cli_answers::{CliAnswers, field_args};
+pub use runner::{RunOutcome, Runtime};
+pub use steply_core::preview::{RenderJsonRequest, RenderJsonScope, SnapshotFormat};
 pub use steply_core::terminal as terminal_types;
-pub use terminal::{RenderMode, Terminal};
+pub use terminal::{Backend, RenderMode, TaskbarProgress, Terminal};
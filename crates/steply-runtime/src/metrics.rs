@@ -0,0 +1,144 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use steply_core::ui::draw_stats::DrawStats;
+use steply_core::ui::span::{Span, SpanLine};
+use steply_core::ui::style::{Color, Style};
+use steply_core::widgets::traits::{StickyBlock, StickyPosition};
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED_BYTES: AtomicU64 = AtomicU64::new(0);
+
+/// Global allocator that counts allocations for the metrics overlay.
+///
+/// Install it in the binary to get allocation counters; without it the
+/// overlay leaves them out.
+///
+/// ```ignore
+/// #[global_allocator]
+/// static ALLOC: steply_runtime::metrics::CountingAllocator =
+///     steply_runtime::metrics::CountingAllocator;
+/// ```
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(layout.size() as u64, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(new_size as u64, Ordering::Relaxed);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+/// Allocations and bytes allocated so far through [`CountingAllocator`].
+pub fn allocation_counters() -> (u64, u64) {
+    (
+        ALLOCATIONS.load(Ordering::Relaxed),
+        ALLOCATED_BYTES.load(Ordering::Relaxed),
+    )
+}
+
+/// What drawing one frame cost, handed to the hook installed with
+/// [`Runtime::with_profiler`](crate::Runtime::with_profiler).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FrameProfile {
+    /// Laying out, drawing and writing the frame.
+    pub frame_time: Duration,
+    /// Bytes sent to the terminal for the frame, escape sequences included;
+    /// zero on headless terminals.
+    pub bytes_written: u64,
+    pub draws: DrawStats,
+}
+
+/// Receives a [`FrameProfile`] after every rendered frame.
+pub type Profiler = Box<dyn FnMut(&FrameProfile) + Send>;
+
+/// Figures shown by the debug metrics overlay, each describing the previous
+/// frame since the current one is still being drawn.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FrameMetrics {
+    enabled: bool,
+    frame_time: Duration,
+    queued_events: usize,
+    draws: DrawStats,
+    allocations: Option<(u64, u64)>,
+}
+
+impl FrameMetrics {
+    /// Starts enabled when `STEPLY_DEBUG_METRICS` is set to anything but `0`.
+    pub(crate) fn from_env() -> Self {
+        let enabled = std::env::var("STEPLY_DEBUG_METRICS")
+            .is_ok_and(|value| !value.is_empty() && value != "0");
+        Self {
+            enabled,
+            ..Self::default()
+        }
+    }
+
+    pub(crate) fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub(crate) fn set_enabled(&mut self, enabled: bool) {
+        *self = Self {
+            enabled,
+            ..Self::default()
+        };
+    }
+
+    pub(crate) fn record_frame(
+        &mut self,
+        frame_time: Duration,
+        queued_events: usize,
+        draws: DrawStats,
+        allocations_before: (u64, u64),
+    ) {
+        let (count, bytes) = allocation_counters();
+        self.frame_time = frame_time;
+        self.queued_events = queued_events;
+        self.draws = draws;
+        // Zero means the counting allocator is not installed.
+        self.allocations = (count > 0).then(|| {
+            (
+                count.saturating_sub(allocations_before.0),
+                bytes.saturating_sub(allocations_before.1),
+            )
+        });
+    }
+
+    pub(crate) fn sticky_block(&self) -> StickyBlock {
+        StickyBlock::new(StickyPosition::Bottom, u8::MAX, vec![self.summary_line()])
+    }
+
+    pub(crate) fn summary_line(&self) -> SpanLine {
+        let mut text = format!(
+            "frame {:.1}ms  queue {}  widgets {}",
+            self.frame_time.as_secs_f64() * 1000.0,
+            self.queued_events,
+            self.draws.widgets_drawn,
+        );
+        if let Some((id, elapsed)) = &self.draws.slowest {
+            text.push_str(
+                format!("  slowest {id} {:.1}ms", elapsed.as_secs_f64() * 1000.0).as_str(),
+            );
+        }
+        if let Some((count, bytes)) = self.allocations {
+            text.push_str(format!("  allocs {count} ({bytes} B)").as_str());
+        }
+        vec![Span::styled(text, Style::new().color(Color::DarkGrey)).no_wrap()]
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/metrics.rs"]
+mod tests;
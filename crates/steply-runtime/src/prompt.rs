@@ -0,0 +1,112 @@
+//! Blocking one-question prompts, for programs that want an answer or two
+//! without describing a whole flow.
+//!
+//! Each call draws a single widget inline on stderr, waits for it to be
+//! submitted and returns its value, or `None` when the user cancels.
+
+use std::io;
+use std::path::PathBuf;
+
+use steply_core::core::value::Value;
+use steply_core::core::value_path::ValueTarget;
+use steply_core::state::app::AppState;
+use steply_core::state::flow::Flow;
+use steply_core::state::step::{Step, StepStatus};
+use steply_core::ui::renderer::RendererConfig;
+use steply_core::widgets::components::file_browser::FileBrowserInput;
+use steply_core::widgets::inputs::confirm::ConfirmInput;
+use steply_core::widgets::inputs::select::SelectInput;
+use steply_core::widgets::inputs::text::TextInput;
+use steply_core::widgets::node::Node;
+use steply_core::widgets::shared::binding::{
+    ReadBinding, StoreBinding, WriteBinding, WriteExpr, bind_node,
+};
+use steply_core::{HostContext, set_host_context};
+
+use crate::terminal::{RenderMode, Terminal};
+use crate::{RunOutcome, Runtime};
+
+const ANSWER_ID: &str = "answer";
+
+/// Asks for a line of text.
+pub fn text(prompt: &str) -> io::Result<Option<String>> {
+    let answer = ask(Node::Input(Box::new(TextInput::new(ANSWER_ID, prompt))))?;
+    Ok(answer.map(|value| value.to_text_scalar().unwrap_or_default()))
+}
+
+/// Asks a yes/no question.
+pub fn confirm(prompt: &str) -> io::Result<Option<bool>> {
+    let answer = ask(Node::Input(Box::new(ConfirmInput::new(ANSWER_ID, prompt))))?;
+    Ok(answer.map(|value| matches!(value, Value::Bool(true))))
+}
+
+/// Asks for one of `options`, returning the chosen option.
+pub fn select(prompt: &str, options: &[&str]) -> io::Result<Option<String>> {
+    let options = options.iter().map(|option| option.to_string()).collect();
+    let answer = ask(Node::Input(Box::new(SelectInput::new(
+        ANSWER_ID, prompt, options,
+    ))))?;
+    Ok(answer.map(|value| value.to_text_scalar().unwrap_or_default()))
+}
+
+/// Asks for a path, browsing from the current directory.
+pub fn path(prompt: &str) -> io::Result<Option<PathBuf>> {
+    let answer = ask(Node::Component(Box::new(FileBrowserInput::new(
+        ANSWER_ID, prompt,
+    ))))?;
+    Ok(answer.map(|value| PathBuf::from(value.to_text_scalar().unwrap_or_default())))
+}
+
+/// Asks with any widget, returning the value it submits. The other helpers
+/// are this with a preset widget; use it for validators, defaults or
+/// widgets they do not cover.
+pub fn ask(node: Node) -> io::Result<Option<Value>> {
+    let _ = set_host_context(HostContext {
+        cwd: std::env::current_dir().unwrap_or_else(|_| PathBuf::from("/")),
+        home_dir: std::env::var_os("HOME").map(PathBuf::from),
+    });
+
+    let id = node.id().to_string();
+    let mut runtime = Runtime::new(prompt_state(node)?, Terminal::new_stderr()?)
+        .with_render_mode(RenderMode::Inline)
+        .with_renderer_config(RendererConfig {
+            chrome_enabled: false,
+        });
+    if runtime.run()? == RunOutcome::Cancelled {
+        return Ok(None);
+    }
+    Ok(answer(&runtime.into_state(), id.as_str()))
+}
+
+/// A flow of one untitled step holding `node`, finishing as soon as it is
+/// submitted. The node's value is bound to the store under its id.
+pub(crate) fn prompt_state(node: Node) -> io::Result<AppState> {
+    let target = ValueTarget::node(node.id());
+    let node = bind_node(
+        node,
+        StoreBinding {
+            value: Some(target.clone()),
+            reads: Some(ReadBinding::Selector(target.clone())),
+            writes: vec![WriteBinding {
+                target,
+                expr: WriteExpr::ScopeRef("value".to_string()),
+            }],
+            ..StoreBinding::default()
+        },
+    );
+    let mut state = AppState::new(Flow::new(vec![Step::new("prompt", "", vec![node])]))
+        .map_err(|err| io::Error::other(format!("prompt init error: {err}")))?;
+    state.set_confirm_finish(false);
+    Ok(state)
+}
+
+pub(crate) fn answer(state: &AppState, id: &str) -> Option<Value> {
+    match state.current_step_status() {
+        StepStatus::Done => Some(state.store_value(id).cloned().unwrap_or(Value::None)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/prompt.rs"]
+mod tests;
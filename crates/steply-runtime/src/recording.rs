@@ -0,0 +1,150 @@
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use steply_core::terminal::TerminalEvent;
+
+/// One terminal input event and its offset from the start of the recording.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordedEvent {
+    pub at_ms: u64,
+    pub event: TerminalEvent,
+}
+
+impl RecordedEvent {
+    fn to_json_line(&self) -> io::Result<String> {
+        let event = serde_json::to_value(&self.event).map_err(io::Error::other)?;
+        Ok(serde_json::json!({ "at_ms": self.at_ms, "event": event }).to_string())
+    }
+
+    fn from_json_line(line: &str) -> Result<Self, String> {
+        let mut doc: serde_json::Value =
+            serde_json::from_str(line).map_err(|err| err.to_string())?;
+        let at_ms = doc
+            .get("at_ms")
+            .and_then(serde_json::Value::as_u64)
+            .ok_or_else(|| "missing 'at_ms'".to_string())?;
+        let event = doc
+            .get_mut("event")
+            .map(serde_json::Value::take)
+            .ok_or_else(|| "missing 'event'".to_string())?;
+        let event = serde_json::from_value(event).map_err(|err| err.to_string())?;
+        Ok(Self { at_ms, event })
+    }
+}
+
+/// Appends terminal input to a file as JSON lines, one event per line.
+///
+/// Ticks are not recorded; each line is flushed so a crash still leaves a
+/// usable recording behind.
+pub struct InputRecorder {
+    out: BufWriter<File>,
+    started: Instant,
+}
+
+impl InputRecorder {
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(Self {
+            out: BufWriter::new(File::create(path)?),
+            started: Instant::now(),
+        })
+    }
+
+    pub fn record(&mut self, event: TerminalEvent) -> io::Result<()> {
+        if matches!(event, TerminalEvent::Tick) {
+            return Ok(());
+        }
+        let at_ms = self.started.elapsed().as_millis().min(u64::MAX as u128) as u64;
+        let line = RecordedEvent { at_ms, event }.to_json_line()?;
+        writeln!(self.out, "{line}")?;
+        self.out.flush()
+    }
+}
+
+/// Feeds a recording back to the runtime with its original timing.
+///
+/// `speed` scales playback: `2.0` replays twice as fast, and `0.0` delivers
+/// every event as soon as the runtime asks for input.
+#[derive(Debug, Clone)]
+pub struct InputReplay {
+    events: VecDeque<RecordedEvent>,
+    speed: f64,
+    started: Option<Instant>,
+}
+
+impl InputReplay {
+    pub fn new(events: impl IntoIterator<Item = RecordedEvent>) -> Self {
+        Self {
+            events: events.into_iter().collect(),
+            speed: 1.0,
+            started: None,
+        }
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::parse(std::fs::read_to_string(path)?.as_str())
+    }
+
+    pub fn parse(raw: &str) -> io::Result<Self> {
+        let events = raw
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(idx, line)| {
+                RecordedEvent::from_json_line(line).map_err(|err| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("invalid recording line {}: {err}", idx + 1),
+                    )
+                })
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+        Ok(Self::new(events))
+    }
+
+    pub fn with_speed(mut self, speed: f64) -> Self {
+        self.speed = if speed.is_finite() && speed > 0.0 {
+            speed
+        } else {
+            0.0
+        };
+        self
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Pops the next event if it is due; the clock starts on the first call.
+    pub(crate) fn next_due(&mut self, now: Instant) -> Option<TerminalEvent> {
+        let started = *self.started.get_or_insert(now);
+        let next = self.events.front()?;
+        if started + self.scaled(next.at_ms) > now {
+            return None;
+        }
+        self.events.pop_front().map(|recorded| recorded.event)
+    }
+
+    pub(crate) fn time_until_next(&self, now: Instant) -> Option<Duration> {
+        let next = self.events.front()?;
+        let due = self.started.unwrap_or(now) + self.scaled(next.at_ms);
+        Some(due.saturating_duration_since(now))
+    }
+
+    fn scaled(&self, at_ms: u64) -> Duration {
+        if self.speed == 0.0 {
+            return Duration::ZERO;
+        }
+        Duration::from_secs_f64(at_ms as f64 / 1000.0 / self.speed)
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/recording.rs"]
+mod tests;
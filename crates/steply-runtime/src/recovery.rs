@@ -0,0 +1,176 @@
+use std::fs::{File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+
+use steply_core::core::value::Value;
+use steply_core::state::app::AppState;
+
+static RECOVERY_PATH: OnceLock<PathBuf> = OnceLock::new();
+static PENDING_SNAPSHOT: Mutex<Option<String>> = Mutex::new(None);
+
+/// Turns on crash recovery for runtimes created afterwards: they keep the
+/// latest answers in memory for [`write_recovery_snapshot`] and offer to
+/// restore a snapshot left at `path` by an earlier crash.
+pub fn enable_crash_recovery(path: impl Into<PathBuf>) {
+    let _ = RECOVERY_PATH.set(path.into());
+}
+
+/// Writes the latest answers of the running session to the recovery file.
+/// Meant for panic hooks, so it never blocks and never panics.
+pub fn write_recovery_snapshot() -> io::Result<bool> {
+    match RECOVERY_PATH.get() {
+        Some(path) => write_snapshot_to(path.as_path()),
+        None => Ok(false),
+    }
+}
+
+fn write_snapshot_to(path: &Path) -> io::Result<bool> {
+    let Ok(snapshot) = PENDING_SNAPSHOT.try_lock() else {
+        return Ok(false);
+    };
+    let Some(json) = snapshot.as_deref() else {
+        return Ok(false);
+    };
+    if let Some(parent) = path.parent()
+        && !parent.as_os_str().is_empty()
+    {
+        create_private_dir(parent)?;
+    }
+    let mut file = open_private_file(path)?;
+    file.write_all(json.as_bytes())?;
+    Ok(true)
+}
+
+/// Answers can be personal, so the file is readable by its owner only.
+#[cfg(unix)]
+fn open_private_file(path: &Path) -> io::Result<File> {
+    use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+
+    let file = OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .mode(0o600)
+        .open(path)?;
+    // `mode` only applies when the file is created.
+    file.set_permissions(std::fs::Permissions::from_mode(0o600))?;
+    Ok(file)
+}
+
+#[cfg(not(unix))]
+fn open_private_file(path: &Path) -> io::Result<File> {
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(path)
+}
+
+#[cfg(unix)]
+fn create_private_dir(path: &Path) -> io::Result<()> {
+    use std::os::unix::fs::DirBuilderExt;
+
+    std::fs::DirBuilder::new()
+        .recursive(true)
+        .mode(0o700)
+        .create(path)
+}
+
+#[cfg(not(unix))]
+fn create_private_dir(path: &Path) -> io::Result<()> {
+    std::fs::create_dir_all(path)
+}
+
+/// Per-runtime side of crash recovery. Snapshots are tagged with the flow's
+/// step ids so answers are never restored into a different flow.
+pub(crate) struct SessionRecovery {
+    path: PathBuf,
+    flow_key: Vec<String>,
+    recorded_revision: Option<u64>,
+}
+
+impl SessionRecovery {
+    pub(crate) fn from_env(state: &AppState) -> Option<Self> {
+        RECOVERY_PATH
+            .get()
+            .map(|path| Self::new(path.clone(), state))
+    }
+
+    fn new(path: PathBuf, state: &AppState) -> Self {
+        Self {
+            path,
+            flow_key: flow_key(state),
+            recorded_revision: None,
+        }
+    }
+
+    /// Reads and removes a snapshot saved for this flow. Snapshots of other
+    /// flows are left in place.
+    pub(crate) fn take_saved(&self) -> Option<Value> {
+        let raw = std::fs::read_to_string(self.path.as_path()).ok()?;
+        let Ok(Value::Object(mut doc)) = Value::from_json(raw.as_str()) else {
+            remove_file(self.path.as_path());
+            return None;
+        };
+        let flow = doc.get("flow").and_then(Value::as_list).map(|steps| {
+            steps
+                .iter()
+                .filter_map(Value::as_text)
+                .map(str::to_string)
+                .collect::<Vec<_>>()
+        });
+        if flow.as_ref() != Some(&self.flow_key) {
+            return None;
+        }
+        remove_file(self.path.as_path());
+        doc.shift_remove("values")
+    }
+
+    /// Keeps the answers, minus secret and masked ones, for the panic hook.
+    /// Does nothing until the store changes again.
+    pub(crate) fn record(&mut self, state: &AppState) {
+        let revision = state.store_revision();
+        if self.recorded_revision == Some(revision) {
+            return;
+        }
+        let values = state.exportable_values();
+        let doc = Value::Object(
+            [
+                (
+                    "flow".to_string(),
+                    Value::List(self.flow_key.iter().cloned().map(Value::Text).collect()),
+                ),
+                ("values".to_string(), values),
+            ]
+            .into_iter()
+            .collect(),
+        );
+        if let Ok(json) = doc.to_json_string() {
+            *PENDING_SNAPSHOT
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(json);
+        }
+        self.recorded_revision = Some(revision);
+    }
+
+    /// Forgets the session once it ended without crashing.
+    pub(crate) fn discard(&mut self) {
+        *PENDING_SNAPSHOT
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = None;
+        self.recorded_revision = None;
+    }
+}
+
+fn flow_key(state: &AppState) -> Vec<String> {
+    state.steps().iter().map(|step| step.id.clone()).collect()
+}
+
+fn remove_file(path: &Path) {
+    let _ = std::fs::remove_file(path);
+}
+
+#[cfg(test)]
+#[path = "tests/recovery.rs"]
+mod tests;
@@ -1,26 +1,54 @@
 use crate::clipboard;
+use crate::job_control;
+use crate::metrics::{FrameMetrics, FrameProfile, Profiler, allocation_counters};
+use crate::recording::{InputRecorder, InputReplay};
+use crate::recovery::SessionRecovery;
 use crate::selection::{
     SelectionState, apply_selection_highlight, extract_selected_text, handle_selection_pointer,
 };
-use crate::task_executor::{LogLine, TaskExecutor};
-use std::io;
+use crate::task_executor::{LogLine, ProgressUpdate, TaskExecutor};
+use crate::theme_reload::ThemeWatcher;
+use std::io::{self, Write};
+use std::path::Path;
 use std::time::{Duration, Instant};
-use steply_core::preview::render::render_json as render_preview_json;
+use steply_core::core::action_bindings::ActionBindings;
+use steply_core::core::value::Value;
+use steply_core::preview::render::{
+    render_json as render_preview_json, render_snapshot as render_preview_snapshot,
+};
 use steply_core::preview::request::RenderJsonRequest;
+use steply_core::preview::{PreviewServiceOptions, SnapshotFormat};
 use steply_core::runtime::effect::Effect;
 use steply_core::runtime::event::{AppEvent, SystemEvent, WidgetAction};
 use steply_core::runtime::intent::Intent;
 use steply_core::runtime::key_bindings::KeyBindings;
 use steply_core::runtime::reducer::Reducer;
 use steply_core::runtime::scheduler::Scheduler;
+use steply_core::state::announce::Announcer;
 use steply_core::state::app::AppState;
-use steply_core::terminal::TerminalEvent;
+use steply_core::state::step::StepStatus;
+use steply_core::task::{TaskId, TaskRequest, TaskStartResult};
+use steply_core::terminal::{TerminalCapabilities, TerminalEvent};
+use steply_core::ui::draw_stats;
 use steply_core::ui::hit_test::FrameHitMap;
 use steply_core::ui::render_view::RenderView;
 use steply_core::ui::renderer::{Renderer, RendererConfig};
 use steply_core::ui::span::SpanLine;
+use steply_core::ui::theme::{Appearance, Theme};
+
+use crate::terminal::{RenderMode, TaskbarProgress, Terminal};
+
+const ACTIVE_TICK_INTERVAL: Duration = Duration::from_millis(120);
+const IDLE_TICK_INTERVAL: Duration = Duration::from_secs(1);
+const CANCEL_GRACE_PERIOD: Duration = Duration::from_millis(500);
 
-use crate::terminal::{RenderMode, Terminal};
+/// How [`Runtime::run`] ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunOutcome {
+    Completed,
+    /// The user cancelled the flow, e.g. with Ctrl+C.
+    Cancelled,
+}
 
 pub struct Runtime {
     state: AppState,
@@ -32,6 +60,18 @@ pub struct Runtime {
     last_hit_map: FrameHitMap,
     selection: SelectionState,
     last_frame_lines: Vec<SpanLine>,
+    recorder: Option<InputRecorder>,
+    replay: Option<InputReplay>,
+    recovery: Option<SessionRecovery>,
+    metrics: FrameMetrics,
+    profiler: Option<Profiler>,
+    tick_interval: Duration,
+    idle_tick_interval: Duration,
+    window_title: Option<String>,
+    theme_watcher: Option<ThemeWatcher>,
+    appearance: Option<Appearance>,
+    json_result: bool,
+    announcements: Option<(Announcer, Box<dyn Write + Send>)>,
 }
 
 impl Runtime {
@@ -52,17 +92,139 @@ impl Runtime {
         self
     }
 
+    /// Selects the message catalog used for labels, hints and validation
+    /// messages; unknown locales render the flow's text as written.
+    pub fn with_locale(mut self, locale: &str) -> Self {
+        self.state.set_locale(locale);
+        self
+    }
+
+    /// Picks the first of `locales` the flow has a catalog for, leaving the
+    /// current locale alone when none matches.
+    pub fn with_preferred_locales(mut self, locales: &[String]) -> Self {
+        self.state
+            .negotiate_locale(locales.iter().map(String::as_str));
+        self
+    }
+
+    /// Remaps navigation keys for every widget, e.g. to a vim or emacs preset.
+    pub fn with_action_bindings(mut self, bindings: ActionBindings) -> Self {
+        self.state.set_action_bindings(bindings);
+        self
+    }
+
+    /// Records every terminal input event with its timestamp to `path`.
+    pub fn with_input_recording(mut self, path: impl AsRef<Path>) -> io::Result<Self> {
+        self.recorder = Some(InputRecorder::create(path)?);
+        Ok(self)
+    }
+
+    /// Plays `replay` back as input; live input is still accepted meanwhile
+    /// and takes over once the recording is exhausted.
+    pub fn with_input_replay(mut self, replay: InputReplay) -> Self {
+        self.replay = Some(replay);
+        self
+    }
+
+    /// Sets how often the loop ticks while widgets or tasks have work
+    /// pending (`active`) and while everything is idle (`idle`).
+    pub fn with_tick_intervals(mut self, active: Duration, idle: Duration) -> Self {
+        self.tick_interval = active;
+        self.idle_tick_interval = idle.max(active);
+        self
+    }
+
+    /// Shows frame time, queued events, widget draws and allocation counts
+    /// in a line pinned to the bottom. Alt+M toggles it while running.
+    pub fn with_debug_metrics(mut self, enabled: bool) -> Self {
+        self.metrics.set_enabled(enabled);
+        self
+    }
+
+    /// Calls `profiler` after every frame with its draw time per widget and
+    /// the bytes written to the terminal, for tracking render performance.
+    pub fn with_profiler(mut self, profiler: impl FnMut(&FrameProfile) + Send + 'static) -> Self {
+        self.profiler = Some(Box::new(profiler));
+        self
+    }
+
+    /// Writes screen-reader announcements of step, focus, choice and error
+    /// changes to `out`, one per line, as frames are drawn.
+    pub fn with_announcements(mut self, out: impl Write + Send + 'static) -> Self {
+        self.announcements = Some((Announcer::new(), Box::new(out)));
+        self
+    }
+
+    /// Records state snapshots as intents are dispatched, so Alt+I can open
+    /// the inspector and step back through them.
+    pub fn with_inspector(mut self) -> Self {
+        self.state.enable_inspector();
+        self
+    }
+
+    /// Titles the terminal window `"{title} — step 3/7"`, following the
+    /// flow as it moves between steps.
+    pub fn with_window_title(mut self, title: impl Into<String>) -> Self {
+        self.window_title = Some(title.into());
+        self
+    }
+
+    /// Styles the UI with the TOML theme at `path` and reloads it whenever
+    /// the file changes. See [`Theme`](steply_core::ui::theme::Theme).
+    pub fn with_theme_file(mut self, path: impl AsRef<Path>) -> io::Result<Self> {
+        self.theme_watcher = Some(ThemeWatcher::load(path.as_ref(), &mut self.state)?);
+        Ok(self)
+    }
+
+    /// Uses the built-in theme for `appearance` instead of asking the
+    /// terminal for its background color. A theme file still wins.
+    pub fn with_appearance(mut self, appearance: Appearance) -> Self {
+        self.appearance = Some(appearance);
+        self
+    }
+
+    /// Draws every symbol with its ASCII fallback whatever the locale
+    /// says, for screen readers and fonts without box drawing.
+    pub fn with_ascii_glyphs(mut self) -> Self {
+        let capabilities = TerminalCapabilities {
+            unicode: false,
+            ..self.terminal.capabilities()
+        };
+        self.terminal = self.terminal.with_capabilities(capabilities);
+        self.state.set_terminal_capabilities(capabilities);
+        self
+    }
+
+    /// Prints the collected values as a single JSON document on stdout once
+    /// the flow completes, and draws the UI on stderr so scripts can pipe
+    /// the result straight into another tool. Nothing is printed on cancel.
+    pub fn with_json_result(mut self) -> Self {
+        self.terminal = self.terminal.with_stderr_output();
+        self.json_result = true;
+        self
+    }
+
     pub fn with_render_mode(mut self, mode: RenderMode) -> Self {
         self.terminal = self.terminal.with_mode(mode);
         self
     }
 
     fn with_parts(
-        state: AppState,
+        mut state: AppState,
         terminal: Terminal,
         key_bindings: KeyBindings,
         renderer: Renderer,
     ) -> Self {
+        state.set_terminal_capabilities(terminal.capabilities());
+        // The recovery snapshot is process-wide; sessions served to remote
+        // terminals share the process and must not restore each other's
+        // answers.
+        let recovery = (terminal.is_headless() || terminal.is_process_tty())
+            .then(|| SessionRecovery::from_env(&state))
+            .flatten();
+        if let Some(values) = recovery.as_ref().and_then(SessionRecovery::take_saved) {
+            state.begin_restore_confirm(values);
+        }
         Self {
             state,
             terminal,
@@ -73,34 +235,254 @@ impl Runtime {
             last_hit_map: FrameHitMap::default(),
             selection: SelectionState::default(),
             last_frame_lines: Vec::new(),
+            recorder: None,
+            replay: None,
+            recovery,
+            metrics: FrameMetrics::from_env(),
+            profiler: None,
+            tick_interval: ACTIVE_TICK_INTERVAL,
+            idle_tick_interval: IDLE_TICK_INTERVAL,
+            window_title: None,
+            theme_watcher: None,
+            appearance: None,
+            json_result: false,
+            announcements: None,
         }
     }
 
-    pub fn run(&mut self) -> io::Result<()> {
+    /// Runs the flow until it finishes or is cancelled. Cancelling (Ctrl+C
+    /// or SIGINT) runs the `on_cancel` hooks and gives running tasks a moment
+    /// to abort before the terminal is restored.
+    pub fn run(&mut self) -> io::Result<RunOutcome> {
         self.terminal.enter()?;
+        self.apply_appearance();
+        let interrupts = self
+            .terminal
+            .is_process_tty()
+            .then(job_control::catch_interrupts);
 
+        let _span = steply_core::trace_span!(INFO, "run");
         let run_result = (|| -> io::Result<()> {
-            self.flush_pending_task_invocations();
-            self.render()?;
+            self.start()?;
 
             while !self.state.should_exit() {
-                self.process_scheduled_events()?;
-                self.process_task_log_lines()?;
-                self.process_task_completions()?;
-                self.flush_pending_task_invocations();
+                if job_control::take_interrupt() && !self.state.cancel_flow() {
+                    // A hook vetoed the cancel; a signal still ends the run.
+                    self.state.request_exit();
+                }
+                self.pump()?;
 
-                let now = Instant::now();
-                let timeout = self.scheduler.poll_timeout(now, Duration::from_millis(120));
-                let event = self.terminal.poll_event(timeout)?;
+                let timeout = self.poll_timeout(Instant::now());
+                let event = self.next_input_event(timeout)?;
 
                 self.dispatch_app_event(AppEvent::Terminal(event))?;
             }
 
-            Ok(())
+            self.wait_for_cancelled_tasks()
         })();
 
+        if let Some(previous) = interrupts {
+            job_control::restore_interrupts(previous);
+        }
         let exit_result = self.terminal.exit();
-        run_result.and(exit_result)
+        if run_result.is_ok()
+            && let Some(recovery) = self.recovery.as_mut()
+        {
+            recovery.discard();
+        }
+        run_result.and(exit_result)?;
+        self.print_json_result()?;
+        let outcome = self.outcome();
+        steply_core::trace_event!(INFO, outcome = ?outcome, "run finished");
+        Ok(outcome)
+    }
+
+    /// Completes the flow without user input: seeds the store from `answers`
+    /// (keyed by store selector, like a prefill file), then submits each step
+    /// once its tasks have finished. Fails with the validation messages of
+    /// the first step that does not accept its answers.
+    pub fn run_unattended(&mut self, answers: &Value) -> io::Result<RunOutcome> {
+        self.state
+            .prefill_values(answers)
+            .map_err(|err| io::Error::other(format!("answers error: {err}")))?;
+        self.state.set_confirm_finish(false);
+        self.start()?;
+
+        let mut last_step: Option<(String, usize)> = None;
+        while !self.state.should_exit() {
+            self.wait_for_running_tasks()?;
+            if self.state.should_exit() {
+                break;
+            }
+            let step_id = self.state.current_step_id().to_string();
+            let attempts = match last_step.take() {
+                Some((id, attempts)) if id == step_id => attempts + 1,
+                _ => 1,
+            };
+            // The second submit acknowledges warnings; a third means the
+            // answers do not satisfy the step.
+            if attempts > 2 {
+                return Err(io::Error::other(self.unattended_failure(step_id.as_str())));
+            }
+            last_step = Some((step_id, attempts));
+            self.dispatch_app_event(AppEvent::System(SystemEvent::RequestSubmit))?;
+            self.pump()?;
+        }
+        self.wait_for_cancelled_tasks()?;
+        self.print_json_result()?;
+        Ok(self.outcome())
+    }
+
+    fn print_json_result(&self) -> io::Result<()> {
+        if !self.json_result {
+            return Ok(());
+        }
+        if let Some(json) = self.state.render_result_json() {
+            println!("{}", json.map_err(io::Error::other)?);
+        }
+        Ok(())
+    }
+
+    fn wait_for_running_tasks(&mut self) -> io::Result<()> {
+        while self.state.has_running_tasks() {
+            std::thread::sleep(Duration::from_millis(10));
+            self.pump()?;
+        }
+        Ok(())
+    }
+
+    fn unattended_failure(&self, step_id: &str) -> String {
+        let messages = self.state.message_catalog();
+        let mut problems = self
+            .state
+            .current_step_errors()
+            .iter()
+            .map(|error| messages.render(error).into_owned())
+            .collect::<Vec<_>>();
+        problems.extend(
+            self.state
+                .validation_state()
+                .visible_entries()
+                .map(|(id, message)| format!("{id}: {}", messages.render(message))),
+        );
+        if problems.is_empty() {
+            format!("step '{step_id}' did not complete")
+        } else {
+            format!(
+                "step '{step_id}' rejected the answers: {}",
+                problems.join("; ")
+            )
+        }
+    }
+
+    fn outcome(&self) -> RunOutcome {
+        if self.state.current_step_status() == StepStatus::Cancelled {
+            RunOutcome::Cancelled
+        } else {
+            RunOutcome::Completed
+        }
+    }
+
+    /// Exiting cancels running tasks; collect their completions for a short
+    /// while so commands are killed before the process goes away.
+    fn wait_for_cancelled_tasks(&mut self) -> io::Result<()> {
+        let deadline = Instant::now() + CANCEL_GRACE_PERIOD;
+        while self.state.has_running_tasks() && Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(10));
+            self.process_task_completions()?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn start(&mut self) -> io::Result<()> {
+        self.flush_pending_task_invocations();
+        self.render()
+    }
+
+    /// Swaps in the light theme on light terminals, unless a theme was
+    /// already set.
+    fn apply_appearance(&mut self) {
+        if **self.state.theme() != Theme::default() {
+            return;
+        }
+        let appearance = self.appearance.or_else(|| {
+            self.terminal
+                .background_color()
+                .map(|(red, green, blue)| Appearance::from_background(red, green, blue))
+        });
+        if let Some(appearance) = appearance {
+            self.state.set_theme(Theme::for_appearance(appearance));
+        }
+    }
+
+    /// Delivers due scheduler events and task output without waiting for input.
+    pub(crate) fn pump(&mut self) -> io::Result<()> {
+        self.process_scheduled_events()?;
+        self.process_task_log_lines()?;
+        self.process_task_progress()?;
+        self.process_task_completions()?;
+        self.flush_pending_task_invocations();
+        self.reload_theme()
+    }
+
+    fn reload_theme(&mut self) -> io::Result<()> {
+        let Some(watcher) = self.theme_watcher.as_mut() else {
+            return Ok(());
+        };
+        if watcher.poll(Instant::now(), &mut self.state) {
+            self.render()?;
+        }
+        Ok(())
+    }
+
+    /// Input still wakes the loop at once; this only bounds how long it waits
+    /// for a tick when nothing arrives.
+    pub(crate) fn poll_timeout(&self, now: Instant) -> Duration {
+        let mut interval = if self.state.wants_tick() {
+            self.tick_interval
+        } else {
+            self.idle_tick_interval
+        };
+        if let Some(remaining) = self.state.idle_timeout_remaining_at(now) {
+            interval = interval.min(remaining);
+        }
+        self.scheduler.poll_timeout(now, interval)
+    }
+
+    fn next_input_event(&mut self, mut timeout: Duration) -> io::Result<TerminalEvent> {
+        let mut event = None;
+        if let Some(replay) = self.replay.as_mut() {
+            let now = Instant::now();
+            event = replay.next_due(now);
+            match replay.time_until_next(now) {
+                Some(wait) => timeout = timeout.min(wait),
+                None if event.is_none() => self.replay = None,
+                None => {}
+            }
+        }
+        let event = match event {
+            Some(event) => event,
+            None => self.terminal.poll_event(timeout)?,
+        };
+        if let Some(recorder) = self.recorder.as_mut() {
+            recorder.record(event.clone())?;
+        }
+        Ok(event)
+    }
+
+    pub(crate) fn handle_terminal_event(&mut self, event: TerminalEvent) -> io::Result<()> {
+        self.dispatch_app_event(AppEvent::Terminal(event))
+    }
+
+    pub(crate) fn last_frame_lines(&self) -> &[SpanLine] {
+        self.last_frame_lines.as_slice()
+    }
+
+    /// Starts a registered task by id, as if one of its triggers fired.
+    pub fn spawn_task(&mut self, task_id: impl Into<TaskId>) -> TaskStartResult {
+        let result = self.state.request_task(TaskRequest::new(task_id));
+        self.flush_pending_task_invocations();
+        result
     }
 
     pub fn state(&self) -> &AppState {
@@ -125,6 +507,21 @@ impl Runtime {
         Ok(())
     }
 
+    /// Prints a text snapshot of the request's scope. Unlike render JSON, the
+    /// live terminal size is ignored so output stays stable across machines.
+    pub fn print_render_snapshot_with_request(
+        &mut self,
+        request: RenderJsonRequest,
+        format: SnapshotFormat,
+    ) -> io::Result<()> {
+        let size = PreviewServiceOptions::default().default_terminal_size;
+        let text =
+            render_preview_snapshot(&mut self.state, &request, &mut self.renderer, size, format)
+                .map_err(io::Error::other)?;
+        print!("{text}");
+        Ok(())
+    }
+
     fn process_scheduled_events(&mut self) -> io::Result<()> {
         for event in self.scheduler.drain_ready(Instant::now()) {
             self.dispatch_app_event(event)?;
@@ -148,6 +545,22 @@ impl Runtime {
         Ok(())
     }
 
+    fn process_task_progress(&mut self) -> io::Result<()> {
+        for ProgressUpdate {
+            task_id,
+            run_id,
+            progress,
+        } in self.task_executor.drain_progress()
+        {
+            self.dispatch_app_event(AppEvent::System(SystemEvent::TaskProgress {
+                task_id,
+                run_id,
+                progress,
+            }))?;
+        }
+        Ok(())
+    }
+
     fn process_task_completions(&mut self) -> io::Result<()> {
         for completion in self.task_executor.drain_ready() {
             self.dispatch_app_event(AppEvent::System(SystemEvent::TaskCompleted { completion }))?;
@@ -156,18 +569,31 @@ impl Runtime {
     }
 
     fn dispatch_app_event(&mut self, event: AppEvent) -> io::Result<()> {
+        steply_core::trace_event!(TRACE, event = ?event, "app event");
         match event {
             AppEvent::Terminal(TerminalEvent::Resize(size)) => {
                 self.terminal.set_size(size);
+                // Selection coordinates refer to the old wrapping.
+                self.selection = SelectionState::default();
                 self.render()
             }
             AppEvent::Terminal(TerminalEvent::Key(key)) => {
-                let intent = self
-                    .key_bindings
-                    .resolve(key)
-                    .unwrap_or(Intent::InputKey(key));
+                let intent = self.state.key_intent(key, &self.key_bindings);
                 self.process_intent(intent)
             }
+            AppEvent::Terminal(TerminalEvent::Paste(text)) => {
+                self.process_intent(Intent::Paste(text))
+            }
+            AppEvent::Terminal(TerminalEvent::FocusGained) => {
+                self.dispatch_app_event(AppEvent::System(SystemEvent::TerminalFocusChanged {
+                    focused: true,
+                }))
+            }
+            AppEvent::Terminal(TerminalEvent::FocusLost) => {
+                self.dispatch_app_event(AppEvent::System(SystemEvent::TerminalFocusChanged {
+                    focused: false,
+                }))
+            }
             AppEvent::Terminal(TerminalEvent::Scroll(delta)) => {
                 self.terminal.scroll(delta);
                 self.render()
@@ -198,7 +624,14 @@ impl Runtime {
                     self.process_intent(Intent::Pointer(frame_event))
                 }
             }
-            AppEvent::Terminal(TerminalEvent::Tick) => self.process_intent(Intent::Tick),
+            AppEvent::Terminal(TerminalEvent::Tick) => {
+                // Some terminals drop resize events (e.g. while suspended).
+                if self.terminal.refresh_size()? {
+                    self.selection = SelectionState::default();
+                    self.render()?;
+                }
+                self.process_intent(Intent::Tick)
+            }
             AppEvent::Intent(intent) => self.process_intent(intent),
             AppEvent::Action(action) => {
                 if self.apply_action(action) {
@@ -217,16 +650,14 @@ impl Runtime {
 
     fn process_intent(&mut self, intent: Intent) -> io::Result<()> {
         match &intent {
-            Intent::Exit => {
-                // Compatibility fallback:
-                // some terminals may collapse Ctrl+Shift+C to Ctrl+C.
-                // If there is an active selection, prefer copy over exit.
-                if self.selection.range().is_some() {
-                    if let Err(err) = self.copy_selection_to_clipboard() {
-                        eprintln!("failed to copy selection: {err}");
-                    }
-                    return Ok(());
+            // Compatibility fallback:
+            // some terminals may collapse Ctrl+Shift+C to Ctrl+C.
+            // If there is an active selection, prefer copy over exit.
+            Intent::Exit if self.selection.range().is_some() => {
+                if let Err(err) = self.copy_selection_to_clipboard() {
+                    eprintln!("failed to copy selection: {err}");
                 }
+                return Ok(());
             }
             Intent::ScrollUp => {
                 self.terminal.scroll(-1);
@@ -252,6 +683,11 @@ impl Runtime {
                 }
                 return Ok(());
             }
+            Intent::Suspend => return self.suspend(),
+            Intent::ToggleMetrics => {
+                self.metrics.set_enabled(!self.metrics.enabled());
+                return self.render();
+            }
 
             Intent::Submit
             | Intent::InputKey(_)
@@ -267,8 +703,15 @@ impl Runtime {
             | Intent::OpenOverlayAtIndex(_)
             | Intent::OpenOverlayShortcut
             | Intent::CloseOverlay
-            | Intent::Pointer(_)
-            | Intent::PointerOn { .. } => {
+            | Intent::ToggleValuePreview
+            | Intent::ToggleTaskDetails
+            | Intent::ToggleInspector
+            | Intent::ExpandValue => {
+                self.terminal.reset_scroll();
+            }
+            Intent::Pointer(event) | Intent::PointerOn { event, .. }
+                if event.kind.wheel_delta().is_none() =>
+            {
                 self.terminal.reset_scroll();
             }
             _ => {}
@@ -277,6 +720,18 @@ impl Runtime {
         self.apply_effects(effects)
     }
 
+    /// Hands the terminal back to the shell and stops the process, as Ctrl+Z
+    /// would outside raw mode; the flow is redrawn in full once resumed.
+    fn suspend(&mut self) -> io::Result<()> {
+        if !self.terminal.is_process_tty() || !job_control::SUPPORTED {
+            return Ok(());
+        }
+        self.terminal.suspend()?;
+        job_control::stop_process()?;
+        self.terminal.resume()?;
+        self.render()
+    }
+
     fn apply_effects(&mut self, effects: Vec<Effect>) -> io::Result<()> {
         let mut render_requested = false;
 
@@ -294,6 +749,10 @@ impl Runtime {
                 Effect::RequestRender => {
                     render_requested = true;
                 }
+                Effect::ScrollViewport(rows) => {
+                    self.terminal.scroll(rows);
+                    render_requested = true;
+                }
             }
         }
 
@@ -306,12 +765,20 @@ impl Runtime {
 
     fn apply_action(&mut self, action: WidgetAction) -> bool {
         match action {
+            // A remote session's browser is not on this machine.
+            WidgetAction::OpenUrl { .. } if self.is_remote_session() => false,
             WidgetAction::OpenUrl { url } => {
                 if let Err(err) = clipboard::open_external_url(url.as_str()) {
                     eprintln!("failed to open URL '{}': {err}", url);
                 }
                 false
             }
+            WidgetAction::CopyToClipboard { text } => {
+                if let Err(err) = self.copy_to_clipboard(text.as_str()) {
+                    eprintln!("failed to copy to clipboard: {err}");
+                }
+                false
+            }
             action => {
                 let result = self.state.handle_action(action);
                 self.finish_state_interaction(result)
@@ -346,14 +813,92 @@ impl Runtime {
     }
 
     fn render(&mut self) -> io::Result<()> {
+        let _span = steply_core::trace_span!(TRACE, "render");
+        // Lay the frame out for the size it is about to be drawn at.
+        self.terminal.refresh_size()?;
+        if let Some(recovery) = self.recovery.as_mut() {
+            recovery.record(&self.state);
+        }
+        let started = Instant::now();
+        let allocations_before = allocation_counters();
+        let bytes_before = self.terminal.bytes_written();
+        if self.metrics.enabled() || self.profiler.is_some() {
+            draw_stats::begin_collecting();
+        }
+        self.state.set_viewport_rows(self.terminal.size().height);
         let view = RenderView::from_state(&self.state);
         let mut frame = self.renderer.render(&view, self.terminal.size());
+        let draws = draw_stats::finish_collecting();
         self.last_frame_lines = frame.lines.clone();
         if let Some(range) = self.selection.range() {
             apply_selection_highlight(&self.last_hit_map, &mut frame.lines, range);
         }
         self.last_hit_map = frame.hit_map.clone();
-        self.terminal.render_frame(&frame)
+        if let Some(block) = self
+            .theme_watcher
+            .as_ref()
+            .and_then(ThemeWatcher::error_block)
+        {
+            frame.sticky.push(block);
+        }
+        if self.metrics.enabled() {
+            frame.sticky.push(self.metrics.sticky_block());
+        }
+        self.terminal.render_frame(&frame)?;
+        self.update_window_status()?;
+        self.announce()?;
+        let Some(draws) = draws else {
+            return Ok(());
+        };
+        let frame_time = started.elapsed();
+        if let Some(profiler) = self.profiler.as_mut() {
+            profiler(&FrameProfile {
+                frame_time,
+                bytes_written: self.terminal.bytes_written() - bytes_before,
+                draws: draws.clone(),
+            });
+        }
+        if self.metrics.enabled() {
+            self.metrics.record_frame(
+                frame_time,
+                self.scheduler.pending_len(),
+                draws,
+                allocations_before,
+            );
+        }
+        Ok(())
+    }
+
+    fn announce(&mut self) -> io::Result<()> {
+        let Some((announcer, out)) = self.announcements.as_mut() else {
+            return Ok(());
+        };
+        let lines = announcer.observe(&self.state);
+        if lines.is_empty() {
+            return Ok(());
+        }
+        for line in lines {
+            writeln!(out, "{line}")?;
+        }
+        out.flush()
+    }
+
+    fn update_window_status(&mut self) -> io::Result<()> {
+        let total = self.state.visible_step_indices().len();
+        if total == 0 {
+            return Ok(());
+        }
+        let current = self.state.current_visible_step_index();
+        if let Some(title) = self.window_title.as_deref() {
+            let title = format!("{title} — step {}/{total}", current + 1);
+            self.terminal.set_title(title.as_str())?;
+        }
+        let progress = if self.state.current_step_status() == StepStatus::Running {
+            TaskbarProgress::Indeterminate
+        } else {
+            TaskbarProgress::Percent((current * 100 / total) as u8)
+        };
+        self.terminal.set_progress(Some(progress))
     }
 
     fn selected_text(&self) -> Option<String> {
@@ -361,13 +906,26 @@ impl Runtime {
         extract_selected_text(&self.last_hit_map, &self.last_frame_lines, range)
     }
 
-    fn copy_selection_to_clipboard(&self) -> io::Result<()> {
+    fn copy_selection_to_clipboard(&mut self) -> io::Result<()> {
         let Some(text) = self.selected_text() else {
             return Ok(());
         };
         if text.is_empty() {
             return Ok(());
         }
-        clipboard::copy_text_to_clipboard(text.as_str())
+        self.copy_to_clipboard(text.as_str())
+    }
+
+    fn copy_to_clipboard(&mut self, text: &str) -> io::Result<()> {
+        if self.is_remote_session() {
+            return self.terminal.copy_via_osc52(text);
+        }
+        clipboard::copy_text_to_clipboard(text)
+    }
+
+    /// Drawing on a terminal at the far end of a connection, whose clipboard
+    /// and browser are not this machine's.
+    fn is_remote_session(&self) -> bool {
+        !self.terminal.is_headless() && !self.terminal.is_process_tty()
     }
 }
@@ -0,0 +1,195 @@
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+use steply_core::core::secrets::{SecretBackend, SecretKey, SecretStore};
+
+/// The secret backends of the host: the OS keychain, `pass` and the
+/// environment, reached through their command-line tools.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemSecretStore;
+
+impl SecretStore for SystemSecretStore {
+    fn read(&self, key: &SecretKey) -> Result<Option<String>, String> {
+        match key.backend {
+            SecretBackend::Env => Ok(std::env::var(key.env_var())
+                .ok()
+                .filter(|value| !value.is_empty())),
+            SecretBackend::Pass => {
+                let output = run("pass", &["show", key.pass_path().as_str()], None)?;
+                if !output.status.success() {
+                    let stderr = String::from_utf8_lossy(&output.stderr);
+                    if stderr.contains("is not in the password store") {
+                        return Ok(None);
+                    }
+                    return Err(failure("pass", &output));
+                }
+                // `pass` keeps the password on the first line.
+                let stdout = String::from_utf8_lossy(&output.stdout);
+                Ok(stdout.lines().next().map(str::to_string))
+            }
+            SecretBackend::Keychain => keychain_read(key),
+        }
+    }
+
+    fn write(&self, key: &SecretKey, secret: &str) -> Result<(), String> {
+        match key.backend {
+            // The environment is read-only; there is nothing to save.
+            SecretBackend::Env => Ok(()),
+            SecretBackend::Pass => {
+                let output = run(
+                    "pass",
+                    &["insert", "--multiline", "--force", key.pass_path().as_str()],
+                    Some(secret),
+                )?;
+                if output.status.success() {
+                    Ok(())
+                } else {
+                    Err(failure("pass", &output))
+                }
+            }
+            SecretBackend::Keychain => keychain_write(key, secret),
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn keychain_read(key: &SecretKey) -> Result<Option<String>, String> {
+    let output = run(
+        "security",
+        &[
+            "find-generic-password",
+            "-s",
+            key.service.as_str(),
+            "-a",
+            key.account.as_str(),
+            "-w",
+        ],
+        None,
+    )?;
+    // 44 is errSecItemNotFound.
+    match output.status.code() {
+        Some(0) => Ok(Some(trim_newline(output.stdout))),
+        Some(44) => Ok(None),
+        _ => Err(failure("security", &output)),
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn keychain_write(key: &SecretKey, secret: &str) -> Result<(), String> {
+    // `security` only takes the password as an argument, so it is briefly
+    // visible to other processes of the same user.
+    let output = run(
+        "security",
+        &[
+            "add-generic-password",
+            "-U",
+            "-s",
+            key.service.as_str(),
+            "-a",
+            key.account.as_str(),
+            "-w",
+            secret,
+        ],
+        None,
+    )?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(failure("security", &output))
+    }
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn keychain_read(key: &SecretKey) -> Result<Option<String>, String> {
+    let output = run(
+        "secret-tool",
+        &[
+            "lookup",
+            "service",
+            key.service.as_str(),
+            "account",
+            key.account.as_str(),
+        ],
+        None,
+    )?;
+    if output.status.success() {
+        return Ok(Some(trim_newline(output.stdout)));
+    }
+    // A missing entry exits non-zero without saying anything.
+    if output.stdout.is_empty() && output.stderr.is_empty() {
+        return Ok(None);
+    }
+    Err(failure("secret-tool", &output))
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn keychain_write(key: &SecretKey, secret: &str) -> Result<(), String> {
+    let label = format!("{} ({})", key.service, key.account);
+    let output = run(
+        "secret-tool",
+        &[
+            "store",
+            "--label",
+            label.as_str(),
+            "service",
+            key.service.as_str(),
+            "account",
+            key.account.as_str(),
+        ],
+        Some(secret),
+    )?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(failure("secret-tool", &output))
+    }
+}
+
+#[cfg(not(unix))]
+fn keychain_read(_key: &SecretKey) -> Result<Option<String>, String> {
+    Err("the keychain backend is not supported on this platform".to_string())
+}
+
+#[cfg(not(unix))]
+fn keychain_write(_key: &SecretKey, _secret: &str) -> Result<(), String> {
+    Err("the keychain backend is not supported on this platform".to_string())
+}
+
+fn run(program: &str, args: &[&str], stdin: Option<&str>) -> Result<Output, String> {
+    let mut child = Command::new(program)
+        .args(args)
+        .stdin(if stdin.is_some() {
+            Stdio::piped()
+        } else {
+            Stdio::null()
+        })
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|err| format!("failed to run '{program}': {err}"))?;
+    if let (Some(input), Some(mut pipe)) = (stdin, child.stdin.take()) {
+        pipe.write_all(input.as_bytes())
+            .map_err(|err| format!("failed to write to '{program}': {err}"))?;
+    }
+    child
+        .wait_with_output()
+        .map_err(|err| format!("failed to run '{program}': {err}"))
+}
+
+fn failure(program: &str, output: &Output) -> String {
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let stderr = stderr.trim();
+    if stderr.is_empty() {
+        format!("'{program}' exited with {}", output.status)
+    } else {
+        format!("'{program}' failed: {stderr}")
+    }
+}
+
+fn trim_newline(stdout: Vec<u8>) -> String {
+    let mut text = String::from_utf8_lossy(&stdout).into_owned();
+    if text.ends_with('\n') {
+        text.pop();
+    }
+    text
+}
@@ -1,21 +1,88 @@
 use std::io::{BufRead, BufReader, Read, Write};
+use std::panic::{self, AssertUnwindSafe};
 use std::process::{Command, Stdio};
 use std::thread::JoinHandle;
 use std::time::{Duration, Instant};
 use steply_core::core::value::Value;
 use steply_core::task::execution::{TaskCompletion, TaskInvocation};
+use steply_core::task::native::{NativeTask, TaskContext, parse_progress_line};
 use steply_core::task::spec::TaskKind;
 
 pub fn execute_invocation(invocation: TaskInvocation) -> TaskCompletion {
-    let task_id = invocation.spec.id.clone();
-    let concurrency_policy = invocation.spec.concurrency_policy;
+    let _span = steply_core::trace_span!(
+        DEBUG,
+        "task_run",
+        task_id = %invocation.spec.id,
+        run_id = invocation.run_id
+    );
+    match invocation.spec.kind.clone() {
+        TaskKind::Exec {
+            program,
+            args,
+            timeout_ms,
+            stream_stdout,
+            ..
+        } => execute_exec(invocation, program, args, timeout_ms, stream_stdout),
+        TaskKind::Native { run, .. } => execute_native(invocation, &run),
+    }
+}
+
+fn execute_native(invocation: TaskInvocation, run: &NativeTask) -> TaskCompletion {
+    let completion = |result: Value, error: Option<String>, cancelled: bool| TaskCompletion {
+        task_id: invocation.spec.id.clone(),
+        run_id: invocation.run_id,
+        concurrency_policy: invocation.spec.concurrency_policy,
+        result,
+        error,
+        cancelled,
+        exit_code: None,
+    };
 
-    let TaskKind::Exec {
-        program,
-        args,
-        timeout_ms,
-        ..
-    } = invocation.spec.kind.clone();
+    let input = match Value::from_json(invocation.stdin_json.as_str()) {
+        Ok(input) => input,
+        Err(err) => {
+            return completion(
+                Value::None,
+                Some(format!("invalid task input: {err}")),
+                false,
+            );
+        }
+    };
+    let ctx = TaskContext::new(
+        input,
+        invocation.cancel_token.clone(),
+        invocation.log_tx.clone(),
+        invocation.progress_tx.clone(),
+    );
+    let outcome = panic::catch_unwind(AssertUnwindSafe(|| run.run(&ctx)));
+    if invocation.cancel_token.is_cancelled() {
+        return completion(Value::None, Some("cancelled".to_string()), true);
+    }
+    match outcome {
+        Ok(Ok(result)) => completion(result, None, false),
+        Ok(Err(err)) => completion(Value::None, Some(err), false),
+        Err(_) => completion(Value::None, Some("task panicked".to_string()), false),
+    }
+}
+
+fn execute_exec(
+    invocation: TaskInvocation,
+    program: String,
+    args: Vec<String>,
+    timeout_ms: u64,
+    stream_stdout: bool,
+) -> TaskCompletion {
+    let completion =
+        |result: Value, error: Option<String>, exit_code: Option<i32>| TaskCompletion {
+            task_id: invocation.spec.id.clone(),
+            run_id: invocation.run_id,
+            concurrency_policy: invocation.spec.concurrency_policy,
+            result,
+            error,
+            cancelled: false,
+            exit_code,
+        };
+    let failed = |error: String| completion(Value::None, Some(error), None);
 
     let mut command = Command::new(program.as_str());
     command
@@ -26,16 +93,7 @@ pub fn execute_invocation(invocation: TaskInvocation) -> TaskCompletion {
 
     let mut child = match command.spawn() {
         Ok(child) => child,
-        Err(err) => {
-            return TaskCompletion {
-                task_id,
-                run_id: invocation.run_id,
-                concurrency_policy,
-                result: Value::None,
-                error: Some(format!("spawn failed: {err}")),
-                cancelled: false,
-            };
-        }
+        Err(err) => return failed(format!("spawn failed: {err}")),
     };
 
     if let Some(mut stdin) = child.stdin.take()
@@ -43,22 +101,30 @@ pub fn execute_invocation(invocation: TaskInvocation) -> TaskCompletion {
     {
         let _ = child.kill();
         let _ = child.wait();
-        return TaskCompletion {
-            task_id,
-            run_id: invocation.run_id,
-            concurrency_policy,
-            result: Value::None,
-            error: Some(format!("stdin write failed: {err}")),
-            cancelled: false,
-        };
+        return failed(format!("stdin write failed: {err}"));
     }
 
     let log_tx = invocation.log_tx.clone();
+    let progress_tx = invocation.progress_tx.clone();
+    let stdout_log_tx = if stream_stdout {
+        invocation.log_tx.clone()
+    } else {
+        None
+    };
     let mut stdout_handle = child.stdout.take().map(|stdout| {
         std::thread::spawn(move || {
-            let mut buf = String::new();
-            let _ = BufReader::new(stdout).read_to_string(&mut buf);
-            buf
+            let Some(tx) = stdout_log_tx else {
+                let mut buf = String::new();
+                let _ = BufReader::new(stdout).read_to_string(&mut buf);
+                return buf;
+            };
+            let mut lines = Vec::new();
+            for line in BufReader::new(stdout).lines() {
+                let line = line.unwrap_or_default();
+                let _ = tx.send(line.clone());
+                lines.push(line);
+            }
+            lines.join("\n")
         })
     });
     let mut stderr_handle = child.stderr.take().map(|stderr| {
@@ -67,6 +133,12 @@ pub fn execute_invocation(invocation: TaskInvocation) -> TaskCompletion {
             let mut lines = Vec::new();
             for line in reader.lines() {
                 let line = line.unwrap_or_default();
+                if let Some(progress) = parse_progress_line(line.as_str()) {
+                    if let Some(ref tx) = progress_tx {
+                        let _ = tx.send(progress);
+                    }
+                    continue;
+                }
                 if let Some(ref tx) = log_tx {
                     let _ = tx.send(line.clone());
                 }
@@ -85,12 +157,8 @@ pub fn execute_invocation(invocation: TaskInvocation) -> TaskCompletion {
             let _ = take_output(&mut stdout_handle);
             let _ = take_output(&mut stderr_handle);
             return TaskCompletion {
-                task_id,
-                run_id: invocation.run_id,
-                concurrency_policy,
-                result: Value::None,
-                error: Some("cancelled".to_string()),
                 cancelled: true,
+                ..failed("cancelled".to_string())
             };
         }
 
@@ -102,14 +170,7 @@ pub fn execute_invocation(invocation: TaskInvocation) -> TaskCompletion {
                     let _ = child.wait();
                     let _ = take_output(&mut stdout_handle);
                     let _ = take_output(&mut stderr_handle);
-                    return TaskCompletion {
-                        task_id,
-                        run_id: invocation.run_id,
-                        concurrency_policy,
-                        result: Value::None,
-                        error: Some(format!("timeout after {}ms", timeout_ms.max(1))),
-                        cancelled: false,
-                    };
+                    return failed(format!("timeout after {}ms", timeout_ms.max(1)));
                 }
                 std::thread::sleep(Duration::from_millis(10));
             }
@@ -118,56 +179,32 @@ pub fn execute_invocation(invocation: TaskInvocation) -> TaskCompletion {
                 let _ = child.wait();
                 let _ = take_output(&mut stdout_handle);
                 let _ = take_output(&mut stderr_handle);
-                return TaskCompletion {
-                    task_id,
-                    run_id: invocation.run_id,
-                    concurrency_policy,
-                    result: Value::None,
-                    error: Some(format!("wait failed: {err}")),
-                    cancelled: false,
-                };
+                return failed(format!("wait failed: {err}"));
             }
         }
     };
 
     let stdout = take_output(&mut stdout_handle);
     let stderr = take_output(&mut stderr_handle);
+    let exit_code = status.code();
 
-    let status_error = if status.success() {
-        None
-    } else {
-        Some(format!(
+    if !status.success() {
+        let error = format!(
             "exit status {:?}: {}",
-            status.code(),
+            exit_code,
             normalize_text(stderr.as_str())
-        ))
-    };
-
-    let result = if status_error.is_none() {
-        match parse_task_result(stdout.as_str()) {
-            Ok(result) => result,
-            Err(err) => {
-                return TaskCompletion {
-                    task_id,
-                    run_id: invocation.run_id,
-                    concurrency_policy,
-                    result: Value::None,
-                    error: Some(err),
-                    cancelled: false,
-                };
-            }
-        }
-    } else {
-        Value::None
-    };
+        );
+        return completion(Value::None, Some(error), exit_code);
+    }
 
-    TaskCompletion {
-        task_id,
-        run_id: invocation.run_id,
-        concurrency_policy,
-        result,
-        error: status_error,
-        cancelled: false,
+    match parse_task_result(stdout.as_str()) {
+        Ok(result) => completion(result, None, exit_code),
+        Err(_) if stream_stdout => completion(
+            Value::Text(normalize_text(stdout.as_str()).to_string()),
+            None,
+            exit_code,
+        ),
+        Err(err) => completion(Value::None, Some(err), exit_code),
     }
 }
 
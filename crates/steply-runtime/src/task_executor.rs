@@ -28,6 +28,12 @@ pub struct LogLine {
     pub line: String,
 }
 
+pub struct ProgressUpdate {
+    pub task_id: TaskId,
+    pub run_id: u64,
+    pub progress: f64,
+}
+
 pub struct TaskExecutor {
     limits: TaskExecutorLimits,
     invocation_tx: SyncSender<TaskInvocation>,
@@ -35,6 +41,8 @@ pub struct TaskExecutor {
     completion_tx: Sender<TaskCompletion>,
     log_rx: Receiver<LogLine>,
     log_tx: Sender<LogLine>,
+    progress_rx: Receiver<ProgressUpdate>,
+    progress_tx: Sender<ProgressUpdate>,
 }
 
 impl TaskExecutor {
@@ -47,6 +55,7 @@ impl TaskExecutor {
             mpsc::sync_channel::<TaskInvocation>(limits.queue_capacity.max(1));
         let (completion_tx, completion_rx) = mpsc::channel::<TaskCompletion>();
         let (log_tx, log_rx) = mpsc::channel::<LogLine>();
+        let (progress_tx, progress_rx) = mpsc::channel::<ProgressUpdate>();
         spawn_workers(invocation_rx, completion_tx.clone());
         Self {
             limits,
@@ -55,18 +64,30 @@ impl TaskExecutor {
             completion_tx,
             log_rx,
             log_tx,
+            progress_rx,
+            progress_tx,
         }
     }
 
     pub fn spawn(&self, mut invocation: TaskInvocation) {
-        invocation.log_tx = Some(
-            LogLineSender {
-                task_id: invocation.spec.id.clone(),
-                run_id: invocation.run_id,
-                tx: self.log_tx.clone(),
+        let task_id = invocation.spec.id.clone();
+        let run_id = invocation.run_id;
+        steply_core::trace_event!(DEBUG, task_id = %task_id, run_id, "task run queued");
+        invocation.log_tx = Some(forward_to(self.log_tx.clone(), {
+            let task_id = task_id.clone();
+            move |line| LogLine {
+                task_id: task_id.clone(),
+                run_id,
+                line,
             }
-            .into_sender(),
-        );
+        }));
+        invocation.progress_tx = Some(forward_to(self.progress_tx.clone(), move |progress| {
+            ProgressUpdate {
+                task_id: task_id.clone(),
+                run_id,
+                progress,
+            }
+        }));
         match self.invocation_tx.try_send(invocation) {
             Ok(()) => {}
             Err(TrySendError::Full(invocation)) => {
@@ -103,6 +124,20 @@ impl TaskExecutor {
         }
         out
     }
+
+    /// Progress updates are coalesced per run: only the latest value is kept.
+    pub fn drain_progress(&self) -> Vec<ProgressUpdate> {
+        let mut out = Vec::<ProgressUpdate>::new();
+        while let Ok(update) = self.progress_rx.try_recv() {
+            match out.iter_mut().find(|pending| {
+                pending.task_id == update.task_id && pending.run_id == update.run_id
+            }) {
+                Some(pending) => pending.progress = update.progress,
+                None => out.push(update),
+            }
+        }
+        out
+    }
 }
 
 impl Default for TaskExecutor {
@@ -111,26 +146,19 @@ impl Default for TaskExecutor {
     }
 }
 
-struct LogLineSender {
-    task_id: TaskId,
-    run_id: u64,
-    tx: Sender<LogLine>,
-}
-
-impl LogLineSender {
-    fn into_sender(self) -> Sender<String> {
-        let (line_tx, line_rx) = mpsc::channel::<String>();
-        std::thread::spawn(move || {
-            while let Ok(line) = line_rx.recv() {
-                let _ = self.tx.send(LogLine {
-                    task_id: self.task_id.clone(),
-                    run_id: self.run_id,
-                    line,
-                });
+fn forward_to<T: Send + 'static, U: Send + 'static>(
+    tx: Sender<U>,
+    wrap: impl Fn(T) -> U + Send + 'static,
+) -> Sender<T> {
+    let (item_tx, item_rx) = mpsc::channel::<T>();
+    std::thread::spawn(move || {
+        while let Ok(item) = item_rx.recv() {
+            if tx.send(wrap(item)).is_err() {
+                return;
             }
-        });
-        line_tx
-    }
+        }
+    });
+    item_tx
 }
 
 fn spawn_workers(invocation_rx: Receiver<TaskInvocation>, completion_tx: Sender<TaskCompletion>) {
@@ -169,5 +197,6 @@ fn rejected_completion(invocation: TaskInvocation, reason: &str) -> TaskCompleti
         result: Value::None,
         error: Some(reason.to_string()),
         cancelled: false,
+        exit_code: None,
     }
 }
@@ -0,0 +1,6 @@
+pub use crate::task_executor::{LogLine, ProgressUpdate, TaskExecutor, TaskExecutorLimits};
+pub use steply_core::task::native::parse_progress_line;
+pub use steply_core::task::{
+    ConcurrencyPolicy, NativeTask, RerunPolicy, TaskCancelToken, TaskCompletion, TaskContext,
+    TaskId, TaskKind, TaskRequest, TaskSpec, TaskStartResult, TaskTrigger,
+};
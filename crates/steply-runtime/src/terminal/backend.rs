@@ -0,0 +1,383 @@
+use super::input_decoder::InputDecoder;
+use super::input_mapping::map_event;
+use crossterm::event;
+use std::collections::VecDeque;
+use std::io::{self, Stderr, Stdout, Write};
+use std::sync::mpsc::{Receiver, RecvTimeoutError};
+use std::time::{Duration, Instant};
+use steply_core::terminal::{TerminalCapabilities, TerminalEvent, TerminalSize};
+
+/// Low-level terminal I/O driven by [`Terminal`](super::Terminal).
+///
+/// Frames are written to the backend itself as ANSI escape sequences, so a
+/// backend only has to move bytes and input; layout, diffing and screen
+/// modes stay in `Terminal`.
+pub trait Backend: Write + Send {
+    fn size(&self) -> io::Result<TerminalSize>;
+    fn enable_raw_mode(&mut self) -> io::Result<()>;
+    fn disable_raw_mode(&mut self) -> io::Result<()>;
+    /// Waits up to `timeout` for input. `Ok(None)` means nothing arrived.
+    fn poll_event(&mut self, timeout: Duration) -> io::Result<Option<TerminalEvent>>;
+    /// Zero-based `(column, row)` of the cursor.
+    fn cursor_position(&mut self) -> io::Result<(u16, u16)>;
+
+    fn capabilities(&self) -> TerminalCapabilities {
+        TerminalCapabilities::default()
+    }
+
+    /// The terminal's background color, for picking a light or dark theme.
+    /// `None` when it cannot be asked or does not answer.
+    fn background_color(&mut self) -> io::Result<Option<(u8, u8, u8)>> {
+        Ok(None)
+    }
+
+    /// Headless backends are never switched into raw mode or an alternate
+    /// screen, and their size only changes through resize events.
+    fn is_headless(&self) -> bool {
+        false
+    }
+
+    /// Queues scripted input. Backends reading a real tty ignore it.
+    fn push_event(&mut self, _event: TerminalEvent) {}
+
+    /// The backend draws on this process's own tty, so a panic hook can
+    /// restore it without going through the backend.
+    fn is_process_tty(&self) -> bool {
+        false
+    }
+}
+
+/// The tty backend, reading input and sizes through crossterm.
+pub struct CrosstermBackend<W> {
+    out: W,
+    capabilities: TerminalCapabilities,
+}
+
+impl<W: Write + Send> CrosstermBackend<W> {
+    pub fn new(out: W) -> Self {
+        Self {
+            out,
+            capabilities: TerminalCapabilities::detect(|name| std::env::var(name).ok()),
+        }
+    }
+}
+
+impl CrosstermBackend<Stdout> {
+    pub fn stdout() -> Self {
+        Self::new(io::stdout())
+    }
+}
+
+impl CrosstermBackend<Stderr> {
+    pub fn stderr() -> Self {
+        Self::new(io::stderr())
+    }
+}
+
+impl<W: Write> Write for CrosstermBackend<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.out.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.out.flush()
+    }
+}
+
+impl<W: Write + Send> Backend for CrosstermBackend<W> {
+    fn size(&self) -> io::Result<TerminalSize> {
+        let (width, height) = crossterm::terminal::size()?;
+        Ok(TerminalSize { width, height })
+    }
+
+    fn enable_raw_mode(&mut self) -> io::Result<()> {
+        crossterm::terminal::enable_raw_mode()
+    }
+
+    fn disable_raw_mode(&mut self) -> io::Result<()> {
+        crossterm::terminal::disable_raw_mode()
+    }
+
+    fn is_process_tty(&self) -> bool {
+        true
+    }
+
+    fn poll_event(&mut self, timeout: Duration) -> io::Result<Option<TerminalEvent>> {
+        if !event::poll(timeout)? {
+            return Ok(None);
+        }
+        // Input that maps to nothing still wakes the loop, as a tick.
+        Ok(Some(
+            map_event(event::read()?).unwrap_or(TerminalEvent::Tick),
+        ))
+    }
+
+    fn cursor_position(&mut self) -> io::Result<(u16, u16)> {
+        crossterm::cursor::position()
+    }
+
+    fn capabilities(&self) -> TerminalCapabilities {
+        self.capabilities
+    }
+
+    fn background_color(&mut self) -> io::Result<Option<(u8, u8, u8)>> {
+        super::background::query_background(&mut self.out)
+    }
+}
+
+/// A terminal of a fixed size that lives in memory: output is discarded and
+/// input comes from [`Backend::push_event`].
+///
+/// Waiting for input after the script runs out fails with `UnexpectedEof`;
+/// a zero-timeout poll just reports that nothing is pending.
+#[derive(Debug, Clone)]
+pub struct MemoryBackend {
+    size: TerminalSize,
+    events: VecDeque<TerminalEvent>,
+}
+
+impl MemoryBackend {
+    pub fn new(size: TerminalSize) -> Self {
+        Self {
+            size,
+            events: VecDeque::new(),
+        }
+    }
+}
+
+impl Write for MemoryBackend {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Backend for MemoryBackend {
+    fn size(&self) -> io::Result<TerminalSize> {
+        Ok(self.size)
+    }
+
+    fn enable_raw_mode(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn disable_raw_mode(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn poll_event(&mut self, timeout: Duration) -> io::Result<Option<TerminalEvent>> {
+        match self.events.pop_front() {
+            Some(event) => Ok(Some(event)),
+            None if timeout.is_zero() => Ok(None),
+            None => Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "headless input exhausted",
+            )),
+        }
+    }
+
+    fn cursor_position(&mut self) -> io::Result<(u16, u16)> {
+        Ok((0, 0))
+    }
+
+    fn is_headless(&self) -> bool {
+        true
+    }
+
+    fn push_event(&mut self, event: TerminalEvent) {
+        self.events.push_back(event);
+    }
+}
+
+/// What the carrier of a [`ChannelBackend`] session hands it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChannelInput {
+    /// Raw bytes typed into the remote terminal.
+    Bytes(Vec<u8>),
+    /// The remote window changed size.
+    Resize(TerminalSize),
+}
+
+/// A terminal at the far end of a connection, such as an SSH channel with a
+/// pty: frames go to `out` as ANSI, and the bytes the remote terminal sends
+/// arrive through a channel and are decoded the way a tty would deliver
+/// them. Dropping the sender ends the session, so polling fails with
+/// `UnexpectedEof`.
+///
+/// Nothing here touches the process tty, signals or stdout, so any number of
+/// sessions can run side by side, each with its own [`Runtime`](crate::Runtime).
+pub struct ChannelBackend {
+    out: Box<dyn Write + Send>,
+    input: Receiver<ChannelInput>,
+    decoder: InputDecoder,
+    events: VecDeque<TerminalEvent>,
+    size: TerminalSize,
+    capabilities: TerminalCapabilities,
+}
+
+impl ChannelBackend {
+    pub fn new(
+        out: impl Write + Send + 'static,
+        input: Receiver<ChannelInput>,
+        size: TerminalSize,
+        capabilities: TerminalCapabilities,
+    ) -> Self {
+        Self {
+            out: Box::new(out),
+            input,
+            decoder: InputDecoder::default(),
+            events: VecDeque::new(),
+            size,
+            capabilities,
+        }
+    }
+
+    fn accept(&mut self, input: ChannelInput) {
+        match input {
+            ChannelInput::Bytes(bytes) => {
+                let mut decoded = Vec::new();
+                self.decoder.feed(bytes.as_slice(), &mut decoded);
+                self.events.extend(decoded);
+            }
+            ChannelInput::Resize(size) => {
+                self.size = size;
+                self.events.push_back(TerminalEvent::Resize(size));
+            }
+        }
+    }
+}
+
+impl Write for ChannelBackend {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.out.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.out.flush()
+    }
+}
+
+impl Backend for ChannelBackend {
+    fn size(&self) -> io::Result<TerminalSize> {
+        Ok(self.size)
+    }
+
+    // The remote pty is already raw as far as this process is concerned.
+    fn enable_raw_mode(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn disable_raw_mode(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn poll_event(&mut self, timeout: Duration) -> io::Result<Option<TerminalEvent>> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            if let Some(event) = self.events.pop_front() {
+                return Ok(Some(event));
+            }
+            let input = match self
+                .input
+                .recv_timeout(deadline.saturating_duration_since(Instant::now()))
+            {
+                Ok(input) => input,
+                Err(RecvTimeoutError::Timeout) => return Ok(None),
+                Err(RecvTimeoutError::Disconnected) => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::UnexpectedEof,
+                        "remote session closed",
+                    ));
+                }
+            };
+            self.accept(input);
+        }
+    }
+
+    /// Only inline rendering asks; remote sessions draw on the alternate
+    /// screen.
+    fn cursor_position(&mut self) -> io::Result<(u16, u16)> {
+        Ok((0, 0))
+    }
+
+    fn capabilities(&self) -> TerminalCapabilities {
+        self.capabilities
+    }
+}
+
+/// Wraps the backend a [`Terminal`](super::Terminal) draws on, counting the
+/// bytes written to it for the profiling hook.
+pub(super) struct CountingBackend {
+    inner: Box<dyn Backend>,
+    bytes_written: u64,
+}
+
+impl CountingBackend {
+    pub(super) fn new(inner: Box<dyn Backend>) -> Self {
+        Self {
+            inner,
+            bytes_written: 0,
+        }
+    }
+
+    pub(super) fn bytes_written(&self) -> u64 {
+        self.bytes_written
+    }
+}
+
+impl Write for CountingBackend {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.bytes_written += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+impl Backend for CountingBackend {
+    fn size(&self) -> io::Result<TerminalSize> {
+        self.inner.size()
+    }
+
+    fn enable_raw_mode(&mut self) -> io::Result<()> {
+        self.inner.enable_raw_mode()
+    }
+
+    fn disable_raw_mode(&mut self) -> io::Result<()> {
+        self.inner.disable_raw_mode()
+    }
+
+    fn poll_event(&mut self, timeout: Duration) -> io::Result<Option<TerminalEvent>> {
+        self.inner.poll_event(timeout)
+    }
+
+    fn cursor_position(&mut self) -> io::Result<(u16, u16)> {
+        self.inner.cursor_position()
+    }
+
+    fn capabilities(&self) -> TerminalCapabilities {
+        self.inner.capabilities()
+    }
+
+    fn background_color(&mut self) -> io::Result<Option<(u8, u8, u8)>> {
+        self.inner.background_color()
+    }
+
+    fn is_headless(&self) -> bool {
+        self.inner.is_headless()
+    }
+
+    fn push_event(&mut self, event: TerminalEvent) {
+        self.inner.push_event(event);
+    }
+
+    fn is_process_tty(&self) -> bool {
+        self.inner.is_process_tty()
+    }
+}
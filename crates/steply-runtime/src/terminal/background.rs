@@ -0,0 +1,85 @@
+use std::io::{self, Write};
+use std::time::Duration;
+
+/// How long to wait for the terminal's answer. Terminals reply to the
+/// trailing device attributes query even when they ignore `OSC 11`, so the
+/// wait only runs out on terminals that answer neither.
+const REPLY_TIMEOUT: Duration = Duration::from_millis(200);
+
+/// Asks the terminal for its background color with `OSC 11`, followed by
+/// a primary device attributes query (`CSI c`) that marks the end of the
+/// reply. Needs raw mode, or the reply would wait for a newline.
+#[cfg(unix)]
+pub(super) fn query_background(out: &mut impl Write) -> io::Result<Option<(u8, u8, u8)>> {
+    use std::io::Read;
+    use std::os::fd::AsRawFd;
+    use std::time::Instant;
+
+    let mut tty = std::fs::File::open("/dev/tty")?;
+    out.write_all(b"\x1b]11;?\x1b\\\x1b[c")?;
+    out.flush()?;
+
+    let deadline = Instant::now() + REPLY_TIMEOUT;
+    let mut reply = Vec::new();
+    while !has_device_attributes(reply.as_slice()) {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            break;
+        }
+        let mut poll = libc::pollfd {
+            fd: tty.as_raw_fd(),
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        // SAFETY: one pollfd for a descriptor that stays open for the call.
+        let ready = unsafe { libc::poll(&mut poll, 1, remaining.as_millis() as libc::c_int) };
+        if ready <= 0 {
+            break;
+        }
+        let mut buf = [0u8; 64];
+        let read = tty.read(&mut buf)?;
+        if read == 0 {
+            break;
+        }
+        reply.extend_from_slice(&buf[..read]);
+    }
+    Ok(parse_background_reply(
+        String::from_utf8_lossy(&reply).as_ref(),
+    ))
+}
+
+#[cfg(not(unix))]
+pub(super) fn query_background(_out: &mut impl Write) -> io::Result<Option<(u8, u8, u8)>> {
+    Ok(None)
+}
+
+fn has_device_attributes(reply: &[u8]) -> bool {
+    reply
+        .windows(3)
+        .position(|window| window == b"\x1b[?")
+        .is_some_and(|start| reply[start..].contains(&b'c'))
+}
+
+/// Reads `ESC ] 11 ; rgb:RRRR/GGGG/BBBB` (ended by BEL or ST) out of
+/// whatever the terminal sent back. Channels may have one to four hex
+/// digits and are scaled to 8 bits.
+pub(super) fn parse_background_reply(reply: &str) -> Option<(u8, u8, u8)> {
+    let start = reply.find("]11;")? + 4;
+    let body = &reply[start..];
+    let end = body.find(['\x07', '\x1b']).unwrap_or(body.len());
+    let body = body[..end].strip_prefix("rgb:")?;
+    let mut channels = body.split('/').map(|channel| {
+        let value = u32::from_str_radix(channel, 16).ok()?;
+        let max = match channel.len() {
+            1..=4 => (1u32 << (4 * channel.len())) - 1,
+            _ => return None,
+        };
+        Some(((value * 255 + max / 2) / max) as u8)
+    });
+    let color = (channels.next()??, channels.next()??, channels.next()??);
+    channels.next().is_none().then_some(color)
+}
+
+#[cfg(test)]
+#[path = "tests/background.rs"]
+mod tests;
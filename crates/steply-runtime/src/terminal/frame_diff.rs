@@ -221,6 +221,7 @@ fn color_sig(color: Option<Color>) -> u64 {
         Some(Color::Magenta) => 8,
         Some(Color::Cyan) => 9,
         Some(Color::White) => 10,
+        Some(Color::Indexed(index)) => 12u64 << 32 | index as u64,
         Some(Color::Rgb(r, g, b)) => 11u64 << 32 | (r as u64) << 16 | (g as u64) << 8 | (b as u64),
     }
 }
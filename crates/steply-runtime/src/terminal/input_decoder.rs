@@ -0,0 +1,291 @@
+use super::{
+    KeyCode, KeyEvent, KeyModifiers, PointerButton, PointerEvent, PointerKind, PointerSemantic,
+    TerminalEvent,
+};
+
+const ESC: u8 = 0x1b;
+const PASTE_START: &[u8] = b"\x1b[200~";
+const PASTE_END: &[u8] = b"\x1b[201~";
+
+/// Turns the raw bytes a terminal sends into events, for backends that do
+/// not read the process tty through crossterm.
+///
+/// Escape sequences split across two reads are reassembled. A lone `ESC` at
+/// the end of a read is taken as the Esc key, which is how terminals deliver
+/// it: sequences arrive in one write.
+#[derive(Debug, Default)]
+pub(crate) struct InputDecoder {
+    pending: Vec<u8>,
+    paste: Option<Vec<u8>>,
+}
+
+impl InputDecoder {
+    pub(crate) fn feed(&mut self, bytes: &[u8], out: &mut Vec<TerminalEvent>) {
+        self.pending.extend_from_slice(bytes);
+        let mut pos = 0;
+        while pos < self.pending.len() {
+            let rest = &self.pending[pos..];
+            if let Some(paste) = self.paste.as_mut() {
+                match find(rest, PASTE_END) {
+                    Some(end) => {
+                        paste.extend_from_slice(&rest[..end]);
+                        let text = String::from_utf8_lossy(paste).into_owned();
+                        out.push(TerminalEvent::Paste(text));
+                        self.paste = None;
+                        pos += end + PASTE_END.len();
+                    }
+                    None => {
+                        // Keep a possible partial terminator for the next read.
+                        let keep = partial_suffix(rest, PASTE_END);
+                        paste.extend_from_slice(&rest[..rest.len() - keep]);
+                        pos = self.pending.len() - keep;
+                        break;
+                    }
+                }
+                continue;
+            }
+            if rest.starts_with(PASTE_START) {
+                self.paste = Some(Vec::new());
+                pos += PASTE_START.len();
+                continue;
+            }
+            match decode_one(rest) {
+                Decoded::Event(event, used) => {
+                    out.extend(event);
+                    pos += used;
+                }
+                Decoded::Incomplete => break,
+            }
+        }
+        self.pending.drain(..pos);
+    }
+}
+
+enum Decoded {
+    Event(Option<TerminalEvent>, usize),
+    Incomplete,
+}
+
+fn decode_one(bytes: &[u8]) -> Decoded {
+    match bytes[0] {
+        ESC => decode_escape(bytes),
+        _ => match decode_key(bytes) {
+            Some((key, used)) => Decoded::Event(Some(TerminalEvent::Key(key)), used),
+            None => Decoded::Incomplete,
+        },
+    }
+}
+
+fn decode_escape(bytes: &[u8]) -> Decoded {
+    match bytes.get(1) {
+        None => Decoded::Event(Some(key(KeyCode::Esc, KeyModifiers::NONE)), 1),
+        Some(b'[') => decode_csi(bytes),
+        Some(b'O') => match bytes.get(2) {
+            None => Decoded::Incomplete,
+            Some(&final_byte) => {
+                let event = cursor_key(final_byte).map(|code| key(code, KeyModifiers::NONE));
+                Decoded::Event(event, 3)
+            }
+        },
+        Some(&ESC) => Decoded::Event(Some(key(KeyCode::Esc, KeyModifiers::NONE)), 1),
+        Some(_) => match decode_key(&bytes[1..]) {
+            Some((mut alt, used)) => {
+                alt.modifiers = alt.modifiers.union(KeyModifiers::ALT);
+                Decoded::Event(Some(TerminalEvent::Key(alt)), used + 1)
+            }
+            None => Decoded::Incomplete,
+        },
+    }
+}
+
+fn decode_csi(bytes: &[u8]) -> Decoded {
+    let Some(end) = bytes
+        .iter()
+        .skip(2)
+        .position(|byte| (0x40..=0x7e).contains(byte))
+        .map(|idx| idx + 2)
+    else {
+        return Decoded::Incomplete;
+    };
+    let params = std::str::from_utf8(&bytes[2..end]).unwrap_or_default();
+    let used = end + 1;
+    let final_byte = bytes[end];
+    if let Some(mouse) = params.strip_prefix('<') {
+        return Decoded::Event(decode_sgr_mouse(mouse, final_byte), used);
+    }
+    let mut fields = params.split(';');
+    let first = fields.next().and_then(|field| field.parse::<u32>().ok());
+    let modifiers = fields
+        .next()
+        .and_then(|field| field.parse::<u8>().ok())
+        .map_or(KeyModifiers::NONE, modifiers_from_param);
+    let event = match final_byte {
+        b'I' => Some(TerminalEvent::FocusGained),
+        b'O' => Some(TerminalEvent::FocusLost),
+        b'Z' => Some(key(KeyCode::BackTab, KeyModifiers::NONE)),
+        b'~' => tilde_key(first.unwrap_or_default()).map(|code| key(code, modifiers)),
+        b'u' => first
+            .and_then(char::from_u32)
+            .and_then(|ch| decode_key(ch.to_string().as_bytes()))
+            .map(|(mut event, _)| {
+                event.modifiers = event.modifiers.union(modifiers);
+                TerminalEvent::Key(event)
+            }),
+        other => cursor_key(other).map(|code| key(code, modifiers)),
+    };
+    Decoded::Event(event, used)
+}
+
+fn decode_sgr_mouse(params: &str, final_byte: u8) -> Option<TerminalEvent> {
+    let mut fields = params.split(';').map(|field| field.parse::<u16>().ok());
+    let (Some(Some(code)), Some(Some(col)), Some(Some(row))) =
+        (fields.next(), fields.next(), fields.next())
+    else {
+        return None;
+    };
+    let mut modifiers = KeyModifiers::NONE;
+    for (bit, modifier) in [
+        (4, KeyModifiers::SHIFT),
+        (8, KeyModifiers::ALT),
+        (16, KeyModifiers::CONTROL),
+    ] {
+        if code & bit != 0 {
+            modifiers = modifiers.union(modifier);
+        }
+    }
+    let button = match code & 0b11 {
+        0 => Some(PointerButton::Left),
+        1 => Some(PointerButton::Middle),
+        2 => Some(PointerButton::Right),
+        _ => None,
+    };
+    let kind = if code & 64 != 0 {
+        match code & 0b11 {
+            0 => PointerKind::ScrollUp,
+            1 => PointerKind::ScrollDown,
+            2 => return Some(TerminalEvent::Scroll(-3)),
+            _ => return Some(TerminalEvent::Scroll(3)),
+        }
+    } else if code & 32 != 0 {
+        button.map_or(PointerKind::Move, PointerKind::Drag)
+    } else if final_byte == b'm' {
+        PointerKind::Up(button?)
+    } else {
+        PointerKind::Down(button?)
+    };
+    Some(TerminalEvent::Pointer(PointerEvent {
+        kind,
+        col: col.saturating_sub(1),
+        row: row.saturating_sub(1),
+        modifiers,
+        semantic: PointerSemantic::None,
+    }))
+}
+
+fn decode_key(bytes: &[u8]) -> Option<(KeyEvent, usize)> {
+    let byte = bytes[0];
+    let (code, modifiers) = match byte {
+        b'\r' | b'\n' => (KeyCode::Enter, KeyModifiers::NONE),
+        b'\t' => (KeyCode::Tab, KeyModifiers::NONE),
+        0x7f => (KeyCode::Backspace, KeyModifiers::NONE),
+        // Ctrl+H arrives as backspace's control character; keep it Ctrl+h
+        // like the tty backend does.
+        0x08 => (KeyCode::Char('h'), KeyModifiers::CONTROL),
+        0x00 => (KeyCode::Char(' '), KeyModifiers::CONTROL),
+        0x01..=0x1a => (
+            KeyCode::Char((b'a' + byte - 1) as char),
+            KeyModifiers::CONTROL,
+        ),
+        0x1c..=0x1f => (
+            KeyCode::Char((b'4' + byte - 0x1c) as char),
+            KeyModifiers::CONTROL,
+        ),
+        _ => {
+            let len = utf8_len(byte);
+            if bytes.len() < len {
+                return None;
+            }
+            let ch = std::str::from_utf8(&bytes[..len])
+                .ok()
+                .and_then(|text| text.chars().next())
+                .unwrap_or(char::REPLACEMENT_CHARACTER);
+            return Some((
+                KeyEvent {
+                    code: KeyCode::Char(ch),
+                    modifiers: KeyModifiers::NONE,
+                },
+                len,
+            ));
+        }
+    };
+    Some((KeyEvent { code, modifiers }, 1))
+}
+
+fn cursor_key(final_byte: u8) -> Option<KeyCode> {
+    match final_byte {
+        b'A' => Some(KeyCode::Up),
+        b'B' => Some(KeyCode::Down),
+        b'C' => Some(KeyCode::Right),
+        b'D' => Some(KeyCode::Left),
+        b'H' => Some(KeyCode::Home),
+        b'F' => Some(KeyCode::End),
+        _ => None,
+    }
+}
+
+fn tilde_key(code: u32) -> Option<KeyCode> {
+    match code {
+        1 | 7 => Some(KeyCode::Home),
+        3 => Some(KeyCode::Delete),
+        4 | 8 => Some(KeyCode::End),
+        5 => Some(KeyCode::PageUp),
+        6 => Some(KeyCode::PageDown),
+        _ => None,
+    }
+}
+
+fn modifiers_from_param(param: u8) -> KeyModifiers {
+    let bits = param.saturating_sub(1);
+    let mut out = KeyModifiers::NONE;
+    for (bit, modifier) in [
+        (1, KeyModifiers::SHIFT),
+        (2, KeyModifiers::ALT),
+        (4, KeyModifiers::CONTROL),
+    ] {
+        if bits & bit != 0 {
+            out = out.union(modifier);
+        }
+    }
+    out
+}
+
+fn key(code: KeyCode, modifiers: KeyModifiers) -> TerminalEvent {
+    TerminalEvent::Key(KeyEvent { code, modifiers })
+}
+
+fn utf8_len(first: u8) -> usize {
+    match first {
+        0xc0..=0xdf => 2,
+        0xe0..=0xef => 3,
+        0xf0..=0xf7 => 4,
+        _ => 1,
+    }
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+/// Length of the longest suffix of `bytes` that starts `needle`.
+fn partial_suffix(bytes: &[u8], needle: &[u8]) -> usize {
+    (1..needle.len().min(bytes.len() + 1))
+        .rev()
+        .find(|&len| bytes.ends_with(&needle[..len]))
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+#[path = "tests/input_decoder.rs"]
+mod tests;
@@ -1,33 +1,69 @@
 use super::{
     KeyCode, KeyEvent, KeyModifiers, PointerButton, PointerEvent, PointerKind, PointerSemantic,
+    TerminalEvent, TerminalSize,
 };
 use crossterm::event::{
-    KeyCode as CrosstermKeyCode, KeyEvent as CrosstermKeyEvent, KeyEventKind,
-    KeyModifiers as CrosstermKeyModifiers, MouseButton as CrosstermMouseButton, MouseEvent,
-    MouseEventKind,
+    Event as CrosstermEvent, KeyCode as CrosstermKeyCode, KeyEvent as CrosstermKeyEvent,
+    KeyEventKind, KeyModifiers as CrosstermKeyModifiers, MouseButton as CrosstermMouseButton,
+    MouseEvent, MouseEventKind,
 };
 
-pub(super) fn map_key_event(key: CrosstermKeyEvent) -> Option<KeyEvent> {
+/// Translates a crossterm event into a steply one; `None` for input steply
+/// has no use for, such as key releases.
+pub fn map_event(event: CrosstermEvent) -> Option<TerminalEvent> {
+    match event {
+        CrosstermEvent::Key(key) => map_key_event(key).map(TerminalEvent::Key),
+        CrosstermEvent::Paste(text) => Some(TerminalEvent::Paste(text)),
+        CrosstermEvent::FocusGained => Some(TerminalEvent::FocusGained),
+        CrosstermEvent::FocusLost => Some(TerminalEvent::FocusLost),
+        CrosstermEvent::Resize(width, height) => {
+            Some(TerminalEvent::Resize(TerminalSize { width, height }))
+        }
+        CrosstermEvent::Mouse(mouse) => match mouse.kind {
+            MouseEventKind::ScrollLeft => Some(TerminalEvent::Scroll(-3)),
+            MouseEventKind::ScrollRight => Some(TerminalEvent::Scroll(3)),
+            _ => map_pointer_event(mouse).map(TerminalEvent::Pointer),
+        },
+    }
+}
+
+/// Translates a key press or repeat; releases map to `None`.
+pub fn map_key_event(key: CrosstermKeyEvent) -> Option<KeyEvent> {
     if !matches!(key.kind, KeyEventKind::Press | KeyEventKind::Repeat) {
         return None;
     }
-    let modifiers = map_key_modifiers(key.modifiers);
+    let raw_modifiers = if cfg!(windows) {
+        strip_alt_gr(key.code, key.modifiers)
+    } else {
+        key.modifiers
+    };
     Some(KeyEvent {
-        code: map_key_code(key.code, key.modifiers),
-        modifiers,
+        code: map_key_code(key.code, raw_modifiers),
+        modifiers: map_key_modifiers(raw_modifiers),
     })
 }
 
-pub(super) fn map_pointer_event(mouse: MouseEvent) -> Option<PointerEvent> {
+// The Windows console reports AltGr as Ctrl+Alt. A printable character with
+// both held is AltGr text (e.g. `@` on German layouts), not a shortcut.
+fn strip_alt_gr(code: CrosstermKeyCode, modifiers: CrosstermKeyModifiers) -> CrosstermKeyModifiers {
+    let alt_gr = CrosstermKeyModifiers::CONTROL | CrosstermKeyModifiers::ALT;
+    match code {
+        CrosstermKeyCode::Char(ch) if modifiers.contains(alt_gr) && !ch.is_control() => {
+            modifiers - alt_gr
+        }
+        _ => modifiers,
+    }
+}
+
+fn map_pointer_event(mouse: MouseEvent) -> Option<PointerEvent> {
     let kind = match mouse.kind {
         MouseEventKind::Moved => PointerKind::Move,
         MouseEventKind::Down(button) => PointerKind::Down(map_pointer_button(button)?),
         MouseEventKind::Up(button) => PointerKind::Up(map_pointer_button(button)?),
         MouseEventKind::Drag(button) => PointerKind::Drag(map_pointer_button(button)?),
-        MouseEventKind::ScrollUp
-        | MouseEventKind::ScrollDown
-        | MouseEventKind::ScrollLeft
-        | MouseEventKind::ScrollRight => return None,
+        MouseEventKind::ScrollUp => PointerKind::ScrollUp,
+        MouseEventKind::ScrollDown => PointerKind::ScrollDown,
+        MouseEventKind::ScrollLeft | MouseEventKind::ScrollRight => return None,
     };
 
     Some(PointerEvent {
@@ -2,31 +2,89 @@ use super::*;
 
 impl Terminal {
     pub fn enter(&mut self) -> io::Result<()> {
+        if self.is_headless() {
+            return Ok(());
+        }
         self.refresh_size()?;
         match self.mode {
-            RenderMode::AltScreen => self.enter_altscreen(),
-            RenderMode::Inline => self.enter_inline(),
+            RenderMode::AltScreen => self.enter_altscreen()?,
+            RenderMode::Inline => self.enter_inline()?,
+        }
+        let modes = restore::ActiveModes {
+            alt_screen: self.mode == RenderMode::AltScreen,
+            keyboard_enhancements: self.keyboard_enhancements_active,
+        };
+        if self.backend.is_process_tty() {
+            restore::register_session(modes);
+        }
+        self.active_modes = Some(modes);
+        Ok(())
+    }
+
+    fn end_session(&mut self) {
+        if self.active_modes.take().is_some() && self.backend.is_process_tty() {
+            restore::clear_session();
         }
     }
 
     pub fn exit(&mut self) -> io::Result<()> {
+        if self.is_headless() {
+            return Ok(());
+        }
+        self.restore_window_status()?;
         self.refresh_size()?;
         match self.mode {
-            RenderMode::AltScreen => self.exit_altscreen(),
-            RenderMode::Inline => self.exit_inline(),
+            RenderMode::AltScreen => self.exit_altscreen()?,
+            RenderMode::Inline => self.exit_inline()?,
+        }
+        self.end_session();
+        Ok(())
+    }
+
+    /// Restores the shell's terminal modes before the process is stopped.
+    pub fn suspend(&mut self) -> io::Result<()> {
+        if self.is_headless() {
+            return Ok(());
+        }
+        self.restore_window_status()?;
+        match self.mode {
+            RenderMode::AltScreen => self.leave_altscreen()?,
+            RenderMode::Inline => self.exit_inline()?,
+        }
+        self.end_session();
+        Ok(())
+    }
+
+    /// Re-enters raw mode after a suspend and forgets what was on screen, so
+    /// the next frame is drawn from scratch over whatever the shell printed.
+    pub fn resume(&mut self) -> io::Result<()> {
+        if self.is_headless() {
+            return Ok(());
+        }
+        if let Some(alt) = self.alt_screen.as_mut() {
+            *alt = AltScreenState {
+                scroll_offset: alt.scroll_offset,
+                manually_scrolled: alt.manually_scrolled,
+                ..AltScreenState::new()
+            };
+        }
+        if let Some(inline) = self.inline_state.as_mut() {
+            *inline = InlineState::new();
         }
+        self.enter()
     }
 
     fn enter_altscreen(&mut self) -> io::Result<()> {
-        terminal::enable_raw_mode()?;
-        execute!(self.stdout, EnterAlternateScreen, EnableMouseCapture, Hide)?;
+        self.backend.enable_raw_mode()?;
+        execute!(self.backend, EnterAlternateScreen, EnableMouseCapture, Hide)?;
+        self.enable_input_reporting();
         self.keyboard_enhancements_active = false;
         if keyboard_enhancements_enabled() {
             self.keyboard_enhancements_active =
                 self.try_push_keyboard_enhancements().inspect_err(|_| {
-                    let _ = terminal::disable_raw_mode();
+                    let _ = self.backend.disable_raw_mode();
                     let _ = execute!(
-                        self.stdout,
+                        self.backend,
                         DisableMouseCapture,
                         LeaveAlternateScreen,
                         EnableLineWrap,
@@ -38,7 +96,7 @@ impl Terminal {
     }
 
     fn enter_inline(&mut self) -> io::Result<()> {
-        let (_, row) = position()?;
+        let (_, row) = self.backend.cursor_position()?;
         let inline = self
             .inline_state
             .as_mut()
@@ -47,39 +105,46 @@ impl Terminal {
         inline.last_rendered_block_start_row = inline.block_start_row;
         inline.last_cursor_row = 0;
         inline.last_skip = 0;
-        terminal::enable_raw_mode()?;
-        execute!(self.stdout, DisableLineWrap, Hide)?;
+        self.backend.enable_raw_mode()?;
+        execute!(self.backend, DisableLineWrap, Hide)?;
+        self.enable_input_reporting();
         self.keyboard_enhancements_active = false;
         if keyboard_enhancements_enabled() {
             self.keyboard_enhancements_active =
                 self.try_push_keyboard_enhancements().inspect_err(|_| {
-                    let _ = terminal::disable_raw_mode();
-                    let _ = execute!(self.stdout, EnableLineWrap, Show);
+                    let _ = self.backend.disable_raw_mode();
+                    let _ = execute!(self.backend, EnableLineWrap, Show);
                 })?;
         }
         Ok(())
     }
 
     fn exit_altscreen(&mut self) -> io::Result<()> {
-        terminal::disable_raw_mode()?;
+        self.leave_altscreen()?;
+
+        if let Some(alt) = &self.alt_screen {
+            let last_frame = alt.last_frame.clone();
+            let width = self.state.size.width;
+            self.print_frame_to_stdout(&last_frame, width)?;
+        }
+        self.backend.flush()?;
+        Ok(())
+    }
+
+    fn leave_altscreen(&mut self) -> io::Result<()> {
+        self.backend.disable_raw_mode()?;
+        self.disable_input_reporting();
         if self.keyboard_enhancements_active {
             self.try_pop_keyboard_enhancements()?;
             self.keyboard_enhancements_active = false;
         }
         execute!(
-            self.stdout,
+            self.backend,
             DisableMouseCapture,
             LeaveAlternateScreen,
             EnableLineWrap,
             Show
         )?;
-
-        if let Some(alt) = &self.alt_screen {
-            let last_frame = alt.last_frame.clone();
-            let width = self.state.size.width;
-            self.print_frame_to_stdout(&last_frame, width)?;
-        }
-        self.stdout.flush()?;
         Ok(())
     }
 
@@ -98,21 +163,35 @@ impl Terminal {
                 .min(max_row)
         };
 
-        queue!(self.stdout, MoveTo(0, last_row))?;
+        queue!(self.backend, MoveTo(0, last_row))?;
+        self.disable_input_reporting();
         if self.keyboard_enhancements_active {
             self.try_pop_keyboard_enhancements()?;
             self.keyboard_enhancements_active = false;
         }
-        execute!(self.stdout, EnableLineWrap, Show)?;
-        terminal::disable_raw_mode()?;
-        self.stdout.write_all(b"\r\n")?;
-        self.stdout.flush()?;
+        execute!(self.backend, EnableLineWrap, Show)?;
+        self.backend.disable_raw_mode()?;
+        self.backend.write_all(b"\r\n")?;
+        self.backend.flush()?;
         Ok(())
     }
 
+    // Bracketed paste and focus reporting are best effort: legacy Windows
+    // consoles reject the former, and terminals without the latter simply
+    // never send focus events. Pasted text then arrives as key presses.
+    fn enable_input_reporting(&mut self) {
+        let _ = execute!(self.backend, EnableBracketedPaste);
+        let _ = execute!(self.backend, EnableFocusChange);
+    }
+
+    fn disable_input_reporting(&mut self) {
+        let _ = execute!(self.backend, DisableFocusChange);
+        let _ = execute!(self.backend, DisableBracketedPaste);
+    }
+
     fn try_push_keyboard_enhancements(&mut self) -> io::Result<bool> {
         match execute!(
-            self.stdout,
+            self.backend,
             PushKeyboardEnhancementFlags(keyboard_enhancement_flags())
         ) {
             Ok(()) => Ok(true),
@@ -122,7 +201,7 @@ impl Terminal {
     }
 
     fn try_pop_keyboard_enhancements(&mut self) -> io::Result<()> {
-        match execute!(self.stdout, PopKeyboardEnhancementFlags) {
+        match execute!(self.backend, PopKeyboardEnhancementFlags) {
             Ok(()) => Ok(()),
             Err(err) if is_legacy_windows_keyboard_enhancement_error(&err) => Ok(()),
             Err(err) => Err(err),
@@ -1,40 +1,49 @@
-use crossterm::cursor::{Hide, MoveTo, Show, position};
+use crossterm::cursor::{Hide, MoveTo, Show};
 use crossterm::event::{
-    self, DisableMouseCapture, EnableMouseCapture, Event as CrosstermEvent,
-    KeyboardEnhancementFlags, MouseEventKind, PopKeyboardEnhancementFlags,
+    DisableBracketedPaste, DisableFocusChange, DisableMouseCapture, EnableBracketedPaste,
+    EnableFocusChange, EnableMouseCapture, KeyboardEnhancementFlags, PopKeyboardEnhancementFlags,
     PushKeyboardEnhancementFlags,
 };
 use crossterm::style::{
     Attribute, Color as CrosstermColor, Print, ResetColor, SetAttribute, SetBackgroundColor,
-    SetForegroundColor,
+    SetForegroundColor, SetUnderlineColor,
 };
 use crossterm::terminal::{
-    self, BeginSynchronizedUpdate, Clear, ClearType, DisableLineWrap, EnableLineWrap,
+    BeginSynchronizedUpdate, Clear, ClearType, DisableLineWrap, EnableLineWrap,
     EndSynchronizedUpdate, EnterAlternateScreen, LeaveAlternateScreen, ScrollUp,
 };
 use crossterm::{execute, queue};
-use std::io::{self, Stderr, Stdout, Write};
+use std::io::{self, Write};
 use std::time::Duration;
 use steply_core::terminal::{
-    CursorPos, KeyCode, KeyEvent, KeyModifiers, PointerButton, PointerEvent, PointerKind,
-    PointerSemantic, TerminalEvent, TerminalSize, TerminalState,
+    ColorDepth, CursorPos, KeyCode, KeyEvent, KeyModifiers, PointerButton, PointerEvent,
+    PointerKind, PointerSemantic, TerminalCapabilities, TerminalEvent, TerminalSize, TerminalState,
 };
 use steply_core::ui::renderer::RenderFrame;
 use steply_core::ui::span::SpanLine;
-use steply_core::ui::style::{Color, Strike};
+use steply_core::ui::style::{Color, Contrast, Strike, Style, Underline};
 use steply_core::ui::text::{clip_to_display_width_without_linebreaks, text_display_width};
 
+mod backend;
+mod background;
 mod frame_diff;
+mod input_decoder;
 mod input_mapping;
 mod lifecycle;
 mod rendering;
 mod resize;
+mod restore;
+mod window;
 mod writer;
 
 use frame_diff::{
     DirtyRows, compute_dirty_rows, estimate_self_reflow_cursor_delta, quick_frame_signature,
 };
-use input_mapping::{map_key_event, map_pointer_event};
+
+use backend::CountingBackend;
+pub use backend::{Backend, ChannelBackend, ChannelInput, CrosstermBackend, MemoryBackend};
+pub use input_mapping::{map_event, map_key_event};
+pub use window::TaskbarProgress;
 
 fn keyboard_enhancement_flags() -> KeyboardEnhancementFlags {
     KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES
@@ -154,51 +163,74 @@ impl InlineState {
     }
 }
 
-enum TerminalWriter {
-    Stdout(Stdout),
-    Stderr(Stderr),
-}
-
-impl Write for TerminalWriter {
-    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
-        match self {
-            Self::Stdout(writer) => writer.write(buf),
-            Self::Stderr(writer) => writer.write(buf),
-        }
-    }
-
-    fn flush(&mut self) -> io::Result<()> {
-        match self {
-            Self::Stdout(writer) => writer.flush(),
-            Self::Stderr(writer) => writer.flush(),
-        }
-    }
-}
-
 pub struct Terminal {
-    stdout: TerminalWriter,
+    backend: CountingBackend,
     state: TerminalState,
     mode: RenderMode,
     keyboard_enhancements_active: bool,
     alt_screen: Option<AltScreenState>,
     inline_state: Option<InlineState>,
+    deferred_event: Option<TerminalEvent>,
+    capabilities: TerminalCapabilities,
+    window: window::WindowStatus,
+    /// Modes to undo; `Some` between `enter` and `exit` or `suspend`.
+    active_modes: Option<restore::ActiveModes>,
 }
 
 impl Terminal {
     pub fn new() -> io::Result<Self> {
-        Self::with_writer(TerminalWriter::Stdout(io::stdout()))
+        Self::with_backend(CrosstermBackend::stdout())
     }
 
     pub fn new_stderr() -> io::Result<Self> {
-        Self::with_writer(TerminalWriter::Stderr(io::stderr()))
+        Self::with_backend(CrosstermBackend::stderr())
+    }
+
+    pub fn with_backend(backend: impl Backend + 'static) -> io::Result<Self> {
+        let size = backend.size()?;
+        Ok(Self::with_size(Box::new(backend), size))
+    }
+
+    /// An in-memory terminal of a fixed size: nothing is written to the tty,
+    /// raw mode is never touched and input comes from [`Terminal::push_event`].
+    /// Polling after the scripted input runs out fails with `UnexpectedEof`.
+    pub fn headless(size: TerminalSize) -> Self {
+        Self::with_size(Box::new(MemoryBackend::new(size)), size)
+    }
+
+    pub fn is_headless(&self) -> bool {
+        self.backend.is_headless()
+    }
+
+    /// Bytes written to the backend so far, escape sequences included.
+    pub fn bytes_written(&self) -> u64 {
+        self.backend.bytes_written()
+    }
+
+    /// Sets the clipboard of whatever terminal this draws on.
+    pub fn copy_via_osc52(&mut self, text: &str) -> io::Result<()> {
+        self.backend
+            .write_all(crate::clipboard::osc52_sequence(text).as_bytes())?;
+        self.backend.flush()
+    }
+
+    /// Whether this terminal is the process's own tty, which signals, job
+    /// control and the system clipboard belong to.
+    pub fn is_process_tty(&self) -> bool {
+        self.backend.is_process_tty()
+    }
+
+    /// Queues an event for a headless terminal's next `poll_event`.
+    pub fn push_event(&mut self, event: TerminalEvent) {
+        self.backend.push_event(event);
     }
 
-    fn with_writer(stdout: TerminalWriter) -> io::Result<Self> {
-        let (width, height) = terminal::size()?;
-        Ok(Self {
-            stdout,
+    fn with_size(backend: Box<dyn Backend>, size: TerminalSize) -> Self {
+        let capabilities = backend.capabilities();
+        Self {
+            backend: CountingBackend::new(backend),
             state: TerminalState {
-                size: TerminalSize { width, height },
+                size,
                 cursor: None,
                 cursor_visible: false,
             },
@@ -206,7 +238,39 @@ impl Terminal {
             keyboard_enhancements_active: false,
             alt_screen: Some(AltScreenState::new()),
             inline_state: None,
-        })
+            deferred_event: None,
+            capabilities,
+            window: window::WindowStatus::default(),
+            active_modes: None,
+        }
+    }
+
+    /// Draws on stderr instead of stdout, leaving stdout free for output a
+    /// script consumes. Headless terminals are left as they are.
+    pub fn with_stderr_output(mut self) -> Self {
+        if !self.is_headless() {
+            self.backend = CountingBackend::new(Box::new(CrosstermBackend::stderr()));
+        }
+        self
+    }
+
+    /// Overrides the detected capabilities, e.g. to force ASCII glyphs.
+    pub fn with_capabilities(mut self, capabilities: TerminalCapabilities) -> Self {
+        self.capabilities = capabilities;
+        self
+    }
+
+    pub fn capabilities(&self) -> TerminalCapabilities {
+        self.capabilities
+    }
+
+    /// Asks the terminal for its background color. Only meaningful once
+    /// [`Self::enter`] has switched to raw mode.
+    pub fn background_color(&mut self) -> Option<(u8, u8, u8)> {
+        if self.is_headless() {
+            return None;
+        }
+        self.backend.background_color().ok().flatten()
     }
 
     pub fn with_mode(mut self, mode: RenderMode) -> Self {
@@ -292,29 +356,37 @@ impl Terminal {
         }
     }
 
+    /// Waits up to `timeout` for input. A burst of resize events (e.g. while
+    /// the window is dragged) is collapsed into one carrying the final size.
     pub fn poll_event(&mut self, timeout: Duration) -> io::Result<TerminalEvent> {
-        if event::poll(timeout)? {
-            match event::read()? {
-                CrosstermEvent::Key(key) => Ok(map_key_event(key)
-                    .map(TerminalEvent::Key)
-                    .unwrap_or(TerminalEvent::Tick)),
-                CrosstermEvent::Resize(width, height) => {
-                    Ok(TerminalEvent::Resize(TerminalSize { width, height }))
+        if let Some(event) = self.deferred_event.take() {
+            return Ok(event);
+        }
+        let event = self.read_event(timeout)?;
+        let TerminalEvent::Resize(mut size) = event else {
+            return Ok(event);
+        };
+        while let Some(next) = self.read_ready_event()? {
+            match next {
+                TerminalEvent::Resize(next_size) => size = next_size,
+                other => {
+                    self.deferred_event = Some(other);
+                    break;
                 }
-                CrosstermEvent::Mouse(mouse) => match mouse.kind {
-                    MouseEventKind::ScrollUp => Ok(TerminalEvent::Scroll(-3)),
-                    MouseEventKind::ScrollDown => Ok(TerminalEvent::Scroll(3)),
-                    MouseEventKind::ScrollLeft => Ok(TerminalEvent::Scroll(-3)),
-                    MouseEventKind::ScrollRight => Ok(TerminalEvent::Scroll(3)),
-                    _ => Ok(map_pointer_event(mouse)
-                        .map(TerminalEvent::Pointer)
-                        .unwrap_or(TerminalEvent::Tick)),
-                },
-                _ => Ok(TerminalEvent::Tick),
             }
-        } else {
-            Ok(TerminalEvent::Tick)
         }
+        Ok(TerminalEvent::Resize(size))
+    }
+
+    fn read_ready_event(&mut self) -> io::Result<Option<TerminalEvent>> {
+        self.backend.poll_event(Duration::ZERO)
+    }
+
+    fn read_event(&mut self, timeout: Duration) -> io::Result<TerminalEvent> {
+        Ok(self
+            .backend
+            .poll_event(timeout)?
+            .unwrap_or(TerminalEvent::Tick))
     }
 
     pub fn size(&self) -> TerminalSize {
@@ -331,14 +403,19 @@ impl Terminal {
         self.handle_inline_size_change(old, size);
     }
 
-    pub fn refresh_size(&mut self) -> io::Result<()> {
+    /// Re-reads the tty size. Returns `true` when it changed since the last
+    /// resize event or refresh.
+    pub fn refresh_size(&mut self) -> io::Result<bool> {
+        if self.is_headless() {
+            return Ok(false);
+        }
         let old = self.state.size;
-        let (width, height) = terminal::size()?;
-        let new = TerminalSize { width, height };
+        let new = self.backend.size()?;
         self.state.size = new;
-        if old.width != width || old.height != height {
-            self.handle_inline_size_change(old, new);
+        if old == new {
+            return Ok(false);
         }
-        Ok(())
+        self.handle_inline_size_change(old, new);
+        Ok(true)
     }
 }
@@ -209,7 +209,7 @@ impl Terminal {
             loop {
                 let target_row = target_row_offset.saturating_add(row);
                 queue!(
-                    self.stdout,
+                    self.backend,
                     MoveTo(0, target_row),
                     Clear(ClearType::CurrentLine)
                 )?;
@@ -239,7 +239,7 @@ impl Terminal {
     ) -> io::Result<()> {
         for (idx, line) in lines.iter().enumerate() {
             let row = start_row.saturating_add(idx.min(u16::MAX as usize) as u16);
-            queue!(self.stdout, MoveTo(0, row), Clear(ClearType::CurrentLine))?;
+            queue!(self.backend, MoveTo(0, row), Clear(ClearType::CurrentLine))?;
             self.write_span_line(line, width)?;
         }
         Ok(())
@@ -248,7 +248,7 @@ impl Terminal {
     fn clear_rows(&mut self, start_row: u16, count: usize) -> io::Result<()> {
         for offset in 0..count {
             let row = start_row.saturating_add(offset.min(u16::MAX as usize) as u16);
-            queue!(self.stdout, MoveTo(0, row), Clear(ClearType::CurrentLine))?;
+            queue!(self.backend, MoveTo(0, row), Clear(ClearType::CurrentLine))?;
         }
         Ok(())
     }
@@ -261,18 +261,18 @@ impl Terminal {
     ) -> io::Result<()> {
         match position {
             Some((col, row)) => {
-                queue!(self.stdout, MoveTo(col, row))?;
+                queue!(self.backend, MoveTo(col, row))?;
                 if cursor_visible {
-                    queue!(self.stdout, Show)?;
+                    queue!(self.backend, Show)?;
                 } else {
-                    queue!(self.stdout, Hide)?;
+                    queue!(self.backend, Hide)?;
                 }
             }
             None => {
                 if let Some((col, row)) = hidden_anchor {
-                    queue!(self.stdout, MoveTo(col, row))?;
+                    queue!(self.backend, MoveTo(col, row))?;
                 }
-                queue!(self.stdout, Hide)?;
+                queue!(self.backend, Hide)?;
             }
         }
         Ok(())
@@ -368,6 +368,9 @@ impl Terminal {
         self.refresh_size()?;
         self.state.cursor = frame.cursor;
         self.state.cursor_visible = frame.cursor_visible;
+        if self.is_headless() {
+            return Ok(());
+        }
         match self.mode {
             RenderMode::AltScreen => self.render_altscreen(frame),
             RenderMode::Inline => self.render_inline(frame),
@@ -476,7 +479,8 @@ impl Terminal {
             .map(|alt| (alt.last_sticky_top_count, alt.last_sticky_bottom_count))
             .unwrap_or((0, 0));
 
-        queue!(self.stdout, BeginSynchronizedUpdate, Hide)?;
+        self.begin_synchronized_update()?;
+        queue!(self.backend, Hide)?;
 
         self.clear_removed_sticky_rows(
             height,
@@ -518,7 +522,7 @@ impl Terminal {
             None
         };
         self.queue_cursor_state(cursor_position, frame.cursor_visible, hidden_anchor)?;
-        queue!(self.stdout, EndSynchronizedUpdate)?;
+        self.end_synchronized_update()?;
 
         if let Some(alt) = self.alt_screen.as_mut() {
             alt.last_frame.clone_from(&frame.lines);
@@ -533,7 +537,7 @@ impl Terminal {
             alt.has_rendered_once = true;
         }
 
-        self.stdout.flush()
+        self.backend.flush()
     }
 
     fn render_inline(&mut self, frame: &RenderFrame) -> io::Result<()> {
@@ -653,7 +657,8 @@ impl Terminal {
             (DirtyRows::default(), false)
         };
 
-        queue!(self.stdout, BeginSynchronizedUpdate, Hide)?;
+        self.begin_synchronized_update()?;
+        queue!(self.backend, Hide)?;
         self.clear_removed_sticky_rows(
             height,
             prev_sticky_top_count,
@@ -665,19 +670,19 @@ impl Terminal {
         if !can_diff_render || size_changed {
             if scroll_up_lines > 0 {
                 queue!(
-                    self.stdout,
+                    self.backend,
                     MoveTo(0, self.state.size.height.saturating_sub(1)),
                     ScrollUp(scroll_up_lines)
                 )?;
             }
             queue!(
-                self.stdout,
+                self.backend,
                 MoveTo(0, clear_start_row),
                 Clear(ClearType::FromCursorDown)
             )?;
             for visible_row in 0..draw_count {
                 let target_row = block_start.saturating_add(visible_row) as u16;
-                queue!(self.stdout, MoveTo(0, target_row))?;
+                queue!(self.backend, MoveTo(0, target_row))?;
                 if let Some(line) = frame.lines.get(skip + visible_row) {
                     self.write_span_line(line, width)?;
                 }
@@ -724,8 +729,24 @@ impl Terminal {
             inline.has_rendered_once = true;
         }
 
-        queue!(self.stdout, EndSynchronizedUpdate)?;
+        self.end_synchronized_update()?;
+
+        self.backend.flush()
+    }
+
+    // Terminals without mode 2026 would show a half-drawn frame only if the
+    // write were slow; skipping the sequences keeps their output clean.
+    fn begin_synchronized_update(&mut self) -> io::Result<()> {
+        if self.capabilities.synchronized_output {
+            queue!(self.backend, BeginSynchronizedUpdate)?;
+        }
+        Ok(())
+    }
 
-        self.stdout.flush()
+    fn end_synchronized_update(&mut self) -> io::Result<()> {
+        if self.capabilities.synchronized_output {
+            queue!(self.backend, EndSynchronizedUpdate)?;
+        }
+        Ok(())
     }
 }
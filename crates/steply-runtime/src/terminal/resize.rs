@@ -70,7 +70,7 @@ impl Terminal {
         let expected_cursor_row = last_rendered_block_start_row
             .saturating_add(last_cursor_row)
             .min(max_row);
-        let maybe_actual_row = match position() {
+        let maybe_actual_row = match self.backend.cursor_position() {
             Ok((_, row)) => Some(row.min(max_row)),
             Err(_) => None,
         };
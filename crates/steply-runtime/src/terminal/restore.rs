@@ -0,0 +1,110 @@
+use super::*;
+use std::io::IsTerminal;
+use std::sync::{Mutex, Once};
+use std::thread::{self, ThreadId};
+
+/// The tty session the panic hook has to put back, if one is running.
+static ACTIVE_SESSION: Mutex<Option<ActiveSession>> = Mutex::new(None);
+static INSTALL_PANIC_HOOK: Once = Once::new();
+
+#[derive(Debug, Clone, Copy)]
+struct ActiveSession {
+    thread: ThreadId,
+    modes: ActiveModes,
+}
+
+/// Terminal modes switched on by `Terminal::enter` that must be undone.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct ActiveModes {
+    pub(super) alt_screen: bool,
+    pub(super) keyboard_enhancements: bool,
+}
+
+/// Remembers a session on this process's tty and, the first time, chains a
+/// panic hook that restores it before the panic message is printed.
+pub(super) fn register_session(modes: ActiveModes) {
+    INSTALL_PANIC_HOOK.call_once(install_panic_hook);
+    if let Ok(mut session) = ACTIVE_SESSION.lock() {
+        *session = Some(ActiveSession {
+            thread: thread::current().id(),
+            modes,
+        });
+    }
+}
+
+pub(super) fn clear_session() {
+    if let Ok(mut session) = ACTIVE_SESSION.lock() {
+        *session = None;
+    }
+}
+
+fn install_panic_hook() {
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        restore_after_panic();
+        previous(info);
+    }));
+}
+
+// Only a panic on the thread drawing the UI ends the session. Task threads
+// run under `catch_unwind` and the flow carries on after they fail, so
+// restoring the shell there would leave a live UI in cooked mode.
+fn restore_after_panic() {
+    let Ok(mut session) = ACTIVE_SESSION.try_lock() else {
+        return;
+    };
+    let current = thread::current().id();
+    let Some(session) = session.take_if(|session| session.thread == current) else {
+        return;
+    };
+    let _ = crossterm::terminal::disable_raw_mode();
+    let sequence = restore_sequence(session.modes);
+    let _ = if io::stdout().is_terminal() {
+        write_and_flush(&mut io::stdout(), &sequence)
+    } else {
+        write_and_flush(&mut io::stderr(), &sequence)
+    };
+}
+
+fn write_and_flush(out: &mut impl Write, bytes: &[u8]) -> io::Result<()> {
+    out.write_all(bytes)?;
+    out.flush()
+}
+
+/// Escape sequences undoing `modes`. Each command is queued on its own so
+/// one the platform rejects does not keep the rest from being written.
+pub(super) fn restore_sequence(modes: ActiveModes) -> Vec<u8> {
+    let mut out = Vec::new();
+    let _ = queue!(out, EndSynchronizedUpdate);
+    if modes.keyboard_enhancements {
+        let _ = queue!(out, PopKeyboardEnhancementFlags);
+    }
+    let _ = queue!(out, DisableFocusChange);
+    let _ = queue!(out, DisableBracketedPaste);
+    if modes.alt_screen {
+        let _ = queue!(out, DisableMouseCapture);
+        let _ = queue!(out, LeaveAlternateScreen);
+    }
+    let _ = queue!(out, EnableLineWrap, Show);
+    if !modes.alt_screen {
+        // Leave the shell prompt below the partly drawn inline frame.
+        out.extend_from_slice(b"\r\n");
+    }
+    out
+}
+
+impl Drop for Terminal {
+    /// Restores the tty when a terminal that was entered is dropped without
+    /// `exit`, e.g. while unwinding from a panic or on an early `?` return.
+    fn drop(&mut self) {
+        let Some(modes) = self.active_modes.take() else {
+            return;
+        };
+        let _ = self.restore_window_status();
+        let _ = self.backend.disable_raw_mode();
+        let _ = write_and_flush(&mut self.backend, &restore_sequence(modes));
+        if self.backend.is_process_tty() {
+            clear_session();
+        }
+    }
+}
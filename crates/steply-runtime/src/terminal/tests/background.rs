@@ -0,0 +1,26 @@
+use super::{has_device_attributes, parse_background_reply};
+
+#[test]
+fn parses_osc_11_replies_ended_by_st_or_bel() {
+    assert_eq!(
+        parse_background_reply("\x1b]11;rgb:fdfd/f6f6/e3e3\x1b\\\x1b[?62;22c"),
+        Some((0xfd, 0xf6, 0xe3))
+    );
+    assert_eq!(
+        parse_background_reply("\x1b]11;rgb:28/2a/36\x07"),
+        Some((0x28, 0x2a, 0x36))
+    );
+    assert_eq!(
+        parse_background_reply("\x1b]11;rgb:f/0/8\x07"),
+        Some((255, 0, 136))
+    );
+}
+
+#[test]
+fn terminals_that_only_answer_device_attributes_give_nothing() {
+    let reply = b"\x1b[?1;2c";
+    assert!(has_device_attributes(reply));
+    assert_eq!(parse_background_reply("\x1b[?1;2c"), None);
+    assert_eq!(parse_background_reply("\x1b]11;rgb:zz/00/00\x07"), None);
+    assert!(!has_device_attributes(b"\x1b]11;rgb:00/00/00\x07"));
+}
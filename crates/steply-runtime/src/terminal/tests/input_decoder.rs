@@ -0,0 +1,79 @@
+use super::InputDecoder;
+use crate::terminal::{KeyCode, KeyEvent, KeyModifiers, PointerButton, PointerKind, TerminalEvent};
+
+fn decode(chunks: &[&[u8]]) -> Vec<TerminalEvent> {
+    let mut decoder = InputDecoder::default();
+    let mut out = Vec::new();
+    for chunk in chunks {
+        decoder.feed(chunk, &mut out);
+    }
+    out
+}
+
+fn key(code: KeyCode, modifiers: KeyModifiers) -> TerminalEvent {
+    TerminalEvent::Key(KeyEvent { code, modifiers })
+}
+
+#[test]
+fn decodes_text_control_keys_and_cursor_sequences() {
+    let events = decode(&[b"a\xc3\xa9\r\x7f\x03\x1b[A\x1bOD\x1b[1;5C\x1b[3~\x1b[Z"]);
+    assert_eq!(
+        events,
+        vec![
+            key(KeyCode::Char('a'), KeyModifiers::NONE),
+            key(KeyCode::Char('é'), KeyModifiers::NONE),
+            key(KeyCode::Enter, KeyModifiers::NONE),
+            key(KeyCode::Backspace, KeyModifiers::NONE),
+            key(KeyCode::Char('c'), KeyModifiers::CONTROL),
+            key(KeyCode::Up, KeyModifiers::NONE),
+            key(KeyCode::Left, KeyModifiers::NONE),
+            key(KeyCode::Right, KeyModifiers::CONTROL),
+            key(KeyCode::Delete, KeyModifiers::NONE),
+            key(KeyCode::BackTab, KeyModifiers::NONE),
+        ]
+    );
+}
+
+#[test]
+fn lone_escape_is_the_esc_key_and_escape_prefix_means_alt() {
+    assert_eq!(
+        decode(&[b"\x1b", b"\x1bx"]),
+        vec![
+            key(KeyCode::Esc, KeyModifiers::NONE),
+            key(KeyCode::Char('x'), KeyModifiers::ALT),
+        ]
+    );
+}
+
+#[test]
+fn sequences_and_pastes_split_across_reads_are_reassembled() {
+    let events = decode(&[b"\x1b[1;", b"2A\x1b[200~one\ntw", b"o\x1b[20", b"1~z"]);
+    assert_eq!(
+        events,
+        vec![
+            key(KeyCode::Up, KeyModifiers::SHIFT),
+            TerminalEvent::Paste("one\ntwo".to_string()),
+            key(KeyCode::Char('z'), KeyModifiers::NONE),
+        ]
+    );
+}
+
+#[test]
+fn decodes_sgr_mouse_reports_zero_based() {
+    let events = decode(&[b"\x1b[<0;5;3M\x1b[<0;5;3m\x1b[<65;1;1M"]);
+    let kinds = events
+        .iter()
+        .map(|event| match event {
+            TerminalEvent::Pointer(pointer) => (pointer.kind, pointer.col, pointer.row),
+            other => panic!("unexpected {other:?}"),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        kinds,
+        vec![
+            (PointerKind::Down(PointerButton::Left), 4, 2),
+            (PointerKind::Up(PointerButton::Left), 4, 2),
+            (PointerKind::ScrollDown, 0, 0),
+        ]
+    );
+}
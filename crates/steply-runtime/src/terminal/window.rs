@@ -0,0 +1,71 @@
+use super::*;
+use crossterm::terminal::SetTitle;
+
+/// What `OSC 9;4` shows in the taskbar or tab of terminals that support it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskbarProgress {
+    /// Percent done, clamped to `0..=100`.
+    Percent(u8),
+    /// Busy without a known end, e.g. while a step's tasks run.
+    Indeterminate,
+}
+
+#[derive(Default)]
+pub(super) struct WindowStatus {
+    title: Option<String>,
+    title_saved: bool,
+    progress: Option<TaskbarProgress>,
+}
+
+impl Terminal {
+    /// Sets the window title. The shell's title is saved on the first call
+    /// and put back when the terminal exits or suspends.
+    pub fn set_title(&mut self, title: &str) -> io::Result<()> {
+        if self.is_headless() || self.window.title.as_deref() == Some(title) {
+            return Ok(());
+        }
+        if !self.window.title_saved {
+            // XTWINOPS: push the icon and window title onto the title stack.
+            self.backend.write_all(b"\x1b[22;0t")?;
+            self.window.title_saved = true;
+        }
+        execute!(self.backend, SetTitle(title))?;
+        self.window.title = Some(title.to_string());
+        Ok(())
+    }
+
+    /// Reports progress to the taskbar or tab; `None` clears it. Does
+    /// nothing unless the terminal understands `OSC 9;4`.
+    pub fn set_progress(&mut self, progress: Option<TaskbarProgress>) -> io::Result<()> {
+        if self.is_headless()
+            || !self.capabilities.progress_reporting
+            || self.window.progress == progress
+        {
+            return Ok(());
+        }
+        let sequence = match progress {
+            None => "\x1b]9;4;0;0\x07".to_string(),
+            Some(TaskbarProgress::Percent(percent)) => {
+                format!("\x1b]9;4;1;{}\x07", percent.min(100))
+            }
+            Some(TaskbarProgress::Indeterminate) => "\x1b]9;4;3;0\x07".to_string(),
+        };
+        self.backend.write_all(sequence.as_bytes())?;
+        self.backend.flush()?;
+        self.window.progress = progress;
+        Ok(())
+    }
+
+    /// Clears progress and restores the saved title. The next `set_title`
+    /// or `set_progress` writes again, so a resumed session gets both back.
+    pub(super) fn restore_window_status(&mut self) -> io::Result<()> {
+        self.set_progress(None)?;
+        if self.window.title_saved {
+            // XTWINOPS: pop the title saved by `set_title`.
+            self.backend.write_all(b"\x1b[23;0t")?;
+            self.backend.flush()?;
+        }
+        self.window = WindowStatus::default();
+        Ok(())
+    }
+}
@@ -16,6 +16,7 @@ impl Terminal {
         } else {
             width
         };
+        let depth = self.capabilities.color_depth;
         let mut used = 0usize;
         for span in line {
             if used >= render_width as usize {
@@ -26,24 +27,71 @@ impl Terminal {
             if clipped.is_empty() {
                 continue;
             }
-            if let Some(color) = span.style.color {
-                queue!(self.stdout, SetForegroundColor(map_color(color)))?;
+            let emphasis = if depth == ColorDepth::Monochrome {
+                Emphasis::for_monochrome(&span.style)
+            } else {
+                if let Some(color) = span.style.color {
+                    queue!(
+                        self.backend,
+                        SetForegroundColor(map_color(color.for_depth(depth)))
+                    )?;
+                }
+                if let Some(background) = span.style.background {
+                    queue!(
+                        self.backend,
+                        SetBackgroundColor(map_color(background.for_depth(depth)))
+                    )?;
+                }
+                if span.style.underline != Underline::None
+                    && self.capabilities.styled_underlines
+                    && let Some(color) = span.style.underline_color
+                {
+                    queue!(
+                        self.backend,
+                        SetUnderlineColor(map_color(color.for_depth(depth)))
+                    )?;
+                }
+                Emphasis {
+                    bold: span.style.bold,
+                    dim: span.style.dim && !span.style.bold,
+                    reverse: span.style.reverse,
+                }
+            };
+            let underline =
+                underline_attribute(span.style.underline, self.capabilities.styled_underlines);
+            if emphasis.bold {
+                queue!(self.backend, SetAttribute(Attribute::Bold))?;
             }
-            if let Some(background) = span.style.background {
-                queue!(self.stdout, SetBackgroundColor(map_color(background)))?;
+            if emphasis.dim {
+                queue!(self.backend, SetAttribute(Attribute::Dim))?;
             }
-            if span.style.bold {
-                queue!(self.stdout, SetAttribute(Attribute::Bold))?;
+            if emphasis.reverse {
+                queue!(self.backend, SetAttribute(Attribute::Reverse))?;
+            }
+            if span.style.italic {
+                queue!(self.backend, SetAttribute(Attribute::Italic))?;
+            }
+            if let Some(underline) = underline {
+                queue!(self.backend, SetAttribute(underline))?;
             }
             if matches!(span.style.strike, Strike::On) {
-                queue!(self.stdout, SetAttribute(Attribute::CrossedOut))?;
+                queue!(self.backend, SetAttribute(Attribute::CrossedOut))?;
+            }
+            queue!(self.backend, Print(clipped.as_str()), ResetColor)?;
+            if emphasis.bold || emphasis.dim {
+                queue!(self.backend, SetAttribute(Attribute::NormalIntensity))?;
+            }
+            if emphasis.reverse {
+                queue!(self.backend, SetAttribute(Attribute::NoReverse))?;
             }
-            queue!(self.stdout, Print(clipped.as_str()), ResetColor)?;
-            if span.style.bold {
-                queue!(self.stdout, SetAttribute(Attribute::NormalIntensity))?;
+            if span.style.italic {
+                queue!(self.backend, SetAttribute(Attribute::NoItalic))?;
+            }
+            if underline.is_some() {
+                queue!(self.backend, SetAttribute(Attribute::NoUnderline))?;
             }
             if matches!(span.style.strike, Strike::On) {
-                queue!(self.stdout, SetAttribute(Attribute::NotCrossedOut))?;
+                queue!(self.backend, SetAttribute(Attribute::NotCrossedOut))?;
             }
             used = used.saturating_add(text_display_width(clipped.as_str()));
         }
@@ -57,12 +105,50 @@ impl Terminal {
     ) -> io::Result<()> {
         for line in lines {
             self.write_span_line_with_margin(line, width, false)?;
-            self.stdout.write_all(b"\r\n")?;
+            self.backend.write_all(b"\r\n")?;
         }
         Ok(())
     }
 }
 
+/// Attributes written for a span. Without colors, contrast between focused,
+/// plain and dimmed text is carried by intensity, and backgrounds such as
+/// the selection by reverse video.
+#[derive(Default)]
+struct Emphasis {
+    bold: bool,
+    dim: bool,
+    reverse: bool,
+}
+
+impl Emphasis {
+    fn for_monochrome(style: &Style) -> Self {
+        let contrast = style.color.map_or(Contrast::Normal, Color::contrast);
+        let bold = style.bold || (contrast == Contrast::Strong && !style.dim);
+        Self {
+            bold,
+            dim: !bold && (style.dim || contrast == Contrast::Dim),
+            reverse: style.reverse
+                || style
+                    .background
+                    .is_some_and(|background| background != Color::Reset),
+        }
+    }
+}
+
+fn underline_attribute(underline: Underline, styled: bool) -> Option<Attribute> {
+    let attribute = match underline {
+        Underline::None => return None,
+        _ if !styled => Attribute::Underlined,
+        Underline::Straight => Attribute::Underlined,
+        Underline::Double => Attribute::DoubleUnderlined,
+        Underline::Curly => Attribute::Undercurled,
+        Underline::Dotted => Attribute::Underdotted,
+        Underline::Dashed => Attribute::Underdashed,
+    };
+    Some(attribute)
+}
+
 fn map_color(color: Color) -> CrosstermColor {
     match color {
         Color::Reset => CrosstermColor::Reset,
@@ -75,6 +161,7 @@ fn map_color(color: Color) -> CrosstermColor {
         Color::Magenta => CrosstermColor::DarkMagenta,
         Color::Cyan => CrosstermColor::DarkCyan,
         Color::White => CrosstermColor::White,
+        Color::Indexed(index) => CrosstermColor::AnsiValue(index),
         Color::Rgb(r, g, b) => CrosstermColor::Rgb { r, g, b },
     }
 }
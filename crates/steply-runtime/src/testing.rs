@@ -0,0 +1,204 @@
+use std::io;
+use std::time::{Duration, Instant};
+
+use steply_core::config::load_from_yaml_str;
+use steply_core::state::app::AppState;
+use steply_core::terminal::{KeyCode, KeyEvent, KeyModifiers, TerminalEvent, TerminalSize};
+use steply_core::ui::frame_text::draw_output_to_text;
+use steply_core::ui::renderer::RendererConfig;
+use steply_core::ui::span::SpanLine;
+use steply_core::widgets::node::Node;
+use steply_core::widgets::traits::RenderContext;
+
+pub use steply_core::ui::frame_text::SnapshotFormat;
+
+use crate::Runtime;
+use crate::terminal::Terminal;
+
+const DEFAULT_SIZE: TerminalSize = TerminalSize {
+    width: 80,
+    height: 24,
+};
+
+/// Drives a [`Runtime`] on a headless terminal for integration tests.
+///
+/// Input goes through the same key bindings, reducer and task executor as an
+/// interactive run; the last rendered frame is kept for assertions.
+pub struct TestDriver {
+    runtime: Runtime,
+}
+
+impl TestDriver {
+    pub fn new(state: AppState) -> io::Result<Self> {
+        Self::with_size(state, DEFAULT_SIZE)
+    }
+
+    pub fn with_size(state: AppState, size: TerminalSize) -> io::Result<Self> {
+        let mut runtime =
+            Runtime::new(state, Terminal::headless(size)).with_renderer_config(RendererConfig {
+                chrome_enabled: true,
+            });
+        runtime.start()?;
+        Ok(Self { runtime })
+    }
+
+    pub fn from_yaml(raw: &str) -> io::Result<Self> {
+        let state = load_from_yaml_str(raw)
+            .map_err(|err| io::Error::other(format!("yaml config error: {err}")))?
+            .into_app_state()
+            .map_err(|err| io::Error::other(format!("app init error: {err}")))?;
+        Self::new(state)
+    }
+
+    pub fn press(&mut self, key: KeyEvent) -> io::Result<&mut Self> {
+        self.send(TerminalEvent::Key(key))
+    }
+
+    pub fn press_code(&mut self, code: KeyCode) -> io::Result<&mut Self> {
+        self.press(KeyEvent {
+            code,
+            modifiers: KeyModifiers::NONE,
+        })
+    }
+
+    pub fn press_all(&mut self, keys: impl IntoIterator<Item = KeyEvent>) -> io::Result<&mut Self> {
+        for key in keys {
+            self.press(key)?;
+        }
+        Ok(self)
+    }
+
+    pub fn type_text(&mut self, text: &str) -> io::Result<&mut Self> {
+        for ch in text.chars() {
+            self.press_code(KeyCode::Char(ch))?;
+        }
+        Ok(self)
+    }
+
+    /// Delivers `text` the way a bracketed paste would, as one event.
+    pub fn paste(&mut self, text: &str) -> io::Result<&mut Self> {
+        self.send(TerminalEvent::Paste(text.to_string()))
+    }
+
+    pub fn resize(&mut self, size: TerminalSize) -> io::Result<&mut Self> {
+        self.send(TerminalEvent::Resize(size))
+    }
+
+    pub fn send(&mut self, event: TerminalEvent) -> io::Result<&mut Self> {
+        self.runtime.handle_terminal_event(event)?;
+        self.runtime.pump()?;
+        Ok(self)
+    }
+
+    /// Keeps delivering timers and task output for `duration`, e.g. to let
+    /// debounced triggers fire.
+    pub fn advance(&mut self, duration: Duration) -> io::Result<&mut Self> {
+        let deadline = Instant::now() + duration;
+        loop {
+            self.runtime.pump()?;
+            if Instant::now() >= deadline {
+                return Ok(self);
+            }
+            std::thread::sleep(Duration::from_millis(5));
+        }
+    }
+
+    /// Pumps until no task is running. Returns `false` on timeout.
+    pub fn wait_for_tasks(&mut self, timeout: Duration) -> io::Result<bool> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            self.runtime.pump()?;
+            if !self.runtime.state().has_running_tasks() {
+                return Ok(true);
+            }
+            if Instant::now() >= deadline {
+                return Ok(false);
+            }
+            std::thread::sleep(Duration::from_millis(5));
+        }
+    }
+
+    pub fn frame_lines(&self) -> &[SpanLine] {
+        self.runtime.last_frame_lines()
+    }
+
+    /// The last rendered frame as plain text, one entry per row.
+    pub fn frame(&self) -> Vec<String> {
+        self.frame_lines()
+            .iter()
+            .map(|line| line.iter().map(|span| span.text.as_str()).collect())
+            .collect()
+    }
+
+    pub fn frame_text(&self) -> String {
+        self.frame().join("\n")
+    }
+
+    pub fn state(&self) -> &AppState {
+        self.runtime.state()
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.runtime.state().should_exit()
+    }
+
+    pub fn into_state(self) -> AppState {
+        self.runtime.into_state()
+    }
+}
+
+/// Renders a single widget outside of any flow, for snapshot tests of custom
+/// widgets.
+///
+/// Rows are clipped to the width and joined with newlines. In
+/// [`SnapshotFormat::Text`] styling is dropped and trailing spaces trimmed;
+/// [`SnapshotFormat::Ansi`] keeps colors as SGR sequences, so styling changes
+/// show up in the snapshot too.
+pub struct WidgetSnapshot<'a> {
+    node: &'a Node,
+    size: TerminalSize,
+    focused_id: Option<String>,
+    format: SnapshotFormat,
+}
+
+impl<'a> WidgetSnapshot<'a> {
+    pub fn new(node: &'a Node) -> Self {
+        Self {
+            node,
+            size: DEFAULT_SIZE,
+            focused_id: None,
+            format: SnapshotFormat::Text,
+        }
+    }
+
+    pub fn with_size(mut self, size: TerminalSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Draws the widget as the focused one.
+    pub fn focused(self) -> Self {
+        let id = self.node.id().to_string();
+        self.with_focus(id)
+    }
+
+    /// Focuses `id`, e.g. a child of a component.
+    pub fn with_focus(mut self, id: impl Into<String>) -> Self {
+        self.focused_id = Some(id.into());
+        self
+    }
+
+    pub fn with_format(mut self, format: SnapshotFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    pub fn render(&self) -> String {
+        let ctx = RenderContext::empty(self.size).with_focus(self.focused_id.clone());
+        draw_output_to_text(&self.node.draw(&ctx), self.size, self.format)
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/testing.rs"]
+mod tests;
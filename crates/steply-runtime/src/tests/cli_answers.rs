@@ -0,0 +1,103 @@
+use super::{CliAnswers, field_args};
+use clap::{Arg, ArgAction, Command};
+use steply_core::config::load_from_yaml_str;
+use steply_core::state::app::AppState;
+
+const FLOW_YAML: &str = r#"
+version: 1
+steps:
+  - id: server
+    title: Server
+    widgets:
+      - type: text_input
+        id: name
+        label: Name
+        value: server.name
+      - type: text_input
+        id: listen_port
+        label: Port
+        value: server.port
+  - id: confirm
+    title: Confirm
+    widgets:
+      - type: text_input
+        id: owner
+        label: Owner
+        value: owner
+"#;
+
+fn state() -> AppState {
+    load_from_yaml_str(FLOW_YAML)
+        .expect("load config")
+        .into_app_state()
+        .expect("app state")
+}
+
+#[test]
+fn field_args_prefill_and_skip_the_answered_step() {
+    let mut state = state();
+    let matches = Command::new("app")
+        .args(field_args(&state))
+        .try_get_matches_from(["app", "--name", "api", "--listen-port", "8080"])
+        .expect("parse args");
+
+    CliAnswers::from_matches(&matches)
+        .skip_answered_steps(true)
+        .apply(&mut state)
+        .expect("apply answers");
+
+    assert_eq!(state.current_step_id(), "confirm");
+    let values = state.collected_values().to_json_string().expect("json");
+    assert!(values.contains(r#""name":"api""#), "{values}");
+    assert!(values.contains(r#""port":"8080""#), "{values}");
+}
+
+#[test]
+fn partially_answered_steps_are_not_skipped() {
+    let mut state = state();
+    let matches = Command::new("app")
+        .args(field_args(&state))
+        .try_get_matches_from(["app", "--name", "api"])
+        .expect("parse args");
+
+    CliAnswers::from_matches(&matches)
+        .skip_answered_steps(true)
+        .apply(&mut state)
+        .expect("apply answers");
+
+    assert_eq!(state.current_step_id(), "server");
+    assert!(state.prefilled_node_ids().contains("name"));
+}
+
+#[test]
+fn renamed_args_must_target_a_field_and_host_flags_are_ignored() {
+    let command = Command::new("app")
+        .arg(
+            Arg::new("verbose")
+                .long("verbose")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(Arg::new("user").long("user"));
+    let matches = command
+        .try_get_matches_from(["app", "--verbose", "--user", "ops"])
+        .expect("parse args");
+
+    let mut state = state();
+    CliAnswers::from_matches(&matches)
+        .rename("user", "owner")
+        .apply(&mut state)
+        .expect("apply answers");
+    assert!(
+        state
+            .collected_values()
+            .to_json_string()
+            .expect("json")
+            .contains(r#""owner":"ops""#)
+    );
+
+    let err = CliAnswers::from_matches(&matches)
+        .rename("user", "missing")
+        .apply(&mut state)
+        .expect_err("unknown field");
+    assert!(err.contains("missing"), "{err}");
+}
@@ -0,0 +1,103 @@
+use super::{FrameMetrics, FrameProfile};
+use crate::Runtime;
+use crate::terminal::{ChannelBackend, Terminal};
+use std::sync::{Arc, Mutex, mpsc};
+use std::time::Duration;
+use steply_core::config::load_from_yaml_str;
+use steply_core::terminal::{TerminalCapabilities, TerminalSize};
+use steply_core::ui::draw_stats;
+use steply_core::ui::render_view::RenderView;
+use steply_core::ui::renderer::{Renderer, RendererConfig};
+
+const TWO_INPUTS: &str = r#"
+version: 1
+steps:
+  - id: project
+    title: Project
+    widgets:
+      - type: text_input
+        id: name
+        label: Name
+      - type: text_input
+        id: owner
+        label: Owner
+"#;
+
+#[test]
+fn summary_names_the_slowest_widget_drawn() {
+    let state = load_from_yaml_str(TWO_INPUTS)
+        .expect("load config")
+        .into_app_state()
+        .expect("app state");
+    let mut renderer = Renderer::new(RendererConfig {
+        chrome_enabled: true,
+    });
+
+    draw_stats::begin_collecting();
+    renderer.render(
+        &RenderView::from_state(&state),
+        TerminalSize {
+            width: 80,
+            height: 24,
+        },
+    );
+    let draws = draw_stats::finish_collecting().expect("draw stats");
+    assert_eq!(draws.widgets_drawn, 2);
+    assert!(draw_stats::finish_collecting().is_none());
+
+    let mut metrics = FrameMetrics::default();
+    metrics.set_enabled(true);
+    metrics.record_frame(Duration::from_micros(2500), 3, draws, (0, 0));
+    let text: String = metrics
+        .summary_line()
+        .iter()
+        .map(|span| span.text.as_str())
+        .collect();
+    assert!(
+        text.starts_with("frame 2.5ms  queue 3  widgets 2"),
+        "{text}"
+    );
+    assert!(
+        text.contains("slowest name") || text.contains("slowest owner"),
+        "{text}"
+    );
+}
+
+#[test]
+fn profiler_receives_widget_draw_times_and_bytes_written() {
+    let state = load_from_yaml_str(TWO_INPUTS)
+        .expect("load config")
+        .into_app_state()
+        .expect("app state");
+    let size = TerminalSize {
+        width: 80,
+        height: 24,
+    };
+    let (_input, rx) = mpsc::channel();
+    let terminal = Terminal::with_backend(ChannelBackend::new(
+        std::io::sink(),
+        rx,
+        size,
+        TerminalCapabilities::default(),
+    ))
+    .expect("terminal");
+    let profiles = Arc::new(Mutex::new(Vec::<FrameProfile>::new()));
+    let sink = Arc::clone(&profiles);
+    let mut runtime = Runtime::new(state, terminal)
+        .with_profiler(move |profile| sink.lock().expect("profiles").push(profile.clone()));
+
+    runtime.start().expect("first frame");
+
+    let profiles = profiles.lock().expect("profiles");
+    let [profile] = profiles.as_slice() else {
+        panic!("one frame profiled: {profiles:?}");
+    };
+    let drawn = profile
+        .draws
+        .widgets
+        .iter()
+        .map(|(id, _)| id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(drawn, vec!["name", "owner"]);
+    assert!(profile.bytes_written > 0);
+}
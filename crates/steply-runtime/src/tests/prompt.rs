@@ -0,0 +1,53 @@
+use super::{ANSWER_ID, answer, prompt_state};
+use crate::testing::TestDriver;
+use steply_core::core::value::Value;
+use steply_core::terminal::{KeyCode, KeyEvent, KeyModifiers};
+use steply_core::widgets::inputs::select::SelectInput;
+use steply_core::widgets::inputs::text::TextInput;
+use steply_core::widgets::node::Node;
+
+#[test]
+fn submitted_text_is_the_answer() {
+    let node = Node::Input(Box::new(TextInput::new(ANSWER_ID, "Name?")));
+    let mut driver = TestDriver::new(prompt_state(node).expect("state")).expect("driver");
+
+    driver.type_text("Ada").expect("type");
+    driver.press_code(KeyCode::Enter).expect("submit");
+
+    assert!(driver.is_finished());
+    assert_eq!(
+        answer(&driver.into_state(), ANSWER_ID),
+        Some(Value::Text("Ada".to_string()))
+    );
+}
+
+#[test]
+fn selected_option_is_the_answer() {
+    let options = vec!["red".to_string(), "green".to_string()];
+    let node = Node::Input(Box::new(SelectInput::new(ANSWER_ID, "Colour?", options)));
+    let mut driver = TestDriver::new(prompt_state(node).expect("state")).expect("driver");
+
+    driver.press_code(KeyCode::Right).expect("next option");
+    driver.press_code(KeyCode::Enter).expect("submit");
+
+    assert_eq!(
+        answer(&driver.into_state(), ANSWER_ID),
+        Some(Value::Text("green".to_string()))
+    );
+}
+
+#[test]
+fn cancelled_prompt_has_no_answer() {
+    let node = Node::Input(Box::new(TextInput::new(ANSWER_ID, "Name?")));
+    let mut driver = TestDriver::new(prompt_state(node).expect("state")).expect("driver");
+
+    driver.type_text("Ada").expect("type");
+    driver
+        .press(KeyEvent {
+            code: KeyCode::Char('c'),
+            modifiers: KeyModifiers::CONTROL,
+        })
+        .expect("cancel");
+
+    assert_eq!(answer(&driver.into_state(), ANSWER_ID), None);
+}
@@ -0,0 +1,76 @@
+use super::{InputRecorder, InputReplay, RecordedEvent};
+use std::time::{Duration, Instant};
+use steply_core::terminal::{KeyCode, KeyEvent, KeyModifiers, TerminalEvent, TerminalSize};
+
+fn key(ch: char) -> TerminalEvent {
+    TerminalEvent::Key(KeyEvent {
+        code: KeyCode::Char(ch),
+        modifiers: KeyModifiers::NONE,
+    })
+}
+
+#[test]
+fn recorded_input_round_trips_without_ticks() {
+    let path = std::env::temp_dir().join(format!("steply-recording-{}.jsonl", std::process::id()));
+    let mut recorder = InputRecorder::create(&path).expect("create recording");
+    recorder.record(key('a')).expect("record");
+    recorder.record(TerminalEvent::Tick).expect("record");
+    recorder
+        .record(TerminalEvent::Resize(TerminalSize {
+            width: 60,
+            height: 20,
+        }))
+        .expect("record");
+    drop(recorder);
+
+    let mut replay = InputReplay::load(&path).expect("load recording");
+    let _ = std::fs::remove_file(&path);
+    assert_eq!(replay.remaining(), 2);
+
+    let mut replay_fast = replay.clone().with_speed(0.0);
+    let now = Instant::now();
+    assert_eq!(replay_fast.next_due(now), Some(key('a')));
+    assert_eq!(
+        replay_fast.next_due(now),
+        Some(TerminalEvent::Resize(TerminalSize {
+            width: 60,
+            height: 20,
+        }))
+    );
+    assert!(replay_fast.is_finished());
+
+    assert_eq!(replay.next_due(now), Some(key('a')));
+}
+
+#[test]
+fn replay_waits_for_scaled_timestamps() {
+    let mut replay = InputReplay::new([
+        RecordedEvent {
+            at_ms: 0,
+            event: key('a'),
+        },
+        RecordedEvent {
+            at_ms: 1000,
+            event: key('b'),
+        },
+    ])
+    .with_speed(4.0);
+    let start = Instant::now();
+
+    assert_eq!(replay.next_due(start), Some(key('a')));
+    assert_eq!(replay.next_due(start), None);
+    assert_eq!(
+        replay.time_until_next(start),
+        Some(Duration::from_millis(250))
+    );
+    assert_eq!(
+        replay.next_due(start + Duration::from_millis(250)),
+        Some(key('b'))
+    );
+}
+
+#[test]
+fn malformed_recording_reports_the_line() {
+    let err = InputReplay::parse("{\"at_ms\":0,\"event\":\"Tick\"}\nnot json\n").unwrap_err();
+    assert!(err.to_string().contains("line 2"), "{err}");
+}
@@ -0,0 +1,74 @@
+use super::{SessionRecovery, write_snapshot_to};
+use steply_core::config::load_from_yaml_str;
+use steply_core::core::value::Value;
+use steply_core::state::app::AppState;
+
+fn state_with_steps(first: &str) -> AppState {
+    let yaml = format!(
+        r#"
+version: 1
+steps:
+  - id: {first}
+    title: Project
+    widgets:
+      - type: text_input
+        id: name
+        label: Name
+        value: project.name
+      - type: text_input
+        id: token
+        label: Token
+        mode: password
+        default: hunter2
+        value: project.token
+"#
+    );
+    load_from_yaml_str(yaml.as_str())
+        .expect("load config")
+        .into_app_state()
+        .expect("app state")
+}
+
+#[test]
+fn private_snapshot_is_restored_only_into_the_same_flow() {
+    let path = std::env::temp_dir().join(format!("steply-recovery-{}.json", std::process::id()));
+    let mut state = state_with_steps("project");
+    state
+        .prefill_values(&Value::from_json(r#"{"project": {"name": "crashy"}}"#).unwrap())
+        .expect("prefill values");
+
+    let mut recovery = SessionRecovery::new(path.clone(), &state);
+    recovery.record(&state);
+    let recorded = recovery.recorded_revision;
+    recovery.record(&state);
+    assert_eq!(recovery.recorded_revision, recorded);
+    assert!(write_snapshot_to(&path).expect("write snapshot"));
+    let raw = std::fs::read_to_string(&path).expect("read snapshot");
+    assert!(!raw.contains("hunter2"));
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = std::fs::metadata(&path)
+            .expect("metadata")
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    let other = SessionRecovery::new(path.clone(), &state_with_steps("other"));
+    assert_eq!(other.take_saved(), None);
+    assert!(path.exists());
+
+    let restored = SessionRecovery::new(path.clone(), &state).take_saved();
+    assert!(!path.exists());
+    assert_eq!(restored, Some(state.exportable_values()));
+
+    state
+        .prefill_values(&Value::from_json(r#"{"project": {"name": "again"}}"#).unwrap())
+        .expect("prefill values");
+    recovery.record(&state);
+    assert_ne!(recovery.recorded_revision, recorded);
+
+    recovery.discard();
+    assert!(!write_snapshot_to(&path).expect("write snapshot"));
+}
@@ -0,0 +1,607 @@
+use super::{SnapshotFormat, TestDriver, WidgetSnapshot};
+use crate::terminal::{Backend, Terminal};
+use crate::{RunOutcome, Runtime};
+use std::collections::VecDeque;
+use std::io::{self, Write};
+use std::panic::AssertUnwindSafe;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use steply_core::config::{load_from_yaml_str, parse_values_document};
+use steply_core::core::action_bindings::{ActionBindings, KeymapPreset};
+use steply_core::core::value::Value;
+use steply_core::state::app::AppState;
+use steply_core::state::flow::Flow;
+use steply_core::state::step::Step;
+use steply_core::terminal::{
+    ColorDepth, KeyCode, KeyEvent, KeyModifiers, PointerEvent, PointerKind, PointerSemantic,
+    TerminalCapabilities, TerminalEvent, TerminalSize,
+};
+use steply_core::ui::renderer::RenderFrame;
+use steply_core::ui::span::Span;
+use steply_core::ui::style::{Color, Style, Underline};
+use steply_core::widgets::inputs::choice::ChoiceInput;
+use steply_core::widgets::node::Node;
+
+const FLOW: &str = r#"
+version: 1
+confirm_finish: false
+steps:
+  - id: profile
+    title: Profile
+    widgets:
+      - type: text_input
+        id: name
+        label: Name
+        value: profile.name
+"#;
+
+#[test]
+fn scripted_keys_fill_the_flow_and_finish_it() {
+    let mut driver = TestDriver::from_yaml(FLOW).expect("driver");
+    assert!(driver.frame_text().contains("Profile"));
+
+    driver.type_text("Ada").expect("type");
+    assert!(
+        driver.frame_text().contains("Ada"),
+        "{}",
+        driver.frame_text()
+    );
+
+    driver.press_code(KeyCode::Enter).expect("submit");
+    assert!(driver.is_finished());
+    assert_eq!(
+        driver.state().store_value("profile.name"),
+        Some(&Value::Text("Ada".to_string()))
+    );
+}
+
+#[test]
+fn pasted_newlines_do_not_submit_a_text_field() {
+    let mut driver = TestDriver::from_yaml(FLOW).expect("driver");
+
+    driver.type_text("Ada ").expect("type");
+    driver.paste("King\r\nLovelace\n").expect("paste");
+    assert!(!driver.is_finished());
+
+    driver.press_code(KeyCode::Enter).expect("submit");
+    assert_eq!(
+        driver.state().store_value("profile.name"),
+        Some(&Value::Text("Ada King Lovelace".to_string()))
+    );
+}
+
+#[test]
+fn focus_events_track_whether_the_terminal_is_focused() {
+    let mut driver = TestDriver::from_yaml(FLOW).expect("driver");
+    assert!(driver.state().terminal_focused());
+
+    driver.send(TerminalEvent::FocusLost).expect("blur");
+    assert!(!driver.state().terminal_focused());
+
+    driver.send(TerminalEvent::FocusGained).expect("focus");
+    assert!(driver.state().terminal_focused());
+}
+
+#[test]
+fn keymap_preset_remaps_navigation_outside_text_fields() {
+    let yaml = r#"
+version: 1
+confirm_finish: false
+steps:
+  - id: prefs
+    title: Prefs
+    widgets:
+      - type: text_input
+        id: name
+        label: Name
+        value: prefs.name
+      - type: select
+        id: color
+        label: Color
+        options: [red, green, blue]
+        value: prefs.color
+"#;
+    let mut state = steply_core::config::load_from_yaml_str(yaml)
+        .expect("load config")
+        .into_app_state()
+        .expect("app state");
+    state.set_action_bindings(ActionBindings::preset(KeymapPreset::Vim));
+    let mut driver = TestDriver::new(state).expect("driver");
+
+    driver.type_text("hjkl").expect("type");
+    driver.press_code(KeyCode::Tab).expect("focus select");
+    driver.type_text("l").expect("next option");
+    driver.press_code(KeyCode::Enter).expect("submit");
+
+    assert_eq!(
+        driver.state().store_value("prefs.name"),
+        Some(&Value::Text("hjkl".to_string()))
+    );
+    assert_eq!(
+        driver.state().store_value("prefs.color"),
+        Some(&Value::Text("green".to_string()))
+    );
+}
+
+#[test]
+fn emacs_preset_leaves_global_shortcuts_working() {
+    let mut state = load_from_yaml_str(FLOW)
+        .expect("load config")
+        .into_app_state()
+        .expect("app state");
+    state.set_action_bindings(ActionBindings::preset(KeymapPreset::Emacs));
+    let mut driver = TestDriver::new(state).expect("driver");
+    let ctrl = |ch| KeyEvent {
+        code: KeyCode::Char(ch),
+        modifiers: KeyModifiers::CONTROL,
+    };
+
+    driver.press(ctrl('p')).expect("toggle preview");
+    assert!(driver.state().value_preview_active());
+    driver.press(ctrl('p')).expect("toggle preview");
+    assert!(!driver.state().value_preview_active());
+
+    driver.type_text("ab").expect("type");
+    driver.press(ctrl('a')).expect("home");
+    driver.type_text(">").expect("type");
+    driver.press_code(KeyCode::Enter).expect("submit");
+    assert_eq!(
+        driver.state().store_value("profile.name"),
+        Some(&Value::Text(">ab".to_string()))
+    );
+}
+
+#[test]
+fn resize_bursts_collapse_to_the_final_size() {
+    let size = |width, height| TerminalSize { width, height };
+    let mut terminal = Terminal::headless(size(80, 24));
+    terminal.push_event(TerminalEvent::Resize(size(60, 20)));
+    terminal.push_event(TerminalEvent::Resize(size(50, 18)));
+    terminal.push_event(TerminalEvent::Key(KeyEvent {
+        code: KeyCode::Char('a'),
+        modifiers: KeyModifiers::NONE,
+    }));
+    terminal.push_event(TerminalEvent::Resize(size(40, 10)));
+
+    let wait = Duration::ZERO;
+    assert_eq!(
+        terminal.poll_event(wait).expect("poll"),
+        TerminalEvent::Resize(size(50, 18))
+    );
+    assert!(matches!(
+        terminal.poll_event(wait).expect("poll"),
+        TerminalEvent::Key(_)
+    ));
+    assert_eq!(
+        terminal.poll_event(wait).expect("poll"),
+        TerminalEvent::Resize(size(40, 10))
+    );
+}
+
+#[test]
+fn resize_rewraps_the_frame_immediately() {
+    let yaml = r#"
+version: 1
+steps:
+  - id: intro
+    title: Intro
+    widgets:
+      - type: text_output
+        id: about
+        text: "Steply walks you through a short series of questions before it writes the result."
+"#;
+    let mut driver = TestDriver::from_yaml(yaml).expect("driver");
+    let widest = |driver: &TestDriver| {
+        driver
+            .frame()
+            .iter()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0)
+    };
+    assert!(widest(&driver) > 40);
+
+    driver
+        .resize(TerminalSize {
+            width: 40,
+            height: 24,
+        })
+        .expect("resize");
+    assert!(widest(&driver) <= 40, "{}", driver.frame_text());
+    assert!(driver.frame_text().contains("writes"));
+}
+
+#[test]
+fn ctrl_c_runs_cancel_hooks_and_reports_a_cancelled_run() {
+    let cleanups = Arc::new(AtomicUsize::new(0));
+    let flow_cleanups = Arc::clone(&cleanups);
+    let flow = Flow::new(vec![Step::builder("intro", "Intro").build()]).on_cancel(move |_| {
+        flow_cleanups.fetch_add(1, Ordering::SeqCst);
+        Ok(())
+    });
+    let state = AppState::new(flow).expect("app state");
+    let mut terminal = Terminal::headless(TerminalSize {
+        width: 80,
+        height: 24,
+    });
+    let ctrl_c = TerminalEvent::Key(KeyEvent {
+        code: KeyCode::Char('c'),
+        modifiers: KeyModifiers::CONTROL,
+    });
+    terminal.push_event(ctrl_c.clone());
+    terminal.push_event(ctrl_c);
+
+    let mut runtime = Runtime::new(state, terminal);
+    assert_eq!(runtime.run().expect("run"), RunOutcome::Cancelled);
+    assert_eq!(cleanups.load(Ordering::SeqCst), 1);
+}
+
+const SETUP_FLOW: &str = r#"
+version: 1
+steps:
+  - id: project
+    title: Project
+    widgets:
+      - type: text_input
+        id: name
+        label: Name
+        value: project.name
+        required: true
+  - id: owner
+    title: Owner
+    widgets:
+      - type: text_input
+        id: email
+        label: Email
+        value: owner.email
+        required: true
+"#;
+
+fn unattended_runtime() -> Runtime {
+    let state = load_from_yaml_str(SETUP_FLOW)
+        .expect("load config")
+        .into_app_state()
+        .expect("app state");
+    Runtime::new(
+        state,
+        Terminal::headless(TerminalSize {
+            width: 80,
+            height: 24,
+        }),
+    )
+}
+
+#[test]
+fn answers_complete_the_flow_without_input() {
+    let answers =
+        parse_values_document("project:\n  name: steply\nowner:\n  email: dev@example.com\n")
+            .expect("answers");
+    let mut runtime = unattended_runtime();
+
+    assert_eq!(
+        runtime.run_unattended(&answers).expect("run"),
+        RunOutcome::Completed
+    );
+    assert!(runtime.state().should_exit());
+    assert_eq!(
+        runtime.state().store_value("owner.email"),
+        Some(&Value::Text("dev@example.com".to_string()))
+    );
+}
+
+#[test]
+fn missing_answers_fail_at_the_step_that_needs_them() {
+    let answers = parse_values_document(r#"{"project": {"name": "steply"}}"#).expect("answers");
+    let mut runtime = unattended_runtime();
+
+    let err = runtime
+        .run_unattended(&answers)
+        .expect_err("owner step has no answer");
+    assert!(err.to_string().contains("step 'owner'"), "{err}");
+}
+
+#[derive(Default)]
+struct CaptureBackend {
+    events: VecDeque<TerminalEvent>,
+    output: Arc<Mutex<Vec<u8>>>,
+    raw_mode: Arc<AtomicBool>,
+}
+
+impl Write for CaptureBackend {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.output.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Backend for CaptureBackend {
+    fn size(&self) -> io::Result<TerminalSize> {
+        Ok(TerminalSize {
+            width: 60,
+            height: 20,
+        })
+    }
+
+    fn enable_raw_mode(&mut self) -> io::Result<()> {
+        self.raw_mode.store(true, Ordering::SeqCst);
+        Ok(())
+    }
+
+    fn disable_raw_mode(&mut self) -> io::Result<()> {
+        self.raw_mode.store(false, Ordering::SeqCst);
+        Ok(())
+    }
+
+    fn poll_event(&mut self, _timeout: Duration) -> io::Result<Option<TerminalEvent>> {
+        Ok(self.events.pop_front())
+    }
+
+    fn cursor_position(&mut self) -> io::Result<(u16, u16)> {
+        Ok((0, 0))
+    }
+}
+
+#[test]
+fn custom_backend_receives_raw_mode_and_frame_output() {
+    let output = Arc::new(Mutex::new(Vec::new()));
+    let raw_mode = Arc::new(AtomicBool::new(false));
+    let mut backend = CaptureBackend {
+        output: Arc::clone(&output),
+        raw_mode: Arc::clone(&raw_mode),
+        ..CaptureBackend::default()
+    };
+    for ch in "Ada".chars() {
+        backend.events.push_back(TerminalEvent::Key(KeyEvent {
+            code: KeyCode::Char(ch),
+            modifiers: KeyModifiers::NONE,
+        }));
+    }
+    backend.events.push_back(TerminalEvent::Key(KeyEvent {
+        code: KeyCode::Enter,
+        modifiers: KeyModifiers::NONE,
+    }));
+
+    let state = load_from_yaml_str(FLOW)
+        .expect("load config")
+        .into_app_state()
+        .expect("app state");
+    let terminal = Terminal::with_backend(backend).expect("terminal");
+    let mut runtime = Runtime::new(state, terminal);
+
+    assert_eq!(runtime.run().expect("run"), RunOutcome::Completed);
+    assert!(!raw_mode.load(Ordering::SeqCst));
+    let written = String::from_utf8_lossy(&output.lock().unwrap()).into_owned();
+    assert!(written.contains("Ada"), "{written}");
+}
+
+fn captured_output(
+    capabilities: TerminalCapabilities,
+    configure: impl FnOnce(Runtime) -> Runtime,
+) -> String {
+    let output = Arc::new(Mutex::new(Vec::new()));
+    let mut backend = CaptureBackend {
+        output: Arc::clone(&output),
+        ..CaptureBackend::default()
+    };
+    for ch in "Ada".chars() {
+        backend.events.push_back(TerminalEvent::Key(KeyEvent {
+            code: KeyCode::Char(ch),
+            modifiers: KeyModifiers::NONE,
+        }));
+    }
+    backend.events.push_back(TerminalEvent::Key(KeyEvent {
+        code: KeyCode::Enter,
+        modifiers: KeyModifiers::NONE,
+    }));
+
+    let state = load_from_yaml_str(FLOW)
+        .expect("load config")
+        .into_app_state()
+        .expect("app state");
+    let terminal = Terminal::with_backend(backend)
+        .expect("terminal")
+        .with_capabilities(capabilities);
+    let mut runtime = configure(Runtime::new(state, terminal));
+    assert_eq!(runtime.run().expect("run"), RunOutcome::Completed);
+    String::from_utf8_lossy(&output.lock().unwrap()).into_owned()
+}
+
+#[test]
+fn frames_are_wrapped_in_synchronized_updates_only_when_supported() {
+    let synced = captured_output(TerminalCapabilities::default(), |runtime| runtime);
+    assert!(synced.contains("\x1b[?2026h"));
+    assert!(synced.contains("\x1b[?2026l"));
+
+    let plain = captured_output(
+        TerminalCapabilities {
+            synchronized_output: false,
+            ..TerminalCapabilities::default()
+        },
+        |runtime| runtime,
+    );
+    assert!(!plain.contains("\x1b[?2026h"));
+}
+
+#[test]
+fn monochrome_output_keeps_contrast_without_colors() {
+    let colored = captured_output(TerminalCapabilities::default(), |runtime| runtime);
+    assert!(colored.contains("\x1b[38;"), "{colored:?}");
+
+    let mono = captured_output(
+        TerminalCapabilities {
+            color_depth: ColorDepth::Monochrome,
+            ..TerminalCapabilities::default()
+        },
+        |runtime| runtime,
+    );
+    assert!(
+        !mono.contains("\x1b[38;") && !mono.contains("\x1b[48;"),
+        "{mono:?}"
+    );
+    // Hints are dimmed and the focused input is bold.
+    assert!(mono.contains("\x1b[2m"), "{mono:?}");
+    assert!(mono.contains("\x1b[1m"), "{mono:?}");
+}
+
+fn rendered_span(capabilities: TerminalCapabilities, style: Style) -> String {
+    let output = Arc::new(Mutex::new(Vec::new()));
+    let backend = CaptureBackend {
+        output: Arc::clone(&output),
+        ..CaptureBackend::default()
+    };
+    let mut terminal = Terminal::with_backend(backend)
+        .expect("terminal")
+        .with_capabilities(capabilities);
+    let frame = RenderFrame {
+        lines: vec![vec![Span::styled("oops", style)]],
+        ..RenderFrame::default()
+    };
+    terminal.render_frame(&frame).expect("render");
+    String::from_utf8_lossy(&output.lock().unwrap()).into_owned()
+}
+
+#[test]
+fn styled_underlines_fall_back_to_straight_ones() {
+    let style = Style::new()
+        .italic()
+        .underline(Underline::Curly)
+        .underline_color(Color::Red);
+    let styled = rendered_span(TerminalCapabilities::default(), style);
+    assert!(styled.contains("\x1b[3m"), "{styled:?}");
+    assert!(styled.contains("\x1b[4:3m"), "{styled:?}");
+    assert!(styled.contains("\x1b[58;5;9m"), "{styled:?}");
+
+    let plain = rendered_span(
+        TerminalCapabilities {
+            styled_underlines: false,
+            ..TerminalCapabilities::default()
+        },
+        style,
+    );
+    assert!(plain.contains("\x1b[4m"), "{plain:?}");
+    assert!(
+        !plain.contains("4:3") && !plain.contains("58;"),
+        "{plain:?}"
+    );
+}
+
+#[test]
+fn window_title_and_progress_follow_the_flow() {
+    let output = captured_output(
+        TerminalCapabilities {
+            progress_reporting: true,
+            ..TerminalCapabilities::default()
+        },
+        |runtime| runtime.with_window_title("Setup"),
+    );
+    assert!(output.contains("\x1b]0;Setup — step 1/1\x07"), "{output:?}");
+    assert!(output.contains("\x1b]9;4;1;0\x07"), "{output:?}");
+    // Progress is cleared and the shell's title restored on exit.
+    assert!(output.contains("\x1b]9;4;0;0\x07\x1b[23;0t"), "{output:?}");
+
+    let unsupported = captured_output(TerminalCapabilities::default(), |runtime| runtime);
+    assert!(!unsupported.contains("\x1b]9;4;"));
+    assert!(!unsupported.contains("\x1b[22;0t"));
+}
+
+#[test]
+fn wheel_over_a_list_moves_its_cursor_instead_of_the_screen() {
+    let yaml = r#"
+version: 1
+steps:
+  - id: features
+    title: Features
+    widgets:
+      - type: select_list
+        id: features
+        label: Features
+        options: [auth, api, billing]
+        value: features
+"#;
+    let mut driver = TestDriver::from_yaml(yaml).expect("driver");
+    let row = driver
+        .frame()
+        .iter()
+        .position(|line| line.contains("auth"))
+        .expect("list row") as u16;
+    let wheel = |kind| {
+        TerminalEvent::Pointer(PointerEvent {
+            kind,
+            col: 4,
+            row,
+            modifiers: KeyModifiers::NONE,
+            semantic: PointerSemantic::None,
+        })
+    };
+    let cursor_on = |driver: &TestDriver, option: &str| {
+        driver
+            .frame()
+            .iter()
+            .any(|line| line.contains('❯') && line.contains(option))
+    };
+
+    driver.send(wheel(PointerKind::ScrollDown)).expect("wheel");
+    assert!(cursor_on(&driver, "api"), "{}", driver.frame_text());
+
+    // The wheel stops at the ends rather than wrapping around.
+    driver.send(wheel(PointerKind::ScrollUp)).expect("wheel");
+    driver.send(wheel(PointerKind::ScrollUp)).expect("wheel");
+    assert!(cursor_on(&driver, "auth"), "{}", driver.frame_text());
+}
+
+#[test]
+fn a_panic_mid_run_still_restores_the_terminal() {
+    let output = Arc::new(Mutex::new(Vec::new()));
+    let raw_mode = Arc::new(AtomicBool::new(false));
+    let backend = CaptureBackend {
+        output: Arc::clone(&output),
+        raw_mode: Arc::clone(&raw_mode),
+        ..CaptureBackend::default()
+    };
+
+    let unwound = std::panic::catch_unwind(AssertUnwindSafe(|| {
+        let mut terminal = Terminal::with_backend(backend).expect("terminal");
+        terminal.enter().expect("enter");
+        assert!(raw_mode.load(Ordering::SeqCst));
+        panic!("widget failed");
+    }));
+
+    assert!(unwound.is_err());
+    assert!(!raw_mode.load(Ordering::SeqCst));
+    let written = String::from_utf8_lossy(&output.lock().unwrap()).into_owned();
+    assert!(written.contains("\x1b[?1049l"), "{written:?}");
+    assert!(written.ends_with("\x1b[?25h"), "{written:?}");
+}
+
+#[test]
+fn widget_snapshot_depends_on_focus_and_format() {
+    let node = Node::Input(Box::new(ChoiceInput::new(
+        "env",
+        "Environment",
+        vec!["dev".to_string(), "prod".to_string()],
+    )));
+    let size = TerminalSize {
+        width: 20,
+        height: 5,
+    };
+
+    let unfocused = WidgetSnapshot::new(&node).with_size(size).render();
+    assert!(unfocused.contains("dev"), "{unfocused}");
+    assert!(!unfocused.contains("prod"), "{unfocused}");
+
+    let focused = WidgetSnapshot::new(&node).with_size(size).focused();
+    let plain = focused.render();
+    assert!(plain.contains("prod"), "{plain}");
+    assert!(!plain.contains('\x1b'));
+    assert!(plain.lines().all(|line| line.chars().count() <= 20));
+
+    let ansi = focused.with_format(SnapshotFormat::Ansi).render();
+    assert!(ansi.contains("\x1b["), "{ansi}");
+    assert_ne!(ansi, plain);
+}
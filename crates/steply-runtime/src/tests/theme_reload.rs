@@ -0,0 +1,56 @@
+use super::{POLL_INTERVAL, ThemeWatcher};
+use std::time::Instant;
+use steply_core::config::load_from_yaml_str;
+use steply_core::state::app::AppState;
+use steply_core::ui::style::Color;
+
+fn state() -> AppState {
+    let yaml = r#"
+version: 1
+steps:
+  - id: project
+    title: Project
+    widgets:
+      - type: text_input
+        id: name
+        label: Name
+        value: project.name
+"#;
+    load_from_yaml_str(yaml)
+        .expect("load config")
+        .into_app_state()
+        .expect("app state")
+}
+
+#[test]
+fn reloads_changed_themes_and_keeps_the_last_good_one() {
+    let path = std::env::temp_dir().join(format!("steply-theme-{}.toml", std::process::id()));
+    std::fs::write(&path, "[palette]\ncyan = \"red\"\n").expect("write theme");
+    let mut state = state();
+    let mut watcher = ThemeWatcher::load(&path, &mut state).expect("load theme");
+    assert_eq!(state.theme().palette.map(Color::Cyan), Color::Red);
+
+    let mut now = Instant::now() + POLL_INTERVAL;
+    assert!(!watcher.poll(now, &mut state), "unchanged file");
+
+    std::fs::write(&path, "[palette]\ncyan = \"magenta\"\n").expect("write theme");
+    now += POLL_INTERVAL;
+    assert!(watcher.poll(now, &mut state));
+    assert_eq!(state.theme().palette.map(Color::Cyan), Color::Magenta);
+    assert!(watcher.error_block().is_none());
+
+    std::fs::write(&path, "[palette]\ncyan = \"not-a-color\"\n").expect("write theme");
+    now += POLL_INTERVAL;
+    assert!(watcher.poll(now, &mut state));
+    assert_eq!(state.theme().palette.map(Color::Cyan), Color::Magenta);
+    let block = watcher.error_block().expect("error block");
+    assert!(block.lines[0][0].text.contains("not-a-color"));
+
+    std::fs::write(&path, "[palette]\ncyan = \"green\"\n").expect("write theme");
+    now += POLL_INTERVAL;
+    assert!(watcher.poll(now, &mut state));
+    assert_eq!(state.theme().palette.map(Color::Cyan), Color::Green);
+    assert!(watcher.error_block().is_none());
+
+    let _ = std::fs::remove_file(&path);
+}
@@ -0,0 +1,88 @@
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
+
+use steply_core::state::app::AppState;
+use steply_core::ui::span::Span;
+use steply_core::ui::style::{Color, Style};
+use steply_core::ui::theme::Theme;
+use steply_core::widgets::traits::{StickyBlock, StickyPosition};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Watches a theme file and swaps the theme in when it changes on disk.
+/// A file that fails to load keeps the previous theme and shows the error
+/// until it is fixed.
+pub(crate) struct ThemeWatcher {
+    path: PathBuf,
+    stamp: Option<(SystemTime, u64)>,
+    next_poll: Instant,
+    error: Option<String>,
+}
+
+impl ThemeWatcher {
+    /// Loads the theme at `path` into `state`. Unlike a reload, a broken
+    /// file is an error here.
+    pub(crate) fn load(path: &Path, state: &mut AppState) -> io::Result<Self> {
+        let stamp = file_stamp(path);
+        let theme = Theme::load(path).map_err(io::Error::other)?;
+        state.set_theme(theme);
+        Ok(Self {
+            path: path.to_path_buf(),
+            stamp,
+            next_poll: Instant::now() + POLL_INTERVAL,
+            error: None,
+        })
+    }
+
+    /// Reloads the theme if the file changed since the last look. Returns
+    /// whether the frame needs redrawing.
+    pub(crate) fn poll(&mut self, now: Instant, state: &mut AppState) -> bool {
+        if now < self.next_poll {
+            return false;
+        }
+        self.next_poll = now + POLL_INTERVAL;
+        let stamp = file_stamp(self.path.as_path());
+        if stamp == self.stamp {
+            return false;
+        }
+        self.stamp = stamp;
+        let error = match Theme::load(self.path.as_path()) {
+            Ok(theme) => {
+                state.set_theme(theme);
+                None
+            }
+            Err(err) => Some(err),
+        };
+        let error_changed = error != self.error;
+        self.error = error;
+        self.error.is_none() || error_changed
+    }
+
+    pub(crate) fn error_block(&self) -> Option<StickyBlock> {
+        let error = self.error.as_deref()?;
+        let line = vec![
+            Span::styled(
+                format!("theme not reloaded: {error}"),
+                Style::new().color(Color::Red),
+            )
+            .no_wrap(),
+        ];
+        Some(StickyBlock::new(
+            StickyPosition::Bottom,
+            u8::MAX,
+            vec![line],
+        ))
+    }
+}
+
+/// Modification time and length; the length catches edits that land
+/// within the file system's timestamp resolution.
+fn file_stamp(path: &Path) -> Option<(SystemTime, u64)> {
+    let metadata = std::fs::metadata(path).ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
+#[cfg(test)]
+#[path = "tests/theme_reload.rs"]
+mod tests;
@@ -0,0 +1,99 @@
+use std::io;
+
+use russh::MethodKind;
+use russh::keys::ssh_key::{AuthorizedKeys, PublicKey};
+
+use crate::SshServerOptions;
+
+/// Who may open a session, resolved once when the server starts.
+#[derive(Debug, Default)]
+pub(crate) struct Access {
+    users: Vec<String>,
+    password: Option<String>,
+    keys: Vec<PublicKey>,
+    anonymous: bool,
+}
+
+impl Access {
+    pub(crate) fn load(options: &SshServerOptions) -> io::Result<Self> {
+        let keys = match options.authorized_keys_path.as_deref() {
+            Some(path) => AuthorizedKeys::read_file(path)
+                .map_err(|err| {
+                    io::Error::other(format!("authorized keys {}: {err}", path.display()))
+                })?
+                .into_iter()
+                .map(|entry| entry.public_key().clone())
+                .collect(),
+            None => Vec::new(),
+        };
+        if options.password.is_none() && keys.is_empty() && !options.allow_anonymous {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "no SSH credentials configured: set a password or authorized keys, \
+                 or allow anonymous access explicitly",
+            ));
+        }
+        Ok(Self {
+            users: options.users.clone(),
+            password: options.password.clone(),
+            keys,
+            anonymous: options.allow_anonymous,
+        })
+    }
+
+    /// Anonymous access was opted into: `none` authentication is accepted.
+    pub(crate) fn is_open(&self) -> bool {
+        self.anonymous
+    }
+
+    /// The authentication methods worth offering: only those that can
+    /// succeed with what is configured.
+    pub(crate) fn methods(&self) -> Vec<MethodKind> {
+        let mut methods = Vec::new();
+        if self.anonymous {
+            methods.push(MethodKind::None);
+        }
+        if self.password.is_some() {
+            methods.push(MethodKind::Password);
+        }
+        if !self.keys.is_empty() {
+            methods.push(MethodKind::PublicKey);
+        }
+        methods
+    }
+
+    pub(crate) fn allows_user(&self, user: &str) -> bool {
+        self.users.is_empty() || self.users.iter().any(|allowed| allowed == user)
+    }
+
+    pub(crate) fn allows_password(&self, user: &str, password: &str) -> bool {
+        self.allows_user(user)
+            && self
+                .password
+                .as_deref()
+                .is_some_and(|expected| constant_time_eq(expected, password))
+    }
+
+    pub(crate) fn allows_key(&self, user: &str, key: &PublicKey) -> bool {
+        self.allows_user(user)
+            && self
+                .keys
+                .iter()
+                .any(|allowed| allowed.key_data() == key.key_data())
+    }
+}
+
+/// Compares without stopping at the first difference, so response times
+/// do not tell how much of a guess was right.
+fn constant_time_eq(expected: &str, actual: &str) -> bool {
+    let (expected, actual) = (expected.as_bytes(), actual.as_bytes());
+    let diff = expected
+        .iter()
+        .zip(actual)
+        .fold(0u8, |diff, (a, b)| diff | (a ^ b));
+    diff == 0 && expected.len() == actual.len()
+}
+
+#[cfg(test)]
+#[path = "tests/access.rs"]
+mod tests;
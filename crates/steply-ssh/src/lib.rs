@@ -0,0 +1,17 @@
+//! Serves steply flows over SSH, so a wizard can be reached with
+//! `ssh setup@host`.
+//!
+//! Every session channel gets its own pty-backed
+//! [`ChannelBackend`](steply_runtime::terminal::ChannelBackend), its own
+//! [`Terminal`](steply_runtime::Terminal) and a fresh
+//! [`Runtime`](steply_runtime::Runtime) built by a [`SessionFlow`], so no
+//! state is shared between connections.
+
+mod access;
+mod options;
+mod server;
+mod session;
+
+pub use options::SshServerOptions;
+pub use server::serve;
+pub use session::{SessionFlow, SessionInfo};
@@ -0,0 +1,40 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// How [`serve`](crate::serve) listens and who it lets in.
+///
+/// A password or authorized keys are required unless `allow_anonymous` is
+/// set, and the default only listens on the loopback interface.
+#[derive(Debug, Clone)]
+pub struct SshServerOptions {
+    /// Address to listen on; `127.0.0.1:2222` by default. Use e.g.
+    /// `0.0.0.0:2222` to accept connections from other hosts.
+    pub listen: String,
+    /// OpenSSH private host key. A new ed25519 key is written there when the
+    /// file does not exist; without a path every start uses a throwaway key.
+    pub host_key_path: Option<PathBuf>,
+    /// User names allowed to log in; any user when empty.
+    pub users: Vec<String>,
+    pub password: Option<String>,
+    /// `authorized_keys` file whose public keys may log in.
+    pub authorized_keys_path: Option<PathBuf>,
+    /// Lets anyone connect without credentials, for wizards behind a
+    /// bastion or on a trusted network.
+    pub allow_anonymous: bool,
+    /// Closes connections with no traffic for this long.
+    pub inactivity_timeout: Option<Duration>,
+}
+
+impl Default for SshServerOptions {
+    fn default() -> Self {
+        Self {
+            listen: "127.0.0.1:2222".to_string(),
+            host_key_path: None,
+            users: Vec::new(),
+            password: None,
+            authorized_keys_path: None,
+            allow_anonymous: false,
+            inactivity_timeout: Some(Duration::from_secs(3600)),
+        }
+    }
+}
@@ -0,0 +1,333 @@
+use std::collections::HashMap;
+use std::io;
+use std::net::SocketAddr;
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::mpsc::{self, Sender};
+
+use russh::keys::ssh_key::LineEnding;
+use russh::keys::{Algorithm, PrivateKey, PublicKey, load_secret_key};
+use russh::server::{Auth, Config, Handler, Msg, Server, Session};
+use russh::{Channel, ChannelId, MethodSet, Pty};
+use steply_core::terminal::TerminalSize;
+use steply_runtime::terminal::ChannelInput;
+use tokio::sync::mpsc::{UnboundedReceiver, unbounded_channel};
+
+use crate::SshServerOptions;
+use crate::access::Access;
+use crate::session::{Outbound, SessionFlow, SessionInfo, run_session};
+
+const DEFAULT_SIZE: TerminalSize = TerminalSize {
+    width: 80,
+    height: 24,
+};
+
+/// Listens on `options.listen` and runs `flow` for every SSH session until
+/// the listener fails. Each session runs on its own thread.
+pub fn serve(options: SshServerOptions, flow: impl SessionFlow) -> io::Result<()> {
+    let config = Config {
+        keys: vec![host_key(options.host_key_path.as_deref())?],
+        inactivity_timeout: options.inactivity_timeout,
+        nodelay: true,
+        ..Default::default()
+    };
+    let mut server = SshServer {
+        flow: Arc::new(flow),
+        access: Arc::new(Access::load(&options)?),
+    };
+    tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()?
+        .block_on(server.run_on_address(Arc::new(config), options.listen.as_str()))
+}
+
+fn host_key(path: Option<&Path>) -> io::Result<PrivateKey> {
+    let Some(path) = path else {
+        return generate_host_key();
+    };
+    if path.exists() {
+        return load_secret_key(path, None)
+            .map_err(|err| io::Error::other(format!("host key {}: {err}", path.display())));
+    }
+    let key = generate_host_key()?;
+    key.write_openssh_file(path, LineEnding::LF)
+        .map_err(|err| io::Error::other(format!("host key {}: {err}", path.display())))?;
+    Ok(key)
+}
+
+fn generate_host_key() -> io::Result<PrivateKey> {
+    PrivateKey::random(&mut rand::rng(), Algorithm::Ed25519)
+        .map_err(|err| io::Error::other(format!("host key generation failed: {err}")))
+}
+
+struct SshServer {
+    flow: Arc<dyn SessionFlow>,
+    access: Arc<Access>,
+}
+
+impl Server for SshServer {
+    type Handler = ConnectionHandler;
+
+    fn new_client(&mut self, peer: Option<SocketAddr>) -> ConnectionHandler {
+        ConnectionHandler {
+            flow: self.flow.clone(),
+            access: self.access.clone(),
+            peer,
+            user: String::new(),
+            channels: HashMap::new(),
+        }
+    }
+}
+
+/// One SSH connection, which may open several session channels.
+struct ConnectionHandler {
+    flow: Arc<dyn SessionFlow>,
+    access: Arc<Access>,
+    peer: Option<SocketAddr>,
+    user: String,
+    channels: HashMap<ChannelId, ChannelState>,
+}
+
+/// What a client asked for on a channel before starting its shell.
+#[derive(Default)]
+struct ChannelState {
+    term: String,
+    size: Option<TerminalSize>,
+    env: Vec<(String, String)>,
+    /// Set once the flow runs; dropping it ends the session.
+    input: Option<Sender<ChannelInput>>,
+}
+
+impl ConnectionHandler {
+    fn reject(&self) -> Auth {
+        Auth::Reject {
+            proceed_with_methods: Some(MethodSet::from(self.access.methods().as_slice())),
+            partial_success: false,
+        }
+    }
+
+    fn accept_user(&mut self, user: &str, allowed: bool) -> Auth {
+        if !allowed {
+            return self.reject();
+        }
+        self.user = user.to_string();
+        Auth::Accept
+    }
+
+    fn start_flow(
+        &mut self,
+        channel: ChannelId,
+        command: Option<String>,
+        session: &mut Session,
+    ) -> Result<(), russh::Error> {
+        let Some(state) = self
+            .channels
+            .get_mut(&channel)
+            .filter(|state| state.input.is_none())
+        else {
+            return session.channel_failure(channel);
+        };
+        session.channel_success(channel)?;
+        let info = SessionInfo {
+            user: self.user.clone(),
+            peer: self.peer,
+            term: state.term.clone(),
+            env: state.env.clone(),
+            command,
+        };
+        let size = state.size.unwrap_or(DEFAULT_SIZE);
+        let (input, input_rx) = mpsc::channel();
+        state.input = Some(input);
+        let (outbound, outbound_rx) = unbounded_channel();
+        tokio::spawn(forward_output(session.handle(), channel, outbound_rx));
+        let flow = self.flow.clone();
+        std::thread::spawn(move || run_session(flow, info, size, input_rx, outbound));
+        Ok(())
+    }
+
+    fn send_input(&mut self, channel: ChannelId, input: ChannelInput) {
+        let Some(state) = self.channels.get_mut(&channel) else {
+            return;
+        };
+        if let ChannelInput::Resize(size) = input {
+            state.size = Some(size);
+        }
+        if let Some(sender) = state.input.as_ref()
+            && sender.send(input).is_err()
+        {
+            state.input = None;
+        }
+    }
+}
+
+/// Writes a session's frames to its channel and closes the channel once
+/// the flow is over.
+async fn forward_output(
+    handle: russh::server::Handle,
+    channel: ChannelId,
+    mut outbound: UnboundedReceiver<Outbound>,
+) {
+    while let Some(message) = outbound.recv().await {
+        match message {
+            Outbound::Data(bytes) => {
+                if handle.data(channel, bytes).await.is_err() {
+                    return;
+                }
+            }
+            Outbound::Exit(status) => {
+                let _ = handle.exit_status_request(channel, status).await;
+                break;
+            }
+        }
+    }
+    let _ = handle.eof(channel).await;
+    let _ = handle.close(channel).await;
+}
+
+fn terminal_size(col_width: u32, row_height: u32) -> TerminalSize {
+    TerminalSize {
+        width: u16::try_from(col_width).unwrap_or(u16::MAX).max(1),
+        height: u16::try_from(row_height).unwrap_or(u16::MAX).max(1),
+    }
+}
+
+impl Handler for ConnectionHandler {
+    type Error = russh::Error;
+
+    async fn auth_none(&mut self, user: &str) -> Result<Auth, Self::Error> {
+        let allowed = self.access.is_open() && self.access.allows_user(user);
+        Ok(self.accept_user(user, allowed))
+    }
+
+    async fn auth_password(&mut self, user: &str, password: &str) -> Result<Auth, Self::Error> {
+        let allowed = self.access.allows_password(user, password);
+        Ok(self.accept_user(user, allowed))
+    }
+
+    async fn auth_publickey_offered(
+        &mut self,
+        user: &str,
+        public_key: &PublicKey,
+    ) -> Result<Auth, Self::Error> {
+        if self.access.allows_key(user, public_key) {
+            Ok(Auth::Accept)
+        } else {
+            Ok(self.reject())
+        }
+    }
+
+    async fn auth_publickey(
+        &mut self,
+        user: &str,
+        public_key: &PublicKey,
+    ) -> Result<Auth, Self::Error> {
+        let allowed = self.access.allows_key(user, public_key);
+        Ok(self.accept_user(user, allowed))
+    }
+
+    async fn channel_open_session(
+        &mut self,
+        channel: Channel<Msg>,
+        reply: russh::server::ChannelOpenHandle,
+        _session: &mut Session,
+    ) -> Result<(), Self::Error> {
+        self.channels.insert(channel.id(), ChannelState::default());
+        reply.accept().await;
+        Ok(())
+    }
+
+    async fn pty_request(
+        &mut self,
+        channel: ChannelId,
+        term: &str,
+        col_width: u32,
+        row_height: u32,
+        _pix_width: u32,
+        _pix_height: u32,
+        _modes: &[(Pty, u32)],
+        session: &mut Session,
+    ) -> Result<(), Self::Error> {
+        let Some(state) = self.channels.get_mut(&channel) else {
+            return session.channel_failure(channel);
+        };
+        state.term = term.to_string();
+        state.size = Some(terminal_size(col_width, row_height));
+        session.channel_success(channel)
+    }
+
+    async fn env_request(
+        &mut self,
+        channel: ChannelId,
+        variable_name: &str,
+        variable_value: &str,
+        session: &mut Session,
+    ) -> Result<(), Self::Error> {
+        let Some(state) = self.channels.get_mut(&channel) else {
+            return session.channel_failure(channel);
+        };
+        state
+            .env
+            .push((variable_name.to_string(), variable_value.to_string()));
+        session.channel_success(channel)
+    }
+
+    async fn shell_request(
+        &mut self,
+        channel: ChannelId,
+        session: &mut Session,
+    ) -> Result<(), Self::Error> {
+        self.start_flow(channel, None, session)
+    }
+
+    async fn exec_request(
+        &mut self,
+        channel: ChannelId,
+        data: &[u8],
+        session: &mut Session,
+    ) -> Result<(), Self::Error> {
+        let command = String::from_utf8_lossy(data).into_owned();
+        self.start_flow(channel, Some(command), session)
+    }
+
+    async fn data(
+        &mut self,
+        channel: ChannelId,
+        data: &[u8],
+        _session: &mut Session,
+    ) -> Result<(), Self::Error> {
+        self.send_input(channel, ChannelInput::Bytes(data.to_vec()));
+        Ok(())
+    }
+
+    async fn window_change_request(
+        &mut self,
+        channel: ChannelId,
+        col_width: u32,
+        row_height: u32,
+        _pix_width: u32,
+        _pix_height: u32,
+        _session: &mut Session,
+    ) -> Result<(), Self::Error> {
+        let size = terminal_size(col_width, row_height);
+        self.send_input(channel, ChannelInput::Resize(size));
+        Ok(())
+    }
+
+    async fn channel_eof(
+        &mut self,
+        channel: ChannelId,
+        _session: &mut Session,
+    ) -> Result<(), Self::Error> {
+        self.channels.remove(&channel);
+        Ok(())
+    }
+
+    async fn channel_close(
+        &mut self,
+        channel: ChannelId,
+        _session: &mut Session,
+    ) -> Result<(), Self::Error> {
+        self.channels.remove(&channel);
+        Ok(())
+    }
+}
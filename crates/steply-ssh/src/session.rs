@@ -0,0 +1,131 @@
+use std::io::{self, Write};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::sync::mpsc::Receiver;
+
+use steply_core::state::app::AppState;
+use steply_core::terminal::{TerminalCapabilities, TerminalSize};
+use steply_runtime::terminal::{ChannelBackend, ChannelInput};
+use steply_runtime::{RenderMode, RunOutcome, Runtime, Terminal};
+use tokio::sync::mpsc::UnboundedSender;
+
+/// The connection a flow runs for.
+#[derive(Debug, Clone)]
+pub struct SessionInfo {
+    pub user: String,
+    pub peer: Option<SocketAddr>,
+    /// `TERM` of the client's pty.
+    pub term: String,
+    /// Variables the client sent with `SendEnv`.
+    pub env: Vec<(String, String)>,
+    /// The command of `ssh host <command>`; `None` for a login shell.
+    pub command: Option<String>,
+}
+
+impl SessionInfo {
+    pub fn env_var(&self, name: &str) -> Option<&str> {
+        self.env
+            .iter()
+            .rev()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn capabilities(&self) -> TerminalCapabilities {
+        TerminalCapabilities::detect(|name| match name {
+            "TERM" => Some(self.term.clone()),
+            _ => self.env_var(name).map(str::to_string),
+        })
+    }
+}
+
+/// Builds the flow each SSH session runs. `start` is called on the
+/// session's own thread, so every connection gets fresh state.
+pub trait SessionFlow: Send + Sync + 'static {
+    /// Creates the runtime for one session around `terminal`, which draws
+    /// on the client's pty.
+    fn start(&self, session: &SessionInfo, terminal: Terminal) -> io::Result<Runtime>;
+
+    /// Called once the flow ended, before the connection is closed.
+    fn finish(&self, _session: &SessionInfo, _state: &AppState, _outcome: RunOutcome) {}
+}
+
+impl<F> SessionFlow for F
+where
+    F: Fn(&SessionInfo, Terminal) -> io::Result<Runtime> + Send + Sync + 'static,
+{
+    fn start(&self, session: &SessionInfo, terminal: Terminal) -> io::Result<Runtime> {
+        self(session, terminal)
+    }
+}
+
+/// What a session thread hands the task writing to its channel.
+pub(crate) enum Outbound {
+    Data(Vec<u8>),
+    /// The flow ended; send the exit status and close the channel.
+    Exit(u32),
+}
+
+/// Buffers frames and passes them to the channel task on flush, so each
+/// frame goes out as one SSH packet rather than one per escape sequence.
+struct ChannelWriter {
+    sink: Vec<u8>,
+    sender: UnboundedSender<Outbound>,
+}
+
+impl Write for ChannelWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.sink.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if self.sink.is_empty() {
+            return Ok(());
+        }
+        self.sender
+            .send(Outbound::Data(std::mem::take(&mut self.sink)))
+            .map_err(|_| io::Error::new(io::ErrorKind::BrokenPipe, "ssh channel closed"))
+    }
+}
+
+/// Runs one session to the end on the calling thread. The exit status
+/// follows `ssh` conventions: 130 when cancelled, 1 on errors.
+pub(crate) fn run_session(
+    flow: Arc<dyn SessionFlow>,
+    session: SessionInfo,
+    size: TerminalSize,
+    input: Receiver<ChannelInput>,
+    outbound: UnboundedSender<Outbound>,
+) {
+    let writer = ChannelWriter {
+        sink: Vec::new(),
+        sender: outbound.clone(),
+    };
+    let backend = ChannelBackend::new(writer, input, size, session.capabilities());
+    let status = match run_flow(flow.as_ref(), &session, backend) {
+        Ok(RunOutcome::Completed) => 0,
+        Ok(RunOutcome::Cancelled) => 130,
+        Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => return,
+        Err(err) => {
+            let message = format!("error: {err}\r\n");
+            let _ = outbound.send(Outbound::Data(message.into_bytes()));
+            1
+        }
+    };
+    let _ = outbound.send(Outbound::Exit(status));
+}
+
+// The runtime is dropped before returning, so the client's terminal is
+// restored before the exit status goes out.
+fn run_flow(
+    flow: &dyn SessionFlow,
+    session: &SessionInfo,
+    backend: ChannelBackend,
+) -> io::Result<RunOutcome> {
+    let terminal = Terminal::with_backend(backend)?.with_mode(RenderMode::AltScreen);
+    let mut runtime = flow.start(session, terminal)?;
+    let outcome = runtime.run()?;
+    flow.finish(session, runtime.state(), outcome);
+    Ok(outcome)
+}
@@ -0,0 +1,69 @@
+use super::*;
+
+const KEY: &str =
+    "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAIKuTMIYXGIgXOE1H1NBgZRUbq6SbMghPDdoyZkL8i8LV ops@example";
+const OTHER_KEY: &str = "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAIHFzgpMfuT6kk2qBBjcXy9ZQo4oMi0O7+ZPD4nxMv6Kd other@example";
+
+fn key(line: &str) -> PublicKey {
+    PublicKey::from_openssh(line).expect("public key")
+}
+
+#[test]
+fn anonymous_access_must_be_opted_into() {
+    assert!(!Access::default().is_open());
+    let err = Access::load(&SshServerOptions::default()).expect_err("no credentials");
+    assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    assert_eq!(SshServerOptions::default().listen, "127.0.0.1:2222");
+
+    let open = Access::load(&SshServerOptions {
+        allow_anonymous: true,
+        ..SshServerOptions::default()
+    })
+    .expect("anonymous access");
+    assert!(open.is_open());
+    assert!(open.allows_user("anyone"));
+    assert_eq!(open.methods(), [MethodKind::None]);
+
+    let listed = Access {
+        users: vec!["setup".to_string()],
+        anonymous: true,
+        ..Access::default()
+    };
+    assert!(listed.is_open());
+    assert!(listed.allows_user("setup"));
+    assert!(!listed.allows_user("root"));
+}
+
+#[test]
+fn password_must_match_exactly_for_an_allowed_user() {
+    let access = Access {
+        users: vec!["setup".to_string()],
+        password: Some("hunter2".to_string()),
+        ..Access::default()
+    };
+    assert!(!access.is_open());
+    assert_eq!(access.methods(), [MethodKind::Password]);
+    assert!(access.allows_password("setup", "hunter2"));
+    assert!(!access.allows_password("setup", "hunter"));
+    assert!(!access.allows_password("setup", "hunter22"));
+    assert!(!access.allows_password("root", "hunter2"));
+}
+
+#[test]
+fn public_keys_are_matched_against_authorized_keys() {
+    let path =
+        std::env::temp_dir().join(format!("steply-ssh-authorized-keys-{}", std::process::id()));
+    std::fs::write(&path, format!("# deploy keys\n{KEY}\n")).expect("write authorized keys");
+    let access = Access::load(&SshServerOptions {
+        authorized_keys_path: Some(path.clone()),
+        ..SshServerOptions::default()
+    });
+    let _ = std::fs::remove_file(&path);
+    let access = access.expect("load access");
+
+    assert!(!access.is_open());
+    assert_eq!(access.methods(), [MethodKind::PublicKey]);
+    assert!(access.allows_key("setup", &key(KEY)));
+    assert!(!access.allows_key("setup", &key(OTHER_KEY)));
+    assert!(!access.allows_password("setup", ""));
+}
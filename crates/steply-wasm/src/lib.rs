@@ -93,7 +93,6 @@ mod wasm_exports {
     use std::sync::atomic::{AtomicU64, Ordering};
     use steply_core::preview::{PreviewService, PreviewServiceOptions};
     use steply_core::runtime::effect::Effect;
-    use steply_core::runtime::intent::Intent;
     use steply_core::runtime::key_bindings::KeyBindings;
     use steply_core::runtime::reducer::Reducer;
     use steply_core::ui::renderer::{Renderer, RendererConfig};
@@ -160,7 +159,7 @@ mod wasm_exports {
                 Effect::System(event) => {
                     let _ = session.state.handle_system_event(event);
                 }
-                Effect::RequestRender | Effect::Schedule(_) => {}
+                Effect::RequestRender | Effect::Schedule(_) | Effect::ScrollViewport(_) => {}
             }
         }
         // Web preview mode intentionally skips task execution/scheduler runtime.
@@ -247,10 +246,7 @@ mod wasm_exports {
         let request = parse_request(request_json)?;
 
         with_session_mut(session_id, |session| {
-            let intent = session
-                .key_bindings
-                .resolve(key_event)
-                .unwrap_or(Intent::InputKey(key_event));
+            let intent = session.state.key_intent(key_event, &session.key_bindings);
             let effects = Reducer::reduce(&mut session.state, intent);
             apply_effects(session, effects);
             render_session(session, &request)